@@ -0,0 +1,252 @@
+//! Implements the escrow, unescrow, mint and burn of tokens required by the
+//! token transfer contexts on top of the [`BankKeeper`] of the host.
+//!
+//! Hosts implementing [`TokenTransferBankContext`] can delegate the
+//! corresponding methods of
+//! [`TokenTransferValidationContext`](crate::context::TokenTransferValidationContext)
+//! and
+//! [`TokenTransferExecutionContext`](crate::context::TokenTransferExecutionContext)
+//! to the functions of this module.
+
+pub use ibc_app_primitives::bank::{BankError, BankKeeper};
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::{escrow_address, PrefixedCoin};
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+
+/// The [`BankKeeper`] of the host, along with the accounts escrowing the
+/// tokens transferred over each channel.
+pub trait TokenTransferBankContext: BankKeeper {
+    /// Returns the account of the host identified by the given raw address.
+    fn account_from_address(&self, address: &[u8]) -> Result<Self::AccountId, TokenTransferError>;
+
+    /// Returns the account escrowing the tokens sent over the given channel.
+    ///
+    /// Defaults to the account of the [`escrow_address`] of the channel, so
+    /// that escrow accounts are the same as on ibc-go chains.
+    fn escrow_account(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Self::AccountId, TokenTransferError> {
+        self.account_from_address(&escrow_address(port_id, channel_id))
+    }
+}
+
+/// Validates that `from_account` holds enough tokens to escrow `coin`.
+pub fn escrow_coins_validate<Ctx>(
+    ctx: &Ctx,
+    from_account: &Ctx::AccountId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferBankContext,
+{
+    Ok(ctx.verify_sufficient_balance(from_account, coin)?)
+}
+
+/// Moves `coin` from `from_account` into the escrow account of the channel.
+pub fn escrow_coins_execute<Ctx>(
+    ctx: &mut Ctx,
+    from_account: &Ctx::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferBankContext,
+{
+    let escrow_account = ctx.escrow_account(port_id, channel_id)?;
+
+    Ok(ctx.send_coins(from_account, &escrow_account, coin)?)
+}
+
+/// Validates that the escrow account of the channel holds enough tokens to
+/// unescrow `coin`.
+pub fn unescrow_coins_validate<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferBankContext,
+{
+    let escrow_account = ctx.escrow_account(port_id, channel_id)?;
+
+    Ok(ctx.verify_sufficient_balance(&escrow_account, coin)?)
+}
+
+/// Moves `coin` from the escrow account of the channel to `to_account`.
+pub fn unescrow_coins_execute<Ctx>(
+    ctx: &mut Ctx,
+    to_account: &Ctx::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferBankContext,
+{
+    let escrow_account = ctx.escrow_account(port_id, channel_id)?;
+
+    Ok(ctx.send_coins(&escrow_account, to_account, coin)?)
+}
+
+/// Mints `coin` into `account`.
+pub fn mint_coins_execute<Ctx>(
+    ctx: &mut Ctx,
+    account: &Ctx::AccountId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferBankContext,
+{
+    Ok(ctx.mint_coins(account, coin)?)
+}
+
+/// Validates that `account` holds enough tokens to burn `coin`.
+pub fn burn_coins_validate<Ctx>(
+    ctx: &Ctx,
+    account: &Ctx::AccountId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferBankContext,
+{
+    Ok(ctx.verify_sufficient_balance(account, coin)?)
+}
+
+/// Burns `coin` from `account`.
+pub fn burn_coins_execute<Ctx>(
+    ctx: &mut Ctx,
+    account: &Ctx::AccountId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferBankContext,
+{
+    Ok(ctx.burn_coins(account, coin)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ibc_app_transfer_types::{Amount, PrefixedDenom};
+    use ibc_core::primitives::prelude::*;
+    use subtle_encoding::bech32;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct InMemoryBank {
+        balances: BTreeMap<(String, String), Amount>,
+    }
+
+    impl InMemoryBank {
+        fn debit(&mut self, account: &String, coin: &PrefixedCoin) -> Result<(), BankError> {
+            self.verify_sufficient_balance(account, coin)?;
+            let balance = self
+                .balances
+                .entry((account.clone(), coin.denom.to_string()))
+                .or_insert_with(Amount::zero);
+            *balance = balance
+                .checked_sub(coin.amount)
+                .expect("balance was verified to be sufficient");
+            Ok(())
+        }
+
+        fn credit(&mut self, account: &str, coin: &PrefixedCoin) -> Result<(), BankError> {
+            let balance = self
+                .balances
+                .entry((account.to_string(), coin.denom.to_string()))
+                .or_insert_with(Amount::zero);
+            *balance =
+                balance
+                    .checked_add(coin.amount)
+                    .ok_or_else(|| BankError::SupplyOverflow {
+                        denom: coin.denom.clone(),
+                    })?;
+            Ok(())
+        }
+    }
+
+    impl BankKeeper for InMemoryBank {
+        type AccountId = String;
+
+        fn send_coins(
+            &mut self,
+            from: &String,
+            to: &String,
+            coin: &PrefixedCoin,
+        ) -> Result<(), BankError> {
+            self.debit(from, coin)?;
+            self.credit(to, coin)
+        }
+
+        fn mint_coins(&mut self, account: &String, coin: &PrefixedCoin) -> Result<(), BankError> {
+            self.credit(account, coin)
+        }
+
+        fn burn_coins(&mut self, account: &String, coin: &PrefixedCoin) -> Result<(), BankError> {
+            self.debit(account, coin)
+        }
+
+        fn balance(&self, account: &String, denom: &PrefixedDenom) -> Result<Amount, BankError> {
+            Ok(self
+                .balances
+                .get(&(account.clone(), denom.to_string()))
+                .copied()
+                .unwrap_or_else(Amount::zero))
+        }
+    }
+
+    impl TokenTransferBankContext for InMemoryBank {
+        fn account_from_address(&self, address: &[u8]) -> Result<String, TokenTransferError> {
+            Ok(bech32::encode("cosmos", address))
+        }
+    }
+
+    #[test]
+    fn bank_backed_transfer() {
+        let mut bank = InMemoryBank::default();
+        let alice = "alice".to_string();
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::zero();
+        let coin: PrefixedCoin = "100uatom".parse().unwrap();
+        let voucher: PrefixedCoin = "40transfer/channel-1/uatom".parse().unwrap();
+
+        assert!(matches!(
+            escrow_coins_validate(&bank, &alice, &coin),
+            Err(TokenTransferError::InsufficientFunds { .. })
+        ));
+
+        mint_coins_execute(&mut bank, &alice, &coin).unwrap();
+        escrow_coins_validate(&bank, &alice, &coin).unwrap();
+        escrow_coins_execute(&mut bank, &alice, &port_id, &channel_id, &coin).unwrap();
+        assert_eq!(bank.balance(&alice, &coin.denom).unwrap(), Amount::zero());
+
+        unescrow_coins_validate(&bank, &port_id, &channel_id, &coin).unwrap();
+        unescrow_coins_execute(&mut bank, &alice, &port_id, &channel_id, &coin).unwrap();
+        assert_eq!(bank.balance(&alice, &coin.denom).unwrap(), coin.amount);
+        assert!(unescrow_coins_validate(&bank, &port_id, &channel_id, &coin).is_err());
+
+        mint_coins_execute(&mut bank, &alice, &voucher).unwrap();
+        burn_coins_validate(&bank, &alice, &voucher).unwrap();
+        burn_coins_execute(&mut bank, &alice, &voucher).unwrap();
+        assert!(burn_coins_validate(&bank, &alice, &voucher).is_err());
+    }
+
+    #[test]
+    fn escrow_account_of_ibc_go() {
+        // address obtained using `gaiad query ibc-transfer escrow-address transfer channel-141`
+        let escrow_account = InMemoryBank::default()
+            .escrow_account(&PortId::transfer(), &"channel-141".parse().unwrap())
+            .unwrap();
+
+        assert_eq!(
+            escrow_account,
+            "cosmos1x54ltnyg88k0ejmk8ytwrhd3ltm84xehrnlslf"
+        );
+    }
+}
@@ -0,0 +1,267 @@
+//! Defines the wire formats in which token transfers' packet data may be
+//! encoded, as negotiated through the version of their channel.
+
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::packet::{PacketData, PacketDataV2};
+use ibc_app_transfer_types::VERSION;
+#[cfg(feature = "serde")]
+use ibc_app_transfer_types::VERSION_V2;
+use ibc_core::channel::types::Version;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Protobuf;
+
+/// Encodes and decodes the packet data of token transfers in a given wire
+/// format.
+///
+/// Both ends of a channel must agree on the wire format, which they do by
+/// negotiating the [`version`](PacketDataCodec::version) of the codec upon
+/// opening the channel. Hosts serving counterparties that expect different
+/// encodings bind a transfer module per codec, each on its own port.
+pub trait PacketDataCodec {
+    /// Returns the channel version designating this encoding.
+    fn version() -> Version;
+
+    /// Encodes the given packet data.
+    fn encode(data: &PacketData) -> Vec<u8>;
+
+    /// Decodes packet data from the given bytes.
+    fn decode(bytes: &[u8]) -> Result<PacketData, TokenTransferError>;
+
+    /// Returns all the channel versions the codec can negotiate, starting
+    /// with the preferred one.
+    fn supported_versions() -> Vec<Version> {
+        vec![Self::version()]
+    }
+
+    /// Encodes the given multi-token packet data for a channel of the given
+    /// version.
+    ///
+    /// By default, the packet data must carry a single token and no
+    /// forwarding hops.
+    fn encode_v2(data: &PacketDataV2, _version: &Version) -> Result<Vec<u8>, TokenTransferError> {
+        PacketData::try_from(data.clone()).map(|data| Self::encode(&data))
+    }
+
+    /// Decodes multi-token packet data received over a channel of the given
+    /// version.
+    ///
+    /// By default, the packet data is decoded as by
+    /// [`decode`](PacketDataCodec::decode).
+    fn decode_v2(bytes: &[u8], _version: &Version) -> Result<PacketDataV2, TokenTransferError> {
+        Self::decode(bytes).map(Into::into)
+    }
+}
+
+/// Checks that `version` is one of the versions supported by the codec `C`.
+pub fn verify_version_supported<C: PacketDataCodec>(
+    version: &Version,
+) -> Result<(), TokenTransferError> {
+    let supported_versions = C::supported_versions();
+
+    if supported_versions.contains(version) {
+        return Ok(());
+    }
+
+    version
+        .verify_is_expected(C::version())
+        .map_err(|e| ContextError::from(e).into())
+}
+
+/// The [`PacketDataCodec`] encoding packet data as JSON, as prescribed by
+/// ICS-20 and expected by ibc-go. It negotiates the bare [`VERSION`].
+#[cfg(feature = "serde")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonCodec;
+
+#[cfg(feature = "serde")]
+impl PacketDataCodec for JsonCodec {
+    fn version() -> Version {
+        Version::new(VERSION.to_string())
+    }
+
+    fn encode(data: &PacketData) -> Vec<u8> {
+        serde_json::to_vec(data).expect("PacketData's infallible Serialize impl failed")
+    }
+
+    fn decode(bytes: &[u8]) -> Result<PacketData, TokenTransferError> {
+        serde_json::from_slice(bytes).map_err(|_| TokenTransferError::PacketDataDeserialization)
+    }
+}
+
+/// The [`PacketDataCodec`] encoding multi-token packet data as the protobuf
+/// `FungibleTokenPacketDataV2` message, as the `ics20-2` version of ibc-go
+/// does.
+///
+/// It also negotiates the bare [`VERSION`] with counterparties that do not
+/// support [`VERSION_V2`], in which case packets are encoded as by
+/// [`JsonCodec`] and may only carry a single token.
+#[cfg(feature = "serde")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProtoV2Codec;
+
+#[cfg(feature = "serde")]
+impl PacketDataCodec for ProtoV2Codec {
+    fn version() -> Version {
+        Version::new(VERSION_V2.to_string())
+    }
+
+    fn encode(data: &PacketData) -> Vec<u8> {
+        PacketDataV2::from(data.clone()).encode_vec()
+    }
+
+    fn decode(bytes: &[u8]) -> Result<PacketData, TokenTransferError> {
+        Self::decode_v2(bytes, &Self::version())?.try_into()
+    }
+
+    fn supported_versions() -> Vec<Version> {
+        vec![Self::version(), JsonCodec::version()]
+    }
+
+    fn encode_v2(data: &PacketDataV2, version: &Version) -> Result<Vec<u8>, TokenTransferError> {
+        if version == &JsonCodec::version() {
+            return JsonCodec::encode_v2(data, version);
+        }
+
+        Ok(data.clone().encode_vec())
+    }
+
+    fn decode_v2(bytes: &[u8], version: &Version) -> Result<PacketDataV2, TokenTransferError> {
+        if version == &JsonCodec::version() {
+            return JsonCodec::decode_v2(bytes, version);
+        }
+
+        PacketDataV2::decode_vec(bytes).map_err(|_| TokenTransferError::PacketDataDeserialization)
+    }
+}
+
+/// The [`PacketDataCodec`] encoding packet data as the protobuf
+/// `FungibleTokenPacketData` message. It negotiates the `ics20-1/proto3`
+/// version.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProtoCodec;
+
+impl PacketDataCodec for ProtoCodec {
+    fn version() -> Version {
+        Version::new(format!("{VERSION}/proto3"))
+    }
+
+    fn encode(data: &PacketData) -> Vec<u8> {
+        data.clone().encode_vec()
+    }
+
+    fn decode(bytes: &[u8]) -> Result<PacketData, TokenTransferError> {
+        PacketData::decode_vec(bytes).map_err(|_| TokenTransferError::PacketDataDeserialization)
+    }
+}
+
+/// The [`PacketDataCodec`] encoding packet data with borsh. It negotiates the
+/// `ics20-1/borsh` version.
+#[cfg(feature = "borsh")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BorshCodec;
+
+#[cfg(feature = "borsh")]
+impl PacketDataCodec for BorshCodec {
+    fn version() -> Version {
+        Version::new(format!("{VERSION}/borsh"))
+    }
+
+    fn encode(data: &PacketData) -> Vec<u8> {
+        borsh::to_vec(data).expect("PacketData's infallible BorshSerialize impl failed")
+    }
+
+    fn decode(bytes: &[u8]) -> Result<PacketData, TokenTransferError> {
+        borsh::BorshDeserialize::try_from_slice(bytes)
+            .map_err(|_| TokenTransferError::PacketDataDeserialization)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_app_transfer_types::{BaseCoin, U256};
+    use ibc_core::primitives::Signer;
+
+    use super::*;
+
+    fn dummy_packet_data() -> PacketData {
+        let address: Signer = "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"
+            .to_string()
+            .into();
+
+        PacketData {
+            token: BaseCoin {
+                denom: "uatom".parse().unwrap(),
+                amount: U256::from(10).into(),
+            }
+            .into(),
+            sender: address.clone(),
+            receiver: address,
+            memo: "".to_string().into(),
+        }
+    }
+
+    fn assert_roundtrip<C: PacketDataCodec>() {
+        let data = dummy_packet_data();
+        assert_eq!(C::decode(&C::encode(&data)).unwrap(), data);
+    }
+
+    #[test]
+    fn test_codecs_roundtrip() {
+        #[cfg(feature = "serde")]
+        assert_roundtrip::<JsonCodec>();
+        assert_roundtrip::<ProtoCodec>();
+        #[cfg(feature = "borsh")]
+        assert_roundtrip::<BorshCodec>();
+    }
+
+    #[test]
+    fn test_codecs_reject_other_encodings() {
+        let proto_bytes = ProtoCodec::encode(&dummy_packet_data());
+        #[cfg(feature = "serde")]
+        {
+            assert!(JsonCodec::decode(&proto_bytes).is_err());
+            assert!(ProtoCodec::decode(&JsonCodec::encode(&dummy_packet_data())).is_err());
+        }
+        #[cfg(feature = "borsh")]
+        assert!(BorshCodec::decode(&proto_bytes).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_proto_v2_codec() {
+        assert_roundtrip::<ProtoV2Codec>();
+
+        let mut data = PacketDataV2::from(dummy_packet_data());
+        data.tokens.push(data.tokens[0].clone());
+
+        let v2_bytes = ProtoV2Codec::encode_v2(&data, &ProtoV2Codec::version()).unwrap();
+        let v2 = ProtoV2Codec::version();
+        let v1 = JsonCodec::version();
+        assert_eq!(ProtoV2Codec::decode_v2(&v2_bytes, &v2).unwrap(), data);
+        assert!(JsonCodec::decode_v2(&v2_bytes, &v1).is_err());
+        // Over `ics20-1` channels, only the JSON layout of `JsonCodec` is accepted
+        assert!(ProtoV2Codec::decode_v2(&v2_bytes, &v1).is_err());
+
+        // Over `ics20-1` channels, packets carry a single token in the layout of `JsonCodec`
+        assert!(ProtoV2Codec::encode_v2(&data, &JsonCodec::version()).is_err());
+        data.tokens.pop();
+        let v1_bytes = ProtoV2Codec::encode_v2(&data, &JsonCodec::version()).unwrap();
+        assert_eq!(v1_bytes, JsonCodec::encode(&dummy_packet_data()));
+        assert_eq!(ProtoV2Codec::decode_v2(&v1_bytes, &v1).unwrap(), data);
+        assert!(ProtoV2Codec::decode_v2(&v1_bytes, &v2).is_err());
+
+        assert!(verify_version_supported::<ProtoV2Codec>(&JsonCodec::version()).is_ok());
+        assert!(verify_version_supported::<JsonCodec>(&ProtoV2Codec::version()).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_codec_layout() {
+        let data = dummy_packet_data();
+        assert_eq!(
+            JsonCodec::encode(&data),
+            br#"{"denom":"uatom","amount":"10","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","memo":""}"#
+        );
+    }
+}
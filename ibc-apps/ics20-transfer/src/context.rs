@@ -1,14 +1,20 @@
 //! Defines the main context traits and IBC module callbacks
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::{Memo, PrefixedCoin, PrefixedDenom};
+use ibc_app_transfer_types::{Amount, Memo, PrefixedCoin, PrefixedDenom, TransferParams};
+use ibc_core::channel::types::Version;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 
+use crate::codec::PacketDataCodec;
+
 /// Methods required in token transfer validation, to be implemented by the host
 pub trait TokenTransferValidationContext {
     type AccountId: TryFrom<Signer>;
+    /// The wire format of the packet data, whose version the transfer module
+    /// negotiates upon opening channels.
+    type PacketDataCodec: PacketDataCodec;
 
     /// get_port returns the portID for the transfer module.
     fn get_port(&self) -> Result<PortId, TokenTransferError>;
@@ -33,19 +39,27 @@ pub trait TokenTransferValidationContext {
     ) -> Result<(), TokenTransferError>;
 
     /// Validates that the tokens can be unescrowed successfully.
+    ///
+    /// `memo` field allows to incorporate additional contextual details in the
+    /// unescrow validation.
     fn unescrow_coins_validate(
         &self,
         to_account: &Self::AccountId,
         port_id: &PortId,
         channel_id: &ChannelId,
         coin: &PrefixedCoin,
+        memo: &Memo,
     ) -> Result<(), TokenTransferError>;
 
     /// Validates the receiver account and the coin input
+    ///
+    /// `memo` field allows to incorporate additional contextual details in the
+    /// mint validation.
     fn mint_coins_validate(
         &self,
         account: &Self::AccountId,
         coin: &PrefixedCoin,
+        memo: &Memo,
     ) -> Result<(), TokenTransferError>;
 
     /// Validates the sender account and the coin input before burning.
@@ -59,11 +73,69 @@ pub trait TokenTransferValidationContext {
         memo: &Memo,
     ) -> Result<(), TokenTransferError>;
 
+    /// Returns the version of the given channel, which determines how the
+    /// packet data sent and received over it is encoded.
+    ///
+    /// Hosts should return the version stored in the end of the channel, as
+    /// negotiated during its handshake, since codecs such as `ProtoV2Codec`
+    /// support several versions.
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, TokenTransferError>;
+
     /// Returns a hash of the prefixed denom.
     /// Implement only if the host chain supports hashed denominations.
     fn denom_hash_string(&self, _denom: &PrefixedDenom) -> Option<String> {
         None
     }
+
+    /// Returns the denomination registered under the given
+    /// [`trace_hash`](PrefixedDenom::trace_hash), if any.
+    ///
+    /// Implement along with
+    /// [`store_denom_trace`](TokenTransferExecutionContext::store_denom_trace)
+    /// only if the host resolves hashed denominations. Defaults to none.
+    fn get_denom_trace(
+        &self,
+        _trace_hash: &str,
+    ) -> Result<Option<PrefixedDenom>, TokenTransferError> {
+        Ok(None)
+    }
+
+    /// Returns the total amount of the given denomination escrowed by the
+    /// host over all its channels, or `None` if the host does not track its
+    /// total escrow.
+    ///
+    /// Implement along with
+    /// [`store_total_escrow`](TokenTransferExecutionContext::store_total_escrow)
+    /// only if the host tracks its total escrow, returning zero for the
+    /// denominations it never escrowed. Hosts starting to track it after
+    /// escrowing tokens must seed it with
+    /// [`migrate_total_escrow`](crate::handler::migrate_total_escrow).
+    /// Defaults to `None`.
+    fn get_total_escrow(
+        &self,
+        _denom: &PrefixedDenom,
+    ) -> Result<Option<Amount>, TokenTransferError> {
+        Ok(None)
+    }
+
+    /// Returns the transfer parameters in effect on the given channel, or the
+    /// global ones of the host if no channel is given.
+    ///
+    /// Hosts scoping parameters per channel should fall back to their global
+    /// parameters for the channels they have no parameters for. The handlers
+    /// only enable transfers over a channel if both its parameters and the
+    /// global ones do, as by [`channel_params`](crate::handler::channel_params).
+    /// Defaults to both sending and receiving being enabled.
+    fn get_params(
+        &self,
+        _channel: Option<(&PortId, &ChannelId)>,
+    ) -> Result<TransferParams, TokenTransferError> {
+        Ok(TransferParams::default())
+    }
 }
 
 /// Methods required in token transfer execution, to be implemented by the host.
@@ -82,19 +154,27 @@ pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
     ) -> Result<(), TokenTransferError>;
 
     /// Executes the unescrow of the tokens in a user account.
+    ///
+    /// `memo` field allows to incorporate additional contextual details in the
+    /// unescrow execution.
     fn unescrow_coins_execute(
         &mut self,
         to_account: &Self::AccountId,
         port_id: &PortId,
         channel_id: &ChannelId,
         coin: &PrefixedCoin,
+        memo: &Memo,
     ) -> Result<(), TokenTransferError>;
 
     /// Executes minting of the tokens in a user account.
+    ///
+    /// `memo` field allows to incorporate additional contextual details in the
+    /// mint execution.
     fn mint_coins_execute(
         &mut self,
         account: &Self::AccountId,
         coin: &PrefixedCoin,
+        memo: &Memo,
     ) -> Result<(), TokenTransferError>;
 
     /// Executes burning of the tokens in a user account.
@@ -107,4 +187,48 @@ pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
         coin: &PrefixedCoin,
         memo: &Memo,
     ) -> Result<(), TokenTransferError>;
+
+    /// Registers the denomination of vouchers upon their first receipt, under
+    /// its [`trace_hash`](PrefixedDenom::trace_hash), so that their `ibc/`
+    /// denomination can be resolved back to it.
+    ///
+    /// Implement only if the host resolves hashed denominations.
+    fn store_denom_trace(
+        &mut self,
+        _trace_hash: &str,
+        _denom: &PrefixedDenom,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Stores the total amount of the given denomination escrowed by the host
+    /// over all its channels, as updated upon each escrow and unescrow of
+    /// tokens.
+    ///
+    /// Auditors compare the total escrow of a denomination with the supply of
+    /// its vouchers on the counterparty chains. Implement only if the host
+    /// tracks its total escrow.
+    fn store_total_escrow(
+        &mut self,
+        _denom: &PrefixedDenom,
+        _amount: Amount,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Stores the transfer parameters of the given channel, or the global ones
+    /// of the host if no channel is given, as returned afterwards by
+    /// [`get_params`](TokenTransferValidationContext::get_params).
+    ///
+    /// Implement only if the host lets its operators pause transfers. Fails
+    /// by default.
+    fn store_params(
+        &mut self,
+        _channel: Option<(&PortId, &ChannelId)>,
+        _params: TransferParams,
+    ) -> Result<(), TokenTransferError> {
+        Err(TokenTransferError::Other(
+            "transfer parameters are not supported by the host".to_string(),
+        ))
+    }
 }
@@ -1,20 +1,28 @@
 //! Implements the processing logic for ICS20 (token transfer) message.
 mod on_recv_packet;
+mod params;
 mod send_transfer;
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::is_sender_chain_source;
-use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::packet::PacketDataV2;
+use ibc_app_transfer_types::{is_sender_chain_source, Amount, PrefixedCoin, PrefixedDenom};
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
 pub use on_recv_packet::*;
+pub use params::*;
 pub use send_transfer::*;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
+/// Refunds the tokens of a packet to its sender.
+///
+/// The refund is not atomic: a failure after the first token leaves the
+/// earlier ones refunded. It fails the whole message though, so the host must
+/// discard the writes of the failed message.
 pub fn refund_packet_token_execute(
     ctx_a: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
-    data: &PacketData,
+    data: &PacketDataV2,
 ) -> Result<(), TokenTransferError> {
     let sender = data
         .sender
@@ -22,28 +30,34 @@ pub fn refund_packet_token_execute(
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        ctx_a.unescrow_coins_execute(
-            &sender,
-            &packet.port_id_on_a,
-            &packet.chan_id_on_a,
-            &data.token,
-        )
-    }
-    // mint vouchers back to sender
-    else {
-        ctx_a.mint_coins_execute(&sender, &data.token)
+    for token in &data.tokens {
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            ctx_a.unescrow_coins_execute(
+                &sender,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                token,
+                &data.memo,
+            )?;
+            decrease_total_escrow(ctx_a, token)?;
+        }
+        // mint vouchers back to sender
+        else {
+            ctx_a.mint_coins_execute(&sender, token, &data.memo)?;
+        }
     }
+
+    Ok(())
 }
 
 pub fn refund_packet_token_validate(
     ctx_a: &impl TokenTransferValidationContext,
     packet: &Packet,
-    data: &PacketData,
+    data: &PacketDataV2,
 ) -> Result<(), TokenTransferError> {
     let sender = data
         .sender
@@ -51,18 +65,154 @@ pub fn refund_packet_token_validate(
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        ctx_a.unescrow_coins_validate(
-            &sender,
-            &packet.port_id_on_a,
-            &packet.chan_id_on_a,
-            &data.token,
-        )
-    } else {
-        ctx_a.mint_coins_validate(&sender, &data.token)
+    for token in &data.tokens {
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            ctx_a.unescrow_coins_validate(
+                &sender,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                token,
+                &data.memo,
+            )?;
+            decrease_total_escrow_validate(ctx_a, token)?;
+        } else {
+            ctx_a.mint_coins_validate(&sender, token, &data.memo)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the total escrow of the denomination of `coin` once `coin` is
+/// escrowed, if the host tracks it.
+fn increased_total_escrow(
+    ctx: &impl TokenTransferValidationContext,
+    coin: &PrefixedCoin,
+) -> Result<Option<Amount>, TokenTransferError> {
+    let Some(total_escrow) = ctx.get_total_escrow(&coin.denom)? else {
+        return Ok(None);
+    };
+
+    total_escrow
+        .checked_add(coin.amount)
+        .map(Some)
+        .ok_or_else(|| TokenTransferError::TotalEscrowOverflow {
+            denom: coin.denom.to_string(),
+        })
+}
+
+/// Validates that `coin` can be added to the total escrow of its denomination.
+pub(crate) fn increase_total_escrow_validate(
+    ctx: &impl TokenTransferValidationContext,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    increased_total_escrow(ctx, coin).map(|_| ())
+}
+
+/// Adds the escrowed `coin` to the total escrow of its denomination.
+pub(crate) fn increase_total_escrow(
+    ctx: &mut impl TokenTransferExecutionContext,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    if let Some(total_escrow) = increased_total_escrow(ctx, coin)? {
+        ctx.store_total_escrow(&coin.denom, total_escrow)?;
     }
+
+    Ok(())
+}
+
+/// Returns the total escrow of the denomination of `coin` once `coin` is
+/// unescrowed, if the host tracks it.
+///
+/// Unescrowing more than the total escrow fails, as the total escrow must
+/// cover all the tokens the host escrowed. Hosts that start tracking it after
+/// some tokens were escrowed must first seed it with
+/// [`migrate_total_escrow`].
+fn decreased_total_escrow(
+    ctx: &impl TokenTransferValidationContext,
+    coin: &PrefixedCoin,
+) -> Result<Option<Amount>, TokenTransferError> {
+    let Some(total_escrow) = ctx.get_total_escrow(&coin.denom)? else {
+        return Ok(None);
+    };
+
+    total_escrow
+        .checked_sub(coin.amount)
+        .map(Some)
+        .ok_or_else(|| TokenTransferError::TotalEscrowUnderflow {
+            denom: coin.denom.to_string(),
+        })
+}
+
+/// Validates that `coin` can be removed from the total escrow of its
+/// denomination.
+pub(crate) fn decrease_total_escrow_validate(
+    ctx: &impl TokenTransferValidationContext,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    decreased_total_escrow(ctx, coin).map(|_| ())
+}
+
+/// Removes the unescrowed `coin` from the total escrow of its denomination.
+pub(crate) fn decrease_total_escrow(
+    ctx: &mut impl TokenTransferExecutionContext,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    if let Some(total_escrow) = decreased_total_escrow(ctx, coin)? {
+        ctx.store_total_escrow(&coin.denom, total_escrow)?;
+    }
+
+    Ok(())
+}
+
+/// Seeds the total escrow of each denomination with the balances of the
+/// escrow accounts of the host, as the `MigrateTotalEscrowForDenom` migration
+/// of ibc-go does.
+///
+/// Hosts that start tracking their total escrow after some tokens were
+/// escrowed must run it before processing any further transfer, passing the
+/// balance of every escrow account in each denomination. The balances of the
+/// same denomination are summed, and the resulting totals replace the stored
+/// ones.
+pub fn migrate_total_escrow(
+    ctx: &mut impl TokenTransferExecutionContext,
+    escrowed: impl IntoIterator<Item = PrefixedCoin>,
+) -> Result<(), TokenTransferError> {
+    let mut totals: BTreeMap<PrefixedDenom, Amount> = BTreeMap::new();
+
+    for coin in escrowed {
+        let total = totals
+            .entry(coin.denom.clone())
+            .or_insert_with(Amount::zero);
+        *total = total.checked_add(coin.amount).ok_or_else(|| {
+            TokenTransferError::TotalEscrowOverflow {
+                denom: coin.denom.to_string(),
+            }
+        })?;
+    }
+
+    for (denom, total) in totals {
+        ctx.store_total_escrow(&denom, total)?;
+    }
+
+    Ok(())
+}
+
+/// Registers the denomination of the minted `coin` if it is received for the
+/// first time.
+pub(crate) fn register_denom_trace(
+    ctx: &mut impl TokenTransferExecutionContext,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    let trace_hash = coin.denom.trace_hash();
+
+    if ctx.get_denom_trace(&trace_hash)?.is_none() {
+        ctx.store_denom_trace(&trace_hash, &coin.denom)?;
+    }
+
+    Ok(())
 }
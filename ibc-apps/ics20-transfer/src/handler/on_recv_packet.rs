@@ -1,11 +1,14 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::DenomTraceEvent;
-use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{is_receiver_chain_source, TracePrefix};
+use ibc_app_transfer_types::packet::PacketDataV2;
+use ibc_app_transfer_types::{is_receiver_chain_source, PrefixedCoin, TracePrefix};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::module::ModuleExtras;
 
+use super::{
+    channel_params, decrease_total_escrow, decrease_total_escrow_validate, register_denom_trace,
+};
 use crate::context::TokenTransferExecutionContext;
 
 /// This function handles the transfer receiving logic.
@@ -14,15 +17,46 @@ use crate::context::TokenTransferExecutionContext;
 /// to validate accounts and token info. But the result is then used for
 /// execution on the IBC side, including storing acknowledgements and emitting
 /// events.
+///
+/// All the tokens of the packet are validated before any of them is
+/// unescrowed or minted, but their execution is not atomic: a failure after
+/// the first token leaves the earlier ones received. Since a failed receive
+/// becomes an error acknowledgement rather than a failed message, the host must
+/// run this callback against a cache-wrapped context and discard its writes
+/// when it returns an error, as ibc-go does with its cache context.
 pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
     ctx_b: &mut Ctx,
     packet: &Packet,
-    data: PacketData,
+    data: PacketDataV2,
 ) -> Result<ModuleExtras, (ModuleExtras, TokenTransferError)> {
     ctx_b
         .can_receive_coins()
         .map_err(|err| (ModuleExtras::empty(), err))?;
 
+    let params = channel_params(ctx_b, &packet.port_id_on_b, &packet.chan_id_on_b)
+        .map_err(|err| (ModuleExtras::empty(), err))?;
+    if !params.receive_enabled {
+        return Err((
+            ModuleExtras::empty(),
+            TokenTransferError::ReceiveDisabled {
+                reason: format!(
+                    "receiving is disabled on port `{}` and channel `{}`",
+                    packet.port_id_on_b, packet.chan_id_on_b
+                ),
+            },
+        ));
+    }
+
+    data.validate_basic()
+        .map_err(|err| (ModuleExtras::empty(), err))?;
+
+    if !data.forwarding.is_empty() {
+        return Err((
+            ModuleExtras::empty(),
+            TokenTransferError::ForwardingNotSupported,
+        ));
+    }
+
     let receiver_account = data.receiver.clone().try_into().map_err(|_| {
         (
             ModuleExtras::empty(),
@@ -30,87 +64,87 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         )
     })?;
 
-    let extras = if is_receiver_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        // sender chain is not the source, unescrow tokens
-        let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
-        let coin = {
-            let mut c = data.token;
-            c.denom.remove_trace_prefix(&prefix);
-            c
-        };
-
-        // Note: it is correct to do the validation here because `recv_packet()`
-        // works slightly differently. We do not have a
-        // `on_recv_packet_validate()` callback because regardless of whether or
-        // not the app succeeds to receive the packet, we want to run the
-        // `execute()` phase. And this is because the app failing to receive
-        // does not constitute a failure of the message processing.
-        // Specifically, when the app fails to receive, we need to return
-        // a `TokenTransferAcknowledgement::Error` acknowledgement, which
-        // gets relayed back to the sender so that the escrowed tokens
-        // can be refunded.
-        ctx_b
-            .unescrow_coins_validate(
-                &receiver_account,
-                &packet.port_id_on_b,
-                &packet.chan_id_on_b,
-                &coin,
-            )
-            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
-        ctx_b
-            .unescrow_coins_execute(
-                &receiver_account,
-                &packet.port_id_on_b,
-                &packet.chan_id_on_b,
-                &coin,
-            )
-            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
-
-        ModuleExtras::empty()
-    } else {
-        // sender chain is the source, mint vouchers
-        let prefix = TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
-        let coin = {
-            let mut c = data.token;
-            c.denom.add_trace_prefix(prefix);
-            c
-        };
+    let memo = data.memo;
 
-        let extras = {
-            let denom_trace_event = DenomTraceEvent {
-                trace_hash: ctx_b.denom_hash_string(&coin.denom),
-                denom: coin.denom.clone(),
-            };
-            ModuleExtras {
-                events: vec![denom_trace_event.into()],
-                log: Vec::new(),
+    // Each token is either unescrowed, if the sender chain is not its source,
+    // or minted as a voucher otherwise.
+    let coins: Vec<(PrefixedCoin, bool)> = data
+        .tokens
+        .into_iter()
+        .map(|mut coin| {
+            if is_receiver_chain_source(
+                packet.port_id_on_a.clone(),
+                packet.chan_id_on_a.clone(),
+                &coin.denom,
+            ) {
+                let prefix =
+                    TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
+                coin.denom.remove_trace_prefix(&prefix);
+                (coin, true)
+            } else {
+                let prefix =
+                    TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
+                coin.denom.add_trace_prefix(prefix);
+                (coin, false)
             }
-        };
+        })
+        .collect();
 
-        // Note: it is correct to do the validation here because `recv_packet()`
-        // works slightly differently. We do not have a
-        // `on_recv_packet_validate()` callback because regardless of whether or
-        // not the app succeeds to receive the packet, we want to run the
-        // `execute()` phase. And this is because the app failing to receive
-        // does not constitute a failure of the message processing.
-        // Specifically, when the app fails to receive, we need to return
-        // a `TokenTransferAcknowledgement::Error` acknowledgement, which
-        // gets relayed back to the sender so that the escrowed tokens
-        // can be refunded.
-        ctx_b
-            .mint_coins_validate(&receiver_account, &coin)
-            .map_err(|token_err| (extras.clone(), token_err))?;
+    // Note: it is correct to do the validation here because `recv_packet()`
+    // works slightly differently. We do not have a
+    // `on_recv_packet_validate()` callback because regardless of whether or
+    // not the app succeeds to receive the packet, we want to run the
+    // `execute()` phase. And this is because the app failing to receive
+    // does not constitute a failure of the message processing.
+    // Specifically, when the app fails to receive, we need to return
+    // a `TokenTransferAcknowledgement::Error` acknowledgement, which
+    // gets relayed back to the sender so that the escrowed tokens
+    // can be refunded.
+    for (coin, unescrow) in &coins {
+        if *unescrow {
+            ctx_b
+                .unescrow_coins_validate(
+                    &receiver_account,
+                    &packet.port_id_on_b,
+                    &packet.chan_id_on_b,
+                    coin,
+                    &memo,
+                )
+                .and_then(|()| decrease_total_escrow_validate(ctx_b, coin))
+        } else {
+            ctx_b.mint_coins_validate(&receiver_account, coin, &memo)
+        }
+        .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
+    }
 
-        ctx_b
-            .mint_coins_execute(&receiver_account, &coin)
-            .map_err(|token_err| (extras.clone(), token_err))?;
+    // As in ibc-go, the denomination traces are only emitted once all the
+    // tokens are received, as a failed receive reverts them.
+    let mut extras = ModuleExtras::empty();
 
-        extras
-    };
+    for (coin, unescrow) in &coins {
+        if *unescrow {
+            ctx_b
+                .unescrow_coins_execute(
+                    &receiver_account,
+                    &packet.port_id_on_b,
+                    &packet.chan_id_on_b,
+                    coin,
+                    &memo,
+                )
+                .and_then(|()| decrease_total_escrow(ctx_b, coin))
+        } else {
+            ctx_b
+                .mint_coins_execute(&receiver_account, coin, &memo)
+                .and_then(|()| register_denom_trace(ctx_b, coin))
+                .map(|()| {
+                    let trace_hash = ctx_b
+                        .denom_hash_string(&coin.denom)
+                        .unwrap_or_else(|| coin.denom.trace_hash());
+                    extras.events.push(DenomTraceEvent::new(trace_hash).into());
+                })
+        }
+        .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
+    }
 
     Ok(extras)
 }
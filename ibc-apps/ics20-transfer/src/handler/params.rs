@@ -0,0 +1,46 @@
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::events::ParamsEvent;
+use ibc_app_transfer_types::TransferParams;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+
+use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+
+/// Returns the transfer parameters in effect on the given channel.
+///
+/// Sending or receiving tokens over the channel is enabled only if it is
+/// enabled both by the global parameters of the host and by those of the
+/// channel, so that pausing transfers globally pauses them on every channel.
+pub fn channel_params<Ctx: TokenTransferValidationContext>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<TransferParams, TokenTransferError> {
+    let global = ctx.get_params(None)?;
+    let channel = ctx.get_params(Some((port_id, channel_id)))?;
+
+    Ok(TransferParams {
+        send_enabled: global.send_enabled && channel.send_enabled,
+        receive_enabled: global.receive_enabled && channel.receive_enabled,
+    })
+}
+
+/// Updates the transfer parameters of the given channel, or the global ones of
+/// the host if no channel is given, so that operators can pause sending or
+/// receiving tokens.
+///
+/// Returns the event the host should emit upon the update. Authorizing the
+/// update, e.g. through governance, is up to the host.
+pub fn update_params<Ctx: TokenTransferExecutionContext>(
+    ctx: &mut Ctx,
+    channel: Option<(PortId, ChannelId)>,
+    params: TransferParams,
+) -> Result<ParamsEvent, TokenTransferError> {
+    ctx.store_params(
+        channel
+            .as_ref()
+            .map(|(port_id, channel_id)| (port_id, channel_id)),
+        params,
+    )?;
+
+    Ok(ParamsEvent { channel, params })
+}
@@ -1,6 +1,6 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::TransferEvent;
-use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
+use ibc_app_transfer_types::msgs::transfer::MsgTransferV2;
 use ibc_app_transfer_types::{is_sender_chain_source, MODULE_ID_STR};
 use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
 use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
@@ -10,18 +10,25 @@ use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::event::ModuleEvent;
 
+use super::{channel_params, increase_total_escrow, increase_total_escrow_validate};
+use crate::codec::PacketDataCodec;
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
 /// Initiate a token transfer. Equivalent to calling [`send_transfer_validate`], followed by [`send_transfer_execute`].
+///
+/// Both `MsgTransfer`s and multi-token `MsgTransferV2`s are accepted. The
+/// packet is encoded according to the version of the channel, which must be
+/// `ics20-2` for packets carrying several tokens or forwarding hops.
 pub fn send_transfer<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     token_ctx_a: &mut TokenCtx,
-    msg: MsgTransfer,
+    msg: impl Into<MsgTransferV2>,
 ) -> Result<(), TokenTransferError>
 where
     SendPacketCtx: SendPacketExecutionContext,
     TokenCtx: TokenTransferExecutionContext,
 {
+    let msg = msg.into();
     send_transfer_validate(send_packet_ctx_a, token_ctx_a, msg.clone())?;
     send_transfer_execute(send_packet_ctx_a, token_ctx_a, msg)
 }
@@ -30,14 +37,27 @@ where
 pub fn send_transfer_validate<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &SendPacketCtx,
     token_ctx_a: &TokenCtx,
-    msg: MsgTransfer,
+    msg: impl Into<MsgTransferV2>,
 ) -> Result<(), TokenTransferError>
 where
     SendPacketCtx: SendPacketValidationContext,
     TokenCtx: TokenTransferValidationContext,
 {
+    let msg = msg.into();
+
     token_ctx_a.can_send_coins()?;
 
+    if !channel_params(token_ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a)?.send_enabled {
+        return Err(TokenTransferError::SendDisabled {
+            reason: format!(
+                "sending is disabled on port `{}` and channel `{}`",
+                msg.port_id_on_a, msg.chan_id_on_a
+            ),
+        });
+    }
+
+    msg.packet_data.validate_basic()?;
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -54,8 +74,6 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
-    let token = &msg.packet_data.token;
-
     let sender: TokenCtx::AccountId = msg
         .packet_data
         .sender
@@ -63,25 +81,27 @@ where
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        msg.port_id_on_a.clone(),
-        msg.chan_id_on_a.clone(),
-        &token.denom,
-    ) {
-        token_ctx_a.escrow_coins_validate(
-            &sender,
-            &msg.port_id_on_a,
-            &msg.chan_id_on_a,
-            token,
-            &msg.packet_data.memo,
-        )?;
-    } else {
-        token_ctx_a.burn_coins_validate(&sender, token, &msg.packet_data.memo)?;
+    for token in &msg.packet_data.tokens {
+        if is_sender_chain_source(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            token_ctx_a.escrow_coins_validate(
+                &sender,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                token,
+                &msg.packet_data.memo,
+            )?;
+            increase_total_escrow_validate(token_ctx_a, token)?;
+        } else {
+            token_ctx_a.burn_coins_validate(&sender, token, &msg.packet_data.memo)?;
+        }
     }
 
     let packet = {
-        let data = serde_json::to_vec(&msg.packet_data)
-            .expect("PacketData's infallible Serialize impl failed");
+        let data = TokenCtx::PacketDataCodec::encode_v2(&msg.packet_data, chan_end_on_a.version())?;
 
         Packet {
             seq_on_a: sequence,
@@ -104,12 +124,14 @@ where
 pub fn send_transfer_execute<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     token_ctx_a: &mut TokenCtx,
-    msg: MsgTransfer,
+    msg: impl Into<MsgTransferV2>,
 ) -> Result<(), TokenTransferError>
 where
     SendPacketCtx: SendPacketExecutionContext,
     TokenCtx: TokenTransferExecutionContext,
 {
+    let msg = msg.into();
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -127,8 +149,6 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
-    let token = &msg.packet_data.token;
-
     let sender = msg
         .packet_data
         .sender
@@ -136,27 +156,27 @@ where
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        msg.port_id_on_a.clone(),
-        msg.chan_id_on_a.clone(),
-        &token.denom,
-    ) {
-        token_ctx_a.escrow_coins_execute(
-            &sender,
-            &msg.port_id_on_a,
-            &msg.chan_id_on_a,
-            token,
-            &msg.packet_data.memo,
-        )?;
-    } else {
-        token_ctx_a.burn_coins_execute(&sender, token, &msg.packet_data.memo)?;
+    for token in &msg.packet_data.tokens {
+        if is_sender_chain_source(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            token_ctx_a.escrow_coins_execute(
+                &sender,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                token,
+                &msg.packet_data.memo,
+            )?;
+            increase_total_escrow(token_ctx_a, token)?;
+        } else {
+            token_ctx_a.burn_coins_execute(&sender, token, &msg.packet_data.memo)?;
+        }
     }
 
     let packet = {
-        let data = {
-            serde_json::to_vec(&msg.packet_data)
-                .expect("PacketData's infallible Serialize impl failed")
-        };
+        let data = TokenCtx::PacketDataCodec::encode_v2(&msg.packet_data, chan_end_on_a.version())?;
 
         Packet {
             seq_on_a: sequence,
@@ -172,23 +192,23 @@ where
 
     send_packet_execute(send_packet_ctx_a, packet)?;
 
-    {
+    for token in msg.packet_data.tokens {
         send_packet_ctx_a.log_message(format!(
             "IBC fungible token transfer: {} --({})--> {}",
             msg.packet_data.sender, token, msg.packet_data.receiver
         ))?;
 
         let transfer_event = TransferEvent {
-            sender: msg.packet_data.sender,
-            receiver: msg.packet_data.receiver,
-            amount: msg.packet_data.token.amount,
-            denom: msg.packet_data.token.denom,
-            memo: msg.packet_data.memo,
+            sender: msg.packet_data.sender.clone(),
+            receiver: msg.packet_data.receiver.clone(),
+            amount: token.amount,
+            denom: token.denom,
+            memo: msg.packet_data.memo.clone(),
         };
         send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(transfer_event).into())?;
-
-        send_packet_ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
     }
 
+    send_packet_ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+
     Ok(())
 }
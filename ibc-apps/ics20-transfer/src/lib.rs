@@ -24,8 +24,11 @@ pub mod types {
     pub use ibc_app_transfer_types::*;
 }
 
+pub mod bank;
+pub mod codec;
 pub mod context;
 #[cfg(feature = "serde")]
 pub mod handler;
 #[cfg(feature = "serde")]
 pub mod module;
+pub mod query;
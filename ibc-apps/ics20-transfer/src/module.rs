@@ -1,24 +1,26 @@
+use ibc_app_transfer_types::ack_success_result;
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
-use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{ack_success_b64, VERSION};
-use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_app_transfer_types::packet::PacketDataV2;
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementResult, AcknowledgementStatus,
+};
 use ibc_core::channel::types::channel::{Counterparty, Order};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::channel::types::Version;
-use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_core::router::types::module::ModuleExtras;
 
+use crate::codec::{verify_version_supported, PacketDataCodec};
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 use crate::handler::{
     process_recv_packet_execute, refund_packet_token_execute, refund_packet_token_validate,
 };
 
-pub fn on_chan_open_init_validate(
-    ctx: &impl TokenTransferValidationContext,
+pub fn on_chan_open_init_validate<Ctx: TokenTransferValidationContext>(
+    ctx: &Ctx,
     order: Order,
     _connection_hops: &[ConnectionId],
     port_id: &PortId,
@@ -41,28 +43,32 @@ pub fn on_chan_open_init_validate(
     }
 
     if !version.is_empty() {
-        version
-            .verify_is_expected(Version::new(VERSION.to_string()))
-            .map_err(ContextError::from)?;
+        verify_version_supported::<Ctx::PacketDataCodec>(version)?;
     }
 
     Ok(())
 }
 
-pub fn on_chan_open_init_execute(
-    _ctx: &mut impl TokenTransferExecutionContext,
+pub fn on_chan_open_init_execute<Ctx: TokenTransferExecutionContext>(
+    _ctx: &mut Ctx,
     _order: Order,
     _connection_hops: &[ConnectionId],
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _version: &Version,
+    version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    let version = if version.is_empty() {
+        Ctx::PacketDataCodec::version()
+    } else {
+        version.clone()
+    };
+
+    Ok((ModuleExtras::empty(), version))
 }
 
-pub fn on_chan_open_try_validate(
-    _ctx: &impl TokenTransferValidationContext,
+pub fn on_chan_open_try_validate<Ctx: TokenTransferValidationContext>(
+    _ctx: &Ctx,
     order: Order,
     _connection_hops: &[ConnectionId],
     _port_id: &PortId,
@@ -77,34 +83,30 @@ pub fn on_chan_open_try_validate(
         });
     }
 
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    verify_version_supported::<Ctx::PacketDataCodec>(counterparty_version)?;
 
     Ok(())
 }
 
-pub fn on_chan_open_try_execute(
-    _ctx: &mut impl TokenTransferExecutionContext,
+pub fn on_chan_open_try_execute<Ctx: TokenTransferExecutionContext>(
+    _ctx: &mut Ctx,
     _order: Order,
     _connection_hops: &[ConnectionId],
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _counterparty_version: &Version,
+    counterparty_version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    Ok((ModuleExtras::empty(), counterparty_version.clone()))
 }
 
-pub fn on_chan_open_ack_validate(
-    _ctx: &impl TokenTransferValidationContext,
+pub fn on_chan_open_ack_validate<Ctx: TokenTransferValidationContext>(
+    _ctx: &Ctx,
     _port_id: &PortId,
     _channel_id: &ChannelId,
     counterparty_version: &Version,
 ) -> Result<(), TokenTransferError> {
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    verify_version_supported::<Ctx::PacketDataCodec>(counterparty_version)?;
 
     Ok(())
 }
@@ -166,30 +168,49 @@ pub fn on_chan_close_confirm_execute(
     Ok(ModuleExtras::empty())
 }
 
-pub fn on_recv_packet_execute(
-    ctx_b: &mut impl TokenTransferExecutionContext,
+/// Decodes the data of a packet sent by the host, as encoded for the version
+/// of its channel.
+fn decode_sent_packet_data<Ctx: TokenTransferValidationContext>(
+    ctx: &Ctx,
+    packet: &Packet,
+) -> Result<PacketDataV2, TokenTransferError> {
+    let version = ctx.channel_version(&packet.port_id_on_a, &packet.chan_id_on_a)?;
+
+    Ctx::PacketDataCodec::decode_v2(&packet.data, &version)
+}
+
+pub fn on_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
+    ctx_b: &mut Ctx,
     packet: &Packet,
 ) -> (ModuleExtras, Acknowledgement) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
-        let ack =
-            AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into());
+    let data = ctx_b
+        .channel_version(&packet.port_id_on_b, &packet.chan_id_on_b)
+        .and_then(|version| Ctx::PacketDataCodec::decode_v2(&packet.data, &version));
+    let Ok(data) = data else {
+        let ack = AcknowledgementResult::from(TokenTransferError::PacketDataDeserialization);
         return (ModuleExtras::empty(), ack.into());
     };
 
-    let (mut extras, ack) = match process_recv_packet_execute(ctx_b, packet, data.clone()) {
-        Ok(extras) => (extras, AcknowledgementStatus::success(ack_success_b64())),
-        Err((extras, error)) => (extras, AcknowledgementStatus::error(error.into())),
+    let (mut extras, ack, error) = match process_recv_packet_execute(ctx_b, packet, data.clone()) {
+        Ok(extras) => (extras, ack_success_result(), None),
+        Err((extras, error)) => {
+            let message = error.to_string();
+            (extras, error.into(), Some(message))
+        }
     };
 
-    let recv_event = RecvEvent {
-        sender: data.sender,
-        receiver: data.receiver,
-        denom: data.token.denom,
-        amount: data.token.amount,
-        memo: data.memo,
-        success: ack.is_successful(),
-    };
-    extras.events.push(recv_event.into());
+    for token in data.tokens {
+        let recv_event = RecvEvent {
+            sender: data.sender.clone(),
+            receiver: data.receiver.clone(),
+            denom: token.denom,
+            amount: token.amount,
+            memo: data.memo.clone(),
+            success: ack.is_successful(),
+            error: error.clone(),
+        };
+        extras.events.push(recv_event.into());
+    }
 
     (extras, ack.into())
 }
@@ -203,8 +224,7 @@ pub fn on_acknowledgement_packet_validate<Ctx>(
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+    let data = decode_sent_packet_data(ctx, packet)?;
 
     let acknowledgement = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
         .map_err(|_| TokenTransferError::AckDeserialization)?;
@@ -216,13 +236,13 @@ where
     Ok(())
 }
 
-pub fn on_acknowledgement_packet_execute(
-    ctx: &mut impl TokenTransferExecutionContext,
+pub fn on_acknowledgement_packet_execute<Ctx: TokenTransferExecutionContext>(
+    ctx: &mut Ctx,
     packet: &Packet,
     acknowledgement: &Acknowledgement,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
+    let Ok(data) = decode_sent_packet_data(ctx, packet) else {
         return (
             ModuleExtras::empty(),
             Err(TokenTransferError::PacketDataDeserialization),
@@ -244,17 +264,25 @@ pub fn on_acknowledgement_packet_execute(
         }
     }
 
-    let ack_event = AckEvent {
-        sender: data.sender,
-        receiver: data.receiver,
-        denom: data.token.denom,
-        amount: data.token.amount,
-        memo: data.memo,
-        acknowledgement: acknowledgement.clone(),
-    };
+    let mut events: Vec<_> = data
+        .tokens
+        .into_iter()
+        .map(|token| {
+            AckEvent {
+                sender: data.sender.clone(),
+                receiver: data.receiver.clone(),
+                denom: token.denom,
+                amount: token.amount,
+                memo: data.memo.clone(),
+                acknowledgement: acknowledgement.clone(),
+            }
+            .into()
+        })
+        .collect();
+    events.push(AckStatusEvent { acknowledgement }.into());
 
     let extras = ModuleExtras {
-        events: vec![ack_event.into(), AckStatusEvent { acknowledgement }.into()],
+        events,
         log: Vec::new(),
     };
 
@@ -269,20 +297,19 @@ pub fn on_timeout_packet_validate<Ctx>(
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+    let data = decode_sent_packet_data(ctx, packet)?;
 
     refund_packet_token_validate(ctx, packet, &data)?;
 
     Ok(())
 }
 
-pub fn on_timeout_packet_execute(
-    ctx: &mut impl TokenTransferExecutionContext,
+pub fn on_timeout_packet_execute<Ctx: TokenTransferExecutionContext>(
+    ctx: &mut Ctx,
     packet: &Packet,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
+    let Ok(data) = decode_sent_packet_data(ctx, packet) else {
         return (
             ModuleExtras::empty(),
             Err(TokenTransferError::PacketDataDeserialization),
@@ -293,15 +320,22 @@ pub fn on_timeout_packet_execute(
         return (ModuleExtras::empty(), Err(err));
     }
 
-    let timeout_event = TimeoutEvent {
-        refund_receiver: data.sender,
-        refund_denom: data.token.denom,
-        refund_amount: data.token.amount,
-        memo: data.memo,
-    };
+    let events = data
+        .tokens
+        .into_iter()
+        .map(|token| {
+            TimeoutEvent {
+                refund_receiver: data.sender.clone(),
+                refund_denom: token.denom,
+                refund_amount: token.amount,
+                memo: data.memo.clone(),
+            }
+            .into()
+        })
+        .collect();
 
     let extras = ModuleExtras {
-        events: vec![timeout_event.into()],
+        events,
         log: Vec::new(),
     };
 
@@ -310,6 +344,8 @@ pub fn on_timeout_packet_execute(
 
 #[cfg(test)]
 mod test {
+    use ibc_app_transfer_types::ack_success_b64;
+
     use super::*;
 
     #[test]
@@ -354,6 +390,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_ack_result_to_vec() {
+        assert_eq!(ack_success_result().encode(), br#"{"result":"AQ=="}"#);
+
+        let ack_error =
+            AcknowledgementResult::from(TokenTransferError::PacketDataDeserialization).encode();
+        assert_eq!(
+            ack_error,
+            br#"{"error":"ABCI code: 2: failed to deserialize packet data"}"#
+        );
+
+        // Counterparties reading the acknowledgement status still see an error
+        let ack_status = serde_json::from_slice::<AcknowledgementStatus>(&ack_error).unwrap();
+        assert!(!ack_status.is_successful());
+    }
+
     #[test]
     fn test_ack_de() {
         fn de_json_assert_eq(json_str: &str, ack: AcknowledgementStatus) {
@@ -0,0 +1,102 @@
+//! Serves the queries of the `ibc.applications.transfer.v1.Query` service
+//! from the token transfer contexts of the host.
+
+use core::str::FromStr;
+
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::proto::transfer::v1::{
+    QueryDenomHashRequest, QueryDenomHashResponse, QueryDenomTraceRequest, QueryDenomTraceResponse,
+    QueryParamsRequest, QueryParamsResponse, QueryTotalEscrowForDenomRequest,
+    QueryTotalEscrowForDenomResponse,
+};
+use ibc_app_transfer_types::{Amount, PrefixedCoin, PrefixedDenom, IBC_DENOM_PREFIX};
+use ibc_core::primitives::prelude::*;
+
+use crate::context::TokenTransferValidationContext;
+
+/// Resolves the given denomination, which is either a full denomination path
+/// or the `ibc/{hash}` denomination of vouchers, to its full trace.
+///
+/// Wallets and frontends use it to display the origin of the vouchers they
+/// hold.
+pub fn resolve_denom<Ctx: TokenTransferValidationContext>(
+    ctx: &Ctx,
+    denom: &str,
+) -> Result<PrefixedDenom, TokenTransferError> {
+    match denom
+        .strip_prefix(IBC_DENOM_PREFIX)
+        .and_then(|hash| hash.strip_prefix('/'))
+    {
+        Some(trace_hash) => get_denom_trace(ctx, trace_hash),
+        None => PrefixedDenom::from_str(denom),
+    }
+}
+
+fn get_denom_trace<Ctx: TokenTransferValidationContext>(
+    ctx: &Ctx,
+    trace_hash: &str,
+) -> Result<PrefixedDenom, TokenTransferError> {
+    ctx.get_denom_trace(&trace_hash.to_uppercase())?
+        .ok_or_else(|| TokenTransferError::DenomTraceNotFound {
+            hash: trace_hash.to_string(),
+        })
+}
+
+/// Returns the trace of the denomination registered under the requested hash,
+/// or `ibc/{hash}` denomination, as `DenomTrace` of ibc-go.
+pub fn query_denom_trace<Ctx: TokenTransferValidationContext>(
+    ctx: &Ctx,
+    request: QueryDenomTraceRequest,
+) -> Result<QueryDenomTraceResponse, TokenTransferError> {
+    let trace_hash = request
+        .hash
+        .strip_prefix(IBC_DENOM_PREFIX)
+        .and_then(|hash| hash.strip_prefix('/'))
+        .unwrap_or(&request.hash);
+
+    let denom = get_denom_trace(ctx, trace_hash)?;
+
+    Ok(QueryDenomTraceResponse {
+        denom_trace: Some(denom.into()),
+    })
+}
+
+/// Returns the hash of the requested denomination trace, provided it is
+/// registered, as `DenomHash` of ibc-go.
+pub fn query_denom_hash<Ctx: TokenTransferValidationContext>(
+    ctx: &Ctx,
+    request: QueryDenomHashRequest,
+) -> Result<QueryDenomHashResponse, TokenTransferError> {
+    let trace_hash = PrefixedDenom::from_str(&request.trace)?.trace_hash();
+
+    get_denom_trace(ctx, &trace_hash)?;
+
+    Ok(QueryDenomHashResponse { hash: trace_hash })
+}
+
+/// Returns the total amount of the requested denomination escrowed by the
+/// host, as `TotalEscrowForDenom` of ibc-go, which is zero if the host does
+/// not track its total escrow.
+pub fn query_total_escrow_for_denom<Ctx: TokenTransferValidationContext>(
+    ctx: &Ctx,
+    request: QueryTotalEscrowForDenomRequest,
+) -> Result<QueryTotalEscrowForDenomResponse, TokenTransferError> {
+    let denom = resolve_denom(ctx, &request.denom)?;
+    let amount = ctx.get_total_escrow(&denom)?.unwrap_or_else(Amount::zero);
+
+    Ok(QueryTotalEscrowForDenomResponse {
+        amount: Some(PrefixedCoin { denom, amount }.into()),
+    })
+}
+
+/// Returns the global transfer parameters of the host, as `Params` of ibc-go.
+pub fn query_params<Ctx: TokenTransferValidationContext>(
+    ctx: &Ctx,
+    _request: QueryParamsRequest,
+) -> Result<QueryParamsResponse, TokenTransferError> {
+    let params = ctx.get_params(None)?;
+
+    Ok(QueryParamsResponse {
+        params: Some(params.into()),
+    })
+}
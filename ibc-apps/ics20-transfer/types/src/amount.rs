@@ -7,10 +7,14 @@ use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
 use ibc_core::primitives::serializers;
 use primitive_types::U256;
+use uint::FromDecStrErr;
 
 use super::error::TokenTransferError;
 
 /// A type for representing token transfer amounts.
+///
+/// Amounts are unsigned 256-bit integers, serialized as decimal strings so
+/// that no JSON parser truncates them.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Display, From, Into)]
@@ -92,6 +96,21 @@ impl From<[u64; 4]> for Amount {
 }
 
 impl Amount {
+    /// The zero amount.
+    pub const fn zero() -> Self {
+        Self(U256::zero())
+    }
+
+    /// The largest amount.
+    pub const fn max_value() -> Self {
+        Self(U256::MAX)
+    }
+
+    /// Returns `true` if the amount is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
     pub fn checked_add(self, rhs: Self) -> Option<Self> {
         self.0.checked_add(rhs.0).map(Self)
     }
@@ -99,6 +118,36 @@ impl Amount {
     pub fn checked_sub(self, rhs: Self) -> Option<Self> {
         self.0.checked_sub(rhs.0).map(Self)
     }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.0.checked_mul(rhs.0).map(Self)
+    }
+
+    /// Adds the given amount, failing if the sum overflows.
+    pub fn try_add(self, rhs: Self) -> Result<Self, TokenTransferError> {
+        self.checked_add(rhs)
+            .ok_or(TokenTransferError::AmountOverflow {
+                lhs: self,
+                op: "+",
+                rhs,
+            })
+    }
+
+    /// Subtracts the given amount, failing if the difference is negative.
+    pub fn try_sub(self, rhs: Self) -> Result<Self, TokenTransferError> {
+        self.checked_sub(rhs)
+            .ok_or(TokenTransferError::AmountUnderflow { lhs: self, rhs })
+    }
+
+    /// Multiplies by the given amount, failing if the product overflows.
+    pub fn try_mul(self, rhs: Self) -> Result<Self, TokenTransferError> {
+        self.checked_mul(rhs)
+            .ok_or(TokenTransferError::AmountOverflow {
+                lhs: self,
+                op: "*",
+                rhs,
+            })
+    }
 }
 
 impl AsRef<U256> for Amount {
@@ -110,7 +159,14 @@ impl AsRef<U256> for Amount {
 impl FromStr for Amount {
     type Err = TokenTransferError;
 
+    /// Parses a decimal amount. Unlike `U256::from_dec_str`, the empty string
+    /// is rejected instead of being read as zero.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(TokenTransferError::InvalidAmount(
+                FromDecStrErr::InvalidLength,
+            ));
+        }
         let amount = U256::from_dec_str(s).map_err(TokenTransferError::InvalidAmount)?;
         Ok(Self(amount))
     }
@@ -122,24 +178,107 @@ impl From<u64> for Amount {
     }
 }
 
+impl From<u128> for Amount {
+    fn from(v: u128) -> Self {
+        Self(v.into())
+    }
+}
+
+impl TryFrom<Amount> for u64 {
+    type Error = TokenTransferError;
+
+    fn try_from(amount: Amount) -> Result<Self, Self::Error> {
+        amount
+            .0
+            .try_into()
+            .map_err(|_| TokenTransferError::AmountNotRepresentable {
+                amount,
+                target: "u64",
+            })
+    }
+}
+
+impl TryFrom<Amount> for u128 {
+    type Error = TokenTransferError;
+
+    fn try_from(amount: Amount) -> Result<Self, Self::Error> {
+        amount
+            .0
+            .try_into()
+            .map_err(|_| TokenTransferError::AmountNotRepresentable {
+                amount,
+                target: "u128",
+            })
+    }
+}
+
 #[cfg(feature = "serde")]
 fn deserialize<'de, D>(deserializer: D) -> Result<U256, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
-    use serde::Deserialize;
-    U256::from_dec_str(<String>::deserialize(deserializer)?.as_str())
-        .map_err(serde::de::Error::custom)
+    serializers::deserialize::<Amount, D>(deserializer).map(|amount| amount.0)
 }
 
 #[cfg(test)]
 mod tests {
+    use ibc_core::primitives::prelude::*;
+    use primitive_types::U256;
+
     use super::Amount;
+    use crate::error::TokenTransferError;
+
+    #[test]
+    fn checked_arithmetic() {
+        let max = Amount::max_value();
+        let one = Amount::from(1u64);
+
+        assert_eq!(
+            Amount::from(2u64).try_add(Amount::from(3u64)).unwrap(),
+            Amount::from(5u64)
+        );
+        assert_eq!(Amount::from(3u64).try_sub(Amount::from(2u64)).unwrap(), one);
+        assert_eq!(
+            Amount::from(2u64).try_mul(Amount::from(3u64)).unwrap(),
+            Amount::from(6u64)
+        );
+
+        assert!(matches!(
+            max.try_add(one),
+            Err(TokenTransferError::AmountOverflow { op: "+", .. })
+        ));
+        assert!(matches!(
+            Amount::zero().try_sub(one),
+            Err(TokenTransferError::AmountUnderflow { .. })
+        ));
+        assert!(matches!(
+            max.try_mul(Amount::from(2u64)),
+            Err(TokenTransferError::AmountOverflow { op: "*", .. })
+        ));
+    }
+
+    #[test]
+    fn integer_conversions() {
+        let amount = Amount::from(u128::MAX);
+        assert_eq!(u128::try_from(amount).unwrap(), u128::MAX);
+        assert!(matches!(
+            u64::try_from(amount),
+            Err(TokenTransferError::AmountNotRepresentable { target: "u64", .. })
+        ));
+        assert_eq!(u64::try_from(Amount::from(42u64)).unwrap(), 42);
+        assert_eq!(Amount::from(U256::MAX), Amount::max_value());
+    }
+
+    #[test]
+    fn empty_amount_is_invalid() {
+        assert!("".parse::<Amount>().is_err());
+        assert_eq!("0".parse::<Amount>().unwrap(), Amount::zero());
+    }
 
     #[cfg(feature = "serde")]
     #[test]
     fn serde_amount() {
-        let value = Amount::from(42);
+        let value = Amount::from(42u64);
         let string = serde_json::to_string(&value).expect("can serde string");
         assert_eq!(string, "\"42\"");
         let binary = serde_json::to_vec(&value).expect("can serde binary");
@@ -147,12 +286,25 @@ mod tests {
         assert_eq!(de, value);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_amount_preserves_large_values() {
+        let value = Amount::max_value();
+        let string = serde_json::to_string(&value).expect("can serde string");
+        assert_eq!(string, format!("\"{}\"", U256::MAX));
+        let de: Amount = serde_json::from_str(&string).expect("can deserialize");
+        assert_eq!(de, value);
+
+        assert!(serde_json::from_str::<Amount>("42").is_err());
+        assert!(serde_json::from_str::<Amount>("\"\"").is_err());
+    }
+
     #[cfg(feature = "borsh")]
     #[test]
     fn borsh_amount() {
         use borsh::BorshDeserialize;
 
-        let value = Amount::from(42);
+        let value = Amount::from(42u64);
         let serialized = borsh::to_vec(&value).unwrap();
 
         // Amount is supposed to be a U256 according to the spec, which is 32 bytes
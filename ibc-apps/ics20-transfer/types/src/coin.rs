@@ -132,16 +132,16 @@ mod tests {
     use super::*;
 
     #[rstest]
-    #[case::nat("123stake", 123, "stake")]
-    #[case::zero("0stake", 0, "stake")]
+    #[case::nat("123stake", 123u64, "stake")]
+    #[case::zero("0stake", 0u64, "stake")]
     #[case::u256_max(
         "115792089237316195423570985008687907853269984665640564039457584007913129639935stake",
         U256::MAX,
         "stake"
     )]
-    #[case::digit_in_denom("1a1", 1, "a1")]
-    #[case::chars_in_denom("0x1/:._-", 0, "x1/:._-")]
-    #[case::ibc_denom("1234ibc/a0B1C", 1234, "ibc/a0B1C")]
+    #[case::digit_in_denom("1a1", 1u64, "a1")]
+    #[case::chars_in_denom("0x1/:._-", 0u64, "x1/:._-")]
+    #[case::ibc_denom("1234ibc/a0B1C", 1234u64, "ibc/a0B1C")]
     fn test_parse_raw_coin(
         #[case] parsed: RawCoin,
         #[case] amount: impl Into<Amount>,
@@ -7,6 +7,7 @@ use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
 use ibc_core::primitives::serializers;
+use ibc_core::primitives::{DefaultCryptoProvider, HostCryptoProvider};
 use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
 
 use super::error::TokenTransferError;
@@ -83,6 +84,14 @@ impl TracePrefix {
             channel_id,
         }
     }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
 }
 
 impl Display for TracePrefix {
@@ -139,6 +148,11 @@ impl TracePath {
     pub fn empty() -> Self {
         Self(vec![])
     }
+
+    /// Returns the prefixes of the path, starting from the outermost one.
+    pub fn prefixes(&self) -> impl DoubleEndedIterator<Item = &TracePrefix> {
+        self.0.iter().rev()
+    }
 }
 
 impl<'a> TryFrom<Vec<&'a str>> for TracePath {
@@ -239,8 +253,32 @@ impl PrefixedDenom {
     pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
         self.trace_path.add_prefix(prefix)
     }
+
+    /// Returns the hash of the denomination, as computed by ibc-go: the
+    /// upper-case hex encoding of the SHA-256 digest of its full path.
+    pub fn trace_hash(&self) -> String {
+        DefaultCryptoProvider::sha256(self.to_string().as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect()
+    }
+
+    /// Returns the denomination under which the host holds the tokens, as
+    /// ibc-go does: `ibc/{trace_hash}` for vouchers, and the base
+    /// denomination for native tokens.
+    pub fn ibc_denom(&self) -> String {
+        if self.trace_path.is_empty() {
+            self.base_denom.to_string()
+        } else {
+            format!("{IBC_DENOM_PREFIX}/{}", self.trace_hash())
+        }
+    }
 }
 
+/// The prefix of the hashed denominations of vouchers, followed by the
+/// [`trace_hash`](PrefixedDenom::trace_hash) of their full path.
+pub const IBC_DENOM_PREFIX: &str = "ibc";
+
 /// Returns true if the denomination originally came from the sender chain and
 /// false otherwise.
 ///
@@ -359,6 +397,23 @@ impl Display for PrefixedDenom {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_denom_trace_hash() -> Result<(), TokenTransferError> {
+        // ATOM on Osmosis, as listed by `osmosisd query ibc-transfer denom-trace`
+        let denom = PrefixedDenom::from_str("transfer/channel-0/uatom")?;
+        assert_eq!(
+            denom.trace_hash(),
+            "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+        assert_eq!(
+            denom.ibc_denom(),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+        assert_eq!(PrefixedDenom::from_str("uatom")?.ibc_denom(), "uatom");
+
+        Ok(())
+    }
+
     #[test]
     fn test_denom_validation() -> Result<(), TokenTransferError> {
         assert!(BaseDenom::from_str("").is_err(), "empty base denom");
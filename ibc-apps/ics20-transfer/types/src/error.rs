@@ -3,14 +3,17 @@ use core::convert::Infallible;
 use core::str::Utf8Error;
 
 use displaydoc::Display;
-use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::acknowledgement::{AcknowledgementResult, StatusValue};
 use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::Version;
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::error::IdentifierError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use uint::FromDecStrErr;
 
+use crate::Amount;
+
 #[derive(Display, Debug)]
 pub enum TokenTransferError {
     /// context error: `{0}`
@@ -43,8 +46,35 @@ pub enum TokenTransferError {
     InvalidTraceLength { len: u64 },
     /// invalid amount error: `{0}`
     InvalidAmount(FromDecStrErr),
+    /// amount overflow: `{lhs}` {op} `{rhs}` exceeds the maximum amount
+    AmountOverflow {
+        lhs: Amount,
+        op: &'static str,
+        rhs: Amount,
+    },
+    /// amount underflow: `{lhs}` - `{rhs}` is negative
+    AmountUnderflow { lhs: Amount, rhs: Amount },
+    /// amount `{amount}` does not fit in `{target}`
+    AmountNotRepresentable {
+        amount: Amount,
+        target: &'static str,
+    },
     /// invalid token
     InvalidToken,
+    /// packet data carries no token
+    EmptyTokens,
+    /// packet data carries the denomination `{denom}` more than once
+    DuplicateDenom { denom: String },
+    /// packet data carrying several tokens or forwarding hops cannot be encoded for version `{version}`
+    PacketDataNotEncodable { version: Version },
+    /// forwarding received tokens is not supported
+    ForwardingNotSupported,
+    /// no denomination trace found for hash `{hash}`
+    DenomTraceNotFound { hash: String },
+    /// total escrow of `{denom}` overflowed
+    TotalEscrowOverflow { denom: String },
+    /// total escrow of `{denom}` is lower than the unescrowed amount
+    TotalEscrowUnderflow { denom: String },
     /// expected `{expect_order}` channel, got `{got_order}`
     ChannelNotUnordered {
         expect_order: Order,
@@ -79,6 +109,54 @@ pub enum TokenTransferError {
     Other(String),
 }
 
+impl TokenTransferError {
+    /// Returns the code identifying the class of the error in the error
+    /// acknowledgements of the token transfer application:
+    /// - `1`: the host failed to process the packet,
+    /// - `2`: the packet data or acknowledgement is malformed,
+    /// - `3`: the transferred token is invalid,
+    /// - `4`: the receiver account is invalid,
+    /// - `5`: the funds are insufficient,
+    /// - `6`: transfers are disabled,
+    /// - `7`: the port or channel is invalid.
+    pub fn ack_code(&self) -> u32 {
+        match self {
+            Self::ContextError(_)
+            | Self::ForwardingNotSupported
+            | Self::TotalEscrowOverflow { .. }
+            | Self::TotalEscrowUnderflow { .. }
+            | Self::Other(_) => 1,
+            Self::PacketDataDeserialization
+            | Self::PacketDataNotEncodable { .. }
+            | Self::AckDeserialization
+            | Self::DecodeRawMsg { .. }
+            | Self::UnknownMsgType { .. }
+            | Self::Utf8Decode(_) => 2,
+            Self::EmptyBaseDenom
+            | Self::InvalidTracePortId { .. }
+            | Self::InvalidTraceChannelId { .. }
+            | Self::InvalidTraceLength { .. }
+            | Self::InvalidAmount(_)
+            | Self::AmountOverflow { .. }
+            | Self::AmountUnderflow { .. }
+            | Self::AmountNotRepresentable { .. }
+            | Self::InvalidToken
+            | Self::EmptyTokens
+            | Self::DuplicateDenom { .. }
+            | Self::DenomTraceNotFound { .. }
+            | Self::InvalidCoin { .. } => 3,
+            Self::ParseAccountFailure => 4,
+            Self::InsufficientFunds { .. } => 5,
+            Self::ReceiveDisabled { .. } | Self::SendDisabled { .. } => 6,
+            Self::InvalidIdentifier(_)
+            | Self::DestinationChannelNotFound { .. }
+            | Self::ChannelNotUnordered { .. }
+            | Self::CantCloseChannel
+            | Self::InvalidPort { .. } => 7,
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for TokenTransferError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -123,3 +201,9 @@ impl From<TokenTransferError> for StatusValue {
         StatusValue::new(err.to_string()).expect("error message must not be empty")
     }
 }
+
+impl From<TokenTransferError> for AcknowledgementResult {
+    fn from(err: TokenTransferError) -> Self {
+        AcknowledgementResult::error(err.ack_code(), err)
+    }
+}
@@ -1,18 +1,22 @@
 //! Defines all token transfer event types
-use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc_core::channel::types::acknowledgement::{AcknowledgementStatus, StatusValue};
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::utils::abci::AbciEventError;
 use ibc_core::primitives::Signer;
 use ibc_core::router::types::event::ModuleEvent;
 
 use super::Memo;
-use crate::{Amount, PrefixedDenom, MODULE_ID_STR};
+use crate::{Amount, PrefixedDenom, TransferParams, IBC_DENOM_PREFIX, MODULE_ID_STR};
 
 const EVENT_TYPE_PACKET: &str = "fungible_token_packet";
 const EVENT_TYPE_TIMEOUT: &str = "timeout";
 const EVENT_TYPE_DENOM_TRACE: &str = "denomination_trace";
 const EVENT_TYPE_TRANSFER: &str = "ibc_transfer";
+const EVENT_TYPE_PARAMS: &str = "update_transfer_params";
 
 /// Contains all events variants that can be emitted from the token transfer application
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Event {
     Recv(RecvEvent),
     Ack(AckEvent),
@@ -20,10 +24,15 @@ pub enum Event {
     Timeout(TimeoutEvent),
     DenomTrace(DenomTraceEvent),
     Transfer(TransferEvent),
+    Params(ParamsEvent),
 }
 
 /// Event emitted by the `onRecvPacket` module callback to indicate the that the
 /// `RecvPacket` message was processed
+///
+/// Upon failure, the `error` attribute details the error, which the
+/// acknowledgement only carries the code of.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RecvEvent {
     pub sender: Signer,
     pub receiver: Signer,
@@ -31,6 +40,7 @@ pub struct RecvEvent {
     pub amount: Amount,
     pub memo: Memo,
     pub success: bool,
+    pub error: Option<String>,
 }
 
 impl From<RecvEvent> for ModuleEvent {
@@ -42,8 +52,9 @@ impl From<RecvEvent> for ModuleEvent {
             amount,
             memo,
             success,
+            error,
         } = ev;
-        Self {
+        let mut ev = Self {
             kind: EVENT_TYPE_PACKET.to_string(),
             attributes: vec![
                 ("module", MODULE_ID_STR).into(),
@@ -54,11 +65,39 @@ impl From<RecvEvent> for ModuleEvent {
                 ("memo", memo).into(),
                 ("success", success).into(),
             ],
+        };
+        if let Some(error) = error {
+            ev.attributes.push(("error", error).into());
         }
+        ev
+    }
+}
+
+impl TryFrom<ModuleEvent> for RecvEvent {
+    type Error = AbciEventError;
+
+    fn try_from(ev: ModuleEvent) -> Result<Self, Self::Error> {
+        ev.expect_kind(EVENT_TYPE_PACKET)?;
+
+        Ok(Self {
+            sender: ev.parse_attribute::<String>("sender")?.into(),
+            receiver: ev.parse_attribute::<String>("receiver")?.into(),
+            denom: ev.parse_attribute("denom")?,
+            amount: ev.parse_attribute("amount")?,
+            memo: ev.parse_attribute("memo")?,
+            success: ev.parse_attribute("success")?,
+            error: ev.attribute("error").map(ToString::to_string),
+        })
     }
 }
 
 /// Event emitted in the `onAcknowledgePacket` module callback
+///
+/// As its `acknowledgement` attribute does not tell successful
+/// acknowledgements from failed ones, it cannot be decoded back from a
+/// [`ModuleEvent`], unlike the other events. The status of the
+/// acknowledgement is carried by the accompanying [`AckStatusEvent`].
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AckEvent {
     pub sender: Signer,
     pub receiver: Signer,
@@ -95,6 +134,7 @@ impl From<AckEvent> for ModuleEvent {
 
 /// Event emitted in the `onAcknowledgePacket` module callback to indicate
 /// whether the acknowledgement is a success or a failure
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AckStatusEvent {
     pub acknowledgement: AcknowledgementStatus,
 }
@@ -114,7 +154,36 @@ impl From<AckStatusEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for AckStatusEvent {
+    type Error = AbciEventError;
+
+    fn try_from(ev: ModuleEvent) -> Result<Self, Self::Error> {
+        ev.expect_kind(EVENT_TYPE_PACKET)?;
+
+        let (key, success) = if ev.attribute("success").is_some() {
+            ("success", true)
+        } else {
+            ("error", false)
+        };
+        let value: String = ev.parse_attribute(key)?;
+        let status = StatusValue::new(&value).map_err(|_| AbciEventError::InvalidAttribute {
+            kind: ev.kind.clone(),
+            key: key.to_string(),
+            value,
+        })?;
+
+        let acknowledgement = if success {
+            AcknowledgementStatus::success(status)
+        } else {
+            AcknowledgementStatus::error(status)
+        };
+
+        Ok(Self { acknowledgement })
+    }
+}
+
 /// Event emitted in the `onTimeoutPacket` module callback
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TimeoutEvent {
     pub refund_receiver: Signer,
     pub refund_denom: PrefixedDenom,
@@ -143,27 +212,66 @@ impl From<TimeoutEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for TimeoutEvent {
+    type Error = AbciEventError;
+
+    fn try_from(ev: ModuleEvent) -> Result<Self, Self::Error> {
+        ev.expect_kind(EVENT_TYPE_TIMEOUT)?;
+
+        Ok(Self {
+            refund_receiver: ev.parse_attribute::<String>("refund_receiver")?.into(),
+            refund_denom: ev.parse_attribute("refund_denom")?,
+            refund_amount: ev.parse_attribute("refund_amount")?,
+            memo: ev.parse_attribute("memo")?,
+        })
+    }
+}
+
 /// Event emitted in the `onRecvPacket` module callback when new tokens are minted
+///
+/// As in ibc-go, it carries the `ibc/{trace_hash}` denomination of the minted
+/// vouchers, whose full trace is resolved from the hash.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DenomTraceEvent {
-    pub trace_hash: Option<String>,
-    pub denom: PrefixedDenom,
+    pub trace_hash: String,
+    pub denom: String,
+}
+
+impl DenomTraceEvent {
+    /// Returns the event for the vouchers of the given hash.
+    pub fn new(trace_hash: String) -> Self {
+        Self {
+            denom: format!("{IBC_DENOM_PREFIX}/{trace_hash}"),
+            trace_hash,
+        }
+    }
 }
 
 impl From<DenomTraceEvent> for ModuleEvent {
     fn from(ev: DenomTraceEvent) -> Self {
         let DenomTraceEvent { trace_hash, denom } = ev;
-        let mut ev = Self {
+        Self {
             kind: EVENT_TYPE_DENOM_TRACE.to_string(),
-            attributes: vec![("denom", denom).into()],
-        };
-        if let Some(hash) = trace_hash {
-            ev.attributes.push(("trace_hash", hash).into());
+            attributes: vec![("trace_hash", trace_hash).into(), ("denom", denom).into()],
         }
-        ev
+    }
+}
+
+impl TryFrom<ModuleEvent> for DenomTraceEvent {
+    type Error = AbciEventError;
+
+    fn try_from(ev: ModuleEvent) -> Result<Self, Self::Error> {
+        ev.expect_kind(EVENT_TYPE_DENOM_TRACE)?;
+
+        Ok(Self {
+            trace_hash: ev.parse_attribute("trace_hash")?,
+            denom: ev.parse_attribute("denom")?,
+        })
     }
 }
 
 /// Event emitted after a successful `sendTransfer`
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TransferEvent {
     pub sender: Signer,
     pub receiver: Signer,
@@ -195,6 +303,73 @@ impl From<TransferEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for TransferEvent {
+    type Error = AbciEventError;
+
+    fn try_from(ev: ModuleEvent) -> Result<Self, Self::Error> {
+        ev.expect_kind(EVENT_TYPE_TRANSFER)?;
+
+        Ok(Self {
+            sender: ev.parse_attribute::<String>("sender")?.into(),
+            receiver: ev.parse_attribute::<String>("receiver")?.into(),
+            amount: ev.parse_attribute("amount")?,
+            denom: ev.parse_attribute("denom")?,
+            memo: ev.parse_attribute("memo")?,
+        })
+    }
+}
+
+/// Event emitted upon updating the transfer parameters of the host, or of one
+/// of its channels
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParamsEvent {
+    pub channel: Option<(PortId, ChannelId)>,
+    pub params: TransferParams,
+}
+
+impl From<ParamsEvent> for ModuleEvent {
+    fn from(ev: ParamsEvent) -> Self {
+        let ParamsEvent { channel, params } = ev;
+        let mut attributes = vec![("module", MODULE_ID_STR).into()];
+        if let Some((port_id, channel_id)) = channel {
+            attributes.push(("port_id", port_id).into());
+            attributes.push(("channel_id", channel_id).into());
+        }
+        attributes.push(("send_enabled", params.send_enabled).into());
+        attributes.push(("receive_enabled", params.receive_enabled).into());
+
+        Self {
+            kind: EVENT_TYPE_PARAMS.to_string(),
+            attributes,
+        }
+    }
+}
+
+impl TryFrom<ModuleEvent> for ParamsEvent {
+    type Error = AbciEventError;
+
+    fn try_from(ev: ModuleEvent) -> Result<Self, Self::Error> {
+        ev.expect_kind(EVENT_TYPE_PARAMS)?;
+
+        let channel = if ev.attribute("port_id").is_some() {
+            Some((
+                ev.parse_attribute("port_id")?,
+                ev.parse_attribute("channel_id")?,
+            ))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            channel,
+            params: TransferParams {
+                send_enabled: ev.parse_attribute("send_enabled")?,
+                receive_enabled: ev.parse_attribute("receive_enabled")?,
+            },
+        })
+    }
+}
+
 impl From<Event> for ModuleEvent {
     fn from(ev: Event) -> Self {
         match ev {
@@ -204,6 +379,99 @@ impl From<Event> for ModuleEvent {
             Event::Timeout(ev) => ev.into(),
             Event::DenomTrace(ev) => ev.into(),
             Event::Transfer(ev) => ev.into(),
+            Event::Params(ev) => ev.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer_event() -> TransferEvent {
+        TransferEvent {
+            sender: "sender".to_string().into(),
+            receiver: "receiver".to_string().into(),
+            amount: 100u64.into(),
+            denom: "transfer/channel-0/uatom".parse().expect("valid denom"),
+            memo: "memo".to_string().into(),
+        }
+    }
+
+    #[test]
+    fn test_module_event_round_trips() {
+        let transfer = transfer_event();
+        let ev = ModuleEvent::from(transfer.clone());
+        assert_eq!(TransferEvent::try_from(ev), Ok(transfer));
+
+        let timeout = TimeoutEvent {
+            refund_receiver: "sender".to_string().into(),
+            refund_denom: "uatom".parse().expect("valid denom"),
+            refund_amount: 7u64.into(),
+            memo: "".to_string().into(),
+        };
+        let ev = ModuleEvent::from(timeout.clone());
+        assert_eq!(TimeoutEvent::try_from(ev), Ok(timeout));
+
+        let ack_status = AckStatusEvent {
+            acknowledgement: AcknowledgementStatus::error(
+                StatusValue::new("insufficient funds").expect("non-empty"),
+            ),
+        };
+        let ev = ModuleEvent::from(ack_status.clone());
+        assert_eq!(AckStatusEvent::try_from(ev), Ok(ack_status));
+
+        let denom: PrefixedDenom = "transfer/channel-0/uatom".parse().expect("valid denom");
+        let denom_trace = DenomTraceEvent::new(denom.trace_hash());
+        assert_eq!(denom_trace.denom, denom.ibc_denom());
+        let ev = ModuleEvent::from(denom_trace.clone());
+        assert_eq!(DenomTraceEvent::try_from(ev), Ok(denom_trace));
+
+        for error in [None, Some("insufficient funds".to_string())] {
+            let recv = RecvEvent {
+                sender: "sender".to_string().into(),
+                receiver: "receiver".to_string().into(),
+                denom: denom.clone(),
+                amount: 100u64.into(),
+                memo: "memo".to_string().into(),
+                success: error.is_none(),
+                error,
+            };
+            let ev = ModuleEvent::from(recv.clone());
+            assert_eq!(RecvEvent::try_from(ev), Ok(recv));
+        }
+
+        for channel in [None, Some((PortId::transfer(), ChannelId::new(3)))] {
+            let params = ParamsEvent {
+                channel,
+                params: TransferParams {
+                    send_enabled: false,
+                    receive_enabled: true,
+                },
+            };
+            let ev = ModuleEvent::from(params.clone());
+            assert_eq!(ParamsEvent::try_from(ev), Ok(params));
         }
+
+        let ev = ModuleEvent::from(transfer_event());
+        assert!(matches!(
+            TimeoutEvent::try_from(ev),
+            Err(AbciEventError::UnexpectedEventType { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rewritten_module_event() {
+        let mut ev = ModuleEvent::from(transfer_event());
+        ev.set_attribute("memo", "rewritten");
+
+        let transfer = TransferEvent::try_from(ev.clone()).expect("valid event");
+        assert_eq!(transfer.memo, "rewritten".to_string().into());
+
+        ev.set_attribute("amount", "not-an-amount");
+        assert!(matches!(
+            TransferEvent::try_from(ev),
+            Err(AbciEventError::InvalidAttribute { .. })
+        ));
     }
 }
@@ -19,6 +19,7 @@ mod amount;
 mod coin;
 mod denom;
 mod memo;
+mod params;
 
 pub use amount::*;
 pub use coin::*;
@@ -28,6 +29,7 @@ pub mod events;
 pub mod msgs;
 pub mod packet;
 pub use memo::*;
+pub use params::*;
 /// Re-exports `U256` from `primitive-types` crate for convenience.
 pub use primitive_types::U256;
 
@@ -46,13 +48,40 @@ pub const PORT_ID_STR: &str = "transfer";
 /// ICS20 application current version.
 pub const VERSION: &str = "ics20-1";
 
+/// ICS20 application version carrying multiple tokens per packet, along with
+/// forwarding information.
+pub const VERSION_V2: &str = "ics20-2";
+
 /// The successful string used for creating an acknowledgement status,
 /// equivalent to `base64::encode(0x01)`.
 pub const ACK_SUCCESS_B64: &str = "AQ==";
 
-use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::acknowledgement::{AcknowledgementResult, StatusValue};
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::{DefaultCryptoProvider, HostCryptoProvider};
 
 /// Returns a successful acknowledgement status for the token transfer application.
 pub fn ack_success_b64() -> StatusValue {
     StatusValue::new(ACK_SUCCESS_B64).expect("ack status value is never supposed to be empty")
 }
+
+/// Returns a successful acknowledgement result for the token transfer
+/// application, encoded as `{"result":"AQ=="}`.
+pub fn ack_success_result() -> AcknowledgementResult {
+    AcknowledgementResult::success([0x01])
+}
+
+/// Returns the address of the account escrowing the tokens sent over the given
+/// channel, derived as by ibc-go: the first 20 bytes of the SHA-256 digest of
+/// the [`VERSION`], a zero byte and `{port_id}/{channel_id}`.
+///
+/// Tooling predicting the escrow accounts of ibc-go chains, such as `gaiad
+/// query ibc-transfer escrow-address`, yields the same addresses.
+pub fn escrow_address(port_id: &PortId, channel_id: &ChannelId) -> Vec<u8> {
+    let mut preimage = VERSION.as_bytes().to_vec();
+    preimage.push(0);
+    preimage.extend(format!("{port_id}/{channel_id}").as_bytes());
+
+    DefaultCryptoProvider::sha256(&preimage)[..20].to_vec()
+}
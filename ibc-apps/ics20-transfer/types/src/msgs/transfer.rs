@@ -1,19 +1,19 @@
 //! Defines the token transfer message type
 
 use ibc_core::channel::types::error::PacketError;
-use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
-use ibc_core::primitives::Timestamp;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
 use ibc_proto::Protobuf;
 
 use crate::error::TokenTransferError;
-use crate::packet::PacketData;
+use crate::packet::{PacketData, PacketDataV2};
 
-pub(crate) const TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
+/// The type URL of the token transfer message.
+pub const TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
 
 /// Message used to build an ICS20 token transfer packet.
 ///
@@ -44,15 +44,59 @@ pub struct MsgTransfer {
     /// The timeout is disabled when set to None.
     pub timeout_height_on_b: TimeoutHeight,
     /// Timeout timestamp relative to the current block timestamp.
-    /// The timeout is disabled when set to 0.
-    pub timeout_timestamp_on_b: Timestamp,
+    /// The timeout is disabled when set to `Never`.
+    pub timeout_timestamp_on_b: TimeoutTimestamp,
+}
+
+/// Message used to build an `ics20-2` token transfer packet, which may carry
+/// multiple tokens along with forwarding information.
+///
+/// It has no protobuf counterpart in `ibc-proto` yet, so hosts build it from
+/// their own messages, or from a [`MsgTransfer`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct MsgTransferV2 {
+    /// the port on which the packet will be sent
+    pub port_id_on_a: PortId,
+    /// the channel by which the packet will be sent
+    pub chan_id_on_a: ChannelId,
+    /// token transfer packet data of the packet that will be sent
+    pub packet_data: PacketDataV2,
+    /// Timeout height relative to the current block height.
+    /// The timeout is disabled when set to None.
+    pub timeout_height_on_b: TimeoutHeight,
+    /// Timeout timestamp relative to the current block timestamp.
+    /// The timeout is disabled when set to `Never`.
+    pub timeout_timestamp_on_b: TimeoutTimestamp,
+}
+
+impl From<MsgTransfer> for MsgTransferV2 {
+    fn from(msg: MsgTransfer) -> Self {
+        Self {
+            port_id_on_a: msg.port_id_on_a,
+            chan_id_on_a: msg.chan_id_on_a,
+            packet_data: msg.packet_data.into(),
+            timeout_height_on_b: msg.timeout_height_on_b,
+            timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
+        }
+    }
 }
 
 impl TryFrom<RawMsgTransfer> for MsgTransfer {
     type Error = TokenTransferError;
 
     fn try_from(raw_msg: RawMsgTransfer) -> Result<Self, Self::Error> {
-        let timeout_timestamp_on_b = Timestamp::from_nanoseconds(raw_msg.timeout_timestamp)
+        let timeout_timestamp_on_b: TimeoutTimestamp = raw_msg
+            .timeout_timestamp
+            .try_into()
             .map_err(PacketError::InvalidPacketTimestamp)
             .map_err(ContextError::from)?;
 
@@ -94,7 +138,7 @@ impl From<MsgTransfer> for RawMsgTransfer {
             sender: domain_msg.packet_data.sender.to_string(),
             receiver: domain_msg.packet_data.receiver.to_string(),
             timeout_height: domain_msg.timeout_height_on_b.into(),
-            timeout_timestamp: domain_msg.timeout_timestamp_on_b.nanoseconds(),
+            timeout_timestamp: domain_msg.timeout_timestamp_on_b.into(),
             memo: domain_msg.packet_data.memo.to_string(),
         }
     }
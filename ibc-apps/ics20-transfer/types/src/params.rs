@@ -0,0 +1,62 @@
+//! Defines the parameters of the token transfer application.
+
+use ibc_core::primitives::prelude::*;
+use ibc_proto::ibc::applications::transfer::v1::Params as RawTransferParams;
+use ibc_proto::Protobuf;
+
+/// Defines whether tokens can be sent and received by the token transfer
+/// application, either over all the channels of the host or over a single
+/// one.
+///
+/// Operators disable transfers to pause token flows, e.g. during incidents.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferParams {
+    /// Whether tokens can be sent.
+    pub send_enabled: bool,
+    /// Whether tokens can be received.
+    pub receive_enabled: bool,
+}
+
+/// Enables both sending and receiving tokens.
+impl Default for TransferParams {
+    fn default() -> Self {
+        Self {
+            send_enabled: true,
+            receive_enabled: true,
+        }
+    }
+}
+
+impl Protobuf<RawTransferParams> for TransferParams {}
+
+impl From<RawTransferParams> for TransferParams {
+    fn from(raw: RawTransferParams) -> Self {
+        Self {
+            send_enabled: raw.send_enabled,
+            receive_enabled: raw.receive_enabled,
+        }
+    }
+}
+
+impl From<TransferParams> for RawTransferParams {
+    fn from(params: TransferParams) -> Self {
+        Self {
+            send_enabled: params.send_enabled,
+            receive_enabled: params.receive_enabled,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Defines the Interchain Accounts (ICS-27) error types.
+use displaydoc::Display;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum InterchainAccountError {
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// failed to deserialize the interchain accounts metadata
+    MetadataDeserialization,
+    /// unsupported interchain accounts version `{version}`
+    UnsupportedVersion { version: String },
+    /// unsupported encoding `{encoding}`
+    UnsupportedEncoding { encoding: String },
+    /// unsupported transaction type `{tx_type}`
+    UnsupportedTxType { tx_type: String },
+    /// expected the {side} connection `{expected}` in the metadata, got `{actual}`
+    ConnectionMismatch {
+        side: &'static str,
+        expected: ConnectionId,
+        actual: ConnectionId,
+    },
+    /// invalid account address `{address}`: it must be alphanumeric and at most `{max_len}` characters long
+    InvalidAccountAddress { address: String, max_len: usize },
+    /// the host did not provide the address of the interchain account
+    MissingAccountAddress,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InterchainAccountError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::InvalidIdentifier(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<IdentifierError> for InterchainAccountError {
+    fn from(err: IdentifierError) -> Self {
+        Self::InvalidIdentifier(err)
+    }
+}
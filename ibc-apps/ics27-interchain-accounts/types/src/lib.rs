@@ -0,0 +1,34 @@
+//! Implementation of the IBC [Interchain
+//! Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+//! (ICS-27) data structures.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod metadata;
+
+pub mod error;
+pub use metadata::*;
+
+/// ICS-27 application current version.
+pub const VERSION: &str = "ics27-1";
+
+/// The port identifier that the host chains of ICS-27 applications typically
+/// bind with.
+pub const HOST_PORT_ID_STR: &str = "icahost";
+
+/// The prefix of the port identifiers that the controller chains of ICS-27
+/// applications bind with, followed by the owner of the interchain account.
+pub const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
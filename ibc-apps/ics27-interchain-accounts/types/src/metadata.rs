@@ -0,0 +1,392 @@
+//! Defines the `Metadata` negotiated as the version of interchain accounts
+//! channels.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+
+use crate::error::InterchainAccountError;
+use crate::VERSION;
+
+/// The maximum length of the interchain account addresses, as enforced by
+/// ibc-go.
+pub const MAX_ADDRESS_LENGTH: usize = 128;
+
+/// The encoding of the messages that the controller chain sends to the
+/// interchain account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    /// The messages are encoded as protobuf.
+    Proto3,
+    /// The messages are encoded as the JSON mapping of protobuf.
+    Proto3Json,
+}
+
+impl Encoding {
+    /// Returns the string identifying the encoding in the metadata.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Proto3 => "proto3",
+            Self::Proto3Json => "proto3json",
+        }
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = InterchainAccountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "proto3" => Ok(Self::Proto3),
+            "proto3json" => Ok(Self::Proto3Json),
+            _ => Err(InterchainAccountError::UnsupportedEncoding {
+                encoding: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The type of the transactions that the interchain account executes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxType {
+    /// The transactions carry any number of Cosmos SDK messages.
+    SdkMultiMsg,
+}
+
+impl TxType {
+    /// Returns the string identifying the transaction type in the metadata.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SdkMultiMsg => "sdk_multi_msg",
+        }
+    }
+}
+
+impl FromStr for TxType {
+    type Err = InterchainAccountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sdk_multi_msg" => Ok(Self::SdkMultiMsg),
+            _ => Err(InterchainAccountError::UnsupportedTxType {
+                tx_type: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for TxType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The metadata that the controller and host chains of an interchain account
+/// negotiate as the version of the channel between them.
+///
+/// It is exchanged as the JSON object that ibc-go produces, in which every
+/// field is present and the address is empty until the host sets it upon
+/// `ChanOpenTry`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "RawMetadata", into = "RawMetadata")]
+pub struct Metadata {
+    /// The version of the ICS-27 protocol, which is only checked upon
+    /// verification so that unknown versions can still be decoded.
+    pub version: String,
+    pub controller_connection_id: ConnectionId,
+    pub host_connection_id: ConnectionId,
+    /// The address of the interchain account on the host chain, if already
+    /// known.
+    pub address: Option<String>,
+    pub encoding: Encoding,
+    pub tx_type: TxType,
+}
+
+impl Metadata {
+    /// Returns the default metadata proposed by controller chains, with no
+    /// account address yet.
+    pub fn new(controller_connection_id: ConnectionId, host_connection_id: ConnectionId) -> Self {
+        Self {
+            version: VERSION.to_string(),
+            controller_connection_id,
+            host_connection_id,
+            address: None,
+            encoding: Encoding::Proto3,
+            tx_type: TxType::SdkMultiMsg,
+        }
+    }
+
+    /// Verifies the metadata in the channel callbacks of the controller
+    /// chain, upon `ChanOpenInit` and `ChanOpenAck`.
+    ///
+    /// The `connection_id` is the one the channel is built upon on the
+    /// controller chain, and `counterparty_connection_id` its counterparty on
+    /// the host chain.
+    pub fn verify_on_controller(
+        &self,
+        connection_id: &ConnectionId,
+        counterparty_connection_id: &ConnectionId,
+    ) -> Result<(), InterchainAccountError> {
+        self.verify(connection_id, counterparty_connection_id)
+    }
+
+    /// Verifies the metadata that the host chain acknowledged upon
+    /// `ChanOpenAck`, which must carry the address of the interchain account.
+    pub fn verify_on_controller_ack(
+        &self,
+        connection_id: &ConnectionId,
+        counterparty_connection_id: &ConnectionId,
+    ) -> Result<(), InterchainAccountError> {
+        if self.address.is_none() {
+            return Err(InterchainAccountError::MissingAccountAddress);
+        }
+
+        self.verify_on_controller(connection_id, counterparty_connection_id)
+    }
+
+    /// Verifies the metadata in the channel callbacks of the host chain,
+    /// upon `ChanOpenTry`.
+    ///
+    /// The `connection_id` is the one the channel is built upon on the host
+    /// chain, and `counterparty_connection_id` its counterparty on the
+    /// controller chain.
+    pub fn verify_on_host(
+        &self,
+        connection_id: &ConnectionId,
+        counterparty_connection_id: &ConnectionId,
+    ) -> Result<(), InterchainAccountError> {
+        self.verify(counterparty_connection_id, connection_id)
+    }
+
+    fn verify(
+        &self,
+        controller_connection_id: &ConnectionId,
+        host_connection_id: &ConnectionId,
+    ) -> Result<(), InterchainAccountError> {
+        if self.version != VERSION {
+            return Err(InterchainAccountError::UnsupportedVersion {
+                version: self.version.clone(),
+            });
+        }
+
+        if &self.controller_connection_id != controller_connection_id {
+            return Err(InterchainAccountError::ConnectionMismatch {
+                side: "controller",
+                expected: controller_connection_id.clone(),
+                actual: self.controller_connection_id.clone(),
+            });
+        }
+
+        if &self.host_connection_id != host_connection_id {
+            return Err(InterchainAccountError::ConnectionMismatch {
+                side: "host",
+                expected: host_connection_id.clone(),
+                actual: self.host_connection_id.clone(),
+            });
+        }
+
+        if let Some(address) = &self.address {
+            validate_account_address(address)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that the address of an interchain account is alphanumeric and at
+/// most [`MAX_ADDRESS_LENGTH`] characters long, as ibc-go does.
+pub fn validate_account_address(address: &str) -> Result<(), InterchainAccountError> {
+    if address.is_empty()
+        || address.len() > MAX_ADDRESS_LENGTH
+        || !address.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return Err(InterchainAccountError::InvalidAccountAddress {
+            address: address.to_string(),
+            max_len: MAX_ADDRESS_LENGTH,
+        });
+    }
+
+    Ok(())
+}
+
+impl FromStr for Metadata {
+    type Err = InterchainAccountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map_err(|_| InterchainAccountError::MetadataDeserialization)
+    }
+}
+
+impl Display for Metadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        let json = serde_json::to_string(self).map_err(|_| FmtError)?;
+        write!(f, "{json}")
+    }
+}
+
+/// Mirrors the JSON layout of the `Metadata` message of ibc-go, rejecting
+/// missing and unknown fields.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawMetadata {
+    version: String,
+    controller_connection_id: String,
+    host_connection_id: String,
+    address: String,
+    encoding: String,
+    tx_type: String,
+}
+
+impl TryFrom<RawMetadata> for Metadata {
+    type Error = InterchainAccountError;
+
+    fn try_from(raw: RawMetadata) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: raw.version,
+            controller_connection_id: raw.controller_connection_id.parse()?,
+            host_connection_id: raw.host_connection_id.parse()?,
+            address: (!raw.address.is_empty()).then_some(raw.address),
+            encoding: raw.encoding.parse()?,
+            tx_type: raw.tx_type.parse()?,
+        })
+    }
+}
+
+impl From<Metadata> for RawMetadata {
+    fn from(metadata: Metadata) -> Self {
+        Self {
+            version: metadata.version,
+            controller_connection_id: metadata.controller_connection_id.to_string(),
+            host_connection_id: metadata.host_connection_id.to_string(),
+            address: metadata.address.unwrap_or_default(),
+            encoding: metadata.encoding.to_string(),
+            tx_type: metadata.tx_type.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// The default metadata that ibc-go proposes for a channel over
+    /// `connection-0` on both chains, as shown in its documentation.
+    const IBC_GO_DEFAULT_METADATA: &str = r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-0","address":"","encoding":"proto3","tx_type":"sdk_multi_msg"}"#;
+
+    /// Metadata in the same layout, once the host set the account address
+    /// upon `ChanOpenTry`, on a channel using the `proto3json` encoding.
+    const IBC_GO_HOST_METADATA: &str = r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-1","address":"cosmos17dtl0mjt3t77kpuhg2edqzjpszulwhgzuj9ljs","encoding":"proto3json","tx_type":"sdk_multi_msg"}"#;
+
+    fn connection(id: u64) -> ConnectionId {
+        ConnectionId::new(id)
+    }
+
+    #[rstest]
+    #[case(IBC_GO_DEFAULT_METADATA)]
+    #[case(IBC_GO_HOST_METADATA)]
+    fn test_metadata_roundtrip(#[case] json: &str) {
+        let metadata = Metadata::from_str(json).unwrap();
+        assert_eq!(metadata.to_string(), json);
+    }
+
+    #[test]
+    fn test_default_metadata() {
+        let metadata = Metadata::new(connection(0), connection(0));
+        assert_eq!(metadata.to_string(), IBC_GO_DEFAULT_METADATA);
+        assert_eq!(metadata.address, None);
+
+        let metadata = Metadata::from_str(IBC_GO_HOST_METADATA).unwrap();
+        assert_eq!(metadata.encoding, Encoding::Proto3Json);
+        assert_eq!(
+            metadata.address.as_deref(),
+            Some("cosmos17dtl0mjt3t77kpuhg2edqzjpszulwhgzuj9ljs")
+        );
+    }
+
+    #[rstest]
+    // unknown field
+    #[case(r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-0","address":"","encoding":"proto3","tx_type":"sdk_multi_msg","extra":""}"#)]
+    // missing field
+    #[case(r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-0","encoding":"proto3","tx_type":"sdk_multi_msg"}"#)]
+    // invalid connection identifier
+    #[case(r#"{"version":"ics27-1","controller_connection_id":"channel-0","host_connection_id":"connection-0","address":"","encoding":"proto3","tx_type":"sdk_multi_msg"}"#)]
+    // unsupported encoding
+    #[case(r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-0","address":"","encoding":"amino","tx_type":"sdk_multi_msg"}"#)]
+    // unsupported transaction type
+    #[case(r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-0","address":"","encoding":"proto3","tx_type":"sdk_single_msg"}"#)]
+    // the version of ICS-20 channels
+    #[case("ics20-1")]
+    fn test_metadata_strict_deser(#[case] json: &str) {
+        assert!(Metadata::from_str(json).is_err());
+    }
+
+    #[test]
+    fn test_verify_metadata() {
+        let metadata = Metadata::from_str(IBC_GO_HOST_METADATA).unwrap();
+
+        // The controller end is built upon `connection-0`, the host one upon `connection-1`
+        metadata
+            .verify_on_controller(&connection(0), &connection(1))
+            .unwrap();
+        metadata
+            .verify_on_controller_ack(&connection(0), &connection(1))
+            .unwrap();
+        metadata
+            .verify_on_host(&connection(1), &connection(0))
+            .unwrap();
+
+        assert!(matches!(
+            metadata.verify_on_controller(&connection(1), &connection(0)),
+            Err(InterchainAccountError::ConnectionMismatch {
+                side: "controller",
+                ..
+            })
+        ));
+        assert!(matches!(
+            metadata.verify_on_host(&connection(2), &connection(0)),
+            Err(InterchainAccountError::ConnectionMismatch { side: "host", .. })
+        ));
+
+        let mut unknown_version = metadata.clone();
+        unknown_version.version = "ics27-2".to_string();
+        assert!(matches!(
+            unknown_version.verify_on_host(&connection(1), &connection(0)),
+            Err(InterchainAccountError::UnsupportedVersion { .. })
+        ));
+
+        let mut invalid_address = metadata;
+        invalid_address.address = Some("cosmos1-invalid".to_string());
+        assert!(matches!(
+            invalid_address.verify_on_host(&connection(1), &connection(0)),
+            Err(InterchainAccountError::InvalidAccountAddress { .. })
+        ));
+
+        let proposed = Metadata::from_str(IBC_GO_DEFAULT_METADATA).unwrap();
+        proposed
+            .verify_on_controller(&connection(0), &connection(0))
+            .unwrap();
+        assert!(matches!(
+            proposed.verify_on_controller_ack(&connection(0), &connection(0)),
+            Err(InterchainAccountError::MissingAccountAddress)
+        ));
+    }
+
+    #[test]
+    fn test_validate_account_address() {
+        assert!(validate_account_address("cosmos17dtl0mjt3t77kpuhg2edqzjpszulwhgzuj9ljs").is_ok());
+        assert!(validate_account_address("").is_err());
+        assert!(validate_account_address("cosmos1 address").is_err());
+        assert!(validate_account_address(&"a".repeat(MAX_ADDRESS_LENGTH)).is_ok());
+        assert!(validate_account_address(&"a".repeat(MAX_ADDRESS_LENGTH + 1)).is_err());
+    }
+}
@@ -1,11 +1,10 @@
 //! Defines the Non-Fungible Token Transfer message type
 
 use ibc_core::channel::types::error::PacketError;
-use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
-use ibc_core::primitives::Timestamp;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::applications::nft_transfer::v1::MsgTransfer as RawMsgTransfer;
 use ibc_proto::Protobuf;
@@ -44,15 +43,17 @@ pub struct MsgTransfer {
     /// The timeout is disabled when set to None.
     pub timeout_height_on_b: TimeoutHeight,
     /// Timeout timestamp relative to the current block timestamp.
-    /// The timeout is disabled when set to 0.
-    pub timeout_timestamp_on_b: Timestamp,
+    /// The timeout is disabled when set to `Never`.
+    pub timeout_timestamp_on_b: TimeoutTimestamp,
 }
 
 impl TryFrom<RawMsgTransfer> for MsgTransfer {
     type Error = NftTransferError;
 
     fn try_from(raw_msg: RawMsgTransfer) -> Result<Self, Self::Error> {
-        let timeout_timestamp_on_b = Timestamp::from_nanoseconds(raw_msg.timeout_timestamp)
+        let timeout_timestamp_on_b: TimeoutTimestamp = raw_msg
+            .timeout_timestamp
+            .try_into()
             .map_err(PacketError::InvalidPacketTimestamp)
             .map_err(ContextError::from)?;
 
@@ -108,7 +109,7 @@ impl From<MsgTransfer> for RawMsgTransfer {
             sender: domain_msg.packet_data.sender.to_string(),
             receiver: domain_msg.packet_data.receiver.to_string(),
             timeout_height: domain_msg.timeout_height_on_b.into(),
-            timeout_timestamp: domain_msg.timeout_timestamp_on_b.nanoseconds(),
+            timeout_timestamp: domain_msg.timeout_timestamp_on_b.into(),
             memo: domain_msg
                 .packet_data
                 .memo
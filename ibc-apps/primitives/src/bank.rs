@@ -0,0 +1,96 @@
+//! Defines the bank keeper through which the IBC applications move tokens on
+//! the host.
+
+use displaydoc::Display;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::{Amount, PrefixedCoin, PrefixedDenom};
+use ibc_core::primitives::prelude::*;
+
+/// Errors raised by the bank keeper of the host.
+#[derive(Debug, Display)]
+pub enum BankError {
+    /// insufficient funds: `{required}` `{denom}` required, but only `{available}` available
+    InsufficientFunds {
+        denom: PrefixedDenom,
+        required: Amount,
+        available: Amount,
+    },
+    /// the supply of `{denom}` overflowed
+    SupplyOverflow { denom: PrefixedDenom },
+    /// other error: `{description}`
+    Other { description: String },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BankError {}
+
+impl From<BankError> for TokenTransferError {
+    fn from(e: BankError) -> Self {
+        match e {
+            BankError::InsufficientFunds {
+                denom,
+                required,
+                available,
+            } => TokenTransferError::InsufficientFunds {
+                send_attempt: format!("{required}{denom}"),
+                available_funds: format!("{available}{denom}"),
+            },
+            e => TokenTransferError::Other(e.to_string()),
+        }
+    }
+}
+
+/// The money movement primitives of the host, consumed by all the IBC
+/// applications handling tokens, e.g. the escrow, mint and burn of ICS-20
+/// transfers.
+pub trait BankKeeper {
+    type AccountId;
+
+    /// Moves `coin` from the `from` account to the `to` account.
+    fn send_coins(
+        &mut self,
+        from: &Self::AccountId,
+        to: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), BankError>;
+
+    /// Creates `coin` out of thin air, crediting it to `account`.
+    fn mint_coins(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), BankError>;
+
+    /// Destroys `coin`, debiting it from `account`.
+    fn burn_coins(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), BankError>;
+
+    /// Returns the balance of `account` in the given denomination.
+    fn balance(
+        &self,
+        account: &Self::AccountId,
+        denom: &PrefixedDenom,
+    ) -> Result<Amount, BankError>;
+
+    /// Checks that `account` holds at least `coin`.
+    fn verify_sufficient_balance(
+        &self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), BankError> {
+        let available = self.balance(account, &coin.denom)?;
+
+        if available < coin.amount {
+            return Err(BankError::InsufficientFunds {
+                denom: coin.denom.clone(),
+                required: coin.amount,
+                available,
+            });
+        }
+
+        Ok(())
+    }
+}
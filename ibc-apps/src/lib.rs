@@ -11,6 +11,13 @@
     rust_2018_idioms
 )]
 
+/// Re-exports the host integration traits shared by the IBC applications, such
+/// as the bank keeper through which they move tokens.
+pub mod primitives {
+    #[doc(inline)]
+    pub use ibc_app_primitives::*;
+}
+
 /// Re-exports the implementation of the IBC [fungible token
 /// transfer](https://github.com/cosmos/ibc/blob/main/spec/app/ics-020-fungible-token-transfer/README.md)
 /// (ICS-20) application logic.
@@ -27,3 +34,12 @@ pub mod nft_transfer {
     #[cfg(feature = "nft-transfer")]
     pub use ibc_app_nft_transfer::*;
 }
+
+/// Re-exports the data structures of the IBC [Interchain
+/// Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+/// (ICS-27) application.
+pub mod ica {
+    #[doc(inline)]
+    #[cfg(feature = "ica")]
+    pub use ibc_app_ica_types::*;
+}
@@ -80,6 +80,12 @@ impl<'a, C: ClientType<'a>> ClientValidationContext for Context<'a, C> {
 
         Ok((timestamp, height))
     }
+
+    fn update_heights(&self, _client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        let heights = self.get_heights()?;
+
+        Ok(heights)
+    }
 }
 
 impl<'a, C: ClientType<'a>> ClientExecutionContext for Context<'a, C> {
@@ -163,7 +169,7 @@ impl<'a, C: ClientType<'a>> ClientExecutionContext for Context<'a, C> {
         Ok(())
     }
 
-    fn delete_update_meta(
+    fn delete_update_time(
         &mut self,
         _client_id: ClientId,
         height: Height,
@@ -174,6 +180,14 @@ impl<'a, C: ClientType<'a>> ClientExecutionContext for Context<'a, C> {
 
         self.remove(prefixed_time_key);
 
+        Ok(())
+    }
+
+    fn delete_update_height(
+        &mut self,
+        _client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
         let height_key = self.client_update_height_key(&height);
 
         let prefixed_height_key = self.prefixed_key(height_key);
@@ -1,11 +1,15 @@
-//! Implementation of the `ExtClientValidationContext` trait for the `Context`
-//! type.
+//! Implementation of the `ExtClientValidationContext` and, with the
+//! `tendermint` feature, `TmValidationContext` traits for the `Context` type.
+#[cfg(feature = "tendermint")]
+use ibc_client_tendermint::context::{ProdVerifier, TmValidationContext};
 use ibc_core::client::context::prelude::*;
 use ibc_core::client::types::error::ClientError;
 use ibc_core::client::types::Height;
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::ClientId;
 use ibc_core::host::types::path::ClientConsensusStatePath;
+#[cfg(feature = "tendermint")]
+use ibc_core::primitives::DefaultCryptoProvider;
 use ibc_core::primitives::Timestamp;
 
 use super::Context;
@@ -75,3 +79,13 @@ impl<'a, C: ClientType<'a>> ExtClientValidationContext for Context<'a, C> {
         }
     }
 }
+
+#[cfg(feature = "tendermint")]
+impl<'a, C: ClientType<'a>> TmValidationContext for Context<'a, C> {
+    type Verifier = ProdVerifier;
+    type CryptoProvider = DefaultCryptoProvider;
+
+    fn tm_verifier(&self) -> Self::Verifier {
+        ProdVerifier::default()
+    }
+}
@@ -0,0 +1,251 @@
+//! Provides a cache of the validator sets whose hash has already been checked
+//! on the update path, sparing the re-hashing of unchanged validator sets
+//! across consecutive updates.
+//!
+//! Hosts opt into it by returning a [`ValidatorSetCache`] from the
+//! [`TmValidationContext`](crate::context::TmValidationContext)
+//! implementation, and thus control for how long the cache lives.
+
+use core::ops::DerefMut;
+
+use ibc_primitives::prelude::*;
+use tendermint::crypto::Sha256;
+use tendermint::merkle::MerkleHash;
+use tendermint::validator::Set as ValidatorSet;
+use tendermint::vote::Power;
+use tendermint::{Hash, PublicKey};
+
+/// A bounded cache of validator sets, keyed by their hash.
+///
+/// Only the public keys and voting powers of the validators, i.e. the fields
+/// that go into the hash of a validator set, are kept. A validator set is
+/// hence found in the cache regardless of the proposer priorities, which
+/// change with every block.
+///
+/// Once full, the least recently used entry is evicted to make room for a new
+/// one.
+///
+/// With the `std` feature, the cache is guarded by a mutex, so that the
+/// contexts holding it can be shared across threads, e.g. by gRPC servers.
+#[derive(Debug)]
+pub struct ValidatorSetCache {
+    capacity: usize,
+    entries: Entries,
+}
+
+#[cfg(feature = "std")]
+type Entries = std::sync::Mutex<Vec<CachedValidatorSet>>;
+
+#[cfg(not(feature = "std"))]
+type Entries = core::cell::RefCell<Vec<CachedValidatorSet>>;
+
+/// The hash of a validator set, along with the public keys and voting powers
+/// it was computed from.
+#[derive(Clone, Debug)]
+struct CachedValidatorSet {
+    hash: Hash,
+    validators: Vec<(PublicKey, Power)>,
+}
+
+impl ValidatorSetCache {
+    /// Creates an empty cache holding up to `capacity` validator sets.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Entries::new(Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Gives exclusive access to the cached validator sets.
+    #[cfg(feature = "std")]
+    fn entries(&self) -> impl DerefMut<Target = Vec<CachedValidatorSet>> + '_ {
+        // the entries are left consistent by a panicking holder of the lock
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Gives exclusive access to the cached validator sets.
+    #[cfg(not(feature = "std"))]
+    fn entries(&self) -> impl DerefMut<Target = Vec<CachedValidatorSet>> + '_ {
+        self.entries.borrow_mut()
+    }
+
+    /// Checks that `validator_set` hashes to `expected_hash`.
+    ///
+    /// The hash is only computed with `H` if the validator set is not already
+    /// cached under `expected_hash`, in which case it is cached on success.
+    /// On failure, returns the hash that was computed.
+    pub fn check_hash<H>(
+        &self,
+        validator_set: &ValidatorSet,
+        expected_hash: &Hash,
+    ) -> Result<(), Hash>
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        let hashed_fields: Vec<(PublicKey, Power)> = validator_set
+            .validators()
+            .iter()
+            .map(|validator| (validator.pub_key, validator.power))
+            .collect();
+
+        let mut entries = self.entries();
+
+        if let Some(pos) = entries
+            .iter()
+            .position(|cached| &cached.hash == expected_hash && cached.validators == hashed_fields)
+        {
+            let entry = entries.remove(pos);
+            entries.push(entry);
+            return Ok(());
+        }
+
+        let hash = validator_set.hash_with::<H>();
+        if &hash != expected_hash {
+            return Err(hash);
+        }
+
+        if self.capacity > 0 {
+            if entries.len() == self.capacity {
+                entries.remove(0);
+            }
+            entries.push(CachedValidatorSet {
+                hash,
+                validators: hashed_fields,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of cached validator sets.
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+
+    /// Evicts all the cached validator sets.
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+}
+
+impl Clone for ValidatorSetCache {
+    fn clone(&self) -> Self {
+        Self {
+            capacity: self.capacity,
+            entries: Entries::new(self.entries().clone()),
+        }
+    }
+}
+
+/// Checks that `validator_set` hashes to `expected_hash`, through the cache
+/// if any.
+pub(crate) fn check_validator_set_hash<H>(
+    cache: Option<&ValidatorSetCache>,
+    validator_set: &ValidatorSet,
+    expected_hash: &Hash,
+) -> Result<(), Hash>
+where
+    H: MerkleHash + Sha256 + Default,
+{
+    match cache {
+        Some(cache) => cache.check_hash::<H>(validator_set, expected_hash),
+        None => {
+            let hash = validator_set.hash_with::<H>();
+            if &hash == expected_hash {
+                Ok(())
+            } else {
+                Err(hash)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint::crypto::default::Sha256 as DefaultSha256;
+    use tendermint_testgen::{
+        Generator, Validator as TestgenValidator, ValidatorSet as TestgenValidatorSet,
+    };
+
+    use super::*;
+
+    fn validator_set(ids: &[&str]) -> ValidatorSet {
+        TestgenValidatorSet::new(ids.to_vec()).generate().unwrap()
+    }
+
+    #[test]
+    fn caches_matching_validator_sets() {
+        let cache = ValidatorSetCache::new(2);
+        let set = validator_set(&["a", "b"]);
+        let hash = set.hash_with::<DefaultSha256>();
+
+        assert!(cache.check_hash::<DefaultSha256>(&set, &hash).is_ok());
+        assert_eq!(cache.len(), 1);
+        assert!(cache.check_hash::<DefaultSha256>(&set, &hash).is_ok());
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn rejects_mismatching_validator_sets() {
+        let cache = ValidatorSetCache::new(2);
+        let set = validator_set(&["a", "b"]);
+        let other_set = validator_set(&["c"]);
+        let hash = set.hash_with::<DefaultSha256>();
+
+        assert!(cache.check_hash::<DefaultSha256>(&set, &hash).is_ok());
+        assert_eq!(
+            cache.check_hash::<DefaultSha256>(&other_set, &hash),
+            Err(other_set.hash_with::<DefaultSha256>())
+        );
+
+        // A cached set of another power does not hash the same.
+        let repowered_set = ValidatorSet::without_proposer(vec![
+            TestgenValidator::new("a")
+                .voting_power(100)
+                .generate()
+                .unwrap(),
+            TestgenValidator::new("b").generate().unwrap(),
+        ]);
+        assert!(cache
+            .check_hash::<DefaultSha256>(&repowered_set, &hash)
+            .is_err());
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_validator_sets() {
+        let cache = ValidatorSetCache::new(2);
+        let sets = [
+            validator_set(&["a"]),
+            validator_set(&["b"]),
+            validator_set(&["c"]),
+        ];
+        let hashes: Vec<_> = sets
+            .iter()
+            .map(|set| set.hash_with::<DefaultSha256>())
+            .collect();
+
+        cache
+            .check_hash::<DefaultSha256>(&sets[0], &hashes[0])
+            .unwrap();
+        cache
+            .check_hash::<DefaultSha256>(&sets[1], &hashes[1])
+            .unwrap();
+        // Refreshes the first set, making the second one the eviction candidate.
+        cache
+            .check_hash::<DefaultSha256>(&sets[0], &hashes[0])
+            .unwrap();
+        cache
+            .check_hash::<DefaultSha256>(&sets[2], &hashes[2])
+            .unwrap();
+
+        let cached_hashes: Vec<Hash> = cache.entries().iter().map(|cached| cached.hash).collect();
+        assert_eq!(cached_hashes, vec![hashes[0], hashes[2]]);
+    }
+}
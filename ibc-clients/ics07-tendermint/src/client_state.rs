@@ -111,10 +111,7 @@ mod tests {
             latest_height: Height::new(1, 10).expect("Never fails"),
             proof_specs: ProofSpecs::cosmos(),
             upgrade_path: Vec::new(),
-            allow_update: AllowUpdate {
-                after_expiry: false,
-                after_misbehaviour: false,
-            },
+            allow_update: AllowUpdate::default(),
         };
 
         struct Test {
@@ -13,7 +13,7 @@ use ibc_core_host::types::identifiers::ClientType;
 use ibc_core_host::types::path::{Path, UpgradeClientPath};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
-use ibc_primitives::ToVec;
+use ibc_primitives::{Timestamp, ToVec};
 
 use super::ClientState;
 use crate::consensus_state::ConsensusState as TmConsensusState;
@@ -86,6 +86,13 @@ impl ClientStateCommon for ClientState {
             path,
         )
     }
+
+    fn decode_multihop_consensus_state(
+        &self,
+        consensus_state: Any,
+    ) -> Result<(CommitmentRoot, Timestamp), ClientError> {
+        decode_multihop_consensus_state(consensus_state)
+    }
 }
 
 /// Verify an `Any` consensus state by attempting to convert it to a `TmConsensusState`.
@@ -106,6 +113,32 @@ pub fn verify_consensus_state(consensus_state: Any) -> Result<(), ClientError> {
     Ok(())
 }
 
+/// Decodes a Tendermint consensus state proven on an intermediate chain of a
+/// multi-hop channel into its commitment root and timestamp.
+///
+/// Intermediate chains are expected to be Tendermint chains as well, sharing
+/// the proof specs of the client verifying their proofs.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn decode_multihop_consensus_state(
+    consensus_state: Any,
+) -> Result<(CommitmentRoot, Timestamp), ClientError> {
+    let tm_consensus_state = TmConsensusState::try_from(consensus_state)?;
+
+    if tm_consensus_state.root().is_empty() {
+        return Err(ClientError::Other {
+            description: "empty commitment root".into(),
+        });
+    };
+
+    Ok((
+        tm_consensus_state.root().clone(),
+        tm_consensus_state.timestamp().into(),
+    ))
+}
+
 /// Validate the given proof height against the client state's latest height, returning
 /// an error if the proof height is greater than the latest height of the client state.
 ///
@@ -10,10 +10,11 @@ use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
 
 use super::ClientState;
+use crate::context::TmValidationContext;
 
 impl<E> ClientStateExecution<E> for ClientState
 where
-    E: ExtClientExecutionContext,
+    E: ExtClientExecutionContext + TmValidationContext,
     E::ClientStateRef: From<ClientStateType>,
     E::ConsensusStateRef: Convertible<ConsensusStateType, ClientError>,
 {
@@ -156,35 +157,34 @@ where
     if maybe_existing_consensus_state.is_some() {
         // if we already had the header installed by a previous relayer
         // then this is a no-op.
-        //
-        // Do nothing.
-    } else {
-        let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
-        let host_height = ExtClientValidationContext::host_height(ctx)?;
-
-        let new_consensus_state = ConsensusStateType::from(header.clone());
-        let new_client_state = client_state.clone().with_header(header)?;
-
-        ctx.store_consensus_state(
-            ClientConsensusStatePath::new(
-                client_id.clone(),
-                header_height.revision_number(),
-                header_height.revision_height(),
-            ),
-            new_consensus_state.into(),
-        )?;
-        ctx.store_client_state(
-            ClientStatePath::new(client_id.clone()),
-            new_client_state.into(),
-        )?;
-        ctx.store_update_meta(
-            client_id.clone(),
-            header_height,
-            host_timestamp,
-            host_height,
-        )?;
+        return Ok(Vec::new());
     }
 
+    let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
+    let host_height = ExtClientValidationContext::host_height(ctx)?;
+
+    let new_consensus_state = ConsensusStateType::from(header.clone());
+    let new_client_state = client_state.clone().with_header(header)?;
+
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            header_height.revision_number(),
+            header_height.revision_height(),
+        ),
+        new_consensus_state.into(),
+    )?;
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        new_client_state.into(),
+    )?;
+    ctx.store_update_meta(
+        client_id.clone(),
+        header_height,
+        host_timestamp,
+        host_height,
+    )?;
+
     Ok(vec![header_height])
 }
 
@@ -4,6 +4,7 @@ use ibc_client_tendermint_types::{
 };
 use ibc_core_client::context::{Convertible, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::{MisbehaviourEvidence, MisbehaviourKind};
 use ibc_core_host::types::identifiers::{ChainId, ClientId};
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
@@ -143,18 +144,41 @@ pub fn check_for_misbehaviour_on_misbehavior(
     header_1: &Header,
     header_2: &Header,
 ) -> Result<bool, ClientError> {
-    if header_1.height() == header_2.height() {
+    Ok(misbehaviour_evidence_on_misbehaviour(header_1, header_2).is_some())
+}
+
+/// Returns the evidence of misbehaviour carried by the two headers of a
+/// misbehaviour message, if any.
+pub fn misbehaviour_evidence_on_misbehaviour(
+    header_1: &Header,
+    header_2: &Header,
+) -> Option<MisbehaviourEvidence> {
+    let hash_1 = header_1.signed_header.commit.block_id.hash;
+    let hash_2 = header_2.signed_header.commit.block_id.hash;
+
+    let kind = if header_1.height() == header_2.height() {
         // when the height of the 2 headers are equal, we only have evidence
         // of misbehaviour in the case where the headers are different
         // (otherwise, the same header was added twice in the message,
         // and this is evidence of nothing)
-        Ok(header_1.signed_header.commit.block_id.hash
-            != header_2.signed_header.commit.block_id.hash)
+        if hash_1 == hash_2 {
+            return None;
+        }
+        MisbehaviourKind::DoubleSign
     } else {
         // header_1 is at greater height than header_2, therefore
         // header_1 time must be less than or equal to
         // header_2 time in order to be valid misbehaviour (violation of
         // monotonic time).
-        Ok(header_1.signed_header.header.time <= header_2.signed_header.header.time)
-    }
+        if header_1.signed_header.header.time > header_2.signed_header.header.time {
+            return None;
+        }
+        MisbehaviourKind::TimeViolation
+    };
+
+    Some(MisbehaviourEvidence {
+        kind,
+        conflicting_heights: vec![header_1.height(), header_2.height()],
+        conflicting_hashes: vec![hash_1.as_bytes().to_vec(), hash_2.as_bytes().to_vec()],
+    })
 }
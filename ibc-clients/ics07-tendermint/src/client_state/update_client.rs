@@ -2,7 +2,7 @@ use ibc_client_tendermint_types::error::{Error, IntoResult};
 use ibc_client_tendermint_types::{ConsensusState as ConsensusStateType, Header as TmHeader};
 use ibc_core_client::context::{Convertible, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
-use ibc_core_client::types::Height;
+use ibc_core_client::types::{Height, MisbehaviourEvidence, MisbehaviourKind};
 use ibc_core_host::types::identifiers::{ChainId, ClientId};
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
@@ -12,6 +12,8 @@ use tendermint_light_client_verifier::options::Options;
 use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockState};
 use tendermint_light_client_verifier::Verifier;
 
+use crate::cache::{check_validator_set_hash, ValidatorSetCache};
+
 pub fn verify_header<V, H>(
     ctx: &V,
     header: &TmHeader,
@@ -19,6 +21,7 @@ pub fn verify_header<V, H>(
     chain_id: &ChainId,
     options: &Options,
     verifier: &impl Verifier,
+    validator_set_cache: Option<&ValidatorSetCache>,
 ) -> Result<(), ClientError>
 where
     V: ExtClientValidationContext,
@@ -26,7 +29,16 @@ where
     H: MerkleHash + Sha256 + Default,
 {
     // Checks that the header fields are valid.
-    header.validate_basic::<H>()?;
+    header.validate_heights()?;
+    check_validator_set_hash::<H>(
+        validator_set_cache,
+        &header.validator_set,
+        &header.signed_header.header.validators_hash,
+    )
+    .map_err(|validators_hash| Error::MismatchValidatorsHashes {
+        signed_header_validators_hash: header.signed_header.header.validators_hash,
+        validators_hash,
+    })?;
 
     // The tendermint-light-client crate though works on heights that are assumed
     // to have the same revision number. We ensure this here.
@@ -45,9 +57,18 @@ where
                 .consensus_state(&trusted_client_cons_state_path)?
                 .try_into()?;
 
-            header.check_trusted_next_validator_set::<H>(
+            // `header.trusted_next_validator_set` was given to us by the
+            // relayer, so it must match the hash we have stored on chain.
+            check_validator_set_hash::<H>(
+                validator_set_cache,
+                &header.trusted_next_validator_set,
                 &trusted_consensus_state.next_validators_hash,
-            )?;
+            )
+            .map_err(|_| ClientError::HeaderVerificationFailure {
+                reason:
+                    "header trusted next validator set hash does not match hash stored on chain"
+                        .to_string(),
+            })?;
 
             TrustedBlockState {
                 chain_id: &chain_id
@@ -109,6 +130,34 @@ where
     V: ExtClientValidationContext,
     V::ConsensusStateRef: Convertible<ConsensusStateType, ClientError>,
 {
+    misbehaviour_evidence_on_update(ctx, header, client_id, client_latest_height)
+        .map(|evidence| evidence.is_some())
+}
+
+/// Returns the evidence of misbehaviour carried by the header of a client
+/// update, if any.
+pub fn misbehaviour_evidence_on_update<V>(
+    ctx: &V,
+    header: TmHeader,
+    client_id: &ClientId,
+    client_latest_height: &Height,
+) -> Result<Option<MisbehaviourEvidence>, ClientError>
+where
+    V: ExtClientValidationContext,
+    V::ConsensusStateRef: Convertible<ConsensusStateType, ClientError>,
+{
+    let evidence = |kind| MisbehaviourEvidence {
+        kind,
+        conflicting_heights: vec![header.height()],
+        conflicting_hashes: vec![header
+            .signed_header
+            .commit
+            .block_id
+            .hash
+            .as_bytes()
+            .to_vec()],
+    };
+
     let maybe_existing_consensus_state = {
         let path_at_header_height = ClientConsensusStatePath::new(
             client_id.clone(),
@@ -122,11 +171,14 @@ where
     if let Some(existing_consensus_state) = maybe_existing_consensus_state {
         let existing_consensus_state = existing_consensus_state.try_into()?;
 
-        let header_consensus_state = ConsensusStateType::from(header);
+        let header_consensus_state = ConsensusStateType::from(header.clone());
 
         // There is evidence of misbehaviour if the stored consensus state
         // is different from the new one we received.
-        Ok(existing_consensus_state != header_consensus_state)
+        if existing_consensus_state != header_consensus_state {
+            return Ok(Some(evidence(MisbehaviourKind::DoubleSign)));
+        }
+        Ok(None)
     } else {
         // If no header was previously installed, we ensure the monotonicity of timestamps.
 
@@ -141,7 +193,7 @@ where
                 let prev_cs = prev_cs.try_into()?;
 
                 if header.signed_header.header().time <= prev_cs.timestamp() {
-                    return Ok(true);
+                    return Ok(Some(evidence(MisbehaviourKind::TimeViolation)));
                 }
             }
         }
@@ -157,11 +209,11 @@ where
                 let next_cs = next_cs.try_into()?;
 
                 if header.signed_header.header().time >= next_cs.timestamp() {
-                    return Ok(true);
+                    return Ok(Some(evidence(MisbehaviourKind::TimeViolation)));
                 }
             }
         }
 
-        Ok(false)
+        Ok(None)
     }
 }
@@ -1,59 +1,65 @@
+use core::time::Duration;
+
+use ibc_client_tendermint_types::error::Error;
 use ibc_client_tendermint_types::{
     ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
-    Misbehaviour as TmMisbehaviour, TENDERMINT_HEADER_TYPE_URL, TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+    Misbehaviour as TmMisbehaviour, VerificationMode, TENDERMINT_HEADER_TYPE_URL,
+    TENDERMINT_MISBEHAVIOUR_TYPE_URL,
 };
 use ibc_core_client::context::client_state::ClientStateValidation;
 use ibc_core_client::context::{Convertible, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
-use ibc_core_client::types::Status;
+use ibc_core_client::types::{MisbehaviourEvidence, Status};
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
-use tendermint::crypto::default::Sha256;
-use tendermint::crypto::Sha256 as Sha256Trait;
+use tendermint::crypto::Sha256;
 use tendermint::merkle::MerkleHash;
-use tendermint_light_client_verifier::{ProdVerifier, Verifier};
 
-use super::{check_for_misbehaviour_on_misbehavior, check_for_misbehaviour_on_update, ClientState};
+use super::{
+    check_for_misbehaviour_on_misbehavior, check_for_misbehaviour_on_update,
+    misbehaviour_evidence_on_misbehaviour, misbehaviour_evidence_on_update, ClientState,
+};
 use crate::client_state::{verify_header, verify_misbehaviour};
+use crate::context::TmValidationContext;
+use crate::crypto::HostCrypto;
 
 impl<V> ClientStateValidation<V> for ClientState
 where
-    V: ExtClientValidationContext,
+    V: TmValidationContext,
     V::ConsensusStateRef: Convertible<ConsensusStateType, ClientError>,
 {
     /// The default verification logic exposed by ibc-rs simply delegates to a
-    /// standalone `verify_client_message` function. This is to make it as
-    /// simple as possible for those who merely need the default
-    /// [`ProdVerifier`] behaviour, as well as those who require custom
-    /// verification logic.
+    /// standalone `verify_client_message` function, which verifies headers
+    /// against the verifier returned by [`TmValidationContext::tm_verifier`],
+    /// and hashes them with the [`TmValidationContext::CryptoProvider`].
     ///
     /// In a situation where the Tendermint [`ProdVerifier`] doesn't provide the
-    /// desired outcome, users should define a custom verifier struct and then
-    /// implement the [`Verifier`] trait for it.
+    /// desired outcome, users should define a custom verifier struct,
+    /// implement the [`Verifier`] trait for it, and return it from their
+    /// [`TmValidationContext`] implementation.
+    ///
+    /// Should bespoke logic be desired beyond the verifier, create a newtype
+    /// `ClientState` wrapper similar to [`ClientState`] and implement all
+    /// client state traits for it. For method implementation, the simplest way
+    /// is to import and call their analogous standalone versions under the
+    /// [`crate::client_state`] module, such as the [`verify_client_message`]
+    /// function.
     ///
-    /// In order to wire up the custom verifier, create a newtype `ClientState`
-    /// wrapper similar to [`ClientState`] and implement all client state traits
-    /// for it. For method implementation, the simplest way is to import and
-    /// call their analogous standalone versions under the
-    /// [`crate::client_state`] module, unless bespoke logic is desired for any
-    /// of those functions. Then, when it comes to implementing the
-    /// `verify_client_message` method, use the [`verify_client_message`]
-    /// function and pass your custom verifier object as the `verifier`
-    /// parameter.
+    /// [`ProdVerifier`]: crate::context::ProdVerifier
+    /// [`Verifier`]: crate::context::Verifier
     fn verify_client_message(
         &self,
         ctx: &V,
         client_id: &ClientId,
         client_message: Any,
     ) -> Result<(), ClientError> {
-        verify_client_message::<V, Sha256>(
+        verify_client_message::<V, HostCrypto<V::CryptoProvider>>(
             self.inner(),
             ctx,
             client_id,
             client_message,
-            &ProdVerifier::default(),
         )
     }
 
@@ -66,10 +72,36 @@ where
         check_for_misbehaviour(self.inner(), ctx, client_id, client_message)
     }
 
+    fn misbehaviour_evidence(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<Option<MisbehaviourEvidence>, ClientError> {
+        misbehaviour_evidence(self.inner(), ctx, client_id, client_message)
+    }
+
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
         status(self.inner(), ctx, client_id)
     }
 
+    fn is_within_expiry_grace_period(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        grace_period: Duration,
+    ) -> Result<bool, ClientError> {
+        is_within_expiry_grace_period(self.inner(), ctx, client_id, grace_period)
+    }
+
+    fn time_until_expiry(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+    ) -> Result<Option<Duration>, ClientError> {
+        time_until_expiry(self.inner(), ctx, client_id)
+    }
+
     fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
         check_substitute::<V>(self.inner(), substitute_client_state)
     }
@@ -81,31 +113,43 @@ where
 /// [`ClientStateValidation`] trait, but has been made a standalone function in
 /// order to make the ClientState APIs more flexible. It mostly adheres to the
 /// same signature as the `ClientStateValidation::verify_client_message`
-/// function, except for an additional `verifier` parameter that allows users
-/// who require custom verification logic to easily pass in their own verifier
-/// implementation.
+/// function, except for the `H` type parameter hashing the headers.
+///
+/// The verifier, the validator set cache, the trust policy and the
+/// verification mode are all read from the [`TmValidationContext`], so users
+/// who require custom verification logic plug it in through their context.
 pub fn verify_client_message<V, H>(
     client_state: &ClientStateType,
     ctx: &V,
     client_id: &ClientId,
     client_message: Any,
-    verifier: &impl Verifier,
 ) -> Result<(), ClientError>
 where
-    V: ExtClientValidationContext,
+    V: TmValidationContext,
     V::ConsensusStateRef: Convertible<ConsensusStateType, ClientError>,
-    H: MerkleHash + Sha256Trait + Default,
+    H: MerkleHash + Sha256 + Default,
 {
+    let verifier = ctx.tm_verifier();
+
     match client_message.type_url.as_str() {
         TENDERMINT_HEADER_TYPE_URL => {
             let header = TmHeader::try_from(client_message)?;
+            check_verification_mode(ctx.verification_mode(client_id), &header)?;
+
+            let mut options = client_state.as_light_client_options()?;
+            options.trust_threshold = ctx
+                .trust_policy()
+                .trust_threshold(client_id, client_state.trust_level, &header)?
+                .try_into()?;
+
             verify_header::<V, H>(
                 ctx,
                 &header,
                 client_id,
                 client_state.chain_id(),
-                &client_state.as_light_client_options()?,
-                verifier,
+                &options,
+                &verifier,
+                ctx.validator_set_cache(),
             )
         }
         TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
@@ -116,13 +160,37 @@ where
                 client_id,
                 client_state.chain_id(),
                 &client_state.as_light_client_options()?,
-                verifier,
+                &verifier,
             )
         }
         _ => Err(ClientError::InvalidUpdateClientMessage),
     }
 }
 
+/// Checks that the header complies with the verification mode of the client,
+/// i.e. that it is adjacent to its trusted height if the client only accepts
+/// sequential updates.
+///
+/// Misbehaviour submissions are exempt: their headers may skip blocks, as
+/// freezing a client never lowers its security.
+pub fn check_verification_mode(
+    verification_mode: VerificationMode,
+    header: &TmHeader,
+) -> Result<(), ClientError> {
+    let trusted_height = header.trusted_height;
+    let header_height = header.height();
+
+    if verification_mode.is_sequential() && trusted_height.increment() != header_height {
+        return Err(Error::NonAdjacentHeader {
+            trusted_height,
+            header_height,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
 /// Check for misbehaviour on the client state as part of the client state
 /// validation process.
 ///
@@ -178,6 +246,36 @@ where
     }
 }
 
+/// Returns the evidence of the misbehaviour found in the client message, as
+/// determined by the same checks as [`check_for_misbehaviour`]: conflicting
+/// headers at the same height evidence a double-sign, while non-monotonic
+/// header timestamps evidence a BFT time violation.
+pub fn misbehaviour_evidence<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<Option<MisbehaviourEvidence>, ClientError>
+where
+    V: ExtClientValidationContext,
+    V::ConsensusStateRef: Convertible<ConsensusStateType, ClientError>,
+{
+    match client_message.type_url.as_str() {
+        TENDERMINT_HEADER_TYPE_URL => {
+            let header = TmHeader::try_from(client_message)?;
+            misbehaviour_evidence_on_update(ctx, header, client_id, &client_state.latest_height)
+        }
+        TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = TmMisbehaviour::try_from(client_message)?;
+            Ok(misbehaviour_evidence_on_misbehaviour(
+                misbehaviour.header1(),
+                misbehaviour.header2(),
+            ))
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
 /// Query the status of the client state.
 ///
 /// Note that this function is typically implemented as part of the
@@ -225,6 +323,93 @@ where
     Ok(Status::Active)
 }
 
+/// Determines whether the client expired no longer than `grace_period` ago,
+/// i.e. whether its latest consensus state is within its trusting period
+/// extended by `grace_period`.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn is_within_expiry_grace_period<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    grace_period: Duration,
+) -> Result<bool, ClientError>
+where
+    V: ExtClientValidationContext,
+    V::ConsensusStateRef: Convertible<ConsensusStateType, ClientError>,
+{
+    if client_state.is_frozen() {
+        return Ok(false);
+    }
+
+    let latest_consensus_state: ConsensusStateType =
+        match ctx.consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            client_state.latest_height.revision_number(),
+            client_state.latest_height.revision_height(),
+        )) {
+            Ok(cs) => cs.try_into()?,
+            Err(_) => return Ok(false),
+        };
+
+    let now = ctx.host_timestamp()?;
+
+    let within_grace_period = now
+        .duration_since(&latest_consensus_state.timestamp().into())
+        .map_or(true, |elapsed_since_latest_consensus_state| {
+            elapsed_since_latest_consensus_state
+                <= client_state.trusting_period.saturating_add(grace_period)
+        });
+
+    Ok(within_grace_period)
+}
+
+/// Determines how long the client has left before it expires, i.e. before its
+/// latest consensus state falls out of its trusting period.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn time_until_expiry<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+) -> Result<Option<Duration>, ClientError>
+where
+    V: ExtClientValidationContext,
+    V::ConsensusStateRef: Convertible<ConsensusStateType, ClientError>,
+{
+    if client_state.is_frozen() {
+        return Ok(None);
+    }
+
+    let latest_consensus_state: ConsensusStateType =
+        match ctx.consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            client_state.latest_height.revision_number(),
+            client_state.latest_height.revision_height(),
+        )) {
+            Ok(cs) => cs.try_into()?,
+            Err(_) => return Ok(Some(Duration::ZERO)),
+        };
+
+    let now = ctx.host_timestamp()?;
+
+    // As in `status`, a latest consensus state in the future has not started
+    // to age yet.
+    let elapsed_since_latest_consensus_state = now
+        .duration_since(&latest_consensus_state.timestamp().into())
+        .unwrap_or(Duration::ZERO);
+
+    Ok(Some(
+        client_state
+            .trusting_period
+            .saturating_sub(elapsed_since_latest_consensus_state),
+    ))
+}
+
 /// Check that the subject and substitute client states match as part of
 /// the client recovery validation step.
 ///
@@ -0,0 +1,64 @@
+//! Defines the context required by the Tendermint light client on top of the
+//! ICS-02 client contexts.
+
+use ibc_client_tendermint_types::VerificationMode;
+use ibc_core_client::context::ExtClientValidationContext;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::HostCryptoProvider;
+pub use tendermint_light_client_verifier::options::Options;
+pub use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockState};
+pub use tendermint_light_client_verifier::{ProdVerifier, Verdict, Verifier};
+
+use crate::cache::ValidatorSetCache;
+use crate::trust_policy::{DefaultTrustPolicy, TrustPolicy};
+
+/// Extends the [`ExtClientValidationContext`] with the Tendermint-specific
+/// capabilities consulted by the [`ClientState`](crate::client_state::ClientState)
+/// implementation of the client traits.
+///
+/// Hosts that are content with the default light client checks can simply set
+/// the `Verifier` to [`ProdVerifier`].
+/// Those who need to swap the signature verification backend, or to relax or
+/// tighten the verification predicates, can instead plug in their own
+/// [`Verifier`] implementation, without having to fork the client state.
+///
+/// The `CryptoProvider` hashes the headers of update and misbehaviour
+/// messages. Setting the `Verifier` to a
+/// [`HostCryptoVerifier`](crate::crypto::HostCryptoVerifier) over it has it
+/// verify their signatures as well.
+pub trait TmValidationContext: ExtClientValidationContext {
+    type Verifier: Verifier;
+    type CryptoProvider: HostCryptoProvider;
+
+    /// Returns the verifier against which the headers of update and
+    /// misbehaviour messages are checked.
+    fn tm_verifier(&self) -> Self::Verifier;
+
+    /// Returns the cache of validator sets consulted when checking the
+    /// validator set hashes of update headers, if the host keeps one.
+    ///
+    /// The host owns the cache, and thus decides for how long its entries
+    /// live. No cache is used by default.
+    fn validator_set_cache(&self) -> Option<&ValidatorSetCache> {
+        None
+    }
+
+    /// Returns the policy deciding the trust threshold against which the
+    /// headers of update messages are verified.
+    ///
+    /// The [`DefaultTrustPolicy`] is used by default, verifying every header
+    /// against the trust level of the client state.
+    fn trust_policy(&self) -> &dyn TrustPolicy {
+        &DefaultTrustPolicy
+    }
+
+    /// Returns whether the headers of update messages of the given client may
+    /// skip blocks past their trusted height, or must be adjacent to it.
+    ///
+    /// Hosts with stricter security policies may require sequential headers
+    /// for some or all of their clients. [`VerificationMode::Skipping`], the
+    /// ICS-07 default, is used by default.
+    fn verification_mode(&self, _client_id: &ClientId) -> VerificationMode {
+        VerificationMode::Skipping
+    }
+}
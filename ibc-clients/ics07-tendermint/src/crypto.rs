@@ -0,0 +1,92 @@
+//! Bridges the [`HostCryptoProvider`] of the host to the crypto traits of the
+//! `tendermint` crates, so that the Tendermint light client hashes headers and
+//! verifies their signatures with the crypto supplied by the host.
+
+use core::marker::PhantomData;
+
+use ibc_primitives::prelude::*;
+use ibc_primitives::HostCryptoProvider;
+use tendermint::crypto::signature::{Error as SignatureError, Verifier as SignatureVerifier};
+use tendermint::crypto::Sha256;
+use tendermint::merkle::{Hash, MerkleHash};
+use tendermint::{PublicKey, Signature};
+use tendermint_light_client_verifier::operations::{
+    ProdCommitValidator, ProvidedVotingPowerCalculator,
+};
+use tendermint_light_client_verifier::predicates::ProdPredicates;
+use tendermint_light_client_verifier::PredicateVerifier;
+
+/// Implements the `tendermint` hashing and signature verification traits on
+/// top of the given [`HostCryptoProvider`].
+///
+/// Merkle hashing is not incremental, as host-provided hash functions
+/// typically only digest whole messages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct HostCrypto<C> {
+    _provider: PhantomData<fn() -> C>,
+}
+
+impl<C> Default for HostCrypto<C> {
+    fn default() -> Self {
+        Self {
+            _provider: PhantomData,
+        }
+    }
+}
+
+impl<C: HostCryptoProvider> Sha256 for HostCrypto<C> {
+    fn digest(data: impl AsRef<[u8]>) -> [u8; 32] {
+        C::sha256(data.as_ref())
+    }
+}
+
+impl<C: HostCryptoProvider> MerkleHash for HostCrypto<C> {
+    fn empty_hash(&mut self) -> Hash {
+        C::sha256(&[])
+    }
+
+    fn leaf_hash(&mut self, bytes: &[u8]) -> Hash {
+        C::sha256(&[&[0x00], bytes].concat())
+    }
+
+    fn inner_hash(&mut self, left: Hash, right: Hash) -> Hash {
+        C::sha256(&[[0x01].as_slice(), &left, &right].concat())
+    }
+}
+
+impl<C: HostCryptoProvider> SignatureVerifier for HostCrypto<C> {
+    fn verify(pubkey: PublicKey, msg: &[u8], signature: &Signature) -> Result<(), SignatureError> {
+        C::verify_signature(pubkey, msg, signature)
+    }
+}
+
+/// A [`ProdVerifier`](crate::context::ProdVerifier) that verifies the commit
+/// signatures with the given [`HostCryptoProvider`].
+pub type HostCryptoVerifier<C> = PredicateVerifier<
+    ProdPredicates,
+    ProvidedVotingPowerCalculator<HostCrypto<C>>,
+    ProdCommitValidator,
+>;
+
+#[cfg(test)]
+mod tests {
+    use ibc_primitives::DefaultCryptoProvider;
+    use tendermint::crypto::default::Sha256 as DefaultSha256;
+    use tendermint::merkle::simple_hash_from_byte_vectors;
+
+    use super::*;
+
+    #[test]
+    fn merkle_hash_matches_default_sha256() {
+        for leaves in [
+            vec![],
+            vec![b"a".to_vec()],
+            vec![b"a".to_vec(), b"bc".to_vec(), vec![]],
+        ] {
+            assert_eq!(
+                simple_hash_from_byte_vectors::<HostCrypto<DefaultCryptoProvider>>(&leaves),
+                simple_hash_from_byte_vectors::<DefaultSha256>(&leaves),
+            );
+        }
+    }
+}
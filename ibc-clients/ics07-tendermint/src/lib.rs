@@ -13,11 +13,20 @@
     rust_2018_idioms
 )]
 
+extern crate alloc;
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
+pub mod cache;
 pub mod client_state;
 pub mod consensus_state;
+pub mod context;
+pub mod crypto;
+pub mod signature;
+pub mod trust_policy;
+#[cfg(feature = "parallel")]
+pub mod verifier;
+mod votes;
 
 pub const TENDERMINT_CLIENT_TYPE: &str = "07-tendermint";
 
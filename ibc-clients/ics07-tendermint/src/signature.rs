@@ -0,0 +1,372 @@
+//! Selectable signature verification backends for the Tendermint light
+//! client, along with a [`VotingPowerCalculator`] that verifies the
+//! signatures of a commit as a batch.
+//!
+//! Each Ed25519 backend is enabled by the cargo feature of the same name, and
+//! comes as a [`HostCryptoProvider`] for the host to set as the
+//! `CryptoProvider` of its
+//! [`TmValidationContext`](crate::context::TmValidationContext), verifying
+//! headers with either a [`HostCryptoVerifier`](crate::crypto::HostCryptoVerifier)
+//! or a [`BatchVerifier`] over it. Hashing, as well as the signatures of
+//! other key types, are left to the [`DefaultCryptoProvider`].
+//!
+//! Note that the backends do not all agree on the validity of edge-case
+//! signatures, such as ones under small-order keys. Hosts must pick one that
+//! matches the validation rules of the counterparty chain.
+
+use core::marker::PhantomData;
+
+use ibc_primitives::prelude::*;
+use ibc_primitives::{DefaultCryptoProvider, HostCryptoProvider};
+use tendermint::block::signed_header::SignedHeader;
+use tendermint::crypto::signature::Error as SignatureError;
+use tendermint::trust_threshold::TrustThresholdFraction as TrustThreshold;
+use tendermint::validator::Set as ValidatorSet;
+use tendermint::{PublicKey, Signature};
+use tendermint_light_client_verifier::errors::VerificationError;
+use tendermint_light_client_verifier::operations::{
+    ProdCommitValidator, VotingPowerCalculator, VotingPowerTally,
+};
+use tendermint_light_client_verifier::predicates::ProdPredicates;
+use tendermint_light_client_verifier::PredicateVerifier;
+
+use crate::votes::PendingVotes;
+
+/// A signature to verify as part of a batch.
+#[derive(Clone, Debug)]
+pub struct SignatureItem<'a> {
+    pub pubkey: PublicKey,
+    pub msg: &'a [u8],
+    pub signature: &'a Signature,
+}
+
+/// A [`HostCryptoProvider`] able to verify many signatures at once, faster
+/// than one by one.
+pub trait BatchSignatureVerifier: HostCryptoProvider {
+    /// Verifies that all the given signatures are valid, without telling
+    /// which one is not upon erroring.
+    ///
+    /// Verifies the signatures one by one by default.
+    fn verify_batch(items: &[SignatureItem<'_>]) -> Result<(), SignatureError> {
+        items
+            .iter()
+            .try_for_each(|item| Self::verify_signature(item.pubkey, item.msg, item.signature))
+    }
+}
+
+impl BatchSignatureVerifier for DefaultCryptoProvider {}
+
+/// A [`ProdVerifier`](crate::context::ProdVerifier) that verifies the commit
+/// signatures as a batch with the given [`BatchSignatureVerifier`].
+pub type BatchVerifier<B> =
+    PredicateVerifier<ProdPredicates, BatchVotingPowerCalculator<B>, ProdCommitValidator>;
+
+/// A [`VotingPowerCalculator`] that verifies the signatures of a commit as a
+/// batch with the given [`BatchSignatureVerifier`].
+///
+/// It tallies the voting power exactly as the
+/// [`ProdVotingPowerCalculator`](tendermint_light_client_verifier::operations::ProdVotingPowerCalculator)
+/// does. Should the batch be invalid, its signatures are verified one by one
+/// to report the same error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BatchVotingPowerCalculator<B> {
+    _verifier: PhantomData<fn() -> B>,
+}
+
+impl<B> Default for BatchVotingPowerCalculator<B> {
+    fn default() -> Self {
+        Self {
+            _verifier: PhantomData,
+        }
+    }
+}
+
+impl<B: BatchSignatureVerifier> VotingPowerCalculator for BatchVotingPowerCalculator<B> {
+    fn voting_power_in(
+        &self,
+        signed_header: &SignedHeader,
+        validator_set: &ValidatorSet,
+        trust_threshold: TrustThreshold,
+    ) -> Result<VotingPowerTally, VerificationError> {
+        let pending_votes = PendingVotes::gather(signed_header, validator_set);
+
+        let sign_bytes: Vec<Vec<u8>> = pending_votes
+            .votes
+            .iter()
+            .map(|pending| pending.signed_vote.sign_bytes())
+            .collect();
+        let items: Vec<SignatureItem<'_>> = pending_votes
+            .votes
+            .iter()
+            .zip(&sign_bytes)
+            .map(|(pending, msg)| SignatureItem {
+                pubkey: pending.validator.pub_key,
+                msg,
+                signature: pending.signed_vote.signature(),
+            })
+            .collect();
+
+        if B::verify_batch(&items).is_err() {
+            let invalid_vote = pending_votes.votes.iter().zip(&items).find(|(_, item)| {
+                B::verify_signature(item.pubkey, item.msg, item.signature).is_err()
+            });
+
+            if let Some((invalid_vote, _)) = invalid_vote {
+                return Err(invalid_vote.invalid_signature_err());
+            }
+        }
+
+        if let Some(err) = pending_votes.malformed_vote_err {
+            return Err(err);
+        }
+
+        Ok(VotingPowerTally {
+            total: self.total_power_of(validator_set),
+            tallied: pending_votes.tallied_voting_power(),
+            trust_threshold,
+        })
+    }
+}
+
+/// Seeds the random coefficients of a batch verification with the hash of
+/// the batch itself, keeping the verification deterministic across hosts
+/// while out of reach of whoever crafts the signatures.
+#[cfg(all(
+    feature = "std",
+    any(feature = "ed25519-consensus", feature = "ed25519-zebra")
+))]
+fn batch_rng(items: &[SignatureItem<'_>]) -> rand_chacha::ChaCha20Rng {
+    use rand_chacha::rand_core::SeedableRng;
+
+    let mut transcript = Vec::new();
+    for item in items {
+        transcript.extend(item.pubkey.to_bytes());
+        transcript.extend(item.signature.as_bytes());
+        transcript.extend((item.msg.len() as u64).to_be_bytes());
+        transcript.extend(item.msg);
+    }
+
+    rand_chacha::ChaCha20Rng::from_seed(DefaultCryptoProvider::sha256(&transcript))
+}
+
+/// The [`HostCryptoProvider`] verifying Ed25519 signatures with the
+/// `ed25519-consensus` crate, following the ZIP-215 validation rules of
+/// CometBFT.
+///
+/// Verifies batches at once if the `std` feature is enabled.
+#[cfg(feature = "ed25519-consensus")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ed25519ConsensusProvider;
+
+#[cfg(feature = "ed25519-consensus")]
+impl HostCryptoProvider for Ed25519ConsensusProvider {
+    fn sha256(data: &[u8]) -> [u8; 32] {
+        DefaultCryptoProvider::sha256(data)
+    }
+
+    fn verify_signature(
+        pubkey: PublicKey,
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), SignatureError> {
+        let PublicKey::Ed25519(pubkey) = pubkey else {
+            return DefaultCryptoProvider::verify_signature(pubkey, msg, signature);
+        };
+        let pubkey = ed25519_consensus::VerificationKey::try_from(pubkey.as_bytes())
+            .map_err(|_| SignatureError::MalformedPublicKey)?;
+        let signature = ed25519_consensus::Signature::try_from(signature.as_bytes())
+            .map_err(|_| SignatureError::MalformedSignature)?;
+
+        pubkey
+            .verify(&signature, msg)
+            .map_err(|_| SignatureError::VerificationFailed)
+    }
+}
+
+#[cfg(feature = "ed25519-consensus")]
+impl BatchSignatureVerifier for Ed25519ConsensusProvider {
+    #[cfg(feature = "std")]
+    fn verify_batch(items: &[SignatureItem<'_>]) -> Result<(), SignatureError> {
+        let mut batch = ed25519_consensus::batch::Verifier::new();
+        for item in items {
+            let PublicKey::Ed25519(pubkey) = item.pubkey else {
+                DefaultCryptoProvider::verify_signature(item.pubkey, item.msg, item.signature)?;
+                continue;
+            };
+            let pubkey = ed25519_consensus::VerificationKeyBytes::try_from(pubkey.as_bytes())
+                .map_err(|_| SignatureError::MalformedPublicKey)?;
+            let signature = ed25519_consensus::Signature::try_from(item.signature.as_bytes())
+                .map_err(|_| SignatureError::MalformedSignature)?;
+            batch.queue((pubkey, signature, item.msg));
+        }
+
+        batch
+            .verify(batch_rng(items))
+            .map_err(|_| SignatureError::VerificationFailed)
+    }
+}
+
+/// The [`HostCryptoProvider`] verifying Ed25519 signatures with the
+/// `ed25519-dalek` crate.
+///
+/// Signatures are verified strictly, rejecting small-order keys and
+/// non-canonical encodings that the ZIP-215 validation rules of CometBFT
+/// accept. Batches are verified one by one.
+#[cfg(feature = "ed25519-dalek")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ed25519DalekProvider;
+
+#[cfg(feature = "ed25519-dalek")]
+impl HostCryptoProvider for Ed25519DalekProvider {
+    fn sha256(data: &[u8]) -> [u8; 32] {
+        DefaultCryptoProvider::sha256(data)
+    }
+
+    fn verify_signature(
+        pubkey: PublicKey,
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), SignatureError> {
+        let PublicKey::Ed25519(pubkey) = pubkey else {
+            return DefaultCryptoProvider::verify_signature(pubkey, msg, signature);
+        };
+        let pubkey = ed25519_dalek::VerifyingKey::try_from(pubkey.as_bytes())
+            .map_err(|_| SignatureError::MalformedPublicKey)?;
+        let signature = ed25519_dalek::Signature::from_slice(signature.as_bytes())
+            .map_err(|_| SignatureError::MalformedSignature)?;
+
+        pubkey
+            .verify_strict(msg, &signature)
+            .map_err(|_| SignatureError::VerificationFailed)
+    }
+}
+
+#[cfg(feature = "ed25519-dalek")]
+impl BatchSignatureVerifier for Ed25519DalekProvider {}
+
+/// The [`HostCryptoProvider`] verifying Ed25519 signatures with the
+/// `ed25519-zebra` crate, following the ZIP-215 validation rules of CometBFT.
+///
+/// Verifies batches at once if the `std` feature is enabled.
+#[cfg(feature = "ed25519-zebra")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ed25519ZebraProvider;
+
+#[cfg(feature = "ed25519-zebra")]
+impl HostCryptoProvider for Ed25519ZebraProvider {
+    fn sha256(data: &[u8]) -> [u8; 32] {
+        DefaultCryptoProvider::sha256(data)
+    }
+
+    fn verify_signature(
+        pubkey: PublicKey,
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), SignatureError> {
+        let PublicKey::Ed25519(pubkey) = pubkey else {
+            return DefaultCryptoProvider::verify_signature(pubkey, msg, signature);
+        };
+        let pubkey = ed25519_zebra::VerificationKey::try_from(pubkey.as_bytes())
+            .map_err(|_| SignatureError::MalformedPublicKey)?;
+        let signature = ed25519_zebra::Signature::try_from(signature.as_bytes())
+            .map_err(|_| SignatureError::MalformedSignature)?;
+
+        pubkey
+            .verify(&signature, msg)
+            .map_err(|_| SignatureError::VerificationFailed)
+    }
+}
+
+#[cfg(feature = "ed25519-zebra")]
+impl BatchSignatureVerifier for Ed25519ZebraProvider {
+    #[cfg(feature = "std")]
+    fn verify_batch(items: &[SignatureItem<'_>]) -> Result<(), SignatureError> {
+        let mut batch = ed25519_zebra::batch::Verifier::new();
+        for item in items {
+            let PublicKey::Ed25519(pubkey) = item.pubkey else {
+                DefaultCryptoProvider::verify_signature(item.pubkey, item.msg, item.signature)?;
+                continue;
+            };
+            let pubkey = ed25519_zebra::VerificationKeyBytes::try_from(pubkey.as_bytes())
+                .map_err(|_| SignatureError::MalformedPublicKey)?;
+            let signature = ed25519_zebra::Signature::try_from(item.signature.as_bytes())
+                .map_err(|_| SignatureError::MalformedSignature)?;
+            batch.queue((pubkey, signature, item.msg));
+        }
+
+        batch
+            .verify(batch_rng(items))
+            .map_err(|_| SignatureError::VerificationFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint_light_client_verifier::operations::{
+        ProdVotingPowerCalculator, ProvidedVotingPowerCalculator,
+    };
+    use tendermint_testgen::light_block::TmLightBlock;
+    use tendermint_testgen::{Generator, LightBlock};
+
+    use super::*;
+    use crate::crypto::HostCrypto;
+
+    fn assert_same_voting_power<B: BatchSignatureVerifier>(light_block: &TmLightBlock) {
+        let trust_threshold = TrustThreshold::TWO_THIRDS;
+        let voting_power_in = |calculator: &dyn VotingPowerCalculator| {
+            calculator
+                .voting_power_in(
+                    &light_block.signed_header,
+                    &light_block.validators,
+                    trust_threshold,
+                )
+                .map_err(|e| e.detail().to_string())
+        };
+
+        let expected = voting_power_in(&ProdVotingPowerCalculator::default());
+        for actual in [
+            voting_power_in(&ProvidedVotingPowerCalculator::<HostCrypto<B>>::default()),
+            voting_power_in(&BatchVotingPowerCalculator::<B>::default()),
+        ] {
+            assert_eq!(expected, actual);
+        }
+    }
+
+    fn assert_backend_matches_prod<B: BatchSignatureVerifier>() {
+        let light_block = LightBlock::new_default(10).generate().unwrap();
+        assert_same_voting_power::<B>(&light_block);
+
+        let mut testgen_lb = LightBlock::new_default(10);
+        let mut commit = testgen_lb.commit.clone().unwrap();
+        let mut votes = commit.votes.unwrap();
+        let vote = votes.pop().unwrap();
+        let header = vote.clone().header.unwrap().chain_id("bad-chain");
+        votes.push(vote.header(header));
+        commit.votes = Some(votes);
+        testgen_lb.commit = Some(commit);
+        assert_same_voting_power::<B>(&testgen_lb.generate().unwrap());
+    }
+
+    #[test]
+    fn default_provider_matches_prod() {
+        assert_backend_matches_prod::<DefaultCryptoProvider>();
+    }
+
+    #[cfg(feature = "ed25519-consensus")]
+    #[test]
+    fn ed25519_consensus_provider_matches_prod() {
+        assert_backend_matches_prod::<Ed25519ConsensusProvider>();
+    }
+
+    #[cfg(feature = "ed25519-dalek")]
+    #[test]
+    fn ed25519_dalek_provider_matches_prod() {
+        assert_backend_matches_prod::<Ed25519DalekProvider>();
+    }
+
+    #[cfg(feature = "ed25519-zebra")]
+    #[test]
+    fn ed25519_zebra_provider_matches_prod() {
+        assert_backend_matches_prod::<Ed25519ZebraProvider>();
+    }
+}
@@ -0,0 +1,137 @@
+//! Lets hosts adjust the trust threshold against which the headers of client
+//! updates are verified, instead of always using the one configured in the
+//! client state.
+//!
+//! Hosts opt into it by returning a [`TrustPolicy`] from the
+//! [`TmValidationContext`](crate::context::TmValidationContext)
+//! implementation. The [`DefaultTrustPolicy`] keeps the trust level of the
+//! client state.
+
+use ibc_client_tendermint_types::{Header as TmHeader, TrustThreshold};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_host::types::identifiers::ClientId;
+use tendermint::validator::Set as ValidatorSet;
+
+/// Decides the trust threshold against which the header of a client update is
+/// verified.
+///
+/// The trust threshold is the fraction of the voting power of the trusted
+/// validator set that must have signed the header. Policies can tighten it,
+/// e.g. for headers that change the validator set substantially, or relax it
+/// for chains the host trusts more.
+pub trait TrustPolicy {
+    /// Returns the trust threshold against which the given update header of
+    /// the client is verified, out of the `trust_level` of its client state.
+    fn trust_threshold(
+        &self,
+        client_id: &ClientId,
+        trust_level: TrustThreshold,
+        header: &TmHeader,
+    ) -> Result<TrustThreshold, ClientError>;
+}
+
+/// The policy verifying every header against the trust level of the client
+/// state.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultTrustPolicy;
+
+impl TrustPolicy for DefaultTrustPolicy {
+    fn trust_threshold(
+        &self,
+        _client_id: &ClientId,
+        trust_level: TrustThreshold,
+        _header: &TmHeader,
+    ) -> Result<TrustThreshold, ClientError> {
+        Ok(trust_level)
+    }
+}
+
+/// The policy requiring `trust_level_on_change` of the trusted voting power to
+/// sign headers whose validator set changed by more than `max_change`.
+///
+/// The change of a validator set is the share of its voting power held by
+/// validators missing from the trusted validator set. Headers changing it by
+/// at most `max_change` are verified against the trust level of the client
+/// state.
+#[derive(Clone, Copy, Debug)]
+pub struct ValidatorSetChangePolicy {
+    max_change: TrustThreshold,
+    trust_level_on_change: TrustThreshold,
+}
+
+impl ValidatorSetChangePolicy {
+    pub fn new(max_change: TrustThreshold, trust_level_on_change: TrustThreshold) -> Self {
+        Self {
+            max_change,
+            trust_level_on_change,
+        }
+    }
+
+    pub fn max_change(&self) -> TrustThreshold {
+        self.max_change
+    }
+
+    pub fn trust_level_on_change(&self) -> TrustThreshold {
+        self.trust_level_on_change
+    }
+
+    /// Returns whether the `untrusted` validator set changed by more than
+    /// `max_change` from the `trusted` one.
+    pub fn exceeds_max_change(&self, trusted: &ValidatorSet, untrusted: &ValidatorSet) -> bool {
+        let changed_power: u128 = untrusted
+            .validators()
+            .iter()
+            .filter(|validator| trusted.validator(validator.address).is_none())
+            .map(|validator| u128::from(validator.power.value()))
+            .sum();
+        let total_power = u128::from(untrusted.total_voting_power().value());
+
+        changed_power * u128::from(self.max_change.denominator())
+            > total_power * u128::from(self.max_change.numerator())
+    }
+}
+
+impl TrustPolicy for ValidatorSetChangePolicy {
+    fn trust_threshold(
+        &self,
+        _client_id: &ClientId,
+        trust_level: TrustThreshold,
+        header: &TmHeader,
+    ) -> Result<TrustThreshold, ClientError> {
+        if self.exceeds_max_change(&header.trusted_next_validator_set, &header.validator_set) {
+            Ok(self.trust_level_on_change)
+        } else {
+            Ok(trust_level)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint_testgen::{Generator, ValidatorSet as TestgenValidatorSet};
+
+    use super::*;
+
+    fn validator_set(ids: &[&str]) -> ValidatorSet {
+        TestgenValidatorSet::new(ids.to_vec()).generate().unwrap()
+    }
+
+    #[test]
+    fn measures_validator_set_change() {
+        let policy = ValidatorSetChangePolicy::new(
+            TrustThreshold::new(1, 4).unwrap(),
+            TrustThreshold::TWO_THIRDS,
+        );
+        let trusted = validator_set(&["a", "b", "c", "d"]);
+
+        // Unchanged, and one validator out of four replaced.
+        assert!(!policy.exceeds_max_change(&trusted, &trusted));
+        assert!(!policy.exceeds_max_change(&trusted, &validator_set(&["a", "b", "c", "e"])));
+
+        // Two validators out of four replaced.
+        assert!(policy.exceeds_max_change(&trusted, &validator_set(&["a", "b", "e", "f"])));
+
+        // Dropping validators changes nothing of the remaining voting power.
+        assert!(!policy.exceeds_max_change(&trusted, &validator_set(&["a"])));
+    }
+}
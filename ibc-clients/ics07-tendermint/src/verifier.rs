@@ -0,0 +1,168 @@
+//! Provides a [`VotingPowerCalculator`] that verifies the signatures of a commit
+//! in parallel, speeding up the header verification of chains with large
+//! validator sets.
+//!
+//! Wire it up by returning a [`ParallelVerifier`] from the
+//! [`TmValidationContext`](crate::context::TmValidationContext) implementation
+//! of the host.
+
+use core::marker::PhantomData;
+
+use ibc_primitives::prelude::*;
+use rayon::prelude::*;
+use tendermint::block::signed_header::SignedHeader;
+use tendermint::crypto::default::signature::Verifier as DefaultSignatureVerifier;
+use tendermint::crypto::signature;
+use tendermint::trust_threshold::TrustThresholdFraction as TrustThreshold;
+use tendermint::validator::Set as ValidatorSet;
+use tendermint_light_client_verifier::errors::VerificationError;
+use tendermint_light_client_verifier::operations::{
+    ProdCommitValidator, VotingPowerCalculator, VotingPowerTally,
+};
+use tendermint_light_client_verifier::predicates::ProdPredicates;
+use tendermint_light_client_verifier::PredicateVerifier;
+
+use crate::votes::PendingVotes;
+
+/// A [`ProdVerifier`](crate::context::ProdVerifier) that verifies the commit
+/// signatures in parallel.
+pub type ParallelVerifier = PredicateVerifier<
+    ProdPredicates,
+    ParallelVotingPowerCalculator<DefaultSignatureVerifier>,
+    ProdCommitValidator,
+>;
+
+/// A [`VotingPowerCalculator`], parameterized with the signature verification
+/// trait, that verifies the signatures of a commit in parallel.
+///
+/// It tallies the voting power exactly as the
+/// [`ProdVotingPowerCalculator`](tendermint_light_client_verifier::operations::ProdVotingPowerCalculator)
+/// does, and reports the same error should the commit be invalid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParallelVotingPowerCalculator<V> {
+    _verifier: PhantomData<fn() -> V>,
+}
+
+impl<V> Default for ParallelVotingPowerCalculator<V> {
+    fn default() -> Self {
+        Self {
+            _verifier: PhantomData,
+        }
+    }
+}
+
+impl<V: signature::Verifier> VotingPowerCalculator for ParallelVotingPowerCalculator<V> {
+    fn voting_power_in(
+        &self,
+        signed_header: &SignedHeader,
+        validator_set: &ValidatorSet,
+        trust_threshold: TrustThreshold,
+    ) -> Result<VotingPowerTally, VerificationError> {
+        let pending_votes = PendingVotes::gather(signed_header, validator_set);
+
+        let invalid_vote = pending_votes.votes.par_iter().find_first(|pending| {
+            pending
+                .validator
+                .verify_signature::<V>(
+                    &pending.signed_vote.sign_bytes(),
+                    pending.signed_vote.signature(),
+                )
+                .is_err()
+        });
+
+        if let Some(invalid_vote) = invalid_vote {
+            return Err(invalid_vote.invalid_signature_err());
+        }
+
+        if let Some(err) = pending_votes.malformed_vote_err {
+            return Err(err);
+        }
+
+        Ok(VotingPowerTally {
+            total: self.total_power_of(validator_set),
+            tallied: pending_votes.tallied_voting_power(),
+            trust_threshold,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint_light_client_verifier::operations::ProdVotingPowerCalculator;
+    use tendermint_testgen::light_block::TmLightBlock;
+    use tendermint_testgen::{Generator, LightBlock, ValidatorSet as TestgenValidatorSet};
+
+    use super::*;
+
+    fn assert_same_voting_power(light_block: &TmLightBlock) {
+        let trust_threshold = TrustThreshold::TWO_THIRDS;
+
+        let expected = ProdVotingPowerCalculator::default().voting_power_in(
+            &light_block.signed_header,
+            &light_block.validators,
+            trust_threshold,
+        );
+        let actual = ParallelVotingPowerCalculator::<DefaultSignatureVerifier>::default()
+            .voting_power_in(
+                &light_block.signed_header,
+                &light_block.validators,
+                trust_threshold,
+            );
+
+        match (expected, actual) {
+            (Ok(expected), Ok(actual)) => assert_eq!(expected, actual),
+            (Err(expected), Err(actual)) => {
+                assert_eq!(expected.detail().to_string(), actual.detail().to_string())
+            }
+            (expected, actual) => panic!("expected {expected:?}, got {actual:?}"),
+        }
+    }
+
+    #[test]
+    fn matches_prod_calculator_on_valid_commit() {
+        let light_block = LightBlock::new_default(10).generate().unwrap();
+
+        assert_same_voting_power(&light_block);
+    }
+
+    #[test]
+    fn matches_prod_calculator_on_absent_signatures() {
+        let mut light_block = LightBlock::new_default(10).generate().unwrap();
+        light_block.signed_header.commit.signatures = vec![];
+
+        assert_same_voting_power(&light_block);
+    }
+
+    #[test]
+    fn matches_prod_calculator_on_invalid_signature() {
+        let mut testgen_lb = LightBlock::new_default(10);
+        let mut commit = testgen_lb.commit.clone().unwrap();
+        let mut votes = commit.votes.unwrap();
+        let vote = votes.pop().unwrap();
+        let header = vote.clone().header.unwrap().chain_id("bad-chain");
+        votes.push(vote.header(header));
+        commit.votes = Some(votes);
+        testgen_lb.commit = Some(commit);
+
+        assert_same_voting_power(&testgen_lb.generate().unwrap());
+    }
+
+    #[test]
+    fn matches_prod_calculator_on_duplicate_validator() {
+        let mut light_block = LightBlock::new_default(10).generate().unwrap();
+        let signatures = &mut light_block.signed_header.commit.signatures;
+        signatures.push(signatures[0].clone());
+
+        assert_same_voting_power(&light_block);
+    }
+
+    #[test]
+    fn matches_prod_calculator_on_unknown_validators() {
+        let mut light_block = LightBlock::new_default(10).generate().unwrap();
+        light_block.validators = TestgenValidatorSet::new(vec!["bad-val1", "bad-val2"])
+            .generate()
+            .unwrap();
+
+        assert_same_voting_power(&light_block);
+    }
+}
@@ -0,0 +1,143 @@
+//! Gathers the votes of a commit whose signatures need verifying, shared by
+//! the [`VotingPowerCalculator`](tendermint_light_client_verifier::operations::VotingPowerCalculator)s
+//! that do not verify signatures one by one.
+
+use alloc::collections::BTreeSet;
+
+use ibc_primitives::prelude::*;
+use tendermint::account;
+use tendermint::block::signed_header::SignedHeader;
+use tendermint::block::{Commit, CommitSig};
+use tendermint::validator::{Info as ValidatorInfo, Set as ValidatorSet};
+use tendermint::vote::{SignedVote, ValidatorIndex, Vote};
+use tendermint_light_client_verifier::errors::VerificationError;
+
+/// A vote of a known validator whose signature is pending verification.
+pub(crate) struct PendingVote {
+    pub validator: ValidatorInfo,
+    pub signed_vote: SignedVote,
+    pub is_commit: bool,
+}
+
+impl PendingVote {
+    /// The error reported upon the signature of this vote being invalid.
+    pub fn invalid_signature_err(&self) -> VerificationError {
+        VerificationError::invalid_signature(
+            self.signed_vote.signature().as_bytes().to_vec(),
+            Box::new(self.validator.clone()),
+            self.signed_vote.sign_bytes(),
+        )
+    }
+}
+
+/// The votes of a commit, gathered in the order they are visited
+/// sequentially, stopping at the first malformed one.
+pub(crate) struct PendingVotes {
+    pub votes: Vec<PendingVote>,
+    /// The error of the malformed vote, only to be reported if all the
+    /// signatures preceding it are valid.
+    pub malformed_vote_err: Option<VerificationError>,
+}
+
+impl PendingVotes {
+    pub fn gather(signed_header: &SignedHeader, validator_set: &ValidatorSet) -> Self {
+        let mut seen_validators = BTreeSet::<account::Id>::new();
+        let mut votes = Vec::new();
+        let mut malformed_vote_err = None;
+
+        for (idx, commit_sig) in signed_header.commit.signatures.iter().enumerate() {
+            // Commits never hold more signatures than there are validator
+            // indices; skipping any such vote would only lower the tally.
+            let Ok(validator_index) = ValidatorIndex::try_from(idx) else {
+                break;
+            };
+
+            let Some(vote) = non_absent_vote(commit_sig, validator_index, &signed_header.commit)
+            else {
+                continue;
+            };
+
+            // Ensure we only count a validator's power once
+            if !seen_validators.insert(vote.validator_address) {
+                malformed_vote_err = Some(VerificationError::duplicate_validator(
+                    vote.validator_address,
+                ));
+                break;
+            }
+
+            let Some(validator) = validator_set.validator(vote.validator_address) else {
+                continue;
+            };
+
+            let Some(signed_vote) =
+                SignedVote::from_vote(vote, signed_header.header.chain_id.clone())
+            else {
+                malformed_vote_err = Some(VerificationError::missing_signature());
+                break;
+            };
+
+            votes.push(PendingVote {
+                validator,
+                signed_vote,
+                is_commit: commit_sig.is_commit(),
+            });
+        }
+
+        Self {
+            votes,
+            malformed_vote_err,
+        }
+    }
+
+    /// Returns the voting power of the votes for the block, once their
+    /// signatures have all been verified.
+    ///
+    /// Stray signatures (i.e. votes for nil) are verified but not tallied.
+    pub fn tallied_voting_power(&self) -> u64 {
+        self.votes
+            .iter()
+            .filter(|pending| pending.is_commit)
+            .map(|pending| pending.validator.power())
+            .sum()
+    }
+}
+
+/// Reconstructs the vote behind the given commit signature, unless the
+/// validator is absent from the commit.
+fn non_absent_vote(
+    commit_sig: &CommitSig,
+    validator_index: ValidatorIndex,
+    commit: &Commit,
+) -> Option<Vote> {
+    let (validator_address, timestamp, signature, block_id) = match commit_sig {
+        CommitSig::BlockIdFlagAbsent => return None,
+        CommitSig::BlockIdFlagCommit {
+            validator_address,
+            timestamp,
+            signature,
+        } => (
+            *validator_address,
+            *timestamp,
+            signature,
+            Some(commit.block_id),
+        ),
+        CommitSig::BlockIdFlagNil {
+            validator_address,
+            timestamp,
+            signature,
+        } => (*validator_address, *timestamp, signature, None),
+    };
+
+    Some(Vote {
+        vote_type: tendermint::vote::Type::Precommit,
+        height: commit.height,
+        round: commit.round,
+        block_id,
+        timestamp: Some(timestamp),
+        validator_address,
+        validator_index,
+        signature: signature.clone(),
+        extension: Default::default(),
+        extension_signature: None,
+    })
+}
@@ -1,7 +1,6 @@
 //! Contains the implementation of the Tendermint `ClientState` domain type.
 
 use core::cmp::max;
-use core::str::FromStr;
 use core::time::Duration;
 
 use ibc_core_client_types::error::ClientError;
@@ -24,13 +23,53 @@ use crate::trust_threshold::TrustThreshold;
 
 pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
 
+/// Legacy `allow_update_after_expiry` and `allow_update_after_misbehaviour`
+/// flags of the Tendermint client state.
+///
+/// These fields are deprecated in the ICS-07 specification and are ignored by
+/// every client handler: neither an expired nor a frozen client can be updated
+/// regardless of their values. They are only modeled so that client states
+/// created by older `ibc-go` versions survive a decode/encode round trip
+/// byte-for-byte, which matters when such states are tracked, recovered or
+/// upgraded.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct AllowUpdate {
+    /// Deprecated: preserved for encoding purposes only.
     pub after_expiry: bool,
+    /// Deprecated: preserved for encoding purposes only.
     pub after_misbehaviour: bool,
 }
 
+impl AllowUpdate {
+    /// Returns `true` if any of the deprecated flags are set, i.e. the client
+    /// state was most likely created by an older `ibc-go` version.
+    pub fn is_legacy(&self) -> bool {
+        self.after_expiry || self.after_misbehaviour
+    }
+}
+
+/// The policy by which the Tendermint client accepts new headers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum VerificationMode {
+    /// Headers may skip over any number of blocks past their trusted height,
+    /// as long as enough of the trusted validators signed them. This is the
+    /// ICS-07 default.
+    #[default]
+    Skipping,
+    /// Headers must be adjacent to their trusted height, i.e. every block is
+    /// verified in sequence against the validator set of its predecessor.
+    Sequential,
+}
+
+impl VerificationMode {
+    /// Returns `true` if headers must be adjacent to their trusted height.
+    pub fn is_sequential(&self) -> bool {
+        matches!(self, Self::Sequential)
+    }
+}
+
 /// Defines data structure for Tendermint client state.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
@@ -43,6 +82,8 @@ pub struct ClientState {
     pub latest_height: Height,
     pub proof_specs: ProofSpecs,
     pub upgrade_path: Vec<String>,
+    /// Deprecated flags, kept only for byte-compatible encoding. See
+    /// [`AllowUpdate`].
     pub allow_update: AllowUpdate,
     pub frozen_height: Option<Height>,
 }
@@ -112,6 +153,18 @@ impl ClientState {
         })
     }
 
+    /// Replaces the proof specs against which the proofs of the counterparty
+    /// are verified, e.g. for chains whose storage is not IAVL-based, and
+    /// checks that they are valid.
+    pub fn with_proof_specs(self, proof_specs: ProofSpecs) -> Result<Self, Error> {
+        proof_specs.validate()?;
+
+        Ok(Self {
+            proof_specs,
+            ..self
+        })
+    }
+
     pub fn with_frozen_height(self, h: Height) -> Self {
         Self {
             frozen_height: Some(h),
@@ -220,6 +273,13 @@ impl ClientState {
         self.frozen_height.is_some()
     }
 
+    /// Returns the deprecated `allow_update_*` flags of this client state.
+    ///
+    /// Note that these flags have no effect on the client behaviour.
+    pub fn allow_update(&self) -> AllowUpdate {
+        self.allow_update
+    }
+
     // Resets custom fields to zero values (used in `update_client`)
     pub fn zero_custom_fields(&mut self) {
         self.trusting_period = ZERO_DURATION;
@@ -237,7 +297,7 @@ impl TryFrom<RawTmClientState> for ClientState {
     type Error = Error;
 
     fn try_from(raw: RawTmClientState) -> Result<Self, Self::Error> {
-        let chain_id = ChainId::from_str(raw.chain_id.as_str())?;
+        let chain_id = ChainId::new_lenient(raw.chain_id.as_str())?;
 
         let trust_level = {
             let trust_level = raw
@@ -281,8 +341,9 @@ impl TryFrom<RawTmClientState> for ClientState {
         let frozen_height =
             Height::try_from(raw.frozen_height.ok_or(Error::MissingFrozenHeight)?).ok();
 
-        // We use set this deprecated field just so that we can properly convert
-        // it back in its raw form
+        // These deprecated fields are ignored by the client logic, but are
+        // kept so that we can properly convert the client state back in its
+        // raw form
         #[allow(deprecated)]
         let allow_update = AllowUpdate {
             after_expiry: raw.allow_update_after_expiry,
@@ -411,6 +472,8 @@ pub(crate) mod serde_tests {
 
 #[cfg(test)]
 mod tests {
+    use ibc_proto::ics23::ProofSpec as RawProofSpec;
+
     use super::*;
 
     #[derive(Clone, Debug, PartialEq)]
@@ -438,10 +501,7 @@ mod tests {
             latest_height: Height::new(0, 10).expect("Never fails"),
             proof_specs: ProofSpecs::cosmos(),
             upgrade_path: Vec::new(),
-            allow_update: AllowUpdate {
-                after_expiry: false,
-                after_misbehaviour: false,
-            },
+            allow_update: AllowUpdate::default(),
         };
 
         struct Test {
@@ -583,4 +643,87 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn custom_proof_specs() {
+        let client_state = ClientState::new(
+            ChainId::new("ibc-0").unwrap(),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128_000, 0),
+            Duration::new(3, 0),
+            Height::new(0, 10).expect("Never fails"),
+            ProofSpecs::cosmos(),
+            Vec::new(),
+            AllowUpdate::default(),
+        )
+        .expect("Never fails");
+
+        // A counterparty committing to its state through a single, non-IAVL tree
+        let mut raw_specs = Vec::<RawProofSpec>::from(ProofSpecs::cosmos());
+        let tendermint_spec = raw_specs.pop().expect("Never fails");
+
+        let custom_specs = ProofSpecs::new(vec![tendermint_spec.clone()]).expect("Never fails");
+        let client_state = client_state
+            .with_proof_specs(custom_specs.clone())
+            .expect("Never fails");
+        assert_eq!(client_state.proof_specs, custom_specs);
+        client_state.validate().expect("Never fails");
+
+        let decoded = ClientState::try_from(RawTmClientState::from(client_state.clone()))
+            .expect("Never fails");
+        assert_eq!(decoded, client_state);
+
+        let invalid_specs = ProofSpecs::try_from(vec![RawProofSpec {
+            inner_spec: None,
+            ..tendermint_spec
+        }])
+        .expect("Never fails");
+        assert!(client_state.with_proof_specs(invalid_specs).is_err());
+    }
+
+    #[test]
+    fn legacy_allow_update_roundtrip() {
+        let client_state = ClientState::new(
+            ChainId::new("ibc-0").unwrap(),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128_000, 0),
+            Duration::new(3, 0),
+            Height::new(0, 10).expect("Never fails"),
+            ProofSpecs::cosmos(),
+            Vec::new(),
+            AllowUpdate::default(),
+        )
+        .expect("Never fails");
+
+        for (after_expiry, after_misbehaviour) in
+            [(false, false), (true, false), (false, true), (true, true)]
+        {
+            let allow_update = AllowUpdate {
+                after_expiry,
+                after_misbehaviour,
+            };
+
+            let mut raw = RawTmClientState::from(client_state.clone());
+            #[allow(deprecated)]
+            {
+                raw.allow_update_after_expiry = after_expiry;
+                raw.allow_update_after_misbehaviour = after_misbehaviour;
+            }
+            let raw_bytes = Protobuf::<RawTmClientState>::encode_vec(client_state.clone());
+
+            let decoded = ClientState::try_from(raw.clone()).expect("Never fails");
+            assert_eq!(decoded.allow_update(), allow_update);
+            assert_eq!(decoded.allow_update().is_legacy(), allow_update.is_legacy());
+            assert_eq!(RawTmClientState::from(decoded.clone()), raw);
+
+            // The legacy flags must not affect the encoding of everything else.
+            let reset = ClientState {
+                allow_update: AllowUpdate::default(),
+                ..decoded
+            };
+            assert_eq!(Protobuf::<RawTmClientState>::encode_vec(reset), raw_bytes);
+        }
+    }
 }
@@ -89,6 +89,11 @@ pub enum Error {
         duration_since_consensus_state: Duration,
         trusting_period: Duration,
     },
+    /// header at height `{header_height}` is not adjacent to its trusted height `{trusted_height}`, as required by the sequential verification mode of the client
+    NonAdjacentHeader {
+        trusted_height: Height,
+        header_height: Height,
+    },
     /// headers block hashes are equal
     MisbehaviourHeadersBlockHashesEqual,
     /// headers are not at same height and are monotonically increasing
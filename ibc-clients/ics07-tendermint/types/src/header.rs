@@ -1,7 +1,6 @@
 //! Defines the domain type for tendermint headers
 
 use core::fmt::{Display, Error as FmtError, Formatter};
-use core::str::FromStr;
 
 use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::Height;
@@ -53,7 +52,7 @@ impl Header {
 
     pub fn height(&self) -> Height {
         Height::new(
-            ChainId::from_str(self.signed_header.header.chain_id.as_str())
+            ChainId::new_lenient(self.signed_header.header.chain_id.as_str())
                 .expect("chain id")
                 .revision_number(),
             u64::from(self.signed_header.header.height),
@@ -120,6 +119,24 @@ impl Header {
 
     /// Checks if the fields of a given header are consistent with the trusted fields of this header.
     pub fn validate_basic<H: MerkleHash + Sha256 + Default>(&self) -> Result<(), Error> {
+        self.validate_heights()?;
+
+        let validators_hash = self.validator_set.hash_with::<H>();
+
+        if validators_hash != self.signed_header.header.validators_hash {
+            return Err(Error::MismatchValidatorsHashes {
+                signed_header_validators_hash: self.signed_header.header.validators_hash,
+                validators_hash,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the header height is above its trusted height, within the
+    /// same revision. Part of [`Header::validate_basic`], for callers that
+    /// check the validator set hash on their own.
+    pub fn validate_heights(&self) -> Result<(), Error> {
         if self.height().revision_number() != self.trusted_height.revision_number() {
             return Err(Error::MismatchHeightRevisions {
                 trusted_revision: self.trusted_height.revision_number(),
@@ -137,15 +154,6 @@ impl Header {
             });
         }
 
-        let validators_hash = self.validator_set.hash_with::<H>();
-
-        if validators_hash != self.signed_header.header.validators_hash {
-            return Err(Error::MismatchValidatorsHashes {
-                signed_header_validators_hash: self.signed_header.header.validators_hash,
-                validators_hash,
-            });
-        }
-
         Ok(())
     }
 }
@@ -1,13 +1,16 @@
 //! Defines the misbehaviour type for the tendermint light client
 
 use ibc_core_client_types::error::ClientError;
-use ibc_core_host_types::identifiers::ClientId;
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::{ChainId, ClientId};
 use ibc_primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawMisbehaviour;
 use ibc_proto::Protobuf;
 use tendermint::crypto::Sha256;
+use tendermint::evidence::LightClientAttackEvidence;
 use tendermint::merkle::MerkleHash;
+use tendermint::validator::Set as ValidatorSet;
 
 use crate::error::Error;
 use crate::header::Header;
@@ -32,6 +35,69 @@ impl Misbehaviour {
         }
     }
 
+    /// Constructs a misbehaviour out of two conflicting headers of the same
+    /// chain, in whichever order they are given.
+    ///
+    /// The headers are ordered such that `header1` is the one at the greater
+    /// (or equal) height, as expected by [`Misbehaviour::validate_basic`].
+    pub fn from_conflicting_headers(
+        client_id: ClientId,
+        header_a: Header,
+        header_b: Header,
+    ) -> Self {
+        if header_a.height() >= header_b.height() {
+            Self::new(client_id, header_a, header_b)
+        } else {
+            Self::new(client_id, header_b, header_a)
+        }
+    }
+
+    /// Constructs a misbehaviour out of a Tendermint
+    /// [`LightClientAttackEvidence`] and the header of the honest chain at the
+    /// height of the conflicting block.
+    ///
+    /// The conflicting block of the evidence is turned into a header trusting
+    /// the client at the evidence's common height, against the given
+    /// `trusted_next_validator_set`.
+    pub fn from_light_client_attack_evidence(
+        client_id: ClientId,
+        evidence: LightClientAttackEvidence,
+        trusted_next_validator_set: ValidatorSet,
+        honest_header: Header,
+    ) -> Result<Self, Error> {
+        let conflicting_block = evidence.conflicting_block;
+
+        let chain_id = &conflicting_block.signed_header.header.chain_id;
+
+        if chain_id != &honest_header.signed_header.header.chain_id {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "evidence and honest header must have identical chain_ids".to_owned(),
+            });
+        }
+
+        let revision_number = ChainId::new_lenient(chain_id.as_str())?.revision_number();
+
+        let trusted_height =
+            Height::new(revision_number, evidence.common_height.value()).map_err(|_| {
+                Error::InvalidHeaderHeight {
+                    height: evidence.common_height.value(),
+                }
+            })?;
+
+        let conflicting_header = Header {
+            signed_header: conflicting_block.signed_header,
+            validator_set: conflicting_block.validator_set,
+            trusted_height,
+            trusted_next_validator_set,
+        };
+
+        Ok(Self::from_conflicting_headers(
+            client_id,
+            conflicting_header,
+            honest_header,
+        ))
+    }
+
     pub fn client_id(&self) -> &ClientId {
         &self.client_id
     }
@@ -1,7 +1,9 @@
 //! Defines `ClientState`, the core type to be implemented by light clients
 
+use core::time::Duration;
+
 use ibc_core_client_types::error::ClientError;
-use ibc_core_client_types::{Height, Status};
+use ibc_core_client_types::{Height, MisbehaviourEvidence, Status};
 use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
@@ -9,6 +11,7 @@ use ibc_core_host_types::identifiers::{ClientId, ClientType};
 use ibc_core_host_types::path::Path;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
 
 /// Convenient trait to decode a client state from an `Any` type and obtain a
 /// handle to the local instance of `ClientState`.
@@ -75,6 +78,26 @@ pub trait ClientStateCommon: ClientStateDecoder {
         root: &CommitmentRoot,
         path: Path,
     ) -> Result<(), ClientError>;
+
+    /// Decodes a consensus state of the kind of chains this client tracks, as
+    /// proven to be stored on an intermediate chain of a multi-hop channel,
+    /// into its commitment root and timestamp.
+    ///
+    /// The proofs of the intermediate chains are verified by this client, so
+    /// it should only accept consensus states it knows how to verify proofs
+    /// against. Clients not supporting multi-hop channels keep the default,
+    /// which rejects any consensus state.
+    fn decode_multihop_consensus_state(
+        &self,
+        _consensus_state: Any,
+    ) -> Result<(CommitmentRoot, Timestamp), ClientError> {
+        Err(ClientError::Other {
+            description: format!(
+                "client type {} does not support multi-hop channels",
+                self.client_type()
+            ),
+        })
+    }
 }
 
 /// `ClientState` methods which require access to the client's validation
@@ -122,9 +145,54 @@ where
         client_message: Any,
     ) -> Result<bool, ClientError>;
 
+    /// Returns the evidence of the misbehaviour found in the client_message,
+    /// which the handler emits along with freezing the client. It assumes
+    /// `check_for_misbehaviour` found misbehaviour in the client_message.
+    ///
+    /// Returns `None` by default, for light clients that do not provide
+    /// structured evidence.
+    fn misbehaviour_evidence(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<Option<MisbehaviourEvidence>, ClientError> {
+        Ok(None)
+    }
+
     /// Returns the status of the client. Only Active clients are allowed to process packets.
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError>;
 
+    /// Returns whether the client, found `Expired` by [`status`](Self::status),
+    /// expired no longer than `grace_period` ago, in which case hosts opting
+    /// into an expiry grace period still let it verify the proofs of packet
+    /// messages.
+    ///
+    /// Returns `false` by default, for light clients that cannot tell when
+    /// they expired.
+    fn is_within_expiry_grace_period(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _grace_period: Duration,
+    ) -> Result<bool, ClientError> {
+        Ok(false)
+    }
+
+    /// Returns how long the client, found `Active` by [`status`](Self::status),
+    /// has left before it expires, i.e. before its latest consensus state
+    /// falls out of its trusting period.
+    ///
+    /// Returns `None` by default, for light clients that cannot tell when
+    /// they expire.
+    fn time_until_expiry(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+    ) -> Result<Option<Duration>, ClientError> {
+        Ok(None)
+    }
+
     /// Verifies whether the calling (subject) client state matches the substitute
     /// client state for the purposes of client recovery.
     ///
@@ -168,8 +236,8 @@ where
     ///
     /// Note that `header` is the field associated with `UpdateKind::UpdateClient`.
     ///
-    /// Post-condition: on success, the return value MUST contain at least one
-    /// height.
+    /// If the header had already been installed by a previous update, the
+    /// update MUST be a no-op and an empty list of heights is returned.
     fn update_state(
         &self,
         ctx: &mut E,
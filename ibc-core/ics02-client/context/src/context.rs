@@ -21,6 +21,21 @@ pub trait ClientValidationContext: Sized {
     /// Note: Clients have the responsibility to store client states on client creation and update.
     fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, ContextError>;
 
+    /// Returns the ClientState for the given identifier `client_id`, borrowed
+    /// from the host store when possible.
+    ///
+    /// Defaults to the owned [`client_state`](Self::client_state), in the same
+    /// way as the connection and channel end getters of the host context.
+    fn client_state_cow(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Cow<'_, Self::ClientStateRef>, ContextError>
+    where
+        Self::ClientStateRef: Clone,
+    {
+        self.client_state(client_id).map(Cow::Owned)
+    }
+
     /// Retrieve the consensus state for the given client ID at the specified
     /// height.
     ///
@@ -39,6 +54,13 @@ pub trait ClientValidationContext: Sized {
         client_id: &ClientId,
         height: &Height,
     ) -> Result<(Timestamp, Height), ContextError>;
+
+    /// Returns, in ascending order, all the heights at which the client was
+    /// updated and whose update metadata is still stored.
+    ///
+    /// Heights must be tracked by [`ClientExecutionContext::store_update_meta`]
+    /// and pruned by [`ClientExecutionContext::delete_update_meta`].
+    fn update_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
 }
 
 /// Defines the methods that all client `ExecutionContext`s (precisely the
@@ -90,18 +112,53 @@ pub trait ClientExecutionContext:
         host_height: Height,
     ) -> Result<(), ContextError>;
 
-    /// Delete the update time and height associated with the client at the
-    /// specified height.
+    /// Delete the update time associated with the client at the specified
+    /// height.
     ///
     /// This update time should be associated with a consensus state through the
     /// specified height.
     ///
     /// Note that this timestamp is determined by the host.
-    fn delete_update_meta(
+    fn delete_update_time(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError>;
+
+    /// Delete the update height associated with the client at the specified
+    /// height.
+    ///
+    /// Once deleted, the height must no longer be reported by
+    /// [`ClientValidationContext::update_heights`].
+    fn delete_update_height(
         &mut self,
         client_id: ClientId,
         height: Height,
     ) -> Result<(), ContextError>;
+
+    /// Delete the update time and height associated with the client at the
+    /// specified height.
+    fn delete_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.delete_update_time(client_id.clone(), height)?;
+        self.delete_update_height(client_id, height)
+    }
+
+    /// Delete the update time and height associated with the client at all
+    /// the heights returned by [`ClientValidationContext::update_heights`].
+    ///
+    /// Hosts may use this to clean up the metadata of a client that is no
+    /// longer in use, e.g. once it has been recovered.
+    fn delete_all_update_meta(&mut self, client_id: &ClientId) -> Result<(), ContextError> {
+        for height in self.update_heights(client_id)? {
+            self.delete_update_meta(client_id.clone(), height)?;
+        }
+
+        Ok(())
+    }
 }
 
 /// An optional trait that extends the client validation context capabilities by
@@ -120,17 +177,20 @@ pub trait ExtClientValidationContext: ClientValidationContext {
     /// Returns the current height of the local chain.
     fn host_height(&self) -> Result<Height, ContextError>;
 
-    /// Returns all the heights at which a consensus state is stored.
+    /// Returns all the heights at which a consensus state is stored, in
+    /// ascending order.
+    ///
+    /// Used by light clients to prune expired consensus states.
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
 
-    /// Search for the lowest consensus state higher than `height`.
+    /// Search for the lowest consensus state strictly higher than `height`.
     fn next_consensus_state(
         &self,
         client_id: &ClientId,
         height: &Height,
     ) -> Result<Option<Self::ConsensusStateRef>, ContextError>;
 
-    /// Search for the highest consensus state lower than `height`.
+    /// Search for the highest consensus state strictly lower than `height`.
     fn prev_consensus_state(
         &self,
         client_id: &ClientId,
@@ -0,0 +1,58 @@
+//! Host-invoked monitoring of the expiry of clients, warning operators ahead
+//! of the clients expiring along with the channels relying on them.
+
+use core::time::Duration;
+
+use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::events::{ClientExpired, ClientExpiring};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::HostExecutionContext;
+
+/// Emits a [`ClientExpiring`] event for each of the given clients expiring
+/// within `warning_period`, and a [`ClientExpired`] event for each of them
+/// that already expired.
+///
+/// Frozen clients, as well as light clients that cannot tell when they expire,
+/// are skipped. Hosts typically call this once per block, or every few blocks,
+/// with the clients they want to monitor, and will hence keep emitting the
+/// events until the clients are updated or recovered.
+pub fn check_client_expirations<Ctx>(
+    ctx: &mut Ctx,
+    client_ids: &[ClientId],
+    warning_period: Duration,
+) -> Result<(), ContextError>
+where
+    Ctx: HostExecutionContext,
+{
+    for client_id in client_ids {
+        let client_val_ctx = ctx.get_client_validation_context();
+        let client_state = client_val_ctx.client_state(client_id)?;
+        let status = client_state.status(client_val_ctx, client_id)?;
+
+        let event = if status.is_expired() {
+            IbcEvent::ClientExpired(ClientExpired::new(
+                client_id.clone(),
+                client_state.client_type(),
+            ))
+        } else if status.is_active() {
+            match client_state.time_until_expiry(client_val_ctx, client_id)? {
+                Some(time_until_expiry) if time_until_expiry <= warning_period => {
+                    IbcEvent::ClientExpiring(ClientExpiring::new(
+                        client_id.clone(),
+                        client_state.client_type(),
+                        time_until_expiry,
+                    ))
+                }
+                _ => continue,
+            }
+        } else {
+            continue;
+        };
+
+        ctx.emit_ibc_event(event)?;
+    }
+
+    Ok(())
+}
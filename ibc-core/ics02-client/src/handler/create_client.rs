@@ -5,13 +5,15 @@ use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::events::CreateClient;
 use ibc_core_client_types::msgs::MsgCreateClient;
 use ibc_core_handler_types::error::ContextError;
-use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::{ClientStateMut, ClientStateRef, ExecutionContext, ValidationContext};
+use ibc_core_handler_types::events::{CapReached, CapScope, IbcEvent, MessageEvent};
+use ibc_core_host::{
+    ClientRegistryExecutionContext, ClientRegistryValidationContext, ClientStateMut, ClientStateRef,
+};
 use ibc_primitives::prelude::*;
 
 pub fn validate<Ctx>(ctx: &Ctx, msg: MsgCreateClient) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ClientRegistryValidationContext,
 {
     let MsgCreateClient {
         client_state,
@@ -24,11 +26,23 @@ where
     // Construct this client's identifier
     let id_counter = ctx.client_counter()?;
 
+    if let Some(max_clients) = ctx.host_limits()?.max_clients {
+        if id_counter >= max_clients {
+            return Err(ClientError::MaxClientsReached { max_clients }.into());
+        }
+    }
+
     let client_val_ctx = ctx.get_client_validation_context();
 
     let client_state = ClientStateRef::<Ctx>::try_from(client_state)?;
 
-    let client_id = client_state.client_type().build_client_id(id_counter);
+    let client_type = client_state.client_type();
+
+    if !ctx.client_params()?.is_allowed_client(&client_type) {
+        return Err(ClientError::ClientTypeNotAllowed { client_type }.into());
+    }
+
+    let client_id = client_type.build_client_id(id_counter);
 
     let status = client_state.status(client_val_ctx, &client_id)?;
 
@@ -50,7 +64,7 @@ where
 
 pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgCreateClient) -> Result<(), ContextError>
 where
-    Ctx: ExecutionContext,
+    Ctx: ClientRegistryExecutionContext,
 {
     let MsgCreateClient {
         client_state,
@@ -71,6 +85,7 @@ where
     client_state.initialise(client_exec_ctx, &client_id, consensus_state)?;
 
     ctx.increase_client_counter()?;
+    let client_counter = ctx.client_counter()?;
 
     let event = IbcEvent::CreateClient(CreateClient::new(
         client_id.clone(),
@@ -80,6 +95,12 @@ where
     ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
     ctx.emit_ibc_event(event)?;
 
+    if let Some(max_clients) = ctx.host_limits()?.max_clients {
+        if client_counter >= max_clients {
+            ctx.emit_ibc_event(CapReached::new(CapScope::Clients, max_clients).into())?;
+        }
+    }
+
     ctx.log_message(format!(
         "success: generated new client identifier: {client_id}"
     ))?;
@@ -2,5 +2,7 @@
 
 pub mod create_client;
 pub mod recover_client;
+pub mod submit_evidence;
 pub mod update_client;
+pub mod update_client_params;
 pub mod upgrade_client;
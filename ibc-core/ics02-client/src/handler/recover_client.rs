@@ -4,7 +4,7 @@ use ibc_core_client_context::prelude::*;
 use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::msgs::MsgRecoverClient;
 use ibc_core_handler_types::error::ContextError;
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{HostExecutionContext, HostValidationContext};
 
 /// Performs the validation steps associated with the client recovery process. This
 /// includes validating that the parameters of the subject and substitute clients match,
@@ -12,7 +12,7 @@ use ibc_core_host::{ExecutionContext, ValidationContext};
 /// client is *not* active.
 pub fn validate<Ctx>(ctx: &Ctx, msg: MsgRecoverClient) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: HostValidationContext,
 {
     let signer = msg.signer;
     let subject_client_id = msg.subject_client_id.clone();
@@ -63,7 +63,7 @@ where
 ///  - setting the subject client's latest height, trusting period, and chain ID values to match the substitute client's
 pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgRecoverClient) -> Result<(), ContextError>
 where
-    Ctx: ExecutionContext,
+    Ctx: HostExecutionContext,
 {
     let subject_client_id = msg.subject_client_id.clone();
     let substitute_client_id = msg.substitute_client_id.clone();
@@ -0,0 +1,66 @@
+//! Protocol logic for processing evidence of misbehaviour submitted by the
+//! host itself, e.g. by its slashing or monitoring modules, rather than by a
+//! relayer through `MsgSubmitMisbehaviour`.
+
+use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::events::ClientMisbehaviour;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::{HostExecutionContext, HostValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+/// Checks that the client is active and that the evidence is a valid client
+/// message of its type. As the evidence is not carried by a message, there is
+/// no signer to validate.
+pub fn validate<Ctx>(ctx: &Ctx, client_id: &ClientId, evidence: Any) -> Result<(), ContextError>
+where
+    Ctx: HostValidationContext,
+{
+    let client_val_ctx = ctx.get_client_validation_context();
+
+    let client_state = client_val_ctx.client_state(client_id)?;
+
+    client_state
+        .status(client_val_ctx, client_id)?
+        .verify_is_active()?;
+
+    client_state.verify_client_message(client_val_ctx, client_id, evidence)?;
+
+    Ok(())
+}
+
+/// Freezes the client if the evidence proves its misbehaviour, and emits the
+/// same events as a submitted misbehaviour would. Fails otherwise.
+pub fn execute<Ctx>(ctx: &mut Ctx, client_id: &ClientId, evidence: Any) -> Result<(), ContextError>
+where
+    Ctx: HostExecutionContext,
+{
+    let client_exec_ctx = ctx.get_client_execution_context();
+
+    let client_state = client_exec_ctx.client_state(client_id)?;
+
+    if !client_state.check_for_misbehaviour(client_exec_ctx, client_id, evidence.clone())? {
+        return Err(ClientError::MisbehaviourHandlingFailure {
+            reason: "evidence submitted, but no misbehaviour found".to_string(),
+        }
+        .into());
+    }
+
+    let misbehaviour_evidence =
+        client_state.misbehaviour_evidence(client_exec_ctx, client_id, evidence.clone())?;
+
+    client_state.update_state_on_misbehaviour(client_exec_ctx, client_id, evidence)?;
+
+    let event = IbcEvent::ClientMisbehaviour(ClientMisbehaviour::new(
+        client_id.clone(),
+        client_state.client_type(),
+        misbehaviour_evidence,
+    ));
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+    ctx.emit_ibc_event(event)?;
+
+    Ok(())
+}
@@ -2,18 +2,18 @@
 
 use ibc_core_client_context::prelude::*;
 use ibc_core_client_types::error::ClientError;
-use ibc_core_client_types::events::{ClientMisbehaviour, UpdateClient};
+use ibc_core_client_types::events::{ClientMisbehaviour, UpdateClient, UpdateClientNoOp};
 use ibc_core_client_types::msgs::MsgUpdateOrMisbehaviour;
 use ibc_core_client_types::UpdateKind;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ClientRegistryExecutionContext, ClientRegistryValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::ToVec;
 
 pub fn validate<Ctx>(ctx: &Ctx, msg: MsgUpdateOrMisbehaviour) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ClientRegistryValidationContext,
 {
     ctx.validate_message_signer(msg.signer())?;
 
@@ -28,16 +28,35 @@ where
         .status(client_val_ctx, &client_id)?
         .verify_is_active()?;
 
+    // Anyone may freeze a client with evidence of misbehaviour, so only
+    // updates with an actual header are subject to authorization.
+    let update_signer = match &msg {
+        MsgUpdateOrMisbehaviour::UpdateClient(msg) => Some(msg.signer.clone()),
+        MsgUpdateOrMisbehaviour::Misbehaviour(_) => None,
+    };
+
     let client_message = msg.client_message();
 
-    client_state.verify_client_message(client_val_ctx, &client_id, client_message)?;
+    client_state.verify_client_message(client_val_ctx, &client_id, client_message.clone())?;
+
+    if let Some(signer) = update_signer {
+        let found_misbehaviour = client_state.check_for_misbehaviour(
+            client_val_ctx,
+            &client_id,
+            client_message.clone(),
+        )?;
+
+        if !found_misbehaviour {
+            ctx.authorize_client_update(&client_id, &client_message, &signer)?;
+        }
+    }
 
     Ok(())
 }
 
 pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpdateOrMisbehaviour) -> Result<(), ContextError>
 where
-    Ctx: ExecutionContext,
+    Ctx: ClientRegistryExecutionContext,
 {
     let client_id = msg.client_id().clone();
     let update_kind = match msg {
@@ -54,11 +73,18 @@ where
         client_state.check_for_misbehaviour(client_exec_ctx, &client_id, client_message.clone())?;
 
     if found_misbehaviour {
+        let evidence = client_state.misbehaviour_evidence(
+            client_exec_ctx,
+            &client_id,
+            client_message.clone(),
+        )?;
+
         client_state.update_state_on_misbehaviour(client_exec_ctx, &client_id, client_message)?;
 
         let event = IbcEvent::ClientMisbehaviour(ClientMisbehaviour::new(
             client_id,
             client_state.client_type(),
+            evidence,
         ));
         ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
         ctx.emit_ibc_event(event)?;
@@ -75,19 +101,23 @@ where
         let consensus_heights =
             client_state.update_state(client_exec_ctx, &client_id, header.clone())?;
 
-        {
-            let event = {
-                let consensus_height = consensus_heights.first().ok_or(ClientError::Other {
-                    description: "client update state returned no updated height".to_string(),
-                })?;
+        ctx.on_client_updated(&client_id, &consensus_heights, &header.type_url)?;
 
-                IbcEvent::UpdateClient(UpdateClient::new(
+        {
+            let event = match consensus_heights.first().copied() {
+                Some(consensus_height) => IbcEvent::UpdateClient(UpdateClient::new(
                     client_id,
                     client_state.client_type(),
-                    *consensus_height,
+                    consensus_height,
                     consensus_heights,
                     header.to_vec(),
-                ))
+                )),
+                // The header had already been installed, so the update was a no-op.
+                None => IbcEvent::UpdateClientNoOp(UpdateClientNoOp::new(
+                    client_id,
+                    client_state.client_type(),
+                    header.to_vec(),
+                )),
             };
             ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
             ctx.emit_ibc_event(event)?;
@@ -0,0 +1,36 @@
+//! Protocol logic for updating the ICS02 parameters of the host.
+//!
+//! Unlike the other ICS02 handlers, these are not triggered by an IBC message,
+//! but are meant to be called by the host, e.g. upon a governance decision.
+
+use ibc_core_client_types::events::UpdateClientParams;
+use ibc_core_client_types::ClientParams;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::{ClientRegistryExecutionContext, ClientRegistryValidationContext};
+use ibc_primitives::prelude::*;
+
+pub fn validate<Ctx>(_ctx: &Ctx, params: &ClientParams) -> Result<(), ContextError>
+where
+    Ctx: ClientRegistryValidationContext,
+{
+    params.validate()?;
+
+    Ok(())
+}
+
+pub fn execute<Ctx>(ctx: &mut Ctx, params: ClientParams) -> Result<(), ContextError>
+where
+    Ctx: ClientRegistryExecutionContext,
+{
+    let event =
+        IbcEvent::UpdateClientParams(UpdateClientParams::new(params.allowed_clients.clone()));
+
+    ctx.store_client_params(params)?;
+
+    ctx.emit_ibc_event(event)?;
+
+    ctx.log_message("success: client params updated".to_string())?;
+
+    Ok(())
+}
@@ -7,12 +7,12 @@ use ibc_core_client_types::msgs::MsgUpgradeClient;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::ClientConsensusStatePath;
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{HostExecutionContext, HostValidationContext};
 use ibc_primitives::prelude::*;
 
 pub fn validate<Ctx>(ctx: &Ctx, msg: MsgUpgradeClient) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: HostValidationContext,
 {
     let MsgUpgradeClient {
         client_id, signer, ..
@@ -57,7 +57,7 @@ where
 
 pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpgradeClient) -> Result<(), ContextError>
 where
-    Ctx: ExecutionContext,
+    Ctx: HostExecutionContext,
 {
     let MsgUpgradeClient { client_id, .. } = msg;
 
@@ -15,6 +15,7 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod expiry;
 pub mod handler;
 
 /// Re-exports ICS-02 traits from `ibc-core-client-context` for custom IBC
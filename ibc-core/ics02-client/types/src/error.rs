@@ -5,7 +5,8 @@ use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::utils::abci::AbciEventError;
+use ibc_primitives::{Signer, Timestamp};
 
 use super::status::Status;
 use crate::height::Height;
@@ -25,6 +26,14 @@ pub enum ClientError {
     ClientStateNotFound { client_id: ClientId },
     /// client state already exists: `{client_id}`
     ClientStateAlreadyExists { client_id: ClientId },
+    /// client type `{client_type}` is not in the allowed clients of the host
+    ClientTypeNotAllowed { client_type: ClientType },
+    /// invalid client params: `{description}`
+    InvalidClientParams { description: String },
+    /// the host does not allow more than `{max_clients}` clients to be created
+    MaxClientsReached { max_clients: u64 },
+    /// signer `{signer}` is not authorized to update client `{client_id}`
+    UnauthorizedClientUpdate { client_id: ClientId, signer: Signer },
     /// Substitute client height `{substitute_height}` is not greater than subject client height `{subject_height}` during client recovery
     ClientRecoveryHeightMismatch {
         subject_height: Height,
@@ -101,6 +110,8 @@ pub enum ClientError {
     CounterOverflow,
     /// update client message did not contain valid header or misbehaviour
     InvalidUpdateClientMessage,
+    /// invalid client event: `{0}`
+    InvalidEvent(AbciEventError),
     /// other error: `{description}`
     Other { description: String },
 }
@@ -113,6 +124,12 @@ impl From<&'static str> for ClientError {
     }
 }
 
+impl From<AbciEventError> for ClientError {
+    fn from(e: AbciEventError) -> Self {
+        Self::InvalidEvent(e)
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for ClientError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -122,6 +139,7 @@ impl std::error::Error for ClientError {
             | Self::InvalidRawMisbehaviour(e) => Some(e),
             Self::InvalidCommitmentProof(e) | Self::Ics23Verification(e) => Some(e),
             Self::InvalidPacketTimestamp(e) => Some(e),
+            Self::InvalidEvent(e) => Some(e),
             _ => None,
         }
     }
@@ -1,17 +1,27 @@
 //! Types for the IBC events emitted from Tendermint Websocket by the client module.
+use core::str;
+use core::time::Duration;
+
 use derive_more::From;
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
 use ibc_primitives::prelude::*;
+use ibc_primitives::utils::abci::{AbciEventError, EventAttributes};
 use subtle_encoding::hex;
 use tendermint::abci;
 
+use crate::error::ClientError;
+use crate::evidence::{MisbehaviourEvidence, MisbehaviourKind};
 use crate::height::Height;
 
 /// Client event types
 pub const CREATE_CLIENT_EVENT: &str = "create_client";
 pub const UPDATE_CLIENT_EVENT: &str = "update_client";
+pub const UPDATE_CLIENT_NO_OP_EVENT: &str = "update_client_no_op";
 pub const CLIENT_MISBEHAVIOUR_EVENT: &str = "client_misbehaviour";
 pub const UPGRADE_CLIENT_EVENT: &str = "upgrade_client";
+pub const UPDATE_CLIENT_PARAMS_EVENT: &str = "update_client_params";
+pub const CLIENT_EXPIRING_EVENT: &str = "client_expiring";
+pub const CLIENT_EXPIRED_EVENT: &str = "client_expired";
 
 /// The content of the `key` field for the attribute containing the client identifier.
 pub const CLIENT_ID_ATTRIBUTE_KEY: &str = "client_id";
@@ -28,6 +38,21 @@ pub const CONSENSUS_HEIGHTS_ATTRIBUTE_KEY: &str = "consensus_heights";
 /// The content of the `key` field for the header in update client event.
 pub const HEADER_ATTRIBUTE_KEY: &str = "header";
 
+/// The content of the `key` field for the attribute containing the allowed client types.
+pub const ALLOWED_CLIENTS_ATTRIBUTE_KEY: &str = "allowed_clients";
+
+/// The content of the `key` field for the attribute containing the kind of misbehaviour.
+pub const MISBEHAVIOUR_TYPE_ATTRIBUTE_KEY: &str = "misbehaviour_type";
+
+/// The content of the `key` field for the attribute containing the heights of the conflicting headers.
+pub const CONFLICTING_HEIGHTS_ATTRIBUTE_KEY: &str = "conflicting_heights";
+
+/// The content of the `key` field for the attribute containing the hashes of the conflicting headers.
+pub const CONFLICTING_HASHES_ATTRIBUTE_KEY: &str = "conflicting_hashes";
+
+/// The content of the `key` field for the attribute containing the seconds left before the client expires.
+pub const SECONDS_UNTIL_EXPIRY_ATTRIBUTE_KEY: &str = "seconds_until_expiry";
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -160,6 +185,43 @@ impl From<HeaderAttribute> for abci::EventAttribute {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct AllowedClientsAttribute {
+    allowed_clients: Vec<String>,
+}
+
+impl From<AllowedClientsAttribute> for abci::EventAttribute {
+    fn from(attr: AllowedClientsAttribute) -> Self {
+        (
+            ALLOWED_CLIENTS_ATTRIBUTE_KEY,
+            attr.allowed_clients.join(","),
+        )
+            .into()
+    }
+}
+
+/// Decodes the hexadecimal `value` of the attribute under `key`.
+fn decode_hex(
+    attributes: &EventAttributes<'_>,
+    key: &str,
+    value: &str,
+) -> Result<Vec<u8>, AbciEventError> {
+    hex::decode(value).map_err(|_| attributes.invalid(key, value))
+}
+
 /// CreateClient event signals the creation of a new on-chain client (IBC client).
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -220,6 +282,20 @@ impl From<CreateClient> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for CreateClient {
+    type Error = ClientError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, CREATE_CLIENT_EVENT)?;
+
+        Ok(Self::new(
+            attributes.parse(CLIENT_ID_ATTRIBUTE_KEY)?,
+            attributes.parse(CLIENT_TYPE_ATTRIBUTE_KEY)?,
+            attributes.parse(CONSENSUS_HEIGHT_ATTRIBUTE_KEY)?,
+        ))
+    }
+}
+
 /// UpdateClient event signals a recent update of an on-chain client (IBC Client).
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -306,6 +382,114 @@ impl From<UpdateClient> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for UpdateClient {
+    type Error = ClientError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, UPDATE_CLIENT_EVENT)?;
+        let consensus_heights: Vec<Height> =
+            attributes.parse_list(CONSENSUS_HEIGHTS_ATTRIBUTE_KEY)?;
+
+        // The deprecated `consensus_height` and the `header` attributes are
+        // dropped by recent ibc-go releases.
+        let consensus_height = match (
+            attributes.find(CONSENSUS_HEIGHT_ATTRIBUTE_KEY),
+            consensus_heights.first(),
+        ) {
+            (None, Some(consensus_height)) => *consensus_height,
+            _ => attributes.parse(CONSENSUS_HEIGHT_ATTRIBUTE_KEY)?,
+        };
+        let header = match attributes.find(HEADER_ATTRIBUTE_KEY) {
+            Some(header) => decode_hex(&attributes, HEADER_ATTRIBUTE_KEY, header)?,
+            None => Vec::new(),
+        };
+
+        Ok(Self::new(
+            attributes.parse(CLIENT_ID_ATTRIBUTE_KEY)?,
+            attributes.parse(CLIENT_TYPE_ATTRIBUTE_KEY)?,
+            consensus_height,
+            consensus_heights,
+            header,
+        ))
+    }
+}
+
+/// UpdateClientNoOp event signals that a client update was submitted with a
+/// header that had already been installed, and was hence a no-op.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateClientNoOp {
+    client_id: ClientIdAttribute,
+    client_type: ClientTypeAttribute,
+    header: HeaderAttribute,
+}
+
+impl UpdateClientNoOp {
+    /// Constructs a new UpdateClientNoOp event.
+    ///
+    /// NOTE: the `header` is the encoded bytes of the
+    /// [`Any`](ibc_proto::google::protobuf::Any) type.
+    pub fn new(client_id: ClientId, client_type: ClientType, header: Vec<u8>) -> Self {
+        Self {
+            client_id: ClientIdAttribute::from(client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+            header: HeaderAttribute::from(header),
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id.client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn header(&self) -> &Vec<u8> {
+        &self.header.header
+    }
+
+    pub fn event_type(&self) -> &str {
+        UPDATE_CLIENT_NO_OP_EVENT
+    }
+}
+
+impl From<UpdateClientNoOp> for abci::Event {
+    fn from(u: UpdateClientNoOp) -> Self {
+        Self {
+            kind: UPDATE_CLIENT_NO_OP_EVENT.to_owned(),
+            attributes: vec![u.client_id.into(), u.client_type.into(), u.header.into()],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for UpdateClientNoOp {
+    type Error = ClientError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, UPDATE_CLIENT_NO_OP_EVENT)?;
+        let header = attributes.get(HEADER_ATTRIBUTE_KEY)?;
+
+        Ok(Self::new(
+            attributes.parse(CLIENT_ID_ATTRIBUTE_KEY)?,
+            attributes.parse(CLIENT_TYPE_ATTRIBUTE_KEY)?,
+            decode_hex(&attributes, HEADER_ATTRIBUTE_KEY, header)?,
+        ))
+    }
+}
+
 /// ClientMisbehaviour event signals the update of an on-chain client (IBC Client) with evidence of
 /// misbehaviour.
 #[cfg_attr(
@@ -325,13 +509,19 @@ impl From<UpdateClient> for abci::Event {
 pub struct ClientMisbehaviour {
     client_id: ClientIdAttribute,
     client_type: ClientTypeAttribute,
+    evidence: Option<MisbehaviourEvidence>,
 }
 
 impl ClientMisbehaviour {
-    pub fn new(client_id: ClientId, client_type: ClientType) -> Self {
+    pub fn new(
+        client_id: ClientId,
+        client_type: ClientType,
+        evidence: Option<MisbehaviourEvidence>,
+    ) -> Self {
         Self {
             client_id: ClientIdAttribute::from(client_id),
             client_type: ClientTypeAttribute::from(client_type),
+            evidence,
         }
     }
 
@@ -343,6 +533,11 @@ impl ClientMisbehaviour {
         &self.client_type.client_type
     }
 
+    /// The evidence of the misbehaviour, if the light client provides it.
+    pub fn evidence(&self) -> Option<&MisbehaviourEvidence> {
+        self.evidence.as_ref()
+    }
+
     pub fn event_type(&self) -> &str {
         CLIENT_MISBEHAVIOUR_EVENT
     }
@@ -350,13 +545,88 @@ impl ClientMisbehaviour {
 
 impl From<ClientMisbehaviour> for abci::Event {
     fn from(c: ClientMisbehaviour) -> Self {
+        let mut attributes = vec![c.client_id.into(), c.client_type.into()];
+
+        if let Some(evidence) = c.evidence {
+            let conflicting_heights: Vec<String> = evidence
+                .conflicting_heights
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            let conflicting_hashes: Vec<String> = evidence
+                .conflicting_hashes
+                .iter()
+                .map(|hash| {
+                    String::from_utf8(hex::encode(hash))
+                        .expect("Never fails because hexadecimal is valid UTF-8")
+                })
+                .collect();
+
+            attributes.extend([
+                (MISBEHAVIOUR_TYPE_ATTRIBUTE_KEY, evidence.kind.as_str()).into(),
+                (
+                    CONFLICTING_HEIGHTS_ATTRIBUTE_KEY,
+                    conflicting_heights.join(","),
+                )
+                    .into(),
+                (
+                    CONFLICTING_HASHES_ATTRIBUTE_KEY,
+                    conflicting_hashes.join(","),
+                )
+                    .into(),
+            ]);
+        }
+
         Self {
             kind: CLIENT_MISBEHAVIOUR_EVENT.to_owned(),
-            attributes: vec![c.client_id.into(), c.client_type.into()],
+            attributes,
         }
     }
 }
 
+impl TryFrom<abci::Event> for ClientMisbehaviour {
+    type Error = ClientError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, CLIENT_MISBEHAVIOUR_EVENT)?;
+
+        // The evidence attributes are only present if the light client
+        // provided evidence of the misbehaviour.
+        let evidence = match attributes.find(MISBEHAVIOUR_TYPE_ATTRIBUTE_KEY) {
+            None => None,
+            Some(kind) => {
+                let kind = match kind {
+                    "double_sign" => MisbehaviourKind::DoubleSign,
+                    "time_violation" => MisbehaviourKind::TimeViolation,
+                    _ => {
+                        return Err(attributes
+                            .invalid(MISBEHAVIOUR_TYPE_ATTRIBUTE_KEY, kind)
+                            .into())
+                    }
+                };
+                let conflicting_hashes = attributes
+                    .parse_list::<String>(CONFLICTING_HASHES_ATTRIBUTE_KEY)?
+                    .iter()
+                    .map(|hash| decode_hex(&attributes, CONFLICTING_HASHES_ATTRIBUTE_KEY, hash))
+                    .collect::<Result<_, _>>()?;
+
+                Some(MisbehaviourEvidence {
+                    kind,
+                    conflicting_heights: attributes
+                        .parse_list(CONFLICTING_HEIGHTS_ATTRIBUTE_KEY)?,
+                    conflicting_hashes,
+                })
+            }
+        };
+
+        Ok(Self::new(
+            attributes.parse(CLIENT_ID_ATTRIBUTE_KEY)?,
+            attributes.parse(CLIENT_TYPE_ATTRIBUTE_KEY)?,
+            evidence,
+        ))
+    }
+}
+
 /// Signals a recent upgrade of an on-chain client (IBC Client).
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -416,3 +686,217 @@ impl From<UpgradeClient> for abci::Event {
         }
     }
 }
+
+impl TryFrom<abci::Event> for UpgradeClient {
+    type Error = ClientError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, UPGRADE_CLIENT_EVENT)?;
+
+        Ok(Self::new(
+            attributes.parse(CLIENT_ID_ATTRIBUTE_KEY)?,
+            attributes.parse(CLIENT_TYPE_ATTRIBUTE_KEY)?,
+            attributes.parse(CONSENSUS_HEIGHT_ATTRIBUTE_KEY)?,
+        ))
+    }
+}
+
+/// Signals an update of the ICS-02 parameters of the host.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateClientParams {
+    allowed_clients: AllowedClientsAttribute,
+}
+
+impl UpdateClientParams {
+    pub fn new(allowed_clients: Vec<String>) -> Self {
+        Self {
+            allowed_clients: AllowedClientsAttribute::from(allowed_clients),
+        }
+    }
+
+    pub fn allowed_clients(&self) -> &[String] {
+        &self.allowed_clients.allowed_clients
+    }
+
+    pub fn event_type(&self) -> &str {
+        UPDATE_CLIENT_PARAMS_EVENT
+    }
+}
+
+impl From<UpdateClientParams> for abci::Event {
+    fn from(u: UpdateClientParams) -> Self {
+        Self {
+            kind: UPDATE_CLIENT_PARAMS_EVENT.to_owned(),
+            attributes: vec![u.allowed_clients.into()],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for UpdateClientParams {
+    type Error = ClientError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, UPDATE_CLIENT_PARAMS_EVENT)?;
+
+        Ok(Self::new(
+            attributes.parse_list(ALLOWED_CLIENTS_ATTRIBUTE_KEY)?,
+        ))
+    }
+}
+
+/// ClientExpiring event warns that an active client expires within the
+/// warning period of the host, unless updated in the meantime.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientExpiring {
+    client_id: ClientIdAttribute,
+    client_type: ClientTypeAttribute,
+    seconds_until_expiry: u64,
+}
+
+impl ClientExpiring {
+    pub fn new(client_id: ClientId, client_type: ClientType, time_until_expiry: Duration) -> Self {
+        Self {
+            client_id: ClientIdAttribute::from(client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+            seconds_until_expiry: time_until_expiry.as_secs(),
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id.client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    /// The time left before the client expires, in whole seconds.
+    pub fn time_until_expiry(&self) -> Duration {
+        Duration::from_secs(self.seconds_until_expiry)
+    }
+
+    pub fn event_type(&self) -> &str {
+        CLIENT_EXPIRING_EVENT
+    }
+}
+
+impl From<ClientExpiring> for abci::Event {
+    fn from(c: ClientExpiring) -> Self {
+        Self {
+            kind: CLIENT_EXPIRING_EVENT.to_owned(),
+            attributes: vec![
+                c.client_id.into(),
+                c.client_type.into(),
+                (
+                    SECONDS_UNTIL_EXPIRY_ATTRIBUTE_KEY,
+                    c.seconds_until_expiry.to_string(),
+                )
+                    .into(),
+            ],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for ClientExpiring {
+    type Error = ClientError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, CLIENT_EXPIRING_EVENT)?;
+
+        Ok(Self::new(
+            attributes.parse(CLIENT_ID_ATTRIBUTE_KEY)?,
+            attributes.parse(CLIENT_TYPE_ATTRIBUTE_KEY)?,
+            Duration::from_secs(attributes.parse(SECONDS_UNTIL_EXPIRY_ATTRIBUTE_KEY)?),
+        ))
+    }
+}
+
+/// ClientExpired event signals that a client expired, and can no longer be
+/// updated nor verify proofs until recovered.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientExpired {
+    client_id: ClientIdAttribute,
+    client_type: ClientTypeAttribute,
+}
+
+impl ClientExpired {
+    pub fn new(client_id: ClientId, client_type: ClientType) -> Self {
+        Self {
+            client_id: ClientIdAttribute::from(client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id.client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn event_type(&self) -> &str {
+        CLIENT_EXPIRED_EVENT
+    }
+}
+
+impl From<ClientExpired> for abci::Event {
+    fn from(c: ClientExpired) -> Self {
+        Self {
+            kind: CLIENT_EXPIRED_EVENT.to_owned(),
+            attributes: vec![c.client_id.into(), c.client_type.into()],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for ClientExpired {
+    type Error = ClientError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, CLIENT_EXPIRED_EVENT)?;
+
+        Ok(Self::new(
+            attributes.parse(CLIENT_ID_ATTRIBUTE_KEY)?,
+            attributes.parse(CLIENT_TYPE_ATTRIBUTE_KEY)?,
+        ))
+    }
+}
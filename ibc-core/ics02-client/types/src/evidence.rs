@@ -0,0 +1,70 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_primitives::prelude::*;
+
+use crate::height::Height;
+
+/// The kind of misbehaviour a client was frozen for.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MisbehaviourKind {
+    /// Two conflicting headers were signed at the same height.
+    DoubleSign,
+    /// The timestamps of the headers do not increase monotonically with
+    /// their heights.
+    TimeViolation,
+}
+
+impl MisbehaviourKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DoubleSign => "double_sign",
+            Self::TimeViolation => "time_violation",
+        }
+    }
+}
+
+impl Display for MisbehaviourKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Evidence of the misbehaviour a client was frozen for, as found by the
+/// light client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MisbehaviourEvidence {
+    pub kind: MisbehaviourKind,
+    /// The heights of the submitted headers evidencing the misbehaviour.
+    pub conflicting_heights: Vec<Height>,
+    /// The hashes of the submitted headers, in the same order as their
+    /// heights.
+    pub conflicting_hashes: Vec<Vec<u8>>,
+}
@@ -16,11 +16,15 @@ extern crate std;
 
 pub mod error;
 pub mod events;
+mod evidence;
 mod height;
 pub mod msgs;
+mod params;
 mod status;
 
+pub use evidence::*;
 pub use height::*;
+pub use params::*;
 pub use status::*;
 
 /// Re-exports ICS-02 proto types from the `ibc-proto` crate for added convenience.
@@ -0,0 +1,123 @@
+//! Definition of the ICS-02 parameters of a host chain.
+
+use ibc_core_host_types::identifiers::ClientType;
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Params as RawClientParams;
+use ibc_proto::Protobuf;
+
+use crate::error::ClientError;
+
+/// The wildcard entry of the `allowed_clients` list, allowing any client type
+/// to be created.
+pub const ALLOW_ALL_CLIENTS: &str = "*";
+
+/// Defines the ICS-02 parameters of a host chain, as stored by the host and
+/// consulted by the client handlers.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientParams {
+    /// The client types that can be created on the host, or the
+    /// [`ALLOW_ALL_CLIENTS`] wildcard.
+    pub allowed_clients: Vec<String>,
+}
+
+impl ClientParams {
+    pub fn new(allowed_clients: Vec<String>) -> Result<Self, ClientError> {
+        let params = Self { allowed_clients };
+
+        params.validate()?;
+
+        Ok(params)
+    }
+
+    /// Checks that the allowed clients contain neither blank nor duplicate
+    /// entries.
+    pub fn validate(&self) -> Result<(), ClientError> {
+        for (i, client_type) in self.allowed_clients.iter().enumerate() {
+            if client_type.trim().is_empty() {
+                return Err(ClientError::InvalidClientParams {
+                    description: format!("allowed client type at index {i} is blank"),
+                });
+            }
+
+            if self.allowed_clients[..i].contains(client_type) {
+                return Err(ClientError::InvalidClientParams {
+                    description: format!("duplicate allowed client type: {client_type}"),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether clients of the given type are allowed to be created.
+    pub fn is_allowed_client(&self, client_type: &ClientType) -> bool {
+        self.allowed_clients
+            .iter()
+            .any(|allowed| allowed == ALLOW_ALL_CLIENTS || allowed == client_type.as_str())
+    }
+}
+
+/// Allows all client types to be created.
+impl Default for ClientParams {
+    fn default() -> Self {
+        Self {
+            allowed_clients: vec![ALLOW_ALL_CLIENTS.to_string()],
+        }
+    }
+}
+
+impl Protobuf<RawClientParams> for ClientParams {}
+
+impl TryFrom<RawClientParams> for ClientParams {
+    type Error = ClientError;
+
+    fn try_from(raw: RawClientParams) -> Result<Self, Self::Error> {
+        Self::new(raw.allowed_clients)
+    }
+}
+
+impl From<ClientParams> for RawClientParams {
+    fn from(params: ClientParams) -> Self {
+        RawClientParams {
+            allowed_clients: params.allowed_clients,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allowed_clients() {
+        let tm_client_type = ClientType::new("07-tendermint").unwrap();
+        let mock_client_type = ClientType::new("9999-mock").unwrap();
+
+        assert!(ClientParams::default().is_allowed_client(&mock_client_type));
+
+        let params = ClientParams::new(vec![tm_client_type.as_str().to_string()]).unwrap();
+        assert!(params.is_allowed_client(&tm_client_type));
+        assert!(!params.is_allowed_client(&mock_client_type));
+
+        assert!(!ClientParams::new(vec![])
+            .unwrap()
+            .is_allowed_client(&tm_client_type));
+
+        assert!(ClientParams::new(vec![" ".to_string()]).is_err());
+        assert!(ClientParams::new(vec!["07-tendermint".to_string(); 2]).is_err());
+    }
+}
@@ -3,7 +3,7 @@ use ibc_core_client::types::Height;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
-use ibc_core_host::ValidationContext;
+use ibc_core_host::HostValidationContext;
 
 pub fn verify_conn_delay_passed<Ctx>(
     ctx: &Ctx,
@@ -11,7 +11,7 @@ pub fn verify_conn_delay_passed<Ctx>(
     connection_end: &ConnectionEnd,
 ) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: HostValidationContext,
 {
     // Fetch the current host chain time and height.
     let current_host_time = ctx.host_timestamp()?;
@@ -0,0 +1,49 @@
+//! Enforcement of the caps the host puts on the number of connections opened
+//! on top of a client.
+
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{CapReached, CapScope};
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::{ConnectionExecutionContext, ConnectionValidationContext};
+
+/// Checks that the host allows another connection to be opened on top of the
+/// given client.
+pub(crate) fn verify_connection_cap<Ctx>(
+    ctx: &Ctx,
+    client_id: &ClientId,
+) -> Result<(), ContextError>
+where
+    Ctx: ConnectionValidationContext,
+{
+    if let Some(max_connections) = ctx.host_limits()?.max_connections_per_client {
+        if ctx.client_connection_count(client_id)? >= max_connections {
+            return Err(ConnectionError::MaxConnectionsPerClientReached {
+                client_id: client_id.clone(),
+                max_connections,
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Emits a `CapReached` event if the connection just opened on top of the
+/// given client exhausted the cap of the host.
+pub(crate) fn emit_connection_cap_reached<Ctx>(
+    ctx: &mut Ctx,
+    client_id: &ClientId,
+) -> Result<(), ContextError>
+where
+    Ctx: ConnectionExecutionContext,
+{
+    if let Some(max_connections) = ctx.host_limits()?.max_connections_per_client {
+        if ctx.client_connection_count(client_id)? >= max_connections {
+            let scope = CapScope::ConnectionsPerClient(client_id.clone());
+            ctx.emit_ibc_event(CapReached::new(scope, max_connections).into())?;
+        }
+    }
+
+    Ok(())
+}
@@ -9,14 +9,14 @@ use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ConnectionExecutionContext, ConnectionValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 use ibc_primitives::ToVec;
 
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ConnectionValidationContext,
 {
     let vars = LocalVars::new(ctx_a, &msg)?;
     validate_impl(ctx_a, &msg, &vars)
@@ -28,7 +28,7 @@ fn validate_impl<Ctx>(
     vars: &LocalVars,
 ) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ConnectionValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
@@ -53,7 +53,16 @@ where
     msg.version
         .verify_is_supported(vars.conn_end_on_a.versions())?;
 
-    vars.conn_end_on_a.verify_state_matches(&State::Init)?;
+    if vars.conn_end_on_a.state() == &State::TryOpen {
+        // Crossing hellos: the host already opened its end upon the
+        // counterparty's `ConnOpenTry`, which must be for the very same
+        // connection as this acknowledgement.
+        if vars.conn_end_on_a.counterparty().connection_id() != Some(&msg.conn_id_on_b) {
+            return Err(ConnectionError::InvalidCounterparty.into());
+        }
+    } else {
+        vars.conn_end_on_a.verify_state_matches(&State::Init)?;
+    }
 
     // Proof verification.
     {
@@ -141,7 +150,7 @@ where
 
 pub fn execute<Ctx>(ctx_a: &mut Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
 where
-    Ctx: ExecutionContext,
+    Ctx: ConnectionExecutionContext,
 {
     let vars = LocalVars::new(ctx_a, &msg)?;
     execute_impl(ctx_a, msg, vars)
@@ -153,7 +162,7 @@ fn execute_impl<Ctx>(
     vars: LocalVars,
 ) -> Result<(), ContextError>
 where
-    Ctx: ExecutionContext,
+    Ctx: ConnectionExecutionContext,
 {
     let event = IbcEvent::OpenAckConnection(OpenAck::new(
         msg.conn_id_on_a.clone(),
@@ -191,7 +200,7 @@ struct LocalVars {
 impl LocalVars {
     fn new<Ctx>(ctx_a: &Ctx, msg: &MsgConnectionOpenAck) -> Result<Self, ContextError>
     where
-        Ctx: ValidationContext,
+        Ctx: ConnectionValidationContext,
     {
         Ok(LocalVars {
             conn_end_on_a: ctx_a.connection_end(&msg.conn_id_on_a)?,
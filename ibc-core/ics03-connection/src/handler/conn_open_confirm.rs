@@ -9,13 +9,13 @@ use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
 use ibc_core_host::types::path::{ClientConsensusStatePath, ConnectionPath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ConnectionExecutionContext, ConnectionValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
 pub fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgConnectionOpenConfirm) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ConnectionValidationContext,
 {
     let vars = LocalVars::new(ctx_b, msg)?;
     validate_impl(ctx_b, msg, &vars)
@@ -27,7 +27,7 @@ fn validate_impl<Ctx>(
     vars: &LocalVars,
 ) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ConnectionValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
@@ -89,7 +89,7 @@ where
 
 pub fn execute<Ctx>(ctx_b: &mut Ctx, msg: &MsgConnectionOpenConfirm) -> Result<(), ContextError>
 where
-    Ctx: ExecutionContext,
+    Ctx: ConnectionExecutionContext,
 {
     let vars = LocalVars::new(ctx_b, msg)?;
     execute_impl(ctx_b, msg, vars)
@@ -101,7 +101,7 @@ fn execute_impl<Ctx>(
     vars: LocalVars,
 ) -> Result<(), ContextError>
 where
-    Ctx: ExecutionContext,
+    Ctx: ConnectionExecutionContext,
 {
     let client_id_on_a = vars.client_id_on_a();
     let client_id_on_b = vars.client_id_on_b();
@@ -138,7 +138,7 @@ struct LocalVars {
 impl LocalVars {
     fn new<Ctx>(ctx_b: &Ctx, msg: &MsgConnectionOpenConfirm) -> Result<Self, ContextError>
     where
-        Ctx: ValidationContext,
+        Ctx: ConnectionValidationContext,
     {
         Ok(Self {
             conn_end_on_b: ctx_b.connection_end(&msg.conn_id_on_b)?,
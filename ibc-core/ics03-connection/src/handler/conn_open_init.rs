@@ -1,5 +1,7 @@
 //! Protocol logic specific to ICS3 messages of type `MsgConnectionOpenInit`.
 use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenInit;
 use ibc_core_connection_types::msgs::MsgConnectionOpenInit;
 use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
@@ -7,12 +9,14 @@ use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::ConnectionId;
 use ibc_core_host::types::path::{ClientConnectionPath, ConnectionPath};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ConnectionExecutionContext, ConnectionValidationContext};
 use ibc_primitives::prelude::*;
 
+use super::caps::{emit_connection_cap_reached, verify_connection_cap};
+
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenInit) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ConnectionValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
@@ -25,6 +29,12 @@ where
         .status(client_val_ctx_a, &msg.client_id_on_a)?
         .verify_is_active()?;
 
+    verify_connection_cap(ctx_a, &msg.client_id_on_a)?;
+
+    if msg.counterparty.prefix().is_empty() && !ctx_a.allows_empty_counterparty_prefix() {
+        return Err(ConnectionError::Client(ClientError::EmptyPrefix).into());
+    }
+
     if let Some(version) = msg.version {
         version.verify_is_supported(&ctx_a.get_compatible_versions())?;
     }
@@ -34,7 +44,7 @@ where
 
 pub fn execute<Ctx>(ctx_a: &mut Ctx, msg: MsgConnectionOpenInit) -> Result<(), ContextError>
 where
-    Ctx: ExecutionContext,
+    Ctx: ConnectionExecutionContext,
 {
     let versions = if let Some(version) = msg.version {
         version.verify_is_supported(&ctx_a.get_compatible_versions())?;
@@ -76,10 +86,13 @@ where
 
     ctx_a.increase_connection_counter()?;
     ctx_a.store_connection_to_client(
-        &ClientConnectionPath::new(msg.client_id_on_a),
+        &ClientConnectionPath::new(msg.client_id_on_a.clone()),
         conn_id_on_a.clone(),
     )?;
+
     ctx_a.store_connection(&ConnectionPath::new(&conn_id_on_a), conn_end_on_a)?;
 
+    emit_connection_cap_reached(ctx_a, &msg.client_id_on_a)?;
+
     Ok(())
 }
@@ -1,5 +1,6 @@
 //! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenTry`.;
 use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenTry;
 use ibc_core_connection_types::msgs::MsgConnectionOpenTry;
@@ -10,14 +11,16 @@ use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
 use ibc_core_host::types::path::{
     ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ConnectionExecutionContext, ConnectionValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 use ibc_primitives::ToVec;
 
+use super::caps::{emit_connection_cap_reached, verify_connection_cap};
+
 pub fn validate<Ctx>(ctx_b: &Ctx, msg: MsgConnectionOpenTry) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ConnectionValidationContext,
 {
     let vars = LocalVars::new(ctx_b, &msg)?;
     validate_impl(ctx_b, &msg, &vars)
@@ -29,7 +32,7 @@ fn validate_impl<Ctx>(
     vars: &LocalVars,
 ) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ConnectionValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
@@ -52,6 +55,16 @@ where
         .into());
     }
 
+    // In the crossing hellos case, the connection was already accounted for
+    // upon its initialisation.
+    if !vars.reuses_previous_conn {
+        verify_connection_cap(ctx_b, &msg.client_id_on_b)?;
+    }
+
+    if msg.counterparty.prefix().is_empty() && !ctx_b.allows_empty_counterparty_prefix() {
+        return Err(ConnectionError::Client(ClientError::EmptyPrefix).into());
+    }
+
     let client_id_on_a = msg.counterparty.client_id();
 
     // Verify proofs
@@ -137,7 +150,7 @@ where
 
 pub fn execute<Ctx>(ctx_b: &mut Ctx, msg: MsgConnectionOpenTry) -> Result<(), ContextError>
 where
-    Ctx: ExecutionContext,
+    Ctx: ConnectionExecutionContext,
 {
     let vars = LocalVars::new(ctx_b, &msg)?;
     execute_impl(ctx_b, msg, vars)
@@ -149,7 +162,7 @@ fn execute_impl<Ctx>(
     vars: LocalVars,
 ) -> Result<(), ContextError>
 where
-    Ctx: ExecutionContext,
+    Ctx: ConnectionExecutionContext,
 {
     let conn_id_on_a = vars
         .conn_end_on_b
@@ -166,19 +179,31 @@ where
     ctx_b.emit_ibc_event(event)?;
     ctx_b.log_message("success: conn_open_try verification passed".to_string())?;
 
-    ctx_b.increase_connection_counter()?;
-    ctx_b.store_connection_to_client(
-        &ClientConnectionPath::new(msg.client_id_on_b),
-        vars.conn_id_on_b.clone(),
-    )?;
+    // In the crossing hellos case, the connection was allocated, and
+    // associated with its client, upon its initialisation.
+    if !vars.reuses_previous_conn {
+        ctx_b.increase_connection_counter()?;
+        ctx_b.store_connection_to_client(
+            &ClientConnectionPath::new(msg.client_id_on_b.clone()),
+            vars.conn_id_on_b.clone(),
+        )?;
+    }
+
     ctx_b.store_connection(&ConnectionPath::new(&vars.conn_id_on_b), vars.conn_end_on_b)?;
 
+    if !vars.reuses_previous_conn {
+        emit_connection_cap_reached(ctx_b, &msg.client_id_on_b)?;
+    }
+
     Ok(())
 }
 
 struct LocalVars {
     conn_id_on_b: ConnectionId,
     conn_end_on_b: ConnectionEnd,
+    /// Whether the handshake reuses the connection the host initialised
+    /// itself, in the crossing hellos case.
+    reuses_previous_conn: bool,
     client_id_on_a: ClientId,
     conn_id_on_a: ConnectionId,
 }
@@ -186,19 +211,42 @@ struct LocalVars {
 impl LocalVars {
     fn new<Ctx>(ctx_b: &Ctx, msg: &MsgConnectionOpenTry) -> Result<Self, ContextError>
     where
-        Ctx: ValidationContext,
+        Ctx: ConnectionValidationContext,
     {
         let version_on_b = ctx_b.pick_version(&msg.versions_on_a)?;
 
+        let conn_end_on_b = ConnectionEnd::new(
+            State::TryOpen,
+            msg.client_id_on_b.clone(),
+            msg.counterparty.clone(),
+            vec![version_on_b],
+            msg.delay_period,
+        )?;
+
+        #[allow(deprecated)]
+        let previous_conn_id_on_b = &msg.previous_connection_id;
+
+        let (conn_id_on_b, reuses_previous_conn) = if previous_conn_id_on_b.is_empty() {
+            (ConnectionId::new(ctx_b.connection_counter()?), false)
+        } else {
+            let previous_conn_id_on_b = previous_conn_id_on_b
+                .parse()
+                .map_err(ConnectionError::InvalidIdentifier)?;
+            let previous_conn_end_on_b = ctx_b.connection_end(&previous_conn_id_on_b)?;
+
+            verify_previous_conn_end(
+                &previous_conn_id_on_b,
+                &previous_conn_end_on_b,
+                &conn_end_on_b,
+            )?;
+
+            (previous_conn_id_on_b, true)
+        };
+
         Ok(Self {
-            conn_id_on_b: ConnectionId::new(ctx_b.connection_counter()?),
-            conn_end_on_b: ConnectionEnd::new(
-                State::TryOpen,
-                msg.client_id_on_b.clone(),
-                msg.counterparty.clone(),
-                vec![version_on_b],
-                msg.delay_period,
-            )?,
+            conn_id_on_b,
+            conn_end_on_b,
+            reuses_previous_conn,
             client_id_on_a: msg.counterparty.client_id().clone(),
             conn_id_on_a: msg
                 .counterparty
@@ -208,3 +256,38 @@ impl LocalVars {
         })
     }
 }
+
+/// Checks that the connection the host initialised while the counterparty
+/// initialised its own end, i.e. in the crossing hellos case, can be reused
+/// for the connection being opened.
+///
+/// The previous connection must still be in its `Init` state, be bound to the
+/// same clients, counterparty prefix and delay period, and support the version
+/// picked for the connection being opened.
+fn verify_previous_conn_end(
+    previous_conn_id_on_b: &ConnectionId,
+    previous_conn_end_on_b: &ConnectionEnd,
+    conn_end_on_b: &ConnectionEnd,
+) -> Result<(), ConnectionError> {
+    previous_conn_end_on_b.verify_state_matches(&State::Init)?;
+
+    let previous_counterparty = previous_conn_end_on_b.counterparty();
+    let counterparty = conn_end_on_b.counterparty();
+
+    if previous_conn_end_on_b.client_id() != conn_end_on_b.client_id()
+        || previous_counterparty.client_id() != counterparty.client_id()
+        || previous_counterparty.connection_id().is_some()
+        || previous_counterparty.prefix() != counterparty.prefix()
+        || previous_conn_end_on_b.delay_period() != conn_end_on_b.delay_period()
+    {
+        return Err(ConnectionError::PreviousConnectionMismatch {
+            connection_id: previous_conn_id_on_b.clone(),
+        });
+    }
+
+    for version_on_b in conn_end_on_b.versions() {
+        version_on_b.verify_is_supported(previous_conn_end_on_b.versions())?;
+    }
+
+    Ok(())
+}
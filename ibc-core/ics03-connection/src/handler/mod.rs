@@ -1,4 +1,6 @@
+mod caps;
 pub mod conn_open_ack;
 pub mod conn_open_confirm;
 pub mod conn_open_init;
 pub mod conn_open_try;
+pub mod update_connection_params;
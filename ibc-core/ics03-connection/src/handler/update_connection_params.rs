@@ -0,0 +1,29 @@
+//! Protocol logic for updating the ICS03 parameters of the host.
+//!
+//! Unlike the other ICS03 handlers, these are not triggered by an IBC message,
+//! but are meant to be called by the host, e.g. upon a governance decision.
+
+use ibc_core_connection_types::params::ConnectionParams;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::{ConnectionExecutionContext, ConnectionValidationContext};
+use ibc_primitives::prelude::*;
+
+pub fn validate<Ctx>(_ctx: &Ctx, params: &ConnectionParams) -> Result<(), ContextError>
+where
+    Ctx: ConnectionValidationContext,
+{
+    params.validate()?;
+
+    Ok(())
+}
+
+pub fn execute<Ctx>(ctx: &mut Ctx, params: ConnectionParams) -> Result<(), ContextError>
+where
+    Ctx: ConnectionExecutionContext,
+{
+    ctx.store_connection_params(params)?;
+
+    ctx.log_message("success: connection params updated".to_string())?;
+
+    Ok(())
+}
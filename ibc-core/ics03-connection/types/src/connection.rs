@@ -4,7 +4,6 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use core::time::Duration;
 use core::u64;
 
-use ibc_core_client_types::error::ClientError;
 use ibc_core_commitment_types::commitment::CommitmentPrefix;
 use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
 use ibc_primitives::prelude::*;
@@ -394,12 +393,14 @@ impl TryFrom<RawCounterparty> for Counterparty {
                 .parse()
                 .map_err(ConnectionError::InvalidIdentifier)?,
             connection_id,
-            raw_counterparty
-                .prefix
-                .ok_or(ConnectionError::MissingCounterparty)?
-                .key_prefix
-                .try_into()
-                .map_err(|_| ConnectionError::Client(ClientError::EmptyPrefix))?,
+            // Whether empty prefixes are allowed is up to the host, upon
+            // the connection handshake.
+            CommitmentPrefix::from_bytes_allow_empty(
+                raw_counterparty
+                    .prefix
+                    .ok_or(ConnectionError::MissingCounterparty)?
+                    .key_prefix,
+            ),
         ))
     }
 }
@@ -5,6 +5,7 @@ use ibc_core_client_types::{error as client_error, Height};
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
 use ibc_primitives::prelude::*;
+use ibc_primitives::utils::abci::AbciEventError;
 use ibc_primitives::{Timestamp, TimestampOverflowError};
 
 use crate::version::Version;
@@ -28,14 +29,23 @@ pub enum ConnectionError {
     EmptyVersions,
     /// single version must be negotiated on connection before opening channel
     InvalidVersionLength,
-    /// version \"`{version}`\" not supported
-    VersionNotSupported { version: Version },
-    /// no common version
-    NoCommonVersion,
+    /// version \"`{version}`\" not supported, supported versions: `{supported:?}`
+    VersionNotSupported {
+        version: Version,
+        supported: Vec<Version>,
+    },
+    /// no common version between the supported versions `{supported:?}` and the proposed versions `{proposed:?}`
+    NoCommonVersion {
+        supported: Vec<Version>,
+        proposed: Vec<Version>,
+    },
     /// empty supported features
     EmptyFeatures,
-    /// feature \"`{feature}`\" not supported
-    FeatureNotSupported { feature: String },
+    /// feature \"`{feature}`\" not supported, supported features: `{supported:?}`
+    FeatureNotSupported {
+        feature: String,
+        supported: Vec<String>,
+    },
     /// no common features
     NoCommonFeatures,
     /// missing proof height
@@ -50,6 +60,8 @@ pub enum ConnectionError {
     InvalidSigner { reason: String },
     /// no connection was found for the previous connection id provided `{connection_id}`
     ConnectionNotFound { connection_id: ConnectionId },
+    /// previous connection `{connection_id}` cannot be reused for the handshake, as it does not match the connection being opened
+    PreviousConnectionMismatch { connection_id: ConnectionId },
     /// invalid counterparty
     InvalidCounterparty,
     /// missing counterparty
@@ -81,12 +93,27 @@ pub enum ConnectionError {
     },
     /// timestamp overflowed error: `{0}`
     TimestampOverflow(TimestampOverflowError),
+    /// client `{client_id}` already has the maximum of `{max_connections}` connections allowed by the host
+    MaxConnectionsPerClientReached {
+        client_id: ClientId,
+        max_connections: u64,
+    },
+    /// invalid connection params: `{description}`
+    InvalidConnectionParams { description: String },
     /// connection counter overflow error
     CounterOverflow,
+    /// invalid connection event: `{0}`
+    InvalidEvent(AbciEventError),
     /// other error: `{description}`
     Other { description: String },
 }
 
+impl From<AbciEventError> for ConnectionError {
+    fn from(e: AbciEventError) -> Self {
+        Self::InvalidEvent(e)
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for ConnectionError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -101,6 +128,7 @@ impl std::error::Error for ConnectionError {
             } => Some(e),
             Self::InvalidIdentifier(e) => Some(e),
             Self::TimestampOverflow(e) => Some(e),
+            Self::InvalidEvent(e) => Some(e),
             _ => None,
         }
     }
@@ -2,13 +2,16 @@
 
 use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
 use ibc_primitives::prelude::*;
+use ibc_primitives::utils::abci::{AbciEventError, EventAttributes};
 use tendermint::abci;
 
+use crate::error::ConnectionError;
+
 /// Connection event types
-const CONNECTION_OPEN_INIT_EVENT: &str = "connection_open_init";
-const CONNECTION_OPEN_TRY_EVENT: &str = "connection_open_try";
-const CONNECTION_OPEN_ACK_EVENT: &str = "connection_open_ack";
-const CONNECTION_OPEN_CONFIRM_EVENT: &str = "connection_open_confirm";
+pub const CONNECTION_OPEN_INIT_EVENT: &str = "connection_open_init";
+pub const CONNECTION_OPEN_TRY_EVENT: &str = "connection_open_try";
+pub const CONNECTION_OPEN_ACK_EVENT: &str = "connection_open_ack";
+pub const CONNECTION_OPEN_CONFIRM_EVENT: &str = "connection_open_confirm";
 
 /// The content of the `key` field for the attribute containing the connection identifier.
 pub const CONN_ID_ATTRIBUTE_KEY: &str = "connection_id";
@@ -67,6 +70,20 @@ impl From<Attributes> for Vec<abci::EventAttribute> {
     }
 }
 
+impl TryFrom<EventAttributes<'_>> for Attributes {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: EventAttributes<'_>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            connection_id: attributes.parse(CONN_ID_ATTRIBUTE_KEY)?,
+            client_id: attributes.parse(CLIENT_ID_ATTRIBUTE_KEY)?,
+            counterparty_connection_id: attributes
+                .parse_optional(COUNTERPARTY_CONN_ID_ATTRIBUTE_KEY)?,
+            counterparty_client_id: attributes.parse(COUNTERPARTY_CLIENT_ID_ATTRIBUTE_KEY)?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -125,6 +142,16 @@ impl From<OpenInit> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenInit {
+    type Error = ConnectionError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        Ok(Self(
+            EventAttributes::new(&event, CONNECTION_OPEN_INIT_EVENT)?.try_into()?,
+        ))
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -184,6 +211,16 @@ impl From<OpenTry> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenTry {
+    type Error = ConnectionError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        Ok(Self(
+            EventAttributes::new(&event, CONNECTION_OPEN_TRY_EVENT)?.try_into()?,
+        ))
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -243,6 +280,16 @@ impl From<OpenAck> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenAck {
+    type Error = ConnectionError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        Ok(Self(
+            EventAttributes::new(&event, CONNECTION_OPEN_ACK_EVENT)?.try_into()?,
+        ))
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -302,6 +349,16 @@ impl From<OpenConfirm> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenConfirm {
+    type Error = ConnectionError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        Ok(Self(
+            EventAttributes::new(&event, CONNECTION_OPEN_CONFIRM_EVENT)?.try_into()?,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -20,6 +20,7 @@ pub use connection::*;
 pub mod error;
 pub mod events;
 pub mod msgs;
+pub mod params;
 pub mod version;
 
 /// Re-exports ICS-03 proto types from the `ibc-proto` crate for added
@@ -45,7 +45,9 @@ pub struct MsgConnectionOpenTry {
     pub proof_consensus_state_of_b: Option<CommitmentProofBytes>,
 
     #[deprecated(since = "0.22.0")]
-    /// Only kept here for proper conversion to/from the raw type
+    /// Identifier of the connection the host initialised itself, to be reused
+    /// in the crossing hellos case, i.e. when both chains initialised their
+    /// end of the connection. Left empty otherwise.
     pub previous_connection_id: String,
 }
 
@@ -0,0 +1,132 @@
+//! Definition of the ICS-03 parameters of a host chain.
+
+use core::time::Duration;
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::connection::v1::Params as RawConnectionParams;
+
+use crate::error::ConnectionError;
+
+/// The maximum expected time per block used by default, matching ibc-go.
+pub const DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK: Duration = Duration::from_secs(30);
+
+/// Defines the ICS-03 parameters of a host chain, as stored by the host and
+/// consulted when enforcing the delay period of connections.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnectionParams {
+    /// The maximum expected time per block on the host, used to convert the
+    /// delay period of a connection into a number of blocks.
+    pub max_expected_time_per_block: Duration,
+}
+
+impl ConnectionParams {
+    pub fn new(max_expected_time_per_block: Duration) -> Result<Self, ConnectionError> {
+        let params = Self {
+            max_expected_time_per_block,
+        };
+
+        params.validate()?;
+
+        Ok(params)
+    }
+
+    /// Checks that the maximum expected time per block is non-zero and fits
+    /// in the nanoseconds of the raw parameters.
+    pub fn validate(&self) -> Result<(), ConnectionError> {
+        if self.max_expected_time_per_block.is_zero() {
+            return Err(ConnectionError::InvalidConnectionParams {
+                description: "max expected time per block cannot be zero".to_string(),
+            });
+        }
+
+        self.max_expected_time_per_block_nanos()?;
+
+        Ok(())
+    }
+
+    /// Returns the maximum expected time per block in nanoseconds, failing if
+    /// it does not fit in a `u64`.
+    pub fn max_expected_time_per_block_nanos(&self) -> Result<u64, ConnectionError> {
+        let nanos = self.max_expected_time_per_block.as_nanos();
+
+        u64::try_from(nanos).map_err(|_| ConnectionError::InvalidConnectionParams {
+            description: format!("max expected time per block too long: {nanos} nanos"),
+        })
+    }
+}
+
+impl Default for ConnectionParams {
+    fn default() -> Self {
+        Self {
+            max_expected_time_per_block: DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK,
+        }
+    }
+}
+
+impl TryFrom<RawConnectionParams> for ConnectionParams {
+    type Error = ConnectionError;
+
+    fn try_from(raw: RawConnectionParams) -> Result<Self, Self::Error> {
+        Self::new(Duration::from_nanos(raw.max_expected_time_per_block))
+    }
+}
+
+impl TryFrom<ConnectionParams> for RawConnectionParams {
+    type Error = ConnectionError;
+
+    fn try_from(params: ConnectionParams) -> Result<Self, Self::Error> {
+        Ok(RawConnectionParams {
+            max_expected_time_per_block: params.max_expected_time_per_block_nanos()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connection_params() {
+        assert!(ConnectionParams::default().validate().is_ok());
+        assert!(ConnectionParams::new(Duration::ZERO).is_err());
+
+        let params = ConnectionParams::new(Duration::from_millis(1500)).unwrap();
+        let raw = RawConnectionParams::try_from(params.clone()).unwrap();
+        assert_eq!(raw.max_expected_time_per_block, 1_500_000_000);
+        assert_eq!(ConnectionParams::try_from(raw).unwrap(), params);
+
+        assert!(ConnectionParams::try_from(RawConnectionParams {
+            max_expected_time_per_block: 0
+        })
+        .is_err());
+
+        let too_long = ConnectionParams {
+            max_expected_time_per_block: Duration::from_secs(u64::MAX),
+        };
+        assert!(too_long.validate().is_err());
+        assert!(RawConnectionParams::try_from(too_long).is_err());
+    }
+    #[test]
+    fn connection_params_above_u64_nanos() {
+        let max = ConnectionParams::new(Duration::from_nanos(u64::MAX)).unwrap();
+        let raw = RawConnectionParams::try_from(max.clone()).unwrap();
+        assert_eq!(raw.max_expected_time_per_block, u64::MAX);
+        assert_eq!(ConnectionParams::try_from(raw).unwrap(), max);
+
+        let above_max = Duration::from_nanos(u64::MAX) + Duration::from_nanos(1);
+        assert!(matches!(
+            ConnectionParams::new(above_max),
+            Err(ConnectionError::InvalidConnectionParams { .. })
+        ));
+
+        let too_long = ConnectionParams {
+            max_expected_time_per_block: above_max,
+        };
+        assert!(matches!(
+            RawConnectionParams::try_from(too_long),
+            Err(ConnectionError::InvalidConnectionParams { .. })
+        ));
+    }
+}
@@ -33,6 +33,27 @@ pub struct Version {
 }
 
 impl Version {
+    /// Constructs a version out of its identifier and the features it
+    /// supports, e.g. for hosts supporting other connection versions than
+    /// the [`compatibles`](Version::compatibles) ones.
+    pub fn new(identifier: String, features: Vec<String>) -> Result<Self, ConnectionError> {
+        RawVersion {
+            identifier,
+            features,
+        }
+        .try_into()
+    }
+
+    /// Returns the identifier of this version.
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Returns the features supported by this version.
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
     /// Checks whether the version has a matching version identifier and its
     /// feature set is a subset of the supported features
     pub fn verify_is_supported(
@@ -54,7 +75,10 @@ impl Version {
     /// Checks whether the given feature is supported in this version
     pub fn verify_feature_supported(&self, feature: String) -> Result<(), ConnectionError> {
         if !self.features.contains(&feature) {
-            return Err(ConnectionError::FeatureNotSupported { feature });
+            return Err(ConnectionError::FeatureNotSupported {
+                feature,
+                supported: self.features.clone(),
+            });
         }
         Ok(())
     }
@@ -134,7 +158,10 @@ pub fn pick_version(
     }
 
     if intersection.is_empty() {
-        return Err(ConnectionError::NoCommonVersion);
+        return Err(ConnectionError::NoCommonVersion {
+            supported: supported_versions.to_vec(),
+            proposed: counterparty_versions.to_vec(),
+        });
     }
 
     intersection.sort_by(|a, b| a.identifier.cmp(&b.identifier));
@@ -150,10 +177,11 @@ fn find_supported_version(
     supported_versions
         .iter()
         .find(|sv| sv.identifier == version.identifier)
-        .ok_or(ConnectionError::VersionNotSupported {
+        .cloned()
+        .ok_or_else(|| ConnectionError::VersionNotSupported {
             version: version.clone(),
+            supported: supported_versions.to_vec(),
         })
-        .cloned()
 }
 
 /// Returns the intersections of supported features by a host and the
@@ -185,6 +213,22 @@ mod tests {
     use crate::error::ConnectionError;
     use crate::version::{pick_version, Version};
 
+    #[test]
+    fn version_new() {
+        let version = Version::new("2".to_string(), get_dummy_features()).unwrap();
+        assert_eq!(version.identifier(), "2");
+        assert_eq!(version.features(), get_dummy_features());
+
+        assert!(matches!(
+            Version::new("".to_string(), get_dummy_features()),
+            Err(ConnectionError::EmptyVersions)
+        ));
+        assert!(matches!(
+            Version::new("2".to_string(), vec!["".to_string()]),
+            Err(ConnectionError::EmptyFeatures)
+        ));
+    }
+
     fn get_dummy_features() -> Vec<String> {
         vec!["ORDER_RANDOM".to_string(), "ORDER_UNORDERED".to_string()]
     }
@@ -368,7 +412,10 @@ mod tests {
                 name: "Disjoint versions".to_string(),
                 supported: disjoint().0,
                 counterparty: disjoint().1,
-                picked: Err(ConnectionError::NoCommonVersion),
+                picked: Err(ConnectionError::NoCommonVersion {
+                    supported: disjoint().0,
+                    proposed: disjoint().1,
+                }),
                 want_pass: false,
             },
         ];
@@ -1,19 +1,36 @@
 //! ICS4 (channel) context.
 
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
 use ibc_core_channel_types::channel::ChannelEnd;
-use ibc_core_channel_types::commitment::PacketCommitment;
+use ibc_core_channel_types::commitment::{
+    compute_ack_commitment_with, AcknowledgementCommitment, PacketCommitment,
+};
+use ibc_core_channel_types::packet::Receipt;
 use ibc_core_client::context::prelude::*;
 use ibc_core_connection::types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
 use ibc_core_host::types::identifiers::{ConnectionId, Sequence};
-use ibc_core_host::types::path::{ChannelEndPath, CommitmentPath, SeqSendPath};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::types::limits::HostLimits;
+use ibc_core_host::types::path::{
+    AckPath, ChannelEndPath, CommitmentPath, ReceiptPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host::{
+    ChannelValidationContext, ConnectionEndValidationContext, PacketExecutionContext,
+    PacketValidationContext,
+};
 use ibc_primitives::prelude::*;
+use ibc_primitives::HostCryptoProvider;
 
 /// Methods required in send packet validation, to be implemented by the host
+///
+/// Derived from the connection end, channel and packet contexts of the host,
+/// so that hosts which only send packets, e.g. contracts, need not implement
+/// the client registry nor the connection handshake methods.
 pub trait SendPacketValidationContext {
     type V: ClientValidationContext;
+    /// The provider hashing the commitments of the sent packets.
+    type CryptoProvider: HostCryptoProvider;
 
     /// Retrieve the context that implements all clients' `ValidationContext`.
     fn get_client_validation_context(&self) -> &Self::V;
@@ -26,13 +43,20 @@ pub trait SendPacketValidationContext {
 
     fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
         -> Result<Sequence, ContextError>;
+
+    /// Returns the limits of the host, of which the maximum packet data size
+    /// is enforced on the sent packets. No limits are set by default.
+    fn host_limits(&self) -> Result<HostLimits, ContextError> {
+        Ok(HostLimits::unlimited())
+    }
 }
 
 impl<T> SendPacketValidationContext for T
 where
-    T: ValidationContext,
+    T: ConnectionEndValidationContext + ChannelValidationContext + PacketValidationContext,
 {
     type V = T::V;
+    type CryptoProvider = T::CryptoProvider;
 
     fn get_client_validation_context(&self) -> &Self::V {
         self.get_client_validation_context()
@@ -52,6 +76,10 @@ where
     ) -> Result<Sequence, ContextError> {
         self.get_next_sequence_send(seq_send_path)
     }
+
+    fn host_limits(&self) -> Result<HostLimits, ContextError> {
+        self.host_limits()
+    }
 }
 
 /// Methods required in send packet execution, to be implemented by the host
@@ -77,7 +105,7 @@ pub trait SendPacketExecutionContext: SendPacketValidationContext {
 
 impl<T> SendPacketExecutionContext for T
 where
-    T: ExecutionContext,
+    T: ConnectionEndValidationContext + ChannelValidationContext + PacketExecutionContext,
 {
     fn store_next_sequence_send(
         &mut self,
@@ -103,3 +131,108 @@ where
         self.log_message(message)
     }
 }
+
+/// Methods required in write acknowledgement validation, to be implemented by
+/// the host
+///
+/// Hosts that only run applications acknowledging their packets
+/// asynchronously implement this trait, or the [`ChannelValidationContext`]
+/// and [`PacketValidationContext`] it is derived from, instead of the whole
+/// [`ValidationContext`](ibc_core_host::ValidationContext).
+pub trait WriteAckValidationContext {
+    /// The provider hashing the commitments of the written acknowledgements.
+    type CryptoProvider: HostCryptoProvider;
+
+    /// Returns the ChannelEnd for the given `port_id` and `chan_id`.
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError>;
+
+    /// Returns the sequence number for the next packet to be received for the
+    /// given store path.
+    fn get_next_sequence_recv(&self, seq_recv_path: &SeqRecvPath)
+        -> Result<Sequence, ContextError>;
+
+    /// Returns the packet receipt for the given store path.
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError>;
+
+    /// Returns the packet acknowledgement for the given store path.
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError>;
+
+    /// Computes the commitment of the given acknowledgement.
+    fn ack_commitment(&self, ack: &Acknowledgement) -> AcknowledgementCommitment {
+        compute_ack_commitment_with::<Self::CryptoProvider>(ack)
+    }
+}
+
+impl<T> WriteAckValidationContext for T
+where
+    T: ChannelValidationContext + PacketValidationContext,
+{
+    type CryptoProvider = T::CryptoProvider;
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        self.channel_end(channel_end_path)
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.get_next_sequence_recv(seq_recv_path)
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.get_packet_receipt(receipt_path)
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.get_packet_acknowledgement(ack_path)
+    }
+
+    fn ack_commitment(&self, ack: &Acknowledgement) -> AcknowledgementCommitment {
+        self.ack_commitment(ack)
+    }
+}
+
+/// Methods required in write acknowledgement execution, to be implemented by
+/// the host
+pub trait WriteAckExecutionContext: WriteAckValidationContext {
+    /// Stores the given packet acknowledgement at the given store path.
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError>;
+
+    /// Ibc events
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
+
+    /// Logging facility
+    fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+}
+
+impl<T> WriteAckExecutionContext for T
+where
+    T: ChannelValidationContext + PacketExecutionContext,
+{
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.store_packet_acknowledgement(ack_path, ack_commitment)
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.emit_ibc_event(event)
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.log_message(message)
+    }
+}
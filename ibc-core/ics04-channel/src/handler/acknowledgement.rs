@@ -1,5 +1,5 @@
 use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
-use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc_core_channel_types::commitment::compute_packet_commitment_with;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::AcknowledgePacket;
 use ibc_core_channel_types::msgs::MsgAcknowledgement;
@@ -11,17 +11,27 @@ use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
     AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, SeqAckPath,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{
+    ChannelValidationContext, ClientRegistryValidationContext, ConnectionEndValidationContext,
+    PacketExecutionContext, PacketValidationContext,
+};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::caps::verify_ack_size;
+use super::client_status::verify_client_usable_for_packets;
+use super::multihop::CounterpartyStore;
+
 pub fn acknowledgement_packet_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
     msg: MsgAcknowledgement,
 ) -> Result<(), ContextError>
 where
-    ValCtx: ValidationContext,
+    ValCtx: ClientRegistryValidationContext
+        + ConnectionEndValidationContext
+        + ChannelValidationContext
+        + PacketValidationContext,
 {
     validate(ctx_a, &msg)?;
 
@@ -36,7 +46,7 @@ pub fn acknowledgement_packet_execute<ExecCtx>(
     msg: MsgAcknowledgement,
 ) -> Result<(), ContextError>
 where
-    ExecCtx: ExecutionContext,
+    ExecCtx: ChannelValidationContext + PacketExecutionContext,
 {
     let chan_end_path_on_a =
         ChannelEndPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
@@ -105,13 +115,16 @@ where
 
 fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgAcknowledgement) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ClientRegistryValidationContext
+        + ConnectionEndValidationContext
+        + ChannelValidationContext
+        + PacketValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
     let packet = &msg.packet;
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
-    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+    let chan_end_on_a = ctx_a.channel_end_cow(&chan_end_path_on_a)?;
 
     chan_end_on_a.verify_state_matches(&ChannelState::Open)?;
 
@@ -122,8 +135,10 @@ where
 
     chan_end_on_a.verify_counterparty_matches(&counterparty)?;
 
+    verify_ack_size(&ctx_a.host_limits()?, packet, &msg.acknowledgement)?;
+
     let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
-    let conn_end_on_a = ctx_a.connection_end(conn_id_on_a)?;
+    let conn_end_on_a = ctx_a.connection_end_cow(conn_id_on_a)?;
 
     conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
 
@@ -140,7 +155,7 @@ where
     };
 
     if commitment_on_a
-        != compute_packet_commitment(
+        != compute_packet_commitment_with::<Ctx::CryptoProvider>(
             &packet.data,
             &packet.timeout_height_on_b,
             &packet.timeout_timestamp_on_b,
@@ -172,9 +187,7 @@ where
 
         let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
 
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
+        verify_client_usable_for_packets(ctx_a, &client_state_of_b_on_a, client_id_on_a)?;
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
 
         let client_cons_state_path_on_a = ClientConsensusStatePath::new(
@@ -184,18 +197,26 @@ where
         );
         let consensus_state_of_b_on_a =
             client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let ack_commitment = compute_ack_commitment(&msg.acknowledgement);
+        let ack_commitment = ctx_a.ack_commitment(&msg.acknowledgement);
         let ack_path_on_b =
             AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
 
         verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
 
+        let store_of_b = CounterpartyStore::new(
+            &client_state_of_b_on_a,
+            &consensus_state_of_b_on_a,
+            msg.proof_height_on_b,
+            &conn_end_on_a,
+            chan_end_on_a.connection_hops(),
+            &msg.proof_acked_on_b,
+        )
+        .map_err(PacketError::Channel)?;
+
         // Verify the proof for the packet against the chain store.
-        client_state_of_b_on_a
+        store_of_b
             .verify_membership(
-                conn_end_on_a.counterparty().prefix(),
-                &msg.proof_acked_on_b,
-                consensus_state_of_b_on_a.root(),
+                &client_state_of_b_on_a,
                 Path::Ack(ack_path_on_b),
                 ack_commitment.into_vec(),
             )
@@ -0,0 +1,90 @@
+//! Enforcement of the caps the host puts on the number of channels opened on
+//! top of a connection and on the size of packet payloads.
+
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{CapReached, CapScope};
+use ibc_core_host::types::identifiers::ConnectionId;
+use ibc_core_host::types::limits::HostLimits;
+use ibc_core_host::{ChannelExecutionContext, ChannelValidationContext};
+
+/// Checks that the host allows another channel to be opened on top of the
+/// given connection.
+pub(crate) fn verify_channel_cap<Ctx>(ctx: &Ctx, conn_id: &ConnectionId) -> Result<(), ContextError>
+where
+    Ctx: ChannelValidationContext,
+{
+    if let Some(max_channels) = ctx.host_limits()?.max_channels_per_connection {
+        if ctx.connection_channel_count(conn_id)? >= max_channels {
+            return Err(ChannelError::MaxChannelsPerConnectionReached {
+                connection_id: conn_id.clone(),
+                max_channels,
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Emits a `CapReached` event if the channel just opened on top of the given
+/// connection exhausted the cap of the host.
+pub(crate) fn emit_channel_cap_reached<Ctx>(
+    ctx: &mut Ctx,
+    conn_id: &ConnectionId,
+) -> Result<(), ContextError>
+where
+    Ctx: ChannelExecutionContext,
+{
+    if let Some(max_channels) = ctx.host_limits()?.max_channels_per_connection {
+        if ctx.connection_channel_count(conn_id)? >= max_channels {
+            let scope = CapScope::ChannelsPerConnection(conn_id.clone());
+            ctx.emit_ibc_event(CapReached::new(scope, max_channels).into())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the data of the given packet fits in the size allowed by the
+/// host.
+pub(crate) fn verify_packet_data_size(
+    limits: &HostLimits,
+    packet: &Packet,
+) -> Result<(), PacketError> {
+    if let Some(max_size) = limits.max_packet_data_size {
+        let size = packet.data.len() as u64;
+        if size > max_size {
+            return Err(PacketError::PacketDataTooLarge {
+                sequence: packet.seq_on_a,
+                size,
+                max_size,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the acknowledgement of the given packet fits in the size
+/// allowed by the host.
+pub(crate) fn verify_ack_size(
+    limits: &HostLimits,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+) -> Result<(), PacketError> {
+    if let Some(max_size) = limits.max_ack_size {
+        let size = acknowledgement.as_bytes().len() as u64;
+        if size > max_size {
+            return Err(PacketError::AcknowledgementTooLarge {
+                sequence: packet.seq_on_a,
+                size,
+                max_size,
+            });
+        }
+    }
+
+    Ok(())
+}
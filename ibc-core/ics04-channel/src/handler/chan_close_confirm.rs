@@ -9,18 +9,24 @@ use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{
+    ChannelExecutionContext, ChannelValidationContext, ConnectionEndValidationContext,
+    PacketExecutionContext,
+};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::cleanup::delete_closed_channel_state;
+use super::multihop::CounterpartyStore;
+
 pub fn chan_close_confirm_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
     msg: MsgChannelCloseConfirm,
 ) -> Result<(), ContextError>
 where
-    ValCtx: ValidationContext,
+    ValCtx: ConnectionEndValidationContext + ChannelValidationContext,
 {
     validate(ctx_b, &msg)?;
 
@@ -35,7 +41,7 @@ pub fn chan_close_confirm_execute<ExecCtx>(
     msg: MsgChannelCloseConfirm,
 ) -> Result<(), ContextError>
 where
-    ExecCtx: ExecutionContext,
+    ExecCtx: ChannelExecutionContext + PacketExecutionContext,
 {
     let extras = module.on_chan_close_confirm_execute(&msg.port_id_on_b, &msg.chan_id_on_b)?;
     let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
@@ -49,6 +55,9 @@ where
             chan_end_on_b
         };
         ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b)?;
+
+        // The counterparty channel was proven closed.
+        delete_closed_channel_state(ctx_b, &chan_end_path_on_b, true)?;
     }
 
     // emit events and logs
@@ -93,7 +102,7 @@ where
 
 fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelCloseConfirm) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ConnectionEndValidationContext + ChannelValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
@@ -128,34 +137,34 @@ where
         );
         let consensus_state_of_a_on_b =
             client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-        let prefix_on_a = conn_end_on_b.counterparty().prefix();
+        let store_of_a = CounterpartyStore::new(
+            &client_state_of_a_on_b,
+            &consensus_state_of_a_on_b,
+            msg.proof_height_on_a,
+            &conn_end_on_b,
+            chan_end_on_b.connection_hops(),
+            &msg.proof_chan_end_on_a,
+        )?;
         let port_id_on_a = &chan_end_on_b.counterparty().port_id;
         let chan_id_on_a = chan_end_on_b
             .counterparty()
             .channel_id()
             .ok_or(ChannelError::MissingCounterparty)?;
-        let conn_id_on_a = conn_end_on_b.counterparty().connection_id().ok_or(
-            ChannelError::UndefinedConnectionCounterparty {
-                connection_id: chan_end_on_b.connection_hops()[0].clone(),
-            },
-        )?;
 
         let expected_chan_end_on_a = ChannelEnd::new(
             ChannelState::Closed,
             *chan_end_on_b.ordering(),
             Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
-            vec![conn_id_on_a.clone()],
+            store_of_a.connection_hops.clone(),
             chan_end_on_b.version().clone(),
         )?;
         let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_a_on_b
+        store_of_a
             .verify_membership(
-                prefix_on_a,
-                &msg.proof_chan_end_on_a,
-                consensus_state_of_a_on_b.root(),
+                &client_state_of_a_on_b,
                 Path::ChannelEnd(chan_end_path_on_a),
                 expected_chan_end_on_a.encode_vec(),
             )
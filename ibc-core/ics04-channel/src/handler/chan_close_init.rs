@@ -8,21 +8,26 @@ use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::ChannelEndPath;
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{
+    ChannelExecutionContext, ChannelValidationContext, ConnectionEndValidationContext,
+    PacketExecutionContext,
+};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::cleanup::delete_closed_channel_state;
+
 pub fn chan_close_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
     msg: MsgChannelCloseInit,
 ) -> Result<(), ContextError>
 where
-    ValCtx: ValidationContext,
+    ValCtx: ConnectionEndValidationContext + ChannelValidationContext,
 {
     validate(ctx_a, &msg)?;
 
-    module.on_chan_close_init_validate(&msg.port_id_on_a, &msg.chan_id_on_a)?;
+    module.on_chan_close_init_validate(&msg.port_id_on_a, &msg.chan_id_on_a, &msg.signer)?;
 
     Ok(())
 }
@@ -33,7 +38,7 @@ pub fn chan_close_init_execute<ExecCtx>(
     msg: MsgChannelCloseInit,
 ) -> Result<(), ContextError>
 where
-    ExecCtx: ExecutionContext,
+    ExecCtx: ChannelExecutionContext + PacketExecutionContext,
 {
     let extras = module.on_chan_close_init_execute(&msg.port_id_on_a, &msg.chan_id_on_a)?;
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
@@ -48,6 +53,8 @@ where
         };
 
         ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+
+        delete_closed_channel_state(ctx_a, &chan_end_path_on_a, false)?;
     }
 
     // emit events and logs
@@ -92,7 +99,7 @@ where
 
 fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelCloseInit) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ConnectionEndValidationContext + ChannelValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
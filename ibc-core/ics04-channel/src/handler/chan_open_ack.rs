@@ -8,18 +8,22 @@ use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{
+    ChannelExecutionContext, ChannelValidationContext, ConnectionEndValidationContext,
+};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::multihop::CounterpartyStore;
+
 pub fn chan_open_ack_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
     msg: MsgChannelOpenAck,
 ) -> Result<(), ContextError>
 where
-    ValCtx: ValidationContext,
+    ValCtx: ConnectionEndValidationContext + ChannelValidationContext,
 {
     validate(ctx_a, &msg)?;
 
@@ -34,7 +38,7 @@ pub fn chan_open_ack_execute<ExecCtx>(
     msg: MsgChannelOpenAck,
 ) -> Result<(), ContextError>
 where
-    ExecCtx: ExecutionContext,
+    ExecCtx: ChannelExecutionContext,
 {
     let extras =
         module.on_chan_open_ack_execute(&msg.port_id_on_a, &msg.chan_id_on_a, &msg.version_on_b)?;
@@ -88,7 +92,7 @@ where
 
 fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelOpenAck) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ConnectionEndValidationContext + ChannelValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
@@ -123,13 +127,15 @@ where
         );
         let consensus_state_of_b_on_a =
             client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let prefix_on_b = conn_end_on_a.counterparty().prefix();
-        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
-        let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
-            ChannelError::UndefinedConnectionCounterparty {
-                connection_id: chan_end_on_a.connection_hops()[0].clone(),
-            },
+        let store_of_b = CounterpartyStore::new(
+            &client_state_of_b_on_a,
+            &consensus_state_of_b_on_a,
+            msg.proof_height_on_b,
+            &conn_end_on_a,
+            chan_end_on_a.connection_hops(),
+            &msg.proof_chan_end_on_b,
         )?;
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
 
         let expected_chan_end_on_b = ChannelEnd::new(
             ChannelState::TryOpen,
@@ -137,18 +143,16 @@ where
             // fine to use A's ordering here
             *chan_end_on_a.ordering(),
             Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
-            vec![conn_id_on_b.clone()],
+            store_of_b.connection_hops.clone(),
             msg.version_on_b.clone(),
         )?;
         let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, &msg.chan_id_on_b);
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_b_on_a
+        store_of_b
             .verify_membership(
-                prefix_on_b,
-                &msg.proof_chan_end_on_b,
-                consensus_state_of_b_on_a.root(),
+                &client_state_of_b_on_a,
                 Path::ChannelEnd(chan_end_path_on_b),
                 expected_chan_end_on_b.encode_vec(),
             )
@@ -8,17 +8,22 @@ use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::ChannelId;
 use ibc_core_host::types::path::{ChannelEndPath, SeqAckPath, SeqRecvPath, SeqSendPath};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{
+    ChannelExecutionContext, ChannelValidationContext, ConnectionEndValidationContext,
+    PacketExecutionContext,
+};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::caps::{emit_channel_cap_reached, verify_channel_cap};
+
 pub fn chan_open_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
     msg: MsgChannelOpenInit,
 ) -> Result<(), ContextError>
 where
-    ValCtx: ValidationContext,
+    ValCtx: ConnectionEndValidationContext + ChannelValidationContext,
 {
     validate(ctx_a, &msg)?;
     let chan_id_on_a = ChannelId::new(ctx_a.channel_counter()?);
@@ -41,7 +46,7 @@ pub fn chan_open_init_execute<ExecCtx>(
     msg: MsgChannelOpenInit,
 ) -> Result<(), ContextError>
 where
-    ExecCtx: ExecutionContext,
+    ExecCtx: ChannelExecutionContext + PacketExecutionContext,
 {
     let chan_id_on_a = ChannelId::new(ctx_a.channel_counter()?);
     let (extras, version) = module.on_chan_open_init_execute(
@@ -89,12 +94,14 @@ where
             msg.port_id_on_a.clone(),
             chan_id_on_a.clone(),
             msg.port_id_on_b,
-            conn_id_on_a,
+            conn_id_on_a.clone(),
             version,
         ));
         ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_a.emit_ibc_event(core_event)?;
 
+        emit_channel_cap_reached(ctx_a, &conn_id_on_a)?;
+
         for module_event in extras.events {
             ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
         }
@@ -109,7 +116,7 @@ where
 
 fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelOpenInit) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ConnectionEndValidationContext + ChannelValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
@@ -117,6 +124,8 @@ where
     // An IBC connection running on the local (host) chain should exist.
     let conn_end_on_a = ctx_a.connection_end(&msg.connection_hops_on_a[0])?;
 
+    verify_channel_cap(ctx_a, &msg.connection_hops_on_a[0])?;
+
     // Note: Not needed check if the connection end is OPEN. Optimistic channel handshake is allowed.
 
     let client_id_on_a = conn_end_on_a.client_id();
@@ -12,18 +12,24 @@ use ibc_core_host::types::identifiers::ChannelId;
 use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, Path, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{
+    ChannelExecutionContext, ChannelValidationContext, ConnectionEndValidationContext,
+    PacketExecutionContext,
+};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::caps::{emit_channel_cap_reached, verify_channel_cap};
+use super::multihop::CounterpartyStore;
+
 pub fn chan_open_try_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
     msg: MsgChannelOpenTry,
 ) -> Result<(), ContextError>
 where
-    ValCtx: ValidationContext,
+    ValCtx: ConnectionEndValidationContext + ChannelValidationContext,
 {
     validate(ctx_b, &msg)?;
 
@@ -47,7 +53,7 @@ pub fn chan_open_try_execute<ExecCtx>(
     msg: MsgChannelOpenTry,
 ) -> Result<(), ContextError>
 where
-    ExecCtx: ExecutionContext,
+    ExecCtx: ChannelExecutionContext + PacketExecutionContext,
 {
     let chan_id_on_b = ChannelId::new(ctx_b.channel_counter()?);
     let (extras, version) = module.on_chan_open_try_execute(
@@ -73,6 +79,7 @@ where
 
         let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &chan_id_on_b);
         ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b)?;
+
         ctx_b.increase_channel_counter()?;
 
         // Initialize send, recv, and ack sequence numbers.
@@ -97,12 +104,14 @@ where
             chan_id_on_b.clone(),
             msg.port_id_on_a.clone(),
             msg.chan_id_on_a.clone(),
-            conn_id_on_b,
+            conn_id_on_b.clone(),
             version,
         ));
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(core_event)?;
 
+        emit_channel_cap_reached(ctx_b, &conn_id_on_b)?;
+
         for module_event in extras.events {
             ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
         }
@@ -117,7 +126,7 @@ where
 
 fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelOpenTry) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ConnectionEndValidationContext + ChannelValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
@@ -125,6 +134,8 @@ where
 
     let conn_end_on_b = ctx_b.connection_end(&msg.connection_hops_on_b[0])?;
 
+    verify_channel_cap(ctx_b, &msg.connection_hops_on_b[0])?;
+
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
     let conn_version = conn_end_on_b.versions();
@@ -150,31 +161,31 @@ where
         );
         let consensus_state_of_a_on_b =
             client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-        let prefix_on_a = conn_end_on_b.counterparty().prefix();
+        let store_of_a = CounterpartyStore::new(
+            &client_state_of_a_on_b,
+            &consensus_state_of_a_on_b,
+            msg.proof_height_on_a,
+            &conn_end_on_b,
+            &msg.connection_hops_on_b,
+            &msg.proof_chan_end_on_a,
+        )?;
         let port_id_on_a = msg.port_id_on_a.clone();
         let chan_id_on_a = msg.chan_id_on_a.clone();
-        let conn_id_on_a = conn_end_on_b.counterparty().connection_id().ok_or(
-            ChannelError::UndefinedConnectionCounterparty {
-                connection_id: msg.connection_hops_on_b[0].clone(),
-            },
-        )?;
 
         let expected_chan_end_on_a = ChannelEnd::new(
             ChannelState::Init,
             msg.ordering,
             Counterparty::new(msg.port_id_on_b.clone(), None),
-            vec![conn_id_on_a.clone()],
+            store_of_a.connection_hops.clone(),
             msg.version_supported_on_a.clone(),
         )?;
         let chan_end_path_on_a = ChannelEndPath::new(&port_id_on_a, &chan_id_on_a);
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_a_on_b
+        store_of_a
             .verify_membership(
-                prefix_on_a,
-                &msg.proof_chan_end_on_a,
-                consensus_state_of_a_on_b.root(),
+                &client_state_of_a_on_b,
                 Path::ChannelEnd(chan_end_path_on_a),
                 expected_chan_end_on_a.encode_vec(),
             )
@@ -0,0 +1,38 @@
+//! Deletion of the state of closed channels that no handler reads anymore.
+
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::path::{ChannelEndPath, SeqAckPath, SeqSendPath};
+use ibc_core_host::PacketExecutionContext;
+
+/// Deletes the state of the given channel, just closed, that has gone stale.
+///
+/// No packet can be sent or acknowledged on a closed channel, so its
+/// `nextSequenceSend` and `nextSequenceAck` are deleted. If the counterparty
+/// channel is known to be closed as well, the acknowledgements of the packets
+/// received on the channel are deleted too.
+///
+/// Packet receipts and `nextSequenceRecv` are kept, as the counterparty still
+/// proves against them that its packets were not received, to time them out
+/// on close.
+pub(crate) fn delete_closed_channel_state<ExecCtx>(
+    ctx: &mut ExecCtx,
+    chan_end_path: &ChannelEndPath,
+    counterparty_closed: bool,
+) -> Result<(), ContextError>
+where
+    ExecCtx: PacketExecutionContext,
+{
+    let ChannelEndPath(port_id, chan_id) = chan_end_path;
+
+    let seq_send_path = SeqSendPath::new(port_id, chan_id);
+    ctx.delete_next_sequence_send(&seq_send_path)?;
+
+    let seq_ack_path = SeqAckPath::new(port_id, chan_id);
+    ctx.delete_next_sequence_ack(&seq_ack_path)?;
+
+    if counterparty_closed {
+        ctx.delete_packet_acknowledgements(chan_end_path)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! Verification of the status of the clients verifying the proofs of packet
+//! messages, honoring the client expiry grace period of the host.
+
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::{ClientRegistryValidationContext, ClientStateRef};
+
+/// Checks that the given client is active, or expired for no longer than the
+/// client expiry grace period of the host, if any.
+pub(crate) fn verify_client_usable_for_packets<Ctx>(
+    ctx: &Ctx,
+    client_state: &ClientStateRef<Ctx>,
+    client_id: &ClientId,
+) -> Result<(), ContextError>
+where
+    Ctx: ClientRegistryValidationContext,
+{
+    let client_val_ctx = ctx.get_client_validation_context();
+    let status = client_state.status(client_val_ctx, client_id)?;
+
+    if status.is_expired() {
+        if let Some(grace_period) = ctx.client_expiry_grace_period()? {
+            if client_state.is_within_expiry_grace_period(
+                client_val_ctx,
+                client_id,
+                grace_period,
+            )? {
+                return Ok(());
+            }
+        }
+    }
+
+    Ok(status.verify_is_active()?)
+}
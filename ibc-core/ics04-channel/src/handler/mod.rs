@@ -1,15 +1,22 @@
 //! This module implements the processing logic for ICS4 (channel) messages.
 mod acknowledgement;
+mod caps;
 mod chan_close_confirm;
 mod chan_close_init;
 mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod cleanup;
+mod client_status;
+mod multihop;
+mod prune_acknowledgements;
 mod recv_packet;
 mod send_packet;
 mod timeout;
+mod timeout_batch;
 mod timeout_on_close;
+mod write_acknowledgement;
 
 pub use acknowledgement::*;
 pub use chan_close_confirm::*;
@@ -18,7 +25,10 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+pub use prune_acknowledgements::*;
 pub use recv_packet::*;
 pub use send_packet::*;
 pub use timeout::*;
+pub use timeout_batch::*;
 pub use timeout_on_close::*;
+pub use write_acknowledgement::*;
@@ -0,0 +1,225 @@
+//! Verification of the proofs of channels routed through intermediate chains,
+//! as per [ICS-33](https://github.com/cosmos/ibc/tree/main/spec/core/ics-033-multi-hop).
+
+use core::str::FromStr;
+
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::multihop::MsgMultihopProofs;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_commitment_types::merkle::apply_prefix;
+use ibc_core_commitment_types::proto::v1::MerklePath;
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_host::types::identifiers::ConnectionId;
+use ibc_core_host::types::path::{ConnectionPath, Path};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+use ibc_primitives::Timestamp;
+
+/// The store of the counterparty chain of a channel, as seen through the
+/// proofs of a channel or packet message.
+///
+/// For single-hop channels, this is the store tracked by the client of the
+/// connection of the channel. For multi-hop channels, this is the store of the
+/// last chain of the route, whose consensus state has been proven along the
+/// intermediate chains.
+pub(crate) struct CounterpartyStore {
+    prefix: CommitmentPrefix,
+    root: CommitmentRoot,
+    proof: CommitmentProofBytes,
+    /// The prefixed key the proof was built for, if the channel is multi-hop.
+    key_path: Option<MerklePath>,
+    /// The height of the counterparty chain the proofs were built at.
+    pub height: Height,
+    /// The timestamp of the counterparty chain at `height`.
+    pub timestamp: Timestamp,
+    /// The connection hops of the channel end on the counterparty chain.
+    pub connection_hops: Vec<ConnectionId>,
+}
+
+impl CounterpartyStore {
+    /// Builds the counterparty store out of the consensus state of the first
+    /// hop of the channel at `proof_height` and the proof of the message.
+    ///
+    /// If the channel has more than one hop, the proof is expected to be an
+    /// encoded [`MsgMultihopProofs`], which is verified up to the last chain.
+    pub fn new<CS, ConsS>(
+        client_state: &CS,
+        consensus_state: &ConsS,
+        proof_height: Height,
+        conn_end: &ConnectionEnd,
+        connection_hops: &[ConnectionId],
+        proof: &CommitmentProofBytes,
+    ) -> Result<Self, ChannelError>
+    where
+        CS: ClientStateCommon,
+        ConsS: ConsensusState,
+    {
+        let counterparty_conn_id = |conn_id: &ConnectionId, conn_end: &ConnectionEnd| {
+            conn_end
+                .counterparty()
+                .connection_id()
+                .cloned()
+                .ok_or_else(|| ChannelError::UndefinedConnectionCounterparty {
+                    connection_id: conn_id.clone(),
+                })
+        };
+
+        let mut store = Self {
+            prefix: conn_end.counterparty().prefix().clone(),
+            root: consensus_state.root().clone(),
+            proof: proof.clone(),
+            key_path: None,
+            height: proof_height,
+            timestamp: consensus_state.timestamp(),
+            connection_hops: vec![counterparty_conn_id(&connection_hops[0], conn_end)?],
+        };
+
+        if connection_hops.len() == 1 {
+            return Ok(store);
+        }
+
+        let proofs = MsgMultihopProofs::try_from(proof)?;
+
+        if proofs.connection_proofs.len() != connection_hops.len() - 1 {
+            return Err(ChannelError::InvalidMultihopProof {
+                description: format!(
+                    "expected proofs for {} intermediate chains, got {}",
+                    connection_hops.len() - 1,
+                    proofs.connection_proofs.len()
+                ),
+            });
+        }
+
+        for (hop, ((conn_id, conn_proof), cons_proof)) in connection_hops[1..]
+            .iter()
+            .zip(proofs.connection_proofs.iter())
+            .zip(proofs.consensus_proofs.iter())
+            .enumerate()
+            .map(|(i, item)| (i as u64 + 1, item))
+        {
+            let verification_failed =
+                |client_error| ChannelError::MultihopVerificationFailed { hop, client_error };
+
+            // Verify the connection end of the hop on the intermediate chain.
+            let conn_path = ConnectionPath::new(conn_id);
+            if conn_proof.unprefixed_key(&store.prefix)? != conn_path.to_string() {
+                return Err(ChannelError::InvalidMultihopProof {
+                    description: format!("connection proof of hop {hop} is not for {conn_path}"),
+                });
+            }
+            client_state
+                .verify_membership(
+                    &store.prefix,
+                    &conn_proof.proof,
+                    &store.root,
+                    Path::Connection(conn_path),
+                    conn_proof.value.clone(),
+                )
+                .map_err(verification_failed)?;
+
+            let hop_conn_end = ConnectionEnd::decode_vec(&conn_proof.value).map_err(|e| {
+                ChannelError::InvalidMultihopProof {
+                    description: format!("invalid connection end of hop {hop}: {e}"),
+                }
+            })?;
+            if !hop_conn_end.is_open() {
+                return Err(ChannelError::InvalidMultihopProof {
+                    description: format!("connection {conn_id} of hop {hop} is not open"),
+                });
+            }
+
+            // Verify the consensus state of the next chain held by the client
+            // of that connection end.
+            let cons_path = match Path::from_str(cons_proof.unprefixed_key(&store.prefix)?) {
+                Ok(Path::ClientConsensusState(path))
+                    if &path.client_id == hop_conn_end.client_id() =>
+                {
+                    path
+                }
+                _ => {
+                    return Err(ChannelError::InvalidMultihopProof {
+                        description: format!(
+                        "consensus proof of hop {hop} is not for a consensus state of client {}",
+                        hop_conn_end.client_id()
+                    ),
+                    })
+                }
+            };
+            client_state
+                .verify_membership(
+                    &store.prefix,
+                    &cons_proof.proof,
+                    &store.root,
+                    Path::ClientConsensusState(cons_path.clone()),
+                    cons_proof.value.clone(),
+                )
+                .map_err(verification_failed)?;
+
+            let (root, timestamp) = client_state
+                .decode_multihop_consensus_state(cons_proof.value_as_any()?)
+                .map_err(verification_failed)?;
+
+            store.root = root;
+            store.timestamp = timestamp;
+            store.height = Height::new(cons_path.revision_number, cons_path.revision_height)
+                .map_err(verification_failed)?;
+            store
+                .connection_hops
+                .push(counterparty_conn_id(conn_id, &hop_conn_end)?);
+            store.prefix = hop_conn_end.counterparty().prefix().clone();
+        }
+
+        // The counterparty lists its hops starting from its own connection.
+        store.connection_hops.reverse();
+        store.proof = proofs.key_proof.proof;
+        store.key_path = Some(proofs.key_proof.prefixed_key);
+
+        Ok(store)
+    }
+
+    /// Verifies that `value` is stored under `path` on the counterparty chain.
+    pub fn verify_membership<CS>(
+        &self,
+        client_state: &CS,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError>
+    where
+        CS: ClientStateCommon,
+    {
+        self.verify_key_path(&path)?;
+
+        client_state.verify_membership(&self.prefix, &self.proof, &self.root, path, value)
+    }
+
+    /// Verifies that nothing is stored under `path` on the counterparty chain.
+    pub fn verify_non_membership<CS>(
+        &self,
+        client_state: &CS,
+        path: Path,
+    ) -> Result<(), ClientError>
+    where
+        CS: ClientStateCommon,
+    {
+        self.verify_key_path(&path)?;
+
+        client_state.verify_non_membership(&self.prefix, &self.proof, &self.root, path)
+    }
+
+    /// Checks that the key proof of a multi-hop channel was built for `path`.
+    fn verify_key_path(&self, path: &Path) -> Result<(), ClientError> {
+        match &self.key_path {
+            Some(key_path) if *key_path != apply_prefix(&self.prefix, vec![path.to_string()]) => {
+                Err(ClientError::Other {
+                    description: format!("multi-hop key proof is not for path {path}"),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use ibc_core_channel_types::channel::Order;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::PruneAcknowledgements;
+use ibc_core_channel_types::msgs::MsgPruneAcknowledgements;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core_host::types::path::{
+    AckPath, ChannelEndPath, PruningSeqEndPath, PruningSeqStartPath, ReceiptPath,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+pub fn prune_acknowledgements_validate<ValCtx>(
+    ctx: &ValCtx,
+    msg: MsgPruneAcknowledgements,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx.validate_message_signer(&msg.signer)?;
+
+    ctx.channel_end(&ChannelEndPath::new(&msg.port_id, &msg.chan_id))?;
+
+    // Only the channels whose upgrade completed have anything to prune.
+    pruning_sequences(ctx, &msg.port_id, &msg.chan_id)?;
+
+    Ok(())
+}
+
+pub fn prune_acknowledgements_execute<ExecCtx>(
+    ctx: &mut ExecCtx,
+    msg: MsgPruneAcknowledgements,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end = ctx.channel_end(&ChannelEndPath::new(&msg.port_id, &msg.chan_id))?;
+    let (pruning_seq_start, pruning_seq_end) = pruning_sequences(ctx, &msg.port_id, &msg.chan_id)?;
+
+    let mut seq = pruning_seq_start;
+    let mut limit = msg.limit;
+
+    while seq < pruning_seq_end && limit > 0 {
+        let ack_path = AckPath::new(&msg.port_id, &msg.chan_id, seq);
+
+        // Pruning stops at the first packet not acknowledged yet, and resumes
+        // from it with the next message.
+        if ctx.get_packet_acknowledgement(&ack_path).is_err() {
+            break;
+        }
+
+        ctx.delete_packet_acknowledgement(&ack_path)?;
+
+        // Receipts are only stored on unordered channels.
+        if chan_end.ordering == Order::Unordered {
+            let receipt_path = ReceiptPath::new(&msg.port_id, &msg.chan_id, seq);
+            ctx.delete_packet_receipt(&receipt_path)?;
+        }
+
+        seq = seq.increment();
+        limit -= 1;
+    }
+
+    let pruning_seq_start_path = PruningSeqStartPath::new(&msg.port_id, &msg.chan_id);
+    ctx.store_pruning_sequence_start(&pruning_seq_start_path, seq)?;
+
+    let total_pruned_sequences = seq.value() - pruning_seq_start.value();
+    let total_remaining_sequences = pruning_seq_end.value().saturating_sub(seq.value());
+
+    ctx.log_message(format!(
+        "success: pruned {total_pruned_sequences} acknowledgements, {total_remaining_sequences} remaining"
+    ))?;
+
+    let event = IbcEvent::PruneAcknowledgements(PruneAcknowledgements::new(
+        msg.port_id,
+        msg.chan_id,
+        total_pruned_sequences,
+        total_remaining_sequences,
+    ));
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+    ctx.emit_ibc_event(event)?;
+
+    Ok(())
+}
+
+/// Returns the pruning sequence start and end of the channel, both set once
+/// its upgrade completed.
+fn pruning_sequences<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+) -> Result<(Sequence, Sequence), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let pruning_seq_end = ctx
+        .get_pruning_sequence_end(&PruningSeqEndPath::new(port_id, chan_id))?
+        .ok_or_else(|| ChannelError::PruningSequenceEndNotFound {
+            port_id: port_id.clone(),
+            channel_id: chan_id.clone(),
+        })?;
+
+    let pruning_seq_start = ctx
+        .get_pruning_sequence_start(&PruningSeqStartPath::new(port_id, chan_id))?
+        .ok_or_else(|| ChannelError::PruningSequenceStartNotFound {
+            port_id: port_id.clone(),
+            channel_id: chan_id.clone(),
+        })?;
+
+    Ok((pruning_seq_start, pruning_seq_end))
+}
@@ -1,7 +1,7 @@
 use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
-use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc_core_channel_types::commitment::compute_packet_commitment_with;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
-use ibc_core_channel_types::events::{ReceivePacket, WriteAcknowledgement};
+use ibc_core_channel_types::events::ReceivePacket;
 use ibc_core_channel_types::msgs::MsgRecvPacket;
 use ibc_core_channel_types::packet::Receipt;
 use ibc_core_client::context::prelude::*;
@@ -13,14 +13,24 @@ use ibc_core_host::types::path::{
     AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath,
     SeqRecvPath,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{
+    ChannelValidationContext, ClientRegistryValidationContext, ConnectionEndValidationContext,
+    PacketExecutionContext, PacketValidationContext,
+};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Expiry;
+
+use super::caps::verify_packet_data_size;
+use super::client_status::verify_client_usable_for_packets;
+use super::multihop::CounterpartyStore;
+use super::write_acknowledgement::write_acknowledgement_execute;
 
 pub fn recv_packet_validate<ValCtx>(ctx_b: &ValCtx, msg: MsgRecvPacket) -> Result<(), ContextError>
 where
-    ValCtx: ValidationContext,
+    ValCtx: ClientRegistryValidationContext
+        + ConnectionEndValidationContext
+        + ChannelValidationContext
+        + PacketValidationContext,
 {
     // Note: this contains the validation for `write_acknowledgement` as well.
     validate(ctx_b, &msg)
@@ -35,7 +45,7 @@ pub fn recv_packet_execute<ExecCtx>(
     msg: MsgRecvPacket,
 ) -> Result<(), ContextError>
 where
-    ExecCtx: ExecutionContext,
+    ExecCtx: ChannelValidationContext + PacketExecutionContext,
 {
     let chan_end_path_on_b =
         ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
@@ -92,22 +102,11 @@ where
             }
             _ => {}
         }
-        let ack_path_on_b = AckPath::new(
-            &msg.packet.port_id_on_b,
-            &msg.packet.chan_id_on_b,
-            msg.packet.seq_on_a,
-        );
-        // `writeAcknowledgement` handler state changes
-        ctx_b.store_packet_acknowledgement(
-            &ack_path_on_b,
-            compute_ack_commitment(&acknowledgement),
-        )?;
     }
 
     // emit events and logs
     {
         ctx_b.log_message("success: packet receive".to_string())?;
-        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
 
         let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
         let event = IbcEvent::ReceivePacket(ReceivePacket::new(
@@ -117,13 +116,12 @@ where
         ));
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(event)?;
-        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
-            msg.packet,
-            acknowledgement,
-            conn_id_on_b.clone(),
-        ));
-        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
-        ctx_b.emit_ibc_event(event)?;
+
+        // Modules acknowledging the packet asynchronously write the
+        // acknowledgement later on through `write_acknowledgement`.
+        if let Some(acknowledgement) = acknowledgement {
+            write_acknowledgement_execute(ctx_b, &msg.packet, acknowledgement)?;
+        }
 
         for module_event in extras.events {
             ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
@@ -139,13 +137,16 @@ where
 
 fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgRecvPacket) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ClientRegistryValidationContext
+        + ConnectionEndValidationContext
+        + ChannelValidationContext
+        + PacketValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
     let chan_end_path_on_b =
         ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
-    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+    let chan_end_on_b = ctx_b.channel_end_cow(&chan_end_path_on_b)?;
 
     chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
 
@@ -156,8 +157,10 @@ where
 
     chan_end_on_b.verify_counterparty_matches(&counterparty)?;
 
+    verify_packet_data_size(&ctx_b.host_limits()?, &msg.packet)?;
+
     let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
-    let conn_end_on_b = ctx_b.connection_end(conn_id_on_b)?;
+    let conn_end_on_b = ctx_b.connection_end_cow(conn_id_on_b)?;
 
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
@@ -171,7 +174,11 @@ where
     }
 
     let latest_timestamp = ctx_b.host_timestamp()?;
-    if let Expiry::Expired = latest_timestamp.check_expiry(&msg.packet.timeout_timestamp_on_b) {
+    if msg
+        .packet
+        .timeout_timestamp_on_b
+        .has_expired(&latest_timestamp)
+    {
         return Err(PacketError::LowPacketTimestamp.into());
     }
 
@@ -181,9 +188,7 @@ where
         let client_val_ctx_b = ctx_b.get_client_validation_context();
         let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
 
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
-            .verify_is_active()?;
+        verify_client_usable_for_packets(ctx_b, &client_state_of_a_on_b, client_id_on_b)?;
 
         client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
 
@@ -196,7 +201,7 @@ where
         let consensus_state_of_a_on_b =
             client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
 
-        let expected_commitment_on_a = compute_packet_commitment(
+        let expected_commitment_on_a = compute_packet_commitment_with::<Ctx::CryptoProvider>(
             &msg.packet.data,
             &msg.packet.timeout_height_on_b,
             &msg.packet.timeout_timestamp_on_b,
@@ -209,12 +214,20 @@ where
 
         verify_conn_delay_passed(ctx_b, msg.proof_height_on_a, &conn_end_on_b)?;
 
+        let store_of_a = CounterpartyStore::new(
+            &client_state_of_a_on_b,
+            &consensus_state_of_a_on_b,
+            msg.proof_height_on_a,
+            &conn_end_on_b,
+            chan_end_on_b.connection_hops(),
+            &msg.proof_commitment_on_a,
+        )
+        .map_err(PacketError::Channel)?;
+
         // Verify the proof for the packet against the chain store.
-        client_state_of_a_on_b
+        store_of_a
             .verify_membership(
-                conn_end_on_b.counterparty().prefix(),
-                &msg.proof_commitment_on_a,
-                consensus_state_of_a_on_b.root(),
+                &client_state_of_a_on_b,
                 Path::Commitment(commitment_path_on_a),
                 expected_commitment_on_a.into_vec(),
             )
@@ -274,7 +287,7 @@ where
 
 fn validate_write_acknowledgement<Ctx>(ctx_b: &Ctx, msg: &MsgRecvPacket) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: PacketValidationContext,
 {
     let packet = msg.packet.clone();
     let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
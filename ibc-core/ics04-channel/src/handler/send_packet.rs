@@ -1,5 +1,5 @@
 use ibc_core_channel_types::channel::Counterparty;
-use ibc_core_channel_types::commitment::compute_packet_commitment;
+use ibc_core_channel_types::commitment::compute_packet_commitment_with;
 use ibc_core_channel_types::error::PacketError;
 use ibc_core_channel_types::events::SendPacket;
 use ibc_core_channel_types::packet::Packet;
@@ -10,8 +10,8 @@ use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, SeqSendPath,
 };
 use ibc_primitives::prelude::*;
-use ibc_primitives::Expiry;
 
+use super::caps::verify_packet_data_size;
 use crate::context::{SendPacketExecutionContext, SendPacketValidationContext};
 
 /// Send the given packet, including all necessary validation.
@@ -44,6 +44,8 @@ pub fn send_packet_validate(
 
     chan_end_on_a.verify_counterparty_matches(&counterparty)?;
 
+    verify_packet_data_size(&ctx_a.host_limits()?, packet)?;
+
     let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
 
     let conn_end_on_a = ctx_a.connection_end(conn_id_on_a)?;
@@ -76,8 +78,7 @@ pub fn send_packet_validate(
     let consensus_state_of_b_on_a =
         client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
     let latest_timestamp = consensus_state_of_b_on_a.timestamp();
-    let packet_timestamp = packet.timeout_timestamp_on_b;
-    if let Expiry::Expired = latest_timestamp.check_expiry(&packet_timestamp) {
+    if packet.timeout_timestamp_on_b.has_expired(&latest_timestamp) {
         return Err(PacketError::LowPacketTimestamp.into());
     }
 
@@ -98,10 +99,10 @@ pub fn send_packet_validate(
 /// Send the packet without any validation.
 ///
 /// A prior call to [`send_packet_validate`] MUST have succeeded.
-pub fn send_packet_execute(
-    ctx_a: &mut impl SendPacketExecutionContext,
-    packet: Packet,
-) -> Result<(), ContextError> {
+pub fn send_packet_execute<ExecCtx>(ctx_a: &mut ExecCtx, packet: Packet) -> Result<(), ContextError>
+where
+    ExecCtx: SendPacketExecutionContext,
+{
     {
         let seq_send_path_on_a = SeqSendPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
         let next_seq_send_on_a = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
@@ -109,14 +110,17 @@ pub fn send_packet_execute(
         ctx_a.store_next_sequence_send(&seq_send_path_on_a, next_seq_send_on_a.increment())?;
     }
 
-    ctx_a.store_packet_commitment(
-        &CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a),
-        compute_packet_commitment(
+    {
+        let commitment_path_on_a =
+            CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+        let commitment_on_a = compute_packet_commitment_with::<ExecCtx::CryptoProvider>(
             &packet.data,
             &packet.timeout_height_on_b,
             &packet.timeout_timestamp_on_b,
-        ),
-    )?;
+        );
+
+        ctx_a.store_packet_commitment(&commitment_path_on_a, commitment_on_a)?;
+    }
 
     // emit events and logs
     {
@@ -1,5 +1,5 @@
 use ibc_core_channel_types::channel::{Counterparty, Order, State};
-use ibc_core_channel_types::commitment::compute_packet_commitment;
+use ibc_core_channel_types::commitment::compute_packet_commitment_with;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ChannelClosed, TimeoutPacket};
 use ibc_core_channel_types::msgs::{MsgTimeout, MsgTimeoutOnClose};
@@ -10,10 +10,16 @@ use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{
+    ChannelExecutionContext, ChannelValidationContext, ClientRegistryValidationContext,
+    ConnectionEndValidationContext, PacketExecutionContext, PacketValidationContext,
+};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::cleanup::delete_closed_channel_state;
+use super::client_status::verify_client_usable_for_packets;
+use super::multihop::CounterpartyStore;
 use super::timeout_on_close;
 
 pub enum TimeoutMsgType {
@@ -27,7 +33,10 @@ pub fn timeout_packet_validate<ValCtx>(
     timeout_msg_type: TimeoutMsgType,
 ) -> Result<(), ContextError>
 where
-    ValCtx: ValidationContext,
+    ValCtx: ClientRegistryValidationContext
+        + ConnectionEndValidationContext
+        + ChannelValidationContext
+        + PacketValidationContext,
 {
     match &timeout_msg_type {
         TimeoutMsgType::Timeout(msg) => validate(ctx_a, msg),
@@ -50,11 +59,12 @@ pub fn timeout_packet_execute<ExecCtx>(
     timeout_msg_type: TimeoutMsgType,
 ) -> Result<(), ContextError>
 where
-    ExecCtx: ExecutionContext,
+    ExecCtx: ChannelExecutionContext + PacketExecutionContext,
 {
-    let (packet, signer) = match timeout_msg_type {
-        TimeoutMsgType::Timeout(msg) => (msg.packet, msg.signer),
-        TimeoutMsgType::TimeoutOnClose(msg) => (msg.packet, msg.signer),
+    // A timeout on close proves the counterparty channel closed.
+    let (packet, signer, counterparty_closed) = match timeout_msg_type {
+        TimeoutMsgType::Timeout(msg) => (msg.packet, msg.signer, false),
+        TimeoutMsgType::TimeoutOnClose(msg) => (msg.packet, msg.signer, true),
     };
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
@@ -89,6 +99,8 @@ where
             chan_end_on_a.state = State::Closed;
             ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
 
+            delete_closed_channel_state(ctx_a, &chan_end_path_on_a, counterparty_closed)?;
+
             chan_end_on_a
         } else {
             chan_end_on_a
@@ -128,11 +140,14 @@ where
 
 fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgTimeout) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ClientRegistryValidationContext
+        + ConnectionEndValidationContext
+        + ChannelValidationContext
+        + PacketValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
-    let chan_end_on_a = ctx_a.channel_end(&ChannelEndPath::new(
+    let chan_end_on_a = ctx_a.channel_end_cow(&ChannelEndPath::new(
         &msg.packet.port_id_on_a,
         &msg.packet.chan_id_on_a,
     ))?;
@@ -147,7 +162,7 @@ where
     chan_end_on_a.verify_counterparty_matches(&counterparty)?;
 
     let conn_id_on_a = chan_end_on_a.connection_hops()[0].clone();
-    let conn_end_on_a = ctx_a.connection_end(&conn_id_on_a)?;
+    let conn_end_on_a = ctx_a.connection_end_cow(&conn_id_on_a)?;
 
     //verify packet commitment
     let commitment_path_on_a = CommitmentPath::new(
@@ -163,7 +178,7 @@ where
         return Ok(());
     };
 
-    let expected_commitment_on_a = compute_packet_commitment(
+    let expected_commitment_on_a = compute_packet_commitment_with::<Ctx::CryptoProvider>(
         &msg.packet.data,
         &msg.packet.timeout_height_on_b,
         &msg.packet.timeout_timestamp_on_b,
@@ -181,9 +196,7 @@ where
         let client_val_ctx_a = ctx_a.get_client_validation_context();
         let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
 
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
+        verify_client_usable_for_packets(ctx_a, &client_state_of_b_on_a, client_id_on_a)?;
 
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
 
@@ -195,14 +208,28 @@ where
         );
         let consensus_state_of_b_on_a =
             client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let timestamp_of_b = consensus_state_of_b_on_a.timestamp();
 
-        if !msg.packet.timed_out(&timestamp_of_b, msg.proof_height_on_b) {
+        let store_of_b = CounterpartyStore::new(
+            &client_state_of_b_on_a,
+            &consensus_state_of_b_on_a,
+            msg.proof_height_on_b,
+            &conn_end_on_a,
+            chan_end_on_a.connection_hops(),
+            &msg.proof_unreceived_on_b,
+        )
+        .map_err(PacketError::Channel)?;
+
+        // For multi-hop channels, the timeout is checked against the last
+        // chain of the route.
+        if !msg
+            .packet
+            .timed_out(&store_of_b.timestamp, store_of_b.height)
+        {
             return Err(PacketError::PacketTimeoutNotReached {
                 timeout_height: msg.packet.timeout_height_on_b,
-                chain_height: msg.proof_height_on_b,
+                chain_height: store_of_b.height,
                 timeout_timestamp: msg.packet.timeout_timestamp_on_b,
-                chain_timestamp: timestamp_of_b,
+                chain_timestamp: store_of_b.timestamp,
             }
             .into());
         }
@@ -221,10 +248,8 @@ where
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
 
-                client_state_of_b_on_a.verify_membership(
-                    conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
+                store_of_b.verify_membership(
+                    &client_state_of_b_on_a,
                     Path::SeqRecv(seq_recv_path_on_b),
                     msg.packet.seq_on_a.to_vec(),
                 )
@@ -236,10 +261,8 @@ where
                     msg.packet.seq_on_a,
                 );
 
-                client_state_of_b_on_a.verify_non_membership(
-                    conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
+                store_of_b.verify_non_membership(
+                    &client_state_of_b_on_a,
                     Path::Receipt(receipt_path_on_b),
                 )
             }
@@ -0,0 +1,308 @@
+use ibc_core_channel_types::channel::{Counterparty, Order, State};
+use ibc_core_channel_types::commitment::compute_packet_commitment_with;
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::events::{ChannelClosed, TimeoutPacket};
+use ibc_core_channel_types::msgs::MsgTimeoutBatch;
+use ibc_core_client::context::prelude::*;
+use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
+};
+use ibc_core_host::{
+    ChannelExecutionContext, ChannelValidationContext, ClientRegistryValidationContext,
+    ConnectionEndValidationContext, PacketExecutionContext, PacketValidationContext,
+};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+
+use super::cleanup::delete_closed_channel_state;
+use super::client_status::verify_client_usable_for_packets;
+use super::multihop::CounterpartyStore;
+
+pub fn timeout_batch_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    module: &dyn Module,
+    msg: MsgTimeoutBatch,
+) -> Result<(), ContextError>
+where
+    ValCtx: ClientRegistryValidationContext
+        + ConnectionEndValidationContext
+        + ChannelValidationContext
+        + PacketValidationContext,
+{
+    validate(ctx_a, &msg)?;
+
+    for packet in &msg.packets {
+        module
+            .on_timeout_packet_validate(packet, &msg.signer)
+            .map_err(ContextError::PacketError)?;
+    }
+
+    Ok(())
+}
+
+pub fn timeout_batch_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgTimeoutBatch,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ChannelExecutionContext + PacketExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    let mut timed_out_any = false;
+
+    for packet in msg.packets {
+        // In all cases, this event is emitted
+        let event =
+            IbcEvent::TimeoutPacket(TimeoutPacket::new(packet.clone(), chan_end_on_a.ordering));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(event)?;
+
+        let commitment_path_on_a =
+            CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+
+        // Packets whose timeout has already been relayed are skipped, as for
+        // a single timeout.
+        if ctx_a.get_packet_commitment(&commitment_path_on_a).is_err() {
+            continue;
+        };
+
+        let (extras, cb_result) = module.on_timeout_packet_execute(&packet, &msg.signer);
+
+        cb_result?;
+
+        ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
+        timed_out_any = true;
+
+        ctx_a.log_message(format!("success: packet timeout {}", packet.seq_on_a))?;
+
+        for module_event in extras.events {
+            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_a.log_message(log_message)?;
+        }
+    }
+
+    // An ordered channel is closed once, however many packets timed out.
+    if timed_out_any && chan_end_on_a.ordering == Order::Ordered {
+        let mut chan_end_on_a = chan_end_on_a;
+        chan_end_on_a.state = State::Closed;
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
+
+        delete_closed_channel_state(ctx_a, &chan_end_path_on_a, false)?;
+
+        let conn_id_on_a = chan_end_on_a.connection_hops()[0].clone();
+
+        let event = IbcEvent::ChannelClosed(ChannelClosed::new(
+            msg.port_id_on_a,
+            msg.chan_id_on_a,
+            chan_end_on_a.counterparty().port_id.clone(),
+            chan_end_on_a.counterparty().channel_id.clone(),
+            conn_id_on_a,
+            chan_end_on_a.ordering,
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(event)?;
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgTimeoutBatch) -> Result<(), ContextError>
+where
+    Ctx: ClientRegistryValidationContext
+        + ConnectionEndValidationContext
+        + ChannelValidationContext
+        + PacketValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    msg.verify_batch()?;
+
+    let chan_end_on_a =
+        ctx_a.channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))?;
+
+    chan_end_on_a.verify_state_matches(&State::Open)?;
+
+    // `verify_batch` ensures that all packets share the same counterparty.
+    let first_packet = &msg.packets[0];
+    let counterparty = Counterparty::new(
+        first_packet.port_id_on_b.clone(),
+        Some(first_packet.chan_id_on_b.clone()),
+    );
+
+    chan_end_on_a.verify_counterparty_matches(&counterparty)?;
+
+    let conn_id_on_a = chan_end_on_a.connection_hops()[0].clone();
+    let conn_end_on_a = ctx_a.connection_end(&conn_id_on_a)?;
+
+    // Packets without a commitment were already timed out and are skipped, as
+    // for a single timeout; the others must match their commitment.
+    let mut pending = Vec::with_capacity(msg.packets.len());
+    for (index, packet) in msg.packets.iter().enumerate() {
+        let commitment_path_on_a =
+            CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+        let Ok(commitment_on_a) = ctx_a.get_packet_commitment(&commitment_path_on_a) else {
+            continue;
+        };
+
+        let expected_commitment_on_a = compute_packet_commitment_with::<Ctx::CryptoProvider>(
+            &packet.data,
+            &packet.timeout_height_on_b,
+            &packet.timeout_timestamp_on_b,
+        );
+        if commitment_on_a != expected_commitment_on_a {
+            return Err(PacketError::IncorrectPacketCommitment {
+                sequence: packet.seq_on_a,
+            }
+            .into());
+        }
+
+        pending.push((index, packet));
+    }
+
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    // Verify proofs
+    {
+        let client_id_on_a = conn_end_on_a.client_id();
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+
+        verify_client_usable_for_packets(ctx_a, &client_state_of_b_on_a, client_id_on_a)?;
+
+        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            msg.proof_height_on_b.revision_number(),
+            msg.proof_height_on_b.revision_height(),
+        );
+        let consensus_state_of_b_on_a =
+            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+
+        let build_store_of_b = |proof| {
+            CounterpartyStore::new(
+                &client_state_of_b_on_a,
+                &consensus_state_of_b_on_a,
+                msg.proof_height_on_b,
+                &conn_end_on_a,
+                chan_end_on_a.connection_hops(),
+                proof,
+            )
+            .map_err(PacketError::Channel)
+        };
+
+        let shared_store_of_b = build_store_of_b(&msg.proofs_unreceived_on_b[0])?;
+
+        // check that timeout height or timeout timestamp has passed on the
+        // other end for every packet
+        for (_, packet) in &pending {
+            if !packet.timed_out(&shared_store_of_b.timestamp, shared_store_of_b.height) {
+                return Err(PacketError::PacketTimeoutNotReached {
+                    timeout_height: packet.timeout_height_on_b,
+                    chain_height: shared_store_of_b.height,
+                    timeout_timestamp: packet.timeout_timestamp_on_b,
+                    chain_timestamp: shared_store_of_b.timestamp,
+                }
+                .into());
+            }
+        }
+
+        verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
+
+        let verification_failed = |sequence, client_error| {
+            PacketError::Channel(ChannelError::PacketVerificationFailed {
+                sequence,
+                client_error,
+            })
+        };
+
+        match chan_end_on_a.ordering {
+            Order::Ordered => {
+                // The packets are contiguous, so a single proof that the
+                // counterparty expects the lowest of them covers the batch.
+                if msg.proofs_unreceived_on_b.len() != 1 {
+                    return Err(PacketError::InvalidTimeoutBatch {
+                        description:
+                            "ordered channels take a single proof of the next receive sequence"
+                                .to_string(),
+                    }
+                    .into());
+                }
+
+                let (_, lowest_packet) = pending[0];
+                if lowest_packet.seq_on_a < msg.next_seq_recv_on_b {
+                    return Err(PacketError::InvalidPacketSequence {
+                        given_sequence: lowest_packet.seq_on_a,
+                        next_sequence: msg.next_seq_recv_on_b,
+                    }
+                    .into());
+                }
+
+                let seq_recv_path_on_b =
+                    SeqRecvPath::new(&lowest_packet.port_id_on_b, &lowest_packet.chan_id_on_b);
+
+                shared_store_of_b
+                    .verify_membership(
+                        &client_state_of_b_on_a,
+                        Path::SeqRecv(seq_recv_path_on_b),
+                        msg.next_seq_recv_on_b.to_vec(),
+                    )
+                    .map_err(|e| verification_failed(msg.next_seq_recv_on_b, e))?;
+            }
+            Order::Unordered => {
+                // Each receipt is proven absent by its own proof.
+                if msg.proofs_unreceived_on_b.len() != msg.packets.len() {
+                    return Err(PacketError::InvalidTimeoutBatch {
+                        description: format!(
+                            "unordered channels take one proof per packet, expected {} proofs",
+                            msg.packets.len()
+                        ),
+                    }
+                    .into());
+                }
+
+                for (index, packet) in pending {
+                    let receipt_path_on_b = ReceiptPath::new(
+                        &packet.port_id_on_b,
+                        &packet.chan_id_on_b,
+                        packet.seq_on_a,
+                    );
+
+                    let own_store_of_b;
+                    let store_of_b = if index == 0 {
+                        &shared_store_of_b
+                    } else {
+                        own_store_of_b = build_store_of_b(&msg.proofs_unreceived_on_b[index])?;
+                        &own_store_of_b
+                    };
+
+                    store_of_b
+                        .verify_non_membership(
+                            &client_state_of_b_on_a,
+                            Path::Receipt(receipt_path_on_b),
+                        )
+                        .map_err(|e| verification_failed(packet.seq_on_a, e))?;
+                }
+            }
+            Order::None => {
+                return Err(ContextError::ChannelError(ChannelError::InvalidOrderType {
+                    expected: "Channel ordering cannot be None".to_string(),
+                    actual: chan_end_on_a.ordering.to_string(),
+                }))
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State};
-use ibc_core_channel_types::commitment::compute_packet_commitment;
+use ibc_core_channel_types::commitment::compute_packet_commitment_with;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::msgs::MsgTimeoutOnClose;
 use ibc_core_client::context::prelude::*;
@@ -8,13 +8,22 @@ use ibc_core_handler_types::error::ContextError;
 use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
 };
-use ibc_core_host::ValidationContext;
+use ibc_core_host::{
+    ChannelValidationContext, ClientRegistryValidationContext, ConnectionEndValidationContext,
+    PacketValidationContext,
+};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::client_status::verify_client_usable_for_packets;
+use super::multihop::CounterpartyStore;
+
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgTimeoutOnClose) -> Result<(), ContextError>
 where
-    Ctx: ValidationContext,
+    Ctx: ClientRegistryValidationContext
+        + ConnectionEndValidationContext
+        + ChannelValidationContext
+        + PacketValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
@@ -44,7 +53,7 @@ where
         return Ok(());
     };
 
-    let expected_commitment_on_a = compute_packet_commitment(
+    let expected_commitment_on_a = compute_packet_commitment_with::<Ctx::CryptoProvider>(
         &packet.data,
         &packet.timeout_height_on_b,
         &packet.timeout_timestamp_on_b,
@@ -65,9 +74,7 @@ where
         let client_val_ctx_a = ctx_a.get_client_validation_context();
         let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
 
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
+        verify_client_usable_for_packets(ctx_a, &client_state_of_b_on_a, client_id_on_a)?;
 
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
 
@@ -78,18 +85,25 @@ where
         );
         let consensus_state_of_b_on_a =
             client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let prefix_on_b = conn_end_on_a.counterparty().prefix();
+        let store_of_b = |proof| {
+            CounterpartyStore::new(
+                &client_state_of_b_on_a,
+                &consensus_state_of_b_on_a,
+                msg.proof_height_on_b,
+                &conn_end_on_a,
+                chan_end_on_a.connection_hops(),
+                proof,
+            )
+            .map_err(PacketError::Channel)
+        };
+        let close_store_of_b = store_of_b(&msg.proof_close_on_b)?;
+        let unreceived_store_of_b = store_of_b(&msg.proof_unreceived_on_b)?;
         let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
         let chan_id_on_b = chan_end_on_a
             .counterparty()
             .channel_id()
             .ok_or(PacketError::Channel(ChannelError::MissingCounterparty))?;
-        let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
-            PacketError::UndefinedConnectionCounterparty {
-                connection_id: chan_end_on_a.connection_hops()[0].clone(),
-            },
-        )?;
-        let expected_conn_hops_on_b = vec![conn_id_on_b.clone()];
+        let expected_conn_hops_on_b = close_store_of_b.connection_hops.clone();
         let expected_counterparty = Counterparty::new(
             packet.port_id_on_a.clone(),
             Some(packet.chan_id_on_a.clone()),
@@ -106,11 +120,9 @@ where
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_b_on_a
+        close_store_of_b
             .verify_membership(
-                prefix_on_b,
-                &msg.proof_unreceived_on_b,
-                consensus_state_of_b_on_a.root(),
+                &client_state_of_b_on_a,
                 Path::ChannelEnd(chan_end_path_on_b),
                 expected_chan_end_on_b.encode_vec(),
             )
@@ -131,10 +143,8 @@ where
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
 
-                client_state_of_b_on_a.verify_membership(
-                    conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
+                unreceived_store_of_b.verify_membership(
+                    &client_state_of_b_on_a,
                     Path::SeqRecv(seq_recv_path_on_b),
                     packet.seq_on_a.to_vec(),
                 )
@@ -146,10 +156,8 @@ where
                     msg.packet.seq_on_a,
                 );
 
-                client_state_of_b_on_a.verify_non_membership(
-                    conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
+                unreceived_store_of_b.verify_non_membership(
+                    &client_state_of_b_on_a,
                     Path::Receipt(receipt_path_on_b),
                 )
             }
@@ -0,0 +1,119 @@
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{Order, State as ChannelState};
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::events::WriteAcknowledgement;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{AckPath, ChannelEndPath, ReceiptPath, SeqRecvPath};
+use ibc_primitives::prelude::*;
+
+use crate::context::{WriteAckExecutionContext, WriteAckValidationContext};
+
+/// Writes the acknowledgement of a received packet, including all necessary
+/// validation.
+///
+/// Modules finalizing the result of a packet asynchronously return no
+/// acknowledgement from `on_recv_packet_execute`, and have the host call this
+/// entrypoint once the acknowledgement is known.
+///
+/// Equivalent to calling [`write_acknowledgement_validate`], followed by
+/// [`write_acknowledgement_execute`]
+pub fn write_acknowledgement<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: &Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError>
+where
+    ExecCtx: WriteAckExecutionContext,
+{
+    write_acknowledgement_validate(ctx_b, packet)?;
+    write_acknowledgement_execute(ctx_b, packet, acknowledgement)
+}
+
+/// Validate that writing the acknowledgement of the given packet would
+/// succeed, i.e. that the packet was received and has not been acknowledged
+/// yet.
+pub fn write_acknowledgement_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    packet: &Packet,
+) -> Result<(), ContextError>
+where
+    ValCtx: WriteAckValidationContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b
+        .verify_state_matches(&ChannelState::Open)
+        .map_err(PacketError::Channel)?;
+
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    if ctx_b.get_packet_acknowledgement(&ack_path_on_b).is_ok() {
+        return Err(PacketError::AcknowledgementExists {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    let packet_received = match chan_end_on_b.ordering {
+        Order::Unordered => {
+            let receipt_path_on_b =
+                ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+            ctx_b.get_packet_receipt(&receipt_path_on_b).is_ok()
+        }
+        Order::Ordered => {
+            let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+            packet.seq_on_a < ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?
+        }
+        Order::None => false,
+    };
+
+    if !packet_received {
+        return Err(PacketError::PacketNotReceived {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Write the acknowledgement of the given packet without any validation.
+///
+/// A prior call to [`write_acknowledgement_validate`] MUST have succeeded.
+pub fn write_acknowledgement_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: &Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError>
+where
+    ExecCtx: WriteAckExecutionContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    // state changes
+    {
+        let ack_path_on_b =
+            AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+        let ack_commitment_on_b = ctx_b.ack_commitment(&acknowledgement);
+        ctx_b.store_packet_acknowledgement(&ack_path_on_b, ack_commitment_on_b)?;
+    }
+
+    // emit events and logs
+    {
+        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
+
+        let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
+        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+            packet.clone(),
+            acknowledgement,
+            conn_id_on_b.clone(),
+        ));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(event)?;
+    }
+
+    Ok(())
+}
@@ -139,3 +139,237 @@ impl From<AcknowledgementStatus> for Acknowledgement {
             .expect("token transfer internal error: ack is never supposed to be empty")
     }
 }
+
+/// The prefix of the message of error acknowledgements, followed by their
+/// code, as written by ibc-go.
+const ABCI_CODE_PREFIX: &str = "ABCI code: ";
+
+/// The outcome of processing a received packet, shared by all applications so
+/// that counterparties can tell the class of a failure from its code.
+///
+/// Unlike [`AcknowledgementStatus`], the result has a single, deterministic
+/// encoding, the JSON acknowledgement of ibc-go with sorted keys and
+/// escaped strings:
+/// - `{"result":"<base64 of the bytes>"}` on success, and
+/// - `{"error":"ABCI code: <code>: <message>"}` on error.
+///
+/// Decoding only accepts that encoding, so that decoding and re-encoding an
+/// acknowledgement always yields the same bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AcknowledgementResult {
+    /// The bytes returned by the application on success.
+    Success(Vec<u8>),
+    /// The code identifying the class of the failure, along with its
+    /// description.
+    Error { code: u32, message: String },
+}
+
+impl AcknowledgementResult {
+    /// Creates a successful acknowledgement result with the given bytes.
+    pub fn success(bytes: impl Into<Vec<u8>>) -> Self {
+        Self::Success(bytes.into())
+    }
+
+    /// Creates an error acknowledgement result with the given code and
+    /// message.
+    pub fn error(code: u32, message: impl ToString) -> Self {
+        Self::Error {
+            code,
+            message: message.to_string(),
+        }
+    }
+
+    /// Returns true if the acknowledgement result is successful.
+    pub fn is_successful(&self) -> bool {
+        matches!(self, AcknowledgementResult::Success(_))
+    }
+
+    /// Returns the code of an error acknowledgement result.
+    pub fn code(&self) -> Option<u32> {
+        match self {
+            AcknowledgementResult::Success(_) => None,
+            AcknowledgementResult::Error { code, .. } => Some(*code),
+        }
+    }
+
+    /// Returns the deterministic encoding of the acknowledgement result.
+    pub fn encode(&self) -> Vec<u8> {
+        let (key, value) = match self {
+            AcknowledgementResult::Success(bytes) => (
+                "result",
+                String::from_utf8(subtle_encoding::base64::encode(bytes))
+                    .expect("base64 is always valid UTF-8"),
+            ),
+            AcknowledgementResult::Error { code, message } => (
+                "error",
+                alloc::format!("{ABCI_CODE_PREFIX}{code}: {message}"),
+            ),
+        };
+
+        let mut encoded = alloc::format!(r#"{{"{key}":""#);
+        escape_json_str(&value, &mut encoded);
+        encoded.push_str(r#""}"#);
+        encoded.into_bytes()
+    }
+
+    /// Decodes an acknowledgement result from its deterministic encoding.
+    pub fn decode(bytes: &[u8]) -> Result<Self, PacketError> {
+        let result =
+            Self::decode_unchecked(bytes).ok_or(PacketError::NonCanonicalAcknowledgementResult)?;
+
+        if result.encode() != bytes {
+            return Err(PacketError::NonCanonicalAcknowledgementResult);
+        }
+
+        Ok(result)
+    }
+
+    fn decode_unchecked(bytes: &[u8]) -> Option<Self> {
+        let json = core::str::from_utf8(bytes).ok()?;
+
+        if let Some(value) = json
+            .strip_prefix(r#"{"result":""#)
+            .and_then(|rest| rest.strip_suffix(r#""}"#))
+        {
+            let bytes = subtle_encoding::base64::decode(unescape_json_str(value)?).ok()?;
+
+            return Some(Self::Success(bytes));
+        }
+
+        let value = json
+            .strip_prefix(r#"{"error":""#)
+            .and_then(|rest| rest.strip_suffix(r#""}"#))?;
+        let value = unescape_json_str(value)?;
+        let (code, message) = value.strip_prefix(ABCI_CODE_PREFIX)?.split_once(": ")?;
+
+        Some(Self::Error {
+            code: code.parse().ok()?,
+            message: message.to_string(),
+        })
+    }
+}
+
+impl Display for AcknowledgementResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            AcknowledgementResult::Success(bytes) => write!(
+                f,
+                "{}",
+                String::from_utf8_lossy(&subtle_encoding::base64::encode(bytes))
+            ),
+            AcknowledgementResult::Error { code, message } => {
+                write!(f, "{ABCI_CODE_PREFIX}{code}: {message}")
+            }
+        }
+    }
+}
+
+impl From<AcknowledgementResult> for Acknowledgement {
+    fn from(result: AcknowledgementResult) -> Self {
+        // The encoding always holds at least the JSON object delimiters.
+        Self(result.encode())
+    }
+}
+
+impl TryFrom<&Acknowledgement> for AcknowledgementResult {
+    type Error = PacketError;
+
+    fn try_from(ack: &Acknowledgement) -> Result<Self, PacketError> {
+        Self::decode(ack.as_bytes())
+    }
+}
+
+/// Escapes `value` into a JSON string the way Go's `encoding/json` does,
+/// including its escaping of HTML characters.
+fn escape_json_str(value: &str, out: &mut String) {
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str(r#"\""#),
+            '\\' => out.push_str(r"\\"),
+            '\n' => out.push_str(r"\n"),
+            '\r' => out.push_str(r"\r"),
+            '\t' => out.push_str(r"\t"),
+            c if c < ' ' || matches!(c, '<' | '>' | '&' | '\u{2028}' | '\u{2029}') => {
+                out.push_str(&alloc::format!(r"\u{:04x}", c as u32))
+            }
+            c => out.push(c),
+        }
+    }
+}
+
+/// Reverses [`escape_json_str`], returning `None` on any unsupported escape.
+fn unescape_json_str(value: &str) -> Option<String> {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return None,
+            '\\' => match chars.next()? {
+                '"' => out.push('"'),
+                '\\' => out.push('\\'),
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                'u' => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    let code = u32::from_str_radix(&code, 16).ok()?;
+                    out.push(char::from_u32(code)?);
+                }
+                _ => return None,
+            },
+            c => out.push(c),
+        }
+    }
+
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ack_result_encoding() {
+        let success = AcknowledgementResult::success([1]);
+        assert_eq!(success.encode(), br#"{"result":"AQ=="}"#);
+
+        let error =
+            AcknowledgementResult::error(5, "error handling packet: see events for details");
+        assert_eq!(
+            error.encode(),
+            br#"{"error":"ABCI code: 5: error handling packet: see events for details"}"#
+        );
+
+        let escaped = AcknowledgementResult::error(2, "invalid \"denom\" <a&b>\n");
+        assert_eq!(
+            escaped.encode(),
+            br#"{"error":"ABCI code: 2: invalid \"denom\" \u003ca\u0026b\u003e\n"}"#
+        );
+
+        for result in [success, error, escaped] {
+            assert_eq!(
+                AcknowledgementResult::decode(&result.encode()).expect("no error"),
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_ack_result_rejects_non_canonical() {
+        for bytes in [
+            &br#"{"result":"AQ"}"#[..],
+            br#"{ "result":"AQ=="}"#,
+            br#"{"error":"failed to deserialize packet data"}"#,
+            br#"{"error":"ABCI code: 05: invalid"}"#,
+            br#"{"error":"ABCI code: 5: <a"}"#,
+            br#"{"error":"ABCI code: 5: \u003Ca"}"#,
+            br#"{"success":"AQ=="}"#,
+        ] {
+            assert!(matches!(
+                AcknowledgementResult::decode(bytes),
+                Err(PacketError::NonCanonicalAcknowledgementResult)
+            ));
+        }
+    }
+}
@@ -312,11 +312,9 @@ impl ChannelEnd {
         Ok(())
     }
 
-    /// Checks if the `connection_hops` has a length of `expected`.
-    ///
-    /// Note: Current IBC version only supports one connection hop.
+    /// Checks `connection_hops` with `verify_connection_hops_length`.
     pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
-        verify_connection_hops_length(&self.connection_hops, 1)
+        verify_connection_hops_length(&self.connection_hops)
     }
 
     pub fn version_matches(&self, other: &Version) -> bool {
@@ -324,15 +322,15 @@ impl ChannelEnd {
     }
 }
 
-/// Checks if the `connection_hops` has a length of `expected`.
+/// Checks that the `connection_hops` holds at least one hop. Channels
+/// with more than one hop are routed through intermediate chains.
 pub(crate) fn verify_connection_hops_length(
     connection_hops: &[ConnectionId],
-    expected: u64,
 ) -> Result<(), ChannelError> {
-    if connection_hops.len() as u64 != expected {
+    if connection_hops.is_empty() {
         return Err(ChannelError::InvalidConnectionHopsLength {
-            expected,
-            actual: connection_hops.len() as u64,
+            expected: 1,
+            actual: 0,
         });
     }
     Ok(())
@@ -0,0 +1,374 @@
+//! Defines a generic helper for applications that need to ship payloads
+//! larger than the host packet size limit, by splitting them across multiple
+//! packets and reassembling them on the receiving end.
+//!
+//! The chunks of a payload are linked together through the sequence of the
+//! packet carrying the first chunk: the `i`-th chunk is expected to be carried
+//! by the packet with sequence `first_sequence + i`. Applications are hence
+//! expected to send all the chunks of a payload back-to-back over the same
+//! channel.
+
+use ibc_core_host_types::identifiers::Sequence;
+use ibc_primitives::prelude::*;
+
+use crate::error::PacketError;
+
+/// Length, in bytes, of the metadata prepended to each encoded chunk.
+pub const CHUNK_HEADER_LEN: usize = 16;
+
+/// A chunk of an application payload, carried as the data of a single packet.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketDataChunk {
+    first_sequence: Sequence,
+    index: u32,
+    total: u32,
+    data: Vec<u8>,
+}
+
+impl PacketDataChunk {
+    /// The sequence of the packet carrying the first chunk of the payload.
+    pub fn first_sequence(&self) -> Sequence {
+        self.first_sequence
+    }
+
+    /// The position of this chunk within the payload.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The total number of chunks the payload was split into.
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// The sequence of the packet expected to carry this chunk.
+    pub fn sequence(&self) -> Result<Sequence, PacketError> {
+        chunk_sequence(self.first_sequence, self.index)
+    }
+
+    /// Encodes the chunk as packet data bytes: a fixed-size big-endian
+    /// header (first sequence, index, total) followed by the chunk data.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(CHUNK_HEADER_LEN + self.data.len());
+        bytes.extend_from_slice(&self.first_sequence.value().to_be_bytes());
+        bytes.extend_from_slice(&self.index.to_be_bytes());
+        bytes.extend_from_slice(&self.total.to_be_bytes());
+        bytes.extend_from_slice(&self.data);
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for PacketDataChunk {
+    type Error = PacketError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() <= CHUNK_HEADER_LEN {
+            return Err(PacketError::InvalidPacketDataChunk {
+                reason: format!(
+                    "chunk must be longer than its {CHUNK_HEADER_LEN} bytes header, got {} bytes",
+                    bytes.len()
+                ),
+            });
+        }
+
+        let (header, data) = bytes.split_at(CHUNK_HEADER_LEN);
+
+        let mut first_sequence = [0; 8];
+        first_sequence.copy_from_slice(&header[0..8]);
+        let first_sequence = u64::from_be_bytes(first_sequence);
+
+        let mut index = [0; 4];
+        index.copy_from_slice(&header[8..12]);
+        let index = u32::from_be_bytes(index);
+
+        let mut total = [0; 4];
+        total.copy_from_slice(&header[12..16]);
+        let total = u32::from_be_bytes(total);
+
+        if first_sequence == 0 {
+            return Err(PacketError::ZeroPacketSequence);
+        }
+
+        if index >= total {
+            return Err(PacketError::InvalidPacketDataChunk {
+                reason: format!("chunk index {index} is out of bounds of {total} chunks"),
+            });
+        }
+
+        // The packet carrying the last chunk must have a valid sequence.
+        chunk_sequence(first_sequence.into(), total - 1)?;
+
+        Ok(Self {
+            first_sequence: first_sequence.into(),
+            index,
+            total,
+            data: data.to_vec(),
+        })
+    }
+}
+
+/// Splits `payload` into chunks to be carried by consecutive packets,
+/// starting with the packet of sequence `first_sequence`.
+///
+/// `max_packet_data_len` bounds the length of each encoded chunk, header
+/// included.
+pub fn split_packet_data(
+    first_sequence: Sequence,
+    payload: &[u8],
+    max_packet_data_len: usize,
+) -> Result<Vec<PacketDataChunk>, PacketError> {
+    if first_sequence.is_zero() {
+        return Err(PacketError::ZeroPacketSequence);
+    }
+
+    if payload.is_empty() {
+        return Err(PacketError::ZeroPacketData);
+    }
+
+    if max_packet_data_len <= CHUNK_HEADER_LEN {
+        return Err(PacketError::InvalidPacketDataChunk {
+            reason: format!(
+                "max packet data length ({max_packet_data_len}) must exceed the {CHUNK_HEADER_LEN} bytes chunk header"
+            ),
+        });
+    }
+
+    let chunks = payload.chunks(max_packet_data_len - CHUNK_HEADER_LEN);
+
+    let total = u32::try_from(chunks.len()).map_err(|_| PacketError::InvalidPacketDataChunk {
+        reason: "payload requires too many chunks".to_string(),
+    })?;
+
+    chunk_sequence(first_sequence, total - 1)?;
+
+    Ok(chunks
+        .zip(0..total)
+        .map(|(data, index)| PacketDataChunk {
+            first_sequence,
+            index,
+            total,
+            data: data.to_vec(),
+        })
+        .collect())
+}
+
+/// Returns the sequence of the packet carrying the chunk at `index` of a
+/// payload whose first chunk is carried by the packet `first_sequence`.
+fn chunk_sequence(first_sequence: Sequence, index: u32) -> Result<Sequence, PacketError> {
+    first_sequence
+        .value()
+        .checked_add(u64::from(index))
+        .map(Sequence::from)
+        .ok_or_else(|| PacketError::InvalidPacketDataChunk {
+            reason: format!("sequence of chunk {index} overflows from {first_sequence}"),
+        })
+}
+
+/// Reassembly buffer for the chunks of a single payload.
+///
+/// Receiving modules are expected to keep one buffer per
+/// `(port, channel, first_sequence)` in their own storage until the payload
+/// is complete.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketDataAssembler {
+    first_sequence: Sequence,
+    total: u32,
+    chunks: BTreeMap<u32, Vec<u8>>,
+}
+
+impl PacketDataAssembler {
+    /// Creates an empty buffer for the payload the given chunk belongs to.
+    pub fn new(chunk: &PacketDataChunk) -> Self {
+        Self {
+            first_sequence: chunk.first_sequence,
+            total: chunk.total,
+            chunks: BTreeMap::new(),
+        }
+    }
+
+    pub fn first_sequence(&self) -> Sequence {
+        self.first_sequence
+    }
+
+    /// Number of chunks received so far.
+    pub fn received(&self) -> u32 {
+        // The number of chunks is bounded by `total`.
+        self.chunks.len() as u32
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.received() == self.total
+    }
+
+    /// Stores a chunk received through the packet with the given `sequence`.
+    pub fn insert(
+        &mut self,
+        sequence: Sequence,
+        chunk: PacketDataChunk,
+    ) -> Result<(), PacketError> {
+        if chunk.first_sequence != self.first_sequence || chunk.total != self.total {
+            return Err(PacketError::InvalidPacketDataChunk {
+                reason: format!(
+                    "chunk of payload ({}, {} chunks) does not belong to payload ({}, {} chunks)",
+                    chunk.first_sequence, chunk.total, self.first_sequence, self.total
+                ),
+            });
+        }
+
+        if chunk.index >= self.total {
+            return Err(PacketError::InvalidPacketDataChunk {
+                reason: format!(
+                    "chunk index {} is out of bounds of {} chunks",
+                    chunk.index, self.total
+                ),
+            });
+        }
+
+        let chunk_sequence = chunk.sequence()?;
+
+        if chunk_sequence != sequence {
+            return Err(PacketError::InvalidPacketSequence {
+                given_sequence: sequence,
+                next_sequence: chunk_sequence,
+            });
+        }
+
+        if self.chunks.contains_key(&chunk.index) {
+            return Err(PacketError::InvalidPacketDataChunk {
+                reason: format!("chunk {} was already received", chunk.index),
+            });
+        }
+
+        self.chunks.insert(chunk.index, chunk.data);
+
+        Ok(())
+    }
+
+    /// Returns the reassembled payload, once all of its chunks were received.
+    pub fn assemble(self) -> Result<Vec<u8>, PacketError> {
+        if !self.is_complete() {
+            return Err(PacketError::InvalidPacketDataChunk {
+                reason: format!(
+                    "payload is incomplete: received {} out of {} chunks",
+                    self.received(),
+                    self.total
+                ),
+            });
+        }
+
+        Ok(self.chunks.into_values().flatten().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_and_reassemble() {
+        let payload: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let first_sequence = Sequence::from(7);
+
+        let chunks = split_packet_data(first_sequence, &payload, 100).unwrap();
+        assert_eq!(chunks.len(), 12);
+        assert!(chunks
+            .iter()
+            .all(|chunk| chunk.to_vec().len() <= 100 && chunk.total() == 12));
+
+        let mut assembler = PacketDataAssembler::new(&chunks[0]);
+
+        // Chunks may be received out of order.
+        for chunk in chunks.into_iter().rev() {
+            assert!(!assembler.is_complete());
+            let decoded = PacketDataChunk::try_from(chunk.to_vec().as_slice()).unwrap();
+            assert_eq!(decoded, chunk);
+            assembler
+                .insert(chunk.sequence().unwrap(), decoded)
+                .unwrap();
+        }
+
+        assert_eq!(assembler.first_sequence(), first_sequence);
+        assert_eq!(assembler.assemble().unwrap(), payload);
+    }
+
+    #[test]
+    fn reject_invalid_chunks() {
+        let chunks = split_packet_data(Sequence::from(1), &[1; 50], 26).unwrap();
+        assert_eq!(chunks.len(), 5);
+
+        let mut assembler = PacketDataAssembler::new(&chunks[0]);
+
+        // Chunk carried by an unexpected packet
+        assert!(assembler.insert(3.into(), chunks[1].clone()).is_err());
+
+        assembler.insert(2.into(), chunks[1].clone()).unwrap();
+
+        // Duplicate chunk
+        assert!(assembler.insert(2.into(), chunks[1].clone()).is_err());
+
+        // Chunk of another payload
+        let other = split_packet_data(Sequence::from(2), &[1; 50], 26).unwrap();
+        assert!(assembler.insert(2.into(), other[0].clone()).is_err());
+
+        // Incomplete payload
+        assert!(assembler.assemble().is_err());
+
+        // Malformed encodings
+        assert!(PacketDataChunk::try_from(&[0u8; CHUNK_HEADER_LEN][..]).is_err());
+        let mut bytes = chunks[0].to_vec();
+        bytes[15] = 0;
+        assert!(PacketDataChunk::try_from(bytes.as_slice()).is_err());
+
+        assert!(split_packet_data(Sequence::from(0), &[1], 26).is_err());
+        assert!(split_packet_data(Sequence::from(u64::MAX), &[1; 20], 26).is_err());
+        assert!(split_packet_data(Sequence::from(1), &[], 26).is_err());
+        assert!(split_packet_data(Sequence::from(1), &[1], CHUNK_HEADER_LEN).is_err());
+    }
+
+    #[test]
+    fn reject_overflowing_chunk_sequences() {
+        // The last of the 3 chunks would be carried by the packet of sequence
+        // `u64::MAX + 1`.
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(u64::MAX - 1).to_be_bytes());
+        bytes.extend_from_slice(&0u32.to_be_bytes());
+        bytes.extend_from_slice(&3u32.to_be_bytes());
+        bytes.push(1);
+
+        assert!(matches!(
+            PacketDataChunk::try_from(bytes.as_slice()),
+            Err(PacketError::InvalidPacketDataChunk { .. })
+        ));
+
+        // Whereas the chunks may end with the packet of sequence `u64::MAX`.
+        bytes[15] = 2;
+        let chunk = PacketDataChunk::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(chunk.sequence().unwrap(), Sequence::from(u64::MAX - 1));
+
+        let chunks = split_packet_data(Sequence::from(u64::MAX - 1), &[1; 20], 26).unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1].sequence().unwrap(), Sequence::from(u64::MAX));
+    }
+}
@@ -1,10 +1,12 @@
 //! Types and utilities related to packet commitments.
 
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+#[cfg(feature = "rust-crypto")]
+use ibc_primitives::DefaultCryptoProvider;
+use ibc_primitives::HostCryptoProvider;
 
 use super::acknowledgement::Acknowledgement;
-use crate::timeout::TimeoutHeight;
+use crate::timeout::{TimeoutHeight, TimeoutTimestamp};
 
 /// Packet commitment
 #[cfg_attr(
@@ -84,37 +86,58 @@ impl From<Vec<u8>> for AcknowledgementCommitment {
 /// `{revision_number: 0, revision_height: 0}` to be consistent with ibc-go,
 /// where this value is used to mean "no timeout height":
 /// <https://github.com/cosmos/ibc-go/blob/04791984b3d6c83f704c4f058e6ca0038d155d91/modules/core/04-channel/keeper/packet.go#L206>
+/// Likewise, the absence of `timeout_timestamp` is treated as `0`.
+#[cfg(feature = "rust-crypto")]
 pub fn compute_packet_commitment(
     packet_data: &[u8],
     timeout_height: &TimeoutHeight,
-    timeout_timestamp: &Timestamp,
+    timeout_timestamp: &TimeoutTimestamp,
+) -> PacketCommitment {
+    compute_packet_commitment_with::<DefaultCryptoProvider>(
+        packet_data,
+        timeout_height,
+        timeout_timestamp,
+    )
+}
+
+/// Compute the commitment for a packet, hashing with the given
+/// [`HostCryptoProvider`].
+///
+/// See [`compute_packet_commitment`] for the treatment of absent timeouts.
+pub fn compute_packet_commitment_with<C: HostCryptoProvider>(
+    packet_data: &[u8],
+    timeout_height: &TimeoutHeight,
+    timeout_timestamp: &TimeoutTimestamp,
 ) -> PacketCommitment {
     let mut hash_input = [0; 8 * 3 + 32];
 
     hash_input[..8].copy_from_slice(&timeout_timestamp.nanoseconds().to_be_bytes());
     hash_input[8..16].copy_from_slice(&timeout_height.commitment_revision_number().to_be_bytes());
     hash_input[16..24].copy_from_slice(&timeout_height.commitment_revision_height().to_be_bytes());
-    hash_input[24..].copy_from_slice(&hash(packet_data));
+    hash_input[24..].copy_from_slice(&C::sha256(packet_data));
 
-    hash(&hash_input).to_vec().into()
+    C::sha256(&hash_input).to_vec().into()
 }
 
 /// Compute the commitment for an acknowledgement.
+///
+/// As in ibc-go, the commitment is the SHA-256 hash of the raw
+/// acknowledgement bytes, without any prefix or encoding:
+/// <https://github.com/cosmos/ibc-go/blob/04791984b3d6c83f704c4f058e6ca0038d155d91/modules/core/04-channel/types/packet.go#L37>
+#[cfg(feature = "rust-crypto")]
 pub fn compute_ack_commitment(ack: &Acknowledgement) -> AcknowledgementCommitment {
-    hash(ack.as_ref()).to_vec().into()
+    compute_ack_commitment_with::<DefaultCryptoProvider>(ack)
 }
 
-/// Helper function to hash a byte slice using SHA256.
-///
-/// Note that computing commitments with anything other than SHA256 will
-/// break the Merkle proofs of the IBC provable store.
-fn hash(data: &[u8]) -> [u8; 32] {
-    use sha2::Digest;
-
-    sha2::Sha256::digest(data).into()
+/// Compute the commitment for an acknowledgement, hashing with the given
+/// [`HostCryptoProvider`].
+pub fn compute_ack_commitment_with<C: HostCryptoProvider>(
+    ack: &Acknowledgement,
+) -> AcknowledgementCommitment {
+    C::sha256(ack.as_ref()).to_vec().into()
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "rust-crypto"))]
 mod test {
     use super::*;
 
@@ -128,7 +151,7 @@ mod test {
         let actual = compute_packet_commitment(
             b"packet data",
             &TimeoutHeight::At(ibc_core_client_types::Height::new(42, 24).unwrap()),
-            &Timestamp::from_nanoseconds(0x42).unwrap(),
+            &TimeoutTimestamp::At(ibc_primitives::Timestamp::from_nanoseconds(0x42).unwrap()),
         );
         assert_eq!(&expected[..], actual.as_ref());
     }
@@ -144,4 +167,18 @@ mod test {
         let actual = compute_ack_commitment(&ack);
         assert_eq!(&expected[..], actual.as_ref())
     }
+
+    #[test]
+    fn test_compute_ack_commitment_matches_ibc_go() {
+        // Commitment of the successful ICS-20 acknowledgement, as stored by
+        // ibc-go under the `AckPath` of a received packet.
+        let expected: [u8; 32] = [
+            0x08, 0xf7, 0x55, 0x7e, 0xd5, 0x18, 0x26, 0xfe, 0x18, 0xd8, 0x45, 0x12, 0xbf, 0x24,
+            0xec, 0x75, 0x00, 0x1e, 0xdb, 0xaf, 0x21, 0x23, 0xa4, 0x77, 0xdf, 0x72, 0xa0, 0xa9,
+            0xf3, 0x64, 0x0a, 0x7c,
+        ];
+        let ack = Acknowledgement::try_from(br#"{"result":"AQ=="}"#.to_vec()).unwrap();
+        let actual = compute_ack_commitment(&ack);
+        assert_eq!(&expected[..], actual.as_ref())
+    }
 }
@@ -6,10 +6,11 @@ use ibc_core_connection_types::error as connection_error;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
+use ibc_primitives::utils::abci::AbciEventError;
 use ibc_primitives::{ParseTimestampError, Timestamp};
 
 use super::channel::Counterparty;
-use super::timeout::TimeoutHeight;
+use super::timeout::{TimeoutHeight, TimeoutTimestamp};
 use crate::channel::State;
 use crate::Version;
 
@@ -67,10 +68,36 @@ pub enum ChannelError {
     UndefinedConnectionCounterparty { connection_id: ConnectionId },
     /// invalid proof: empty proof
     InvalidProof,
+    /// invalid multi-hop proof: `{description}`
+    InvalidMultihopProof { description: String },
+    /// multi-hop proof verification failed at hop `{hop}`: `{client_error}`
+    MultihopVerificationFailed {
+        hop: u64,
+        client_error: client_error::ClientError,
+    },
     /// identifier error: `{0}`
     InvalidIdentifier(IdentifierError),
+    /// connection `{connection_id}` already has the maximum of `{max_channels}` channels allowed by the host
+    MaxChannelsPerConnectionReached {
+        connection_id: ConnectionId,
+        max_channels: u64,
+    },
     /// channel counter overflow error
     CounterOverflow,
+    /// the number of acknowledgements to prune must be greater than zero
+    ZeroPruningLimit,
+    /// pruning sequence start not found for port `{port_id}` and channel `{channel_id}`
+    PruningSequenceStartNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// pruning sequence end not found for port `{port_id}` and channel `{channel_id}`: the channel was not upgraded
+    PruningSequenceEndNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// invalid channel event: `{0}`
+    InvalidEvent(AbciEventError),
     /// other error: `{description}`
     Other { description: String },
 }
@@ -111,21 +138,39 @@ pub enum PacketError {
     PacketTimeoutNotReached {
         timeout_height: TimeoutHeight,
         chain_height: Height,
-        timeout_timestamp: Timestamp,
+        timeout_timestamp: TimeoutTimestamp,
         chain_timestamp: Timestamp,
     },
     /// Packet acknowledgement exists for the packet with the sequence `{sequence}`
     AcknowledgementExists { sequence: Sequence },
+    /// Packet `{sequence}` has not been received, and cannot be acknowledged
+    PacketNotReceived { sequence: Sequence },
     /// Acknowledgment cannot be empty
     InvalidAcknowledgement,
     /// Acknowledgment status cannot be empty
     EmptyAcknowledgementStatus,
+    /// acknowledgement is not a canonically encoded acknowledgement result
+    NonCanonicalAcknowledgementResult,
     /// Acknowledgment for the packet `{sequence}` not found
     PacketAcknowledgementNotFound { sequence: Sequence },
     /// invalid proof: missing height
     MissingHeight,
     /// there is no packet in this message
     MissingPacket,
+    /// packet `{sequence}` data of `{size}` bytes exceeds the maximum of `{max_size}` bytes allowed by the host
+    PacketDataTooLarge {
+        sequence: Sequence,
+        size: u64,
+        max_size: u64,
+    },
+    /// acknowledgement of packet `{sequence}` of `{size}` bytes exceeds the maximum of `{max_size}` bytes allowed by the host
+    AcknowledgementTooLarge {
+        sequence: Sequence,
+        size: u64,
+        max_size: u64,
+    },
+    /// invalid timeout batch: `{description}`
+    InvalidTimeoutBatch { description: String },
     /// invalid signer error: `{reason}`
     InvalidSigner { reason: String },
     /// application module error: `{description}`
@@ -136,6 +181,8 @@ pub enum PacketError {
     ZeroPacketSequence,
     /// packet data bytes cannot be empty
     ZeroPacketData,
+    /// invalid packet data chunk: `{reason}`
+    InvalidPacketDataChunk { reason: String },
     /// invalid timeout height for the packet
     InvalidTimeoutHeight,
     /// Invalid packet timeout timestamp value error: `{0}`
@@ -176,6 +223,12 @@ impl From<IdentifierError> for ChannelError {
     }
 }
 
+impl From<AbciEventError> for ChannelError {
+    fn from(err: AbciEventError) -> Self {
+        Self::InvalidEvent(err)
+    }
+}
+
 impl From<IdentifierError> for PacketError {
     fn from(err: IdentifierError) -> Self {
         Self::InvalidIdentifier(err)
@@ -202,7 +255,11 @@ impl std::error::Error for ChannelError {
             Self::PacketVerificationFailed {
                 client_error: e, ..
             } => Some(e),
+            Self::MultihopVerificationFailed {
+                client_error: e, ..
+            } => Some(e),
             Self::InvalidStringAsSequence { error: e, .. } => Some(e),
+            Self::InvalidEvent(e) => Some(e),
             _ => None,
         }
     }
@@ -2,6 +2,7 @@
 //! during the channel handshake.
 use derive_more::From;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::utils::abci::{AbciEventError, EventAttributes};
 use tendermint::abci;
 
 use crate::Version;
@@ -39,6 +40,16 @@ impl From<PortIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&EventAttributes<'_>> for PortIdAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        attributes
+            .parse::<PortId>(PORT_ID_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -62,6 +73,16 @@ impl From<ChannelIdAttribute> for abci::EventAttribute {
         (CHANNEL_ID_ATTRIBUTE_KEY, attr.channel_id.as_str()).into()
     }
 }
+
+impl TryFrom<&EventAttributes<'_>> for ChannelIdAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        attributes
+            .parse::<ChannelId>(CHANNEL_ID_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -89,6 +110,16 @@ impl From<CounterpartyPortIdAttribute> for abci::EventAttribute {
             .into()
     }
 }
+
+impl TryFrom<&EventAttributes<'_>> for CounterpartyPortIdAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        attributes
+            .parse::<PortId>(COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -117,6 +148,16 @@ impl From<CounterpartyChannelIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&EventAttributes<'_>> for CounterpartyChannelIdAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        attributes
+            .parse::<ChannelId>(COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 impl AsRef<ChannelId> for CounterpartyChannelIdAttribute {
     fn as_ref(&self) -> &ChannelId {
         &self.counterparty_channel_id
@@ -147,6 +188,16 @@ impl From<ConnectionIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&EventAttributes<'_>> for ConnectionIdAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        attributes
+            .parse::<ConnectionId>(CONNECTION_ID_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -170,3 +221,13 @@ impl From<VersionAttribute> for abci::EventAttribute {
         (VERSION_ATTRIBUTE_KEY, attr.version.as_str()).into()
     }
 }
+
+impl TryFrom<&EventAttributes<'_>> for VersionAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        attributes
+            .parse::<Version>(VERSION_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
@@ -5,7 +5,7 @@ mod packet_attributes;
 
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::utils::abci::EventAttributes;
 use tendermint::abci;
 
 use self::channel_attributes::{
@@ -20,25 +20,26 @@ use self::packet_attributes::{
 };
 use super::acknowledgement::Acknowledgement;
 use super::channel::Order;
-use super::timeout::TimeoutHeight;
+use super::timeout::{TimeoutHeight, TimeoutTimestamp};
 use super::Version;
 use crate::error::ChannelError;
 use crate::packet::Packet;
 
 /// Channel event types
-const CHANNEL_OPEN_INIT_EVENT: &str = "channel_open_init";
-const CHANNEL_OPEN_TRY_EVENT: &str = "channel_open_try";
-const CHANNEL_OPEN_ACK_EVENT: &str = "channel_open_ack";
-const CHANNEL_OPEN_CONFIRM_EVENT: &str = "channel_open_confirm";
-const CHANNEL_CLOSE_INIT_EVENT: &str = "channel_close_init";
-const CHANNEL_CLOSE_CONFIRM_EVENT: &str = "channel_close_confirm";
+pub const CHANNEL_OPEN_INIT_EVENT: &str = "channel_open_init";
+pub const CHANNEL_OPEN_TRY_EVENT: &str = "channel_open_try";
+pub const CHANNEL_OPEN_ACK_EVENT: &str = "channel_open_ack";
+pub const CHANNEL_OPEN_CONFIRM_EVENT: &str = "channel_open_confirm";
+pub const CHANNEL_CLOSE_INIT_EVENT: &str = "channel_close_init";
+pub const CHANNEL_CLOSE_CONFIRM_EVENT: &str = "channel_close_confirm";
+pub const PRUNE_ACKNOWLEDGEMENTS_EVENT: &str = "prune_acknowledgements";
 /// Packet event types
-const SEND_PACKET_EVENT: &str = "send_packet";
-const RECEIVE_PACKET_EVENT: &str = "receive_packet";
-const WRITE_ACK_EVENT: &str = "write_acknowledgement";
-const ACK_PACKET_EVENT: &str = "acknowledge_packet";
-const TIMEOUT_EVENT: &str = "timeout_packet";
-const CHANNEL_CLOSED_EVENT: &str = "channel_close";
+pub const SEND_PACKET_EVENT: &str = "send_packet";
+pub const RECEIVE_PACKET_EVENT: &str = "recv_packet";
+pub const WRITE_ACK_EVENT: &str = "write_acknowledgement";
+pub const ACK_PACKET_EVENT: &str = "acknowledge_packet";
+pub const TIMEOUT_EVENT: &str = "timeout_packet";
+pub const CHANNEL_CLOSED_EVENT: &str = "channel_close";
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -115,6 +116,22 @@ impl From<OpenInit> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenInit {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, CHANNEL_OPEN_INIT_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&attributes).try_into()?,
+            chan_id_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_b: (&attributes).try_into()?,
+            conn_id_attr_on_a: (&attributes).try_into()?,
+            version_attr_on_a: (&attributes).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -196,6 +213,23 @@ impl From<OpenTry> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenTry {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, CHANNEL_OPEN_TRY_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_b: (&attributes).try_into()?,
+            chan_id_attr_on_b: (&attributes).try_into()?,
+            port_id_attr_on_a: (&attributes).try_into()?,
+            chan_id_attr_on_a: (&attributes).try_into()?,
+            conn_id_attr_on_b: (&attributes).try_into()?,
+            version_attr_on_b: (&attributes).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -270,6 +304,22 @@ impl From<OpenAck> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenAck {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, CHANNEL_OPEN_ACK_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&attributes).try_into()?,
+            chan_id_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_b: (&attributes).try_into()?,
+            chan_id_attr_on_b: (&attributes).try_into()?,
+            conn_id_attr_on_a: (&attributes).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -344,6 +394,22 @@ impl From<OpenConfirm> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenConfirm {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, CHANNEL_OPEN_CONFIRM_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_b: (&attributes).try_into()?,
+            chan_id_attr_on_b: (&attributes).try_into()?,
+            port_id_attr_on_a: (&attributes).try_into()?,
+            chan_id_attr_on_a: (&attributes).try_into()?,
+            conn_id_attr_on_b: (&attributes).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -418,6 +484,22 @@ impl From<CloseInit> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for CloseInit {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, CHANNEL_CLOSE_INIT_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&attributes).try_into()?,
+            chan_id_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_b: (&attributes).try_into()?,
+            chan_id_attr_on_b: (&attributes).try_into()?,
+            conn_id_attr_on_a: (&attributes).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -492,6 +574,114 @@ impl From<CloseConfirm> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for CloseConfirm {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, CHANNEL_CLOSE_CONFIRM_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_b: (&attributes).try_into()?,
+            chan_id_attr_on_b: (&attributes).try_into()?,
+            port_id_attr_on_a: (&attributes).try_into()?,
+            chan_id_attr_on_a: (&attributes).try_into()?,
+            conn_id_attr_on_b: (&attributes).try_into()?,
+        })
+    }
+}
+
+/// A `PruneAcknowledgements` event is emitted when the acknowledgements and
+/// receipts left over from before the upgrade of a channel are pruned.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PruneAcknowledgements {
+    port_id_attr: PortIdAttribute,
+    chan_id_attr: ChannelIdAttribute,
+    total_pruned_sequences: u64,
+    total_remaining_sequences: u64,
+}
+
+impl PruneAcknowledgements {
+    pub fn new(
+        port_id: PortId,
+        chan_id: ChannelId,
+        total_pruned_sequences: u64,
+        total_remaining_sequences: u64,
+    ) -> Self {
+        Self {
+            port_id_attr: port_id.into(),
+            chan_id_attr: chan_id.into(),
+            total_pruned_sequences,
+            total_remaining_sequences,
+        }
+    }
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id_attr.port_id
+    }
+    pub fn chan_id(&self) -> &ChannelId {
+        &self.chan_id_attr.channel_id
+    }
+    pub fn total_pruned_sequences(&self) -> u64 {
+        self.total_pruned_sequences
+    }
+    pub fn total_remaining_sequences(&self) -> u64 {
+        self.total_remaining_sequences
+    }
+
+    pub fn event_type(&self) -> &str {
+        PRUNE_ACKNOWLEDGEMENTS_EVENT
+    }
+}
+
+impl From<PruneAcknowledgements> for abci::Event {
+    fn from(ev: PruneAcknowledgements) -> Self {
+        abci::Event {
+            kind: PRUNE_ACKNOWLEDGEMENTS_EVENT.to_string(),
+            attributes: vec![
+                ev.port_id_attr.into(),
+                ev.chan_id_attr.into(),
+                (
+                    "total_pruned_sequences",
+                    ev.total_pruned_sequences.to_string(),
+                )
+                    .into(),
+                (
+                    "total_remaining_sequences",
+                    ev.total_remaining_sequences.to_string(),
+                )
+                    .into(),
+            ],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for PruneAcknowledgements {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, PRUNE_ACKNOWLEDGEMENTS_EVENT)?;
+
+        Ok(Self {
+            port_id_attr: (&attributes).try_into()?,
+            chan_id_attr: (&attributes).try_into()?,
+            total_pruned_sequences: attributes.parse("total_pruned_sequences")?,
+            total_remaining_sequences: attributes.parse("total_remaining_sequences")?,
+        })
+    }
+}
+
 /// A `ChannelClosed` event is emitted when a channel is closed as a result of a packet timing out. Note that
 /// since optimistic packet sends (i.e. send a packet before channel handshake is complete) are supported,
 /// we might not have a counterparty channel id value yet. This would happen if a packet is sent right
@@ -580,6 +770,25 @@ impl From<ChannelClosed> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for ChannelClosed {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, CHANNEL_CLOSED_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&attributes).try_into()?,
+            chan_id_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_b: (&attributes).try_into()?,
+            maybe_chan_id_attr_on_b: attributes
+                .parse_optional::<ChannelId>(COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY)?
+                .map(CounterpartyChannelIdAttribute::from),
+            conn_id_attr_on_a: (&attributes).try_into()?,
+            channel_ordering_attr: (&attributes).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -631,7 +840,7 @@ impl SendPacket {
         &self.timeout_height_attr_on_b.timeout_height
     }
 
-    pub fn timeout_timestamp_on_b(&self) -> &Timestamp {
+    pub fn timeout_timestamp_on_b(&self) -> &TimeoutTimestamp {
         &self.timeout_timestamp_attr_on_b.timeout_timestamp
     }
 
@@ -691,6 +900,27 @@ impl TryFrom<SendPacket> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for SendPacket {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, SEND_PACKET_EVENT)?;
+
+        Ok(Self {
+            packet_data_attr: (&attributes).try_into()?,
+            timeout_height_attr_on_b: (&attributes).try_into()?,
+            timeout_timestamp_attr_on_b: (&attributes).try_into()?,
+            seq_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_a: (&attributes).try_into()?,
+            chan_id_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_b: (&attributes).try_into()?,
+            chan_id_attr_on_b: (&attributes).try_into()?,
+            channel_ordering_attr: (&attributes).try_into()?,
+            conn_id_attr_on_a: (&attributes).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -742,7 +972,7 @@ impl ReceivePacket {
         &self.timeout_height_attr_on_b.timeout_height
     }
 
-    pub fn timeout_timestamp_on_b(&self) -> &Timestamp {
+    pub fn timeout_timestamp_on_b(&self) -> &TimeoutTimestamp {
         &self.timeout_timestamp_attr_on_b.timeout_timestamp
     }
 
@@ -802,6 +1032,27 @@ impl TryFrom<ReceivePacket> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for ReceivePacket {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, RECEIVE_PACKET_EVENT)?;
+
+        Ok(Self {
+            packet_data_attr: (&attributes).try_into()?,
+            timeout_height_attr_on_b: (&attributes).try_into()?,
+            timeout_timestamp_attr_on_b: (&attributes).try_into()?,
+            seq_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_a: (&attributes).try_into()?,
+            chan_id_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_b: (&attributes).try_into()?,
+            chan_id_attr_on_b: (&attributes).try_into()?,
+            channel_ordering_attr: (&attributes).try_into()?,
+            conn_id_attr_on_b: (&attributes).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -857,7 +1108,7 @@ impl WriteAcknowledgement {
         &self.timeout_height_attr_on_b.timeout_height
     }
 
-    pub fn timeout_timestamp_on_b(&self) -> &Timestamp {
+    pub fn timeout_timestamp_on_b(&self) -> &TimeoutTimestamp {
         &self.timeout_timestamp_attr_on_b.timeout_timestamp
     }
 
@@ -917,6 +1168,27 @@ impl TryFrom<WriteAcknowledgement> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for WriteAcknowledgement {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, WRITE_ACK_EVENT)?;
+
+        Ok(Self {
+            packet_data: (&attributes).try_into()?,
+            timeout_height_attr_on_b: (&attributes).try_into()?,
+            timeout_timestamp_attr_on_b: (&attributes).try_into()?,
+            seq_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_a: (&attributes).try_into()?,
+            chan_id_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_b: (&attributes).try_into()?,
+            chan_id_attr_on_b: (&attributes).try_into()?,
+            acknowledgement: (&attributes).try_into()?,
+            conn_id_attr_on_b: (&attributes).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -962,7 +1234,7 @@ impl AcknowledgePacket {
         &self.timeout_height_attr_on_b.timeout_height
     }
 
-    pub fn timeout_timestamp_on_b(&self) -> &Timestamp {
+    pub fn timeout_timestamp_on_b(&self) -> &TimeoutTimestamp {
         &self.timeout_timestamp_attr_on_b.timeout_timestamp
     }
 
@@ -1020,6 +1292,26 @@ impl TryFrom<AcknowledgePacket> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for AcknowledgePacket {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, ACK_PACKET_EVENT)?;
+
+        Ok(Self {
+            timeout_height_attr_on_b: (&attributes).try_into()?,
+            timeout_timestamp_attr_on_b: (&attributes).try_into()?,
+            seq_on_a: (&attributes).try_into()?,
+            port_id_attr_on_a: (&attributes).try_into()?,
+            chan_id_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_b: (&attributes).try_into()?,
+            chan_id_attr_on_b: (&attributes).try_into()?,
+            channel_ordering_attr: (&attributes).try_into()?,
+            conn_id_attr_on_a: (&attributes).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -1063,7 +1355,7 @@ impl TimeoutPacket {
         &self.timeout_height_attr_on_b.timeout_height
     }
 
-    pub fn timeout_timestamp_on_b(&self) -> &Timestamp {
+    pub fn timeout_timestamp_on_b(&self) -> &TimeoutTimestamp {
         &self.timeout_timestamp_attr_on_b.timeout_timestamp
     }
 
@@ -1116,6 +1408,25 @@ impl TryFrom<TimeoutPacket> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for TimeoutPacket {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, TIMEOUT_EVENT)?;
+
+        Ok(Self {
+            timeout_height_attr_on_b: (&attributes).try_into()?,
+            timeout_timestamp_attr_on_b: (&attributes).try_into()?,
+            seq_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_a: (&attributes).try_into()?,
+            chan_id_attr_on_a: (&attributes).try_into()?,
+            port_id_attr_on_b: (&attributes).try_into()?,
+            chan_id_attr_on_b: (&attributes).try_into()?,
+            channel_ordering_attr: (&attributes).try_into()?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tendermint::abci::Event as AbciEvent;
@@ -1228,8 +1539,8 @@ mod tests {
             Test {
                 kind: CHANNEL_CLOSE_CONFIRM_EVENT,
                 event: CloseConfirm::new(
-                    port_id,
-                    channel_id,
+                    port_id.clone(),
+                    channel_id.clone(),
                     counterparty_port_id,
                     counterparty_channel_id,
                     connection_id,
@@ -1238,6 +1549,17 @@ mod tests {
                 expected_keys: expected_keys[0..5].to_vec(),
                 expected_values: expected_values[0..5].to_vec(),
             },
+            Test {
+                kind: PRUNE_ACKNOWLEDGEMENTS_EVENT,
+                event: PruneAcknowledgements::new(port_id, channel_id, 3, 7).into(),
+                expected_keys: vec![
+                    "port_id",
+                    "channel_id",
+                    "total_pruned_sequences",
+                    "total_remaining_sequences",
+                ],
+                expected_values: vec!["transfer", "channel-0", "3", "7"],
+            },
         ];
 
         for t in tests {
@@ -6,14 +6,14 @@ use core::str;
 use derive_more::From;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::utils::abci::{AbciEventError, EventAttributes};
 use subtle_encoding::hex;
 use tendermint::abci;
 
 use crate::acknowledgement::Acknowledgement;
 use crate::channel::Order;
 use crate::error::ChannelError;
-use crate::timeout::TimeoutHeight;
+use crate::timeout::{TimeoutHeight, TimeoutTimestamp};
 
 const PKT_SEQ_ATTRIBUTE_KEY: &str = "packet_sequence";
 const PKT_DATA_ATTRIBUTE_KEY: &str = "packet_data";
@@ -28,6 +28,21 @@ const PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_timeout_timestamp";
 const PKT_ACK_ATTRIBUTE_KEY: &str = "packet_ack";
 const PKT_ACK_HEX_ATTRIBUTE_KEY: &str = "packet_ack_hex";
 const PKT_CONNECTION_ID_ATTRIBUTE_KEY: &str = "packet_connection";
+/// The key replacing `packet_connection` in the packet events of ibc-go v8.
+const CONNECTION_ID_ATTRIBUTE_KEY: &str = "connection_id";
+
+/// Decodes the bytes held by the hexadecimal attribute under `hex_key`, or by
+/// the UTF-8 attribute under `key` for events that only carry the latter.
+fn decode_bytes(
+    attributes: &EventAttributes<'_>,
+    hex_key: &str,
+    key: &str,
+) -> Result<Vec<u8>, AbciEventError> {
+    match attributes.find(hex_key) {
+        Some(value) => hex::decode(value).map_err(|_| attributes.invalid(hex_key, value)),
+        None => attributes.get(key).map(|value| value.as_bytes().to_vec()),
+    }
+}
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -59,8 +74,8 @@ impl TryFrom<PacketDataAttribute> for Vec<abci::EventAttribute> {
                 .into(),
             (
                 PKT_DATA_HEX_ATTRIBUTE_KEY,
-                str::from_utf8(&hex::encode(attr.packet_data))
-                    .expect("Never fails because hexadecimal is valid UTF8"),
+                str::from_utf8(&hex::encode(&attr.packet_data))
+                    .expect("Never fails because hexadecimal is valid UTF-8"),
             )
                 .into(),
         ];
@@ -69,6 +84,21 @@ impl TryFrom<PacketDataAttribute> for Vec<abci::EventAttribute> {
     }
 }
 
+/// The packet data is preferably decoded from its hexadecimal attribute, as the
+/// other one only holds packet data that is valid UTF-8.
+impl TryFrom<&EventAttributes<'_>> for PacketDataAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        decode_bytes(
+            attributes,
+            PKT_DATA_HEX_ATTRIBUTE_KEY,
+            PKT_DATA_ATTRIBUTE_KEY,
+        )
+        .map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -98,6 +128,19 @@ impl From<TimeoutHeightAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&EventAttributes<'_>> for TimeoutHeightAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        let timeout_height = match attributes.get(PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY)? {
+            "0-0" => TimeoutHeight::Never,
+            _ => TimeoutHeight::At(attributes.parse(PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY)?),
+        };
+
+        Ok(Self::from(timeout_height))
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -113,19 +156,36 @@ impl From<TimeoutHeightAttribute> for abci::EventAttribute {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct TimeoutTimestampAttribute {
-    pub timeout_timestamp: Timestamp,
+    pub timeout_timestamp: TimeoutTimestamp,
 }
 
 impl From<TimeoutTimestampAttribute> for abci::EventAttribute {
     fn from(attr: TimeoutTimestampAttribute) -> Self {
         (
             PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY,
-            attr.timeout_timestamp.nanoseconds().to_string(),
+            attr.timeout_timestamp.to_event_attribute_value(),
         )
             .into()
     }
 }
 
+impl TryFrom<&EventAttributes<'_>> for TimeoutTimestampAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        let nanoseconds: u64 = attributes.parse(PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY)?;
+
+        TimeoutTimestamp::try_from(nanoseconds)
+            .map(Self::from)
+            .map_err(|_| {
+                attributes.invalid(
+                    PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY,
+                    &nanoseconds.to_string(),
+                )
+            })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -150,6 +210,16 @@ impl From<SequenceAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&EventAttributes<'_>> for SequenceAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        attributes
+            .parse::<Sequence>(PKT_SEQ_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -174,6 +244,16 @@ impl From<SrcPortIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&EventAttributes<'_>> for SrcPortIdAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        attributes
+            .parse::<PortId>(PKT_SRC_PORT_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -198,6 +278,16 @@ impl From<SrcChannelIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&EventAttributes<'_>> for SrcChannelIdAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        attributes
+            .parse::<ChannelId>(PKT_SRC_CHANNEL_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -222,6 +312,16 @@ impl From<DstPortIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&EventAttributes<'_>> for DstPortIdAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        attributes
+            .parse::<PortId>(PKT_DST_PORT_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -246,6 +346,16 @@ impl From<DstChannelIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&EventAttributes<'_>> for DstChannelIdAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        attributes
+            .parse::<ChannelId>(PKT_DST_CHANNEL_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -270,6 +380,16 @@ impl From<ChannelOrderingAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&EventAttributes<'_>> for ChannelOrderingAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        attributes
+            .parse::<Order>(PKT_CHANNEL_ORDERING_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -294,6 +414,19 @@ impl From<PacketConnectionIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&EventAttributes<'_>> for PacketConnectionIdAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        let key = match attributes.find(PKT_CONNECTION_ID_ATTRIBUTE_KEY) {
+            Some(_) => PKT_CONNECTION_ID_ATTRIBUTE_KEY,
+            None => CONNECTION_ID_ATTRIBUTE_KEY,
+        };
+
+        attributes.parse::<ConnectionId>(key).map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -329,8 +462,8 @@ impl TryFrom<AcknowledgementAttribute> for Vec<abci::EventAttribute> {
                 .into(),
             (
                 PKT_ACK_HEX_ATTRIBUTE_KEY,
-                str::from_utf8(&hex::encode(attr.acknowledgement))
-                    .expect("Never fails because hexadecimal is always valid UTF-8"),
+                str::from_utf8(&hex::encode(attr.acknowledgement.as_bytes()))
+                    .expect("Never fails because hexadecimal is valid UTF-8"),
             )
                 .into(),
         ];
@@ -338,3 +471,16 @@ impl TryFrom<AcknowledgementAttribute> for Vec<abci::EventAttribute> {
         Ok(tags)
     }
 }
+
+impl TryFrom<&EventAttributes<'_>> for AcknowledgementAttribute {
+    type Error = AbciEventError;
+
+    fn try_from(attributes: &EventAttributes<'_>) -> Result<Self, Self::Error> {
+        let acknowledgement =
+            decode_bytes(attributes, PKT_ACK_HEX_ATTRIBUTE_KEY, PKT_ACK_ATTRIBUTE_KEY)?;
+
+        Acknowledgement::try_from(acknowledgement)
+            .map(Self::from)
+            .map_err(|_| attributes.invalid(PKT_ACK_HEX_ATTRIBUTE_KEY, ""))
+    }
+}
@@ -17,12 +17,16 @@ extern crate alloc;
 extern crate std;
 
 pub mod channel;
+pub mod chunk;
 pub mod error;
 pub mod events;
 
 pub mod msgs;
+pub mod multihop;
 pub mod packet;
 pub mod timeout;
+#[cfg(feature = "nostd-verify")]
+pub mod verify;
 
 pub mod acknowledgement;
 pub mod commitment;
@@ -31,11 +31,9 @@ pub struct MsgChannelOpenInit {
 }
 
 impl MsgChannelOpenInit {
-    /// Checks if the `connection_hops` has a length of `expected`.
-    ///
-    /// Note: Current IBC version only supports one connection hop.
+    /// Checks `connection_hops_on_a` with `channel::verify_connection_hops_length`.
     pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
-        verify_connection_hops_length(&self.connection_hops_on_a, 1)
+        verify_connection_hops_length(&self.connection_hops_on_a)
     }
 }
 
@@ -8,8 +8,10 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod prune_acknowledgements;
 mod recv_packet;
 mod timeout;
+mod timeout_batch;
 mod timeout_on_close;
 
 // Opening handshake messages.
@@ -24,8 +26,10 @@ pub use chan_open_init::*;
 pub use chan_open_try::*;
 use ibc_core_host_types::identifiers::*;
 use ibc_primitives::prelude::*;
+pub use prune_acknowledgements::*;
 pub use recv_packet::*;
 pub use timeout::*;
+pub use timeout_batch::*;
 pub use timeout_on_close::*;
 
 /// All channel messages
@@ -42,6 +46,7 @@ pub enum ChannelMsg {
     OpenConfirm(MsgChannelOpenConfirm),
     CloseInit(MsgChannelCloseInit),
     CloseConfirm(MsgChannelCloseConfirm),
+    PruneAcknowledgements(MsgPruneAcknowledgements),
 }
 
 /// All packet messages
@@ -56,6 +61,7 @@ pub enum PacketMsg {
     Ack(MsgAcknowledgement),
     Timeout(MsgTimeout),
     TimeoutOnClose(MsgTimeoutOnClose),
+    TimeoutBatch(MsgTimeoutBatch),
 }
 
 pub fn channel_msg_to_port_id(msg: &ChannelMsg) -> &PortId {
@@ -66,6 +72,7 @@ pub fn channel_msg_to_port_id(msg: &ChannelMsg) -> &PortId {
         ChannelMsg::OpenConfirm(msg) => &msg.port_id_on_b,
         ChannelMsg::CloseInit(msg) => &msg.port_id_on_a,
         ChannelMsg::CloseConfirm(msg) => &msg.port_id_on_b,
+        ChannelMsg::PruneAcknowledgements(msg) => &msg.port_id,
     }
 }
 
@@ -75,5 +82,29 @@ pub fn packet_msg_to_port_id(msg: &PacketMsg) -> &PortId {
         PacketMsg::Ack(msg) => &msg.packet.port_id_on_a,
         PacketMsg::Timeout(msg) => &msg.packet.port_id_on_a,
         PacketMsg::TimeoutOnClose(msg) => &msg.packet.port_id_on_a,
+        PacketMsg::TimeoutBatch(msg) => &msg.port_id_on_a,
+    }
+}
+
+/// Returns the identifier of the channel on this chain which the message
+/// applies to, or `None` for the messages opening a new channel.
+pub fn channel_msg_to_channel_id(msg: &ChannelMsg) -> Option<&ChannelId> {
+    match msg {
+        ChannelMsg::OpenInit(_) | ChannelMsg::OpenTry(_) => None,
+        ChannelMsg::OpenAck(msg) => Some(&msg.chan_id_on_a),
+        ChannelMsg::OpenConfirm(msg) => Some(&msg.chan_id_on_b),
+        ChannelMsg::CloseInit(msg) => Some(&msg.chan_id_on_a),
+        ChannelMsg::CloseConfirm(msg) => Some(&msg.chan_id_on_b),
+        ChannelMsg::PruneAcknowledgements(msg) => Some(&msg.chan_id),
+    }
+}
+
+pub fn packet_msg_to_channel_id(msg: &PacketMsg) -> &ChannelId {
+    match msg {
+        PacketMsg::Recv(msg) => &msg.packet.chan_id_on_b,
+        PacketMsg::Ack(msg) => &msg.packet.chan_id_on_a,
+        PacketMsg::Timeout(msg) => &msg.packet.chan_id_on_a,
+        PacketMsg::TimeoutOnClose(msg) => &msg.packet.chan_id_on_a,
+        PacketMsg::TimeoutBatch(msg) => &msg.chan_id_on_a,
     }
 }
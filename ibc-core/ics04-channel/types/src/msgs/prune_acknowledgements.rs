@@ -0,0 +1,57 @@
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgPruneAcknowledgements as RawMsgPruneAcknowledgements;
+use ibc_proto::Protobuf;
+
+use crate::error::ChannelError;
+
+pub const PRUNE_ACKNOWLEDGEMENTS_TYPE_URL: &str = "/ibc.core.channel.v1.MsgPruneAcknowledgements";
+
+///
+/// Message definition for pruning the packet acknowledgements and receipts
+/// left over from before the upgrade of a channel (`PruneAcknowledgements`
+/// datagram), up to `limit` sequences at a time.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgPruneAcknowledgements {
+    pub port_id: PortId,
+    pub chan_id: ChannelId,
+    pub limit: u64,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgPruneAcknowledgements> for MsgPruneAcknowledgements {}
+
+impl TryFrom<RawMsgPruneAcknowledgements> for MsgPruneAcknowledgements {
+    type Error = ChannelError;
+
+    fn try_from(raw_msg: RawMsgPruneAcknowledgements) -> Result<Self, Self::Error> {
+        if raw_msg.limit == 0 {
+            return Err(ChannelError::ZeroPruningLimit);
+        }
+
+        Ok(MsgPruneAcknowledgements {
+            port_id: raw_msg.port_id.parse()?,
+            chan_id: raw_msg.channel_id.parse()?,
+            limit: raw_msg.limit,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgPruneAcknowledgements> for RawMsgPruneAcknowledgements {
+    fn from(domain_msg: MsgPruneAcknowledgements) -> Self {
+        RawMsgPruneAcknowledgements {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.chan_id.to_string(),
+            limit: domain_msg.limit,
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
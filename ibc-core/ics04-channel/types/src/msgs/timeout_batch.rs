@@ -0,0 +1,171 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::Protobuf;
+
+use crate::error::PacketError;
+use crate::packet::Packet;
+
+pub const TIMEOUT_BATCH_TYPE_URL: &str = "/ibc.rs.core.channel.v1.MsgTimeoutBatch";
+
+/// Raw protobuf type of the batch timeout message, which `ibc-proto` does not
+/// define. It lives in the `ibc.rs` package owned by ibc-rs, so as not to
+/// claim a name in the packages of ibc-go.
+pub mod proto {
+    use ibc_primitives::prelude::*;
+    use ibc_proto::ibc::core::channel::v1::Packet;
+    use ibc_proto::ibc::core::client::v1::Height;
+
+    /// Times out a contiguous range of packets sent on a channel.
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MsgTimeoutBatch {
+        #[prost(string, tag = "1")]
+        pub source_port: String,
+        #[prost(string, tag = "2")]
+        pub source_channel: String,
+        #[prost(message, repeated, tag = "3")]
+        pub packets: Vec<Packet>,
+        #[prost(bytes = "vec", repeated, tag = "4")]
+        pub proofs_unreceived: Vec<Vec<u8>>,
+        #[prost(message, optional, tag = "5")]
+        pub proof_height: Option<Height>,
+        #[prost(uint64, tag = "6")]
+        pub next_sequence_recv: u64,
+        #[prost(string, tag = "7")]
+        pub signer: String,
+    }
+
+    impl prost::Name for MsgTimeoutBatch {
+        const NAME: &'static str = "MsgTimeoutBatch";
+        const PACKAGE: &'static str = "ibc.rs.core.channel.v1";
+    }
+}
+
+use proto::MsgTimeoutBatch as RawMsgTimeoutBatch;
+
+///
+/// Message definition for the timeout of a contiguous range of packets sent
+/// on the same channel, which is sent on chain A and needs to prove that none
+/// of the packets were received on chain B
+///
+/// On ordered channels, a single proof of `next_seq_recv_on_b` covers all the
+/// packets. On unordered channels, `proofs_unreceived_on_b` holds the proof of
+/// absence of the receipt of each packet, in the order of `packets`.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgTimeoutBatch {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub packets: Vec<Packet>,
+    pub next_seq_recv_on_b: Sequence,
+    pub proofs_unreceived_on_b: Vec<CommitmentProofBytes>,
+    pub proof_height_on_b: Height,
+    pub signer: Signer,
+}
+
+impl MsgTimeoutBatch {
+    /// Checks that the packets are sent on the channel of the message, with
+    /// increasing and contiguous sequences, and that there is either a single
+    /// shared proof or one proof per packet.
+    pub fn verify_batch(&self) -> Result<(), PacketError> {
+        let invalid_batch = |description: String| PacketError::InvalidTimeoutBatch { description };
+
+        let Some(first_packet) = self.packets.first() else {
+            return Err(PacketError::MissingPacket);
+        };
+
+        let mut expected_seq = first_packet.seq_on_a;
+        for packet in &self.packets {
+            if packet.port_id_on_a != self.port_id_on_a
+                || packet.chan_id_on_a != self.chan_id_on_a
+                || packet.port_id_on_b != first_packet.port_id_on_b
+                || packet.chan_id_on_b != first_packet.chan_id_on_b
+            {
+                return Err(invalid_batch(format!(
+                    "packet {} is not sent on the channel of the batch",
+                    packet.seq_on_a
+                )));
+            }
+
+            if packet.seq_on_a != expected_seq {
+                return Err(invalid_batch(format!(
+                    "packet sequences are not contiguous: expected {expected_seq}, got {}",
+                    packet.seq_on_a
+                )));
+            }
+            expected_seq = expected_seq.increment();
+        }
+
+        let proof_count = self.proofs_unreceived_on_b.len();
+        if proof_count != 1 && proof_count != self.packets.len() {
+            return Err(invalid_batch(format!(
+                "expected 1 or {} proofs, got {proof_count}",
+                self.packets.len()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawMsgTimeoutBatch> for MsgTimeoutBatch {}
+
+impl TryFrom<RawMsgTimeoutBatch> for MsgTimeoutBatch {
+    type Error = PacketError;
+
+    fn try_from(raw_msg: RawMsgTimeoutBatch) -> Result<Self, Self::Error> {
+        if raw_msg.next_sequence_recv == 0 {
+            return Err(PacketError::ZeroPacketSequence);
+        }
+        let msg = MsgTimeoutBatch {
+            port_id_on_a: raw_msg.source_port.parse()?,
+            chan_id_on_a: raw_msg.source_channel.parse()?,
+            packets: raw_msg
+                .packets
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            next_seq_recv_on_b: Sequence::from(raw_msg.next_sequence_recv),
+            proofs_unreceived_on_b: raw_msg
+                .proofs_unreceived
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()
+                .map_err(|_| PacketError::InvalidProof)?,
+            proof_height_on_b: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(PacketError::MissingHeight)?,
+            signer: raw_msg.signer.into(),
+        };
+
+        msg.verify_batch()?;
+
+        Ok(msg)
+    }
+}
+
+impl From<MsgTimeoutBatch> for RawMsgTimeoutBatch {
+    fn from(domain_msg: MsgTimeoutBatch) -> Self {
+        RawMsgTimeoutBatch {
+            source_port: domain_msg.port_id_on_a.to_string(),
+            source_channel: domain_msg.chan_id_on_a.to_string(),
+            packets: domain_msg.packets.into_iter().map(Into::into).collect(),
+            proofs_unreceived: domain_msg
+                .proofs_unreceived_on_b
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            proof_height: Some(domain_msg.proof_height_on_b.into()),
+            next_sequence_recv: domain_msg.next_seq_recv_on_b.into(),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! Defines the proofs of the channels routed through intermediate chains, as
+//! per [ICS-33](https://github.com/cosmos/ibc/tree/main/spec/core/ics-033-multi-hop).
+
+use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_core_commitment_types::merkle::apply_prefix;
+use ibc_core_commitment_types::proto::v1::MerklePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use prost::Message;
+
+use crate::error::ChannelError;
+
+/// Raw protobuf types of the multi-hop proofs, mirroring the
+/// `ibc.core.channel.v1` definitions of ibc-go, which `ibc-proto` does not
+/// ship yet.
+pub mod proto {
+    use ibc_primitives::prelude::*;
+
+    use super::MerklePath;
+
+    /// Proof of a value stored on a chain along a multi-hop channel.
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MultihopProof {
+        #[prost(bytes = "vec", tag = "1")]
+        pub proof: Vec<u8>,
+        #[prost(bytes = "vec", tag = "2")]
+        pub value: Vec<u8>,
+        #[prost(message, optional, tag = "3")]
+        pub prefixed_key: Option<MerklePath>,
+    }
+
+    impl prost::Name for MultihopProof {
+        const NAME: &'static str = "MultihopProof";
+        const PACKAGE: &'static str = "ibc.core.channel.v1";
+    }
+
+    /// Proofs of a key on the counterparty chain of a multi-hop channel,
+    /// along with the proofs of the intermediate chains routing to it.
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MsgMultihopProofs {
+        #[prost(message, optional, tag = "1")]
+        pub key_proof: Option<MultihopProof>,
+        #[prost(message, repeated, tag = "2")]
+        pub connection_proofs: Vec<MultihopProof>,
+        #[prost(message, repeated, tag = "3")]
+        pub consensus_proofs: Vec<MultihopProof>,
+    }
+
+    impl prost::Name for MsgMultihopProofs {
+        const NAME: &'static str = "MsgMultihopProofs";
+        const PACKAGE: &'static str = "ibc.core.channel.v1";
+    }
+}
+
+/// Proof of a `value` stored under `prefixed_key` on a chain along a
+/// multi-hop channel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultihopProof {
+    pub proof: CommitmentProofBytes,
+    pub value: Vec<u8>,
+    pub prefixed_key: MerklePath,
+}
+
+impl MultihopProof {
+    /// Returns the path of the proven value on its chain, stripped of the
+    /// store prefix of the chain, which must match `prefix`.
+    pub fn unprefixed_key(&self, prefix: &CommitmentPrefix) -> Result<&str, ChannelError> {
+        match self.prefixed_key.key_path.as_slice() {
+            [.., key] if apply_prefix(prefix, vec![key.clone()]) == self.prefixed_key => Ok(key),
+            _ => Err(ChannelError::InvalidMultihopProof {
+                description: format!(
+                    "key path {:?} is not prefixed by {prefix:?}",
+                    self.prefixed_key.key_path
+                ),
+            }),
+        }
+    }
+
+    /// Decodes the proven value as an `Any`, the encoding under which
+    /// consensus states are stored.
+    pub fn value_as_any(&self) -> Result<Any, ChannelError> {
+        Any::decode(self.value.as_slice()).map_err(|e| ChannelError::InvalidMultihopProof {
+            description: e.to_string(),
+        })
+    }
+}
+
+impl Protobuf<proto::MultihopProof> for MultihopProof {}
+
+impl TryFrom<proto::MultihopProof> for MultihopProof {
+    type Error = ChannelError;
+
+    fn try_from(raw: proto::MultihopProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proof: raw
+                .proof
+                .try_into()
+                .map_err(|_| ChannelError::InvalidProof)?,
+            value: raw.value,
+            prefixed_key: raw
+                .prefixed_key
+                .ok_or_else(|| ChannelError::InvalidMultihopProof {
+                    description: "missing prefixed key".to_string(),
+                })?,
+        })
+    }
+}
+
+impl From<MultihopProof> for proto::MultihopProof {
+    fn from(value: MultihopProof) -> Self {
+        Self {
+            proof: value.proof.into(),
+            value: value.value,
+            prefixed_key: Some(value.prefixed_key),
+        }
+    }
+}
+
+/// Proofs of a key on the counterparty chain of a multi-hop channel, carried
+/// in place of the single-hop proof of the channel and packet messages.
+///
+/// The connection and consensus proofs are ordered from the intermediate
+/// chain adjacent to the host to the one adjacent to the counterparty. Each
+/// proves, on its chain, the connection end of the next hop of the channel
+/// and the consensus state of the next chain held by the client of that
+/// connection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MsgMultihopProofs {
+    pub key_proof: MultihopProof,
+    pub connection_proofs: Vec<MultihopProof>,
+    pub consensus_proofs: Vec<MultihopProof>,
+}
+
+impl Protobuf<proto::MsgMultihopProofs> for MsgMultihopProofs {}
+
+impl TryFrom<proto::MsgMultihopProofs> for MsgMultihopProofs {
+    type Error = ChannelError;
+
+    fn try_from(raw: proto::MsgMultihopProofs) -> Result<Self, Self::Error> {
+        let connection_proofs = raw
+            .connection_proofs
+            .into_iter()
+            .map(MultihopProof::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let consensus_proofs = raw
+            .consensus_proofs
+            .into_iter()
+            .map(MultihopProof::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if connection_proofs.len() != consensus_proofs.len() {
+            return Err(ChannelError::InvalidMultihopProof {
+                description: format!(
+                    "{} connection proofs for {} consensus proofs",
+                    connection_proofs.len(),
+                    consensus_proofs.len()
+                ),
+            });
+        }
+
+        Ok(Self {
+            key_proof: raw
+                .key_proof
+                .ok_or_else(|| ChannelError::InvalidMultihopProof {
+                    description: "missing key proof".to_string(),
+                })?
+                .try_into()?,
+            connection_proofs,
+            consensus_proofs,
+        })
+    }
+}
+
+impl From<MsgMultihopProofs> for proto::MsgMultihopProofs {
+    fn from(value: MsgMultihopProofs) -> Self {
+        Self {
+            key_proof: Some(value.key_proof.into()),
+            connection_proofs: value
+                .connection_proofs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            consensus_proofs: value.consensus_proofs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<&CommitmentProofBytes> for MsgMultihopProofs {
+    type Error = ChannelError;
+
+    fn try_from(value: &CommitmentProofBytes) -> Result<Self, Self::Error> {
+        Protobuf::<proto::MsgMultihopProofs>::decode(value.as_ref()).map_err(|e| {
+            ChannelError::InvalidMultihopProof {
+                description: e.to_string(),
+            }
+        })
+    }
+}
+
+impl TryFrom<MsgMultihopProofs> for CommitmentProofBytes {
+    type Error = ChannelError;
+
+    fn try_from(value: MsgMultihopProofs) -> Result<Self, Self::Error> {
+        Protobuf::<proto::MsgMultihopProofs>::encode_vec(value)
+            .try_into()
+            .map_err(|_| ChannelError::InvalidProof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proof(key: &str) -> MultihopProof {
+        MultihopProof {
+            proof: vec![1].try_into().unwrap(),
+            value: key.as_bytes().to_vec(),
+            prefixed_key: apply_prefix(&CommitmentPrefix::empty(), vec![key.to_string()]),
+        }
+    }
+
+    #[test]
+    fn multihop_proofs_round_trip() {
+        let proofs = MsgMultihopProofs {
+            key_proof: proof("commitments/ports/transfer/channels/channel-0/sequences/1"),
+            connection_proofs: vec![proof("connections/connection-1")],
+            consensus_proofs: vec![proof("clients/07-tendermint-1/consensusStates/0-5")],
+        };
+
+        let bytes = CommitmentProofBytes::try_from(proofs.clone()).unwrap();
+
+        assert_eq!(MsgMultihopProofs::try_from(&bytes).unwrap(), proofs);
+    }
+
+    #[test]
+    fn unbalanced_multihop_proofs() {
+        let raw = proto::MsgMultihopProofs {
+            key_proof: Some(proof("connections/connection-0").into()),
+            connection_proofs: vec![proof("connections/connection-1").into()],
+            consensus_proofs: vec![],
+        };
+
+        assert!(MsgMultihopProofs::try_from(raw).is_err());
+    }
+
+    #[test]
+    fn unprefixed_key() {
+        let prefix = CommitmentPrefix::empty();
+        let proof = proof("connections/connection-1");
+
+        assert_eq!(
+            proof.unprefixed_key(&prefix).unwrap(),
+            "connections/connection-1"
+        );
+        assert!(proof
+            .unprefixed_key(&CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap())
+            .is_err());
+    }
+}
@@ -2,11 +2,10 @@
 use ibc_core_client_types::Height;
 use ibc_core_host_types::identifiers::{ChannelId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Expiry::Expired;
 use ibc_primitives::Timestamp;
 use ibc_proto::ibc::core::channel::v1::{Packet as RawPacket, PacketState as RawPacketState};
 
-use super::timeout::TimeoutHeight;
+use super::timeout::{TimeoutHeight, TimeoutTimestamp};
 use crate::error::PacketError;
 
 /// Enumeration of proof carrying ICS4 message, helper for relayer.
@@ -33,7 +32,7 @@ pub enum PacketMsgType {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Receipt {
     Ok,
 }
@@ -80,7 +79,7 @@ pub struct Packet {
     )]
     pub data: Vec<u8>,
     pub timeout_height_on_b: TimeoutHeight,
-    pub timeout_timestamp_on_b: Timestamp,
+    pub timeout_timestamp_on_b: TimeoutTimestamp,
 }
 
 struct PacketData<'a>(&'a [u8]);
@@ -140,8 +139,7 @@ impl Packet {
     pub fn timed_out(&self, dst_chain_ts: &Timestamp, dst_chain_height: Height) -> bool {
         let height_timed_out = self.timeout_height_on_b.has_expired(dst_chain_height);
 
-        let timestamp_timed_out = self.timeout_timestamp_on_b.is_set()
-            && dst_chain_ts.check_expiry(&self.timeout_timestamp_on_b) == Expired;
+        let timestamp_timed_out = self.timeout_timestamp_on_b.has_expired(dst_chain_ts);
 
         height_timed_out || timestamp_timed_out
     }
@@ -189,7 +187,9 @@ impl TryFrom<RawPacket> for Packet {
             .try_into()
             .map_err(|_| PacketError::InvalidTimeoutHeight)?;
 
-        let timeout_timestamp_on_b = Timestamp::from_nanoseconds(raw_pkt.timeout_timestamp)
+        let timeout_timestamp_on_b: TimeoutTimestamp = raw_pkt
+            .timeout_timestamp
+            .try_into()
             .map_err(PacketError::InvalidPacketTimestamp)?;
 
         // Packet timeout height and packet timeout timestamp cannot both be unset.
@@ -220,7 +220,7 @@ impl From<Packet> for RawPacket {
             destination_channel: packet.chan_id_on_b.to_string(),
             data: packet.data,
             timeout_height: packet.timeout_height_on_b.into(),
-            timeout_timestamp: packet.timeout_timestamp_on_b.nanoseconds(),
+            timeout_timestamp: packet.timeout_timestamp_on_b.into(),
         }
     }
 }
@@ -5,6 +5,7 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::Height;
 use ibc_primitives::prelude::*;
+use ibc_primitives::{Expiry, ParseTimestampError, Timestamp};
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 
 /// Indicates a consensus height on the destination chain after which the packet
@@ -142,11 +143,111 @@ impl Display for TimeoutHeight {
     }
 }
 
+/// Indicates a timestamp on the destination chain after which the packet will
+/// no longer be processed, and will instead count as having timed-out.
+///
+/// The IBC protocol represents timeout timestamps as `u64` Unix timestamps in
+/// nanoseconds, where `0` means "no timeout". `TimeoutTimestamp` makes this
+/// convention explicit, the same way [`TimeoutHeight`] does for heights.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum TimeoutTimestamp {
+    Never,
+    At(Timestamp),
+}
+
+impl TimeoutTimestamp {
+    /// Returns if the timeout timestamp is set.
+    pub fn is_set(&self) -> bool {
+        match self {
+            TimeoutTimestamp::At(_) => true,
+            TimeoutTimestamp::Never => false,
+        }
+    }
+
+    pub fn no_timeout() -> Self {
+        Self::Never
+    }
+
+    /// Unix timestamp in nanoseconds to be used in packet commitment
+    /// computation and in the protobuf representation.
+    pub fn nanoseconds(&self) -> u64 {
+        match self {
+            Self::At(timestamp) => timestamp.nanoseconds(),
+            Self::Never => 0,
+        }
+    }
+
+    /// Check if a timestamp is *strictly past* the timeout timestamp, and thus
+    /// is deemed expired.
+    pub fn has_expired(&self, timestamp: &Timestamp) -> bool {
+        match self {
+            Self::At(timeout_timestamp) => {
+                timestamp.check_expiry(timeout_timestamp) == Expiry::Expired
+            }
+            // When there's no timeout, timestamps are never expired
+            Self::Never => false,
+        }
+    }
+
+    /// Returns a string formatted for an ABCI event attribute value.
+    pub fn to_event_attribute_value(self) -> String {
+        self.nanoseconds().to_string()
+    }
+}
+
+impl TryFrom<u64> for TimeoutTimestamp {
+    type Error = ParseTimestampError;
+
+    fn try_from(nanoseconds: u64) -> Result<Self, Self::Error> {
+        Timestamp::from_nanoseconds(nanoseconds).map(Self::from)
+    }
+}
+
+impl From<TimeoutTimestamp> for u64 {
+    fn from(timeout_timestamp: TimeoutTimestamp) -> Self {
+        timeout_timestamp.nanoseconds()
+    }
+}
+
+/// An unset [`Timestamp`] maps to "no timeout".
+impl From<Timestamp> for TimeoutTimestamp {
+    fn from(timestamp: Timestamp) -> Self {
+        if timestamp.is_set() {
+            Self::At(timestamp)
+        } else {
+            Self::Never
+        }
+    }
+}
+
+impl Display for TimeoutTimestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            TimeoutTimestamp::At(timeout_timestamp) => write!(f, "{timeout_timestamp}"),
+            TimeoutTimestamp::Never => write!(f, "no timeout"),
+        }
+    }
+}
+
 #[cfg(feature = "serde")]
 mod tests {
+    use ibc_primitives::Timestamp;
     use serde::{Deserialize, Serialize};
 
-    use super::TimeoutHeight;
+    use super::{TimeoutHeight, TimeoutTimestamp};
 
     impl Serialize for TimeoutHeight {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -202,4 +303,56 @@ mod tests {
             })
         }
     }
+
+    // `TimeoutTimestamp` keeps the representation of the `Timestamp` it
+    // replaced in packets, with "no timeout" being an unset timestamp.
+    impl Serialize for TimeoutTimestamp {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            match self {
+                TimeoutTimestamp::Never => Timestamp::none().serialize(serializer),
+                TimeoutTimestamp::At(timestamp) => timestamp.serialize(serializer),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for TimeoutTimestamp {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            Timestamp::deserialize(deserializer).map(TimeoutTimestamp::from)
+        }
+    }
+
+    #[test]
+    fn timeout_timestamp_from_nanoseconds() {
+        assert_eq!(
+            TimeoutTimestamp::try_from(0u64).unwrap(),
+            TimeoutTimestamp::Never
+        );
+
+        let timeout_timestamp = TimeoutTimestamp::try_from(42u64).unwrap();
+        assert_eq!(
+            timeout_timestamp,
+            TimeoutTimestamp::At(Timestamp::from_nanoseconds(42).unwrap())
+        );
+        assert_eq!(u64::from(timeout_timestamp), 42);
+        assert_eq!(u64::from(TimeoutTimestamp::Never), 0);
+    }
+
+    #[test]
+    fn timeout_timestamp_expiry() {
+        let timeout_timestamp = TimeoutTimestamp::try_from(42u64).unwrap();
+
+        assert!(!timeout_timestamp.has_expired(&Timestamp::from_nanoseconds(41).unwrap()));
+        assert!(!timeout_timestamp.has_expired(&Timestamp::from_nanoseconds(42).unwrap()));
+        assert!(timeout_timestamp.has_expired(&Timestamp::from_nanoseconds(43).unwrap()));
+
+        assert!(!TimeoutTimestamp::Never.has_expired(&Timestamp::from_nanoseconds(43).unwrap()));
+        // An unknown host timestamp never expires a packet.
+        assert!(!timeout_timestamp.has_expired(&Timestamp::none()));
+    }
 }
@@ -0,0 +1,263 @@
+//! Allocation-free validation of packets, heights, timestamps and identifiers.
+//!
+//! The functions of this module mirror the checks performed when decoding
+//! the corresponding domain types, but operate on borrowed data and report
+//! errors that carry no heap-allocated context. They are meant for
+//! constrained environments, e.g. zk circuits or embedded signers, that only
+//! need to tell whether IBC data is well-formed.
+
+use core::str::FromStr;
+
+use displaydoc::Display;
+use ibc_core_host_types::validate::{
+    is_valid_identifier_chars, is_valid_identifier_length, CHANNEL_ID_MAX_LENGTH,
+    CHANNEL_ID_MIN_LENGTH, CLIENT_ID_MAX_LENGTH, CLIENT_ID_MIN_LENGTH, CONNECTION_ID_MAX_LENGTH,
+    CONNECTION_ID_MIN_LENGTH, PORT_ID_MAX_LENGTH, PORT_ID_MIN_LENGTH,
+};
+
+/// Errors raised by the allocation-free validation functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Display)]
+pub enum VerifyError {
+    /// identifier contains invalid characters
+    InvalidIdentifierCharacter,
+    /// identifier has length `{length}`, expected between `{min}` and `{max}`
+    InvalidIdentifierLength { length: u64, min: u64, max: u64 },
+    /// height is not of the form `{{revision_number}}-{{revision_height}}`
+    InvalidHeightFormat,
+    /// height cannot have a zero revision height
+    ZeroHeight,
+    /// timestamp is not a number of nanoseconds
+    InvalidTimestamp,
+    /// packet sequence cannot be 0
+    ZeroPacketSequence,
+    /// packet data bytes cannot be empty
+    ZeroPacketData,
+    /// packet timeout height and timeout timestamp cannot both be unset
+    MissingTimeout,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VerifyError {}
+
+/// Borrowed view of the fields of a packet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PacketRef<'a> {
+    pub sequence: u64,
+    pub source_port: &'a str,
+    pub source_channel: &'a str,
+    pub destination_port: &'a str,
+    pub destination_channel: &'a str,
+    pub data: &'a [u8],
+    pub timeout_revision_number: u64,
+    pub timeout_revision_height: u64,
+    pub timeout_timestamp: u64,
+}
+
+fn verify_identifier(id: &str, min: u64, max: u64) -> Result<(), VerifyError> {
+    if !is_valid_identifier_chars(id) {
+        return Err(VerifyError::InvalidIdentifierCharacter);
+    }
+
+    if !is_valid_identifier_length(id, min, max) {
+        return Err(VerifyError::InvalidIdentifierLength {
+            length: id.len() as u64,
+            min,
+            max,
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that `id` is a valid client identifier.
+pub fn verify_client_identifier(id: &str) -> Result<(), VerifyError> {
+    verify_identifier(id, CLIENT_ID_MIN_LENGTH, CLIENT_ID_MAX_LENGTH)
+}
+
+/// Checks that `id` is a valid connection identifier.
+pub fn verify_connection_identifier(id: &str) -> Result<(), VerifyError> {
+    verify_identifier(id, CONNECTION_ID_MIN_LENGTH, CONNECTION_ID_MAX_LENGTH)
+}
+
+/// Checks that `id` is a valid port identifier.
+pub fn verify_port_identifier(id: &str) -> Result<(), VerifyError> {
+    verify_identifier(id, PORT_ID_MIN_LENGTH, PORT_ID_MAX_LENGTH)
+}
+
+/// Checks that `id` is a valid channel identifier.
+pub fn verify_channel_identifier(id: &str) -> Result<(), VerifyError> {
+    verify_identifier(id, CHANNEL_ID_MIN_LENGTH, CHANNEL_ID_MAX_LENGTH)
+}
+
+/// Checks that `height` is a valid height of the form
+/// `{revision_number}-{revision_height}`, and returns its components.
+pub fn verify_height(height: &str) -> Result<(u64, u64), VerifyError> {
+    let (revision_number, revision_height) = height
+        .split_once('-')
+        .ok_or(VerifyError::InvalidHeightFormat)?;
+
+    let revision_number =
+        u64::from_str(revision_number).map_err(|_| VerifyError::InvalidHeightFormat)?;
+    let revision_height =
+        u64::from_str(revision_height).map_err(|_| VerifyError::InvalidHeightFormat)?;
+
+    if revision_height == 0 {
+        return Err(VerifyError::ZeroHeight);
+    }
+
+    Ok((revision_number, revision_height))
+}
+
+/// Checks that `timestamp` is a valid number of nanoseconds since the Unix
+/// epoch, and returns it. Zero stands for an unset timestamp.
+pub fn verify_timestamp(timestamp: &str) -> Result<u64, VerifyError> {
+    u64::from_str(timestamp).map_err(|_| VerifyError::InvalidTimestamp)
+}
+
+/// Checks that `packet` would be accepted as a packet by the channel
+/// handlers.
+pub fn verify_packet(packet: &PacketRef<'_>) -> Result<(), VerifyError> {
+    if packet.sequence == 0 {
+        return Err(VerifyError::ZeroPacketSequence);
+    }
+
+    if packet.data.is_empty() {
+        return Err(VerifyError::ZeroPacketData);
+    }
+
+    // A zero timeout height is only valid as the unset timeout height `0-0`.
+    let timeout_height_set =
+        packet.timeout_revision_number != 0 || packet.timeout_revision_height != 0;
+    if timeout_height_set && packet.timeout_revision_height == 0 {
+        return Err(VerifyError::ZeroHeight);
+    }
+
+    if !timeout_height_set && packet.timeout_timestamp == 0 {
+        return Err(VerifyError::MissingTimeout);
+    }
+
+    verify_port_identifier(packet.source_port)?;
+    verify_channel_identifier(packet.source_channel)?;
+    verify_port_identifier(packet.destination_port)?;
+    verify_channel_identifier(packet.destination_channel)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_client_types::Height;
+    use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+    use ibc_primitives::prelude::*;
+    use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use super::*;
+    use crate::packet::Packet;
+
+    #[test]
+    fn identifiers_match_domain_types() {
+        let ids = [
+            "07-tendermint-0",
+            "connection-0",
+            "channel-0",
+            "transfer",
+            "p",
+            "channel",
+            "chan/nel-0",
+            "",
+        ];
+
+        for id in ids {
+            assert_eq!(
+                verify_client_identifier(id).is_ok(),
+                ClientId::from_str(id).is_ok()
+            );
+            assert_eq!(
+                verify_connection_identifier(id).is_ok(),
+                ConnectionId::from_str(id).is_ok()
+            );
+            assert_eq!(
+                verify_port_identifier(id).is_ok(),
+                PortId::from_str(id).is_ok()
+            );
+            assert_eq!(
+                verify_channel_identifier(id).is_ok(),
+                ChannelId::from_str(id).is_ok()
+            );
+        }
+    }
+
+    #[test]
+    fn heights_match_domain_type() {
+        for height in ["1-1", "0-10", "1-0", "1", "a-1", "1-1-1"] {
+            assert_eq!(
+                verify_height(height).ok(),
+                Height::from_str(height)
+                    .ok()
+                    .map(|h| (h.revision_number(), h.revision_height())),
+                "height {height}"
+            );
+        }
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(verify_timestamp("0"), Ok(0));
+        assert_eq!(
+            verify_timestamp("1700000000000000000"),
+            Ok(1_700_000_000_000_000_000)
+        );
+        assert_eq!(verify_timestamp("-1"), Err(VerifyError::InvalidTimestamp));
+        assert_eq!(verify_timestamp("now"), Err(VerifyError::InvalidTimestamp));
+    }
+
+    #[test]
+    fn packets_match_domain_type() {
+        // (sequence, data, timeout revision number, timeout revision height, timeout timestamp)
+        let cases: [(u64, &[u8], u64, u64, u64); 6] = [
+            (1, b"data", 0, 10, 0),
+            (1, b"data", 0, 0, 1),
+            (0, b"data", 0, 10, 0),
+            (1, b"", 0, 10, 0),
+            (1, b"data", 1, 0, 1),
+            (1, b"data", 0, 0, 0),
+        ];
+
+        for (sequence, data, timeout_revision_number, timeout_revision_height, timeout_timestamp) in
+            cases
+        {
+            let packet = PacketRef {
+                sequence,
+                source_port: "transfer",
+                source_channel: "channel-0",
+                destination_port: "transfer",
+                destination_channel: "channel-1",
+                data,
+                timeout_revision_number,
+                timeout_revision_height,
+                timeout_timestamp,
+            };
+
+            let raw = RawPacket {
+                sequence,
+                source_port: packet.source_port.to_string(),
+                source_channel: packet.source_channel.to_string(),
+                destination_port: packet.destination_port.to_string(),
+                destination_channel: packet.destination_channel.to_string(),
+                data: data.to_vec(),
+                timeout_height: Some(RawHeight {
+                    revision_number: timeout_revision_number,
+                    revision_height: timeout_revision_height,
+                }),
+                timeout_timestamp,
+            };
+
+            assert_eq!(
+                verify_packet(&packet).is_ok(),
+                Packet::try_from(raw).is_ok(),
+                "packet {packet:?}"
+            );
+        }
+    }
+}
@@ -128,6 +128,11 @@ impl<'a> TryFrom<&'a CommitmentProofBytes> for MerkleProof {
 
 /// Defines a store prefix of the commitment proof.
 ///
+/// Prefixes are non-empty, as the ones of Cosmos SDK chains, unless explicitly
+/// built with [`CommitmentPrefix::empty`] or
+/// [`CommitmentPrefix::from_bytes_allow_empty`] for hosts committing their IBC
+/// state at the root of their store.
+///
 /// See [spec](https://github.com/cosmos/ibc/blob/main/spec/core/ics-023-vector-commitments/README.md#prefix).
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -157,9 +162,21 @@ impl CommitmentPrefix {
         self.bytes
     }
 
+    /// Returns the empty prefix, of the hosts committing their IBC state at
+    /// the root of their store.
     pub fn empty() -> Self {
         Self { bytes: Vec::new() }
     }
+
+    /// Builds a prefix out of the given bytes, which unlike
+    /// `CommitmentPrefix::try_from` accepts an empty prefix.
+    pub fn from_bytes_allow_empty(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
 }
 
 impl TryFrom<Vec<u8>> for CommitmentPrefix {
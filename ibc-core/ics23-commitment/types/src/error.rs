@@ -17,6 +17,10 @@ pub enum CommitmentError {
     EmptyProofSpecs,
     /// invalid depth range: [{0}, {1}]
     InvalidDepthRange(i32, i32),
+    /// missing leaf spec
+    MissingLeafSpec,
+    /// missing inner spec
+    MissingInnerSpec,
     /// mismatch between the number of proofs with that of specs
     NumberOfSpecsMismatch,
     /// mismatch between the number of proofs with that of keys
@@ -19,6 +19,11 @@ pub mod commitment;
 pub mod error;
 pub mod merkle;
 pub mod specs;
+pub mod verification;
+pub mod verifier;
+
+#[cfg(feature = "mpt")]
+pub mod mpt;
 
 #[cfg(feature = "serde")]
 pub mod serializer;
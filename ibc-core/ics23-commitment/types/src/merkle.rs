@@ -3,22 +3,126 @@
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 use ibc_proto::ibc::core::commitment::v1::{MerklePath, MerkleProof as RawMerkleProof, MerkleRoot};
-use ibc_proto::ics23::commitment_proof::Proof;
-use ibc_proto::ics23::{
-    calculate_existence_root, verify_membership, verify_non_membership, CommitmentProof,
-    HostFunctionsProvider, NonExistenceProof,
-};
+use ibc_proto::ics23::{CommitmentProof, HostFunctionsProvider};
 
-use crate::commitment::{CommitmentPrefix, CommitmentRoot};
+use crate::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
 use crate::error::CommitmentError;
 use crate::specs::ProofSpecs;
+use crate::verification::{
+    calculate_existence_root, calculate_non_existence_root, decompress, existence_proof_of,
+    non_existence_proof_of, verify_existence, verify_non_existence,
+};
 
+/// Prepends the store `prefix` to `path`, unless it is empty, in which case
+/// the path is committed at the root of the store.
 pub fn apply_prefix(prefix: &CommitmentPrefix, mut path: Vec<String>) -> MerklePath {
+    if prefix.is_empty() {
+        return MerklePath { key_path: path };
+    }
+
     let mut key_path: Vec<String> = vec![format!("{prefix:?}")];
     key_path.append(&mut path);
     MerklePath { key_path }
 }
 
+/// Verifies that `proof` proves `value` to be stored under `key` in the store
+/// of `prefix`, within the state committed to by `root`.
+///
+/// The proof is expected to be an encoded [`MerkleProof`], with one ICS-23
+/// proof per spec of `specs`, from the store of `prefix` up to `root`, like
+/// the ones of Cosmos SDK chains verified against [`ProofSpecs::cosmos`]. This
+/// is the verification light clients perform on the IBC paths, for any
+/// key/value pair.
+pub fn verify_membership<H: HostFunctionsProvider>(
+    specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    key: &str,
+    value: Vec<u8>,
+) -> Result<(), CommitmentError> {
+    let merkle_path = apply_prefix(prefix, vec![key.to_string()]);
+
+    MerkleProof::try_from(proof)?.verify_membership::<H>(
+        specs,
+        root.clone().into(),
+        merkle_path,
+        value,
+        0,
+    )
+}
+
+/// Verifies that `proof` proves that nothing is stored under `key` in the
+/// store of `prefix`, within the state committed to by `root`.
+///
+/// See [`verify_membership`] for the expected proof.
+pub fn verify_non_membership<H: HostFunctionsProvider>(
+    specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    key: &str,
+) -> Result<(), CommitmentError> {
+    let merkle_path = apply_prefix(prefix, vec![key.to_string()]);
+
+    MerkleProof::try_from(proof)?.verify_non_membership::<H>(
+        specs,
+        root.clone().into(),
+        merkle_path,
+    )
+}
+
+/// Verifies that `proof` proves each value of `items` to be stored under its
+/// key in the store of `prefix`, within the state committed to by `root`.
+///
+/// The innermost proof of `proof` is typically a batch proof, compressed or
+/// not, covering all the keys of `items`. See [`verify_membership`].
+pub fn verify_batch_membership<H: HostFunctionsProvider>(
+    specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    items: &[(&str, Vec<u8>)],
+) -> Result<(), CommitmentError> {
+    let merkle_proof = MerkleProof::try_from(proof)?;
+
+    for (key, value) in items {
+        merkle_proof.verify_membership::<H>(
+            specs,
+            root.clone().into(),
+            apply_prefix(prefix, vec![key.to_string()]),
+            value.clone(),
+            0,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Verifies that `proof` proves that nothing is stored under any of the `keys`
+/// in the store of `prefix`, within the state committed to by `root`.
+///
+/// See [`verify_batch_membership`].
+pub fn verify_batch_non_membership<H: HostFunctionsProvider>(
+    specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    keys: &[&str],
+) -> Result<(), CommitmentError> {
+    let merkle_proof = MerkleProof::try_from(proof)?;
+
+    for key in keys {
+        merkle_proof.verify_non_membership::<H>(
+            specs,
+            root.clone().into(),
+            apply_prefix(prefix, vec![key.to_string()]),
+        )?;
+    }
+
+    Ok(())
+}
+
 impl From<CommitmentRoot> for MerkleRoot {
     fn from(root: CommitmentRoot) -> Self {
         Self {
@@ -94,18 +198,15 @@ impl MerkleProof {
                     .expect("safe because if u64 is more than usize it will skip all anyway"),
             )
         {
-            match &proof.proof {
-                Some(Proof::Exist(existence_proof)) => {
-                    subroot = calculate_existence_root::<H>(existence_proof)
-                        .map_err(|_| CommitmentError::InvalidMerkleProof)?;
-
-                    if !verify_membership::<H>(proof, spec, &subroot, key.as_bytes(), &value) {
-                        return Err(CommitmentError::VerificationFailure);
-                    }
-                    value.clone_from(&subroot);
-                }
-                _ => return Err(CommitmentError::InvalidMerkleProof),
-            }
+            // batch proofs may prove other keys along with this one
+            let proof = decompress(proof)?;
+            let existence_proof = existence_proof_of(&proof, key.as_bytes())
+                .ok_or(CommitmentError::InvalidMerkleProof)?;
+
+            subroot = calculate_existence_root::<H>(existence_proof)?;
+
+            verify_existence::<H>(&proof, spec, &subroot, key.as_bytes(), &value)?;
+            value.clone_from(&subroot);
         }
 
         if root.hash != subroot {
@@ -150,31 +251,323 @@ impl MerkleProof {
             .key_path
             .get(num - 1)
             .ok_or(CommitmentError::InvalidMerkleProof)?;
-        match &proof.proof {
-            Some(Proof::Nonexist(non_existence_proof)) => {
-                let subroot = calculate_non_existence_root::<H>(non_existence_proof)?;
-
-                if !verify_non_membership::<H>(proof, spec, &subroot, key.as_bytes()) {
-                    return Err(CommitmentError::VerificationFailure);
-                }
-
-                // verify membership proofs starting from index 1 with value = subroot
-                self.verify_membership::<H>(specs, root, keys, subroot, 1)
-            }
-            _ => Err(CommitmentError::InvalidMerkleProof),
-        }
+        let proof = decompress(proof)?;
+        let non_existence_proof = non_existence_proof_of(&proof, key.as_bytes())
+            .ok_or(CommitmentError::InvalidMerkleProof)?;
+
+        let subroot = calculate_non_existence_root::<H>(non_existence_proof)?;
+
+        verify_non_existence::<H>(&proof, spec, &subroot, key.as_bytes())?;
+
+        // verify membership proofs starting from index 1 with value = subroot
+        self.verify_membership::<H>(specs, root, keys, subroot, 1)
     }
 }
 
-// TODO move to ics23
-fn calculate_non_existence_root<H: HostFunctionsProvider>(
-    proof: &NonExistenceProof,
-) -> Result<Vec<u8>, CommitmentError> {
-    if let Some(left) = &proof.left {
-        calculate_existence_root::<H>(left).map_err(|_| CommitmentError::InvalidMerkleProof)
-    } else if let Some(right) = &proof.right {
-        calculate_existence_root::<H>(right).map_err(|_| CommitmentError::InvalidMerkleProof)
-    } else {
-        Err(CommitmentError::InvalidMerkleProof)
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ics23::batch_entry::Proof as BatchEntryProof;
+    use ibc_proto::ics23::commitment_proof::Proof;
+    use ibc_proto::ics23::{
+        BatchEntry, BatchProof, ExistenceProof, HashOp, HostFunctionsManager, InnerOp, LeafOp,
+        NonExistenceProof,
+    };
+
+    use super::*;
+
+    const KEY: &str = "food";
+    const VALUE: &[u8] = b"some longer text";
+
+    fn prefix() -> CommitmentPrefix {
+        CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap()
+    }
+
+    /// Returns a leaf-only existence proof of `value` under `key`, with the
+    /// leaf operation of `spec` and the given leaf prefix.
+    fn existence_proof(
+        spec: ics23::ProofSpec,
+        prefix: Vec<u8>,
+        key: &[u8],
+        value: &[u8],
+    ) -> ExistenceProof {
+        ExistenceProof {
+            key: key.to_vec(),
+            value: value.to_vec(),
+            leaf: Some(LeafOp {
+                prefix,
+                ..spec.leaf_spec.unwrap()
+            }),
+            path: vec![],
+        }
+    }
+
+    /// Completes the proof of a key in the `ibc` store of root `store_root`
+    /// with the proof of the store within a multistore, and returns it along
+    /// with the root of the multistore.
+    fn multistore_proof(
+        store_proof: CommitmentProof,
+        store_root: &[u8],
+    ) -> (CommitmentProofBytes, CommitmentRoot) {
+        let store_existence_proof = existence_proof(
+            ics23::tendermint_spec(),
+            vec![0],
+            prefix().as_bytes(),
+            store_root,
+        );
+        let root =
+            calculate_existence_root::<HostFunctionsManager>(&store_existence_proof).unwrap();
+
+        let proof = MerkleProof {
+            proofs: vec![
+                store_proof,
+                CommitmentProof {
+                    proof: Some(Proof::Exist(store_existence_proof)),
+                },
+            ],
+        };
+
+        (proof.try_into().unwrap(), root.into())
+    }
+
+    fn iavl_existence_proof() -> ExistenceProof {
+        // height 0, size 1, version 1
+        existence_proof(ics23::iavl_spec(), vec![0, 2, 2], KEY.as_bytes(), VALUE)
+    }
+
+    #[test]
+    fn test_verify_membership() {
+        let iavl_proof = iavl_existence_proof();
+        let store_root = calculate_existence_root::<HostFunctionsManager>(&iavl_proof).unwrap();
+        let (proof, root) = multistore_proof(
+            CommitmentProof {
+                proof: Some(Proof::Exist(iavl_proof)),
+            },
+            &store_root,
+        );
+        let specs = ProofSpecs::cosmos();
+
+        verify_membership::<HostFunctionsManager>(
+            &specs,
+            &prefix(),
+            &proof,
+            &root,
+            KEY,
+            VALUE.to_vec(),
+        )
+        .unwrap();
+
+        assert!(verify_membership::<HostFunctionsManager>(
+            &specs,
+            &prefix(),
+            &proof,
+            &root,
+            KEY,
+            b"other value".to_vec(),
+        )
+        .is_err());
+        assert!(verify_membership::<HostFunctionsManager>(
+            &specs,
+            &CommitmentPrefix::try_from(b"bank".to_vec()).unwrap(),
+            &proof,
+            &root,
+            KEY,
+            VALUE.to_vec(),
+        )
+        .is_err());
+        assert!(verify_non_membership::<HostFunctionsManager>(
+            &specs,
+            &prefix(),
+            &proof,
+            &root,
+            KEY
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_verify_non_membership() {
+        let iavl_proof = iavl_existence_proof();
+        let store_root = calculate_existence_root::<HostFunctionsManager>(&iavl_proof).unwrap();
+        // "foody" sorts after the only key of the store
+        let (proof, root) = multistore_proof(
+            CommitmentProof {
+                proof: Some(Proof::Nonexist(NonExistenceProof {
+                    key: b"foody".to_vec(),
+                    left: Some(iavl_proof),
+                    right: None,
+                })),
+            },
+            &store_root,
+        );
+        let specs = ProofSpecs::cosmos();
+
+        verify_non_membership::<HostFunctionsManager>(&specs, &prefix(), &proof, &root, "foody")
+            .unwrap();
+
+        assert!(verify_non_membership::<HostFunctionsManager>(
+            &specs,
+            &prefix(),
+            &proof,
+            &root,
+            KEY
+        )
+        .is_err());
+        assert!(verify_membership::<HostFunctionsManager>(
+            &specs,
+            &prefix(),
+            &proof,
+            &root,
+            "foody",
+            VALUE.to_vec(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_verify_membership_at_store_root() {
+        let iavl_proof = iavl_existence_proof();
+        let root = calculate_existence_root::<HostFunctionsManager>(&iavl_proof).unwrap();
+        let proof = MerkleProof {
+            proofs: vec![CommitmentProof {
+                proof: Some(Proof::Exist(iavl_proof)),
+            }],
+        };
+        let specs = ProofSpecs::new(vec![ics23::iavl_spec()]).unwrap();
+
+        assert_eq!(
+            apply_prefix(&CommitmentPrefix::empty(), vec![KEY.to_string()]).key_path,
+            vec![KEY.to_string()]
+        );
+
+        verify_membership::<HostFunctionsManager>(
+            &specs,
+            &CommitmentPrefix::empty(),
+            &proof.try_into().unwrap(),
+            &root.into(),
+            KEY,
+            VALUE.to_vec(),
+        )
+        .unwrap();
+    }
+
+    /// Returns a batch proof of `food` and `fooz`, the two keys of an IAVL
+    /// store, and of the absence of `foo`, along with the root of the store.
+    fn iavl_batch_proof() -> (CommitmentProof, Vec<u8>) {
+        let leaf_food = iavl_existence_proof();
+        let hash_food = calculate_existence_root::<HostFunctionsManager>(&leaf_food).unwrap();
+        let leaf_fooz = existence_proof(ics23::iavl_spec(), vec![0, 2, 2], b"fooz", b"other value");
+        let hash_fooz = calculate_existence_root::<HostFunctionsManager>(&leaf_fooz).unwrap();
+
+        // height 1, size 2, version 1, followed by the length of the left child hash
+        let inner_prefix = vec![2, 4, 2, 32];
+        let proof_food = ExistenceProof {
+            path: vec![InnerOp {
+                hash: HashOp::Sha256.into(),
+                prefix: inner_prefix.clone(),
+                suffix: [vec![32], hash_fooz].concat(),
+            }],
+            ..leaf_food
+        };
+        let proof_fooz = ExistenceProof {
+            path: vec![InnerOp {
+                hash: HashOp::Sha256.into(),
+                prefix: [inner_prefix, hash_food, vec![32]].concat(),
+                suffix: vec![],
+            }],
+            ..leaf_fooz
+        };
+
+        let store_root = calculate_existence_root::<HostFunctionsManager>(&proof_food).unwrap();
+        assert_eq!(
+            calculate_existence_root::<HostFunctionsManager>(&proof_fooz).unwrap(),
+            store_root
+        );
+
+        let entries = vec![
+            BatchEntryProof::Exist(proof_food.clone()),
+            BatchEntryProof::Exist(proof_fooz),
+            BatchEntryProof::Nonexist(NonExistenceProof {
+                key: b"foo".to_vec(),
+                left: None,
+                right: Some(proof_food),
+            }),
+        ];
+        let proof = CommitmentProof {
+            proof: Some(Proof::Batch(BatchProof {
+                entries: entries
+                    .into_iter()
+                    .map(|proof| BatchEntry { proof: Some(proof) })
+                    .collect(),
+            })),
+        };
+
+        (proof, store_root)
+    }
+
+    #[rstest::rstest]
+    #[case::batch(false)]
+    #[case::compressed(true)]
+    fn test_verify_batch(#[case] compressed: bool) {
+        let (batch_proof, store_root) = iavl_batch_proof();
+        let batch_proof = if compressed {
+            ics23::compress(&batch_proof).unwrap()
+        } else {
+            batch_proof
+        };
+        let (proof, root) = multistore_proof(batch_proof, &store_root);
+        let specs = ProofSpecs::cosmos();
+
+        verify_batch_membership::<HostFunctionsManager>(
+            &specs,
+            &prefix(),
+            &proof,
+            &root,
+            &[(KEY, VALUE.to_vec()), ("fooz", b"other value".to_vec())],
+        )
+        .unwrap();
+        verify_batch_non_membership::<HostFunctionsManager>(
+            &specs,
+            &prefix(),
+            &proof,
+            &root,
+            &["foo"],
+        )
+        .unwrap();
+
+        // the keys of the batch can be verified one at a time
+        verify_membership::<HostFunctionsManager>(
+            &specs,
+            &prefix(),
+            &proof,
+            &root,
+            "fooz",
+            b"other value".to_vec(),
+        )
+        .unwrap();
+
+        assert!(verify_batch_membership::<HostFunctionsManager>(
+            &specs,
+            &prefix(),
+            &proof,
+            &root,
+            &[(KEY, VALUE.to_vec()), ("fooz", VALUE.to_vec())],
+        )
+        .is_err());
+        assert!(verify_batch_membership::<HostFunctionsManager>(
+            &specs,
+            &prefix(),
+            &proof,
+            &root,
+            &[("foo", VALUE.to_vec())],
+        )
+        .is_err());
+        assert!(verify_batch_non_membership::<HostFunctionsManager>(
+            &specs,
+            &prefix(),
+            &proof,
+            &root,
+            &["foo", "fooz"],
+        )
+        .is_err());
     }
 }
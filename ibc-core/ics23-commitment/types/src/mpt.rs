@@ -0,0 +1,590 @@
+//! Verification of the Merkle Patricia trie proofs of EVM chains, as returned
+//! by `eth_getProof`, for light clients of Ethereum-family counterparties.
+
+use core::marker::PhantomData;
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ics23::HostFunctionsProvider;
+
+use crate::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::error::CommitmentError;
+use crate::verifier::CommitmentVerifier;
+
+/// Verifies the proof of `key` in the Merkle Patricia trie of root `root`,
+/// made of the trie nodes from the root down to the key, and returns the value
+/// stored under the key, or `None` if the proof shows that there is none.
+///
+/// The key is the path of the value in the trie: the keys of the secure tries
+/// of the EVM state, i.e. of its account and storage tries, are to be hashed
+/// beforehand.
+pub fn verify_proof<H: HostFunctionsProvider>(
+    root: &[u8],
+    key: &[u8],
+    proof: &[Vec<u8>],
+) -> Result<Option<Vec<u8>>, CommitmentError> {
+    let nibbles = to_nibbles(key);
+    let mut path = nibbles.as_slice();
+    let mut nodes = proof.iter();
+    let mut next = NodeRef::Hash(root);
+
+    loop {
+        let node = match next {
+            NodeRef::Empty => return Ok(None),
+            NodeRef::Hash(hash) => {
+                let node = nodes.next().ok_or(CommitmentError::InvalidMerkleProof)?;
+                if H::keccak_256(node).as_slice() != hash {
+                    return Err(CommitmentError::VerificationFailure);
+                }
+                node.as_slice()
+            }
+            NodeRef::Inline(node) => node,
+        };
+
+        let items = match rlp::decode(node)? {
+            // the root node of an empty trie
+            rlp::Item::Bytes([]) => return Ok(None),
+            item => item.items()?,
+        };
+
+        match items.as_slice() {
+            // branch node
+            [children @ .., value] if children.len() == 16 => match path.split_first() {
+                Some((nibble, rest)) => {
+                    next = NodeRef::from_item(&children[usize::from(*nibble)])?;
+                    path = rest;
+                }
+                None => return Ok(non_empty(value.as_bytes()?)),
+            },
+            // extension or leaf node
+            [encoded_path, child] => {
+                let (is_leaf, node_path) = decode_compact_path(encoded_path.as_bytes()?)?;
+
+                if is_leaf {
+                    if path != node_path.as_slice() {
+                        return Ok(None);
+                    }
+                    return Ok(non_empty(child.as_bytes()?));
+                }
+
+                match path.strip_prefix(node_path.as_slice()) {
+                    Some(rest) => {
+                        next = NodeRef::from_item(child)?;
+                        path = rest;
+                    }
+                    None => return Ok(None),
+                }
+            }
+            _ => return Err(CommitmentError::InvalidMerkleProof),
+        }
+    }
+}
+
+/// A reference to a trie node from its parent.
+enum NodeRef<'a> {
+    /// No node.
+    Empty,
+    /// The Keccak-256 hash of the node.
+    Hash(&'a [u8]),
+    /// The node itself, when its encoding is shorter than a hash.
+    Inline(&'a [u8]),
+}
+
+impl<'a> NodeRef<'a> {
+    fn from_item(item: &rlp::Item<'a>) -> Result<Self, CommitmentError> {
+        match *item {
+            rlp::Item::Bytes([]) => Ok(Self::Empty),
+            rlp::Item::Bytes(hash) if hash.len() == 32 => Ok(Self::Hash(hash)),
+            rlp::Item::List { encoding, .. } => Ok(Self::Inline(encoding)),
+            rlp::Item::Bytes(_) => Err(CommitmentError::InvalidMerkleProof),
+        }
+    }
+}
+
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes
+        .iter()
+        .flat_map(|byte| [byte >> 4, byte & 0x0f])
+        .collect()
+}
+
+fn non_empty(value: &[u8]) -> Option<Vec<u8>> {
+    (!value.is_empty()).then(|| value.to_vec())
+}
+
+/// Decodes the hex-prefix encoded path of an extension or leaf node, and
+/// returns whether the node is a leaf along with the nibbles of the path.
+fn decode_compact_path(encoded_path: &[u8]) -> Result<(bool, Vec<u8>), CommitmentError> {
+    let (first, rest) = encoded_path
+        .split_first()
+        .ok_or(CommitmentError::InvalidMerkleProof)?;
+
+    let flag = first >> 4;
+    let is_leaf = match flag {
+        0 | 1 => false,
+        2 | 3 => true,
+        _ => return Err(CommitmentError::InvalidMerkleProof),
+    };
+
+    let mut nibbles = Vec::with_capacity(2 * encoded_path.len());
+    // odd paths start in the first byte
+    if flag & 1 == 1 {
+        nibbles.push(first & 0x0f);
+    }
+    nibbles.extend(to_nibbles(rest));
+
+    Ok((is_leaf, nibbles))
+}
+
+/// The proof of a storage slot of an EVM contract against the state root of
+/// the chain, made of the account proof of the contract and of the storage
+/// proof of the slot, as returned by `eth_getProof`.
+///
+/// It is carried in [`CommitmentProofBytes`] as the RLP encoding of the list
+/// of these two lists of trie nodes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvmStorageProof {
+    pub account_proof: Vec<Vec<u8>>,
+    pub storage_proof: Vec<Vec<u8>>,
+}
+
+impl TryFrom<&CommitmentProofBytes> for EvmStorageProof {
+    type Error = CommitmentError;
+
+    fn try_from(proof: &CommitmentProofBytes) -> Result<Self, Self::Error> {
+        let nodes = |item: &rlp::Item<'_>| -> Result<Vec<Vec<u8>>, CommitmentError> {
+            item.items()?
+                .iter()
+                .map(|node| node.as_bytes().map(<[u8]>::to_vec))
+                .collect()
+        };
+
+        match rlp::decode(proof.as_ref())?.items()?.as_slice() {
+            [account_proof, storage_proof] => Ok(Self {
+                account_proof: nodes(account_proof)?,
+                storage_proof: nodes(storage_proof)?,
+            }),
+            _ => Err(CommitmentError::InvalidMerkleProof),
+        }
+    }
+}
+
+impl TryFrom<EvmStorageProof> for CommitmentProofBytes {
+    type Error = CommitmentError;
+
+    fn try_from(proof: EvmStorageProof) -> Result<Self, Self::Error> {
+        let nodes = |nodes: &[Vec<u8>]| {
+            rlp::encode_list(
+                &nodes
+                    .iter()
+                    .map(|node| rlp::encode_bytes(node))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        rlp::encode_list(&[nodes(&proof.account_proof), nodes(&proof.storage_proof)]).try_into()
+    }
+}
+
+/// The commitment scheme of EVM chains hosting IBC in a contract, verifying
+/// [`EvmStorageProof`]s against the state root of the chain.
+///
+/// The contract is expected to store the Keccak-256 hash of the value of an
+/// IBC path in its `mapping(bytes32 => bytes32)` at `commitments_slot`, under
+/// the Keccak-256 hash of the prefix followed by the path.
+pub struct EvmStorageVerifier<H> {
+    contract_address: [u8; 20],
+    commitments_slot: [u8; 32],
+    _host_functions: PhantomData<H>,
+}
+
+impl<H: HostFunctionsProvider> EvmStorageVerifier<H> {
+    pub fn new(contract_address: [u8; 20], commitments_slot: [u8; 32]) -> Self {
+        Self {
+            contract_address,
+            commitments_slot,
+            _host_functions: PhantomData,
+        }
+    }
+
+    pub fn contract_address(&self) -> &[u8; 20] {
+        &self.contract_address
+    }
+
+    /// Returns the storage slot of the contract holding the commitment to the
+    /// value of `key` in the store of `prefix`.
+    pub fn storage_slot(&self, prefix: &CommitmentPrefix, key: &str) -> [u8; 32] {
+        let mapping_key = H::keccak_256(&[prefix.as_bytes(), key.as_bytes()].concat());
+
+        H::keccak_256(&[mapping_key.as_slice(), self.commitments_slot.as_slice()].concat())
+    }
+
+    /// Returns the value stored in the given slot of the contract, if any, out
+    /// of the proof.
+    fn stored_value(
+        &self,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        slot: &[u8; 32],
+    ) -> Result<Option<Vec<u8>>, CommitmentError> {
+        let proof = EvmStorageProof::try_from(proof)?;
+
+        let account = verify_proof::<H>(
+            root.as_bytes(),
+            &H::keccak_256(&self.contract_address),
+            &proof.account_proof,
+        )?
+        .ok_or(CommitmentError::VerificationFailure)?;
+
+        // accounts are encoded as [nonce, balance, storage root, code hash]
+        let account = rlp::decode(&account)?.items()?;
+        let storage_root = account
+            .get(2)
+            .ok_or(CommitmentError::InvalidMerkleProof)?
+            .as_bytes()?;
+
+        let Some(value) =
+            verify_proof::<H>(storage_root, &H::keccak_256(slot), &proof.storage_proof)?
+        else {
+            return Ok(None);
+        };
+
+        // storage values are RLP-encoded
+        Ok(Some(rlp::decode(&value)?.as_bytes()?.to_vec()))
+    }
+}
+
+impl<H: HostFunctionsProvider> CommitmentVerifier for EvmStorageVerifier<H> {
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), CommitmentError> {
+        if value.is_empty() {
+            return Err(CommitmentError::EmptyVerifiedValue);
+        }
+
+        let stored_value = self
+            .stored_value(proof, root, &self.storage_slot(prefix, key))?
+            .ok_or(CommitmentError::VerificationFailure)?;
+
+        // storage values are stored without their leading zeros
+        let commitment = H::keccak_256(&value);
+        let start = commitment
+            .iter()
+            .position(|byte| *byte != 0)
+            .unwrap_or(commitment.len());
+
+        if stored_value != commitment[start..] {
+            return Err(CommitmentError::VerificationFailure);
+        }
+
+        Ok(())
+    }
+
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        key: &str,
+    ) -> Result<(), CommitmentError> {
+        match self.stored_value(proof, root, &self.storage_slot(prefix, key))? {
+            Some(_) => Err(CommitmentError::VerificationFailure),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The subset of the RLP encoding needed by the trie proofs.
+mod rlp {
+    use core::mem::size_of;
+
+    use ibc_primitives::prelude::*;
+
+    use crate::error::CommitmentError;
+
+    /// A decoded RLP item.
+    pub enum Item<'a> {
+        /// A byte string.
+        Bytes(&'a [u8]),
+        /// A list, along with its whole encoding.
+        List {
+            encoding: &'a [u8],
+            payload: &'a [u8],
+        },
+    }
+
+    impl<'a> Item<'a> {
+        pub fn as_bytes(&self) -> Result<&'a [u8], CommitmentError> {
+            match *self {
+                Self::Bytes(bytes) => Ok(bytes),
+                Self::List { .. } => Err(invalid_encoding()),
+            }
+        }
+
+        pub fn items(&self) -> Result<Vec<Item<'a>>, CommitmentError> {
+            let Self::List { payload, .. } = *self else {
+                return Err(invalid_encoding());
+            };
+
+            let mut items = Vec::new();
+            let mut rest = payload;
+            while !rest.is_empty() {
+                let (item, next) = decode_item(rest)?;
+                items.push(item);
+                rest = next;
+            }
+
+            Ok(items)
+        }
+    }
+
+    fn invalid_encoding() -> CommitmentError {
+        CommitmentError::DecodingFailure("invalid RLP encoding".to_string())
+    }
+
+    /// Decodes `data` as a single item.
+    pub fn decode(data: &[u8]) -> Result<Item<'_>, CommitmentError> {
+        match decode_item(data)? {
+            (item, []) => Ok(item),
+            _ => Err(invalid_encoding()),
+        }
+    }
+
+    /// Decodes the item at the start of `data`, and returns it along with the
+    /// rest of `data`.
+    fn decode_item(data: &[u8]) -> Result<(Item<'_>, &[u8]), CommitmentError> {
+        let (first, rest) = data.split_first().ok_or_else(invalid_encoding)?;
+
+        let (is_list, offset, len) = match *first {
+            0x00..=0x7f => return Ok((Item::Bytes(&data[..1]), rest)),
+            0x80..=0xb7 => (false, 1, usize::from(first - 0x80)),
+            0xb8..=0xbf => {
+                let len_of_len = usize::from(first - 0xb7);
+                (false, 1 + len_of_len, decode_length(rest, len_of_len)?)
+            }
+            0xc0..=0xf7 => (true, 1, usize::from(first - 0xc0)),
+            0xf8..=0xff => {
+                let len_of_len = usize::from(first - 0xf7);
+                (true, 1 + len_of_len, decode_length(rest, len_of_len)?)
+            }
+        };
+
+        let end = offset
+            .checked_add(len)
+            .filter(|end| *end <= data.len())
+            .ok_or_else(invalid_encoding)?;
+
+        let item = if is_list {
+            Item::List {
+                encoding: &data[..end],
+                payload: &data[offset..end],
+            }
+        } else {
+            Item::Bytes(&data[offset..end])
+        };
+
+        Ok((item, &data[end..]))
+    }
+
+    fn decode_length(data: &[u8], len_of_len: usize) -> Result<usize, CommitmentError> {
+        if len_of_len > size_of::<usize>() {
+            return Err(invalid_encoding());
+        }
+
+        let len_bytes = data.get(..len_of_len).ok_or_else(invalid_encoding)?;
+
+        Ok(len_bytes
+            .iter()
+            .fold(0, |len, byte| (len << 8) | usize::from(*byte)))
+    }
+
+    pub fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+        match bytes {
+            [byte] if *byte < 0x80 => vec![*byte],
+            _ => [encode_length(bytes.len(), 0x80), bytes.to_vec()].concat(),
+        }
+    }
+
+    /// Encodes the list of the given encoded items.
+    pub fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+        let payload = items.concat();
+
+        [encode_length(payload.len(), 0xc0), payload].concat()
+    }
+
+    fn encode_length(len: usize, offset: u8) -> Vec<u8> {
+        if len < 56 {
+            return vec![offset + len as u8];
+        }
+
+        let len_bytes: Vec<u8> = len
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|byte| *byte == 0)
+            .collect();
+
+        [vec![offset + 55 + len_bytes.len() as u8], len_bytes].concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ics23::HostFunctionsManager;
+
+    use super::*;
+
+    /// Encodes the given nibbles as the path of a leaf node.
+    fn leaf_path(nibbles: &[u8]) -> Vec<u8> {
+        let (first, rest) = if nibbles.len() % 2 == 1 {
+            (0x30 | nibbles[0], &nibbles[1..])
+        } else {
+            (0x20, nibbles)
+        };
+
+        let mut path = vec![first];
+        path.extend(rest.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+        path
+    }
+
+    fn leaf(nibbles: &[u8], value: &[u8]) -> Vec<u8> {
+        rlp::encode_list(&[
+            rlp::encode_bytes(&leaf_path(nibbles)),
+            rlp::encode_bytes(value),
+        ])
+    }
+
+    fn keccak(data: &[u8]) -> Vec<u8> {
+        HostFunctionsManager::keccak_256(data).to_vec()
+    }
+
+    #[test]
+    fn test_empty_trie() {
+        let empty_node = rlp::encode_bytes(&[]);
+        let root = keccak(&empty_node);
+
+        assert_eq!(
+            subtle_encoding::hex::encode(&root),
+            b"56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+        );
+        assert_eq!(
+            verify_proof::<HostFunctionsManager>(&root, &keccak(b"key"), &[empty_node]).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_branch_trie() {
+        let key_a = [0x1a; 32];
+        let key_b = [0x2b; 32];
+        let leaf_a = leaf(
+            &to_nibbles(&key_a)[1..],
+            b"value of a, long enough to be hashed",
+        );
+        let leaf_b = leaf(
+            &to_nibbles(&key_b)[1..],
+            b"value of b, long enough to be hashed",
+        );
+
+        let mut children = vec![rlp::encode_bytes(&[]); 17];
+        children[1] = rlp::encode_bytes(&keccak(&leaf_a));
+        children[2] = rlp::encode_bytes(&keccak(&leaf_b));
+        let branch = rlp::encode_list(&children);
+        let root = keccak(&branch);
+
+        assert_eq!(
+            verify_proof::<HostFunctionsManager>(&root, &key_a, &[branch.clone(), leaf_a.clone()])
+                .unwrap(),
+            Some(b"value of a, long enough to be hashed".to_vec())
+        );
+        assert_eq!(
+            verify_proof::<HostFunctionsManager>(&root, &key_b, &[branch.clone(), leaf_b]).unwrap(),
+            Some(b"value of b, long enough to be hashed".to_vec())
+        );
+
+        // no child under the first nibble of the key
+        assert_eq!(
+            verify_proof::<HostFunctionsManager>(
+                &root,
+                &[0x3c; 32],
+                core::slice::from_ref(&branch)
+            )
+            .unwrap(),
+            None
+        );
+        // the path of the leaf diverges from the key
+        assert_eq!(
+            verify_proof::<HostFunctionsManager>(
+                &root,
+                &[0x1c; 32],
+                &[branch.clone(), leaf_a.clone()]
+            )
+            .unwrap(),
+            None
+        );
+
+        // the leaf is not the child of the branch
+        assert!(verify_proof::<HostFunctionsManager>(&root, &key_b, &[branch, leaf_a]).is_err());
+    }
+
+    #[test]
+    fn test_evm_storage_verifier() {
+        let contract_address = [0x42; 20];
+        let verifier = EvmStorageVerifier::<HostFunctionsManager>::new(contract_address, [0; 32]);
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        let key = "commitments/ports/transfer/channels/channel-0/sequences/1";
+        let value = b"packet commitment".to_vec();
+
+        // the storage trie holds the commitment only
+        let commitment = keccak(&value);
+        let stored_commitment: Vec<u8> = commitment
+            .iter()
+            .copied()
+            .skip_while(|byte| *byte == 0)
+            .collect();
+        let storage_leaf = leaf(
+            &to_nibbles(&keccak(&verifier.storage_slot(&prefix, key))),
+            &rlp::encode_bytes(&stored_commitment),
+        );
+        let storage_root = keccak(&storage_leaf);
+
+        // the account trie holds the contract only
+        let account = rlp::encode_list(&[
+            rlp::encode_bytes(&[1]),
+            rlp::encode_bytes(&[]),
+            rlp::encode_bytes(&storage_root),
+            rlp::encode_bytes(&keccak(b"code")),
+        ]);
+        let account_leaf = leaf(&to_nibbles(&keccak(&contract_address)), &account);
+        let root = CommitmentRoot::from(keccak(&account_leaf));
+
+        let proof: CommitmentProofBytes = EvmStorageProof {
+            account_proof: vec![account_leaf],
+            storage_proof: vec![storage_leaf],
+        }
+        .try_into()
+        .unwrap();
+
+        verifier
+            .verify_membership(&prefix, &proof, &root, key, value)
+            .unwrap();
+        assert!(verifier
+            .verify_membership(&prefix, &proof, &root, key, b"other".to_vec())
+            .is_err());
+        assert!(verifier
+            .verify_non_membership(&prefix, &proof, &root, key)
+            .is_err());
+
+        // the same proof shows that other keys are absent
+        let other_key = "commitments/ports/transfer/channels/channel-0/sequences/2";
+        verifier
+            .verify_non_membership(&prefix, &proof, &root, other_key)
+            .unwrap();
+        assert!(verifier
+            .verify_membership(&prefix, &proof, &root, other_key, commitment)
+            .is_err());
+    }
+}
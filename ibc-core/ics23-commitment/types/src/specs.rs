@@ -5,15 +5,27 @@ use ibc_proto::ics23::{InnerSpec as RawInnerSpec, LeafOp as RawLeafOp, ProofSpec
 use ics23::{HashOp, LengthOp};
 
 use crate::error::CommitmentError;
+
 /// An array of proof specifications.
 ///
 /// This type encapsulates different types of proof specifications, mostly predefined, e.g., for
-/// Cosmos-SDK.
+/// Cosmos-SDK. Counterparties with a different storage layout, e.g. SMT-based stores, can define
+/// their own through [`ProofSpecs::new`].
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct ProofSpecs(Vec<ProofSpec>);
 
 impl ProofSpecs {
+    /// Constructs custom proof specs, ordered from the innermost store up to
+    /// the state root, and checks that they are valid.
+    pub fn new(specs: Vec<RawProofSpec>) -> Result<Self, CommitmentError> {
+        let specs = Self::try_from(specs)?;
+
+        specs.validate()?;
+
+        Ok(specs)
+    }
+
     /// Returns the specification for Cosmos-SDK proofs
     pub fn cosmos() -> Self {
         vec![
@@ -28,6 +40,12 @@ impl ProofSpecs {
         self.0.is_empty()
     }
 
+    /// Returns the number of proof specs, i.e. the number of proofs expected
+    /// in a merkle proof.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
     pub fn validate(&self) -> Result<(), CommitmentError> {
         if self.is_empty() {
             return Err(CommitmentError::EmptyProofSpecs);
@@ -48,6 +66,13 @@ impl ProofSpecs {
                     proof_spec.0.max_depth,
                 ));
             }
+            // Proofs cannot be verified against specs lacking either operation.
+            if proof_spec.0.leaf_spec.is_none() {
+                return Err(CommitmentError::MissingLeafSpec);
+            }
+            if proof_spec.0.inner_spec.is_none() {
+                return Err(CommitmentError::MissingInnerSpec);
+            }
         }
         Ok(())
     }
@@ -281,4 +306,34 @@ mod tests {
         };
         LeafOp::try_from(raw_leaf_op).unwrap();
     }
+
+    #[test]
+    fn test_custom_proof_specs() {
+        let smt_specs = ProofSpecs::new(vec![ics23::smt_spec(), ics23::tendermint_spec()]).unwrap();
+        assert_eq!(smt_specs.len(), 2);
+        assert_ne!(smt_specs, ProofSpecs::cosmos());
+
+        assert!(matches!(
+            ProofSpecs::new(vec![]),
+            Err(CommitmentError::EmptyProofSpecs)
+        ));
+
+        let without_leaf_spec = RawProofSpec {
+            leaf_spec: None,
+            ..ics23::smt_spec()
+        };
+        assert!(matches!(
+            ProofSpecs::new(vec![without_leaf_spec]),
+            Err(CommitmentError::MissingLeafSpec)
+        ));
+
+        let without_inner_spec = RawProofSpec {
+            inner_spec: None,
+            ..ics23::smt_spec()
+        };
+        assert!(matches!(
+            ProofSpecs::new(vec![without_inner_spec]),
+            Err(CommitmentError::MissingInnerSpec)
+        ));
+    }
 }
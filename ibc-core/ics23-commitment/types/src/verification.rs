@@ -0,0 +1,301 @@
+//! Wraps the proof verification functions of the `ics23` crate, so that the
+//! rest of the IBC stack does not depend on them directly.
+//!
+//! The behaviour of the wrapped functions is pinned by the test vectors of this
+//! module, which cover the hash and length operations used by the IAVL, SMT and
+//! Tendermint proof specs. Any change of behaviour in the dependency is hence
+//! caught here.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ics23::batch_entry::Proof as BatchEntryProof;
+use ibc_proto::ics23::commitment_proof::Proof;
+use ibc_proto::ics23::{CommitmentProof, ExistenceProof, HostFunctionsProvider, NonExistenceProof};
+use ics23::ProofSpec;
+
+use crate::error::CommitmentError;
+
+/// Decompresses the given proof if it is a compressed batch proof, and returns
+/// it as is otherwise.
+pub fn decompress(proof: &CommitmentProof) -> Result<CommitmentProof, CommitmentError> {
+    ics23::decompress(proof).map_err(|_| CommitmentError::InvalidMerkleProof)
+}
+
+/// Returns the existence proof of `key` out of the given existence proof or
+/// batch proof, to be decompressed beforehand.
+pub fn existence_proof_of<'a>(
+    proof: &'a CommitmentProof,
+    key: &[u8],
+) -> Option<&'a ExistenceProof> {
+    match &proof.proof {
+        Some(Proof::Exist(existence_proof)) => Some(existence_proof),
+        Some(Proof::Batch(batch_proof)) => {
+            batch_proof
+                .entries
+                .iter()
+                .find_map(|entry| match &entry.proof {
+                    Some(BatchEntryProof::Exist(existence_proof)) if existence_proof.key == key => {
+                        Some(existence_proof)
+                    }
+                    _ => None,
+                })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the non-existence proof of `key` out of the given non-existence
+/// proof or batch proof, to be decompressed beforehand.
+pub fn non_existence_proof_of<'a>(
+    proof: &'a CommitmentProof,
+    key: &[u8],
+) -> Option<&'a NonExistenceProof> {
+    match &proof.proof {
+        Some(Proof::Nonexist(non_existence_proof)) => Some(non_existence_proof),
+        Some(Proof::Batch(batch_proof)) => {
+            batch_proof
+                .entries
+                .iter()
+                .find_map(|entry| match &entry.proof {
+                    Some(BatchEntryProof::Nonexist(non_existence_proof))
+                        if non_existence_proof.key == key =>
+                    {
+                        Some(non_existence_proof)
+                    }
+                    _ => None,
+                })
+        }
+        _ => None,
+    }
+}
+
+/// Computes the root hash committed to by the given existence proof.
+pub fn calculate_existence_root<H: HostFunctionsProvider>(
+    proof: &ExistenceProof,
+) -> Result<Vec<u8>, CommitmentError> {
+    ics23::calculate_existence_root::<H>(proof).map_err(|_| CommitmentError::InvalidMerkleProof)
+}
+
+/// Computes the root hash committed to by the given non-existence proof,
+/// through either of its neighbouring existence proofs.
+pub fn calculate_non_existence_root<H: HostFunctionsProvider>(
+    proof: &NonExistenceProof,
+) -> Result<Vec<u8>, CommitmentError> {
+    if let Some(left) = &proof.left {
+        calculate_existence_root::<H>(left)
+    } else if let Some(right) = &proof.right {
+        calculate_existence_root::<H>(right)
+    } else {
+        Err(CommitmentError::InvalidMerkleProof)
+    }
+}
+
+/// Verifies that `proof` proves the existence of `key` with `value`, under
+/// `root` and according to `spec`.
+pub fn verify_existence<H: HostFunctionsProvider>(
+    proof: &CommitmentProof,
+    spec: &ProofSpec,
+    root: &[u8],
+    key: &[u8],
+    value: &[u8],
+) -> Result<(), CommitmentError> {
+    if ics23::verify_membership::<H>(proof, spec, &root.to_vec(), key, value) {
+        Ok(())
+    } else {
+        Err(CommitmentError::VerificationFailure)
+    }
+}
+
+/// Verifies that `proof` proves the absence of `key`, under `root` and
+/// according to `spec`.
+pub fn verify_non_existence<H: HostFunctionsProvider>(
+    proof: &CommitmentProof,
+    spec: &ProofSpec,
+    root: &[u8],
+    key: &[u8],
+) -> Result<(), CommitmentError> {
+    if ics23::verify_non_membership::<H>(proof, spec, &root.to_vec(), key) {
+        Ok(())
+    } else {
+        Err(CommitmentError::VerificationFailure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ics23::commitment_proof::Proof;
+    use ibc_proto::ics23::{HashOp, HostFunctionsManager, InnerOp, LeafOp, LengthOp};
+    use rstest::rstest;
+    use subtle_encoding::hex;
+
+    use super::*;
+
+    const KEY: &[u8] = b"food";
+    const VALUE: &[u8] = b"some longer text";
+
+    /// A sibling hash, to the right of the proven node.
+    const SIBLING: [u8; 32] = [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31,
+    ];
+
+    fn existence_proof(leaf: LeafOp, path: Vec<InnerOp>) -> ExistenceProof {
+        ExistenceProof {
+            key: KEY.to_vec(),
+            value: VALUE.to_vec(),
+            leaf: Some(leaf),
+            path,
+        }
+    }
+
+    fn decode_hex(hash: &str) -> Vec<u8> {
+        hex::decode(hash).expect("valid hex")
+    }
+
+    #[rstest]
+    #[case(
+        HashOp::NoHash,
+        HashOp::NoHash,
+        LengthOp::NoPrefix,
+        "40810eae43752c4556228a1d366aa4c48ffb7c22648df0230e84ff59e5ca06bb"
+    )]
+    #[case(
+        HashOp::NoHash,
+        HashOp::NoHash,
+        LengthOp::VarProto,
+        "bedd70dd80f11149b74f09ad898e7f0f826db539b9ff12e4c3a80f3eaa4c1584"
+    )]
+    #[case(
+        HashOp::NoHash,
+        HashOp::Sha256,
+        LengthOp::NoPrefix,
+        "842f759e10a479c1f4a07444b9a7b421593f0f54c7cafb29bfc96b199695d7c7"
+    )]
+    #[case(
+        HashOp::NoHash,
+        HashOp::Sha256,
+        LengthOp::VarProto,
+        "478131997c1ab15f42724722c7455d6bbbd4b6f307706c8c1071650c4aa89808"
+    )]
+    #[case(
+        HashOp::Sha256,
+        HashOp::NoHash,
+        LengthOp::NoPrefix,
+        "09f9b4f92b10810cc908ce858ebcb5f42d4bc06a05c4a562f32c9ae38d6a9047"
+    )]
+    #[case(
+        HashOp::Sha256,
+        HashOp::NoHash,
+        LengthOp::VarProto,
+        "41419d163fd0e6df3793d929ce6c22d93d2b3bb68b3118ad607d81cd35af96e9"
+    )]
+    #[case(
+        HashOp::Sha256,
+        HashOp::Sha256,
+        LengthOp::NoPrefix,
+        "687a19c8023ef874cc8ffc9c590007b6a44e4833745f06cac18cd9da80de9397"
+    )]
+    #[case(
+        HashOp::Sha256,
+        HashOp::Sha256,
+        LengthOp::VarProto,
+        "66c4c1a074704562352e05fe625d0aa7c150e94ab7f8341a2010e7b5b559c926"
+    )]
+    fn leaf_op_vectors(
+        #[case] prehash_key: HashOp,
+        #[case] prehash_value: HashOp,
+        #[case] length: LengthOp,
+        #[case] expected_root: &str,
+    ) {
+        let leaf = LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: prehash_key.into(),
+            prehash_value: prehash_value.into(),
+            length: length.into(),
+            prefix: vec![0],
+        };
+
+        let root = calculate_existence_root::<HostFunctionsManager>(&existence_proof(leaf, vec![]))
+            .unwrap();
+
+        assert_eq!(root, decode_hex(expected_root));
+    }
+
+    #[rstest]
+    #[case::tendermint(
+        ics23::tendermint_spec(),
+        vec![0],
+        vec![1],
+        vec![],
+        "611aaa79a70c49ab25a8d77d3b488bb3d3d30ecdf69f4f803993ab129d94974f"
+    )]
+    #[case::smt(
+        ics23::smt_spec(),
+        vec![0],
+        vec![1],
+        vec![],
+        "839ee38faaea311a07f3e90fcd1e9d12373c673011e9b8963ae23a0a5822ce17"
+    )]
+    #[case::iavl(
+        ics23::iavl_spec(),
+        // height 0, size 1, version 1
+        vec![0, 2, 2],
+        // height 1, size 2, version 1, followed by the length of the child hash
+        vec![2, 4, 2, 32],
+        vec![32],
+        "4637fd91d64d406340541a6c53464387be8e14d339b8bcc0f7d229fa2851a447"
+    )]
+    fn spec_vectors(
+        #[case] spec: ProofSpec,
+        #[case] leaf_prefix: Vec<u8>,
+        #[case] inner_prefix: Vec<u8>,
+        #[case] sibling_prefix: Vec<u8>,
+        #[case] expected_root: &str,
+    ) {
+        let leaf = LeafOp {
+            prefix: leaf_prefix,
+            ..spec.leaf_spec.clone().unwrap()
+        };
+        let inner = InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix: inner_prefix,
+            suffix: [sibling_prefix, SIBLING.to_vec()].concat(),
+        };
+        let existence_proof = existence_proof(leaf, vec![inner]);
+        let root = decode_hex(expected_root);
+
+        assert_eq!(
+            calculate_existence_root::<HostFunctionsManager>(&existence_proof).unwrap(),
+            root
+        );
+
+        let proof = CommitmentProof {
+            proof: Some(Proof::Exist(existence_proof)),
+        };
+
+        verify_existence::<HostFunctionsManager>(&proof, &spec, &root, KEY, VALUE).unwrap();
+
+        assert!(verify_existence::<HostFunctionsManager>(
+            &proof,
+            &spec,
+            &root,
+            KEY,
+            b"other value"
+        )
+        .is_err());
+        assert!(
+            verify_existence::<HostFunctionsManager>(&proof, &spec, &SIBLING, KEY, VALUE).is_err()
+        );
+        assert!(verify_non_existence::<HostFunctionsManager>(&proof, &spec, &root, KEY).is_err());
+    }
+
+    #[test]
+    fn non_existence_root_requires_a_neighbour() {
+        let proof = NonExistenceProof {
+            key: KEY.to_vec(),
+            left: None,
+            right: None,
+        };
+
+        assert!(calculate_non_existence_root::<HostFunctionsManager>(&proof).is_err());
+    }
+}
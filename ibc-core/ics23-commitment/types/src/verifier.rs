@@ -0,0 +1,81 @@
+//! Abstracts the verification of commitment proofs, so that light clients can
+//! verify proofs of counterparties committing to their state with another
+//! scheme than ICS-23, such as the Merkle Patricia tries of EVM chains.
+
+use core::marker::PhantomData;
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ics23::HostFunctionsProvider;
+
+use crate::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::error::CommitmentError;
+use crate::merkle::{verify_membership, verify_non_membership};
+use crate::specs::ProofSpecs;
+
+/// A commitment scheme, verifying proofs of the (non-)membership of key/value
+/// pairs in the state committed to by a root.
+pub trait CommitmentVerifier {
+    /// Verifies that `proof` proves `value` to be stored under `key` in the
+    /// store of `prefix`, within the state committed to by `root`.
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), CommitmentError>;
+
+    /// Verifies that `proof` proves that nothing is stored under `key` in the
+    /// store of `prefix`, within the state committed to by `root`.
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        key: &str,
+    ) -> Result<(), CommitmentError>;
+}
+
+/// The ICS-23 commitment scheme, verifying Merkle proofs against the given
+/// proof specs.
+pub struct Ics23Verifier<H> {
+    specs: ProofSpecs,
+    _host_functions: PhantomData<H>,
+}
+
+impl<H> Ics23Verifier<H> {
+    pub fn new(specs: ProofSpecs) -> Self {
+        Self {
+            specs,
+            _host_functions: PhantomData,
+        }
+    }
+
+    pub fn specs(&self) -> &ProofSpecs {
+        &self.specs
+    }
+}
+
+impl<H: HostFunctionsProvider> CommitmentVerifier for Ics23Verifier<H> {
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), CommitmentError> {
+        verify_membership::<H>(&self.specs, prefix, proof, root, key, value)
+    }
+
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        key: &str,
+    ) -> Result<(), CommitmentError> {
+        verify_non_membership::<H>(&self.specs, prefix, proof, root, key)
+    }
+}
@@ -1,22 +1,14 @@
-use ibc_app_transfer_types::VERSION;
+use ibc_app_transfer_types::escrow_address;
 use ibc_core_host_types::identifiers::{ChannelId, PortId};
 use ibc_primitives::prelude::*;
-use sha2::{Digest, Sha256};
 
 /// Helper function to generate an escrow address for a given port and channel
 /// ids according to the format specified in the Cosmos SDK
 /// [`ADR-028`](https://github.com/cosmos/cosmos-sdk/blob/master/docs/architecture/adr-028-public-key-addresses.md)
+///
+/// Equivalent to [`escrow_address`] of the token transfer application.
 pub fn cosmos_adr028_escrow_address(port_id: &PortId, channel_id: &ChannelId) -> Vec<u8> {
-    let contents = format!("{port_id}/{channel_id}");
-
-    let mut hasher = Sha256::new();
-    hasher.update(VERSION.as_bytes());
-    hasher.update([0]);
-    hasher.update(contents.as_bytes());
-
-    let mut hash = hasher.finalize().to_vec();
-    hash.truncate(20);
-    hash
+    escrow_address(port_id, channel_id)
 }
 
 #[cfg(test)]
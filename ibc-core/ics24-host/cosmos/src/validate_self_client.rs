@@ -11,14 +11,35 @@ use ibc_primitives::prelude::*;
 use tendermint::trust_threshold::TrustThresholdFraction as TendermintTrustThresholdFraction;
 
 /// Provides a default implementation intended for implementing the
-/// `ValidationContext::validate_self_client` API.
+/// `ConnectionValidationContext::validate_self_client` API.
 ///
 /// This validation logic tailored for Tendermint client states of a host chain
-/// operating across various counterparty chains.
-pub trait ValidateSelfClientContext {
+/// operating across various counterparty chains. The validation is split into
+/// individual checks, each of which comes with a default implementation. Hosts
+/// with different requirements override only the checks that differ, and keep
+/// the defaults for the rest.
+pub trait SelfClientValidator {
+    /// Runs every check of the validator against the client state of the host
+    /// stored on the counterparty chain.
     fn validate_self_tendermint_client(
         &self,
         client_state_of_host_on_counterparty: TmClientState,
+    ) -> Result<(), ContextError> {
+        self.validate_client_state(&client_state_of_host_on_counterparty)?;
+        self.validate_chain_id(&client_state_of_host_on_counterparty)?;
+        self.validate_revision(&client_state_of_host_on_counterparty)?;
+        self.validate_proof_specs(&client_state_of_host_on_counterparty)?;
+        self.validate_trust_level(&client_state_of_host_on_counterparty)?;
+        self.validate_unbonding_period(&client_state_of_host_on_counterparty)?;
+        self.validate_upgrade_path(&client_state_of_host_on_counterparty)?;
+
+        Ok(())
+    }
+
+    /// Checks that the client state is well-formed and not frozen.
+    fn validate_client_state(
+        &self,
+        client_state_of_host_on_counterparty: &TmClientState,
     ) -> Result<(), ContextError> {
         client_state_of_host_on_counterparty
             .validate()
@@ -31,6 +52,14 @@ pub trait ValidateSelfClientContext {
             .into());
         }
 
+        Ok(())
+    }
+
+    /// Checks that the client tracks the chain id of the host.
+    fn validate_chain_id(
+        &self,
+        client_state_of_host_on_counterparty: &TmClientState,
+    ) -> Result<(), ContextError> {
         let self_chain_id = self.chain_id();
         if self_chain_id != &client_state_of_host_on_counterparty.chain_id {
             return Err(ContextError::ConnectionError(
@@ -43,8 +72,17 @@ pub trait ValidateSelfClientContext {
             ));
         }
 
+        Ok(())
+    }
+
+    /// Checks that the latest height of the client is in the current revision
+    /// of the host and below its current height.
+    fn validate_revision(
+        &self,
+        client_state_of_host_on_counterparty: &TmClientState,
+    ) -> Result<(), ContextError> {
         let latest_height = client_state_of_host_on_counterparty.latest_height;
-        let self_revision_number = self_chain_id.revision_number();
+        let self_revision_number = self.chain_id().revision_number();
         if self_revision_number != latest_height.revision_number() {
             return Err(ContextError::ConnectionError(
                 ConnectionError::InvalidClientState {
@@ -69,6 +107,15 @@ pub trait ValidateSelfClientContext {
             ));
         }
 
+        Ok(())
+    }
+
+    /// Checks that the client verifies proofs against the proof specs of the
+    /// host.
+    fn validate_proof_specs(
+        &self,
+        client_state_of_host_on_counterparty: &TmClientState,
+    ) -> Result<(), ContextError> {
         if self.proof_specs() != &client_state_of_host_on_counterparty.proof_specs {
             return Err(ContextError::ConnectionError(
                 ConnectionError::InvalidClientState {
@@ -81,18 +128,31 @@ pub trait ValidateSelfClientContext {
             ));
         }
 
-        let _ = {
-            let trust_level = client_state_of_host_on_counterparty.trust_level;
+        Ok(())
+    }
+
+    /// Checks that the trust level of the client lies within the bounds
+    /// accepted by Tendermint, i.e. between 1/3 and 1.
+    fn validate_trust_level(
+        &self,
+        client_state_of_host_on_counterparty: &TmClientState,
+    ) -> Result<(), ContextError> {
+        let trust_level = client_state_of_host_on_counterparty.trust_level;
 
-            TendermintTrustThresholdFraction::new(
-                trust_level.numerator(),
-                trust_level.denominator(),
-            )
+        TendermintTrustThresholdFraction::new(trust_level.numerator(), trust_level.denominator())
             .map_err(|_| ConnectionError::InvalidClientState {
-                reason: "invalid trust level".to_string(),
-            })?
-        };
+            reason: "invalid trust level".to_string(),
+        })?;
 
+        Ok(())
+    }
+
+    /// Checks that the unbonding period of the client matches the one of the
+    /// host, and exceeds the trusting period of the client.
+    fn validate_unbonding_period(
+        &self,
+        client_state_of_host_on_counterparty: &TmClientState,
+    ) -> Result<(), ContextError> {
         if self.unbonding_period() != client_state_of_host_on_counterparty.unbonding_period {
             return Err(ContextError::ConnectionError(
                 ConnectionError::InvalidClientState {
@@ -115,6 +175,15 @@ pub trait ValidateSelfClientContext {
             )}));
         }
 
+        Ok(())
+    }
+
+    /// Checks that the upgrade path of the client, if any, matches the one of
+    /// the host.
+    fn validate_upgrade_path(
+        &self,
+        client_state_of_host_on_counterparty: &TmClientState,
+    ) -> Result<(), ContextError> {
         if !client_state_of_host_on_counterparty.upgrade_path.is_empty()
             && self.upgrade_path() != client_state_of_host_on_counterparty.upgrade_path
         {
@@ -147,3 +216,124 @@ pub trait ValidateSelfClientContext {
     /// Returns the host upgrade path. May be empty.
     fn upgrade_path(&self) -> &[String];
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_client_tendermint::types::{AllowUpdate, TrustThreshold};
+
+    use super::*;
+
+    struct Host {
+        chain_id: ChainId,
+        proof_specs: ProofSpecs,
+        upgrade_path: Vec<String>,
+    }
+
+    impl SelfClientValidator for Host {
+        fn chain_id(&self) -> &ChainId {
+            &self.chain_id
+        }
+
+        fn host_current_height(&self) -> Height {
+            Height::new(1, 10).unwrap()
+        }
+
+        fn proof_specs(&self) -> &ProofSpecs {
+            &self.proof_specs
+        }
+
+        fn unbonding_period(&self) -> Duration {
+            Duration::from_secs(128_000)
+        }
+
+        fn upgrade_path(&self) -> &[String] {
+            &self.upgrade_path
+        }
+    }
+
+    /// A host that accepts clients tracking any chain id.
+    struct AnyChainIdHost(Host);
+
+    impl SelfClientValidator for AnyChainIdHost {
+        fn validate_chain_id(&self, _: &TmClientState) -> Result<(), ContextError> {
+            Ok(())
+        }
+
+        fn chain_id(&self) -> &ChainId {
+            self.0.chain_id()
+        }
+
+        fn host_current_height(&self) -> Height {
+            self.0.host_current_height()
+        }
+
+        fn proof_specs(&self) -> &ProofSpecs {
+            self.0.proof_specs()
+        }
+
+        fn unbonding_period(&self) -> Duration {
+            self.0.unbonding_period()
+        }
+
+        fn upgrade_path(&self) -> &[String] {
+            self.0.upgrade_path()
+        }
+    }
+
+    fn host() -> Host {
+        Host {
+            chain_id: ChainId::new("ibc-1").unwrap(),
+            proof_specs: ProofSpecs::cosmos(),
+            upgrade_path: vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+        }
+    }
+
+    fn client_state(chain_id: &str, latest_height: Height) -> TmClientState {
+        TmClientState::new(
+            ChainId::new(chain_id).unwrap(),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(64_000),
+            Duration::from_secs(128_000),
+            Duration::from_millis(3_000),
+            latest_height,
+            ProofSpecs::cosmos(),
+            vec![],
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_validate_self_tendermint_client() {
+        let height = Height::new(1, 5).unwrap();
+
+        assert!(host()
+            .validate_self_tendermint_client(client_state("ibc-1", height))
+            .is_ok());
+
+        assert!(host()
+            .validate_self_tendermint_client(client_state("other-1", height))
+            .is_err());
+
+        assert!(host()
+            .validate_self_tendermint_client(client_state("ibc-1", Height::new(1, 10).unwrap()))
+            .is_err());
+    }
+
+    #[test]
+    fn test_override_single_check() {
+        let host = AnyChainIdHost(host());
+
+        assert!(host
+            .validate_self_tendermint_client(client_state("other-1", Height::new(1, 5).unwrap()))
+            .is_ok());
+
+        // The remaining checks still apply.
+        assert!(host
+            .validate_self_tendermint_client(client_state("other-2", Height::new(2, 5).unwrap()))
+            .is_err());
+    }
+}
@@ -0,0 +1,110 @@
+//! Implements the port binding and capability authentication (ICS-05) of the
+//! hosts that [enforce capabilities](crate::ChannelValidationContext::enforces_capabilities).
+
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_core_router_types::capability::{Capability, CapabilityName};
+use ibc_core_router_types::error::RouterError;
+use ibc_core_router_types::module::ModuleId;
+
+use crate::{ChannelExecutionContext, ChannelValidationContext};
+
+/// Binds the port `port_id` to the module `module_id`, and returns the port
+/// capability issued to the module.
+///
+/// Errors if the port is already bound.
+pub fn bind_port<Ctx>(
+    ctx: &mut Ctx,
+    module_id: &ModuleId,
+    port_id: &PortId,
+) -> Result<Capability, ContextError>
+where
+    Ctx: ChannelExecutionContext,
+{
+    let name = CapabilityName::port(port_id);
+
+    if ctx.get_capability(&name)?.is_some() {
+        return Err(RouterError::PortAlreadyBound {
+            port_id: port_id.clone(),
+        }
+        .into());
+    }
+
+    issue_capability(ctx, module_id, &name)
+}
+
+/// Issues the capability over the channel `channel_id` of the port `port_id`
+/// to the module `module_id`, which the channel handshake does once the
+/// channel is opened on this chain.
+pub fn claim_channel_capability<Ctx>(
+    ctx: &mut Ctx,
+    module_id: &ModuleId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<Capability, ContextError>
+where
+    Ctx: ChannelExecutionContext,
+{
+    issue_capability(
+        ctx,
+        module_id,
+        &CapabilityName::channel(port_id, channel_id),
+    )
+}
+
+/// Verifies that `capability` is the one recorded under `name`, and that the
+/// module `module_id` presenting it owns it.
+pub fn authenticate_capability<Ctx>(
+    ctx: &Ctx,
+    module_id: &ModuleId,
+    capability: &Capability,
+    name: &CapabilityName,
+) -> Result<(), ContextError>
+where
+    Ctx: ChannelValidationContext,
+{
+    if ctx.get_capability(name)?.as_ref() != Some(capability) {
+        return Err(capability_not_owned(module_id, name));
+    }
+
+    verify_capability_owner(ctx, module_id, name)
+}
+
+/// Verifies that the module `module_id` owns the capability recorded under
+/// `name`.
+pub fn verify_capability_owner<Ctx>(
+    ctx: &Ctx,
+    module_id: &ModuleId,
+    name: &CapabilityName,
+) -> Result<(), ContextError>
+where
+    Ctx: ChannelValidationContext,
+{
+    if !ctx.capability_owners(name)?.contains(module_id) {
+        return Err(capability_not_owned(module_id, name));
+    }
+
+    Ok(())
+}
+
+fn issue_capability<Ctx>(
+    ctx: &mut Ctx,
+    module_id: &ModuleId,
+    name: &CapabilityName,
+) -> Result<Capability, ContextError>
+where
+    Ctx: ChannelExecutionContext,
+{
+    let capability = ctx.new_capability(name)?;
+    ctx.claim_capability(module_id, name)?;
+
+    Ok(capability)
+}
+
+fn capability_not_owned(module_id: &ModuleId, name: &CapabilityName) -> ContextError {
+    RouterError::CapabilityNotOwned {
+        module_id: module_id.clone(),
+        name: name.clone(),
+    }
+    .into()
+}
@@ -1,34 +1,88 @@
+use core::fmt::{Debug, Display};
 use core::time::Duration;
 
-use ibc_core_channel_types::channel::ChannelEnd;
-use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use displaydoc::Display;
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc_core_channel_types::commitment::{
+    compute_ack_commitment_with, AcknowledgementCommitment, PacketCommitment,
+};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::packet::Receipt;
 use ibc_core_client_context::prelude::*;
-use ibc_core_client_types::Height;
-use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_client_types::{ClientParams, Height};
+use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_core_commitment_types::error::CommitmentError;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::params::ConnectionParams;
 use ibc_core_connection_types::version::{pick_version, Version as ConnectionVersion};
-use ibc_core_connection_types::ConnectionEnd;
-use ibc_core_handler_types::error::ContextError;
+use ibc_core_connection_types::{ConnectionEnd, IdentifiedConnectionEnd};
+use ibc_core_handler_types::error::{ContextError, HostError};
 use ibc_core_handler_types::events::IbcEvent;
-use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_core_host_types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc_core_host_types::limits::HostLimits;
 use ibc_core_host_types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath, ReceiptPath,
+    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
+    CommitmentPath, ConnectionPath, Path, PruningSeqEndPath, PruningSeqStartPath, ReceiptPath,
     SeqAckPath, SeqRecvPath, SeqSendPath,
 };
+use ibc_core_host_types::write_set::WriteSet;
+use ibc_core_router_types::capability::{Capability, CapabilityName};
+use ibc_core_router_types::error::RouterError;
+use ibc_core_router_types::module::ModuleId;
 use ibc_primitives::prelude::*;
-use ibc_primitives::{Signer, Timestamp};
+use ibc_primitives::proto::Any;
+use ibc_primitives::{HostCryptoProvider, Signer, Timestamp};
 
 use crate::utils::calculate_block_delay;
 
 /// Context to be implemented by the host that provides all "read-only" methods.
 ///
 /// Trait used for the top-level `validate` entrypoint in the `ibc-core` crate.
-pub trait ValidationContext {
+///
+/// This trait, as it stands right now, serves as a trait alias for types that
+/// implement the contexts of the clients, connections, channels and packets of
+/// the host, and it is auto-implemented for such types. Hosts only running
+/// some of the handlers, e.g. applications that only send packets, implement
+/// the contexts those handlers require instead.
+pub trait ValidationContext:
+    ClientRegistryValidationContext
+    + ConnectionValidationContext
+    + ChannelValidationContext
+    + PacketValidationContext
+{
+}
+
+impl<T> ValidationContext for T where
+    T: ClientRegistryValidationContext
+        + ConnectionValidationContext
+        + ChannelValidationContext
+        + PacketValidationContext
+{
+}
+
+/// Context to be implemented by the host that provides the "read-only"
+/// methods shared by the handlers of all the ICS-26 messages.
+pub trait HostValidationContext {
     type V: ClientValidationContext;
-    /// The client state type for the host chain.
-    type HostClientState: ClientStateValidation<Self::V>;
-    /// The consensus state type for the host chain.
-    type HostConsensusState: ConsensusState;
+
+    /// The provider of the cryptographic primitives used by the handlers,
+    /// such as the hashing of packet commitments.
+    type CryptoProvider: HostCryptoProvider;
+
+    /// The errors of the host, e.g. of its storage.
+    ///
+    /// Hosts wrap them with [`host_error`](Self::host_error), and callers
+    /// recover them with [`ContextError::host_error`].
+    type HostError: Debug + Display + Send + Sync + 'static;
+
+    /// Wraps the given error of the host into a [`ContextError`].
+    fn host_error(error: impl Into<Self::HostError>) -> ContextError
+    where
+        Self: Sized,
+    {
+        ContextError::HostError(HostError::new(error.into()))
+    }
 
     /// Retrieve the context that implements all clients' `ValidationContext`.
     fn get_client_validation_context(&self) -> &Self::V;
@@ -39,20 +93,147 @@ pub trait ValidationContext {
     /// Returns the current timestamp of the local chain.
     fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
 
-    /// Returns the `ConsensusState` of the host (local) chain at a specific height.
-    fn host_consensus_state(
-        &self,
-        height: &Height,
-    ) -> Result<Self::HostConsensusState, ContextError>;
+    /// Returns the caps on the number of clients, connections and channels
+    /// that can be created on the host, enforced upon their creation. No caps
+    /// are set by default.
+    fn host_limits(&self) -> Result<HostLimits, ContextError> {
+        Ok(HostLimits::unlimited())
+    }
+
+    /// Returns the maximum expected time per block, as last stored through
+    /// `ConnectionExecutionContext::store_connection_params`.
+    fn max_expected_time_per_block(&self) -> Duration;
+
+    /// Calculates the block delay period using the connection's delay period and the maximum
+    /// expected time per block.
+    fn block_delay(&self, delay_period_time: &Duration) -> u64 {
+        calculate_block_delay(delay_period_time, &self.max_expected_time_per_block())
+    }
+
+    /// Validates the `signer` field of IBC messages, which represents the address
+    /// of the user/relayer that signed the given message.
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+
+    /// Returns the value stored under `path` in the IBC store at `height`,
+    /// along with its ICS-23 proof, or the proof of its absence.
+    ///
+    /// It is not required by the handlers. Hosts whose store can prove its
+    /// entries override it, so that RPC layers serving light clients and
+    /// relayers can be built on top of them. Defaults to an error.
+    fn query_with_proof(&self, path: &Path, height: Height) -> Result<ProvenValue, ContextError> {
+        Err(ProofQueryError::Unsupported {
+            path: path.clone(),
+            height,
+        }
+        .into())
+    }
+}
 
+/// Context to be implemented by the host that provides the "read-only"
+/// methods required by the ICS-02 client handlers, on top of those of the
+/// light clients themselves.
+pub trait ClientRegistryValidationContext: HostValidationContext {
     /// Returns a natural number, counting how many clients have been created
     /// thus far. The value of this counter should increase only via method
-    /// `ExecutionContext::increase_client_counter`.
+    /// `ClientRegistryExecutionContext::increase_client_counter`.
     fn client_counter(&self) -> Result<u64, ContextError>;
 
+    /// Returns the ICS-02 parameters of the host, consulted upon client
+    /// creation.
+    fn client_params(&self) -> Result<ClientParams, ContextError>;
+
+    /// Authorizes the signer of a `MsgUpdateClient` to update the given
+    /// client with the given client message, on top of the verification of
+    /// the message by the light client itself.
+    ///
+    /// Lets hosts restrict who may update clients of permissioned
+    /// counterparties, e.g. proof-of-authority chains where only a known
+    /// operator may relay headers. Freezing a client on misbehaviour remains
+    /// permissionless: neither `MsgSubmitMisbehaviour` nor a `MsgUpdateClient`
+    /// whose message the light client finds to be misbehaviour is subject to
+    /// it. All updates are authorized by default.
+    fn authorize_client_update(
+        &self,
+        _client_id: &ClientId,
+        _client_message: &Any,
+        _signer: &Signer,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Returns the grace period past the expiry of a client during which the
+    /// client still verifies the proofs of packet messages, i.e. receipts,
+    /// acknowledgements and timeouts, though not those of handshakes. This
+    /// spares packets from getting stuck while expired clients await
+    /// recovery.
+    ///
+    /// Only honored by light clients implementing
+    /// `ClientStateValidation::is_within_expiry_grace_period`. Off by default.
+    fn client_expiry_grace_period(&self) -> Result<Option<Duration>, ContextError> {
+        Ok(None)
+    }
+}
+
+/// Context to be implemented by the host that provides the "read-only" access
+/// to its connection ends, required by the ICS-03 connection handlers as well
+/// as by the ICS-04 channel and packet handlers.
+pub trait ConnectionEndValidationContext: HostValidationContext {
     /// Returns the ConnectionEnd for the given identifier `conn_id`.
     fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError>;
 
+    /// Returns the ConnectionEnd for the given identifier `conn_id`, borrowed
+    /// from the host store when possible.
+    ///
+    /// Defaults to the owned [`connection_end`](Self::connection_end). Hosts
+    /// able to serve references into their store override this method to
+    /// spare the packet handlers a clone of the connection end on every packet.
+    fn connection_end_cow(
+        &self,
+        conn_id: &ConnectionId,
+    ) -> Result<Cow<'_, ConnectionEnd>, ContextError> {
+        self.connection_end(conn_id).map(Cow::Owned)
+    }
+}
+
+/// Context to be implemented by the host that provides the "read-only"
+/// methods required by the ICS-03 connection handlers.
+pub trait ConnectionValidationContext: ConnectionEndValidationContext {
+    /// The client state type for the host chain.
+    type HostClientState: ClientStateValidation<Self::V>;
+
+    /// The consensus state type for the host chain.
+    type HostConsensusState: ConsensusState;
+
+    /// Returns the `ConsensusState` of the host (local) chain at a specific height.
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError>;
+
+    /// Returns the identifiers of all the connections opened on top of the
+    /// given client, as recorded by `ConnectionExecutionContext::store_connection_to_client`.
+    ///
+    /// Hosts maintaining a reverse index from clients to their connections
+    /// should override it.
+    fn connections_for_client(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<ConnectionId>, ContextError> {
+        Err(ConnectionError::Other {
+            description: format!("host does not index the connections of client {client_id}"),
+        }
+        .into())
+    }
+
+    /// Returns the number of connections opened on top of the given client.
+    ///
+    /// Only consulted when `HostLimits::max_connections_per_client` is set.
+    /// Defaults to the number of connections returned by
+    /// [`connections_for_client`](Self::connections_for_client).
+    fn client_connection_count(&self, client_id: &ClientId) -> Result<u64, ContextError> {
+        Ok(self.connections_for_client(client_id)?.len() as u64)
+    }
+
     /// Validates the `ClientState` of the host chain stored on the counterparty
     /// chain against the host's internal state.
     ///
@@ -68,13 +249,30 @@ pub trait ValidationContext {
     ) -> Result<(), ContextError>;
 
     /// Returns the prefix that the local chain uses in the KV store.
+    ///
+    /// Hosts committing their IBC state at the root of their store return
+    /// [`CommitmentPrefix::empty`].
     fn commitment_prefix(&self) -> CommitmentPrefix;
 
+    /// Returns whether connections can be opened with counterparties
+    /// committing their IBC state at the root of their store, i.e. with an
+    /// empty commitment prefix.
+    ///
+    /// Defaults to `false`, like ibc-go which requires a prefix.
+    fn allows_empty_counterparty_prefix(&self) -> bool {
+        false
+    }
+
     /// Returns a counter on how many connections have been created thus far.
     fn connection_counter(&self) -> Result<u64, ContextError>;
 
     /// Function required by ICS-03. Returns the list of all possible versions that the connection
     /// handshake protocol supports.
+    ///
+    /// Hosts supporting other versions or feature sets than the
+    /// [`compatibles`](ConnectionVersion::compatibles) ones override this
+    /// method. The versions are offered upon `ConnOpenInit`, and negotiated
+    /// against those of the counterparty upon `ConnOpenTry`.
     fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
         ConnectionVersion::compatibles()
     }
@@ -91,10 +289,70 @@ pub trait ValidationContext {
         )?;
         Ok(version)
     }
+}
+
+/// Context to be implemented by the host that provides the "read-only"
+/// methods required by the ICS-04 channel handshake handlers.
+pub trait ChannelValidationContext: HostValidationContext {
+    /// Returns the number of channels opened on top of the given connection.
+    ///
+    /// Only consulted when `HostLimits::max_channels_per_connection` is set,
+    /// in which case hosts must override it.
+    fn connection_channel_count(&self, conn_id: &ConnectionId) -> Result<u64, ContextError> {
+        Err(ChannelError::Other {
+            description: format!("host does not count the channels of connection {conn_id}"),
+        }
+        .into())
+    }
 
     /// Returns the `ChannelEnd` for the given `port_id` and `chan_id`.
     fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError>;
 
+    /// Returns the `ChannelEnd` for the given `port_id` and `chan_id`,
+    /// borrowed from the host store when possible.
+    ///
+    /// Defaults to the owned [`channel_end`](Self::channel_end), and is
+    /// overridden alongside
+    /// [`connection_end_cow`](ConnectionEndValidationContext::connection_end_cow).
+    fn channel_end_cow(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Cow<'_, ChannelEnd>, ContextError> {
+        self.channel_end(channel_end_path).map(Cow::Owned)
+    }
+
+    /// Returns a counter on the number of channel ids have been created thus far.
+    /// The value of this counter should increase only via method
+    /// `ChannelExecutionContext::increase_channel_counter`.
+    fn channel_counter(&self) -> Result<u64, ContextError>;
+
+    /// Returns whether the host enforces the ownership of ports and channels
+    /// through capabilities (ICS-05).
+    ///
+    /// If so, channels can only be opened on ports bound with
+    /// [`bind_port`](crate::capability::bind_port) by the module they are
+    /// routed to, and the channel capability is then issued to that module,
+    /// which alone can handle the messages on the channel. Hosts enforcing
+    /// capabilities implement the capability methods of both contexts.
+    /// Defaults to `false`.
+    fn enforces_capabilities(&self) -> bool {
+        false
+    }
+
+    /// Returns the capability recorded under `name`, if any.
+    fn get_capability(&self, _name: &CapabilityName) -> Result<Option<Capability>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns the modules owning the capability recorded under `name`.
+    fn capability_owners(&self, _name: &CapabilityName) -> Result<Vec<ModuleId>, ContextError> {
+        Ok(Vec::new())
+    }
+}
+
+/// Context to be implemented by the host that provides the "read-only"
+/// methods required by the ICS-04 packet handlers.
+pub trait PacketValidationContext: HostValidationContext {
     /// Returns the sequence number for the next packet to be sent for the given store path
     fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
         -> Result<Sequence, ContextError>;
@@ -121,39 +379,349 @@ pub trait ValidationContext {
         ack_path: &AckPath,
     ) -> Result<AcknowledgementCommitment, ContextError>;
 
-    /// Returns a counter on the number of channel ids have been created thus far.
-    /// The value of this counter should increase only via method
-    /// `ExecutionContext::increase_channel_counter`.
-    fn channel_counter(&self) -> Result<u64, ContextError>;
+    /// Returns the first sequence whose packet acknowledgement and receipt
+    /// are yet to be pruned on the given channel, or `None` if the channel
+    /// has nothing to prune.
+    ///
+    /// Hosts supporting channel upgrades set it, along with the pruning
+    /// sequence end, once the upgrade of a channel completes, so that the
+    /// acknowledgements written before the upgrade can be pruned through
+    /// `MsgPruneAcknowledgements`. Returns `None` by default.
+    fn get_pruning_sequence_start(
+        &self,
+        _pruning_seq_start_path: &PruningSeqStartPath,
+    ) -> Result<Option<Sequence>, ContextError> {
+        Ok(None)
+    }
 
-    /// Returns the maximum expected time per block
-    fn max_expected_time_per_block(&self) -> Duration;
+    /// Returns the sequence, exclusive, up to which the packet
+    /// acknowledgements and receipts of the given channel can be pruned, i.e.
+    /// the next sequence the counterparty was to send once the upgrade of the
+    /// channel completed, or `None` if the channel was never upgraded.
+    /// Returns `None` by default.
+    fn get_pruning_sequence_end(
+        &self,
+        _pruning_seq_end_path: &PruningSeqEndPath,
+    ) -> Result<Option<Sequence>, ContextError> {
+        Ok(None)
+    }
 
-    /// Calculates the block delay period using the connection's delay period and the maximum
-    /// expected time per block.
-    fn block_delay(&self, delay_period_time: &Duration) -> u64 {
-        calculate_block_delay(delay_period_time, &self.max_expected_time_per_block())
+    /// Encodes the given acknowledgement into the commitment stored under its
+    /// `AckPath` upon writing it, and expected from the counterparty upon
+    /// processing it.
+    ///
+    /// Defaults to the SHA-256 hash of the raw acknowledgement, as prescribed
+    /// by ICS-04 and done by ibc-go. Hosts may store acknowledgements in
+    /// another committed form, e.g. compressed, but the counterparty only ever
+    /// verifies the committed form against its own encoding, so both chains
+    /// must agree on it.
+    fn ack_commitment(&self, ack: &Acknowledgement) -> AcknowledgementCommitment {
+        compute_ack_commitment_with::<Self::CryptoProvider>(ack)
     }
+}
 
-    /// Validates the `signer` field of IBC messages, which represents the address
-    /// of the user/relayer that signed the given message.
-    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+/// The value stored under a path of the IBC store, along with its proof
+/// against the commitment root of the store at some height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProvenValue {
+    /// The value stored under the path, if any.
+    pub value: Option<Vec<u8>>,
+    /// The ICS-23 proof of the value, or of its absence.
+    pub proof: CommitmentProofBytes,
+    /// The height of the store the proof is against.
+    pub proof_height: Height,
+}
+
+/// Errors of the proofs of the IBC store queried through
+/// [`HostValidationContext::query_with_proof`].
+#[derive(Debug, Display)]
+pub enum ProofQueryError {
+    /// host cannot prove the value stored under `{path}` at height `{height}`
+    Unsupported { path: Path, height: Height },
+    /// cannot prove `{path}` at height `{height}`: only the latest height `{latest_height}` is retained
+    HeightNotRetained {
+        path: Path,
+        height: Height,
+        latest_height: Height,
+    },
+    /// store cannot prove `{path}`
+    MissingProof { path: Path },
+    /// invalid proof of `{path}`: `{error}`
+    InvalidProof { path: Path, error: CommitmentError },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProofQueryError {}
+
+impl From<ProofQueryError> for ContextError {
+    fn from(error: ProofQueryError) -> Self {
+        Self::HostError(HostError::new(error))
+    }
+}
+
+/// Context to be implemented by hosts able to iterate over their store by path
+/// prefix, that provides the entries stored under the ICS-24 paths.
+///
+/// It is not required by the handlers. Generic query services and invariant
+/// checkers can build on it instead of host-specific store accessors. Entries
+/// are returned ordered by their path.
+pub trait IterableContext: ValidationContext {
+    /// Returns the client states stored under `clients/{client_id}/clientState`.
+    fn iter_client_states(
+        &self,
+    ) -> Result<Vec<(ClientStatePath, ClientStateRef<Self>)>, ContextError>;
+
+    /// Returns the consensus states stored under
+    /// `clients/{client_id}/consensusStates` for the given client.
+    fn iter_consensus_states(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<(ClientConsensusStatePath, ConsensusStateRef<Self>)>, ContextError>;
+
+    /// Returns the connection ends stored under `connections`.
+    fn iter_connections(&self) -> Result<Vec<(ConnectionPath, ConnectionEnd)>, ContextError>;
+
+    /// Returns the channel ends stored under `channelEnds`.
+    fn iter_channels(&self) -> Result<Vec<(ChannelEndPath, ChannelEnd)>, ContextError>;
+
+    /// Returns all the client states, along with the identifier of their
+    /// client.
+    ///
+    /// Defaults to identifying the entries of
+    /// [`iter_client_states`](Self::iter_client_states).
+    fn all_client_states(&self) -> Result<Vec<(ClientId, ClientStateRef<Self>)>, ContextError> {
+        Ok(self
+            .iter_client_states()?
+            .into_iter()
+            .map(|(ClientStatePath(client_id), client_state)| (client_id, client_state))
+            .collect())
+    }
+
+    /// Returns all the connection ends, along with their identifier.
+    ///
+    /// Defaults to identifying the entries of
+    /// [`iter_connections`](Self::iter_connections).
+    fn all_connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError> {
+        Ok(self
+            .iter_connections()?
+            .into_iter()
+            .map(|(ConnectionPath(conn_id), conn_end)| {
+                IdentifiedConnectionEnd::new(conn_id, conn_end)
+            })
+            .collect())
+    }
+
+    /// Returns all the channel ends, along with their port and channel
+    /// identifiers.
+    ///
+    /// Defaults to identifying the entries of
+    /// [`iter_channels`](Self::iter_channels).
+    fn all_channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError> {
+        Ok(self
+            .iter_channels()?
+            .into_iter()
+            .map(|(ChannelEndPath(port_id, channel_id), chan_end)| {
+                IdentifiedChannelEnd::new(port_id, channel_id, chan_end)
+            })
+            .collect())
+    }
+
+    /// Returns the packet commitments stored under
+    /// `commitments/ports/{port_id}/channels/{channel_id}` for the given
+    /// channel.
+    fn iter_commitments(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<(CommitmentPath, PacketCommitment)>, ContextError>;
+
+    /// Returns the packet receipts stored under
+    /// `receipts/ports/{port_id}/channels/{channel_id}` for the given channel.
+    fn iter_receipts(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<(ReceiptPath, Receipt)>, ContextError>;
+
+    /// Returns the packet acknowledgement commitments stored under
+    /// `acks/ports/{port_id}/channels/{channel_id}` for the given channel.
+    fn iter_acknowledgements(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<(AckPath, AcknowledgementCommitment)>, ContextError>;
+
+    /// Returns at most `limit` packet commitments of the given channel,
+    /// starting from the one of the packet sequence `start`.
+    ///
+    /// Pages are keyed by packet sequence: the next page starts right after
+    /// the sequence of the last returned commitment. Defaults to paginating
+    /// [`iter_commitments`](Self::iter_commitments); hosts override it to
+    /// only read the requested page from their store.
+    fn commitments_page(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        start: Sequence,
+        limit: usize,
+    ) -> Result<Vec<(CommitmentPath, PacketCommitment)>, ContextError> {
+        Ok(self
+            .iter_commitments(channel_end_path)?
+            .into_iter()
+            .filter(|(path, _)| path.sequence >= start)
+            .take(limit)
+            .collect())
+    }
+
+    /// Returns at most `limit` packet receipts of the given channel, starting
+    /// from the one of the packet sequence `start`.
+    ///
+    /// Paginates [`iter_receipts`](Self::iter_receipts) like
+    /// [`commitments_page`](Self::commitments_page).
+    fn receipts_page(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        start: Sequence,
+        limit: usize,
+    ) -> Result<Vec<(ReceiptPath, Receipt)>, ContextError> {
+        Ok(self
+            .iter_receipts(channel_end_path)?
+            .into_iter()
+            .filter(|(path, _)| path.sequence >= start)
+            .take(limit)
+            .collect())
+    }
+
+    /// Returns at most `limit` packet acknowledgement commitments of the given
+    /// channel, starting from the one of the packet sequence `start`.
+    ///
+    /// Paginates [`iter_acknowledgements`](Self::iter_acknowledgements) like
+    /// [`commitments_page`](Self::commitments_page).
+    fn acknowledgements_page(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        start: Sequence,
+        limit: usize,
+    ) -> Result<Vec<(AckPath, AcknowledgementCommitment)>, ContextError> {
+        Ok(self
+            .iter_acknowledgements(channel_end_path)?
+            .into_iter()
+            .filter(|(path, _)| path.sequence >= start)
+            .take(limit)
+            .collect())
+    }
 }
 
 /// Context to be implemented by the host that provides all "write-only" methods.
 ///
 /// Trait used for the top-level `execute` and `dispatch` entrypoints in the `ibc-core` crate.
-pub trait ExecutionContext: ValidationContext {
+///
+/// Like the [`ValidationContext`], it serves as a trait alias for types that
+/// implement the execution contexts of the clients, connections, channels and
+/// packets of the host, and it is auto-implemented for such types.
+pub trait ExecutionContext:
+    ValidationContext
+    + ClientRegistryExecutionContext
+    + ConnectionExecutionContext
+    + ChannelExecutionContext
+    + PacketExecutionContext
+{
+}
+
+impl<T> ExecutionContext for T where
+    T: ValidationContext
+        + ClientRegistryExecutionContext
+        + ConnectionExecutionContext
+        + ChannelExecutionContext
+        + PacketExecutionContext
+{
+}
+
+/// Context to be implemented by the host that provides the "write-only"
+/// methods shared by the handlers of all the ICS-26 messages.
+pub trait HostExecutionContext: HostValidationContext {
     type E: ClientExecutionContext;
 
     /// Retrieve the context that implements all clients' `ExecutionContext`.
     fn get_client_execution_context(&mut self) -> &mut Self::E;
 
+    /// Emit the given IBC event
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
+
+    /// Log the given message.
+    fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+
+    /// Returns the events emitted so far through
+    /// [`emit_ibc_event`](Self::emit_ibc_event), in order, if the host keeps
+    /// them.
+    ///
+    /// The entrypoints return the events appended while executing each
+    /// message. Defaults to none, in which case they return no events.
+    fn emitted_ibc_events(&self) -> &[IbcEvent] {
+        &[]
+    }
+
+    /// Returns the messages logged so far through
+    /// [`log_message`](Self::log_message), in order, if the host keeps them.
+    ///
+    /// Defaults to none, as for [`emitted_ibc_events`](Self::emitted_ibc_events).
+    fn logged_messages(&self) -> &[String] {
+        &[]
+    }
+
+    /// Returns whether the Cosmos SDK `message` event, carrying the type URL
+    /// and the signer of the message, is emitted ahead of the events of each
+    /// dispatched message.
+    ///
+    /// Lets hosts not built on the Cosmos SDK serve the indexers and relayers
+    /// that filter the transactions of ibc-go chains on these attributes.
+    /// Defaults to `false`.
+    fn emits_sdk_message_events(&self) -> bool {
+        false
+    }
+
+    /// Returns the write set in which the host records the store writes of
+    /// the message handlers, if it opts into it.
+    ///
+    /// Handlers do not record anything themselves: hosts opting in record
+    /// each write as their `store_*` and `delete_*` methods perform it,
+    /// including those of the [`ClientExecutionContext`] through which light
+    /// clients store their states, with the value encoded the way `ibc-go`
+    /// commits it.
+    fn write_set_mut(&mut self) -> Option<&mut WriteSet> {
+        None
+    }
+}
+
+/// Context to be implemented by the host that provides the "write-only"
+/// methods required by the ICS-02 client handlers.
+pub trait ClientRegistryExecutionContext:
+    ClientRegistryValidationContext + HostExecutionContext
+{
     /// Called upon client creation.
     /// Increases the counter which keeps track of how many clients have been created.
     /// Should never fail.
     fn increase_client_counter(&mut self) -> Result<(), ContextError>;
 
+    /// Stores the ICS-02 parameters of the host.
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError>;
+
+    /// Called once the client has been updated with a header of the given
+    /// type URL, along with the heights of the consensus states it installed
+    /// (empty if the header had already been installed).
+    ///
+    /// Lets hosts maintain secondary indexes, such as per-counterparty-chain
+    /// lookups, as part of the same state transition as the update. Erroring
+    /// fails the update. Does nothing by default.
+    fn on_client_updated(
+        &mut self,
+        _client_id: &ClientId,
+        _consensus_heights: &[Height],
+        _header_type: &str,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+}
+
+/// Context to be implemented by the host that provides the "write-only"
+/// methods required by the ICS-03 connection handlers.
+pub trait ConnectionExecutionContext: ConnectionValidationContext + HostExecutionContext {
     /// Stores the given connection_end at path
     fn store_connection(
         &mut self,
@@ -162,17 +730,63 @@ pub trait ExecutionContext: ValidationContext {
     ) -> Result<(), ContextError>;
 
     /// Stores the given connection_id at a path associated with the client_id.
+    ///
+    /// A client may back several connections, hence the given connection_id
+    /// must be added to the ones already stored for the client.
     fn store_connection_to_client(
         &mut self,
         client_connection_path: &ClientConnectionPath,
         conn_id: ConnectionId,
     ) -> Result<(), ContextError>;
 
+    /// Stores the ICS-03 parameters of the host.
+    fn store_connection_params(&mut self, params: ConnectionParams) -> Result<(), ContextError>;
+
     /// Called upon connection identifier creation (Init or Try process).
     /// Increases the counter which keeps track of how many connections have been created.
     /// Should never fail.
     fn increase_connection_counter(&mut self) -> Result<(), ContextError>;
+}
+
+/// Context to be implemented by the host that provides the "write-only"
+/// methods required by the ICS-04 channel handshake handlers.
+pub trait ChannelExecutionContext: ChannelValidationContext + HostExecutionContext {
+    /// Stores the given channel_end at a path associated with the port_id and channel_id.
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError>;
+
+    /// Called upon channel identifier creation (Init or Try message processing).
+    /// Increases the counter which keeps track of how many channels have been created.
+    /// Should never fail.
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError>;
+
+    /// Records a newly issued capability under `name`, which must not be
+    /// taken yet, and returns it.
+    ///
+    /// Every capability issued by the host must be distinct. Only called by
+    /// hosts that [enforce capabilities](ChannelValidationContext::enforces_capabilities),
+    /// and errors by default.
+    fn new_capability(&mut self, _name: &CapabilityName) -> Result<Capability, ContextError> {
+        Err(RouterError::CapabilitiesNotSupported.into())
+    }
+
+    /// Adds `module_id` to the owners of the capability recorded under
+    /// `name`. Errors by default.
+    fn claim_capability(
+        &mut self,
+        _module_id: &ModuleId,
+        _name: &CapabilityName,
+    ) -> Result<(), ContextError> {
+        Err(RouterError::CapabilitiesNotSupported.into())
+    }
+}
 
+/// Context to be implemented by the host that provides the "write-only"
+/// methods required by the ICS-04 packet handlers.
+pub trait PacketExecutionContext: PacketValidationContext + HostExecutionContext {
     /// Stores the given packet commitment at the given store path
     fn store_packet_commitment(
         &mut self,
@@ -203,12 +817,48 @@ pub trait ExecutionContext: ValidationContext {
     /// Deletes the packet acknowledgement at the given store path
     fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError>;
 
-    /// Stores the given channel_end at a path associated with the port_id and channel_id.
-    fn store_channel(
+    /// Deletes the packet receipt at the given store path.
+    ///
+    /// Only called upon pruning the receipts of upgraded channels, so that
+    /// hosts not supporting channel upgrades need not implement it.
+    fn delete_packet_receipt(&mut self, receipt_path: &ReceiptPath) -> Result<(), ContextError> {
+        Err(PacketError::Other {
+            description: format!(
+                "host does not support deleting the packet receipt {receipt_path}"
+            ),
+        }
+        .into())
+    }
+
+    /// Stores the first sequence whose packet acknowledgement and receipt are
+    /// yet to be pruned at the given store path.
+    ///
+    /// Only called upon pruning, so that hosts not supporting channel
+    /// upgrades need not implement it.
+    fn store_pruning_sequence_start(
         &mut self,
-        channel_end_path: &ChannelEndPath,
-        channel_end: ChannelEnd,
-    ) -> Result<(), ContextError>;
+        pruning_seq_start_path: &PruningSeqStartPath,
+        _seq: Sequence,
+    ) -> Result<(), ContextError> {
+        Err(ChannelError::Other {
+            description: format!(
+                "host does not support storing the pruning sequence start {pruning_seq_start_path}"
+            ),
+        }
+        .into())
+    }
+
+    /// Deletes all the packet acknowledgements stored for the given channel.
+    ///
+    /// Called once both ends of the channel are closed, as the counterparty
+    /// can no longer acknowledge the packets. Hosts that cannot enumerate
+    /// their acknowledgements may keep them, as the default does.
+    fn delete_packet_acknowledgements(
+        &mut self,
+        _channel_end_path: &ChannelEndPath,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
 
     /// Stores the given `nextSequenceSend` number at the given store path
     fn store_next_sequence_send(
@@ -231,29 +881,37 @@ pub trait ExecutionContext: ValidationContext {
         seq: Sequence,
     ) -> Result<(), ContextError>;
 
-    /// Called upon channel identifier creation (Init or Try message processing).
-    /// Increases the counter which keeps track of how many channels have been created.
-    /// Should never fail.
-    fn increase_channel_counter(&mut self) -> Result<(), ContextError>;
-
-    /// Emit the given IBC event
-    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
+    /// Deletes the `nextSequenceSend` number at the given store path.
+    ///
+    /// Called once the channel is closed, as no packet can be sent on it
+    /// anymore. Keeps the number by default.
+    fn delete_next_sequence_send(
+        &mut self,
+        _seq_send_path: &SeqSendPath,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
 
-    /// Log the given message.
-    fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+    /// Deletes the `nextSequenceAck` number at the given store path.
+    ///
+    /// Called once the channel is closed, as no packet can be acknowledged on
+    /// it anymore. Keeps the number by default.
+    fn delete_next_sequence_ack(&mut self, _seq_ack_path: &SeqAckPath) -> Result<(), ContextError> {
+        Ok(())
+    }
 }
 
 /// Convenient type alias for `ClientStateRef`, providing access to client
 /// validation methods within the context.
 pub type ClientStateRef<Ctx> =
-    <<Ctx as ValidationContext>::V as ClientValidationContext>::ClientStateRef;
+    <<Ctx as HostValidationContext>::V as ClientValidationContext>::ClientStateRef;
 
 /// Convenient type alias for `ClientStateMut`, providing access to client
 /// execution methods within the context.
 pub type ClientStateMut<Ctx> =
-    <<Ctx as ExecutionContext>::E as ClientExecutionContext>::ClientStateMut;
+    <<Ctx as HostExecutionContext>::E as ClientExecutionContext>::ClientStateMut;
 
 /// Convenient type alias for `ConsensusStateRef`, providing access to client
 /// validation methods within the context.
 pub type ConsensusStateRef<Ctx> =
-    <<Ctx as ValidationContext>::V as ClientValidationContext>::ConsensusStateRef;
+    <<Ctx as HostValidationContext>::V as ClientValidationContext>::ConsensusStateRef;
@@ -19,7 +19,10 @@ extern crate std;
 
 pub(crate) mod utils;
 
+pub mod capability;
 mod context;
+pub mod staging;
+pub mod store;
 pub use context::*;
 
 /// Re-exports ICS-24 data structures from `ibc-core-host-types` crate.
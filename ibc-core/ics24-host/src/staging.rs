@@ -0,0 +1,38 @@
+//! Lets hosts discard all the writes performed while handling a message whose
+//! handling fails midway.
+
+use displaydoc::Display;
+
+use crate::ExecutionContext;
+
+/// An [`ExecutionContext`] whose writes, along with the events and logs it
+/// records, can be staged and discarded as a whole.
+///
+/// Handlers may fail after writing through the context, e.g. when an
+/// application callback fails once the channel end is stored. Hosts that do
+/// not roll back the state of failed transactions themselves implement this
+/// trait, and handle messages through the entrypoints staging their writes,
+/// such as `dispatch_batch`, which checkpoint and restore the context along
+/// with the router.
+pub trait StagingContext: ExecutionContext {
+    /// The state of the context to restore when discarding staged writes.
+    type Checkpoint;
+
+    /// Returns the current state of the context.
+    fn checkpoint(&self) -> Self::Checkpoint;
+
+    /// Restores the state of the context to `checkpoint`, discarding all the
+    /// writes, events and logs since it was taken.
+    fn restore(&mut self, checkpoint: Self::Checkpoint);
+}
+
+/// Errors of the entrypoints executing messages against a [`StagingContext`],
+/// returned as host errors.
+#[derive(Debug, Display)]
+pub enum StagingError {
+    /// context does not record the write set of the handlers
+    WriteSetNotRecorded,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StagingError {}
@@ -0,0 +1,1001 @@
+//! Implements the host contexts on top of a provable key-value store, in which
+//! the IBC state is stored under its ICS-24 paths.
+//!
+//! Hosts provide their store through [`ProvableStore`], and the few methods
+//! that depend on the chain itself, e.g. its height or how to validate its own
+//! client state, through [`StoreHost`]. [`StoreContext`] then implements all
+//! the client and host contexts, with values encoded like ibc-go does.
+
+use core::fmt::{Debug, Display};
+use core::str::FromStr;
+use core::time::Duration;
+
+use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::Receipt;
+use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::{ClientParams, Height};
+use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::params::ConnectionParams;
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host_types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc_core_host_types::path::{
+    full_consensus_state_path, AckPath, ChannelEndPath, ClientConnectionPath,
+    ClientConsensusStatePath, ClientStatePath, ClientUpdateHeightPath, ClientUpdateTimePath,
+    CommitmentPath, ConnectionPath, NextChannelSequencePath, NextClientSequencePath,
+    NextConnectionSequencePath, Path, PruningSeqEndPath, PruningSeqStartPath, ReceiptPath,
+    SeqAckPath, SeqRecvPath, SeqSendPath, CHANNEL_PREFIX, PACKET_ACK_PREFIX, PORT_PREFIX,
+    SEQUENCE_PREFIX,
+};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::{HostCryptoProvider, Signer, Timestamp};
+use ibc_proto::ibc::core::channel::v1::Channel as RawChannel;
+use ibc_proto::ibc::core::client::v1::Params as RawClientParams;
+use ibc_proto::ibc::core::connection::v1::{
+    ClientPaths as RawClientPaths, ConnectionEnd as RawConnectionEnd, Params as RawConnectionParams,
+};
+use prost::Message;
+
+use crate::{
+    ChannelExecutionContext, ChannelValidationContext, ClientRegistryExecutionContext,
+    ClientRegistryValidationContext, ConnectionEndValidationContext, ConnectionExecutionContext,
+    ConnectionValidationContext, HostExecutionContext, HostValidationContext,
+    PacketExecutionContext, PacketValidationContext, ProofQueryError, ProvenValue,
+};
+
+/// The key under which the ICS-02 parameters are stored, as in ibc-go.
+pub const CLIENT_PARAMS_KEY: &str = "clientParams";
+
+/// The key under which the ICS-03 parameters are stored, as in ibc-go.
+pub const CONNECTION_PARAMS_KEY: &str = "connectionParams";
+
+/// A key-value store able to prove its entries against its commitment root.
+pub trait ProvableStore {
+    /// Returns the value stored under `key`, if any.
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>>;
+
+    /// Stores `value` under `key`.
+    fn set(&mut self, key: Vec<u8>, value: Vec<u8>);
+
+    /// Deletes the value stored under `key`, if any.
+    fn delete(&mut self, key: &[u8]);
+
+    /// Returns all the keys starting with `prefix`, in any order.
+    ///
+    /// Used to list the consensus states of a client and the
+    /// acknowledgements of a channel.
+    fn keys(&self, prefix: &[u8]) -> Vec<Vec<u8>>;
+
+    /// Returns the commitment root of the store.
+    fn root(&self) -> Vec<u8>;
+
+    /// Returns the proof of the value stored under `key`, or of its absence,
+    /// against the commitment root.
+    fn prove(&self, key: &[u8]) -> Option<Vec<u8>>;
+}
+
+/// Provides the state of the host chain which is not stored in its IBC store,
+/// along with the types of the clients it hosts.
+pub trait StoreHost: Sized {
+    /// The store in which the IBC state is stored.
+    type Store: ProvableStore;
+    /// The client state type of the clients hosted on the chain.
+    type ClientState: ClientStateExecution<StoreContext<Self>>;
+    /// The consensus state type of the clients hosted on the chain.
+    type ConsensusState: ConsensusState + Into<Any> + TryFrom<Any, Error = ClientError>;
+    /// The client state type for the host chain.
+    type HostClientState: ClientStateValidation<StoreContext<Self>>;
+    /// The consensus state type for the host chain.
+    type HostConsensusState: ConsensusState;
+    /// The provider of the cryptographic primitives used by the handlers.
+    type CryptoProvider: HostCryptoProvider;
+    /// The errors of the host, surfaced as the
+    /// [`HostError`](HostValidationContext::HostError) of its [`StoreContext`].
+    type HostError: Debug + Display + Send + Sync + 'static;
+
+    /// Returns the current height of the host chain.
+    fn host_height(&self) -> Result<Height, ContextError>;
+
+    /// Returns the current timestamp of the host chain.
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+
+    /// Returns the consensus state of the host chain at the given height.
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError>;
+
+    /// Validates the client state of the host chain stored on the
+    /// counterparty.
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError>;
+
+    /// Returns the prefix under which the IBC store is committed.
+    fn commitment_prefix(&self) -> CommitmentPrefix;
+
+    /// Validates the signer of IBC messages.
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+}
+
+/// Implements the client and host contexts on top of the [`ProvableStore`]
+/// of the host.
+///
+/// The events and logs emitted by the handlers are kept until the host takes
+/// them with [`take_events`](Self::take_events) and
+/// [`take_logs`](Self::take_logs).
+pub struct StoreContext<H: StoreHost> {
+    store: H::Store,
+    host: H,
+    events: Vec<IbcEvent>,
+    logs: Vec<String>,
+}
+
+impl<H: StoreHost> StoreContext<H> {
+    /// Builds a context storing the IBC state of the host `host` in `store`.
+    pub fn new(store: H::Store, host: H) -> Self {
+        Self {
+            store,
+            host,
+            events: Vec::new(),
+            logs: Vec::new(),
+        }
+    }
+
+    /// Returns the underlying store.
+    pub fn store(&self) -> &H::Store {
+        &self.store
+    }
+
+    /// Returns the host.
+    pub fn host(&self) -> &H {
+        &self.host
+    }
+
+    /// Returns the host, e.g. to move it to the next block.
+    pub fn host_mut(&mut self) -> &mut H {
+        &mut self.host
+    }
+
+    /// Returns the commitment root of the IBC store.
+    pub fn root(&self) -> Vec<u8> {
+        self.store.root()
+    }
+
+    /// Returns the proof of the value stored under `path`, or of its absence.
+    pub fn prove(&self, path: &Path) -> Option<Vec<u8>> {
+        self.store.prove(path.to_string().as_bytes())
+    }
+
+    /// Takes the events emitted since they were last taken.
+    pub fn take_events(&mut self) -> Vec<IbcEvent> {
+        core::mem::take(&mut self.events)
+    }
+
+    /// Takes the logs written since they were last taken.
+    pub fn take_logs(&mut self) -> Vec<String> {
+        core::mem::take(&mut self.logs)
+    }
+
+    fn get(&self, key: &impl Display) -> Option<Vec<u8>> {
+        self.store.get(key.to_string().as_bytes())
+    }
+
+    fn set(&mut self, key: &impl Display, value: Vec<u8>) {
+        self.store.set(key.to_string().into_bytes(), value);
+    }
+
+    fn delete(&mut self, key: &impl Display) {
+        self.store.delete(key.to_string().as_bytes());
+    }
+
+    fn get_u64(&self, key: &impl Display) -> Result<Option<u64>, ContextError> {
+        self.get(key)
+            .map(|value| {
+                value
+                    .try_into()
+                    .map(u64::from_be_bytes)
+                    .map_err(|_| invalid_value(key, "not a big-endian u64"))
+            })
+            .transpose()
+    }
+
+    fn set_u64(&mut self, key: &impl Display, value: u64) {
+        self.set(key, value.to_be_bytes().to_vec());
+    }
+
+    fn increase_counter(&mut self, key: &impl Display) -> Result<(), ContextError> {
+        let counter = self.get_u64(key)?.unwrap_or_default();
+        let counter = counter
+            .checked_add(1)
+            .ok_or_else(|| invalid_value(key, "counter overflow"))?;
+
+        self.set_u64(key, counter);
+
+        Ok(())
+    }
+
+    fn get_any<T>(&self, key: &impl Display) -> Result<Option<T>, ContextError>
+    where
+        T: TryFrom<Any, Error = ClientError>,
+    {
+        self.get(key)
+            .map(|value| {
+                let any = Any::decode(value.as_slice())
+                    .map_err(|e| invalid_value(key, &e.to_string()))?;
+
+                Ok(T::try_from(any)?)
+            })
+            .transpose()
+    }
+
+    fn set_any(&mut self, key: &impl Display, value: impl Into<Any>) {
+        self.set(key, value.into().encode_to_vec());
+    }
+
+    /// Returns the paths stored under the given prefix.
+    fn paths(&self, prefix: &str) -> Vec<Path> {
+        self.store
+            .keys(prefix.as_bytes())
+            .into_iter()
+            .filter_map(|key| String::from_utf8(key).ok())
+            .filter_map(|key| Path::from_str(&key).ok())
+            .collect()
+    }
+
+    /// Returns the heights of the stored consensus states of the client, in
+    /// increasing order.
+    fn sorted_consensus_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        let prefix = format!("{}/", full_consensus_state_path(client_id));
+
+        let mut heights = self
+            .paths(&prefix)
+            .into_iter()
+            .filter_map(|path| match path {
+                Path::ClientConsensusState(path) => Some(path),
+                _ => None,
+            })
+            .map(|path| Height::new(path.revision_number, path.revision_height))
+            .collect::<Result<Vec<_>, _>>()?;
+        heights.sort();
+
+        Ok(heights)
+    }
+}
+
+fn invalid_value(key: &impl Display, description: &str) -> ContextError {
+    ClientError::Other {
+        description: format!("invalid value stored under {key}: {description}"),
+    }
+    .into()
+}
+
+impl<H: StoreHost> ClientValidationContext for StoreContext<H> {
+    type ClientStateRef = H::ClientState;
+    type ConsensusStateRef = H::ConsensusState;
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, ContextError> {
+        self.get_any(&ClientStatePath::new(client_id.clone()))?
+            .ok_or_else(|| {
+                ClientError::ClientStateNotFound {
+                    client_id: client_id.clone(),
+                }
+                .into()
+            })
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::ConsensusStateRef, ContextError> {
+        let height = Height::new(
+            client_cons_state_path.revision_number,
+            client_cons_state_path.revision_height,
+        )?;
+
+        self.get_any(client_cons_state_path)?.ok_or_else(|| {
+            ClientError::ConsensusStateNotFound {
+                client_id: client_cons_state_path.client_id.clone(),
+                height,
+            }
+            .into()
+        })
+    }
+
+    fn client_update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), ContextError> {
+        let not_found = || ClientError::UpdateMetaDataNotFound {
+            client_id: client_id.clone(),
+            height: *height,
+        };
+
+        let time_path = ClientUpdateTimePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        let time = self.get_u64(&time_path)?.ok_or_else(not_found)?;
+        let time = Timestamp::from_nanoseconds(time)
+            .map_err(|e| invalid_value(&time_path, &e.to_string()))?;
+
+        let height_path = ClientUpdateHeightPath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        let processed_height = self.get(&height_path).ok_or_else(not_found)?;
+        let processed_height = String::from_utf8(processed_height)
+            .ok()
+            .and_then(|height| Height::from_str(&height).ok())
+            .ok_or_else(|| invalid_value(&height_path, "not a height"))?;
+
+        Ok((time, processed_height))
+    }
+
+    fn update_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        let prefix = format!("{}/", full_consensus_state_path(client_id));
+
+        let mut heights = self
+            .paths(&prefix)
+            .into_iter()
+            .filter_map(|path| match path {
+                Path::ClientUpdateTime(path) => Some(path),
+                _ => None,
+            })
+            .map(|path| Height::new(path.revision_number, path.revision_height))
+            .collect::<Result<Vec<_>, _>>()?;
+        heights.sort();
+
+        Ok(heights)
+    }
+}
+
+impl<H: StoreHost> ClientExecutionContext for StoreContext<H> {
+    type ClientStateMut = H::ClientState;
+
+    fn store_client_state(
+        &mut self,
+        client_state_path: ClientStatePath,
+        client_state: Self::ClientStateRef,
+    ) -> Result<(), ContextError> {
+        self.set_any(&client_state_path, client_state);
+
+        Ok(())
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::ConsensusStateRef,
+    ) -> Result<(), ContextError> {
+        self.set_any(&consensus_state_path, consensus_state);
+
+        Ok(())
+    }
+
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        self.delete(&consensus_state_path);
+
+        Ok(())
+    }
+
+    fn store_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) -> Result<(), ContextError> {
+        self.set_u64(
+            &ClientUpdateTimePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            host_timestamp.nanoseconds(),
+        );
+        self.set(
+            &ClientUpdateHeightPath::new(
+                client_id,
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            host_height.to_string().into_bytes(),
+        );
+
+        Ok(())
+    }
+
+    fn delete_update_time(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.delete(&ClientUpdateTimePath::new(
+            client_id,
+            height.revision_number(),
+            height.revision_height(),
+        ));
+
+        Ok(())
+    }
+
+    fn delete_update_height(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.delete(&ClientUpdateHeightPath::new(
+            client_id,
+            height.revision_number(),
+            height.revision_height(),
+        ));
+
+        Ok(())
+    }
+}
+
+impl<H: StoreHost> ExtClientValidationContext for StoreContext<H> {
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.host.host_timestamp()
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.host.host_height()
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        self.sorted_consensus_heights(client_id)
+    }
+
+    fn next_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
+        self.sorted_consensus_heights(client_id)?
+            .into_iter()
+            .find(|h| h > height)
+            .map(|h| {
+                self.consensus_state(&ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    h.revision_number(),
+                    h.revision_height(),
+                ))
+            })
+            .transpose()
+    }
+
+    fn prev_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
+        self.sorted_consensus_heights(client_id)?
+            .into_iter()
+            .rev()
+            .find(|h| h < height)
+            .map(|h| {
+                self.consensus_state(&ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    h.revision_number(),
+                    h.revision_height(),
+                ))
+            })
+            .transpose()
+    }
+}
+
+impl<H: StoreHost> HostValidationContext for StoreContext<H> {
+    type V = Self;
+
+    type CryptoProvider = H::CryptoProvider;
+
+    type HostError = H::HostError;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.host.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.host.host_timestamp()
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        let params: ConnectionParams = self
+            .get(&CONNECTION_PARAMS_KEY)
+            .and_then(|value| RawConnectionParams::decode(value.as_slice()).ok())
+            .and_then(|raw| ConnectionParams::try_from(raw).ok())
+            .unwrap_or_default();
+
+        params.max_expected_time_per_block
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.host.validate_message_signer(signer)
+    }
+
+    /// Only the latest height of the store can be proven, as it keeps no
+    /// history.
+    fn query_with_proof(&self, path: &Path, height: Height) -> Result<ProvenValue, ContextError> {
+        let host_height = self.host.host_height()?;
+
+        if height != host_height {
+            return Err(ProofQueryError::HeightNotRetained {
+                path: path.clone(),
+                height,
+                latest_height: host_height,
+            }
+            .into());
+        }
+
+        let proof = self
+            .prove(path)
+            .ok_or_else(|| ProofQueryError::MissingProof { path: path.clone() })
+            .and_then(|proof| {
+                CommitmentProofBytes::try_from(proof).map_err(|error| {
+                    ProofQueryError::InvalidProof {
+                        path: path.clone(),
+                        error,
+                    }
+                })
+            })?;
+
+        Ok(ProvenValue {
+            value: self.get(path),
+            proof,
+            proof_height: host_height,
+        })
+    }
+}
+
+impl<H: StoreHost> ClientRegistryValidationContext for StoreContext<H> {
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        Ok(self.get_u64(&NextClientSequencePath)?.unwrap_or_default())
+    }
+
+    fn client_params(&self) -> Result<ClientParams, ContextError> {
+        self.get(&CLIENT_PARAMS_KEY)
+            .map(|value| {
+                <ClientParams as Protobuf<RawClientParams>>::decode_vec(&value)
+                    .map_err(|e| invalid_value(&CLIENT_PARAMS_KEY, &e.to_string()))
+            })
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+}
+
+impl<H: StoreHost> ConnectionEndValidationContext for StoreContext<H> {
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        let path = ConnectionPath::new(conn_id);
+
+        let value = self
+            .get(&path)
+            .ok_or_else(|| ConnectionError::ConnectionNotFound {
+                connection_id: conn_id.clone(),
+            })?;
+
+        Protobuf::<RawConnectionEnd>::decode_vec(&value).map_err(|e| {
+            ConnectionError::Other {
+                description: format!("invalid connection end stored under {path}: {e}"),
+            }
+            .into()
+        })
+    }
+}
+
+impl<H: StoreHost> ConnectionValidationContext for StoreContext<H> {
+    type HostClientState = H::HostClientState;
+
+    type HostConsensusState = H::HostConsensusState;
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        self.host.host_consensus_state(height)
+    }
+
+    fn connections_for_client(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<ConnectionId>, ContextError> {
+        let path = ClientConnectionPath::new(client_id.clone());
+
+        let Some(value) = self.get(&path) else {
+            return Ok(Vec::new());
+        };
+
+        RawClientPaths::decode(value.as_slice())
+            .map_err(|e| invalid_value(&path, &e.to_string()))?
+            .paths
+            .iter()
+            .map(|conn_id| {
+                ConnectionId::from_str(conn_id).map_err(|e| invalid_value(&path, &e.to_string()))
+            })
+            .collect()
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        self.host
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.host.commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        Ok(self
+            .get_u64(&NextConnectionSequencePath)?
+            .unwrap_or_default())
+    }
+}
+
+impl<H: StoreHost> ChannelValidationContext for StoreContext<H> {
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        let value = self
+            .get(channel_end_path)
+            .ok_or_else(|| ChannelError::ChannelNotFound {
+                port_id: channel_end_path.0.clone(),
+                channel_id: channel_end_path.1.clone(),
+            })?;
+
+        Protobuf::<RawChannel>::decode_vec(&value).map_err(|e| {
+            ChannelError::Other {
+                description: format!("invalid channel end stored under {channel_end_path}: {e}"),
+            }
+            .into()
+        })
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        Ok(self.get_u64(&NextChannelSequencePath)?.unwrap_or_default())
+    }
+}
+
+impl<H: StoreHost> PacketValidationContext for StoreContext<H> {
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        self.get_u64(seq_send_path)?
+            .map(Sequence::from)
+            .ok_or_else(|| {
+                PacketError::MissingNextSendSeq {
+                    port_id: seq_send_path.0.clone(),
+                    channel_id: seq_send_path.1.clone(),
+                }
+                .into()
+            })
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.get_u64(seq_recv_path)?
+            .map(Sequence::from)
+            .ok_or_else(|| {
+                PacketError::MissingNextRecvSeq {
+                    port_id: seq_recv_path.0.clone(),
+                    channel_id: seq_recv_path.1.clone(),
+                }
+                .into()
+            })
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        self.get_u64(seq_ack_path)?
+            .map(Sequence::from)
+            .ok_or_else(|| {
+                PacketError::MissingNextAckSeq {
+                    port_id: seq_ack_path.0.clone(),
+                    channel_id: seq_ack_path.1.clone(),
+                }
+                .into()
+            })
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        self.get(commitment_path)
+            .map(PacketCommitment::from)
+            .ok_or_else(|| {
+                PacketError::PacketCommitmentNotFound {
+                    sequence: commitment_path.sequence,
+                }
+                .into()
+            })
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.get(receipt_path).map(|_| Receipt::Ok).ok_or_else(|| {
+            PacketError::PacketReceiptNotFound {
+                sequence: receipt_path.sequence,
+            }
+            .into()
+        })
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.get(ack_path)
+            .map(AcknowledgementCommitment::from)
+            .ok_or_else(|| {
+                PacketError::PacketAcknowledgementNotFound {
+                    sequence: ack_path.sequence,
+                }
+                .into()
+            })
+    }
+
+    fn get_pruning_sequence_start(
+        &self,
+        pruning_seq_start_path: &PruningSeqStartPath,
+    ) -> Result<Option<Sequence>, ContextError> {
+        Ok(self.get_u64(pruning_seq_start_path)?.map(Sequence::from))
+    }
+
+    fn get_pruning_sequence_end(
+        &self,
+        pruning_seq_end_path: &PruningSeqEndPath,
+    ) -> Result<Option<Sequence>, ContextError> {
+        Ok(self.get_u64(pruning_seq_end_path)?.map(Sequence::from))
+    }
+}
+
+impl<H: StoreHost> HostExecutionContext for StoreContext<H> {
+    type E = Self;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.events.push(event);
+
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.logs.push(message);
+
+        Ok(())
+    }
+
+    fn emitted_ibc_events(&self) -> &[IbcEvent] {
+        &self.events
+    }
+
+    fn logged_messages(&self) -> &[String] {
+        &self.logs
+    }
+}
+
+impl<H: StoreHost> ClientRegistryExecutionContext for StoreContext<H> {
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        self.increase_counter(&NextClientSequencePath)
+    }
+
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        self.set(
+            &CLIENT_PARAMS_KEY,
+            Protobuf::<RawClientParams>::encode_vec(params),
+        );
+
+        Ok(())
+    }
+}
+
+impl<H: StoreHost> ConnectionExecutionContext for StoreContext<H> {
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        self.set(
+            connection_path,
+            Protobuf::<RawConnectionEnd>::encode_vec(connection_end),
+        );
+
+        Ok(())
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        let mut conn_ids = self.connections_for_client(&client_connection_path.0)?;
+        conn_ids.push(conn_id);
+
+        let paths = RawClientPaths {
+            paths: conn_ids.iter().map(ToString::to_string).collect(),
+        };
+        self.set(client_connection_path, paths.encode_to_vec());
+
+        Ok(())
+    }
+
+    fn store_connection_params(&mut self, params: ConnectionParams) -> Result<(), ContextError> {
+        let raw = RawConnectionParams::try_from(params)?;
+        self.set(&CONNECTION_PARAMS_KEY, raw.encode_to_vec());
+
+        Ok(())
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        self.increase_counter(&NextConnectionSequencePath)
+    }
+}
+
+impl<H: StoreHost> ChannelExecutionContext for StoreContext<H> {
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.set(
+            channel_end_path,
+            Protobuf::<RawChannel>::encode_vec(channel_end),
+        );
+
+        Ok(())
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        self.increase_counter(&NextChannelSequencePath)
+    }
+}
+
+impl<H: StoreHost> PacketExecutionContext for StoreContext<H> {
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.set(commitment_path, commitment.into_vec());
+
+        Ok(())
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.delete(commitment_path);
+
+        Ok(())
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        _receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        // As in ibc-go, receipts are stored as a single `1` byte.
+        self.set(receipt_path, vec![1]);
+
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.set(ack_path, ack_commitment.into_vec());
+
+        Ok(())
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.delete(ack_path);
+
+        Ok(())
+    }
+
+    fn delete_packet_receipt(&mut self, receipt_path: &ReceiptPath) -> Result<(), ContextError> {
+        self.delete(receipt_path);
+
+        Ok(())
+    }
+
+    fn store_pruning_sequence_start(
+        &mut self,
+        pruning_seq_start_path: &PruningSeqStartPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.set_u64(pruning_seq_start_path, seq.value());
+
+        Ok(())
+    }
+
+    fn delete_packet_acknowledgements(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<(), ContextError> {
+        let ChannelEndPath(port_id, channel_id) = channel_end_path;
+        let prefix = format!(
+            "{PACKET_ACK_PREFIX}/{PORT_PREFIX}/{port_id}/{CHANNEL_PREFIX}/{channel_id}/{SEQUENCE_PREFIX}/"
+        );
+
+        for path in self.paths(&prefix) {
+            if let Path::Ack(ack_path) = path {
+                self.delete(&ack_path);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.set_u64(seq_send_path, seq.value());
+
+        Ok(())
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.set_u64(seq_recv_path, seq.value());
+
+        Ok(())
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.set_u64(seq_ack_path, seq.value());
+
+        Ok(())
+    }
+
+    fn delete_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<(), ContextError> {
+        self.delete(seq_send_path);
+
+        Ok(())
+    }
+
+    fn delete_next_sequence_ack(&mut self, seq_ack_path: &SeqAckPath) -> Result<(), ContextError> {
+        self.delete(seq_ack_path);
+
+        Ok(())
+    }
+}
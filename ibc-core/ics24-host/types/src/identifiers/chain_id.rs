@@ -12,9 +12,20 @@ use crate::validate::{
 
 /// Defines the domain type for chain identifiers.
 ///
-/// A valid `ChainId` follows the format {chain name}-{revision number} where
-/// the revision number indicates how many times the chain has been upgraded.
-/// Creating `ChainId`s not in this format will result in an error.
+/// A `ChainId` is in epoch format if it follows the format
+/// {chain name}-{revision number}, where the revision number indicates how
+/// many times the chain has been upgraded. The revision number is extracted
+/// from the identifier by the following rules:
+///
+/// - the identifier is split at its last `-`,
+/// - the part after it must be a decimal `u64` without leading zeros, `0`
+///   itself aside,
+/// - the chain name before it must not be empty.
+///
+/// Identifiers not following them, such as `chainA` or `chainA-01`, are not in
+/// epoch format and have a revision number of 0. So do the identifiers of
+/// chains that end with a number which is not a revision number, when created
+/// with [`ChainId::new_without_revision`].
 ///
 /// It should be noted this format is not standardized yet, though it is widely
 /// accepted and compatible with Cosmos SDK driven chains.
@@ -63,13 +74,82 @@ impl ChainId {
         Self::from_str(chain_id)
     }
 
+    /// Creates a new `ChainId`, accepting any chain identifier with valid
+    /// characters and a length of at most 64 characters.
+    ///
+    /// Unlike [`ChainId::new`], it does not require the chain name of
+    /// identifiers in epoch format to leave room for their revision number to
+    /// grow. It is meant for parsing identifiers chosen by other chains.
+    ///
+    /// ```
+    /// use ibc_core_host_types::identifiers::ChainId;
+    ///
+    /// let chain_id = format!("{}-123", "A".repeat(44));
+    /// assert!(ChainId::new(&chain_id).is_err());
+    /// let id = ChainId::new_lenient(&chain_id).unwrap();
+    /// assert_eq!(id.revision_number(), 123);
+    ///
+    /// let id = ChainId::new_lenient("-1").unwrap();
+    /// assert_eq!(id.revision_number(), 0);
+    /// ```
+    pub fn new_lenient(chain_id: &str) -> Result<Self, IdentifierError> {
+        validate_identifier_chars(chain_id)?;
+        validate_identifier_length(chain_id, 1, 64)?;
+
+        let revision_number = parse_chain_id_string(chain_id)
+            .ok()
+            .filter(|(chain_name, _)| !chain_name.is_empty())
+            .map(|(_, revision_number)| revision_number)
+            .unwrap_or(0);
+
+        Ok(Self {
+            id: chain_id.into(),
+            revision_number,
+        })
+    }
+
+    /// Creates a new `ChainId` not in epoch format, whose revision number is
+    /// always 0, for chains whose identifier does not carry a revision number
+    /// even if it ends with one, like `ethereum-1`.
+    ///
+    /// ```
+    /// use ibc_core_host_types::identifiers::ChainId;
+    ///
+    /// let mut id = ChainId::new_without_revision("ethereum-1").unwrap();
+    /// assert_eq!(id.revision_number(), 0);
+    /// assert!(!id.is_epoch_format());
+    /// assert!(id.increment_revision_number().is_err());
+    /// ```
+    pub fn new_without_revision(chain_id: &str) -> Result<Self, IdentifierError> {
+        validate_identifier_chars(chain_id)?;
+        validate_identifier_length(chain_id, 1, 64)?;
+
+        Ok(Self {
+            id: chain_id.into(),
+            revision_number: 0,
+        })
+    }
+
     /// Get a reference to the underlying string.
     pub fn as_str(&self) -> &str {
         &self.id
     }
 
+    /// Splits the chain identifier into its chain name and revision number.
+    /// Fails if the chain identifier is not in epoch format.
     pub fn split_chain_id(&self) -> Result<(&str, u64), IdentifierError> {
         parse_chain_id_string(self.as_str())
+            .ok()
+            .filter(|(_, revision_number)| *revision_number == self.revision_number)
+            .ok_or(IdentifierError::UnformattedRevisionNumber {
+                chain_id: self.id.clone(),
+            })
+    }
+
+    /// Returns whether the chain identifier is in the
+    /// `{chain_name}-{revision_number}` format.
+    pub fn is_epoch_format(&self) -> bool {
+        self.split_chain_id().is_ok()
     }
 
     /// Extract the revision number from the chain identifier
@@ -297,6 +377,8 @@ impl Display for ChainId {
 
 /// Parses a string intended to represent a `ChainId` and, if successful,
 /// returns a tuple containing the chain name and revision number.
+///
+/// See [`ChainId`] for the rules followed by the parsing.
 fn parse_chain_id_string(chain_id_str: &str) -> Result<(&str, u64), IdentifierError> {
     chain_id_str
         .rsplit_once('-')
@@ -389,6 +471,45 @@ mod tests {
         assert!(ChainId::new(chain_id_str).is_err());
     }
 
+    #[rstest]
+    #[case("evmos_9001-2", 2)]
+    #[case(&("A".repeat(44) + "-123"), 123)]
+    #[case("-1", 0)]
+    #[case("chainA-01", 0)]
+    #[case("chainA", 0)]
+    fn test_valid_lenient_chain_id(#[case] raw_chain_id: &str, #[case] revision_number: u64) {
+        let chain_id = ChainId::new_lenient(raw_chain_id).unwrap();
+        assert_eq!(chain_id.as_str(), raw_chain_id);
+        assert_eq!(chain_id.revision_number(), revision_number);
+    }
+
+    #[rstest]
+    #[case(&"A".repeat(65))]
+    #[case(" chainA-1")]
+    #[case("/chainA-1")]
+    fn test_invalid_lenient_chain_id(#[case] raw_chain_id: &str) {
+        assert!(ChainId::new_lenient(raw_chain_id).is_err());
+    }
+
+    #[test]
+    fn test_chain_id_without_revision() {
+        let chain_id = ChainId::new_without_revision("ethereum-1").unwrap();
+        assert_eq!(chain_id.as_str(), "ethereum-1");
+        assert_eq!(chain_id.revision_number(), 0);
+        assert!(!chain_id.is_epoch_format());
+        assert!(chain_id.split_chain_id().is_err());
+        assert_ne!(chain_id, ChainId::new("ethereum-1").unwrap());
+
+        assert!(ChainId::new("ethereum-1").unwrap().is_epoch_format());
+        assert!(!ChainId::new("ethereum").unwrap().is_epoch_format());
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_chain_id_without_revision_borsh_roundtrip() {
+        borsh_ser_de_roundtrip(ChainId::new_without_revision("ethereum-1").unwrap());
+    }
+
     #[test]
     fn test_inc_revision_number() {
         let mut chain_id = ChainId::new("chainA-1").unwrap();
@@ -17,5 +17,7 @@ extern crate std;
 
 pub mod error;
 pub mod identifiers;
+pub mod limits;
 pub mod path;
-pub(crate) mod validate;
+pub mod validate;
+pub mod write_set;
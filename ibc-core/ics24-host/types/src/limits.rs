@@ -0,0 +1,77 @@
+//! Defines the limits a host can put on the number of IBC objects created on
+//! it and on the size of the packet payloads it handles.
+
+use ibc_primitives::prelude::*;
+
+/// Caps on the number of clients, connections and channels that can be
+/// created on the host, and on the size of the packet payloads it handles.
+///
+/// On permissionless chains, anyone can create clients, connections and
+/// channels, which can be abused to exhaust the identifier space or bloat the
+/// store. Each cap is enforced upon creation of the corresponding object, and
+/// `None` leaves the object count unbounded.
+///
+/// Likewise, packet data and acknowledgements are sized by the sending
+/// application or the counterparty chain. Their caps are enforced when packets
+/// are sent, received and acknowledged, and `None` leaves their size
+/// unbounded.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HostLimits {
+    /// The maximum number of clients that can ever be created on the host.
+    pub max_clients: Option<u64>,
+    /// The maximum number of connections that can be opened on top of a
+    /// single client.
+    pub max_connections_per_client: Option<u64>,
+    /// The maximum number of channels that can be opened on top of a single
+    /// connection.
+    pub max_channels_per_connection: Option<u64>,
+    /// The maximum size in bytes of the data of a packet sent or received on
+    /// the host.
+    pub max_packet_data_size: Option<u64>,
+    /// The maximum size in bytes of an acknowledgement relayed to the host.
+    pub max_ack_size: Option<u64>,
+}
+
+impl HostLimits {
+    /// Returns the limits leaving all object counts unbounded.
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_clients(self, max_clients: u64) -> Self {
+        Self {
+            max_clients: Some(max_clients),
+            ..self
+        }
+    }
+
+    pub fn with_max_connections_per_client(self, max_connections_per_client: u64) -> Self {
+        Self {
+            max_connections_per_client: Some(max_connections_per_client),
+            ..self
+        }
+    }
+
+    pub fn with_max_channels_per_connection(self, max_channels_per_connection: u64) -> Self {
+        Self {
+            max_channels_per_connection: Some(max_channels_per_connection),
+            ..self
+        }
+    }
+
+    pub fn with_max_packet_data_size(self, max_packet_data_size: u64) -> Self {
+        Self {
+            max_packet_data_size: Some(max_packet_data_size),
+            ..self
+        }
+    }
+
+    pub fn with_max_ack_size(self, max_ack_size: u64) -> Self {
+        Self {
+            max_ack_size: Some(max_ack_size),
+            ..self
+        }
+    }
+}
@@ -26,6 +26,8 @@ pub const SEQUENCE_PREFIX: &str = "sequences";
 pub const NEXT_SEQ_SEND_PREFIX: &str = "nextSequenceSend";
 pub const NEXT_SEQ_RECV_PREFIX: &str = "nextSequenceRecv";
 pub const NEXT_SEQ_ACK_PREFIX: &str = "nextSequenceAck";
+pub const PRUNING_SEQ_START_PREFIX: &str = "pruningSequenceStart";
+pub const PRUNING_SEQ_END_PREFIX: &str = "pruningSequenceEnd";
 pub const PACKET_COMMITMENT_PREFIX: &str = "commitments";
 pub const PACKET_ACK_PREFIX: &str = "acks";
 pub const PACKET_RECEIPT_PREFIX: &str = "receipts";
@@ -59,6 +61,8 @@ pub enum Path {
     SeqSend(SeqSendPath),
     SeqRecv(SeqRecvPath),
     SeqAck(SeqAckPath),
+    PruningSeqStart(PruningSeqStartPath),
+    PruningSeqEnd(PruningSeqEndPath),
     Commitment(CommitmentPath),
     Ack(AckPath),
     Receipt(ReceiptPath),
@@ -526,6 +530,56 @@ impl SeqAckPath {
     }
 }
 
+/// The path to the first sequence whose acknowledgement and receipt are yet to
+/// be pruned on an upgraded channel.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{PRUNING_SEQ_START_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
+pub struct PruningSeqStartPath(pub PortId, pub ChannelId);
+
+impl PruningSeqStartPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> PruningSeqStartPath {
+        PruningSeqStartPath(port_id.clone(), channel_id.clone())
+    }
+}
+
+/// The path to the sequence, exclusive, up to which the acknowledgements and
+/// receipts of an upgraded channel can be pruned.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{PRUNING_SEQ_END_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
+pub struct PruningSeqEndPath(pub PortId, pub ChannelId);
+
+impl PruningSeqEndPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> PruningSeqEndPath {
+        PruningSeqEndPath(port_id.clone(), channel_id.clone())
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -730,6 +784,66 @@ impl FromStr for Path {
     }
 }
 
+impl TryFrom<&str> for Path {
+    type Error = PathError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Implements `FromStr` and `TryFrom<&str>` for each path type, by parsing the
+/// string into a [`Path`] of the variant wrapping it.
+macro_rules! impl_path_from_str {
+    ($($variant:ident => $path:ty),* $(,)?) => {
+        $(
+            impl FromStr for $path {
+                type Err = PathError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s.parse()? {
+                        Path::$variant(path) => Ok(path),
+                        _ => Err(PathError::ParseFailure {
+                            path: s.to_string(),
+                        }),
+                    }
+                }
+            }
+
+            impl TryFrom<&str> for $path {
+                type Error = PathError;
+
+                fn try_from(s: &str) -> Result<Self, Self::Error> {
+                    s.parse()
+                }
+            }
+        )*
+    };
+}
+
+impl_path_from_str!(
+    NextClientSequence => NextClientSequencePath,
+    NextConnectionSequence => NextConnectionSequencePath,
+    NextChannelSequence => NextChannelSequencePath,
+    ClientState => ClientStatePath,
+    ClientConsensusState => ClientConsensusStatePath,
+    ClientUpdateTime => ClientUpdateTimePath,
+    ClientUpdateHeight => ClientUpdateHeightPath,
+    ClientConnection => ClientConnectionPath,
+    Connection => ConnectionPath,
+    Ports => PortPath,
+    ChannelEnd => ChannelEndPath,
+    SeqSend => SeqSendPath,
+    SeqRecv => SeqRecvPath,
+    SeqAck => SeqAckPath,
+    PruningSeqStart => PruningSeqStartPath,
+    PruningSeqEnd => PruningSeqEndPath,
+    Commitment => CommitmentPath,
+    Ack => AckPath,
+    Receipt => ReceiptPath,
+    UpgradeClient => UpgradeClientPath,
+);
+
 fn parse_next_sequence(components: &[&str]) -> Option<Path> {
     if components.len() != 1 {
         return None;
@@ -932,6 +1046,8 @@ fn parse_seqs(components: &[&str]) -> Option<Path> {
         NEXT_SEQ_SEND_PREFIX => Some(SeqSendPath(port_id, channel_id).into()),
         NEXT_SEQ_RECV_PREFIX => Some(SeqRecvPath(port_id, channel_id).into()),
         NEXT_SEQ_ACK_PREFIX => Some(SeqAckPath(port_id, channel_id).into()),
+        PRUNING_SEQ_START_PREFIX => Some(PruningSeqStartPath(port_id, channel_id).into()),
+        PRUNING_SEQ_END_PREFIX => Some(PruningSeqEndPath(port_id, channel_id).into()),
         _ => None,
     }
 }
@@ -1144,6 +1260,14 @@ mod tests {
         "nextSequenceAck/ports/transfer/channels/channel-0",
         Path::SeqAck(SeqAckPath(PortId::transfer(), ChannelId::zero()))
     )]
+    #[case(
+        "pruningSequenceStart/ports/transfer/channels/channel-0",
+        Path::PruningSeqStart(PruningSeqStartPath(PortId::transfer(), ChannelId::zero()))
+    )]
+    #[case(
+        "pruningSequenceEnd/ports/transfer/channels/channel-0",
+        Path::PruningSeqEnd(PruningSeqEndPath(PortId::transfer(), ChannelId::zero()))
+    )]
     #[case(
         "commitments/ports/transfer/channels/channel-0/sequences/0",
         Path::Commitment(CommitmentPath {
@@ -1192,6 +1316,40 @@ mod tests {
         assert!(Path::from_str(path_str).is_err());
     }
 
+    #[test]
+    fn test_path_types_parsing() {
+        let path_str = "clients/07-tendermint-0/clientState";
+        let path = ClientStatePath(ClientId::new_dummy());
+        assert_eq!(ClientStatePath::from_str(path_str).expect("no error"), path);
+        assert_eq!(path_str, path.to_string());
+
+        let path_str = "clients/07-tendermint-0/consensusStates/15-31";
+        let path = ClientConsensusStatePath::new(ClientId::new_dummy(), 15, 31);
+        assert_eq!(
+            path_str
+                .parse::<ClientConsensusStatePath>()
+                .expect("no error"),
+            path
+        );
+
+        let path_str = "acks/ports/transfer/channels/channel-0/sequences/7";
+        let path = AckPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(7));
+        assert_eq!(AckPath::try_from(path_str).expect("no error"), path);
+        assert_eq!(path_str, path.to_string());
+
+        let path_str = "nextSequenceAck/ports/transfer/channels/channel-0";
+        let path = SeqAckPath::new(&PortId::transfer(), &ChannelId::zero());
+        assert_eq!(SeqAckPath::try_from(path_str).expect("no error"), path);
+
+        // a valid path of another type cannot be parsed
+        assert!(SeqSendPath::from_str(path_str).is_err());
+        assert!(
+            ReceiptPath::from_str("acks/ports/transfer/channels/channel-0/sequences/7").is_err()
+        );
+        // neither can an invalid path
+        assert!(ConnectionPath::from_str("connections").is_err());
+    }
+
     #[test]
     fn test_parse_client_paths_fn() {
         let path = "clients/07-tendermint-0/clientState";
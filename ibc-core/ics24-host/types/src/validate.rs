@@ -1,21 +1,55 @@
+//! Validation of the identifiers of the IBC entities, as specified in the
+//! [`ICS-24`](https://github.com/cosmos/ibc/tree/main/spec/core/ics-024-host-requirements#paths-identifiers-separators)
+//! spec.
+//!
+//! The `is_valid_*` functions are allocation-free, so that constrained
+//! environments can check identifiers without building their errors.
+
 use ibc_primitives::prelude::*;
 
 use crate::error::IdentifierError as Error;
 
 const VALID_SPECIAL_CHARS: &str = "._+-#[]<>";
 
+/// Minimum length of client identifiers.
+pub const CLIENT_ID_MIN_LENGTH: u64 = 9;
+/// Maximum length of client identifiers.
+pub const CLIENT_ID_MAX_LENGTH: u64 = 64;
+/// Minimum length of connection identifiers.
+pub const CONNECTION_ID_MIN_LENGTH: u64 = 10;
+/// Maximum length of connection identifiers.
+pub const CONNECTION_ID_MAX_LENGTH: u64 = 64;
+/// Minimum length of port identifiers.
+pub const PORT_ID_MIN_LENGTH: u64 = 2;
+/// Maximum length of port identifiers.
+pub const PORT_ID_MAX_LENGTH: u64 = 128;
+/// Minimum length of channel identifiers.
+pub const CHANNEL_ID_MIN_LENGTH: u64 = 8;
+/// Maximum length of channel identifiers.
+pub const CHANNEL_ID_MAX_LENGTH: u64 = 64;
+
+/// Returns whether the identifier only contains valid characters:
+/// - Alphanumeric
+/// - `.`, `_`, `+`, `-`, `#`
+/// - `[`, `]`, `<`, `>`
+pub fn is_valid_identifier_chars(id: &str) -> bool {
+    id.chars()
+        .all(|c| c.is_alphanumeric() || VALID_SPECIAL_CHARS.contains(c))
+}
+
+/// Returns whether the length of the identifier lies between the given
+/// min/max lengths. Empty identifiers are always rejected.
+pub fn is_valid_identifier_length(id: &str, min: u64, max: u64) -> bool {
+    // Make sure min is at least one so we reject empty identifiers.
+    let min = min.max(1);
+    (min..=max).contains(&(id.len() as u64))
+}
+
 /// Checks if the identifier only contains valid characters as specified in the
 /// [`ICS-24`](https://github.com/cosmos/ibc/tree/main/spec/core/ics-024-host-requirements#paths-identifiers-separators)]
 /// spec.
 pub fn validate_identifier_chars(id: &str) -> Result<(), Error> {
-    // Check that the identifier comprises only valid characters:
-    // - Alphanumeric
-    // - `.`, `_`, `+`, `-`, `#`
-    // - `[`, `]`, `<`, `>`
-    if !id
-        .chars()
-        .all(|c| c.is_alphanumeric() || VALID_SPECIAL_CHARS.contains(c))
-    {
+    if !is_valid_identifier_chars(id) {
         return Err(Error::InvalidCharacter { id: id.into() });
     }
 
@@ -27,15 +61,12 @@ pub fn validate_identifier_chars(id: &str) -> Result<(), Error> {
 /// [`ICS-24`](https://github.com/cosmos/ibc/tree/main/spec/core/ics-024-host-requirements#paths-identifiers-separators)]
 /// spec.
 pub fn validate_identifier_length(id: &str, min: u64, max: u64) -> Result<(), Error> {
-    // Make sure min is at least one so we reject empty identifiers.
-    let min = min.max(1);
-    let length = id.len() as u64;
-    if (min..=max).contains(&length) {
+    if is_valid_identifier_length(id, min, max) {
         Ok(())
     } else {
         Err(Error::InvalidLength {
             id: id.into(),
-            min,
+            min: min.max(1),
             max,
         })
     }
@@ -64,7 +95,7 @@ pub fn validate_prefix_length(
 /// Default validator function for the Client types.
 pub fn validate_client_type(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_prefix_length(id, 9, 64)
+    validate_prefix_length(id, CLIENT_ID_MIN_LENGTH, CLIENT_ID_MAX_LENGTH)
 }
 
 /// Default validator function for Client identifiers.
@@ -73,7 +104,7 @@ pub fn validate_client_type(id: &str) -> Result<(), Error> {
 /// the ICS-24 spec.
 pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_identifier_length(id, 9, 64)
+    validate_identifier_length(id, CLIENT_ID_MIN_LENGTH, CLIENT_ID_MAX_LENGTH)
 }
 
 /// Default validator function for Connection identifiers.
@@ -82,7 +113,7 @@ pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
 /// in the ICS-24 spec.
 pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_identifier_length(id, 10, 64)
+    validate_identifier_length(id, CONNECTION_ID_MIN_LENGTH, CONNECTION_ID_MAX_LENGTH)
 }
 
 /// Default validator function for Port identifiers.
@@ -91,7 +122,7 @@ pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
 /// ICS-24 spec.
 pub fn validate_port_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_identifier_length(id, 2, 128)
+    validate_identifier_length(id, PORT_ID_MIN_LENGTH, PORT_ID_MAX_LENGTH)
 }
 
 /// Default validator function for Channel identifiers.
@@ -100,7 +131,7 @@ pub fn validate_port_identifier(id: &str) -> Result<(), Error> {
 /// the ICS-24 spec.
 pub fn validate_channel_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_identifier_length(id, 8, 64)
+    validate_identifier_length(id, CHANNEL_ID_MIN_LENGTH, CHANNEL_ID_MAX_LENGTH)
 }
 
 #[cfg(test)]
@@ -0,0 +1,70 @@
+//! Defines the write set, which records the store writes performed while
+//! executing IBC messages.
+
+use ibc_primitives::prelude::*;
+
+use crate::path::Path;
+
+/// A single store write: the path along with the bytes written under it, or
+/// `None` if the path was deleted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StoreWrite {
+    pub path: Path,
+    pub value: Option<Vec<u8>>,
+}
+
+/// The ordered list of store writes performed while executing IBC messages.
+///
+/// Values are encoded the way `ibc-go` commits them, allowing hosts to feed
+/// their commitment machinery, and tests to compare the writes byte for byte
+/// against `ibc-go` state dumps.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WriteSet(Vec<StoreWrite>);
+
+impl WriteSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that `value` is written under `path`.
+    pub fn set(&mut self, path: impl Into<Path>, value: Vec<u8>) {
+        self.0.push(StoreWrite {
+            path: path.into(),
+            value: Some(value),
+        });
+    }
+
+    /// Records that `path` is deleted.
+    pub fn delete(&mut self, path: impl Into<Path>) {
+        self.0.push(StoreWrite {
+            path: path.into(),
+            value: None,
+        });
+    }
+
+    pub fn writes(&self) -> &[StoreWrite] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Takes out all the writes recorded so far, leaving the write set empty.
+    pub fn take(&mut self) -> Self {
+        core::mem::take(self)
+    }
+}
+
+impl IntoIterator for WriteSet {
+    type Item = StoreWrite;
+    type IntoIter = <Vec<StoreWrite> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
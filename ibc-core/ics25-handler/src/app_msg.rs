@@ -0,0 +1,31 @@
+//! Lets hosts dispatch the messages of IBC applications through the same
+//! entrypoint as the core ones.
+
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::proto::Any;
+
+/// Routes the messages of IBC applications, such as the `MsgTransfer` of
+/// token transfer, to the modules handling them.
+///
+/// Unlike the callbacks of the modules, which IBC core invokes, application
+/// messages are submitted by users, and are handled against the host context,
+/// e.g. to send packets. Hosts implement this trait on their router to
+/// dispatch any IBC message through [`dispatch_any`].
+///
+/// [`dispatch_any`]: crate::entrypoint::dispatch_any
+pub trait AppMsgRouter {
+    /// Returns whether a module handles the messages of type `type_url`.
+    fn routes_app_msg(&self, type_url: &str) -> bool;
+
+    /// Validates `msg`, whose type is routed by this router.
+    fn validate_app_msg<Ctx>(&self, ctx: &Ctx, msg: Any) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext;
+
+    /// Executes `msg`, which was validated by
+    /// [`validate_app_msg`](Self::validate_app_msg).
+    fn execute_app_msg<Ctx>(&mut self, ctx: &mut Ctx, msg: Any) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext;
+}
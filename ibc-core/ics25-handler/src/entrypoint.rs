@@ -3,30 +3,43 @@ use ibc_core_channel::handler::{
     chan_close_confirm_validate, chan_close_init_execute, chan_close_init_validate,
     chan_open_ack_execute, chan_open_ack_validate, chan_open_confirm_execute,
     chan_open_confirm_validate, chan_open_init_execute, chan_open_init_validate,
-    chan_open_try_execute, chan_open_try_validate, recv_packet_execute, recv_packet_validate,
-    timeout_packet_execute, timeout_packet_validate, TimeoutMsgType,
+    chan_open_try_execute, chan_open_try_validate, prune_acknowledgements_execute,
+    prune_acknowledgements_validate, recv_packet_execute, recv_packet_validate,
+    timeout_batch_execute, timeout_batch_validate, timeout_packet_execute, timeout_packet_validate,
+    TimeoutMsgType,
 };
 use ibc_core_channel::types::msgs::{
-    channel_msg_to_port_id, packet_msg_to_port_id, ChannelMsg, PacketMsg,
+    channel_msg_to_channel_id, channel_msg_to_port_id, packet_msg_to_channel_id,
+    packet_msg_to_port_id, ChannelMsg, PacketMsg,
 };
-use ibc_core_client::handler::{create_client, update_client, upgrade_client};
+use ibc_core_client::handler::{create_client, submit_evidence, update_client, upgrade_client};
 use ibc_core_client::types::msgs::{ClientMsg, MsgUpdateOrMisbehaviour};
 use ibc_core_connection::handler::{
     conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try,
 };
 use ibc_core_connection::types::msgs::ConnectionMsg;
-use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::error::{ContextError, HostError};
+use ibc_core_handler_types::events::SdkMessageEvent;
 use ibc_core_handler_types::msgs::MsgEnvelope;
-use ibc_core_host::{ExecutionContext, ValidationContext};
-use ibc_core_router::router::Router;
+use ibc_core_handler_types::output::{HandlerOutput, SimulationOutput};
+use ibc_core_host::capability::{claim_channel_capability, verify_capability_owner};
+use ibc_core_host::staging::{StagingContext, StagingError};
+use ibc_core_host::types::identifiers::{ChannelId, ClientId};
+use ibc_core_host::{ExecutionContext, HostExecutionContext, ValidationContext};
+use ibc_core_router::router::{Router, RouterView, StagingRouter};
+use ibc_core_router::types::capability::CapabilityName;
 use ibc_core_router::types::error::RouterError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::app_msg::AppMsgRouter;
 
 /// Entrypoint which performs both validation and message execution
 pub fn dispatch<Ctx>(
     ctx: &mut Ctx,
-    router: &mut impl Router,
+    router: &mut (impl Router + ?Sized),
     msg: MsgEnvelope,
-) -> Result<(), ContextError>
+) -> Result<HandlerOutput, ContextError>
 where
     Ctx: ExecutionContext,
 {
@@ -34,15 +47,184 @@ where
     execute(ctx, router, msg)
 }
 
+/// Entrypoint which dispatches any IBC message, be it a core message or the
+/// message of an application routed by `router`, e.g. a `MsgTransfer`
+///
+/// Gives hosts a single integration point for all the IBC messages they
+/// receive encoded as protobuf `Any`s.
+pub fn dispatch_any<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut (impl Router + AppMsgRouter + ?Sized),
+    msg: Any,
+) -> Result<HandlerOutput, ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    if router.routes_app_msg(&msg.type_url) {
+        router.validate_app_msg(ctx, msg.clone())?;
+
+        return record_output(ctx, |ctx| router.execute_app_msg(ctx, msg));
+    }
+
+    dispatch(ctx, router, MsgEnvelope::try_from(msg)?)
+}
+
+/// Entrypoint which atomically dispatches the messages of a transaction
+///
+/// The messages are dispatched in order up to the first failing one, in which
+/// case the writes, events and logs of the whole batch are discarded, like
+/// those of a Cosmos SDK transaction made of several IBC messages. The state
+/// of the modules of `router` is restored along with the context, so that,
+/// e.g., the tokens minted upon receiving a packet of the batch are not kept
+/// while its receipt is discarded.
+///
+/// Returns the results of the dispatched messages: the batch is applied if and
+/// only if none of them is an error, the last one being the error otherwise.
+pub fn dispatch_batch<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut (impl StagingRouter + ?Sized),
+    msgs: Vec<MsgEnvelope>,
+) -> Vec<Result<HandlerOutput, ContextError>>
+where
+    Ctx: StagingContext,
+{
+    let checkpoint = ctx.checkpoint();
+    let router_checkpoint = router.checkpoint();
+    let mut results = Vec::with_capacity(msgs.len());
+
+    for msg in msgs {
+        let result = dispatch(ctx, router, msg);
+        let failed = result.is_err();
+        results.push(result);
+
+        if failed {
+            ctx.restore(checkpoint);
+            router.restore(router_checkpoint);
+            break;
+        }
+    }
+
+    results
+}
+
+/// Entrypoint which simulates the dispatch of a message without committing it
+///
+/// The message is validated, then executed and rolled back as by
+/// [`execute_deferred`], so that the state of the context and of the modules
+/// of `router` is restored to what it was before the simulation. Returns the
+/// events, logs and store writes that dispatching the message would produce,
+/// e.g. for gas estimation or relayer pre-flight checks. The writes are only
+/// reported if the context records them.
+pub fn simulate<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut (impl StagingRouter + ?Sized),
+    msg: MsgEnvelope,
+) -> Result<SimulationOutput, ContextError>
+where
+    Ctx: StagingContext,
+{
+    validate(ctx, router, msg.clone())?;
+    execute_and_restore(ctx, router, msg)
+}
+
+/// Entrypoint which only performs message execution, returning the writes of
+/// the handlers instead of committing them
+///
+/// The message is executed against the context, whose state is then restored
+/// to what it was before the execution, as for a failed batch, along with the
+/// state of the modules of `router`. Hosts staging writes in a copy-on-write
+/// overlay thus never touch their store. Returns the store writes performed
+/// by the handlers, keyed by their typed path, along with the events and logs
+/// they emitted, so that hosts can audit, batch or defer their commit, as
+/// optimistic execution and fraud-proof environments do, by applying them to
+/// their store themselves.
+///
+/// The writes are taken from the write set of the context, which hosts using
+/// this entrypoint must record: the message is not executed otherwise, and
+/// [`StagingError::WriteSetNotRecorded`] is returned as a host error.
+pub fn execute_deferred<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut (impl StagingRouter + ?Sized),
+    msg: MsgEnvelope,
+) -> Result<SimulationOutput, ContextError>
+where
+    Ctx: StagingContext,
+{
+    if ctx.write_set_mut().is_none() {
+        return Err(HostError::new(StagingError::WriteSetNotRecorded).into());
+    }
+
+    execute_and_restore(ctx, router, msg)
+}
+
+/// Executes `msg`, then restores the state of the context and of the modules
+/// of `router`, returning the output of the execution along with the writes
+/// recorded in the write set of the context, if any.
+fn execute_and_restore<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut (impl StagingRouter + ?Sized),
+    msg: MsgEnvelope,
+) -> Result<SimulationOutput, ContextError>
+where
+    Ctx: StagingContext,
+{
+    let checkpoint = ctx.checkpoint();
+    let router_checkpoint = router.checkpoint();
+    let write_count = ctx.write_set_mut().map_or(0, |write_set| write_set.len());
+
+    let result = execute(ctx, router, msg).map(|output| SimulationOutput {
+        output,
+        writes: ctx
+            .write_set_mut()
+            .map(|write_set| write_set.writes()[write_count..].to_vec())
+            .unwrap_or_default(),
+    });
+
+    ctx.restore(checkpoint);
+    router.restore(router_checkpoint);
+
+    result
+}
+
+/// Entrypoint through which the host itself, e.g. its slashing or monitoring
+/// modules, submits evidence of misbehaviour of the client `client_id`.
+///
+/// The evidence is routed to the light client owning it regardless of its
+/// type, which freezes upon finding misbehaviour and emits the same events as
+/// for a relayer-submitted misbehaviour. Evidence that does not prove any
+/// misbehaviour is rejected.
+pub fn submit_evidence<Ctx>(
+    ctx: &mut Ctx,
+    client_id: &ClientId,
+    evidence: Any,
+) -> Result<HandlerOutput, ContextError>
+where
+    Ctx: HostExecutionContext,
+{
+    submit_evidence::validate(ctx, client_id, evidence.clone())?;
+
+    record_output(ctx, |ctx| {
+        submit_evidence::execute(ctx, client_id, evidence)
+    })
+}
+
 /// Entrypoint which only performs message validation
 ///
+/// It only reads the context, so that ABCI hosts can validate messages against
+/// their committed state upon `CheckTx`, e.g. to keep invalid messages out of
+/// the mempool, without mutating it.
+///
 /// If a transaction contains `n` messages `m_1` ... `m_n`, then
 /// they MUST be processed as follows:
 ///     validate(m_1), execute(m_1), ..., validate(m_n), execute(m_n)
 /// That is, the state transition of message `i` must be applied before
 /// message `i+1` is validated. This is equivalent to calling
 /// `dispatch()` on each successively.
-pub fn validate<Ctx>(ctx: &Ctx, router: &impl Router, msg: MsgEnvelope) -> Result<(), ContextError>
+pub fn validate<Ctx>(
+    ctx: &Ctx,
+    router: &(impl RouterView + ?Sized),
+    msg: MsgEnvelope,
+) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
@@ -79,6 +261,10 @@ where
                 .get_route(&module_id)
                 .ok_or(RouterError::ModuleNotFound)?;
 
+            if ctx.enforces_capabilities() {
+                verify_capability_owner(ctx, &module_id, &channel_msg_capability_name(&msg))?;
+            }
+
             match msg {
                 ChannelMsg::OpenInit(msg) => chan_open_init_validate(ctx, module, msg),
                 ChannelMsg::OpenTry(msg) => chan_open_try_validate(ctx, module, msg),
@@ -86,6 +272,7 @@ where
                 ChannelMsg::OpenConfirm(msg) => chan_open_confirm_validate(ctx, module, msg),
                 ChannelMsg::CloseInit(msg) => chan_close_init_validate(ctx, module, msg),
                 ChannelMsg::CloseConfirm(msg) => chan_close_confirm_validate(ctx, module, msg),
+                ChannelMsg::PruneAcknowledgements(msg) => prune_acknowledgements_validate(ctx, msg),
             }
         }
         MsgEnvelope::Packet(msg) => {
@@ -99,6 +286,12 @@ where
                 .get_route(&module_id)
                 .ok_or(RouterError::ModuleNotFound)?;
 
+            if ctx.enforces_capabilities() {
+                let capability_name =
+                    CapabilityName::channel(port_id, packet_msg_to_channel_id(&msg));
+                verify_capability_owner(ctx, &module_id, &capability_name)?;
+            }
+
             match msg {
                 PacketMsg::Recv(msg) => recv_packet_validate(ctx, msg),
                 PacketMsg::Ack(msg) => acknowledgement_packet_validate(ctx, module, msg),
@@ -108,20 +301,71 @@ where
                 PacketMsg::TimeoutOnClose(msg) => {
                     timeout_packet_validate(ctx, module, TimeoutMsgType::TimeoutOnClose(msg))
                 }
+                PacketMsg::TimeoutBatch(msg) => timeout_batch_validate(ctx, module, msg),
             }
         }
     }
 }
 
 /// Entrypoint which only performs message execution
+///
+/// It assumes `msg` was validated by [`validate`] against the same state, and
+/// is what ABCI hosts call upon `DeliverTx` once the message is validated
+/// again. Use [`dispatch`] to do both at once.
+///
+/// Returns the events and logs emitted while executing `msg`, taken from those
+/// the context keeps (see [`HostExecutionContext::emitted_ibc_events`]).
 pub fn execute<Ctx>(
     ctx: &mut Ctx,
-    router: &mut impl Router,
+    router: &mut (impl Router + ?Sized),
+    msg: MsgEnvelope,
+) -> Result<HandlerOutput, ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    record_output(ctx, |ctx| execute_msg(ctx, router, msg))
+}
+
+/// Runs `execute` against the context, returning the events and logs it
+/// appended to those the context keeps.
+fn record_output<Ctx>(
+    ctx: &mut Ctx,
+    execute: impl FnOnce(&mut Ctx) -> Result<(), ContextError>,
+) -> Result<HandlerOutput, ContextError>
+where
+    Ctx: HostExecutionContext,
+{
+    let event_count = ctx.emitted_ibc_events().len();
+    let log_count = ctx.logged_messages().len();
+
+    execute(ctx)?;
+
+    Ok(HandlerOutput {
+        events: ctx
+            .emitted_ibc_events()
+            .get(event_count..)
+            .unwrap_or_default()
+            .to_vec(),
+        logs: ctx
+            .logged_messages()
+            .get(log_count..)
+            .unwrap_or_default()
+            .to_vec(),
+    })
+}
+
+fn execute_msg<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut (impl Router + ?Sized),
     msg: MsgEnvelope,
 ) -> Result<(), ContextError>
 where
     Ctx: ExecutionContext,
 {
+    if ctx.emits_sdk_message_events() {
+        ctx.emit_ibc_event(SdkMessageEvent::from(&msg).into())?;
+    }
+
     match msg {
         MsgEnvelope::Client(msg) => match msg {
             ClientMsg::CreateClient(msg) => create_client::execute(ctx, msg),
@@ -145,9 +389,9 @@ where
             ConnectionMsg::OpenConfirm(msg) => conn_open_confirm::execute(ctx, &msg),
         },
         MsgEnvelope::Channel(msg) => {
-            let port_id = channel_msg_to_port_id(&msg);
+            let port_id = channel_msg_to_port_id(&msg).clone();
             let module_id = router
-                .lookup_module(port_id)
+                .lookup_module(&port_id)
                 .ok_or(RouterError::UnknownPort {
                     port_id: port_id.clone(),
                 })?;
@@ -155,6 +399,16 @@ where
                 .get_route_mut(&module_id)
                 .ok_or(RouterError::ModuleNotFound)?;
 
+            // The channel opened on this chain by the handshake, whose
+            // capability is issued to the module once the handshake step is
+            // executed.
+            let opened_chan_id = match msg {
+                ChannelMsg::OpenInit(_) | ChannelMsg::OpenTry(_) if ctx.enforces_capabilities() => {
+                    Some(ChannelId::new(ctx.channel_counter()?))
+                }
+                _ => None,
+            };
+
             match msg {
                 ChannelMsg::OpenInit(msg) => chan_open_init_execute(ctx, module, msg),
                 ChannelMsg::OpenTry(msg) => chan_open_try_execute(ctx, module, msg),
@@ -162,7 +416,14 @@ where
                 ChannelMsg::OpenConfirm(msg) => chan_open_confirm_execute(ctx, module, msg),
                 ChannelMsg::CloseInit(msg) => chan_close_init_execute(ctx, module, msg),
                 ChannelMsg::CloseConfirm(msg) => chan_close_confirm_execute(ctx, module, msg),
+                ChannelMsg::PruneAcknowledgements(msg) => prune_acknowledgements_execute(ctx, msg),
+            }?;
+
+            if let Some(chan_id) = opened_chan_id {
+                claim_channel_capability(ctx, &module_id, &port_id, &chan_id)?;
             }
+
+            Ok(())
         }
         MsgEnvelope::Packet(msg) => {
             let port_id = packet_msg_to_port_id(&msg);
@@ -184,7 +445,20 @@ where
                 PacketMsg::TimeoutOnClose(msg) => {
                     timeout_packet_execute(ctx, module, TimeoutMsgType::TimeoutOnClose(msg))
                 }
+                PacketMsg::TimeoutBatch(msg) => timeout_batch_execute(ctx, module, msg),
             }
         }
     }
 }
+
+/// Returns the name of the capability that the module handling `msg` must
+/// own: that of the port for the messages opening a channel, and that of the
+/// channel otherwise.
+fn channel_msg_capability_name(msg: &ChannelMsg) -> CapabilityName {
+    let port_id = channel_msg_to_port_id(msg);
+
+    match channel_msg_to_channel_id(msg) {
+        Some(chan_id) => CapabilityName::channel(port_id, chan_id),
+        None => CapabilityName::port(port_id),
+    }
+}
@@ -24,6 +24,7 @@
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
+pub mod app_msg;
 pub mod entrypoint;
 
 /// Re-export IBC handler types from `ibc-core-handler-types` crate.
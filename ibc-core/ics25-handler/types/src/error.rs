@@ -1,5 +1,8 @@
 //! Defines the context error type
 
+use core::any::Any;
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
 use derive_more::From;
 use displaydoc::Display;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
@@ -21,6 +24,73 @@ pub enum ContextError {
     PacketError(PacketError),
     /// ICS26 Routing error: {0}
     RouterError(RouterError),
+    /// Host error: {0}
+    HostError(HostError),
+}
+
+impl ContextError {
+    /// Returns the wrapped host error if it is of type `E`, typically the
+    /// `HostError` of the context which returned this error.
+    pub fn host_error<E>(&self) -> Option<&E>
+    where
+        E: Debug + Display + Send + Sync + 'static,
+    {
+        match self {
+            Self::HostError(e) => e.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// An error of the host, e.g. of its storage, returned through the context
+/// traits.
+///
+/// The original error is kept as is, and can be recovered with
+/// [`downcast_ref`](Self::downcast_ref). Hosts implement
+/// `From<TheirError> for ContextError` through [`HostError::new`] to surface
+/// their errors with `?`.
+#[derive(Debug)]
+pub struct HostError(Box<dyn HostErrorSource>);
+
+impl HostError {
+    /// Wraps the given host error.
+    pub fn new<E>(error: E) -> Self
+    where
+        E: Debug + Display + Send + Sync + 'static,
+    {
+        Self(Box::new(error))
+    }
+
+    /// Returns the wrapped host error if it is of type `E`.
+    pub fn downcast_ref<E>(&self) -> Option<&E>
+    where
+        E: Debug + Display + Send + Sync + 'static,
+    {
+        // The box itself implements `HostErrorSource`, hence the explicit deref.
+        (*self.0).as_any().downcast_ref()
+    }
+}
+
+impl Display for HostError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HostError {}
+
+trait HostErrorSource: Debug + Display + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<E> HostErrorSource for E
+where
+    E: Debug + Display + Send + Sync + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
 impl From<ContextError> for ClientError {
@@ -43,6 +113,38 @@ impl std::error::Error for ContextError {
             Self::ChannelError(e) => Some(e),
             Self::PacketError(e) => Some(e),
             Self::RouterError(e) => Some(e),
+            Self::HostError(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct StoreError(u32);
+
+    impl Display for StoreError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            write!(f, "store error {}", self.0)
         }
     }
+
+    #[test]
+    fn test_host_error_keeps_original_error() {
+        let error = ContextError::from(HostError::new(StoreError(7)));
+
+        assert_eq!(error.to_string(), "Host error: store error 7");
+
+        assert_eq!(error.host_error(), Some(&StoreError(7)));
+        assert_eq!(error.host_error::<String>(), None);
+        assert_eq!(
+            ContextError::from(ClientError::Other {
+                description: "store error 7".to_string(),
+            })
+            .host_error::<StoreError>(),
+            None
+        );
+    }
 }
@@ -4,13 +4,20 @@ use displaydoc::Display;
 use ibc_core_channel_types::{error as channel_error, events as ChannelEvents};
 use ibc_core_client_types::error as client_error;
 use ibc_core_client_types::events::{self as ClientEvents};
+use ibc_core_connection_types::version::Version as ConnectionVersion;
 use ibc_core_connection_types::{error as connection_error, events as ConnectionEvents};
 use ibc_core_host_types::error::IdentifierError;
+use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
 use ibc_core_router_types::event::ModuleEvent;
 use ibc_primitives::prelude::*;
+use ibc_primitives::utils::abci::{AbciEventError, EventAttributes};
+use ibc_primitives::utils::PrettySlice;
 use ibc_primitives::ParseTimestampError;
 use tendermint::abci;
 
+use crate::error::ContextError;
+use crate::msgs::MsgEnvelope;
+
 /// All error variants related to IBC events
 #[derive(Debug, Display)]
 pub enum Error {
@@ -30,6 +37,14 @@ pub enum Error {
     IncorrectEventType { event: String },
     /// module event cannot use core event types: `{event:?}`
     MalformedModuleEvent { event: ModuleEvent },
+    /// invalid event: `{0}`
+    InvalidEvent(AbciEventError),
+}
+
+impl From<AbciEventError> for Error {
+    fn from(e: AbciEventError) -> Self {
+        Self::InvalidEvent(e)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -41,12 +56,27 @@ impl std::error::Error for Error {
             Self::Connection(e) => Some(e),
             Self::Channel(e) => Some(e),
             Self::Timestamp(e) => Some(e),
+            Self::InvalidEvent(e) => Some(e),
             _ => None,
         }
     }
 }
 
+/// The version of the format of the ABCI events converted from `IbcEvent`s.
+///
+/// Within a version, an `IbcEvent` always converts into the same ABCI event:
+/// its attributes come in a fixed order, heights are written as
+/// `{revision_number}-{revision_height}`, lists are comma-separated and bytes
+/// are lowercase hexadecimal. The version must be bumped along with any change
+/// to the type, the attributes, their order or their encoding of an existing
+/// event, so that hosts hashing their events can tell the formats apart.
+/// Adding a new event leaves the existing ones as they are and does not bump
+/// it.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
 const MESSAGE_EVENT: &str = "message";
+const CAP_REACHED_EVENT: &str = "cap_reached";
+const VERSION_NEGOTIATION_FAILED_EVENT: &str = "version_negotiation_failed";
 
 /// Events created by the IBC component of a chain, destined for a relayer.
 #[cfg_attr(
@@ -66,8 +96,12 @@ const MESSAGE_EVENT: &str = "message";
 pub enum IbcEvent {
     CreateClient(ClientEvents::CreateClient),
     UpdateClient(ClientEvents::UpdateClient),
+    UpdateClientNoOp(ClientEvents::UpdateClientNoOp),
     UpgradeClient(ClientEvents::UpgradeClient),
     ClientMisbehaviour(ClientEvents::ClientMisbehaviour),
+    UpdateClientParams(ClientEvents::UpdateClientParams),
+    ClientExpiring(ClientEvents::ClientExpiring),
+    ClientExpired(ClientEvents::ClientExpired),
 
     OpenInitConnection(ConnectionEvents::OpenInit),
     OpenTryConnection(ConnectionEvents::OpenTry),
@@ -80,6 +114,7 @@ pub enum IbcEvent {
     OpenConfirmChannel(ChannelEvents::OpenConfirm),
     CloseInitChannel(ChannelEvents::CloseInit),
     CloseConfirmChannel(ChannelEvents::CloseConfirm),
+    PruneAcknowledgements(ChannelEvents::PruneAcknowledgements),
 
     SendPacket(ChannelEvents::SendPacket),
     ReceivePacket(ChannelEvents::ReceivePacket),
@@ -88,8 +123,12 @@ pub enum IbcEvent {
     TimeoutPacket(ChannelEvents::TimeoutPacket),
     ChannelClosed(ChannelEvents::ChannelClosed),
 
+    CapReached(CapReached),
+    VersionNegotiationFailed(VersionNegotiationFailed),
+
     Module(ModuleEvent),
     Message(MessageEvent),
+    SdkMessage(SdkMessageEvent),
 }
 
 impl TryFrom<IbcEvent> for abci::Event {
@@ -99,8 +138,12 @@ impl TryFrom<IbcEvent> for abci::Event {
         Ok(match event {
             IbcEvent::CreateClient(event) => event.into(),
             IbcEvent::UpdateClient(event) => event.into(),
+            IbcEvent::UpdateClientNoOp(event) => event.into(),
             IbcEvent::UpgradeClient(event) => event.into(),
             IbcEvent::ClientMisbehaviour(event) => event.into(),
+            IbcEvent::UpdateClientParams(event) => event.into(),
+            IbcEvent::ClientExpiring(event) => event.into(),
+            IbcEvent::ClientExpired(event) => event.into(),
             IbcEvent::OpenInitConnection(event) => event.into(),
             IbcEvent::OpenTryConnection(event) => event.into(),
             IbcEvent::OpenAckConnection(event) => event.into(),
@@ -111,17 +154,127 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::OpenConfirmChannel(event) => event.into(),
             IbcEvent::CloseInitChannel(event) => event.into(),
             IbcEvent::CloseConfirmChannel(event) => event.into(),
+            IbcEvent::PruneAcknowledgements(event) => event.into(),
             IbcEvent::SendPacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::ReceivePacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::WriteAcknowledgement(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::AcknowledgePacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::TimeoutPacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::ChannelClosed(event) => event.into(),
+            IbcEvent::CapReached(event) => event.into(),
+            IbcEvent::VersionNegotiationFailed(event) => event.into(),
             IbcEvent::Module(event) => event.into(),
             IbcEvent::Message(event) => abci::Event {
                 kind: MESSAGE_EVENT.to_string(),
                 attributes: vec![("module", event.module_attribute(), true).into()],
             },
+            IbcEvent::SdkMessage(event) => event.into(),
+        })
+    }
+}
+
+/// Decodes the ABCI events emitted by [`IbcEvent`]s, so that hosts and relayers
+/// can recover them from the events of a block.
+///
+/// Both the events of ibc-rs hosts and the ones of ibc-go chains are decoded.
+/// Relayers and indexers can hence feed it the events of the blocks fetched
+/// from the RPC of any IBC chain, after deserializing them from JSON into
+/// `abci::Event`s.
+///
+/// Events of types not emitted by the core handlers, including the `message`
+/// events of other Cosmos SDK modules, are decoded as [`IbcEvent::Module`]
+/// events.
+impl TryFrom<abci::Event> for IbcEvent {
+    type Error = Error;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        Ok(match event.kind.as_str() {
+            ClientEvents::CREATE_CLIENT_EVENT => {
+                IbcEvent::CreateClient(event.try_into().map_err(Error::Client)?)
+            }
+            ClientEvents::UPDATE_CLIENT_EVENT => {
+                IbcEvent::UpdateClient(event.try_into().map_err(Error::Client)?)
+            }
+            ClientEvents::UPDATE_CLIENT_NO_OP_EVENT => {
+                IbcEvent::UpdateClientNoOp(event.try_into().map_err(Error::Client)?)
+            }
+            ClientEvents::UPGRADE_CLIENT_EVENT => {
+                IbcEvent::UpgradeClient(event.try_into().map_err(Error::Client)?)
+            }
+            ClientEvents::CLIENT_MISBEHAVIOUR_EVENT => {
+                IbcEvent::ClientMisbehaviour(event.try_into().map_err(Error::Client)?)
+            }
+            ClientEvents::UPDATE_CLIENT_PARAMS_EVENT => {
+                IbcEvent::UpdateClientParams(event.try_into().map_err(Error::Client)?)
+            }
+            ClientEvents::CLIENT_EXPIRING_EVENT => {
+                IbcEvent::ClientExpiring(event.try_into().map_err(Error::Client)?)
+            }
+            ClientEvents::CLIENT_EXPIRED_EVENT => {
+                IbcEvent::ClientExpired(event.try_into().map_err(Error::Client)?)
+            }
+            ConnectionEvents::CONNECTION_OPEN_INIT_EVENT => {
+                IbcEvent::OpenInitConnection(event.try_into().map_err(Error::Connection)?)
+            }
+            ConnectionEvents::CONNECTION_OPEN_TRY_EVENT => {
+                IbcEvent::OpenTryConnection(event.try_into().map_err(Error::Connection)?)
+            }
+            ConnectionEvents::CONNECTION_OPEN_ACK_EVENT => {
+                IbcEvent::OpenAckConnection(event.try_into().map_err(Error::Connection)?)
+            }
+            ConnectionEvents::CONNECTION_OPEN_CONFIRM_EVENT => {
+                IbcEvent::OpenConfirmConnection(event.try_into().map_err(Error::Connection)?)
+            }
+            ChannelEvents::CHANNEL_OPEN_INIT_EVENT => {
+                IbcEvent::OpenInitChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            ChannelEvents::CHANNEL_OPEN_TRY_EVENT => {
+                IbcEvent::OpenTryChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            ChannelEvents::CHANNEL_OPEN_ACK_EVENT => {
+                IbcEvent::OpenAckChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            ChannelEvents::CHANNEL_OPEN_CONFIRM_EVENT => {
+                IbcEvent::OpenConfirmChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            ChannelEvents::CHANNEL_CLOSE_INIT_EVENT => {
+                IbcEvent::CloseInitChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            ChannelEvents::CHANNEL_CLOSE_CONFIRM_EVENT => {
+                IbcEvent::CloseConfirmChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            ChannelEvents::PRUNE_ACKNOWLEDGEMENTS_EVENT => {
+                IbcEvent::PruneAcknowledgements(event.try_into().map_err(Error::Channel)?)
+            }
+            ChannelEvents::SEND_PACKET_EVENT => {
+                IbcEvent::SendPacket(event.try_into().map_err(Error::Channel)?)
+            }
+            ChannelEvents::RECEIVE_PACKET_EVENT => {
+                IbcEvent::ReceivePacket(event.try_into().map_err(Error::Channel)?)
+            }
+            ChannelEvents::WRITE_ACK_EVENT => {
+                IbcEvent::WriteAcknowledgement(event.try_into().map_err(Error::Channel)?)
+            }
+            ChannelEvents::ACK_PACKET_EVENT => {
+                IbcEvent::AcknowledgePacket(event.try_into().map_err(Error::Channel)?)
+            }
+            ChannelEvents::TIMEOUT_EVENT => {
+                IbcEvent::TimeoutPacket(event.try_into().map_err(Error::Channel)?)
+            }
+            ChannelEvents::CHANNEL_CLOSED_EVENT => {
+                IbcEvent::ChannelClosed(event.try_into().map_err(Error::Channel)?)
+            }
+            CAP_REACHED_EVENT => IbcEvent::CapReached(event.try_into()?),
+            VERSION_NEGOTIATION_FAILED_EVENT => {
+                IbcEvent::VersionNegotiationFailed(event.try_into()?)
+            }
+            MESSAGE_EVENT if SdkMessageEvent::is_sdk_message_event(&event) => {
+                IbcEvent::SdkMessage(event.try_into()?)
+            }
+            MESSAGE_EVENT if event.attributes.iter().any(|attr| attr.key == "module") => {
+                IbcEvent::Message(event.try_into()?)
+            }
+            _ => IbcEvent::Module(event.into()),
         })
     }
 }
@@ -131,8 +284,12 @@ impl IbcEvent {
         match self {
             IbcEvent::CreateClient(event) => event.event_type(),
             IbcEvent::UpdateClient(event) => event.event_type(),
+            IbcEvent::UpdateClientNoOp(event) => event.event_type(),
             IbcEvent::ClientMisbehaviour(event) => event.event_type(),
             IbcEvent::UpgradeClient(event) => event.event_type(),
+            IbcEvent::UpdateClientParams(event) => event.event_type(),
+            IbcEvent::ClientExpiring(event) => event.event_type(),
+            IbcEvent::ClientExpired(event) => event.event_type(),
             IbcEvent::OpenInitConnection(event) => event.event_type(),
             IbcEvent::OpenTryConnection(event) => event.event_type(),
             IbcEvent::OpenAckConnection(event) => event.event_type(),
@@ -143,14 +300,17 @@ impl IbcEvent {
             IbcEvent::OpenConfirmChannel(event) => event.event_type(),
             IbcEvent::CloseInitChannel(event) => event.event_type(),
             IbcEvent::CloseConfirmChannel(event) => event.event_type(),
+            IbcEvent::PruneAcknowledgements(event) => event.event_type(),
             IbcEvent::SendPacket(event) => event.event_type(),
             IbcEvent::ReceivePacket(event) => event.event_type(),
             IbcEvent::WriteAcknowledgement(event) => event.event_type(),
             IbcEvent::AcknowledgePacket(event) => event.event_type(),
             IbcEvent::TimeoutPacket(event) => event.event_type(),
             IbcEvent::ChannelClosed(event) => event.event_type(),
+            IbcEvent::CapReached(event) => event.event_type(),
+            IbcEvent::VersionNegotiationFailed(event) => event.event_type(),
             IbcEvent::Module(module_event) => module_event.kind.as_str(),
-            IbcEvent::Message(_) => MESSAGE_EVENT,
+            IbcEvent::Message(_) | IbcEvent::SdkMessage(_) => MESSAGE_EVENT,
         }
     }
 }
@@ -195,14 +355,405 @@ impl MessageEvent {
     }
 }
 
+impl TryFrom<abci::Event> for MessageEvent {
+    type Error = Error;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, MESSAGE_EVENT)?;
+
+        Ok(match attributes.get("module")? {
+            "ibc_client" => MessageEvent::Client,
+            "ibc_connection" => MessageEvent::Connection,
+            "ibc_channel" => MessageEvent::Channel,
+            module_name => MessageEvent::Module(module_name.to_string()),
+        })
+    }
+}
+
 impl From<MessageEvent> for IbcEvent {
     fn from(e: MessageEvent) -> Self {
         IbcEvent::Message(e)
     }
 }
 
+/// The `message` event the Cosmos SDK emits ahead of the events of each
+/// message of a transaction, identifying the message by its type URL.
+///
+/// Indexers and relayers written for ibc-go chains filter transactions on its
+/// `message.action` and `message.sender` attributes. Hosts opting into
+/// `HostExecutionContext::emits_sdk_message_events` emit it for each message
+/// they dispatch.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SdkMessageEvent {
+    action: String,
+    sender: String,
+    module: String,
+}
+
+impl SdkMessageEvent {
+    pub fn new(action: String, sender: String, module: String) -> Self {
+        Self {
+            action,
+            sender,
+            module,
+        }
+    }
+
+    /// The type URL of the message.
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    /// The signer of the message.
+    pub fn sender(&self) -> &str {
+        &self.sender
+    }
+
+    /// The module handling the message, `ibc` for the messages of ibc-go.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    pub fn event_type(&self) -> &str {
+        MESSAGE_EVENT
+    }
+
+    /// Whether `event` carries all the attributes of the Cosmos SDK message
+    /// event, as opposed to the `module`-only event of the IBC handlers.
+    fn is_sdk_message_event(event: &abci::Event) -> bool {
+        ["action", "sender", "module"]
+            .iter()
+            .all(|key| event.attributes.iter().any(|attr| attr.key == *key))
+    }
+}
+
+/// Builds the event the Cosmos SDK emits for an IBC message, whose module is
+/// named after the first segment of its type URL.
+impl From<&MsgEnvelope> for SdkMessageEvent {
+    fn from(msg: &MsgEnvelope) -> Self {
+        Self::new(
+            msg.type_url().to_string(),
+            msg.signer().to_string(),
+            "ibc".to_string(),
+        )
+    }
+}
+
+impl From<SdkMessageEvent> for abci::Event {
+    fn from(e: SdkMessageEvent) -> Self {
+        Self {
+            kind: MESSAGE_EVENT.to_string(),
+            attributes: vec![
+                ("action", e.action, true).into(),
+                ("sender", e.sender, true).into(),
+                ("module", e.module, true).into(),
+            ],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for SdkMessageEvent {
+    type Error = Error;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, MESSAGE_EVENT)?;
+
+        Ok(Self::new(
+            attributes.get("action")?.to_string(),
+            attributes.get("sender")?.to_string(),
+            attributes.get("module")?.to_string(),
+        ))
+    }
+}
+
+impl From<SdkMessageEvent> for IbcEvent {
+    fn from(e: SdkMessageEvent) -> Self {
+        IbcEvent::SdkMessage(e)
+    }
+}
+
 impl From<ModuleEvent> for IbcEvent {
     fn from(e: ModuleEvent) -> Self {
         IbcEvent::Module(e)
     }
 }
+
+/// The scope of a cap set by the
+/// [`HostLimits`](ibc_core_host_types::limits::HostLimits) of a host.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CapScope {
+    /// The total number of clients on the host.
+    Clients,
+    /// The number of connections on top of the given client.
+    ConnectionsPerClient(ClientId),
+    /// The number of channels on top of the given connection.
+    ChannelsPerConnection(ConnectionId),
+}
+
+impl CapScope {
+    /// The value of the `cap` attribute of the ABCI event.
+    pub fn cap_attribute(&self) -> &'static str {
+        match self {
+            CapScope::Clients => "max_clients",
+            CapScope::ConnectionsPerClient(_) => "max_connections_per_client",
+            CapScope::ChannelsPerConnection(_) => "max_channels_per_connection",
+        }
+    }
+}
+
+/// CapReached event signals that the creation of a client, connection or
+/// channel exhausted one of the caps of the host, so that no more such objects
+/// can be created in the same scope.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapReached {
+    scope: CapScope,
+    max: u64,
+}
+
+impl CapReached {
+    pub fn new(scope: CapScope, max: u64) -> Self {
+        Self { scope, max }
+    }
+
+    pub fn scope(&self) -> &CapScope {
+        &self.scope
+    }
+
+    pub fn max(&self) -> u64 {
+        self.max
+    }
+
+    pub fn event_type(&self) -> &str {
+        CAP_REACHED_EVENT
+    }
+}
+
+impl From<CapReached> for abci::Event {
+    fn from(e: CapReached) -> Self {
+        let mut attributes = vec![
+            ("cap", e.scope.cap_attribute()).into(),
+            ("max", e.max.to_string()).into(),
+        ];
+
+        match e.scope {
+            CapScope::Clients => {}
+            CapScope::ConnectionsPerClient(client_id) => {
+                attributes.push(("client_id", client_id.as_str()).into())
+            }
+            CapScope::ChannelsPerConnection(connection_id) => {
+                attributes.push(("connection_id", connection_id.as_str()).into())
+            }
+        }
+
+        Self {
+            kind: CAP_REACHED_EVENT.to_string(),
+            attributes,
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for CapReached {
+    type Error = Error;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, CAP_REACHED_EVENT)?;
+
+        let scope = match attributes.get("cap")? {
+            "max_clients" => CapScope::Clients,
+            "max_connections_per_client" => {
+                CapScope::ConnectionsPerClient(attributes.parse("client_id")?)
+            }
+            "max_channels_per_connection" => {
+                CapScope::ChannelsPerConnection(attributes.parse("connection_id")?)
+            }
+            cap => return Err(attributes.invalid("cap", cap).into()),
+        };
+
+        Ok(Self::new(scope, attributes.parse("max")?))
+    }
+}
+
+impl From<CapReached> for IbcEvent {
+    fn from(e: CapReached) -> Self {
+        IbcEvent::CapReached(e)
+    }
+}
+
+/// VersionNegotiationFailed event describes a connection or channel handshake
+/// that failed because the versions proposed by the counterparty are not
+/// supported by the host.
+///
+/// Failed handshake messages leave no events behind, so this event is not
+/// emitted by the handlers. Hosts that record failed transactions can build it
+/// out of the handler error with [`VersionNegotiationFailed::from_error`],
+/// sparing operators from inspecting the versions of both chains.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionNegotiationFailed {
+    proposed: Vec<String>,
+    supported: Vec<String>,
+}
+
+impl VersionNegotiationFailed {
+    pub fn new(proposed: Vec<String>, supported: Vec<String>) -> Self {
+        Self {
+            proposed,
+            supported,
+        }
+    }
+
+    /// Returns the event describing the failed version negotiation, if the
+    /// given error was raised by the version negotiation of a handshake.
+    pub fn from_error(error: &ContextError) -> Option<Self> {
+        fn to_strings(versions: &[ConnectionVersion]) -> Vec<String> {
+            versions.iter().map(ToString::to_string).collect()
+        }
+
+        match error {
+            ContextError::ConnectionError(connection_error::ConnectionError::NoCommonVersion {
+                supported,
+                proposed,
+            }) => Some(Self::new(to_strings(proposed), to_strings(supported))),
+            ContextError::ConnectionError(
+                connection_error::ConnectionError::VersionNotSupported { version, supported },
+            ) => Some(Self::new(vec![version.to_string()], to_strings(supported))),
+            ContextError::ChannelError(channel_error::ChannelError::VersionNotSupported {
+                expected,
+                actual,
+            }) => Some(Self::new(
+                vec![actual.to_string()],
+                vec![expected.to_string()],
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn proposed(&self) -> &[String] {
+        &self.proposed
+    }
+
+    pub fn supported(&self) -> &[String] {
+        &self.supported
+    }
+
+    pub fn event_type(&self) -> &str {
+        VERSION_NEGOTIATION_FAILED_EVENT
+    }
+}
+
+impl From<VersionNegotiationFailed> for abci::Event {
+    fn from(e: VersionNegotiationFailed) -> Self {
+        Self {
+            kind: VERSION_NEGOTIATION_FAILED_EVENT.to_string(),
+            attributes: vec![
+                ("proposed_versions", PrettySlice(&e.proposed).to_string()).into(),
+                ("supported_versions", PrettySlice(&e.supported).to_string()).into(),
+            ],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for VersionNegotiationFailed {
+    type Error = Error;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        let attributes = EventAttributes::new(&event, VERSION_NEGOTIATION_FAILED_EVENT)?;
+
+        let parse_versions = |key: &str| {
+            let value = attributes.get(key)?;
+            split_pretty_slice(value).ok_or_else(|| attributes.invalid(key, value))
+        };
+
+        Ok(Self::new(
+            parse_versions("proposed_versions")?,
+            parse_versions("supported_versions")?,
+        ))
+    }
+}
+
+/// Splits the elements of a slice formatted by [`PrettySlice`].
+///
+/// Connection versions are displayed with their features, which are separated
+/// by commas as well, so that the elements are split only at the commas that
+/// are not nested in brackets.
+fn split_pretty_slice(value: &str) -> Option<Vec<String>> {
+    let elements = value.strip_prefix("[ ")?.strip_suffix(" ]")?;
+    if elements.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut split = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in elements.char_indices() {
+        match c {
+            '[' | '{' | '(' => depth += 1,
+            ']' | '}' | ')' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 && elements[i + 1..].starts_with(' ') => {
+                split.push(elements[start..i].to_string());
+                start = i + 2;
+            }
+            _ => {}
+        }
+    }
+    split.push(elements[start..].to_string());
+
+    Some(split)
+}
+
+impl From<VersionNegotiationFailed> for IbcEvent {
+    fn from(e: VersionNegotiationFailed) -> Self {
+        IbcEvent::VersionNegotiationFailed(e)
+    }
+}
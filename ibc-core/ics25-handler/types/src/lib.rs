@@ -19,3 +19,4 @@ extern crate std;
 pub mod error;
 pub mod events;
 pub mod msgs;
+pub mod output;
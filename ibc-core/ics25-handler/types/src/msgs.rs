@@ -1,16 +1,17 @@
 use ibc_core_channel_types::msgs::{
     ChannelMsg, MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
-    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
-    MsgTimeoutOnClose, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL,
-    CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL,
-    CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL, RECV_PACKET_TYPE_URL,
-    TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
+    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgPruneAcknowledgements,
+    MsgRecvPacket, MsgTimeout, MsgTimeoutBatch, MsgTimeoutOnClose, PacketMsg,
+    ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL, CHAN_CLOSE_INIT_TYPE_URL,
+    CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL, CHAN_OPEN_INIT_TYPE_URL,
+    CHAN_OPEN_TRY_TYPE_URL, PRUNE_ACKNOWLEDGEMENTS_TYPE_URL, RECV_PACKET_TYPE_URL,
+    TIMEOUT_BATCH_TYPE_URL, TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
 };
 #[allow(deprecated)]
 use ibc_core_client_types::msgs::{
     ClientMsg, MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient, MsgUpgradeClient,
-    CREATE_CLIENT_TYPE_URL, SUBMIT_MISBEHAVIOUR_TYPE_URL, UPDATE_CLIENT_TYPE_URL,
-    UPGRADE_CLIENT_TYPE_URL,
+    CREATE_CLIENT_TYPE_URL, RECOVER_CLIENT_TYPE_URL, SUBMIT_MISBEHAVIOUR_TYPE_URL,
+    UPDATE_CLIENT_TYPE_URL, UPGRADE_CLIENT_TYPE_URL,
 };
 use ibc_core_connection_types::msgs::{
     ConnectionMsg, MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit,
@@ -19,6 +20,7 @@ use ibc_core_connection_types::msgs::{
 };
 use ibc_core_router_types::error::RouterError;
 use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::Protobuf;
 
@@ -36,6 +38,80 @@ pub enum MsgEnvelope {
     Packet(PacketMsg),
 }
 
+#[allow(deprecated)]
+impl MsgEnvelope {
+    /// Returns the type URL of the message, under which it is encoded as an
+    /// `Any`.
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            MsgEnvelope::Client(msg) => match msg {
+                ClientMsg::CreateClient(_) => CREATE_CLIENT_TYPE_URL,
+                ClientMsg::UpdateClient(_) => UPDATE_CLIENT_TYPE_URL,
+                ClientMsg::Misbehaviour(_) => SUBMIT_MISBEHAVIOUR_TYPE_URL,
+                ClientMsg::UpgradeClient(_) => UPGRADE_CLIENT_TYPE_URL,
+                ClientMsg::RecoverClient(_) => RECOVER_CLIENT_TYPE_URL,
+            },
+            MsgEnvelope::Connection(msg) => match msg {
+                ConnectionMsg::OpenInit(_) => CONN_OPEN_INIT_TYPE_URL,
+                ConnectionMsg::OpenTry(_) => CONN_OPEN_TRY_TYPE_URL,
+                ConnectionMsg::OpenAck(_) => CONN_OPEN_ACK_TYPE_URL,
+                ConnectionMsg::OpenConfirm(_) => CONN_OPEN_CONFIRM_TYPE_URL,
+            },
+            MsgEnvelope::Channel(msg) => match msg {
+                ChannelMsg::OpenInit(_) => CHAN_OPEN_INIT_TYPE_URL,
+                ChannelMsg::OpenTry(_) => CHAN_OPEN_TRY_TYPE_URL,
+                ChannelMsg::OpenAck(_) => CHAN_OPEN_ACK_TYPE_URL,
+                ChannelMsg::OpenConfirm(_) => CHAN_OPEN_CONFIRM_TYPE_URL,
+                ChannelMsg::CloseInit(_) => CHAN_CLOSE_INIT_TYPE_URL,
+                ChannelMsg::CloseConfirm(_) => CHAN_CLOSE_CONFIRM_TYPE_URL,
+                ChannelMsg::PruneAcknowledgements(_) => PRUNE_ACKNOWLEDGEMENTS_TYPE_URL,
+            },
+            MsgEnvelope::Packet(msg) => match msg {
+                PacketMsg::Recv(_) => RECV_PACKET_TYPE_URL,
+                PacketMsg::Ack(_) => ACKNOWLEDGEMENT_TYPE_URL,
+                PacketMsg::Timeout(_) => TIMEOUT_TYPE_URL,
+                PacketMsg::TimeoutOnClose(_) => TIMEOUT_ON_CLOSE_TYPE_URL,
+                PacketMsg::TimeoutBatch(_) => TIMEOUT_BATCH_TYPE_URL,
+            },
+        }
+    }
+
+    /// Returns the signer of the message.
+    pub fn signer(&self) -> &Signer {
+        match self {
+            MsgEnvelope::Client(msg) => match msg {
+                ClientMsg::CreateClient(msg) => &msg.signer,
+                ClientMsg::UpdateClient(msg) => &msg.signer,
+                ClientMsg::Misbehaviour(msg) => &msg.signer,
+                ClientMsg::UpgradeClient(msg) => &msg.signer,
+                ClientMsg::RecoverClient(msg) => &msg.signer,
+            },
+            MsgEnvelope::Connection(msg) => match msg {
+                ConnectionMsg::OpenInit(msg) => &msg.signer,
+                ConnectionMsg::OpenTry(msg) => &msg.signer,
+                ConnectionMsg::OpenAck(msg) => &msg.signer,
+                ConnectionMsg::OpenConfirm(msg) => &msg.signer,
+            },
+            MsgEnvelope::Channel(msg) => match msg {
+                ChannelMsg::OpenInit(msg) => &msg.signer,
+                ChannelMsg::OpenTry(msg) => &msg.signer,
+                ChannelMsg::OpenAck(msg) => &msg.signer,
+                ChannelMsg::OpenConfirm(msg) => &msg.signer,
+                ChannelMsg::CloseInit(msg) => &msg.signer,
+                ChannelMsg::CloseConfirm(msg) => &msg.signer,
+                ChannelMsg::PruneAcknowledgements(msg) => &msg.signer,
+            },
+            MsgEnvelope::Packet(msg) => match msg {
+                PacketMsg::Recv(msg) => &msg.signer,
+                PacketMsg::Ack(msg) => &msg.signer,
+                PacketMsg::Timeout(msg) => &msg.signer,
+                PacketMsg::TimeoutOnClose(msg) => &msg.signer,
+                PacketMsg::TimeoutBatch(msg) => &msg.signer,
+            },
+        }
+    }
+}
+
 #[allow(deprecated)]
 impl TryFrom<Any> for MsgEnvelope {
     type Error = RouterError;
@@ -167,6 +243,17 @@ impl TryFrom<Any> for MsgEnvelope {
                     })?;
                 Ok(MsgEnvelope::Channel(ChannelMsg::CloseConfirm(domain_msg)))
             }
+            PRUNE_ACKNOWLEDGEMENTS_TYPE_URL => {
+                let domain_msg =
+                    MsgPruneAcknowledgements::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::PruneAcknowledgements(
+                    domain_msg,
+                )))
+            }
             // ICS04 packet messages
             RECV_PACKET_TYPE_URL => {
                 let domain_msg = MsgRecvPacket::decode_vec(&any_msg.value).map_err(|e| {
@@ -200,6 +287,14 @@ impl TryFrom<Any> for MsgEnvelope {
                 })?;
                 Ok(MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(domain_msg)))
             }
+            TIMEOUT_BATCH_TYPE_URL => {
+                let domain_msg = MsgTimeoutBatch::decode_vec(&any_msg.value).map_err(|e| {
+                    RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Ok(MsgEnvelope::Packet(PacketMsg::TimeoutBatch(domain_msg)))
+            }
             _ => Err(RouterError::UnknownMessageTypeUrl {
                 url: any_msg.type_url,
             }),
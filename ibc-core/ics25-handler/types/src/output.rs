@@ -0,0 +1,43 @@
+//! Defines the output of the handler entrypoints.
+
+use ibc_core_host_types::write_set::StoreWrite;
+use ibc_primitives::prelude::*;
+
+use crate::events::IbcEvent;
+
+/// The events and logs emitted while executing messages, which the handler
+/// entrypoints return in addition to recording them through the context.
+///
+/// Lets hosts that surface the events of each message, such as in CosmWasm
+/// responses or ABCI transaction results, do so without diffing the events
+/// recorded by their context themselves: the entrypoints take those appended
+/// by each message from the ones the context exposes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HandlerOutput {
+    pub events: Vec<IbcEvent>,
+    pub logs: Vec<String>,
+}
+
+impl HandlerOutput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the events and logs of `other`.
+    pub fn extend(&mut self, other: HandlerOutput) {
+        self.events.extend(other.events);
+        self.logs.extend(other.logs);
+    }
+}
+
+/// The would-be outcome of a message whose writes were not committed, as
+/// returned by the simulating and deferring entrypoints.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SimulationOutput {
+    /// The events and logs that executing the message would emit.
+    pub output: HandlerOutput,
+    /// The store writes that executing the message would perform, as recorded
+    /// in the write set of the context, in order. Empty if the host does not
+    /// record one.
+    pub writes: Vec<StoreWrite>,
+}
@@ -14,6 +14,7 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod middleware;
 pub mod module;
 pub mod router;
 
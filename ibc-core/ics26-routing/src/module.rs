@@ -1,6 +1,3 @@
-/// The trait that defines an IBC application
-use core::fmt::Debug;
-
 use ibc_core_channel_types::acknowledgement::Acknowledgement;
 use ibc_core_channel_types::channel::{Counterparty, Order};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
@@ -11,7 +8,11 @@ use ibc_core_router_types::module::ModuleExtras;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Signer;
 
-pub trait Module: Debug {
+/// The trait that defines an IBC application.
+///
+/// Modules are neither required to be `'static` nor `Debug`, so that hosts can
+/// route to modules borrowing their state for the duration of a message.
+pub trait Module {
     fn on_chan_open_init_validate(
         &self,
         order: Order,
@@ -86,10 +87,14 @@ pub trait Module: Debug {
         Ok(ModuleExtras::empty())
     }
 
+    /// Authorizes the closure of a channel requested by `signer` through a
+    /// `MsgChannelCloseInit`. Returning an error vetoes the closure, which
+    /// modules that must not have their channels closed by users rely on.
     fn on_chan_close_init_validate(
         &self,
         _port_id: &PortId,
         _channel_id: &ChannelId,
+        _signer: &Signer,
     ) -> Result<(), ChannelError> {
         Ok(())
     }
@@ -123,11 +128,15 @@ pub trait Module: Debug {
     // if any error occurs, than an "error acknowledgement"
     // must be returned
 
+    /// Returns the acknowledgement of the received packet, or `None` if the
+    /// module acknowledges the packet asynchronously. In the latter case, the
+    /// host is expected to call the `write_acknowledgement` entrypoint of the
+    /// channel handlers once the acknowledgement is known.
     fn on_recv_packet_execute(
         &mut self,
         packet: &Packet,
         relayer: &Signer,
-    ) -> (ModuleExtras, Acknowledgement);
+    ) -> (ModuleExtras, Option<Acknowledgement>);
 
     fn on_acknowledgement_packet_validate(
         &self,
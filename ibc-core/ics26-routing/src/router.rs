@@ -2,17 +2,143 @@
 
 use ibc_core_host_types::identifiers::PortId;
 use ibc_core_router_types::module::ModuleId;
+use ibc_primitives::prelude::*;
 
 use crate::module::Module;
 
-/// Router as defined in ICS-26, which binds modules to ports.
-pub trait Router {
+/// Read-only view of a [`Router`], which is all the validation phase of the
+/// message handlers gets access to.
+///
+/// Since it only ever hands out shared references to the modules, application
+/// modules cannot mutate their state while a message is being validated.
+pub trait RouterView {
     /// Returns a reference to a `Module` registered against the specified `ModuleId`
     fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module>;
 
+    /// Return the module_id associated with a given port_id
+    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId>;
+}
+
+/// Router as defined in ICS-26, which binds modules to ports.
+///
+/// Mutable access to the modules is reserved for the execution phase of the
+/// message handlers.
+///
+/// Both router traits are object safe, and the entrypoints accept unsized
+/// routers, e.g. a `&mut dyn Router` built by the host for each transaction
+/// over modules borrowing its state.
+pub trait Router: RouterView {
     /// Returns a mutable reference to a `Module` registered against the specified `ModuleId`
     fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module>;
+}
 
-    /// Return the module_id associated with a given port_id
-    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId>;
+/// A [`Router`] whose modules' own state, e.g. the balances a token transfer
+/// module moves tokens with, can be checkpointed and restored.
+///
+/// The entrypoints discarding the writes of the context, such as
+/// `dispatch_batch` upon a failing message, restore the router along with it,
+/// so that no module keeps the effects of a discarded message. Routers whose
+/// modules keep all their state in the context use `()` as checkpoint.
+pub trait StagingRouter: Router {
+    /// The state of the modules to restore when discarding staged writes.
+    type Checkpoint;
+
+    /// Returns the current state of the modules.
+    fn checkpoint(&self) -> Self::Checkpoint;
+
+    /// Restores the state of the modules to `checkpoint`, discarding all their
+    /// writes since it was taken.
+    fn restore(&mut self, checkpoint: Self::Checkpoint);
+}
+
+/// Table of the ports bound to each module, which [`RouterView`] implementors
+/// can use to look up the module of a port.
+///
+/// Besides exact port identifiers, ports can be routed by prefix, which serves
+/// the applications whose ports are created dynamically, such as the
+/// `icacontroller-{owner}` ports of interchain accounts controllers.
+#[derive(Clone, Debug, Default)]
+pub struct PortRoutes {
+    ports: BTreeMap<PortId, ModuleId>,
+    prefixes: BTreeMap<String, ModuleId>,
+}
+
+impl PortRoutes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Routes the port `port_id` to the module `module_id`, returning the
+    /// module it was previously routed to, if any.
+    pub fn add_port(&mut self, port_id: PortId, module_id: ModuleId) -> Option<ModuleId> {
+        self.ports.insert(port_id, module_id)
+    }
+
+    /// Routes all the ports starting with `prefix`, e.g. `icacontroller-`, to
+    /// the module `module_id`, returning the module the prefix was previously
+    /// routed to, if any.
+    pub fn add_prefix(
+        &mut self,
+        prefix: impl Into<String>,
+        module_id: ModuleId,
+    ) -> Option<ModuleId> {
+        self.prefixes.insert(prefix.into(), module_id)
+    }
+
+    /// Returns the module the port `port_id` is routed to.
+    ///
+    /// Exact routes take precedence over prefix routes, among which the
+    /// longest matching prefix wins.
+    pub fn lookup(&self, port_id: &PortId) -> Option<&ModuleId> {
+        if let Some(module_id) = self.ports.get(port_id) {
+            return Some(module_id);
+        }
+
+        self.prefixes
+            .iter()
+            .filter(|(prefix, _)| port_id.as_str().starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, module_id)| module_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module_id(name: &str) -> ModuleId {
+        ModuleId::new(name.to_string())
+    }
+
+    fn port_id(port: &str) -> PortId {
+        port.parse().expect("valid port identifier")
+    }
+
+    #[test]
+    fn test_port_routes_lookup() {
+        let mut routes = PortRoutes::new();
+        routes.add_port(port_id("icahost"), module_id("icahost"));
+        routes.add_prefix("icacontroller-", module_id("icacontroller"));
+        routes.add_prefix("icacontroller-admin-", module_id("admin"));
+        routes.add_port(port_id("icacontroller-special"), module_id("special"));
+
+        assert_eq!(
+            routes.lookup(&port_id("icahost")),
+            Some(&module_id("icahost"))
+        );
+        assert_eq!(
+            routes.lookup(&port_id("icacontroller-cosmos1owner")),
+            Some(&module_id("icacontroller"))
+        );
+        assert_eq!(
+            routes.lookup(&port_id("icacontroller-admin-cosmos1owner")),
+            Some(&module_id("admin"))
+        );
+        assert_eq!(
+            routes.lookup(&port_id("icacontroller-special")),
+            Some(&module_id("special"))
+        );
+        assert_eq!(routes.lookup(&port_id("icahost-2")), None);
+        assert_eq!(routes.lookup(&PortId::transfer()), None);
+    }
 }
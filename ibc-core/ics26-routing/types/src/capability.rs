@@ -0,0 +1,103 @@
+//! Defines the object-capabilities (ICS-05) through which modules prove their
+//! ownership of ports and channels.
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+
+/// A capability issued by the host, which grants the modules owning it the
+/// right to act on the port or channel it was issued for.
+///
+/// Capabilities are only ever issued by the host, and are authenticated
+/// against the capability it recorded under the same [`CapabilityName`].
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Capability(u64);
+
+impl Capability {
+    pub fn new(index: u64) -> Self {
+        Self(index)
+    }
+
+    /// Returns the host-assigned index of the capability.
+    pub fn index(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for Capability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The name under which the host records a capability, following the ibc-go
+/// naming of port and channel capabilities.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CapabilityName(String);
+
+impl CapabilityName {
+    /// Returns the name of the capability over the port `port_id`.
+    pub fn port(port_id: &PortId) -> Self {
+        Self(format!("ports/{port_id}"))
+    }
+
+    /// Returns the name of the capability over the channel `channel_id` of
+    /// the port `port_id`.
+    pub fn channel(port_id: &PortId, channel_id: &ChannelId) -> Self {
+        Self(format!(
+            "capabilities/ports/{port_id}/channels/{channel_id}"
+        ))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for CapabilityName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capability_names() {
+        let port_id = PortId::transfer();
+
+        assert_eq!(CapabilityName::port(&port_id).as_str(), "ports/transfer");
+        assert_eq!(
+            CapabilityName::channel(&port_id, &ChannelId::new(3)).as_str(),
+            "capabilities/ports/transfer/channels/channel-3"
+        );
+    }
+}
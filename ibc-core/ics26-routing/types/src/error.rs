@@ -2,6 +2,9 @@ use displaydoc::Display;
 use ibc_core_host_types::identifiers::PortId;
 use ibc_primitives::prelude::*;
 
+use crate::capability::CapabilityName;
+use crate::module::ModuleId;
+
 /// Error type for the router module.
 #[derive(Debug, Display)]
 pub enum RouterError {
@@ -13,6 +16,17 @@ pub enum RouterError {
     UnknownPort { port_id: PortId },
     /// module not found
     ModuleNotFound,
+    /// port `{port_id}` is already bound
+    PortAlreadyBound { port_id: PortId },
+    /// capability `{name}` is already issued
+    CapabilityAlreadyIssued { name: CapabilityName },
+    /// module `{module_id}` does not own the capability `{name}`
+    CapabilityNotOwned {
+        module_id: ModuleId,
+        name: CapabilityName,
+    },
+    /// the host does not support capabilities
+    CapabilitiesNotSupported,
 }
 
 #[cfg(feature = "std")]
@@ -1,4 +1,7 @@
+use core::str::FromStr;
+
 use ibc_primitives::prelude::*;
+use ibc_primitives::utils::abci::AbciEventError;
 use tendermint::abci;
 
 /// The event type emitted by IBC applications
@@ -21,6 +24,64 @@ pub struct ModuleEvent {
     pub attributes: Vec<ModuleEventAttribute>,
 }
 
+impl ModuleEvent {
+    pub fn new(kind: impl ToString, attributes: Vec<ModuleEventAttribute>) -> Self {
+        Self {
+            kind: kind.to_string(),
+            attributes,
+        }
+    }
+
+    /// Returns the value of the first attribute under `key`, if any.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+    }
+
+    /// Sets the value of the first attribute under `key`, appending the
+    /// attribute if the event has none.
+    ///
+    /// Lets middlewares rewrite the events of the applications they wrap.
+    pub fn set_attribute(&mut self, key: impl ToString, value: impl ToString) {
+        let key = key.to_string();
+        match self.attributes.iter_mut().find(|attr| attr.key == key) {
+            Some(attr) => attr.value = value.to_string(),
+            None => self.attributes.push((key, value.to_string()).into()),
+        }
+    }
+
+    /// Checks that the event is of the `expected` type, which typed
+    /// application events check first when decoded from a `ModuleEvent`.
+    pub fn expect_kind(&self, expected: &str) -> Result<(), AbciEventError> {
+        if self.kind != expected {
+            return Err(AbciEventError::UnexpectedEventType {
+                kind: self.kind.clone(),
+                expected: expected.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Parses the value of the first attribute under `key`.
+    pub fn parse_attribute<T: FromStr>(&self, key: &str) -> Result<T, AbciEventError> {
+        let value = self
+            .attribute(key)
+            .ok_or_else(|| AbciEventError::MissingAttribute {
+                kind: self.kind.clone(),
+                key: key.to_string(),
+            })?;
+
+        value.parse().map_err(|_| AbciEventError::InvalidAttribute {
+            kind: self.kind.clone(),
+            key: key.to_string(),
+            value: value.to_string(),
+        })
+    }
+}
+
 impl From<ModuleEvent> for abci::Event {
     fn from(event: ModuleEvent) -> Self {
         let attributes = event.attributes.into_iter().map(Into::into).collect();
@@ -31,6 +92,16 @@ impl From<ModuleEvent> for abci::Event {
     }
 }
 
+impl From<abci::Event> for ModuleEvent {
+    fn from(event: abci::Event) -> Self {
+        let attributes = event.attributes.into_iter().map(Into::into).collect();
+        Self {
+            kind: event.kind,
+            attributes,
+        }
+    }
+}
+
 ///  A single key/value pair in a [`ModuleEvent`]
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -65,3 +136,42 @@ impl From<ModuleEventAttribute> for abci::EventAttribute {
         (attr.key, attr.value).into()
     }
 }
+
+impl From<abci::EventAttribute> for ModuleEventAttribute {
+    fn from(attr: abci::EventAttribute) -> Self {
+        Self {
+            key: attr.key,
+            value: attr.value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_event_attributes() {
+        let mut event = ModuleEvent::new("test", vec![("amount", 42).into()]);
+
+        assert_eq!(event.attribute("amount"), Some("42"));
+        assert_eq!(event.parse_attribute::<u64>("amount"), Ok(42));
+        assert!(event.expect_kind("test").is_ok());
+        assert!(matches!(
+            event.expect_kind("other"),
+            Err(AbciEventError::UnexpectedEventType { .. })
+        ));
+        assert!(matches!(
+            event.parse_attribute::<u64>("memo"),
+            Err(AbciEventError::MissingAttribute { .. })
+        ));
+
+        event.set_attribute("amount", 7);
+        event.set_attribute("memo", "rewritten");
+
+        assert_eq!(
+            event.attributes,
+            vec![("amount", 7).into(), ("memo", "rewritten").into()]
+        );
+    }
+}
@@ -15,6 +15,7 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod capability;
 pub mod error;
 pub mod event;
 pub mod module;
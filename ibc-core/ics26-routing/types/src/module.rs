@@ -71,4 +71,20 @@ impl ModuleExtras {
             log: Vec::new(),
         }
     }
+
+    /// Appends `event`, typically a typed application event, to the events.
+    pub fn push_event(&mut self, event: impl Into<ModuleEvent>) {
+        self.events.push(event.into());
+    }
+
+    pub fn push_log(&mut self, message: impl ToString) {
+        self.log.push(message.to_string());
+    }
+
+    /// Appends the events and log of `other`, e.g. those of the module
+    /// wrapped by a middleware.
+    pub fn extend(&mut self, other: ModuleExtras) {
+        self.events.extend(other.events);
+        self.log.extend(other.log);
+    }
 }
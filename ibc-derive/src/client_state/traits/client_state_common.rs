@@ -53,6 +53,12 @@ pub(crate) fn impl_ClientStateCommon(
         quote! {verify_non_membership(cs, prefix, proof, root, path)},
         imports,
     );
+    let decode_multihop_consensus_state_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {decode_multihop_consensus_state(cs, consensus_state)},
+        imports,
+    );
 
     let HostClientState = client_state_enum_name;
 
@@ -65,6 +71,7 @@ pub(crate) fn impl_ClientStateCommon(
     let ClientError = imports.client_error();
     let Height = imports.height();
     let Path = imports.path();
+    let Timestamp = imports.timestamp();
 
     quote! {
         impl #ClientStateCommon for #HostClientState {
@@ -128,6 +135,15 @@ pub(crate) fn impl_ClientStateCommon(
                     #(#verify_non_membership_impl),*
                 }
             }
+
+            fn decode_multihop_consensus_state(
+                &self,
+                consensus_state: #Any,
+            ) -> core::result::Result<(#CommitmentRoot, #Timestamp), #ClientError> {
+                match self {
+                    #(#decode_multihop_consensus_state_impl),*
+                }
+            }
         }
 
     }
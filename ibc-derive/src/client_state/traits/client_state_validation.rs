@@ -29,6 +29,14 @@ pub(crate) fn impl_ClientStateValidation(
         imports,
     );
 
+    let misbehaviour_evidence_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { misbehaviour_evidence(cs, ctx, client_id, client_message) },
+        imports,
+    );
+
     let status_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
@@ -37,6 +45,22 @@ pub(crate) fn impl_ClientStateValidation(
         imports,
     );
 
+    let is_within_expiry_grace_period_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { is_within_expiry_grace_period(cs, ctx, client_id, grace_period) },
+        imports,
+    );
+
+    let time_until_expiry_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { time_until_expiry(cs, ctx, client_id) },
+        imports,
+    );
+
     let check_substitute_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
@@ -50,6 +74,7 @@ pub(crate) fn impl_ClientStateValidation(
     let ClientId = imports.client_id();
     let ClientError = imports.client_error();
     let ClientStateValidation = imports.client_state_validation();
+    let MisbehaviourEvidence = imports.misbehaviour_evidence();
     let Status = imports.status();
 
     // The types we need for the generated code.
@@ -87,6 +112,17 @@ pub(crate) fn impl_ClientStateValidation(
                 }
             }
 
+            fn misbehaviour_evidence(
+                &self,
+                ctx: &#V,
+                client_id: &#ClientId,
+                client_message: #Any,
+            ) -> core::result::Result<core::option::Option<#MisbehaviourEvidence>, #ClientError> {
+                match self {
+                    #(#misbehaviour_evidence_impl),*
+                }
+            }
+
             fn status(
                 &self,
                 ctx: &#V,
@@ -97,6 +133,27 @@ pub(crate) fn impl_ClientStateValidation(
                 }
             }
 
+            fn is_within_expiry_grace_period(
+                &self,
+                ctx: &#V,
+                client_id: &#ClientId,
+                grace_period: core::time::Duration,
+            ) -> core::result::Result<bool, #ClientError> {
+                match self {
+                    #(#is_within_expiry_grace_period_impl),*
+                }
+            }
+
+            fn time_until_expiry(
+                &self,
+                ctx: &#V,
+                client_id: &#ClientId,
+            ) -> core::result::Result<core::option::Option<core::time::Duration>, #ClientError> {
+                match self {
+                    #(#time_until_expiry_impl),*
+                }
+            }
+
             fn check_substitute(
                 &self,
                 ctx: &#V,
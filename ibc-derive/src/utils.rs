@@ -100,6 +100,11 @@ impl Imports {
         quote! {#prefix::primitives::Timestamp}
     }
 
+    pub fn misbehaviour_evidence(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::client::types::MisbehaviourEvidence}
+    }
+
     pub fn status(&self) -> TokenStream {
         let prefix = self.prefix();
         quote! {#prefix::client::types::Status}
@@ -0,0 +1,54 @@
+#[cfg(feature = "rust-crypto")]
+use tendermint::crypto::default::signature::Verifier as DefaultSignatureVerifier;
+#[cfg(feature = "rust-crypto")]
+use tendermint::crypto::default::Sha256 as DefaultSha256;
+use tendermint::crypto::signature::Error as SignatureError;
+#[cfg(feature = "rust-crypto")]
+use tendermint::crypto::signature::Verifier;
+#[cfg(feature = "rust-crypto")]
+use tendermint::crypto::Sha256;
+use tendermint::{PublicKey, Signature};
+
+/// Provides the cryptographic primitives used by the IBC handlers and light
+/// clients, such as the hashing of packet commitments and the verification
+/// of header signatures.
+///
+/// Hosts running in environments that supply their own crypto, such as wasm
+/// runtimes exposing host functions or SGX enclaves, can implement this trait
+/// over it in place of the `DefaultCryptoProvider`, which the default
+/// `rust-crypto` feature compiles in.
+pub trait HostCryptoProvider {
+    /// Computes the SHA-256 digest of `data`.
+    ///
+    /// Note that computing commitments with anything other than SHA256 will
+    /// break the Merkle proofs of the IBC provable store.
+    fn sha256(data: &[u8]) -> [u8; 32];
+
+    /// Verifies that `signature` is a valid signature of `msg` by `pubkey`.
+    fn verify_signature(
+        pubkey: PublicKey,
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), SignatureError>;
+}
+
+/// The [`HostCryptoProvider`] backed by the RustCrypto implementations the
+/// `tendermint` crate is compiled with.
+#[cfg(feature = "rust-crypto")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultCryptoProvider;
+
+#[cfg(feature = "rust-crypto")]
+impl HostCryptoProvider for DefaultCryptoProvider {
+    fn sha256(data: &[u8]) -> [u8; 32] {
+        DefaultSha256::digest(data)
+    }
+
+    fn verify_signature(
+        pubkey: PublicKey,
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), SignatureError> {
+        DefaultSignatureVerifier::verify(pubkey, msg, signature)
+    }
+}
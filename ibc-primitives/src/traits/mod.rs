@@ -1,3 +1,7 @@
+mod crypto;
 mod proto;
+mod time;
 
+pub use crypto::*;
 pub use proto::*;
+pub use time::*;
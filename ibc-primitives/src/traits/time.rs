@@ -0,0 +1,36 @@
+use core::fmt::Debug;
+
+use crate::Timestamp;
+
+/// Provides the current time of the host.
+///
+/// Hosts running in environments without a system clock, such as wasm
+/// runtimes or no_std targets, implement this trait over the time they are
+/// given, e.g. the block time, in place of the [`StdTimeProvider`].
+pub trait HostTimeProvider: Debug {
+    /// Returns the current time of the host.
+    fn now(&self) -> Timestamp;
+}
+
+/// The [`HostTimeProvider`] reading the system clock.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StdTimeProvider;
+
+#[cfg(feature = "std")]
+impl HostTimeProvider for StdTimeProvider {
+    fn now(&self) -> Timestamp {
+        Timestamp::now()
+    }
+}
+
+/// The [`HostTimeProvider`] always returning the same time, for
+/// deterministic environments and tests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedTimeProvider(pub Timestamp);
+
+impl HostTimeProvider for FixedTimeProvider {
+    fn now(&self) -> Timestamp {
+        self.0
+    }
+}
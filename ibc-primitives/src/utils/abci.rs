@@ -0,0 +1,141 @@
+//! Decoding utilities for the attributes of ABCI events.
+
+use core::str::FromStr;
+
+use displaydoc::Display;
+use tendermint::abci;
+
+use crate::prelude::*;
+
+/// Errors raised while decoding an ABCI event into an IBC event.
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
+pub enum AbciEventError {
+    /// unexpected event type `{kind}`, expected `{expected}`
+    UnexpectedEventType { kind: String, expected: String },
+    /// missing attribute `{key}` in `{kind}` event
+    MissingAttribute { kind: String, key: String },
+    /// invalid value `{value}` for attribute `{key}` in `{kind}` event
+    InvalidAttribute {
+        kind: String,
+        key: String,
+        value: String,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AbciEventError {}
+
+/// A view of the attributes of an ABCI event, looked up by their key.
+pub struct EventAttributes<'a> {
+    event: &'a abci::Event,
+}
+
+impl<'a> EventAttributes<'a> {
+    /// Returns the attributes of `event`, checking it is of the `expected`
+    /// type.
+    pub fn new(event: &'a abci::Event, expected: &str) -> Result<Self, AbciEventError> {
+        if event.kind != expected {
+            return Err(AbciEventError::UnexpectedEventType {
+                kind: event.kind.clone(),
+                expected: expected.to_string(),
+            });
+        }
+
+        Ok(Self { event })
+    }
+
+    pub fn kind(&self) -> &'a str {
+        &self.event.kind
+    }
+
+    /// Returns the value of the first attribute under `key`, if any.
+    pub fn find(&self, key: &str) -> Option<&'a str> {
+        self.event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+    }
+
+    /// Returns the value of the first attribute under `key`.
+    pub fn get(&self, key: &str) -> Result<&'a str, AbciEventError> {
+        self.find(key)
+            .ok_or_else(|| AbciEventError::MissingAttribute {
+                kind: self.event.kind.clone(),
+                key: key.to_string(),
+            })
+    }
+
+    /// Parses the value of the attribute under `key`.
+    pub fn parse<T: FromStr>(&self, key: &str) -> Result<T, AbciEventError> {
+        let value = self.get(key)?;
+        value.parse().map_err(|_| self.invalid(key, value))
+    }
+
+    /// Parses the value of the attribute under `key`, left empty when unset.
+    pub fn parse_optional<T: FromStr>(&self, key: &str) -> Result<Option<T>, AbciEventError> {
+        match self.get(key)? {
+            "" => Ok(None),
+            _ => self.parse(key).map(Some),
+        }
+    }
+
+    /// Parses the comma-separated values of the attribute under `key`.
+    pub fn parse_list<T: FromStr>(&self, key: &str) -> Result<Vec<T>, AbciEventError> {
+        match self.get(key)? {
+            "" => Ok(Vec::new()),
+            value => value
+                .split(',')
+                .map(|item| item.parse().map_err(|_| self.invalid(key, value)))
+                .collect(),
+        }
+    }
+
+    /// Returns the error for an invalid `value` of the attribute under `key`.
+    pub fn invalid(&self, key: &str, value: &str) -> AbciEventError {
+        AbciEventError::InvalidAttribute {
+            kind: self.event.kind.clone(),
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> abci::Event {
+        abci::Event {
+            kind: "test".to_string(),
+            attributes: vec![
+                ("number", "42").into(),
+                ("empty", "").into(),
+                ("list", "1,2,3").into(),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_event_attributes() {
+        let event = event();
+        let attributes = EventAttributes::new(&event, "test").expect("no error");
+
+        assert_eq!(attributes.parse::<u64>("number"), Ok(42));
+        assert_eq!(attributes.parse_optional::<u64>("empty"), Ok(None));
+        assert_eq!(attributes.parse_list::<u64>("list"), Ok(vec![1, 2, 3]));
+        assert_eq!(attributes.parse_list::<u64>("empty"), Ok(vec![]));
+        assert!(matches!(
+            attributes.parse::<u64>("missing"),
+            Err(AbciEventError::MissingAttribute { .. })
+        ));
+        assert!(matches!(
+            attributes.parse::<u64>("list"),
+            Err(AbciEventError::InvalidAttribute { .. })
+        ));
+        assert!(matches!(
+            EventAttributes::new(&event, "other"),
+            Err(AbciEventError::UnexpectedEventType { .. })
+        ));
+    }
+}
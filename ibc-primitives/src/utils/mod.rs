@@ -1,4 +1,5 @@
 //! Contains various internally-used utilities.
+pub mod abci;
 pub mod pretty;
 
 pub use pretty::*;
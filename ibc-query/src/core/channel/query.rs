@@ -1,12 +1,14 @@
 //! Provides utility functions for querying IBC channel states.
 
+use ibc::core::channel::types::packet::PacketState;
 use ibc::core::client::context::ClientValidationContext;
+use ibc::core::host::types::identifiers::Sequence;
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, Path,
     ReceiptPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::host::{ConsensusStateRef, ValidationContext};
-use ibc::primitives::prelude::format;
+use ibc::primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
 
 use super::{
@@ -23,8 +25,13 @@ use super::{
     QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
 };
 use crate::core::client::IdentifiedClientState;
-use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::context::{ack_state, commitment_state, ProvableContext, QueryContext};
 use crate::error::QueryError;
+use crate::types::{PageRequest, PageResponse};
+
+/// The number of entries of a page whose request leaves its limit unset, as in
+/// the Cosmos SDK.
+const DEFAULT_PAGE_LIMIT: u64 = 100;
 
 /// Queries for a specific IBC channel by the given channel and port ids and
 /// returns the channel end with the associated proof.
@@ -255,16 +262,19 @@ where
 {
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
-    let commitments = ibc_ctx
-        .packet_commitments(&channel_end_path)?
-        .into_iter()
-        .map(Into::into)
-        .collect();
+    let (commitments, pagination) =
+        packet_states_page(request.pagination.as_ref(), |start, limit| {
+            Ok(ibc_ctx
+                .commitments_page(&channel_end_path, start, limit)?
+                .into_iter()
+                .map(commitment_state)
+                .collect())
+        })?;
 
     Ok(QueryPacketCommitmentsResponse::new(
         commitments,
         ibc_ctx.host_height()?,
-        None,
+        pagination,
     ))
 }
 
@@ -353,16 +363,27 @@ where
 
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
-    let acknowledgements = ibc_ctx
-        .packet_acknowledgements(&channel_end_path, commitment_sequences)?
-        .into_iter()
-        .map(Into::into)
-        .collect();
+    // As in ibc-go, the pagination only applies when listing all the
+    // acknowledgements of the channel.
+    let (acknowledgements, pagination) = if commitment_sequences.len() == 0 {
+        packet_states_page(request.pagination.as_ref(), |start, limit| {
+            Ok(ibc_ctx
+                .acknowledgements_page(&channel_end_path, start, limit)?
+                .into_iter()
+                .map(ack_state)
+                .collect())
+        })?
+    } else {
+        (
+            ibc_ctx.packet_acknowledgements(&channel_end_path, commitment_sequences)?,
+            None,
+        )
+    };
 
     Ok(QueryPacketAcknowledgementsResponse::new(
         acknowledgements,
         ibc_ctx.host_height()?,
-        None,
+        pagination,
     ))
 }
 
@@ -476,3 +497,61 @@ where
         proof_height,
     ))
 }
+
+/// Reads the page of packet states requested by `pagination` through `page`,
+/// which returns at most `limit` packet states from the sequence `start`.
+///
+/// The key of the pages is the big-endian encoding of the sequence they start
+/// from. All the packet states are returned if no page is requested.
+fn packet_states_page(
+    pagination: Option<&PageRequest>,
+    page: impl Fn(Sequence, usize) -> Result<Vec<PacketState>, QueryError>,
+) -> Result<(Vec<PacketState>, Option<PageResponse>), QueryError> {
+    let Some(pagination) = pagination else {
+        return Ok((page(Sequence::from(0), usize::MAX)?, None));
+    };
+
+    if pagination.reverse {
+        return Err(QueryError::invalid_pagination(
+            "packet states cannot be listed in reverse order",
+        ));
+    }
+
+    let limit = match pagination.limit {
+        0 => DEFAULT_PAGE_LIMIT,
+        limit => limit,
+    };
+    let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+
+    let (start, offset) = if pagination.key.is_empty() {
+        let offset = usize::try_from(pagination.offset).unwrap_or(usize::MAX);
+        (Sequence::from(0), offset)
+    } else {
+        let key = <[u8; 8]>::try_from(pagination.key.as_slice()).map_err(|_| {
+            QueryError::invalid_pagination("the page key is not a big-endian sequence")
+        })?;
+        (Sequence::from(u64::from_be_bytes(key)), 0)
+    };
+
+    // Reads one more packet state than requested to tell where the next page
+    // starts.
+    let mut states: Vec<_> = page(start, offset.saturating_add(limit).saturating_add(1))?
+        .into_iter()
+        .skip(offset)
+        .collect();
+
+    let next_key = if states.len() > limit {
+        let next = states.split_off(limit);
+        next[0].seq.value().to_be_bytes().to_vec()
+    } else {
+        Vec::new()
+    };
+
+    let total = if pagination.count_total && pagination.key.is_empty() {
+        page(Sequence::from(0), usize::MAX)?.len() as u64
+    } else {
+        0
+    };
+
+    Ok((states, Some(PageResponse { next_key, total })))
+}
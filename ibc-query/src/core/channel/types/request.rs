@@ -45,6 +45,15 @@ impl TryFrom<RawQueryChannelRequest> for QueryChannelRequest {
     }
 }
 
+impl From<QueryChannelRequest> for RawQueryChannelRequest {
+    fn from(request: QueryChannelRequest) -> Self {
+        Self {
+            port_id: request.port_id.to_string(),
+            channel_id: request.channel_id.to_string(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying all channels
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -61,6 +70,14 @@ impl From<RawQueryChannelsRequest> for QueryChannelsRequest {
     }
 }
 
+impl From<QueryChannelsRequest> for RawQueryChannelsRequest {
+    fn from(request: QueryChannelsRequest) -> Self {
+        Self {
+            pagination: request.pagination.map(Into::into),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying all channels associated
 /// with a connection identifier
 #[derive(Clone, Debug)]
@@ -82,6 +99,15 @@ impl TryFrom<RawQueryConnectionChannelsRequest> for QueryConnectionChannelsReque
     }
 }
 
+impl From<QueryConnectionChannelsRequest> for RawQueryConnectionChannelsRequest {
+    fn from(request: QueryConnectionChannelsRequest) -> Self {
+        Self {
+            connection: request.connection_id.to_string(),
+            pagination: request.pagination.map(Into::into),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying the client state associated
 /// with a channel
 #[derive(Clone, Debug)]
@@ -105,6 +131,15 @@ impl TryFrom<RawQueryChannelClientStateRequest> for QueryChannelClientStateReque
     }
 }
 
+impl From<QueryChannelClientStateRequest> for RawQueryChannelClientStateRequest {
+    fn from(request: QueryChannelClientStateRequest) -> Self {
+        Self {
+            port_id: request.port_id.to_string(),
+            channel_id: request.channel_id.to_string(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying the consensus state
 /// associated with a channel
 #[derive(Clone, Debug)]
@@ -130,6 +165,17 @@ impl TryFrom<RawQueryChannelConsensusStateRequest> for QueryChannelConsensusStat
     }
 }
 
+impl From<QueryChannelConsensusStateRequest> for RawQueryChannelConsensusStateRequest {
+    fn from(request: QueryChannelConsensusStateRequest) -> Self {
+        Self {
+            port_id: request.port_id.to_string(),
+            channel_id: request.channel_id.to_string(),
+            revision_number: request.consensus_height.revision_number(),
+            revision_height: request.consensus_height.revision_height(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying the packet commitment
 /// associated with the specified channel
 #[derive(Clone, Debug)]
@@ -155,6 +201,16 @@ impl TryFrom<RawQueryPacketCommitmentRequest> for QueryPacketCommitmentRequest {
     }
 }
 
+impl From<QueryPacketCommitmentRequest> for RawQueryPacketCommitmentRequest {
+    fn from(request: QueryPacketCommitmentRequest) -> Self {
+        Self {
+            port_id: request.port_id.to_string(),
+            channel_id: request.channel_id.to_string(),
+            sequence: request.sequence.into(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying all packet commitments
 /// associated with the specified channel
 #[derive(Clone, Debug)]
@@ -213,6 +269,16 @@ impl TryFrom<RawQueryPacketReceiptRequest> for QueryPacketReceiptRequest {
     }
 }
 
+impl From<QueryPacketReceiptRequest> for RawQueryPacketReceiptRequest {
+    fn from(request: QueryPacketReceiptRequest) -> Self {
+        Self {
+            port_id: request.port_id.to_string(),
+            channel_id: request.channel_id.to_string(),
+            sequence: request.sequence.into(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying the packet acknowledgement
 /// associated with the specified channel and sequence number
 #[derive(Clone, Debug)]
@@ -238,6 +304,16 @@ impl TryFrom<RawQueryPacketAcknowledgementRequest> for QueryPacketAcknowledgemen
     }
 }
 
+impl From<QueryPacketAcknowledgementRequest> for RawQueryPacketAcknowledgementRequest {
+    fn from(request: QueryPacketAcknowledgementRequest) -> Self {
+        Self {
+            port_id: request.port_id.to_string(),
+            channel_id: request.channel_id.to_string(),
+            sequence: request.sequence.into(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying the packet acknowledgements
 /// associated with the specified channel
 #[derive(Clone, Debug)]
@@ -386,6 +462,15 @@ impl TryFrom<RawQueryNextSequenceReceiveRequest> for QueryNextSequenceReceiveReq
         })
     }
 }
+
+impl From<QueryNextSequenceReceiveRequest> for RawQueryNextSequenceReceiveRequest {
+    fn from(request: QueryNextSequenceReceiveRequest) -> Self {
+        Self {
+            port_id: request.port_id.to_string(),
+            channel_id: request.channel_id.to_string(),
+        }
+    }
+}
 /// Defines the RPC method request type for querying the next sequence send
 /// number for the specified channel
 #[derive(Clone, Debug)]
@@ -408,3 +493,12 @@ impl TryFrom<RawQueryNextSequenceSendRequest> for QueryNextSequenceSendRequest {
         })
     }
 }
+
+impl From<QueryNextSequenceSendRequest> for RawQueryNextSequenceSendRequest {
+    fn from(request: QueryNextSequenceSendRequest) -> Self {
+        Self {
+            port_id: request.port_id.to_string(),
+            channel_id: request.channel_id.to_string(),
+        }
+    }
+}
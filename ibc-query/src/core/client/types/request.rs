@@ -42,6 +42,14 @@ impl TryFrom<RawQueryClientStateRequest> for QueryClientStateRequest {
     }
 }
 
+impl From<QueryClientStateRequest> for RawQueryClientStateRequest {
+    fn from(request: QueryClientStateRequest) -> Self {
+        Self {
+            client_id: request.client_id.to_string(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying all client states.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -58,6 +66,14 @@ impl From<RawQueryClientStatesRequest> for QueryClientStatesRequest {
     }
 }
 
+impl From<QueryClientStatesRequest> for RawQueryClientStatesRequest {
+    fn from(request: QueryClientStatesRequest) -> Self {
+        Self {
+            pagination: request.pagination.map(Into::into),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying the consensus state of a
 /// client.
 #[derive(Clone, Debug)]
@@ -88,6 +104,21 @@ impl TryFrom<RawQueryConsensusStateRequest> for QueryConsensusStateRequest {
     }
 }
 
+impl From<QueryConsensusStateRequest> for RawQueryConsensusStateRequest {
+    fn from(request: QueryConsensusStateRequest) -> Self {
+        Self {
+            client_id: request.client_id.to_string(),
+            revision_number: request
+                .consensus_height
+                .map_or(0, |height| height.revision_number()),
+            revision_height: request
+                .consensus_height
+                .map_or(0, |height| height.revision_height()),
+            latest_height: request.consensus_height.is_none(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying all consensus states.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -179,6 +210,14 @@ impl TryFrom<RawQueryClientStatusRequest> for QueryClientStatusRequest {
     }
 }
 
+impl From<QueryClientStatusRequest> for RawQueryClientStatusRequest {
+    fn from(request: QueryClientStatusRequest) -> Self {
+        Self {
+            client_id: request.client_id.to_string(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying the parameters of a client.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -193,6 +232,12 @@ impl From<RawQueryClientParamsRequest> for QueryClientParamsRequest {
     }
 }
 
+impl From<QueryClientParamsRequest> for RawQueryClientParamsRequest {
+    fn from(_request: QueryClientParamsRequest) -> Self {
+        Self {}
+    }
+}
+
 /// Defines the RPC method request type for querying the upgraded client state.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -214,6 +259,12 @@ impl From<RawUpgradedClientStateRequest> for QueryUpgradedClientStateRequest {
     }
 }
 
+impl From<QueryUpgradedClientStateRequest> for RawUpgradedClientStateRequest {
+    fn from(_request: QueryUpgradedClientStateRequest) -> Self {
+        Self {}
+    }
+}
+
 /// Defines the RPC method request type for querying the upgraded consensus
 /// state.
 #[derive(Clone, Debug)]
@@ -235,3 +286,9 @@ impl From<RawUpgradedConsensusStateRequest> for QueryUpgradedConsensusStateReque
         }
     }
 }
+
+impl From<QueryUpgradedConsensusStateRequest> for RawUpgradedConsensusStateRequest {
+    fn from(_request: QueryUpgradedConsensusStateRequest) -> Self {
+        Self {}
+    }
+}
@@ -1,6 +1,7 @@
 //! Provides utility functions for querying IBC connection states.
 
 use ibc::core::client::context::ClientValidationContext;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::host::types::path::{
     ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
 };
@@ -194,7 +195,16 @@ pub fn query_connection_params<I>(
 where
     I: QueryContext,
 {
+    let max_expected_time_per_block = ibc_ctx.max_expected_time_per_block().as_nanos();
+    let max_expected_time_per_block = u64::try_from(max_expected_time_per_block).map_err(|_| {
+        ConnectionError::InvalidConnectionParams {
+            description: format!(
+                "max expected time per block too long: {max_expected_time_per_block} nanos"
+            ),
+        }
+    })?;
+
     Ok(QueryConnectionParamsResponse::new(
-        ibc_ctx.max_expected_time_per_block().as_secs(),
+        max_expected_time_per_block,
     ))
 }
@@ -36,6 +36,14 @@ impl TryFrom<RawQueryConnectionRequest> for QueryConnectionRequest {
     }
 }
 
+impl From<QueryConnectionRequest> for RawQueryConnectionRequest {
+    fn from(request: QueryConnectionRequest) -> Self {
+        Self {
+            connection_id: request.connection_id.to_string(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying connections.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -52,6 +60,14 @@ impl From<RawQueryConnectionsRequest> for QueryConnectionsRequest {
     }
 }
 
+impl From<QueryConnectionsRequest> for RawQueryConnectionsRequest {
+    fn from(request: QueryConnectionsRequest) -> Self {
+        Self {
+            pagination: request.pagination.map(Into::into),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying connections associated with
 /// a client.
 #[derive(Clone, Debug)]
@@ -73,6 +89,14 @@ impl TryFrom<RawQueryClientConnectionsRequest> for QueryClientConnectionsRequest
     }
 }
 
+impl From<QueryClientConnectionsRequest> for RawQueryClientConnectionsRequest {
+    fn from(request: QueryClientConnectionsRequest) -> Self {
+        Self {
+            client_id: request.client_id.to_string(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying the client state associated
 /// with a connection.
 #[derive(Clone, Debug)]
@@ -94,6 +118,14 @@ impl TryFrom<RawQueryConnectionClientStateRequest> for QueryConnectionClientStat
     }
 }
 
+impl From<QueryConnectionClientStateRequest> for RawQueryConnectionClientStateRequest {
+    fn from(request: QueryConnectionClientStateRequest) -> Self {
+        Self {
+            connection_id: request.connection_id.to_string(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying the consensus state
 /// associated with a connection.
 #[derive(Clone, Debug)]
@@ -117,6 +149,16 @@ impl TryFrom<RawQueryConnectionConsensusStateRequest> for QueryConnectionConsens
     }
 }
 
+impl From<QueryConnectionConsensusStateRequest> for RawQueryConnectionConsensusStateRequest {
+    fn from(request: QueryConnectionConsensusStateRequest) -> Self {
+        Self {
+            connection_id: request.connection_id.to_string(),
+            revision_number: request.height.revision_number(),
+            revision_height: request.height.revision_height(),
+        }
+    }
+}
+
 /// Defines the RPC method request type for querying the connection parameters.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -130,3 +172,9 @@ impl From<RawQueryConnectionParamsRequest> for QueryConnectionParamsRequest {
         Self { query_height: None }
     }
 }
+
+impl From<QueryConnectionParamsRequest> for RawQueryConnectionParamsRequest {
+    fn from(_request: QueryConnectionParamsRequest) -> Self {
+        Self {}
+    }
+}
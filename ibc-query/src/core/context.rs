@@ -1,13 +1,16 @@
 //! Required traits for blanket implementations of [`gRPC query services`](crate::core).
 
-use ibc::core::channel::types::channel::IdentifiedChannelEnd;
+use ibc::core::channel::types::channel::{IdentifiedChannelEnd, Order};
+use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::packet::PacketState;
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::IdentifiedConnectionEnd;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
-use ibc::core::host::types::path::{ChannelEndPath, Path};
-use ibc::core::host::{ClientStateRef, ConsensusStateRef, ValidationContext};
+use ibc::core::host::types::path::{
+    AckPath, ChannelEndPath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
+};
+use ibc::core::host::{ClientStateRef, ConsensusStateRef, IterableContext};
 use ibc::core::primitives::prelude::*;
 
 /// Context to be implemented by the host to provide proofs in query responses
@@ -17,37 +20,67 @@ pub trait ProvableContext {
     fn get_proof(&self, height: Height, path: &Path) -> Option<Vec<u8>>;
 }
 
-/// Context to be implemented by the host that provides gRPC query services.
-pub trait QueryContext: ProvableContext + ValidationContext {
+/// Context providing the entries listed by the gRPC query services.
+///
+/// It is implemented for any host able to iterate over its store and to prove
+/// its entries, i.e. implementing both [`IterableContext`] and
+/// [`ProvableContext`].
+pub trait QueryContext: ProvableContext + IterableContext {
     // Client queries
 
     /// Returns the list of all clients.
-    fn client_states(&self) -> Result<Vec<(ClientId, ClientStateRef<Self>)>, ContextError>;
+    fn client_states(&self) -> Result<Vec<(ClientId, ClientStateRef<Self>)>, ContextError> {
+        self.all_client_states()
+    }
 
     /// Returns the list of all consensus states for the given client.
     fn consensus_states(
         &self,
         client_id: &ClientId,
-    ) -> Result<Vec<(Height, ConsensusStateRef<Self>)>, ContextError>;
+    ) -> Result<Vec<(Height, ConsensusStateRef<Self>)>, ContextError> {
+        self.iter_consensus_states(client_id)?
+            .into_iter()
+            .map(|(path, consensus_state)| {
+                Ok((
+                    Height::new(path.revision_number, path.revision_height)?,
+                    consensus_state,
+                ))
+            })
+            .collect()
+    }
 
     /// Returns the list of all heights at which consensus states for the given client are.
-    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        self.iter_consensus_states(client_id)?
+            .into_iter()
+            .map(|(path, _)| Ok(Height::new(path.revision_number, path.revision_height)?))
+            .collect()
+    }
 
     // Connection queries
 
     /// Returns the list of all connection ends.
-    fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError>;
+    fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError> {
+        self.all_connection_ends()
+    }
 
     /// Returns the list of all connection ids of the given client.
+    ///
+    /// Defaults to
+    /// [`ConnectionValidationContext::connections_for_client`](ibc::core::host::ConnectionValidationContext::connections_for_client).
     fn client_connection_ends(
         &self,
         client_id: &ClientId,
-    ) -> Result<Vec<ConnectionId>, ContextError>;
+    ) -> Result<Vec<ConnectionId>, ContextError> {
+        self.connections_for_client(client_id)
+    }
 
     // Channel queries
 
     /// Returns the list of all channel ends.
-    fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError>;
+    fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError> {
+        self.all_channel_ends()
+    }
 
     // Packet queries
 
@@ -55,7 +88,13 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     fn packet_commitments(
         &self,
         channel_end_path: &ChannelEndPath,
-    ) -> Result<Vec<PacketState>, ContextError>;
+    ) -> Result<Vec<PacketState>, ContextError> {
+        Ok(self
+            .iter_commitments(channel_end_path)?
+            .into_iter()
+            .map(commitment_state)
+            .collect())
+    }
 
     /// Filters the list of packet sequences for the given channel end that are acknowledged.
     /// Returns all the packet acknowledgements if `sequences` is empty.
@@ -63,20 +102,99 @@ pub trait QueryContext: ProvableContext + ValidationContext {
         &self,
         channel_end_path: &ChannelEndPath,
         sequences: impl ExactSizeIterator<Item = Sequence>,
-    ) -> Result<Vec<PacketState>, ContextError>;
+    ) -> Result<Vec<PacketState>, ContextError> {
+        if sequences.len() == 0 {
+            return Ok(self
+                .iter_acknowledgements(channel_end_path)?
+                .into_iter()
+                .map(ack_state)
+                .collect());
+        }
+
+        Ok(sequences
+            .filter_map(|seq| {
+                let ack_path = AckPath::new(&channel_end_path.0, &channel_end_path.1, seq);
+                self.get_packet_acknowledgement(&ack_path)
+                    .ok()
+                    .map(|ack| ack_state((ack_path, ack)))
+            })
+            .collect())
+    }
 
     /// Filters the packet sequences for the given channel end that are not received.
+    ///
+    /// On ordered channels, these are the sequences from the next one to be
+    /// received on. On unordered channels, these are the ones without receipt.
     fn unreceived_packets(
         &self,
         channel_end_path: &ChannelEndPath,
         sequences: impl ExactSizeIterator<Item = Sequence>,
-    ) -> Result<Vec<Sequence>, ContextError>;
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let channel_end = self.channel_end(channel_end_path)?;
+
+        if channel_end.ordering == Order::Ordered {
+            let seq_recv_path = SeqRecvPath::new(&channel_end_path.0, &channel_end_path.1);
+            let next_seq_recv = self.get_next_sequence_recv(&seq_recv_path)?;
+
+            return Ok(sequences.filter(|seq| *seq >= next_seq_recv).collect());
+        }
+
+        Ok(sequences
+            .filter(|seq| {
+                let receipt_path = ReceiptPath::new(&channel_end_path.0, &channel_end_path.1, *seq);
+                self.get_packet_receipt(&receipt_path).is_err()
+            })
+            .collect())
+    }
 
     /// Filters the list of packet sequences for the given channel end whose acknowledgement is not received.
     /// Returns all the unreceived acknowledgements if `sequences` is empty.
+    ///
+    /// These are the sequences whose packet commitment is still stored.
     fn unreceived_acks(
         &self,
         channel_end_path: &ChannelEndPath,
         sequences: impl ExactSizeIterator<Item = Sequence>,
-    ) -> Result<Vec<Sequence>, ContextError>;
+    ) -> Result<Vec<Sequence>, ContextError> {
+        if sequences.len() == 0 {
+            return Ok(self
+                .iter_commitments(channel_end_path)?
+                .into_iter()
+                .map(|(path, _)| path.sequence)
+                .collect());
+        }
+
+        Ok(sequences
+            .filter(|seq| {
+                let commitment_path =
+                    CommitmentPath::new(&channel_end_path.0, &channel_end_path.1, *seq);
+                self.get_packet_commitment(&commitment_path).is_ok()
+            })
+            .collect())
+    }
+}
+
+impl<T> QueryContext for T where T: ProvableContext + IterableContext {}
+
+/// Converts a stored packet commitment into its [`PacketState`].
+pub(crate) fn commitment_state(
+    (path, commitment): (CommitmentPath, PacketCommitment),
+) -> PacketState {
+    PacketState {
+        port_id: path.port_id,
+        chan_id: path.channel_id,
+        seq: path.sequence,
+        data: commitment.into_vec(),
+    }
+}
+
+/// Converts a stored packet acknowledgement commitment into its
+/// [`PacketState`].
+pub(crate) fn ack_state((path, ack): (AckPath, AcknowledgementCommitment)) -> PacketState {
+    PacketState {
+        port_id: path.port_id,
+        chan_id: path.channel_id,
+        seq: path.sequence,
+        data: ack.into_vec(),
+    }
 }
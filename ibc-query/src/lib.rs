@@ -6,9 +6,8 @@
 //!
 //! The provided structs includes blanket implementation of their corresponding
 //! gRPC service traits, if the host implements the following _context_ traits:
-//! - [`ValidationContext`](ibc::core::host::ValidationContext)
+//! - [`IterableContext`](ibc::core::host::IterableContext)
 //! - [`ProvableContext`](crate::core::context::ProvableContext)
-//! - [`QueryContext`](crate::core::context::QueryContext)
 //! - [`UpgradeValidationContext`](ibc::cosmos_host::upgrade_proposal::UpgradeValidationContext)
 //!   - Only for
 //!     [`ClientQuery::upgraded_client_state`](ibc_proto::ibc::core::client::v1::query_server::Query::upgraded_client_state)
@@ -22,15 +21,23 @@
 //!     client::v1::query_server::QueryServer as ClientQueryServer,
 //!     connection::v1::query_server::QueryServer as ConnectionQueryServer,
 //! }
-//! use ibc::core::ValidationContext;
+//! use ibc::core::host::{
+//!     ChannelValidationContext, ClientRegistryValidationContext, ConnectionEndValidationContext,
+//!     ConnectionValidationContext, HostValidationContext, IterableContext, PacketValidationContext,
+//! };
 //! use ibc::hosts::tendermint::upgrade_proposal::UpgradeValidationContext;
-//! use ibc::services::core::{ProvableContext, QueryContext};
+//! use ibc::services::core::ProvableContext;
 //! use ibc::services::{ChannelQueryService, ClientQueryService, ConnectionQueryService};
 //!
 //! struct Ibc;
-//! impl ValidationContext for Ibc { }
+//! impl HostValidationContext for Ibc { }
+//! impl ClientRegistryValidationContext for Ibc { }
+//! impl ConnectionEndValidationContext for Ibc { }
+//! impl ConnectionValidationContext for Ibc { }
+//! impl ChannelValidationContext for Ibc { }
+//! impl PacketValidationContext for Ibc { }
+//! impl IterableContext for Ibc { }
 //! impl ProvableContext for Ibc { }
-//! impl QueryContext for Ibc { }
 //!
 //! struct Upgrade;
 //! impl UpgradeValidationContext for Upgrade { }
@@ -51,6 +58,14 @@
 //!       .serve(addr);
 //! ```
 //!
+//! The [`QueryContext`](crate::core::context::QueryContext) the services are
+//! generic over is implemented for any such host, and the packet commitments
+//! and acknowledgements are paginated through the pages of the
+//! `IterableContext`.
+//!
+//! With the `server` feature enabled, the [`server`] module bundles the above
+//! wiring together with the proto descriptors needed for gRPC reflection.
+//!
 
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
 #![no_std]
@@ -71,5 +86,7 @@ extern crate std;
 
 pub mod core;
 pub mod error;
+#[cfg(feature = "server")]
+pub mod server;
 pub mod types;
 pub mod utils;
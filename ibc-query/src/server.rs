@@ -0,0 +1,94 @@
+//! Provides a ready-to-use [`tonic`] server wiring of the IBC core gRPC query
+//! services, so that hosts can expose Hermes-compatible query endpoints
+//! without assembling the individual services themselves.
+//!
+//! Example
+//! ```rust,ignore
+//! use ibc_query::server::{IbcQueryServices, FILE_DESCRIPTOR_SET};
+//!
+//! // `ibc` and `upgrade` must be thread-safe and `ibc` must be cheaply
+//! // cloneable, e.g. wrapped in an `Arc`.
+//! let reflection = tonic_reflection::server::Builder::configure()
+//!     .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+//!     .build()?;
+//!
+//! IbcQueryServices::new(ibc, upgrade)
+//!     .into_router()
+//!     .add_service(reflection)
+//!     .serve(addr)
+//!     .await?;
+//! ```
+
+use ibc::core::host::ConsensusStateRef;
+use ibc::cosmos_host::upgrade_proposal::{
+    UpgradeValidationContext, UpgradedClientStateRef, UpgradedConsensusStateRef,
+};
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::channel::v1::query_server::QueryServer as ChannelQueryServer;
+use ibc_proto::ibc::core::client::v1::query_server::QueryServer as ClientQueryServer;
+use ibc_proto::ibc::core::connection::v1::query_server::QueryServer as ConnectionQueryServer;
+/// The encoded file descriptor set of the `ibc-proto` definitions, to be
+/// registered with a gRPC reflection service.
+pub use ibc_proto::FILE_DESCRIPTOR_SET;
+use tonic::transport::server::Router;
+use tonic::transport::Server;
+
+use crate::core::channel::ChannelQueryService;
+use crate::core::client::ClientQueryService;
+use crate::core::connection::ConnectionQueryService;
+use crate::core::context::{ProvableContext, QueryContext};
+
+/// Bundles the client, connection and channel gRPC query services of a host.
+pub struct IbcQueryServices<I, U>
+where
+    I: QueryContext + Clone + Send + Sync + 'static,
+    U: UpgradeValidationContext + ProvableContext + Send + Sync + 'static,
+    ConsensusStateRef<I>: Into<Any>,
+    UpgradedClientStateRef<U>: Into<Any>,
+    UpgradedConsensusStateRef<U>: Into<Any>,
+{
+    pub client: ClientQueryServer<ClientQueryService<I, U>>,
+    pub connection: ConnectionQueryServer<ConnectionQueryService<I>>,
+    pub channel: ChannelQueryServer<ChannelQueryService<I>>,
+}
+
+impl<I, U> IbcQueryServices<I, U>
+where
+    I: QueryContext + Clone + Send + Sync + 'static,
+    U: UpgradeValidationContext + ProvableContext + Send + Sync + 'static,
+    ConsensusStateRef<I>: Into<Any>,
+    UpgradedClientStateRef<U>: Into<Any>,
+    UpgradedConsensusStateRef<U>: Into<Any>,
+{
+    /// Parameters `ibc_context` and `upgrade_context` must be a type where writes from one thread are readable from another.
+    /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+    pub fn new(ibc_context: I, upgrade_context: U) -> Self {
+        Self {
+            client: ClientQueryServer::new(ClientQueryService::new(
+                ibc_context.clone(),
+                upgrade_context,
+            )),
+            connection: ConnectionQueryServer::new(ConnectionQueryService::new(
+                ibc_context.clone(),
+            )),
+            channel: ChannelQueryServer::new(ChannelQueryService::new(ibc_context)),
+        }
+    }
+
+    /// Adds the query services to an existing `tonic` router.
+    pub fn add_to(self, router: Router) -> Router {
+        router
+            .add_service(self.client)
+            .add_service(self.connection)
+            .add_service(self.channel)
+    }
+
+    /// Creates a `tonic` router serving only the query services, with the
+    /// default server configuration.
+    pub fn into_router(self) -> Router {
+        Server::builder()
+            .add_service(self.client)
+            .add_service(self.connection)
+            .add_service(self.channel)
+    }
+}
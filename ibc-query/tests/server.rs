@@ -0,0 +1,122 @@
+//! Round-trips a query through the gRPC services wired by the `server`
+//! feature, from the encoded request to the decoded response.
+#![cfg(feature = "server")]
+
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::error::UpgradeClientError;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::{Path, UpgradeClientPath};
+use ibc::cosmos_host::upgrade_proposal::{
+    Plan, UpgradeValidationContext, UpgradedClientStateRef, UpgradedConsensusStateRef,
+};
+use ibc_proto::ibc::core::client::v1::{QueryClientStatesRequest, QueryClientStatesResponse};
+use ibc_query::core::context::ProvableContext;
+use ibc_query::server::IbcQueryServices;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use prost::Message;
+use tonic::codegen::http::{header, Method, Request, StatusCode};
+use tonic::codegen::{Body as _, Service};
+use tonic::transport::Body;
+
+/// The upgrade context of a host that never schedules upgrades.
+struct NoUpgrade;
+
+impl UpgradeValidationContext for NoUpgrade {
+    type V = MockContext;
+
+    fn upgrade_plan(&self) -> Result<Plan, UpgradeClientError> {
+        Err(UpgradeClientError::InvalidUpgradePlan {
+            reason: "no upgrade scheduled".to_string(),
+        })
+    }
+
+    fn upgraded_client_state(
+        &self,
+        _upgrade_path: &UpgradeClientPath,
+    ) -> Result<UpgradedClientStateRef<Self>, UpgradeClientError> {
+        self.upgrade_plan().map(|_| unreachable!())
+    }
+
+    fn upgraded_consensus_state(
+        &self,
+        _upgrade_path: &UpgradeClientPath,
+    ) -> Result<UpgradedConsensusStateRef<Self>, UpgradeClientError> {
+        self.upgrade_plan().map(|_| unreachable!())
+    }
+}
+
+impl ProvableContext for NoUpgrade {
+    fn get_proof(&self, _height: Height, _path: &Path) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+/// Frames the message as the body of a gRPC request to `path`.
+fn grpc_request(path: &str, message: &impl Message) -> Request<Body> {
+    let encoded = message.encode_to_vec();
+
+    let mut body = vec![0];
+    body.extend_from_slice(&(encoded.len() as u32).to_be_bytes());
+    body.extend_from_slice(&encoded);
+
+    Request::builder()
+        .method(Method::POST)
+        .uri(path)
+        .header(header::CONTENT_TYPE, "application/grpc")
+        .body(Body::from(body))
+        .expect("valid request")
+}
+
+#[test]
+fn client_states_roundtrip() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("valid client id");
+    let ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_id(client_id.clone())
+            .latest_height(Height::new(0, 42).expect("valid height"))
+            .build(),
+    );
+    let client_state = ctx.client_state(&client_id).expect("client exists");
+
+    let services = IbcQueryServices::new(ctx.clone(), NoUpgrade);
+    let mut client_service = services.client.clone();
+    // the services must also be servable by a `tonic` router
+    let _ = services.into_router();
+
+    let request = grpc_request(
+        "/ibc.core.client.v1.Query/ClientStates",
+        &QueryClientStatesRequest { pagination: None },
+    );
+
+    let body = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("runtime is built")
+        .block_on(async {
+            let response = client_service.call(request).await.expect("infallible");
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let mut body = response.into_body();
+            let mut bytes = Vec::new();
+            while let Some(chunk) = body.data().await {
+                bytes.extend_from_slice(&chunk.expect("body is readable"));
+            }
+            bytes
+        });
+
+    // skip the compression flag and the length prefix of the gRPC frame
+    let response = QueryClientStatesResponse::decode(&body[5..]).expect("valid response");
+
+    assert_eq!(response.client_states.len(), 1);
+    assert_eq!(response.client_states[0].client_id, client_id.to_string());
+
+    let served_state = response.client_states[0]
+        .client_state
+        .clone()
+        .expect("client state is served");
+    assert_eq!(
+        AnyClientState::try_from(served_state).expect("valid client state"),
+        client_state
+    );
+}
@@ -0,0 +1,131 @@
+//! Generates protobuf-encoded fixtures of the IBC messages out of TOML specs,
+//! and decodes such fixtures back into specs.
+//!
+//! ```text
+//! ibc-fixture-gen encode [--encoding hex|base64] [--out-dir <DIR>] <SPEC>...
+//! ibc-fixture-gen decode [--encoding hex|base64] <FIXTURE>
+//! ```
+//!
+//! Without `--out-dir`, encoded fixtures are printed one per line. `-` reads
+//! the fixture to decode from the standard input.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::{env, fs, io};
+
+use ibc_testkit::fixtures::messages::{generate_fixture, read_fixture, Encoding, MessageSpec};
+
+const USAGE: &str = "\
+usage: ibc-fixture-gen encode [--encoding hex|base64] [--out-dir <DIR>] <SPEC>...
+       ibc-fixture-gen decode [--encoding hex|base64] <FIXTURE>";
+
+enum Command {
+    Encode {
+        encoding: Encoding,
+        out_dir: Option<PathBuf>,
+        specs: Vec<PathBuf>,
+    },
+    Decode {
+        encoding: Encoding,
+        fixture: PathBuf,
+    },
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let command = args.next().ok_or("missing command")?;
+
+    let mut encoding = Encoding::Hex;
+    let mut out_dir = None;
+    let mut paths = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--encoding" => {
+                let value = args.next().ok_or("missing value of --encoding")?;
+                encoding = value.parse().map_err(|e| format!("{e}"))?;
+            }
+            "--out-dir" => {
+                out_dir = Some(args.next().ok_or("missing value of --out-dir")?.into());
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown option {arg}")),
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+
+    match command.as_str() {
+        "encode" if !paths.is_empty() => Ok(Command::Encode {
+            encoding,
+            out_dir,
+            specs: paths,
+        }),
+        "decode" if paths.len() == 1 && out_dir.is_none() => Ok(Command::Decode {
+            encoding,
+            fixture: paths.remove(0),
+        }),
+        "encode" | "decode" => Err(format!("invalid arguments for {command}")),
+        _ => Err(format!("unknown command {command}")),
+    }
+}
+
+fn encode(encoding: Encoding, out_dir: Option<&Path>, specs: &[PathBuf]) -> Result<(), String> {
+    for path in specs {
+        let spec = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let fixture = MessageSpec::from_toml(&spec)
+            .and_then(|spec| generate_fixture(&spec, encoding))
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+
+        match out_dir {
+            Some(out_dir) => {
+                let stem = path
+                    .file_stem()
+                    .ok_or_else(|| format!("{}: not a file", path.display()))?;
+                let out_path = out_dir.join(stem).with_extension(encoding.extension());
+                fs::write(&out_path, format!("{fixture}\n"))
+                    .map_err(|e| format!("{}: {e}", out_path.display()))?;
+            }
+            None => println!("{fixture}"),
+        }
+    }
+
+    Ok(())
+}
+
+fn decode(encoding: Encoding, path: &Path) -> Result<(), String> {
+    let fixture = if path == Path::new("-") {
+        let mut fixture = String::new();
+        io::stdin()
+            .read_to_string(&mut fixture)
+            .map_err(|e| format!("stdin: {e}"))?;
+        fixture
+    } else {
+        fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?
+    };
+
+    let spec = read_fixture(&fixture, encoding)
+        .and_then(|spec| spec.to_toml())
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+
+    print!("{spec}");
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let res = parse_args(env::args().skip(1)).and_then(|command| match command {
+        Command::Encode {
+            encoding,
+            out_dir,
+            specs,
+        } => encode(encoding, out_dir.as_deref(), &specs),
+        Command::Decode { encoding, fixture } => decode(encoding, &fixture),
+    });
+
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}\n{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -4,17 +4,19 @@ use core::time::Duration;
 use ibc::clients::tendermint::client_state::ClientState as TmClientState;
 use ibc::clients::tendermint::types::error::{Error as ClientError, Error};
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
-#[cfg(feature = "serde")]
-use ibc::clients::tendermint::types::Header;
 use ibc::clients::tendermint::types::{
-    AllowUpdate, ClientState as ClientStateType, TrustThreshold,
+    AllowUpdate, ClientState as ClientStateType, Header, Misbehaviour as TmMisbehaviour,
+    TrustThreshold,
 };
 use ibc::core::client::types::proto::v1::Height as RawHeight;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::specs::ProofSpecs;
-use ibc::core::host::types::identifiers::ChainId;
+use ibc::core::host::types::identifiers::{ChainId, ClientId};
 use ibc::core::primitives::prelude::*;
 use tendermint::block::Header as TmHeader;
+use tendermint::evidence::{ConflictingBlock, LightClientAttackEvidence};
+
+use crate::hosts::block::{HostBlock, SyntheticTmBlock};
 
 /// Returns a dummy tendermint `ClientState` by given `frozen_height`, for testing purposes only!
 pub fn dummy_tm_client_state_from_raw(frozen_height: RawHeight) -> Result<TmClientState, Error> {
@@ -33,10 +35,7 @@ pub fn dummy_tm_client_state_from_header(tm_header: TmHeader) -> TmClientState {
         Height::new(chain_id.revision_number(), u64::from(tm_header.height)).expect("Never fails"),
         ProofSpecs::cosmos(),
         Vec::new(),
-        AllowUpdate {
-            after_expiry: false,
-            after_misbehaviour: false,
-        },
+        AllowUpdate::default(),
     )
     .expect("Never fails");
 
@@ -80,7 +79,7 @@ pub struct ClientStateConfig {
     pub proof_specs: ProofSpecs,
     #[builder(default)]
     pub upgrade_path: Vec<String>,
-    #[builder(default = AllowUpdate { after_expiry: false, after_misbehaviour: false })]
+    #[builder(default)]
     allow_update: AllowUpdate,
 }
 
@@ -104,6 +103,53 @@ impl TryFrom<ClientStateConfig> for TmClientState {
     }
 }
 
+/// Builds a Tendermint `Misbehaviour` for `client_id` out of two conflicting
+/// host blocks, both trusting the client at `trusted_height`.
+///
+/// Returns `None` if any of the blocks is not a synthetic Tendermint block.
+pub fn tm_misbehaviour_from_host_blocks(
+    client_id: ClientId,
+    trusted_height: Height,
+    block_a: HostBlock,
+    block_b: HostBlock,
+) -> Option<TmMisbehaviour> {
+    let into_header = |block: HostBlock| -> Option<Header> {
+        let mut tm_block = block.try_into_tm_block()?;
+        tm_block.trusted_height = trusted_height;
+        Some(tm_block.into())
+    };
+
+    Some(TmMisbehaviour::from_conflicting_headers(
+        client_id,
+        into_header(block_a)?,
+        into_header(block_b)?,
+    ))
+}
+
+/// Builds the Tendermint `LightClientAttackEvidence` that a full node would
+/// produce upon detecting the given conflicting block, forked off the honest
+/// chain at `common_height`.
+pub fn tm_light_client_attack_evidence(
+    conflicting_block: &SyntheticTmBlock,
+    common_height: Height,
+) -> LightClientAttackEvidence {
+    let light_block = &conflicting_block.light_block;
+
+    LightClientAttackEvidence {
+        conflicting_block: ConflictingBlock {
+            signed_header: light_block.signed_header.clone(),
+            validator_set: light_block.validators.clone(),
+        },
+        common_height: common_height
+            .revision_height()
+            .try_into()
+            .expect("Never fails"),
+        byzantine_validators: light_block.validators.validators().clone(),
+        total_voting_power: light_block.validators.total_voting_power(),
+        timestamp: light_block.signed_header.header.time,
+    }
+}
+
 #[cfg(feature = "serde")]
 pub fn dummy_tendermint_header() -> tendermint::block::Header {
     use tendermint::block::signed_header::SignedHeader;
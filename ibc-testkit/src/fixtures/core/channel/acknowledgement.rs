@@ -27,30 +27,24 @@ pub fn dummy_raw_msg_ack_with_packet(packet: RawPacket, height: u64) -> RawMsgAc
 
 #[cfg(test)]
 mod test {
-    use ibc::core::channel::types::error::PacketError;
     use ibc::core::channel::types::msgs::MsgAcknowledgement;
     use ibc::primitives::prelude::*;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn msg_acknowledgment_try_from_raw() {
-        struct Test {
-            name: String,
-            raw: RawMsgAcknowledgement,
-            want_pass: bool,
-        }
-
         let height = 50;
         let default_raw_msg = dummy_raw_msg_acknowledgement(height);
 
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawMsgAcknowledgement>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_raw_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Missing packet".to_string(),
                 raw: RawMsgAcknowledgement {
                     packet: None,
@@ -58,7 +52,7 @@ mod test {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing proof height".to_string(),
                 raw: RawMsgAcknowledgement {
                     proof_height: None,
@@ -66,7 +60,7 @@ mod test {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Empty signer".to_string(),
                 raw: RawMsgAcknowledgement {
                     signer: dummy_bech32_account(),
@@ -74,7 +68,7 @@ mod test {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Empty proof acked".to_string(),
                 raw: RawMsgAcknowledgement {
                     proof_acked: Vec::new(),
@@ -84,17 +78,6 @@ mod test {
             },
         ];
 
-        for test in tests {
-            let res_msg: Result<MsgAcknowledgement, PacketError> = test.raw.clone().try_into();
-
-            assert_eq!(
-                res_msg.is_ok(),
-                test.want_pass,
-                "MsgAcknowledgement::try_from failed for test {} \nraw message: {:?} with error: {:?}",
-                test.name,
-                test.raw,
-                res_msg.err()
-            );
-        }
+        run_decoding_tests::<MsgAcknowledgement, _>(tests);
     }
 }
@@ -26,25 +26,20 @@ mod tests {
     use ibc::core::channel::types::msgs::MsgChannelCloseConfirm;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn parse_channel_close_confirm_msg() {
-        struct Test {
-            name: String,
-            raw: RawMsgChannelCloseConfirm,
-            want_pass: bool,
-        }
-
         let proof_height = 10;
         let default_raw_msg = dummy_raw_msg_chan_close_confirm(proof_height);
 
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawMsgChannelCloseConfirm>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_raw_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Correct port".to_string(),
                 raw: RawMsgChannelCloseConfirm {
                     port_id: "p34".to_string(),
@@ -52,7 +47,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad port, name too short".to_string(),
                 raw: RawMsgChannelCloseConfirm {
                     port_id: "p".to_string(),
@@ -60,7 +55,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad port, name too long".to_string(),
                 raw: RawMsgChannelCloseConfirm {
                     port_id:
@@ -70,7 +65,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Correct channel identifier".to_string(),
                 raw: RawMsgChannelCloseConfirm {
                     channel_id: "channel-34".to_string(),
@@ -78,7 +73,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad channel, name too short".to_string(),
                 raw: RawMsgChannelCloseConfirm {
                     channel_id: "chshort".to_string(),
@@ -86,7 +81,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad channel, name too long".to_string(),
                 raw: RawMsgChannelCloseConfirm {
                     channel_id:
@@ -96,7 +91,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad proof height, height = 0".to_string(),
                 raw: RawMsgChannelCloseConfirm {
                     proof_height: Some(Height {
@@ -111,18 +106,7 @@ mod tests {
         .into_iter()
         .collect();
 
-        for test in tests {
-            let msg = MsgChannelCloseConfirm::try_from(test.raw.clone());
-
-            assert_eq!(
-                test.want_pass,
-                msg.is_ok(),
-                "MsgChanCloseConfirm::try_from raw failed for test {}, \nraw msg {:?} with error {:?}",
-                test.name,
-                test.raw,
-                msg.err(),
-            );
-        }
+        run_decoding_tests::<MsgChannelCloseConfirm, _>(tests);
     }
 
     #[test]
@@ -18,24 +18,19 @@ mod tests {
     use ibc::core::channel::types::msgs::MsgChannelCloseInit;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn parse_channel_close_init_msg() {
-        struct Test {
-            name: String,
-            raw: RawMsgChannelCloseInit,
-            want_pass: bool,
-        }
-
         let default_raw_msg = dummy_raw_msg_chan_close_init();
 
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawMsgChannelCloseInit>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_raw_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Correct port".to_string(),
                 raw: RawMsgChannelCloseInit {
                     port_id: "p34".to_string(),
@@ -43,7 +38,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad port, name too short".to_string(),
                 raw: RawMsgChannelCloseInit {
                     port_id: "p".to_string(),
@@ -51,7 +46,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad port, name too long".to_string(),
                 raw: RawMsgChannelCloseInit {
                     port_id: "abcdefsdfasdfasdfasdfasdfasdfadsfasdgafsgadfasdfasdfasdfsdfasdfaghijklmnopqrstuabcdefsdfasdfasdfasdfasdfasdfadsfasdgafsgadfasdfasdfasdfsdfasdfaghijklmnopqrstu".to_string(),
@@ -59,7 +54,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Correct channel identifier".to_string(),
                 raw: RawMsgChannelCloseInit {
                     channel_id: "channel-34".to_string(),
@@ -67,7 +62,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad channel, name too short".to_string(),
                 raw: RawMsgChannelCloseInit {
                     channel_id: "chshort".to_string(),
@@ -75,7 +70,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad channel, name too long".to_string(),
                 raw: RawMsgChannelCloseInit {
                     channel_id: "channel-128391283791827398127398791283912837918273981273987912839".to_string(),
@@ -87,18 +82,7 @@ mod tests {
             .into_iter()
             .collect();
 
-        for test in tests {
-            let msg = MsgChannelCloseInit::try_from(test.raw.clone());
-
-            assert_eq!(
-                test.want_pass,
-                msg.is_ok(),
-                "MsgChanCloseInit::try_from failed for test {}, \nmsg {:?} with error {:?}",
-                test.name,
-                test.raw,
-                msg.err(),
-            );
-        }
+        run_decoding_tests::<MsgChannelCloseInit, _>(tests);
     }
 
     #[test]
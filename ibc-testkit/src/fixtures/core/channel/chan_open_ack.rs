@@ -27,25 +27,20 @@ mod tests {
     use ibc::core::channel::types::msgs::MsgChannelOpenAck;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn parse_channel_open_ack_msg() {
-        struct Test {
-            name: String,
-            raw: RawMsgChannelOpenAck,
-            want_pass: bool,
-        }
-
         let proof_height = 20;
         let default_raw_msg = dummy_raw_msg_chan_open_ack(proof_height);
 
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawMsgChannelOpenAck>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_raw_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Correct port identifier".to_string(),
                 raw: RawMsgChannelOpenAck {
                     port_id: "p34".to_string(),
@@ -53,7 +48,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad port, name too short".to_string(),
                 raw: RawMsgChannelOpenAck {
                     port_id: "p".to_string(),
@@ -61,7 +56,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad port, name too long".to_string(),
                 raw: RawMsgChannelOpenAck {
                     port_id: "abcdezdfDfsdfgfddsfsfdsdfdfvxcvzxcvsgdfsdfwefwvsdfdsfdasgagadgsadgsdffghijklmnopqrstuabcdezdfDfsdfgfddsfsfdsdfdfvxcvzxcvsgdfsdfwefwvsdfdsfdasgagadgsadgsdffghijklmnopqrstu".to_string(),
@@ -69,7 +64,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Correct channel identifier".to_string(),
                 raw: RawMsgChannelOpenAck {
                     channel_id: "channel-34".to_string(),
@@ -77,7 +72,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad channel, name too short".to_string(),
                 raw: RawMsgChannelOpenAck {
                     channel_id: "chshort".to_string(),
@@ -85,7 +80,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad channel, name too long".to_string(),
                 raw: RawMsgChannelOpenAck {
                     channel_id: "channel-128391283791827398127398791283912837918273981273987912839".to_string(),
@@ -93,7 +88,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "[Counterparty] Correct channel identifier".to_string(),
                 raw: RawMsgChannelOpenAck {
                     counterparty_channel_id: "channel-34".to_string(),
@@ -101,7 +96,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "[Counterparty] Bad channel, name too short".to_string(),
                 raw: RawMsgChannelOpenAck {
                     counterparty_channel_id: "chshort".to_string(),
@@ -109,7 +104,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "[Counterparty] Bad channel, name too long".to_string(),
                 raw: RawMsgChannelOpenAck {
                     counterparty_channel_id: "channel-128391283791827398127398791283912837918273981273987912839".to_string(),
@@ -117,7 +112,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Empty counterparty version (allowed)".to_string(),
                 raw: RawMsgChannelOpenAck {
                     counterparty_version: " ".to_string(),
@@ -125,7 +120,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Arbitrary counterparty version (allowed)".to_string(),
                 raw: RawMsgChannelOpenAck {
                     counterparty_version: "v1.1.23-alpha".to_string(),
@@ -133,7 +128,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad proof height, height = 0".to_string(),
                 raw: RawMsgChannelOpenAck {
                     proof_height: Some(Height {
@@ -144,7 +139,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing proof height".to_string(),
                 raw: RawMsgChannelOpenAck {
                     proof_height: None,
@@ -152,7 +147,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing proof try (object proof)".to_string(),
                 raw: RawMsgChannelOpenAck {
                     proof_try: Vec::new(),
@@ -164,18 +159,7 @@ mod tests {
             .into_iter()
             .collect();
 
-        for test in tests {
-            let res_msg = MsgChannelOpenAck::try_from(test.raw.clone());
-
-            assert_eq!(
-                test.want_pass,
-                res_msg.is_ok(),
-                "MsgChanOpenAck::try_from raw failed for test {}, \nraw msg {:?} with error {:?}",
-                test.name,
-                test.raw,
-                res_msg.err(),
-            );
-        }
+        run_decoding_tests::<MsgChannelOpenAck, _>(tests);
     }
 
     #[test]
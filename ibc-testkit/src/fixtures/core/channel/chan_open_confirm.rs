@@ -25,25 +25,20 @@ mod tests {
     use ibc::core::channel::types::msgs::MsgChannelOpenConfirm;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn parse_channel_open_confirm_msg() {
-        struct Test {
-            name: String,
-            raw: RawMsgChannelOpenConfirm,
-            want_pass: bool,
-        }
-
         let proof_height = 78;
         let default_raw_msg = dummy_raw_msg_chan_open_confirm(proof_height);
 
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawMsgChannelOpenConfirm>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_raw_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Correct port".to_string(),
                 raw: RawMsgChannelOpenConfirm {
                     port_id: "p34".to_string(),
@@ -51,7 +46,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad port, name too short".to_string(),
                 raw: RawMsgChannelOpenConfirm {
                     port_id: "p".to_string(),
@@ -59,7 +54,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad port, name too long".to_string(),
                 raw: RawMsgChannelOpenConfirm {
                     port_id: "abcdesdfasdsdffasdfasdfasfasdgasdfgasdfasdfasdfasdfasdfasdffghijklmnopqrstuabcdesdfasdsdffasdfasdfasfasdgasdfgasdfasdfasdfasdfasdfasdffghijklmnopqrstu".to_string(),
@@ -67,7 +62,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Correct channel identifier".to_string(),
                 raw: RawMsgChannelOpenConfirm {
                     channel_id: "channel-34".to_string(),
@@ -75,7 +70,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad channel, name too short".to_string(),
                 raw: RawMsgChannelOpenConfirm {
                     channel_id: "chshort".to_string(),
@@ -83,7 +78,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad channel, name too long".to_string(),
                 raw: RawMsgChannelOpenConfirm {
                     channel_id: "channel-128391283791827398127398791283912837918273981273987912839".to_string(),
@@ -91,7 +86,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad proof height, height = 0".to_string(),
                 raw: RawMsgChannelOpenConfirm {
                     proof_height: Some(Height {
@@ -102,7 +97,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing object proof".to_string(),
                 raw: RawMsgChannelOpenConfirm {
                     proof_ack: Vec::new(),
@@ -114,18 +109,7 @@ mod tests {
             .into_iter()
             .collect();
 
-        for test in tests {
-            let res_msg = MsgChannelOpenConfirm::try_from(test.raw.clone());
-
-            assert_eq!(
-                test.want_pass,
-                res_msg.is_ok(),
-                "MsgChanOpenConfirm::try_from failed for test {}, \nraw msg {:?} with error {:?}",
-                test.name,
-                test.raw,
-                res_msg.err(),
-            );
-        }
+        run_decoding_tests::<MsgChannelOpenConfirm, _>(tests);
     }
 
     #[test]
@@ -19,24 +19,19 @@ mod tests {
     use ibc::core::channel::types::msgs::MsgChannelOpenInit;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn channel_open_init_from_raw() {
-        struct Test {
-            name: String,
-            raw: RawMsgChannelOpenInit,
-            want_pass: bool,
-        }
-
         let default_raw_init_msg = dummy_raw_msg_chan_open_init(None);
 
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawMsgChannelOpenInit>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_raw_init_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Incorrect port identifier, slash (separator) prohibited".to_string(),
                 raw: RawMsgChannelOpenInit {
                     port_id: "p34/".to_string(),
@@ -44,7 +39,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing channel".to_string(),
                 raw: RawMsgChannelOpenInit {
                     channel: None,
@@ -56,18 +51,7 @@ mod tests {
         .into_iter()
         .collect();
 
-        for test in tests {
-            let res_msg = MsgChannelOpenInit::try_from(test.raw.clone());
-
-            assert_eq!(
-                test.want_pass,
-                res_msg.is_ok(),
-                "MsgChanOpenInit::try_from failed for test {}, \nraw msg {:?} with error {:?}",
-                test.name,
-                test.raw,
-                res_msg.err(),
-            );
-        }
+        run_decoding_tests::<MsgChannelOpenInit, _>(tests);
     }
 
     #[test]
@@ -28,25 +28,20 @@ mod tests {
     use ibc::core::channel::types::msgs::MsgChannelOpenTry;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn channel_open_try_from_raw() {
-        struct Test {
-            name: String,
-            raw: RawMsgChannelOpenTry,
-            want_pass: bool,
-        }
-
         let proof_height = 10;
         let default_raw_msg = dummy_raw_msg_chan_open_try(proof_height);
 
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawMsgChannelOpenTry>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_raw_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Correct port".to_string(),
                 raw: RawMsgChannelOpenTry {
                     port_id: "p34".to_string(),
@@ -54,7 +49,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad port, name too short".to_string(),
                 raw: RawMsgChannelOpenTry {
                     port_id: "p".to_string(),
@@ -62,7 +57,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad port, name too long".to_string(),
                 raw: RawMsgChannelOpenTry {
                     port_id: "abcdefghijasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfadgasgasdfasdfaabcdefghijasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfadgasgasdfasdfa".to_string(),
@@ -70,7 +65,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Empty counterparty version (valid choice)".to_string(),
                 raw: RawMsgChannelOpenTry {
                     counterparty_version: " ".to_string(),
@@ -78,7 +73,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Arbitrary counterparty version (valid choice)".to_string(),
                 raw: RawMsgChannelOpenTry {
                     counterparty_version: "anyversion".to_string(),
@@ -86,7 +81,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad proof height, height = 0".to_string(),
                 raw: RawMsgChannelOpenTry {
                     proof_height: Some(Height {
@@ -97,7 +92,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing proof height".to_string(),
                 raw: RawMsgChannelOpenTry {
                     proof_height: None,
@@ -105,7 +100,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing proof init (object proof)".to_string(),
                 raw: RawMsgChannelOpenTry {
                     proof_init: Vec::new(),
@@ -117,18 +112,7 @@ mod tests {
             .into_iter()
             .collect();
 
-        for test in tests {
-            let res_msg = MsgChannelOpenTry::try_from(test.raw.clone());
-
-            assert_eq!(
-                test.want_pass,
-                res_msg.is_ok(),
-                "MsgChanOpenTry::try_from failed for test {}, \nraw msg {:?} with error {:?}",
-                test.name,
-                test.raw,
-                res_msg.err(),
-            );
-        }
+        run_decoding_tests::<MsgChannelOpenTry, _>(tests);
     }
 
     #[test]
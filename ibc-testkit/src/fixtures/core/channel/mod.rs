@@ -6,8 +6,10 @@ mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
 mod packet;
+mod prune_acknowledgements;
 mod recv_packet;
 mod timeout;
+mod timeout_batch;
 mod timeout_on_close;
 
 pub use acknowledgement::*;
@@ -23,8 +25,10 @@ use ibc::core::channel::types::proto::v1::{
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc::primitives::prelude::*;
 pub use packet::*;
+pub use prune_acknowledgements::*;
 pub use recv_packet::*;
 pub use timeout::*;
+pub use timeout_batch::*;
 pub use timeout_on_close::*;
 
 /// Returns a dummy `RawCounterparty`, for testing purposes only!
@@ -59,6 +63,7 @@ mod tests {
     use ibc::core::channel::types::channel::ChannelEnd;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
     #[test]
     fn channel_end_try_from_raw() {
         let raw_channel_end = dummy_raw_channel_end(2, Some(0));
@@ -68,54 +73,48 @@ mod tests {
             ..raw_channel_end.clone()
         };
 
-        struct Test {
-            name: String,
-            params: RawChannel,
-            want_pass: bool,
-        }
-
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawChannel>> = vec![
+            DecodingTest {
                 name: "Raw channel end with missing counterparty".to_string(),
-                params: empty_raw_channel_end,
+                raw: empty_raw_channel_end,
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Raw channel end with incorrect state".to_string(),
-                params: RawChannel {
+                raw: RawChannel {
                     state: -1,
                     ..raw_channel_end.clone()
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Raw channel end with incorrect ordering".to_string(),
-                params: RawChannel {
+                raw: RawChannel {
                     ordering: -1,
                     ..raw_channel_end.clone()
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Raw channel end with incorrect connection id in connection hops".to_string(),
-                params: RawChannel {
+                raw: RawChannel {
                     connection_hops: vec!["connection*".to_string()].into_iter().collect(),
                     ..raw_channel_end.clone()
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Raw channel end with incorrect connection id (has blank space)".to_string(),
-                params: RawChannel {
+                raw: RawChannel {
                     connection_hops: vec!["con nection".to_string()].into_iter().collect(),
                     ..raw_channel_end.clone()
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Raw channel end with two correct connection ids in connection hops"
                     .to_string(),
-                params: RawChannel {
+                raw: RawChannel {
                     connection_hops: vec!["connection-1".to_string(), "connection-2".to_string()]
                         .into_iter()
                         .collect(),
@@ -123,29 +122,16 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Raw channel end with correct params".to_string(),
-                params: raw_channel_end,
+                raw: raw_channel_end,
                 want_pass: true,
             },
         ]
         .into_iter()
         .collect();
 
-        for test in tests {
-            let p = test.params.clone();
-
-            let ce_result = ChannelEnd::try_from(p);
-
-            assert_eq!(
-                test.want_pass,
-                ce_result.is_ok(),
-                "ChannelEnd::try_from() failed for test {}, \nmsg{:?} with error {:?}",
-                test.name,
-                test.params.clone(),
-                ce_result.err(),
-            );
-        }
+        run_decoding_tests::<ChannelEnd, _>(tests);
     }
 
     #[test]
@@ -1,10 +1,9 @@
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::proto::v1::Packet as RawPacket;
-use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
 use ibc::core::client::types::proto::v1::Height as RawHeight;
 use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
 use typed_builder::TypedBuilder;
 
 /// Configuration of the `PacketData` type for building dummy packets.
@@ -25,8 +24,8 @@ pub struct PacketConfig {
     pub data: Vec<u8>,
     #[builder(default = TimeoutHeight::Never)]
     pub timeout_height_on_b: TimeoutHeight,
-    #[builder(default = Timestamp::none())]
-    pub timeout_timestamp_on_b: Timestamp,
+    #[builder(default = TimeoutTimestamp::Never, setter(into))]
+    pub timeout_timestamp_on_b: TimeoutTimestamp,
 }
 
 impl From<PacketConfig> for Packet {
@@ -73,15 +72,10 @@ mod tests {
     use ibc::core::host::types::identifiers::ConnectionId;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn packet_try_from_raw() {
-        struct Test {
-            name: String,
-            raw: RawPacket,
-            want_pass: bool,
-        }
-
         let proof_height = 10;
         let default_raw_packet = dummy_raw_packet(proof_height, 1000);
         let raw_packet_no_timeout_or_timestamp = dummy_raw_packet(10, 0);
@@ -92,25 +86,25 @@ mod tests {
             revision_height: 0,
         });
 
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawPacket>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_raw_packet.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 // Note: ibc-go currently (July 2022) incorrectly rejects this
                 // case, even though it is allowed in ICS-4.
                 name: "Packet with no timeout of timestamp".to_string(),
                 raw: raw_packet_no_timeout_or_timestamp.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Packet with invalid timeout height".to_string(),
                 raw: raw_packet_invalid_timeout_height,
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Src port validation: correct".to_string(),
                 raw: RawPacket {
                     source_port: "srcportp34".to_string(),
@@ -118,7 +112,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad src port, name too short".to_string(),
                 raw: RawPacket {
                     source_port: "p".to_string(),
@@ -126,7 +120,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad src port, name too long".to_string(),
                 raw: RawPacket {
                     source_port: "abcdefghijasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfadgasgasdfasdfasdfasdfaklmnopqrstuabcdefghijasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfadgasgasdfasdfasdfasdfaklmnopqrstu".to_string(),
@@ -134,7 +128,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Dst port validation: correct".to_string(),
                 raw: RawPacket {
                     destination_port: "destportsrcp34".to_string(),
@@ -142,7 +136,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad dst port, name too short".to_string(),
                 raw: RawPacket {
                     destination_port: "p".to_string(),
@@ -150,7 +144,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad dst port, name too long".to_string(),
                 raw: RawPacket {
                     destination_port: "abcdefghijasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfadgasgasdfasdfasdfasdfaklmnopqrstuabcdefghijasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfadgasgasdfas".to_string(),
@@ -158,7 +152,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Src channel validation: correct".to_string(),
                 raw: RawPacket {
                     source_channel: "channel-1".to_string(),
@@ -166,7 +160,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad src channel, name too short".to_string(),
                 raw: RawPacket {
                     source_channel: "p".to_string(),
@@ -174,7 +168,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad src channel, name too long".to_string(),
                 raw: RawPacket {
                     source_channel: "channel-128391283791827398127398791283912837918273981273987912839".to_string(),
@@ -182,7 +176,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Dst channel validation: correct".to_string(),
                 raw: RawPacket {
                     destination_channel: "channel-34".to_string(),
@@ -190,7 +184,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad dst channel, name too short".to_string(),
                 raw: RawPacket {
                     destination_channel: "p".to_string(),
@@ -198,7 +192,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad dst channel, name too long".to_string(),
                 raw: RawPacket {
                     destination_channel: "channel-128391283791827398127398791283912837918273981273987912839".to_string(),
@@ -213,7 +207,7 @@ mod tests {
             // means that they will always generate a field. It is left
             // unspecified what a `None` value means. In this case, I believe it
             // is best to assume the obvious semantic of "no timeout".
-            Test {
+            DecodingTest {
                 name: "Missing timeout height".to_string(),
                 raw: RawPacket {
                     timeout_height: None,
@@ -221,7 +215,7 @@ mod tests {
                 },
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Missing both timeout height and timestamp".to_string(),
                 raw: RawPacket {
                     timeout_height: None,
@@ -231,18 +225,7 @@ mod tests {
             }
         ];
 
-        for test in tests {
-            let res_msg = Packet::try_from(test.raw.clone());
-
-            assert_eq!(
-                test.want_pass,
-                res_msg.is_ok(),
-                "Packet::try_from failed for test {}, \nraw packet {:?} with error {:?}",
-                test.name,
-                test.raw,
-                res_msg.err(),
-            );
-        }
+        run_decoding_tests::<Packet, _>(tests);
     }
 
     #[test]
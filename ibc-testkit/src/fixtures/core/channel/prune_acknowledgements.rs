@@ -0,0 +1,87 @@
+use ibc::core::channel::types::proto::v1::MsgPruneAcknowledgements as RawMsgPruneAcknowledgements;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgPruneAcknowledgements`, for testing purposes only!
+pub fn dummy_raw_msg_prune_acknowledgements(limit: u64) -> RawMsgPruneAcknowledgements {
+    RawMsgPruneAcknowledgements {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        limit,
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::error::ChannelError;
+    use ibc::core::channel::types::msgs::MsgPruneAcknowledgements;
+
+    use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
+
+    #[test]
+    fn parse_prune_acknowledgements_msg() {
+        let default_raw_msg = dummy_raw_msg_prune_acknowledgements(10);
+
+        let tests: Vec<DecodingTest<RawMsgPruneAcknowledgements>> = vec![
+            DecodingTest {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            DecodingTest {
+                name: "Pruning a single acknowledgement".to_string(),
+                raw: RawMsgPruneAcknowledgements {
+                    limit: 1,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: true,
+            },
+            DecodingTest {
+                name: "Bad limit, zero".to_string(),
+                raw: RawMsgPruneAcknowledgements {
+                    limit: 0,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            DecodingTest {
+                name: "Bad port, name too short".to_string(),
+                raw: RawMsgPruneAcknowledgements {
+                    port_id: "p".to_string(),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            DecodingTest {
+                name: "Bad channel, name too short".to_string(),
+                raw: RawMsgPruneAcknowledgements {
+                    channel_id: "chshort".to_string(),
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ];
+
+        run_decoding_tests::<MsgPruneAcknowledgements, _>(tests);
+    }
+
+    #[test]
+    fn zero_limit_is_rejected() {
+        let res = MsgPruneAcknowledgements::try_from(dummy_raw_msg_prune_acknowledgements(0));
+        assert!(matches!(res, Err(ChannelError::ZeroPruningLimit)));
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_prune_acknowledgements(10);
+        let msg = MsgPruneAcknowledgements::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgPruneAcknowledgements::from(msg.clone());
+        let msg_back = MsgPruneAcknowledgements::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
@@ -7,7 +7,7 @@ use ibc::core::channel::types::proto::v1::MsgRecvPacket as RawMsgRecvPacket;
 use ibc::core::client::types::proto::v1::Height as RawHeight;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentProofBytes;
-use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::primitives::{HostTimeProvider, Signer};
 
 use super::{dummy_proof, dummy_raw_packet};
 use crate::fixtures::core::signer::dummy_bech32_account;
@@ -27,9 +27,13 @@ pub fn dummy_msg_recv_packet(
 }
 
 /// Returns a dummy `RawMsgRecvPacket`, for testing purposes only! The `height`
-/// parametrizes both the proof height as well as the timeout height.
-pub fn dummy_raw_msg_recv_packet(height: u64) -> RawMsgRecvPacket {
-    let timestamp = Timestamp::now().add(Duration::from_secs(9));
+/// parametrizes both the proof height as well as the timeout height, while the
+/// packet times out shortly after the current time given by `time_provider`.
+pub fn dummy_raw_msg_recv_packet(
+    height: u64,
+    time_provider: &dyn HostTimeProvider,
+) -> RawMsgRecvPacket {
+    let timestamp = time_provider.now().add(Duration::from_secs(9));
     RawMsgRecvPacket {
         packet: Some(dummy_raw_packet(
             height,
@@ -46,28 +50,23 @@ pub fn dummy_raw_msg_recv_packet(height: u64) -> RawMsgRecvPacket {
 
 #[cfg(test)]
 mod test {
-    use ibc::core::channel::types::error::PacketError;
+    use ibc::core::primitives::StdTimeProvider;
     use ibc::primitives::prelude::*;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn msg_recv_packet_try_from_raw() {
-        struct Test {
-            name: String,
-            raw: RawMsgRecvPacket,
-            want_pass: bool,
-        }
-
         let height = 20;
-        let default_raw_msg = dummy_raw_msg_recv_packet(height);
-        let tests: Vec<Test> = vec![
-            Test {
+        let default_raw_msg = dummy_raw_msg_recv_packet(height, &StdTimeProvider);
+        let tests: Vec<DecodingTest<RawMsgRecvPacket>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_raw_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Missing proof".to_string(),
                 raw: RawMsgRecvPacket {
                     proof_commitment: Vec::new(),
@@ -75,7 +74,7 @@ mod test {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing proof height".to_string(),
                 raw: RawMsgRecvPacket {
                     proof_height: None,
@@ -83,7 +82,7 @@ mod test {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Empty signer".to_string(),
                 raw: RawMsgRecvPacket {
                     signer: dummy_bech32_account(),
@@ -93,23 +92,12 @@ mod test {
             },
         ];
 
-        for test in tests {
-            let res_msg: Result<MsgRecvPacket, PacketError> = test.raw.clone().try_into();
-
-            assert_eq!(
-                res_msg.is_ok(),
-                test.want_pass,
-                "MsgRecvPacket::try_from failed for test {} \nraw message: {:?} with error: {:?}",
-                test.name,
-                test.raw,
-                res_msg.err()
-            );
-        }
+        run_decoding_tests::<MsgRecvPacket, _>(tests);
     }
 
     #[test]
     fn to_and_from() {
-        let raw = dummy_raw_msg_recv_packet(15);
+        let raw = dummy_raw_msg_recv_packet(15, &StdTimeProvider);
         let msg = MsgRecvPacket::try_from(raw.clone()).unwrap();
         let raw_back = RawMsgRecvPacket::from(msg.clone());
         let msg_back = MsgRecvPacket::try_from(raw_back.clone()).unwrap();
@@ -25,33 +25,27 @@ pub fn dummy_raw_msg_timeout(
 
 #[cfg(test)]
 mod test {
-    use ibc::core::channel::types::error::PacketError;
     use ibc::core::channel::types::msgs::MsgTimeout;
     use ibc::primitives::prelude::*;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn msg_timeout_try_from_raw() {
-        struct Test {
-            name: String,
-            raw: RawMsgTimeout,
-            want_pass: bool,
-        }
-
         let proof_height = 50;
         let timeout_height = proof_height;
         let timeout_timestamp = 0;
         let default_raw_msg =
             dummy_raw_msg_timeout(proof_height, timeout_height, timeout_timestamp);
 
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawMsgTimeout>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_raw_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Missing packet".to_string(),
                 raw: RawMsgTimeout {
                     packet: None,
@@ -59,7 +53,7 @@ mod test {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing proof".to_string(),
                 raw: RawMsgTimeout {
                     proof_unreceived: Vec::new(),
@@ -67,7 +61,7 @@ mod test {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing proof height".to_string(),
                 raw: RawMsgTimeout {
                     proof_height: None,
@@ -75,7 +69,7 @@ mod test {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Empty signer".to_string(),
                 raw: RawMsgTimeout {
                     signer: dummy_bech32_account(),
@@ -85,18 +79,7 @@ mod test {
             },
         ];
 
-        for test in tests {
-            let res_msg: Result<MsgTimeout, PacketError> = test.raw.clone().try_into();
-
-            assert_eq!(
-                res_msg.is_ok(),
-                test.want_pass,
-                "MsgTimeout::try_from failed for test {} \nraw message: {:?} with error: {:?}",
-                test.name,
-                test.raw,
-                res_msg.err()
-            );
-        }
+        run_decoding_tests::<MsgTimeout, _>(tests);
     }
 
     #[test]
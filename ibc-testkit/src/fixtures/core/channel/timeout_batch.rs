@@ -0,0 +1,122 @@
+use ibc::core::channel::types::msgs::proto::MsgTimeoutBatch as RawMsgTimeoutBatch;
+use ibc::core::client::types::proto::v1::Height as RawHeight;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::primitives::prelude::*;
+
+use super::{dummy_proof, dummy_raw_packet};
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgTimeoutBatch` of `packet_count` packets with
+/// sequences starting from 1, for testing purposes only!
+///
+/// The message carries a single shared proof, as for ordered channels.
+pub fn dummy_raw_msg_timeout_batch(
+    proof_height: u64,
+    timeout_height: u64,
+    timeout_timestamp: u64,
+    packet_count: u64,
+) -> RawMsgTimeoutBatch {
+    RawMsgTimeoutBatch {
+        source_port: PortId::transfer().to_string(),
+        source_channel: ChannelId::zero().to_string(),
+        packets: (1..=packet_count)
+            .map(|sequence| {
+                let mut packet = dummy_raw_packet(timeout_height, timeout_timestamp);
+                packet.sequence = sequence;
+                packet
+            })
+            .collect(),
+        proofs_unreceived: vec![dummy_proof()],
+        proof_height: Some(RawHeight {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        next_sequence_recv: 1,
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ibc::core::channel::types::msgs::MsgTimeoutBatch;
+
+    use super::*;
+
+    #[test]
+    fn msg_timeout_batch_try_from_raw() {
+        let default_raw_msg = dummy_raw_msg_timeout_batch(50, 50, 0, 3);
+
+        let mut non_contiguous = default_raw_msg.clone();
+        non_contiguous.packets[2].sequence = 4;
+
+        let mut other_channel = default_raw_msg.clone();
+        other_channel.packets[1].source_channel = ChannelId::new(1).to_string();
+
+        let tests: Vec<(&str, RawMsgTimeoutBatch, bool)> = vec![
+            ("Good parameters", default_raw_msg.clone(), true),
+            (
+                "One proof per packet",
+                RawMsgTimeoutBatch {
+                    proofs_unreceived: vec![dummy_proof(); 3],
+                    ..default_raw_msg.clone()
+                },
+                true,
+            ),
+            (
+                "Missing packets",
+                RawMsgTimeoutBatch {
+                    packets: Vec::new(),
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            ("Non-contiguous sequences", non_contiguous, false),
+            ("Packet sent on another channel", other_channel, false),
+            (
+                "Missing proofs",
+                RawMsgTimeoutBatch {
+                    proofs_unreceived: Vec::new(),
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Wrong proof count",
+                RawMsgTimeoutBatch {
+                    proofs_unreceived: vec![dummy_proof(); 2],
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Missing proof height",
+                RawMsgTimeoutBatch {
+                    proof_height: None,
+                    ..default_raw_msg
+                },
+                false,
+            ),
+        ];
+
+        for (name, raw, want_pass) in tests {
+            let res_msg = MsgTimeoutBatch::try_from(raw.clone());
+
+            assert_eq!(
+                res_msg.is_ok(),
+                want_pass,
+                "MsgTimeoutBatch::try_from failed for test {name} \nraw message: {raw:?} with error: {:?}",
+                res_msg.err()
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_timeout_batch(15, 20, 0, 2);
+        let msg = MsgTimeoutBatch::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgTimeoutBatch::from(msg.clone());
+        let msg_back = MsgTimeoutBatch::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
@@ -27,6 +27,7 @@ mod tests {
     use ibc::primitives::prelude::*;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn msg_timeout_on_close_try_from_raw() {
@@ -41,23 +42,17 @@ mod tests {
 
     #[test]
     fn parse_timeout_on_close_msg() {
-        struct Test {
-            name: String,
-            raw: RawMsgTimeoutOnClose,
-            want_pass: bool,
-        }
-
         let height = 50;
         let timeout_timestamp = 5;
         let default_raw_msg = dummy_raw_msg_timeout_on_close(height, timeout_timestamp);
 
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawMsgTimeoutOnClose>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_raw_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Missing packet".to_string(),
                 raw: RawMsgTimeoutOnClose {
                     packet: None,
@@ -65,7 +60,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing proof of unreceived packet".to_string(),
                 raw: RawMsgTimeoutOnClose {
                     proof_unreceived: Vec::new(),
@@ -73,7 +68,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing proof of channel".to_string(),
                 raw: RawMsgTimeoutOnClose {
                     proof_close: Vec::new(),
@@ -81,7 +76,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Missing proof height".to_string(),
                 raw: RawMsgTimeoutOnClose {
                     proof_height: None,
@@ -91,17 +86,6 @@ mod tests {
             },
         ];
 
-        for test in tests {
-            let res_msg = MsgTimeoutOnClose::try_from(test.raw.clone());
-
-            assert_eq!(
-                test.want_pass,
-                res_msg.is_ok(),
-                "MsgTimeoutOnClose::try_from raw failed for test {}, \nraw msg {:?} with error {:?}",
-                test.name,
-                test.raw,
-                res_msg.err(),
-            );
-        }
+        run_decoding_tests::<MsgTimeoutOnClose, _>(tests);
     }
 }
@@ -15,7 +15,7 @@ mod tests {
     use core::str::FromStr;
 
     use ibc::core::client::types::events::*;
-    use ibc::core::client::types::Height;
+    use ibc::core::client::types::{Height, MisbehaviourEvidence, MisbehaviourKind};
     use ibc::core::host::types::identifiers::*;
     use ibc::primitives::prelude::*;
     use ibc::primitives::ToVec;
@@ -85,10 +85,37 @@ mod tests {
             },
             Test {
                 event_kind: CLIENT_MISBEHAVIOUR_EVENT,
-                event: ClientMisbehaviour::new(client_id, client_type).into(),
+                event: ClientMisbehaviour::new(client_id.clone(), client_type.clone(), None).into(),
                 expected_keys: expected_keys[0..2].to_vec(),
                 expected_values: expected_values[0..2].to_vec(),
             },
+            Test {
+                event_kind: CLIENT_MISBEHAVIOUR_EVENT,
+                event: ClientMisbehaviour::new(
+                    client_id,
+                    client_type,
+                    Some(MisbehaviourEvidence {
+                        kind: MisbehaviourKind::DoubleSign,
+                        conflicting_heights: vec![consensus_height, consensus_height],
+                        conflicting_hashes: vec![vec![0xab, 0x01], vec![0xcd, 0x02]],
+                    }),
+                )
+                .into(),
+                expected_keys: vec![
+                    "client_id",
+                    "client_type",
+                    "misbehaviour_type",
+                    "conflicting_heights",
+                    "conflicting_hashes",
+                ],
+                expected_values: vec![
+                    "07-tendermint-0",
+                    "07-tendermint",
+                    "double_sign",
+                    "0-5,0-5",
+                    "ab01,cd02",
+                ],
+            },
         ];
 
         for t in tests {
@@ -1,6 +1,10 @@
-use ibc::core::commitment_types::commitment::CommitmentProofBytes;
-use ibc::core::commitment_types::proto::ics23::CommitmentProof;
+use ibc::core::commitment_types::commitment::{CommitmentProofBytes, CommitmentRoot};
+use ibc::core::commitment_types::proto::ics23::commitment_proof::Proof;
+use ibc::core::commitment_types::proto::ics23::{
+    CommitmentProof, ExistenceProof, HashOp, HostFunctionsManager, InnerOp, LeafOp, LengthOp,
+};
 use ibc::core::commitment_types::proto::v1::MerkleProof as RawMerkleProof;
+use ibc::core::commitment_types::verification::calculate_existence_root;
 use ibc::core::primitives::prelude::*;
 
 /// Returns a dummy `CommitmentProofBytes`, for testing purposes only!
@@ -12,3 +16,92 @@ pub fn dummy_commitment_proof_bytes() -> CommitmentProofBytes {
         .try_into()
         .expect("could not convert to CommitmentProofBytes")
 }
+
+/// Commits the two given key-value pairs, ordered by key, to an IAVL store
+/// mounted under `store_key` in the multistore of a Cosmos SDK chain.
+///
+/// Returns the root of the multistore, along with the membership proofs of
+/// both pairs against it under the Cosmos SDK proof specs, for testing
+/// purposes only!
+pub fn dummy_cosmos_store_proofs(
+    store_key: &str,
+    entries: [(Vec<u8>, Vec<u8>); 2],
+) -> (CommitmentRoot, [CommitmentProofBytes; 2]) {
+    let [(left_key, left_value), (right_key, right_value)] = entries;
+    assert!(left_key < right_key, "entries must be ordered by key");
+
+    // Heights, sizes and versions are zigzag-encoded varints in IAVL nodes.
+    let iavl_leaf = LeafOp {
+        hash: HashOp::Sha256.into(),
+        prehash_key: HashOp::NoHash.into(),
+        prehash_value: HashOp::Sha256.into(),
+        length: LengthOp::VarProto.into(),
+        // height 0, size 1, version 1
+        prefix: vec![0, 2, 2],
+    };
+    // height 1, size 2, version 1
+    let iavl_inner_prefix = [2, 4, 2];
+
+    let leaf_proof = |key: Vec<u8>, value: Vec<u8>, leaf: LeafOp| ExistenceProof {
+        key,
+        value,
+        leaf: Some(leaf),
+        path: vec![],
+    };
+    let root_of = |proof: &ExistenceProof| {
+        calculate_existence_root::<HostFunctionsManager>(proof).expect("Never fails")
+    };
+
+    let mut left_proof = leaf_proof(left_key, left_value, iavl_leaf.clone());
+    let mut right_proof = leaf_proof(right_key, right_value, iavl_leaf);
+    let left_hash = root_of(&left_proof);
+    let right_hash = root_of(&right_proof);
+
+    // Child hashes are length-prefixed within IAVL inner nodes.
+    left_proof.path = vec![InnerOp {
+        hash: HashOp::Sha256.into(),
+        prefix: [iavl_inner_prefix.as_slice(), &[32]].concat(),
+        suffix: [[32].as_slice(), &right_hash].concat(),
+    }];
+    right_proof.path = vec![InnerOp {
+        hash: HashOp::Sha256.into(),
+        prefix: [iavl_inner_prefix.as_slice(), &[32], &left_hash, &[32]].concat(),
+        suffix: vec![],
+    }];
+    let store_root = root_of(&left_proof);
+
+    // The multistore commits to a simple Merkle tree of its stores, here
+    // holding a single leaf.
+    let multistore_proof = leaf_proof(
+        store_key.as_bytes().to_vec(),
+        store_root,
+        LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::VarProto.into(),
+            prefix: vec![0],
+        },
+    );
+    let root = root_of(&multistore_proof);
+
+    let merkle_proof = |store_proof: ExistenceProof| -> CommitmentProofBytes {
+        RawMerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(Proof::Exist(store_proof)),
+                },
+                CommitmentProof {
+                    proof: Some(Proof::Exist(multistore_proof.clone())),
+                },
+            ],
+        }
+        .try_into()
+        .expect("could not convert to CommitmentProofBytes")
+    };
+
+    (
+        root.into(),
+        [merkle_proof(left_proof), merkle_proof(right_proof)],
+    )
+}
@@ -47,25 +47,19 @@ pub fn dummy_raw_msg_conn_open_ack(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn parse_connection_open_ack_msg() {
-        #[derive(Clone, Debug, PartialEq)]
-        struct Test {
-            name: String,
-            raw: RawMsgConnectionOpenAck,
-            want_pass: bool,
-        }
-
         let default_ack_msg = dummy_raw_msg_conn_open_ack(5, 5);
 
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawMsgConnectionOpenAck>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_ack_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad connection id, non-alpha".to_string(),
                 raw: RawMsgConnectionOpenAck {
                     connection_id: "con007".to_string(),
@@ -73,7 +67,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad version, missing version".to_string(),
                 raw: RawMsgConnectionOpenAck {
                     version: None,
@@ -81,7 +75,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad proof height, height is 0".to_string(),
                 raw: RawMsgConnectionOpenAck {
                     proof_height: Some(RawHeight {
@@ -92,7 +86,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad consensus height, height is 0".to_string(),
                 raw: RawMsgConnectionOpenAck {
                     consensus_height: Some(RawHeight {
@@ -107,18 +101,7 @@ mod tests {
         .into_iter()
         .collect();
 
-        for test in tests {
-            let msg = MsgConnectionOpenAck::try_from(test.raw.clone());
-
-            assert_eq!(
-                test.want_pass,
-                msg.is_ok(),
-                "MsgConnOpenAck::new failed for test {}, \nmsg {:?} with error {:?}",
-                test.name,
-                test.raw,
-                msg.err(),
-            );
-        }
+        run_decoding_tests::<MsgConnectionOpenAck, _>(tests);
     }
 
     #[test]
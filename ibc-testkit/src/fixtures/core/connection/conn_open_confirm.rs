@@ -27,24 +27,18 @@ pub fn dummy_raw_msg_conn_open_confirm() -> RawMsgConnectionOpenConfirm {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn parse_connection_open_confirm_msg() {
-        #[derive(Clone, Debug, PartialEq)]
-        struct Test {
-            name: String,
-            raw: RawMsgConnectionOpenConfirm,
-            want_pass: bool,
-        }
-
         let default_ack_msg = dummy_raw_msg_conn_open_confirm();
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawMsgConnectionOpenConfirm>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_ack_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad connection id, non-alpha".to_string(),
                 raw: RawMsgConnectionOpenConfirm {
                     connection_id: "con007".to_string(),
@@ -52,7 +46,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad proof height, height is 0".to_string(),
                 raw: RawMsgConnectionOpenConfirm {
                     proof_height: Some(Height {
@@ -67,18 +61,7 @@ mod tests {
         .into_iter()
         .collect();
 
-        for test in tests {
-            let msg = MsgConnectionOpenConfirm::try_from(test.raw.clone());
-
-            assert_eq!(
-                test.want_pass,
-                msg.is_ok(),
-                "MsgConnOpenTry::new failed for test {}, \nmsg {:?} with error {:?}",
-                test.name,
-                test.raw,
-                msg.err(),
-            );
-        }
+        run_decoding_tests::<MsgConnectionOpenConfirm, _>(tests);
     }
 
     #[test]
@@ -84,25 +84,19 @@ mod tests {
     use ibc::core::connection::types::proto::v1::Counterparty as RawCounterparty;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn parse_connection_open_init_msg() {
-        #[derive(Clone, Debug, PartialEq)]
-        struct Test {
-            name: String,
-            raw: RawMsgConnectionOpenInit,
-            want_pass: bool,
-        }
-
         let default_init_msg = dummy_raw_msg_conn_open_init();
 
-        let tests: Vec<Test> = vec![
-            Test {
+        let tests: Vec<DecodingTest<RawMsgConnectionOpenInit>> = vec![
+            DecodingTest {
                 name: "Good parameters".to_string(),
                 raw: default_init_msg.clone(),
                 want_pass: true,
             },
-            Test {
+            DecodingTest {
                 name: "Bad client id, name too short".to_string(),
                 raw: RawMsgConnectionOpenInit {
                     client_id: "client".to_string(),
@@ -110,7 +104,7 @@ mod tests {
                 },
                 want_pass: false,
             },
-            Test {
+            DecodingTest {
                 name: "Bad destination connection id, name too long".to_string(),
                 raw: RawMsgConnectionOpenInit {
                     counterparty: Some(RawCounterparty {
@@ -127,18 +121,7 @@ mod tests {
         .into_iter()
         .collect();
 
-        for test in tests {
-            let msg = MsgConnectionOpenInit::try_from(test.raw.clone());
-
-            assert_eq!(
-                test.want_pass,
-                msg.is_ok(),
-                "MsgConnOpenInit::new failed for test {}, \nmsg {:?} with error {:?}",
-                test.name,
-                test.raw,
-                msg.err(),
-            );
-        }
+        run_decoding_tests::<MsgConnectionOpenInit, _>(tests);
     }
 
     #[test]
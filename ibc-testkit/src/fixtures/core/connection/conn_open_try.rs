@@ -3,7 +3,7 @@ use ibc::core::client::types::Height;
 use ibc::core::connection::types::msgs::MsgConnectionOpenTry;
 use ibc::core::connection::types::proto::v1::MsgConnectionOpenTry as RawMsgConnectionOpenTry;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
-use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::identifiers::ClientId;
 use ibc::core::primitives::prelude::*;
 
 use super::dummy_raw_counterparty_conn;
@@ -41,7 +41,7 @@ pub fn dummy_raw_msg_conn_open_try(
     #[allow(deprecated)]
     RawMsgConnectionOpenTry {
         client_id: "07-tendermint-0".into(),
-        previous_connection_id: ConnectionId::zero().to_string(),
+        previous_connection_id: String::new(),
         client_state: Some(MockClientState::new(MockHeader::new(client_state_height)).into()),
         counterparty: Some(dummy_raw_counterparty_conn(Some(0))),
         delay_period: 0,
@@ -70,26 +70,20 @@ mod tests {
     use ibc::core::connection::types::proto::v1::Counterparty as RawCounterparty;
 
     use super::*;
+    use crate::fixtures::{run_decoding_tests, DecodingTest};
 
     #[test]
     fn parse_connection_open_try_msg() {
-        #[derive(Clone, Debug, PartialEq)]
-        struct Test {
-            name: String,
-            raw: RawMsgConnectionOpenTry,
-            want_pass: bool,
-        }
-
         let default_try_msg = dummy_raw_msg_conn_open_try(10, 34);
 
-        let tests: Vec<Test> =
+        let tests: Vec<DecodingTest<RawMsgConnectionOpenTry>> =
             vec![
-                Test {
+                DecodingTest {
                     name: "Good parameters".to_string(),
                     raw: default_try_msg.clone(),
                     want_pass: true,
                 },
-                Test {
+                DecodingTest {
                     name: "Bad client id, name too short".to_string(),
                     raw: RawMsgConnectionOpenTry {
                         client_id: "client".to_string(),
@@ -97,7 +91,7 @@ mod tests {
                     },
                     want_pass: false,
                 },
-                Test {
+                DecodingTest {
                     name: "Bad destination connection id, name too long".to_string(),
                     raw: RawMsgConnectionOpenTry {
                         counterparty: Some(RawCounterparty {
@@ -110,7 +104,7 @@ mod tests {
                     },
                     want_pass: false,
                 },
-                Test {
+                DecodingTest {
                     name: "Correct destination client id with lower/upper case and special chars"
                         .to_string(),
                     raw: RawMsgConnectionOpenTry {
@@ -122,7 +116,7 @@ mod tests {
                     },
                     want_pass: true,
                 },
-                Test {
+                DecodingTest {
                     name: "Bad counterparty versions, empty versions vec".to_string(),
                     raw: RawMsgConnectionOpenTry {
                         counterparty_versions: Vec::new(),
@@ -130,7 +124,7 @@ mod tests {
                     },
                     want_pass: false,
                 },
-                Test {
+                DecodingTest {
                     name: "Bad counterparty versions, empty version string".to_string(),
                     raw: RawMsgConnectionOpenTry {
                         counterparty_versions: Vec::new(),
@@ -138,7 +132,7 @@ mod tests {
                     },
                     want_pass: false,
                 },
-                Test {
+                DecodingTest {
                     name: "Bad proof height, height is 0".to_string(),
                     raw: RawMsgConnectionOpenTry {
                         proof_height: Some(RawHeight { revision_number: 1, revision_height: 0 }),
@@ -146,7 +140,7 @@ mod tests {
                     },
                     want_pass: false,
                 },
-                Test {
+                DecodingTest {
                     name: "Bad consensus height, height is 0".to_string(),
                     raw: RawMsgConnectionOpenTry {
                         proof_height: Some(RawHeight { revision_number: 1, revision_height: 0 }),
@@ -154,7 +148,7 @@ mod tests {
                     },
                     want_pass: false,
                 },
-                Test {
+                DecodingTest {
                     name: "Empty proof".to_string(),
                     raw: RawMsgConnectionOpenTry {
                         proof_init: b"".to_vec(),
@@ -166,18 +160,7 @@ mod tests {
             .into_iter()
             .collect();
 
-        for test in tests {
-            let msg = MsgConnectionOpenTry::try_from(test.raw.clone());
-
-            assert_eq!(
-                test.want_pass,
-                msg.is_ok(),
-                "MsgConnOpenTry::new failed for test {}, \nmsg {:?} with error {:?}",
-                test.name,
-                test.raw,
-                msg.err(),
-            );
-        }
+        run_decoding_tests::<MsgConnectionOpenTry, _>(tests);
     }
 
     #[test]
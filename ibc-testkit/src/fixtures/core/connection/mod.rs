@@ -3,36 +3,87 @@ mod conn_open_confirm;
 mod conn_open_init;
 mod conn_open_try;
 
+use core::time::Duration;
+
 pub use conn_open_ack::*;
 pub use conn_open_confirm::*;
 pub use conn_open_init::*;
 pub use conn_open_try::*;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
 use ibc::core::commitment_types::proto::v1::MerklePrefix;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::proto::v1::Counterparty as RawCounterparty;
-use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::connection::types::version::Version;
+use ibc::core::connection::types::{ConnectionEnd, Counterparty, State};
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::ZERO_DURATION;
 use typed_builder::TypedBuilder;
 
+/// Configuration of the `ConnectionEnd` type for building dummy connection
+/// ends, validated upon conversion into a `ConnectionEnd`.
+#[derive(TypedBuilder, Debug)]
+pub struct ConnectionEndConfig {
+    #[builder(default = State::Open)]
+    pub state: State,
+    #[builder(default = ClientId::new("07-tendermint", 0).expect("never fails"))]
+    pub client_id: ClientId,
+    #[builder(default = CounterpartyConfig::builder().build())]
+    pub counterparty: Counterparty,
+    #[builder(default = Version::compatibles())]
+    pub versions: Vec<Version>,
+    #[builder(default = ZERO_DURATION)]
+    pub delay_period: Duration,
+}
+
+impl TryFrom<ConnectionEndConfig> for ConnectionEnd {
+    type Error = ConnectionError;
+
+    fn try_from(config: ConnectionEndConfig) -> Result<Self, Self::Error> {
+        ConnectionEnd::new(
+            config.state,
+            config.client_id,
+            config.counterparty,
+            config.versions,
+            config.delay_period,
+        )
+    }
+}
+
+/// Configuration of the connection `Counterparty` type for building dummy
+/// counterparties.
 #[derive(TypedBuilder, Debug)]
-#[builder(build_method(into = RawCounterparty))]
+#[builder(build_method(into = Counterparty))]
 pub struct CounterpartyConfig {
-    #[builder(default = "07-tendermint-0")]
-    client_id: &'static str,
-    #[builder(default = "connection-0")]
-    connection_id: &'static str,
-    #[builder(default = Some(MerklePrefix {
-        key_prefix: b"ibc".to_vec()
-    }))]
-    prefix: Option<MerklePrefix>,
+    #[builder(default = ClientId::new("07-tendermint", 0).expect("never fails"))]
+    pub client_id: ClientId,
+    #[builder(default = Some(ConnectionId::zero()))]
+    pub connection_id: Option<ConnectionId>,
+    #[builder(default = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("never fails"))]
+    pub prefix: CommitmentPrefix,
 }
 
-impl From<CounterpartyConfig> for RawCounterparty {
+impl From<CounterpartyConfig> for Counterparty {
     fn from(config: CounterpartyConfig) -> Self {
-        Self {
-            client_id: config.client_id.to_string(),
-            connection_id: config.connection_id.to_string(),
-            prefix: config.prefix,
-        }
+        Counterparty::new(config.client_id, config.connection_id, config.prefix)
+    }
+}
+
+/// Configuration of the connection `Version` type for building dummy
+/// versions, validated upon conversion into a `Version`.
+#[derive(TypedBuilder, Debug)]
+pub struct VersionConfig {
+    #[builder(default = "1".to_string(), setter(into))]
+    pub identifier: String,
+    #[builder(default = vec!["ORDER_ORDERED".to_string(), "ORDER_UNORDERED".to_string()])]
+    pub features: Vec<String>,
+}
+
+impl TryFrom<VersionConfig> for Version {
+    type Error = ConnectionError;
+
+    fn try_from(config: VersionConfig) -> Result<Self, Self::Error> {
+        Version::new(config.identifier, config.features)
     }
 }
 
@@ -49,3 +100,61 @@ pub fn dummy_raw_counterparty_conn(conn_id: Option<u64>) -> RawCounterparty {
         }),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connection_end_config_defaults() {
+        let conn_end: ConnectionEnd = ConnectionEndConfig::builder().build().try_into().unwrap();
+
+        assert_eq!(conn_end.state(), &State::Open);
+        assert_eq!(conn_end.versions(), Version::compatibles().as_slice());
+        assert_eq!(
+            conn_end.counterparty(),
+            &Counterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap()
+        );
+    }
+
+    #[test]
+    fn connection_end_config_is_validated() {
+        let versions = vec![
+            VersionConfig::builder().build().try_into().unwrap(),
+            VersionConfig::builder()
+                .identifier("2")
+                .build()
+                .try_into()
+                .unwrap(),
+        ];
+
+        let res = ConnectionEnd::try_from(
+            ConnectionEndConfig::builder()
+                .state(State::TryOpen)
+                .versions(versions.clone())
+                .build(),
+        );
+        assert!(matches!(res, Err(ConnectionError::InvalidVersionLength)));
+
+        let res = ConnectionEnd::try_from(
+            ConnectionEndConfig::builder()
+                .state(State::Init)
+                .versions(versions)
+                .build(),
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn version_config_is_validated() {
+        let res = Version::try_from(VersionConfig::builder().identifier("").build());
+        assert!(res.is_err());
+
+        let res = Version::try_from(
+            VersionConfig::builder()
+                .features(vec!["".to_string()])
+                .build(),
+        );
+        assert!(res.is_err());
+    }
+}
@@ -4,15 +4,21 @@ use core::ops::{Add, Sub};
 use core::time::Duration;
 
 use ibc::core::client::types::Height;
-use ibc::core::host::types::identifiers::ChainId;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::host::types::identifiers::{ChainId, ClientId};
+use ibc::core::host::types::limits::HostLimits;
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
-use parking_lot::Mutex;
+use ibc::core::primitives::{HostTimeProvider, Signer, StdTimeProvider, Timestamp};
 use tendermint_testgen::Validator as TestgenValidator;
 use typed_builder::TypedBuilder;
 
-use crate::hosts::block::{HostBlock, HostType};
-use crate::testapp::ibc::core::types::{MockContext, MockIbcStore, DEFAULT_BLOCK_TIME_SECS};
+use crate::hosts::block::{default_consensus_params, ConsensusParams, HostBlock, HostType};
+use crate::testapp::ibc::applications::transfer::bank::MockBank;
+use crate::testapp::ibc::core::client_ctx::MockTmVerifier;
+use crate::testapp::ibc::core::types::{
+    mock_commitment_prefix, MockContext, MockIbcStore, DEFAULT_BLOCK_TIME_SECS,
+};
 
 /// Configuration of the `MockContext` type for generating dummy contexts.
 #[derive(Debug, TypedBuilder)]
@@ -37,8 +43,39 @@ pub struct MockContextConfig {
     #[builder(default = Height::new(0, 5).expect("Never fails"))]
     latest_height: Height,
 
-    #[builder(default = Timestamp::now())]
-    latest_timestamp: Timestamp,
+    /// Defaults to the current time of the `time_provider`.
+    #[builder(default, setter(strip_option))]
+    latest_timestamp: Option<Timestamp>,
+
+    #[builder(default = Arc::new(StdTimeProvider))]
+    time_provider: Arc<dyn HostTimeProvider + Send + Sync>,
+
+    #[builder(default = default_consensus_params())]
+    consensus_params: ConsensusParams,
+
+    #[builder(default = ConnectionVersion::compatibles())]
+    connection_versions: Vec<ConnectionVersion>,
+
+    #[builder(default = mock_commitment_prefix())]
+    commitment_prefix: CommitmentPrefix,
+
+    #[builder(default)]
+    allow_empty_counterparty_prefix: bool,
+
+    #[builder(default)]
+    emit_sdk_message_events: bool,
+
+    #[builder(default)]
+    enforce_capabilities: bool,
+
+    #[builder(default)]
+    host_limits: HostLimits,
+
+    #[builder(default)]
+    client_expiry_grace_period: Option<Duration>,
+
+    #[builder(default)]
+    client_update_authorities: BTreeMap<ClientId, Signer>,
 }
 
 impl From<MockContextConfig> for MockContext {
@@ -66,8 +103,11 @@ impl From<MockContextConfig> for MockContext {
             "The version in the chain identifier must match the version in the latest height"
         );
 
-        let next_block_timestamp = params
+        let latest_timestamp = params
             .latest_timestamp
+            .unwrap_or_else(|| params.time_provider.now());
+
+        let next_block_timestamp = latest_timestamp
             .add(params.block_time)
             .expect("Never fails");
 
@@ -90,6 +130,7 @@ impl From<MockContextConfig> for MockContext {
                             .expect("Never fails"),
                         &validator_set_history[(n - i) as usize - 1],
                         &validator_set_history[(n - i) as usize],
+                        &params.consensus_params,
                     )
                 })
                 .collect()
@@ -110,6 +151,7 @@ impl From<MockContextConfig> for MockContext {
                         next_block_timestamp
                             .sub(params.block_time * ((i + 1) as u32))
                             .expect("Never fails"),
+                        &params.consensus_params,
                     )
                 })
                 .collect()
@@ -121,7 +163,22 @@ impl From<MockContextConfig> for MockContext {
             max_history_size: params.max_history_size,
             history,
             block_time: params.block_time,
-            ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
+            ibc_store: MockIbcStore::default(),
+            bank: MockBank::default(),
+            consensus_params: params.consensus_params,
+            connection_versions: params.connection_versions,
+            commitment_prefix: params.commitment_prefix,
+            allow_empty_counterparty_prefix: params.allow_empty_counterparty_prefix,
+            emit_sdk_message_events: params.emit_sdk_message_events,
+            enforce_capabilities: params.enforce_capabilities,
+            host_limits: params.host_limits,
+            client_expiry_grace_period: params.client_expiry_grace_period,
+            client_update_authorities: params.client_update_authorities,
+            client_verification_modes: BTreeMap::new(),
+            write_set: None,
+            validator_set_cache: None,
+            tm_verifier: MockTmVerifier::default(),
+            time_provider: params.time_provider,
         }
     }
 }
@@ -0,0 +1,428 @@
+//! Generates protobuf-encoded fixtures of the IBC messages out of
+//! human-readable TOML specs, and turns such fixtures back into specs.
+//!
+//! A spec names the type URL of the message and lays out its fields
+//! following the protobuf JSON mapping, i.e. 64-bit integers are given as
+//! strings and bytes are base64-encoded:
+//!
+//! ```toml
+//! type_url = "/ibc.core.channel.v1.MsgChannelCloseInit"
+//!
+//! [value]
+//! channelId = "channel-0"
+//! portId = "transfer"
+//! signer = "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"
+//! ```
+//!
+//! The fixture is the `Any` wrapping the message, which is what hosts
+//! receive in transactions. Specs are only accepted if the message survives
+//! the round-trip through its domain type unchanged. The fixtures of the
+//! `fixtures/messages` directory of this crate are generated out of the specs
+//! sitting next to them by the `ibc-fixture-gen` binary.
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use displaydoc::Display;
+use ibc::apps::nft_transfer::types::msgs::transfer as nft_transfer;
+use ibc::apps::transfer::types::msgs::transfer;
+use ibc::core::channel::types::msgs as channel;
+use ibc::core::client::types::msgs as client;
+use ibc::core::connection::types::msgs as connection;
+use ibc::core::primitives::prelude::*;
+use ibc::primitives::proto::Any;
+use ibc_proto::ibc::apps::nft_transfer::v1 as raw_nft_transfer;
+use ibc_proto::ibc::apps::transfer::v1 as raw_transfer;
+use ibc_proto::ibc::core::channel::v1 as raw_channel;
+use ibc_proto::ibc::core::client::v1 as raw_client;
+use ibc_proto::ibc::core::connection::v1 as raw_connection;
+use prost::Message;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+const TRANSFER_TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
+
+const NFT_TRANSFER_TYPE_URL: &str = "/ibc.applications.nft_transfer.v1.MsgTransfer";
+
+/// Dispatches the given function, generic over the raw and domain types of
+/// the message, according to the type URL of the message.
+macro_rules! for_message_type {
+    ($type_url:expr, $f:ident, $arg:expr) => {
+        match $type_url.as_str() {
+            client::CREATE_CLIENT_TYPE_URL => {
+                $f::<raw_client::MsgCreateClient, client::MsgCreateClient>($type_url, $arg)
+            }
+            client::UPDATE_CLIENT_TYPE_URL => {
+                $f::<raw_client::MsgUpdateClient, client::MsgUpdateClient>($type_url, $arg)
+            }
+            client::UPGRADE_CLIENT_TYPE_URL => {
+                $f::<raw_client::MsgUpgradeClient, client::MsgUpgradeClient>($type_url, $arg)
+            }
+            client::SUBMIT_MISBEHAVIOUR_TYPE_URL => $f::<
+                raw_client::MsgSubmitMisbehaviour,
+                client::MsgSubmitMisbehaviour,
+            >($type_url, $arg),
+            client::RECOVER_CLIENT_TYPE_URL => {
+                $f::<raw_client::MsgRecoverClient, client::MsgRecoverClient>($type_url, $arg)
+            }
+            connection::CONN_OPEN_INIT_TYPE_URL => $f::<
+                raw_connection::MsgConnectionOpenInit,
+                connection::MsgConnectionOpenInit,
+            >($type_url, $arg),
+            connection::CONN_OPEN_TRY_TYPE_URL => $f::<
+                raw_connection::MsgConnectionOpenTry,
+                connection::MsgConnectionOpenTry,
+            >($type_url, $arg),
+            connection::CONN_OPEN_ACK_TYPE_URL => $f::<
+                raw_connection::MsgConnectionOpenAck,
+                connection::MsgConnectionOpenAck,
+            >($type_url, $arg),
+            connection::CONN_OPEN_CONFIRM_TYPE_URL => $f::<
+                raw_connection::MsgConnectionOpenConfirm,
+                connection::MsgConnectionOpenConfirm,
+            >($type_url, $arg),
+            channel::CHAN_OPEN_INIT_TYPE_URL => {
+                $f::<raw_channel::MsgChannelOpenInit, channel::MsgChannelOpenInit>($type_url, $arg)
+            }
+            channel::CHAN_OPEN_TRY_TYPE_URL => {
+                $f::<raw_channel::MsgChannelOpenTry, channel::MsgChannelOpenTry>($type_url, $arg)
+            }
+            channel::CHAN_OPEN_ACK_TYPE_URL => {
+                $f::<raw_channel::MsgChannelOpenAck, channel::MsgChannelOpenAck>($type_url, $arg)
+            }
+            channel::CHAN_OPEN_CONFIRM_TYPE_URL => $f::<
+                raw_channel::MsgChannelOpenConfirm,
+                channel::MsgChannelOpenConfirm,
+            >($type_url, $arg),
+            channel::CHAN_CLOSE_INIT_TYPE_URL => $f::<
+                raw_channel::MsgChannelCloseInit,
+                channel::MsgChannelCloseInit,
+            >($type_url, $arg),
+            channel::CHAN_CLOSE_CONFIRM_TYPE_URL => $f::<
+                raw_channel::MsgChannelCloseConfirm,
+                channel::MsgChannelCloseConfirm,
+            >($type_url, $arg),
+            channel::RECV_PACKET_TYPE_URL => {
+                $f::<raw_channel::MsgRecvPacket, channel::MsgRecvPacket>($type_url, $arg)
+            }
+            channel::ACKNOWLEDGEMENT_TYPE_URL => {
+                $f::<raw_channel::MsgAcknowledgement, channel::MsgAcknowledgement>($type_url, $arg)
+            }
+            channel::TIMEOUT_TYPE_URL => {
+                $f::<raw_channel::MsgTimeout, channel::MsgTimeout>($type_url, $arg)
+            }
+            channel::TIMEOUT_ON_CLOSE_TYPE_URL => {
+                $f::<raw_channel::MsgTimeoutOnClose, channel::MsgTimeoutOnClose>($type_url, $arg)
+            }
+            TRANSFER_TYPE_URL => {
+                $f::<raw_transfer::MsgTransfer, transfer::MsgTransfer>($type_url, $arg)
+            }
+            NFT_TRANSFER_TYPE_URL => {
+                $f::<raw_nft_transfer::MsgTransfer, nft_transfer::MsgTransfer>($type_url, $arg)
+            }
+            _ => Err(FixtureError::UnknownTypeUrl {
+                type_url: $type_url.to_string(),
+            }),
+        }
+    };
+}
+
+/// Errors raised while generating or reading message fixtures.
+#[derive(Debug, Display)]
+pub enum FixtureError {
+    /// unsupported message type URL `{type_url}`
+    UnknownTypeUrl { type_url: String },
+    /// invalid TOML spec: `{description}`
+    InvalidSpec { description: String },
+    /// invalid `{type_url}` message: `{description}`
+    InvalidMessage {
+        type_url: String,
+        description: String,
+    },
+    /// `{type_url}` message does not round-trip through its domain type
+    NonCanonicalMessage { type_url: String },
+    /// invalid `{encoding}` payload: `{description}`
+    InvalidPayload {
+        encoding: Encoding,
+        description: String,
+    },
+    /// unknown encoding `{0}`, expected `hex` or `base64`
+    UnknownEncoding(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FixtureError {}
+
+/// Human-readable description of an IBC message.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageSpec {
+    pub type_url: String,
+    pub value: toml::Value,
+}
+
+impl MessageSpec {
+    /// Parses a spec out of its TOML representation.
+    pub fn from_toml(spec: &str) -> Result<Self, FixtureError> {
+        toml::from_str(spec).map_err(|e| FixtureError::InvalidSpec {
+            description: e.to_string(),
+        })
+    }
+
+    /// Renders the spec as TOML.
+    pub fn to_toml(&self) -> Result<String, FixtureError> {
+        toml::to_string(self).map_err(|e| FixtureError::InvalidSpec {
+            description: e.to_string(),
+        })
+    }
+
+    /// Encodes the message described by the spec into an `Any`.
+    #[allow(deprecated)]
+    pub fn encode(&self) -> Result<Any, FixtureError> {
+        for_message_type!(&self.type_url, encode_message, &self.value)
+    }
+
+    /// Recovers the spec of the message wrapped by the given `Any`.
+    #[allow(deprecated)]
+    pub fn decode(any: &Any) -> Result<Self, FixtureError> {
+        let value = for_message_type!(&any.type_url, decode_message, &any.value)?;
+
+        Ok(Self {
+            type_url: any.type_url.clone(),
+            value,
+        })
+    }
+}
+
+/// Text encodings of the fixtures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Display)]
+pub enum Encoding {
+    /// hex
+    Hex,
+    /// base64
+    Base64,
+}
+
+impl Encoding {
+    /// Encodes the given bytes.
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        let encoded = match self {
+            Self::Hex => subtle_encoding::hex::encode(bytes),
+            Self::Base64 => subtle_encoding::base64::encode(bytes),
+        };
+        String::from_utf8(encoded).expect("hex and base64 are valid UTF-8")
+    }
+
+    /// Decodes the given text, ignoring surrounding whitespace.
+    pub fn decode(&self, encoded: &str) -> Result<Vec<u8>, FixtureError> {
+        let encoded = encoded.trim().as_bytes();
+        match self {
+            Self::Hex => subtle_encoding::hex::decode(encoded),
+            Self::Base64 => subtle_encoding::base64::decode(encoded),
+        }
+        .map_err(|e| FixtureError::InvalidPayload {
+            encoding: *self,
+            description: e.to_string(),
+        })
+    }
+
+    /// The extension of the fixture files in this encoding.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Hex => "hex",
+            Self::Base64 => "b64",
+        }
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = FixtureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hex" => Ok(Self::Hex),
+            "base64" => Ok(Self::Base64),
+            _ => Err(FixtureError::UnknownEncoding(s.to_string())),
+        }
+    }
+}
+
+/// Encodes the spec into an `Any`, rendered in the given encoding.
+pub fn generate_fixture(spec: &MessageSpec, encoding: Encoding) -> Result<String, FixtureError> {
+    Ok(encoding.encode(&spec.encode()?.encode_to_vec()))
+}
+
+/// Decodes a fixture in the given encoding back into the spec of its message.
+pub fn read_fixture(fixture: &str, encoding: Encoding) -> Result<MessageSpec, FixtureError> {
+    let bytes = encoding.decode(fixture)?;
+    let any = Any::decode(bytes.as_slice()).map_err(|e| FixtureError::InvalidPayload {
+        encoding,
+        description: e.to_string(),
+    })?;
+
+    MessageSpec::decode(&any)
+}
+
+fn encode_message<Raw, Domain>(type_url: &str, value: &toml::Value) -> Result<Any, FixtureError>
+where
+    Raw: Message + Clone + Default + PartialEq + DeserializeOwned + From<Domain>,
+    Domain: TryFrom<Raw>,
+    <Domain as TryFrom<Raw>>::Error: Display,
+{
+    let raw: Raw = value
+        .clone()
+        .try_into()
+        .map_err(|e| FixtureError::InvalidSpec {
+            description: e.to_string(),
+        })?;
+
+    let domain = into_domain::<Raw, Domain>(type_url, raw.clone())?;
+
+    if Raw::from(domain) != raw {
+        return Err(FixtureError::NonCanonicalMessage {
+            type_url: type_url.to_string(),
+        });
+    }
+
+    Ok(Any {
+        type_url: type_url.to_string(),
+        value: raw.encode_to_vec(),
+    })
+}
+
+fn decode_message<Raw, Domain>(type_url: &str, value: &[u8]) -> Result<toml::Value, FixtureError>
+where
+    Raw: Message + Default + Serialize + From<Domain>,
+    Domain: TryFrom<Raw>,
+    <Domain as TryFrom<Raw>>::Error: Display,
+{
+    let raw = Raw::decode(value).map_err(|e| FixtureError::InvalidMessage {
+        type_url: type_url.to_string(),
+        description: e.to_string(),
+    })?;
+
+    let raw = Raw::from(into_domain::<Raw, Domain>(type_url, raw)?);
+
+    toml::Value::try_from(raw).map_err(|e| FixtureError::InvalidSpec {
+        description: e.to_string(),
+    })
+}
+
+fn into_domain<Raw, Domain>(type_url: &str, raw: Raw) -> Result<Domain, FixtureError>
+where
+    Domain: TryFrom<Raw>,
+    <Domain as TryFrom<Raw>>::Error: Display,
+{
+    Domain::try_from(raw).map_err(|e| FixtureError::InvalidMessage {
+        type_url: type_url.to_string(),
+        description: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn checked_in_fixtures_round_trip() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/messages");
+
+        let mut specs = 0;
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map_or(true, |ext| ext != "toml") {
+                continue;
+            }
+
+            let spec = MessageSpec::from_toml(&fs::read_to_string(&path).unwrap()).unwrap();
+            let fixture = fs::read_to_string(path.with_extension("hex")).unwrap();
+
+            assert_eq!(
+                generate_fixture(&spec, Encoding::Hex).unwrap(),
+                fixture.trim(),
+                "stale fixture for {}",
+                path.display()
+            );
+            assert_eq!(read_fixture(&fixture, Encoding::Hex).unwrap(), spec);
+
+            specs += 1;
+        }
+
+        assert!(specs > 0);
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        let spec = MessageSpec::from_toml(
+            r#"
+            type_url = "/ibc.core.channel.v1.MsgChannelCloseInit"
+
+            [value]
+            portId = "transfer"
+            channelId = "channel-0"
+            signer = "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"
+            "#,
+        )
+        .unwrap();
+
+        let fixture = generate_fixture(&spec, Encoding::Base64).unwrap();
+
+        assert_eq!(read_fixture(&fixture, Encoding::Base64).unwrap(), spec);
+    }
+
+    #[test]
+    fn invalid_specs() {
+        let unknown = MessageSpec::from_toml(
+            r#"
+            type_url = "/ibc.core.client.v1.MsgUnknown"
+            value = {}
+            "#,
+        )
+        .unwrap();
+        assert!(matches!(
+            unknown.encode(),
+            Err(FixtureError::UnknownTypeUrl { .. })
+        ));
+
+        let invalid = MessageSpec::from_toml(
+            r#"
+            type_url = "/ibc.core.channel.v1.MsgChannelCloseInit"
+
+            [value]
+            portId = "transfer"
+            channelId = "channel/0"
+            signer = "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"
+            "#,
+        )
+        .unwrap();
+        assert!(matches!(
+            invalid.encode(),
+            Err(FixtureError::InvalidMessage { .. })
+        ));
+
+        // A missing timeout height is re-encoded as the zero height.
+        let non_canonical = MessageSpec::from_toml(
+            r#"
+            type_url = "/ibc.applications.transfer.v1.MsgTransfer"
+
+            [value]
+            sourcePort = "transfer"
+            sourceChannel = "channel-0"
+            sender = "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"
+            receiver = "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"
+            timeoutTimestamp = "1"
+
+            [value.token]
+            denom = "uatom"
+            amount = "100"
+            "#,
+        )
+        .unwrap();
+        assert!(matches!(
+            non_canonical.encode(),
+            Err(FixtureError::NonCanonicalMessage { .. })
+        ));
+    }
+}
@@ -1,8 +1,12 @@
 pub mod applications;
 pub mod clients;
 pub mod core;
+#[cfg(feature = "fixture-gen")]
+pub mod messages;
 use alloc::fmt::Debug;
 
+use ::core::any::type_name;
+use ::core::mem::discriminant;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::primitives::prelude::*;
 
@@ -19,11 +23,11 @@ pub struct Fixture<M: Debug> {
 }
 
 impl<M: Debug> Fixture<M> {
-    pub fn generate_error_msg(
+    pub fn generate_error_msg<T: Debug>(
         &self,
         expect: &Expect,
         process: &str,
-        res: &Result<(), ContextError>,
+        res: &Result<T, ContextError>,
     ) -> String {
         let base_error = match expect {
             Expect::Success => "step failed!",
@@ -35,3 +39,138 @@ impl<M: Debug> Fixture<M> {
         )
     }
 }
+
+/// The outcome expected of a [`HandlerTest`].
+pub enum Outcome {
+    /// The message is processed, emitting events of the given types, in order
+    /// (see `IbcEvent::event_type`).
+    Success(Vec<&'static str>),
+    /// The message is rejected with the given error.
+    Failure(ContextError),
+}
+
+/// A case of a table-driven handler test, run by [`run_handler_tests`].
+pub struct HandlerTest<M: Debug> {
+    pub name: String,
+    pub fixture: Fixture<M>,
+    pub outcome: Outcome,
+}
+
+/// Processes the message of each test case on its context with `handler`,
+/// and checks the outcome against the expected one.
+///
+/// Upon success, the types of the emitted events must match the expected
+/// ones. Upon failure, the error must be of the same variant as the expected
+/// one (see [`is_same_error_variant`]), and the IBC store of the context must
+/// be left unchanged.
+pub fn run_handler_tests<M, F>(tests: Vec<HandlerTest<M>>, mut handler: F)
+where
+    M: Clone + Debug,
+    F: FnMut(&mut MockContext, M) -> Result<(), ContextError>,
+{
+    for mut test in tests {
+        let store_before = test.fixture.ctx.ibc_store.clone();
+        let events_before = test.fixture.ctx.get_events().len();
+
+        let res = handler(&mut test.fixture.ctx, test.fixture.msg.clone());
+
+        match test.outcome {
+            Outcome::Success(expected_events) => {
+                assert!(
+                    res.is_ok(),
+                    "{}: step failed!\n{res:?}\n{:?}",
+                    test.name,
+                    test.fixture.msg
+                );
+
+                let events = test.fixture.ctx.get_events();
+                let event_types: Vec<&str> = events[events_before..]
+                    .iter()
+                    .map(|event| event.event_type())
+                    .collect();
+
+                assert_eq!(
+                    event_types, expected_events,
+                    "{}: unexpected events",
+                    test.name
+                );
+            }
+            Outcome::Failure(expected_err) => {
+                let Err(err) = res else {
+                    panic!(
+                        "{}: step passed but was supposed to fail!\n{:?}",
+                        test.name, test.fixture.msg
+                    );
+                };
+
+                assert!(
+                    is_same_error_variant(&err, &expected_err),
+                    "{}: expected error {expected_err:?}, got {err:?}",
+                    test.name
+                );
+                assert_eq!(
+                    test.fixture.ctx.ibc_store, store_before,
+                    "{}: the failed step changed the store",
+                    test.name
+                );
+            }
+        }
+    }
+}
+
+/// A case of a table-driven decoding test, run by [`run_decoding_tests`].
+pub struct DecodingTest<R: Debug> {
+    pub name: String,
+    pub raw: R,
+    pub want_pass: bool,
+}
+
+/// Converts the raw message of each test case into a `T`, and checks that
+/// the conversion passes or fails as expected.
+pub fn run_decoding_tests<T, R>(tests: Vec<DecodingTest<R>>)
+where
+    T: TryFrom<R>,
+    T::Error: Debug,
+    R: Clone + Debug,
+{
+    for test in tests {
+        let res = T::try_from(test.raw.clone());
+
+        assert_eq!(
+            test.want_pass,
+            res.is_ok(),
+            "{}::try_from failed for test {}, \nraw msg {:?} with error {:?}",
+            type_name::<T>(),
+            test.name,
+            test.raw,
+            res.err(),
+        );
+    }
+}
+
+/// Returns whether both errors are of the same variant, down to the variant of
+/// the error of the module that raised them. Host errors, which are opaque, are
+/// compared through their `Display` representations.
+pub fn is_same_error_variant(err: &ContextError, expected: &ContextError) -> bool {
+    match (err, expected) {
+        (ContextError::ClientError(e), ContextError::ClientError(expected)) => {
+            discriminant(e) == discriminant(expected)
+        }
+        (ContextError::ConnectionError(e), ContextError::ConnectionError(expected)) => {
+            discriminant(e) == discriminant(expected)
+        }
+        (ContextError::ChannelError(e), ContextError::ChannelError(expected)) => {
+            discriminant(e) == discriminant(expected)
+        }
+        (ContextError::PacketError(e), ContextError::PacketError(expected)) => {
+            discriminant(e) == discriminant(expected)
+        }
+        (ContextError::RouterError(e), ContextError::RouterError(expected)) => {
+            discriminant(e) == discriminant(expected)
+        }
+        (ContextError::HostError(e), ContextError::HostError(expected)) => {
+            e.to_string() == expected.to_string()
+        }
+        _ => false,
+    }
+}
@@ -1,6 +1,7 @@
 //! Host chain types and methods, used by context mock.
 
 use core::str::FromStr;
+use core::time::Duration;
 
 use ibc::clients::tendermint::consensus_state::ConsensusState as TmConsensusState;
 use ibc::clients::tendermint::types::proto::v1::Header as RawHeader;
@@ -12,7 +13,11 @@ use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::{Any, Protobuf};
 use ibc::primitives::ToVec;
-use tendermint::block::Header as TmHeader;
+use tendermint::block::{Header as TmHeader, Size as BlockSize};
+use tendermint::consensus::params::{AbciParams, ValidatorParams, VersionParams};
+pub use tendermint::consensus::Params as ConsensusParams;
+use tendermint::evidence::{Duration as EvidenceDuration, Params as EvidenceParams};
+use tendermint::public_key::Algorithm;
 use tendermint::validator::Set as ValidatorSet;
 use tendermint_testgen::light_block::TmLightBlock;
 use tendermint_testgen::{
@@ -35,12 +40,36 @@ pub enum HostType {
     SyntheticTendermint,
 }
 
+/// Returns the consensus parameters of a CometBFT chain out of the box, which
+/// mock hosts run with unless configured otherwise.
+pub fn default_consensus_params() -> ConsensusParams {
+    ConsensusParams {
+        block: BlockSize {
+            max_bytes: 22_020_096,
+            max_gas: -1,
+            time_iota_ms: BlockSize::default_time_iota_ms(),
+        },
+        evidence: EvidenceParams {
+            max_age_num_blocks: 100_000,
+            max_age_duration: EvidenceDuration(Duration::from_secs(48 * 3600)),
+            max_bytes: 1_048_576,
+        },
+        validator: ValidatorParams {
+            pub_key_types: vec![Algorithm::Ed25519],
+        },
+        version: Some(VersionParams::default()),
+        abci: AbciParams::default(),
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SyntheticTmBlock {
     pub trusted_height: Height,
     pub trusted_next_validators: ValidatorSet,
     pub light_block: TmLightBlock,
+    /// The consensus parameters the block was produced under.
+    pub consensus_params: ConsensusParams,
 }
 
 impl SyntheticTmBlock {
@@ -55,6 +84,7 @@ impl From<SyntheticTmBlock> for Header {
             trusted_height,
             trusted_next_validators,
             light_block,
+            ..
         } = light_block;
         Self {
             signed_header: light_block.signed_header,
@@ -113,25 +143,40 @@ impl HostBlock {
         }
     }
 
-    /// Generates a new block at `height` for the given chain identifier and chain type.
+    /// Returns the consensus parameters the block was produced under, if any.
+    /// Mock blocks carry none.
+    pub fn consensus_params(&self) -> Option<&ConsensusParams> {
+        match self {
+            HostBlock::Mock(_) => None,
+            HostBlock::SyntheticTendermint(light_block) => Some(&light_block.consensus_params),
+        }
+    }
+
+    /// Generates a new block at `height` for the given chain identifier and
+    /// chain type, produced under `consensus_params`.
     pub fn generate_block(
         chain_id: ChainId,
         chain_type: HostType,
         height: u64,
         timestamp: Timestamp,
+        consensus_params: &ConsensusParams,
     ) -> HostBlock {
         match chain_type {
             HostType::Mock => HostBlock::Mock(Box::new(MockHeader {
                 height: Height::new(chain_id.revision_number(), height).expect("Never fails"),
                 timestamp,
             })),
-            HostType::SyntheticTendermint => HostBlock::SyntheticTendermint(Box::new(
-                Self::generate_tm_block(chain_id, height, timestamp),
-            )),
+            HostType::SyntheticTendermint => {
+                HostBlock::SyntheticTendermint(Box::new(SyntheticTmBlock {
+                    consensus_params: consensus_params.clone(),
+                    ..Self::generate_tm_block(chain_id, height, timestamp)
+                }))
+            }
         }
     }
 
-    /// Generates a new block at `height` for the given chain identifier, chain type and validator sets.
+    /// Generates a new block at `height` for the given chain identifier, chain
+    /// type and validator sets, produced under `consensus_params`.
     pub fn generate_block_with_validators(
         chain_id: ChainId,
         chain_type: HostType,
@@ -139,6 +184,7 @@ impl HostBlock {
         timestamp: Timestamp,
         validators: &[TestgenValidator],
         next_validators: &[TestgenValidator],
+        consensus_params: &ConsensusParams,
     ) -> HostBlock {
         match chain_type {
             HostType::Mock => HostBlock::Mock(Box::new(MockHeader {
@@ -163,11 +209,15 @@ impl HostBlock {
                         .expect("Never fails"),
                     trusted_next_validators: light_block.next_validators.clone(),
                     light_block,
+                    consensus_params: consensus_params.clone(),
                 }))
             }
         }
     }
 
+    /// Generates a new Tendermint block at `height` for the given chain
+    /// identifier, produced under the [default consensus
+    /// parameters](default_consensus_params).
     pub fn generate_tm_block(
         chain_id: ChainId,
         height: u64,
@@ -192,6 +242,7 @@ impl HostBlock {
             trusted_height: Height::new(chain_id.revision_number(), 1).expect("Never fails"),
             trusted_next_validators: light_block.next_validators.clone(),
             light_block,
+            consensus_params: default_consensus_params(),
         }
     }
 
@@ -238,6 +289,7 @@ impl From<HostBlock> for Any {
                 trusted_height,
                 trusted_next_validators,
                 light_block,
+                ..
             } = light_block;
 
             RawHeader {
@@ -17,5 +17,6 @@ extern crate std;
 
 pub mod fixtures;
 pub mod hosts;
+pub mod node;
 pub mod relayer;
 pub mod testapp;
@@ -0,0 +1,193 @@
+use alloc::collections::BTreeMap;
+
+use ibc::core::client::types::Height;
+use ibc::core::entrypoint::dispatch_batch;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::write_set::{StoreWrite, WriteSet};
+use ibc::core::primitives::prelude::*;
+use tendermint::crypto::default::Sha256;
+use tendermint::merkle::simple_hash_from_byte_vectors;
+use tendermint::AppHash;
+
+use crate::testapp::ibc::core::router::MockRouter;
+use crate::testapp::ibc::core::types::MockContext;
+
+/// The outcome of a transaction: the events it emitted, or the error that
+/// made it fail, in which case none of its writes was kept.
+pub type TxResult = Result<Vec<IbcEvent>, ContextError>;
+
+/// The results of a committed block.
+#[derive(Debug)]
+pub struct BlockResults {
+    /// The height of the committed block.
+    pub height: Height,
+    /// The app hash committing to the state after the block.
+    pub app_hash: AppHash,
+    /// The results of the transactions of the block, in delivery order.
+    pub tx_results: Vec<TxResult>,
+}
+
+/// An in-memory node hosting the IBC handlers, in the manner of `basecoin`.
+///
+/// It drives a [`MockContext`] and a [`MockRouter`] through the block
+/// production loop of a real host: [`begin_block`](Self::begin_block), then
+/// [`deliver_tx`](Self::deliver_tx) for every transaction of the block, then
+/// [`commit`](Self::commit). Examples and fuzzers can thus exercise the
+/// handlers the way a chain does, with transactions applied atomically and
+/// events flushed per block.
+///
+/// The node commits to the key-value store formed by the writes of the
+/// delivered transactions, as recorded in the write set of the context. The
+/// app hash is the simple Merkle root of its entries, ordered by path. Writes
+/// performed on the context before the node was created are not committed.
+pub struct MockNode {
+    pub ctx: MockContext,
+    pub router: MockRouter,
+    committed_state: BTreeMap<String, Vec<u8>>,
+    app_hash: AppHash,
+    block_writes: Vec<StoreWrite>,
+    block_tx_results: Option<Vec<TxResult>>,
+}
+
+impl Default for MockNode {
+    fn default() -> Self {
+        Self::new(MockContext::default(), MockRouter::new_with_transfer())
+    }
+}
+
+impl MockNode {
+    /// Creates a node on top of the given context, routing packets with the
+    /// given router.
+    pub fn new(ctx: MockContext, router: MockRouter) -> Self {
+        let committed_state = BTreeMap::new();
+        let app_hash = app_hash_of(&committed_state);
+
+        Self {
+            ctx: ctx.with_write_set(),
+            router,
+            committed_state,
+            app_hash,
+            block_writes: Vec::new(),
+            block_tx_results: None,
+        }
+    }
+
+    /// Returns the app hash of the latest committed block.
+    pub fn app_hash(&self) -> &AppHash {
+        &self.app_hash
+    }
+
+    /// Returns the committed store, mapping paths to the bytes written under
+    /// them.
+    pub fn committed_state(&self) -> &BTreeMap<String, Vec<u8>> {
+        &self.committed_state
+    }
+
+    /// Returns the height of the latest committed block.
+    pub fn latest_height(&self) -> Height {
+        self.ctx.latest_height()
+    }
+
+    /// Opens a new block on top of the latest one. Transactions are then
+    /// executed at the timestamp of the new block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a block is already open.
+    pub fn begin_block(&mut self) {
+        assert!(
+            self.block_tx_results.is_none(),
+            "the previous block was not committed"
+        );
+
+        self.block_tx_results = Some(Vec::new());
+    }
+
+    /// Delivers a transaction made of the given messages to the open block.
+    ///
+    /// The messages are dispatched in order, and the transaction fails at the
+    /// first failing message, in which case the state is reverted to what it
+    /// was before the transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no block is open.
+    pub fn deliver_tx(&mut self, msgs: impl IntoIterator<Item = MsgEnvelope>) -> &TxResult {
+        let tx_results = self.block_tx_results.as_mut().expect("no block is open");
+
+        let mut results =
+            dispatch_batch(&mut self.ctx, &mut self.router, msgs.into_iter().collect());
+
+        let tx_writes = self.ctx.write_set.as_mut().map(WriteSet::take);
+        let tx_result = match results.pop() {
+            Some(Err(e)) => Err(e),
+            _ => {
+                self.block_writes.extend(tx_writes.into_iter().flatten());
+                Ok(core::mem::take(&mut self.ctx.ibc_store.events))
+            }
+        };
+
+        tx_results.push(tx_result);
+        tx_results.last().expect("Never fails")
+    }
+
+    /// Commits the open block: applies the writes of its transactions to the
+    /// committed store, and appends the block to the history of the host.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no block is open.
+    pub fn commit(&mut self) -> BlockResults {
+        let tx_results = self.block_tx_results.take().expect("no block is open");
+
+        for write in self.block_writes.drain(..) {
+            let path = write.path.to_string();
+            match write.value {
+                Some(value) => self.committed_state.insert(path, value),
+                None => self.committed_state.remove(&path),
+            };
+        }
+        self.app_hash = app_hash_of(&self.committed_state);
+
+        self.ctx.advance_host_chain_height();
+
+        BlockResults {
+            height: self.ctx.latest_height(),
+            app_hash: self.app_hash.clone(),
+            tx_results,
+        }
+    }
+
+    /// Produces a whole block out of the given transactions.
+    pub fn produce_block(
+        &mut self,
+        txs: impl IntoIterator<Item = Vec<MsgEnvelope>>,
+    ) -> BlockResults {
+        self.begin_block();
+        for tx in txs {
+            self.deliver_tx(tx);
+        }
+        self.commit()
+    }
+}
+
+/// Computes the simple Merkle root of the entries of the store. Each leaf is
+/// the length-prefixed path followed by the value.
+fn app_hash_of(state: &BTreeMap<String, Vec<u8>>) -> AppHash {
+    let leaves: Vec<Vec<u8>> = state
+        .iter()
+        .map(|(path, value)| {
+            [
+                (path.len() as u64).to_be_bytes().as_slice(),
+                path.as_bytes(),
+                value,
+            ]
+            .concat()
+        })
+        .collect();
+
+    AppHash::try_from(simple_hash_from_byte_vectors::<Sha256>(&leaves).to_vec())
+        .expect("Never fails")
+}
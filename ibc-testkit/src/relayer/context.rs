@@ -1,12 +1,18 @@
 use ibc::core::client::context::ClientValidationContext;
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::handler::types::error::ContextError;
-use ibc::core::host::types::identifiers::ClientId;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::path::{ClientConsensusStatePath, Path};
+use ibc::core::host::{
+    ConnectionEndValidationContext, ConnectionValidationContext, HostValidationContext,
+};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
 
-use crate::testapp::ibc::clients::AnyClientState;
+use crate::fixtures::core::commitment::dummy_commitment_proof_bytes;
+use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use crate::testapp::ibc::core::types::MockContext;
 /// Trait capturing all dependencies (i.e., the context) which algorithms in ICS18 require to
 /// relay packets between chains. This trait comprises the dependencies towards a single chain.
@@ -21,13 +27,34 @@ pub trait RelayerContext {
     /// Wrapper over the `/abci_query?path=..` endpoint.
     fn query_client_full_state(&self, client_id: &ClientId) -> Option<AnyClientState>;
 
+    /// Returns the consensus state at `height` of the client with the given
+    /// `client_id` on this chain.
+    fn query_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<AnyConsensusState, ContextError>;
+
+    /// Returns the connection end with the given `connection_id` on this chain.
+    fn query_connection_end(
+        &self,
+        connection_id: &ConnectionId,
+    ) -> Result<ConnectionEnd, ContextError>;
+
+    /// Returns the prefix of the IBC store of this chain.
+    fn query_commitment_prefix(&self) -> CommitmentPrefix;
+
+    /// Returns a proof that the value under `path` is stored on this chain at
+    /// its latest height.
+    fn query_membership_proof(&self, path: &Path) -> CommitmentProofBytes;
+
     /// Temporary solution. Similar to `CosmosSDKChain::key_and_signer()` but simpler.
     fn signer(&self) -> Signer;
 }
 
 impl RelayerContext for MockContext {
     fn query_latest_height(&self) -> Result<Height, ContextError> {
-        ValidationContext::host_height(self)
+        HostValidationContext::host_height(self)
     }
 
     fn query_client_full_state(&self, client_id: &ClientId) -> Option<AnyClientState> {
@@ -35,6 +62,35 @@ impl RelayerContext for MockContext {
         self.client_state(client_id).ok()
     }
 
+    fn query_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<AnyConsensusState, ContextError> {
+        self.get_client_validation_context()
+            .consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ))
+    }
+
+    fn query_connection_end(
+        &self,
+        connection_id: &ConnectionId,
+    ) -> Result<ConnectionEnd, ContextError> {
+        self.connection_end(connection_id)
+    }
+
+    fn query_commitment_prefix(&self) -> CommitmentPrefix {
+        self.commitment_prefix()
+    }
+
+    fn query_membership_proof(&self, _path: &Path) -> CommitmentProofBytes {
+        // Mock clients accept any proof.
+        dummy_commitment_proof_bytes()
+    }
+
     fn signer(&self) -> Signer {
         "0CDA3F47EF3C4906693B170EF650EB968C5F4B2C"
             .to_string()
@@ -170,7 +226,7 @@ mod tests {
 
             // - send the message to B. We bypass ICS18 interface and call directly into
             // MockContext `recv` method (to avoid additional serialization steps).
-            let dispatch_res_b = ctx_b.deliver(&mut router_b, MsgEnvelope::Client(client_msg_b));
+            let dispatch_res_b = ctx_b.deliver(&mut router_b, [MsgEnvelope::Client(client_msg_b)]);
             let validation_res = ctx_b.validate();
             assert!(
                 validation_res.is_ok(),
@@ -209,7 +265,7 @@ mod tests {
             debug!("client_msg_a = {:?}", client_msg_a);
 
             // - send the message to A
-            let dispatch_res_a = ctx_a.deliver(&mut router_a, MsgEnvelope::Client(client_msg_a));
+            let dispatch_res_a = ctx_a.deliver(&mut router_a, [MsgEnvelope::Client(client_msg_a)]);
             let validation_res = ctx_a.validate();
             assert!(
                 validation_res.is_ok(),
@@ -1,8 +1,11 @@
 use displaydoc::Display;
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::primitives::prelude::*;
 
 #[derive(Debug, Display)]
 pub enum RelayerError {
@@ -24,6 +27,10 @@ pub enum RelayerError {
     TransactionFailed(ContextError),
     /// connection error: `{0}`
     Connection(ConnectionError),
+    /// query failed error: `{0}`
+    QueryFailed(ContextError),
+    /// channel error: `{0}`
+    Channel(ChannelError),
 }
 
 #[cfg(feature = "std")]
@@ -32,7 +39,28 @@ impl std::error::Error for RelayerError {
         match &self {
             Self::TransactionFailed(e) => Some(e),
             Self::Connection(e) => Some(e),
+            Self::QueryFailed(e) => Some(e),
+            Self::Channel(e) => Some(e),
             _ => None,
         }
     }
 }
+
+impl From<RelayerError> for ContextError {
+    fn from(e: RelayerError) -> Self {
+        match e {
+            RelayerError::TransactionFailed(e) | RelayerError::QueryFailed(e) => e,
+            RelayerError::Connection(e) => ContextError::ConnectionError(e),
+            RelayerError::Channel(e) => ContextError::ChannelError(e),
+            RelayerError::ClientStateNotFound { client_id } => {
+                ContextError::ClientError(ClientError::ClientStateNotFound { client_id })
+            }
+            e @ (RelayerError::ClientAlreadyUpToDate { .. }
+            | RelayerError::ClientAtHigherHeight { .. }) => {
+                ContextError::ClientError(ClientError::Other {
+                    description: e.to_string(),
+                })
+            }
+        }
+    }
+}
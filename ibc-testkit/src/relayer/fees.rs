@@ -0,0 +1,464 @@
+//! Simulates the fee market of relayers, so that economic tests, such as
+//! whether the fees of a packet cover the cost of relaying it, or whether the
+//! fees left unclaimed are refunded upon timeouts, can run against the mock
+//! chains.
+//!
+//! The fees of a packet are escrowed as prescribed by ICS-29: the relayer of
+//! its `RecvPacket` earns the receive fee, the relayer of its
+//! `Acknowledgement` the ack fee, and the relayer of its timeout the timeout
+//! fee, while the fees that no relayer earned are refunded to their payer.
+
+use alloc::collections::BTreeMap;
+
+use displaydoc::Display;
+use ibc::core::channel::types::msgs::PacketMsg;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::router::StagingRouter;
+
+use crate::relayer::error::RelayerError;
+use crate::testapp::ibc::core::types::MockContext;
+
+/// The cost that a relayer incurs for delivering each message, by the type
+/// URL of the message.
+#[derive(Clone, Debug, Default)]
+pub struct RelayCosts {
+    default_cost: u128,
+    costs: BTreeMap<&'static str, u128>,
+}
+
+impl RelayCosts {
+    /// Charges `default_cost` for the messages without a cost of their own.
+    pub fn new(default_cost: u128) -> Self {
+        Self {
+            default_cost,
+            costs: BTreeMap::new(),
+        }
+    }
+
+    /// Charges `cost` for the messages of the given type URL.
+    pub fn with_cost(mut self, type_url: &'static str, cost: u128) -> Self {
+        self.costs.insert(type_url, cost);
+        self
+    }
+
+    /// Returns the cost of delivering the given message.
+    pub fn cost_of(&self, msg: &MsgEnvelope) -> u128 {
+        self.costs
+            .get(msg.type_url())
+            .copied()
+            .unwrap_or(self.default_cost)
+    }
+}
+
+/// The fees incentivizing the relaying of a packet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PacketFee {
+    pub recv_fee: u128,
+    pub ack_fee: u128,
+    pub timeout_fee: u128,
+}
+
+impl PacketFee {
+    /// Returns the amount escrowed for the packet.
+    pub fn total(&self) -> u128 {
+        self.recv_fee + self.ack_fee + self.timeout_fee
+    }
+}
+
+/// Identifies a packet by its port, channel and sequence on the sending
+/// chain.
+pub type PacketKey = (PortId, ChannelId, Sequence);
+
+fn packet_key(packet: &Packet) -> PacketKey {
+    (
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        packet.seq_on_a,
+    )
+}
+
+/// How the lifecycle of a packet ended, along with the relayer that ended it.
+#[derive(Clone, Debug)]
+enum Completion {
+    Acknowledged(Signer),
+    TimedOut(Signer),
+}
+
+#[derive(Clone, Debug)]
+struct EscrowedFee {
+    payer: Signer,
+    fee: PacketFee,
+    forward_relayer: Option<Signer>,
+    completion: Option<Completion>,
+}
+
+#[derive(Debug, Display)]
+pub enum FeeError {
+    /// fee already escrowed for packet `{sequence}` on `{port_id}/{channel_id}`
+    FeeAlreadyEscrowed {
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+    },
+    /// no fee escrowed for packet `{sequence}` on `{port_id}/{channel_id}`
+    FeeNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+    },
+    /// packet `{sequence}` on `{port_id}/{channel_id}` was neither acknowledged nor timed out
+    PacketNotCompleted {
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FeeError {}
+
+impl FeeError {
+    fn fee_not_found((port_id, channel_id, sequence): PacketKey) -> Self {
+        Self::FeeNotFound {
+            port_id,
+            channel_id,
+            sequence,
+        }
+    }
+}
+
+/// Escrows the fees paid for packets, records which relayers relayed them,
+/// and distributes the fees once claimed.
+#[derive(Clone, Debug, Default)]
+pub struct FeeMarket {
+    escrows: BTreeMap<PacketKey, EscrowedFee>,
+    balances: BTreeMap<Signer, u128>,
+}
+
+impl FeeMarket {
+    /// Escrows the fee that `payer` pays for relaying the packet.
+    pub fn pay_packet_fee(
+        &mut self,
+        key: PacketKey,
+        payer: Signer,
+        fee: PacketFee,
+    ) -> Result<(), FeeError> {
+        if self.escrows.contains_key(&key) {
+            let (port_id, channel_id, sequence) = key;
+            return Err(FeeError::FeeAlreadyEscrowed {
+                port_id,
+                channel_id,
+                sequence,
+            });
+        }
+
+        self.escrows.insert(
+            key,
+            EscrowedFee {
+                payer,
+                fee,
+                forward_relayer: None,
+                completion: None,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Records that `relayer` delivered the given message, for the packets it
+    /// carries for which a fee is escrowed.
+    ///
+    /// Only the first delivery of each step of a packet is rewarded, as the
+    /// later ones fail on chain.
+    pub fn record(&mut self, msg: &MsgEnvelope, relayer: &Signer) {
+        let MsgEnvelope::Packet(msg) = msg else {
+            return;
+        };
+
+        match msg {
+            PacketMsg::Recv(msg) => {
+                if let Some(escrow) = self.escrows.get_mut(&packet_key(&msg.packet)) {
+                    escrow
+                        .forward_relayer
+                        .get_or_insert_with(|| relayer.clone());
+                }
+            }
+            PacketMsg::Ack(msg) => {
+                self.complete(&msg.packet, Completion::Acknowledged(relayer.clone()))
+            }
+            PacketMsg::Timeout(msg) => {
+                self.complete(&msg.packet, Completion::TimedOut(relayer.clone()))
+            }
+            PacketMsg::TimeoutOnClose(msg) => {
+                self.complete(&msg.packet, Completion::TimedOut(relayer.clone()))
+            }
+            PacketMsg::TimeoutBatch(msg) => {
+                for packet in &msg.packets {
+                    self.complete(packet, Completion::TimedOut(relayer.clone()))
+                }
+            }
+        }
+    }
+
+    fn complete(&mut self, packet: &Packet, completion: Completion) {
+        if let Some(escrow) = self.escrows.get_mut(&packet_key(packet)) {
+            escrow.completion.get_or_insert(completion);
+        }
+    }
+
+    /// Distributes the fee escrowed for an acknowledged or timed out packet
+    /// to the relayers that relayed it, refunding the rest to its payer.
+    pub fn claim(&mut self, key: PacketKey) -> Result<(), FeeError> {
+        let escrow = self
+            .escrows
+            .get(&key)
+            .cloned()
+            .ok_or_else(|| FeeError::fee_not_found(key.clone()))?;
+
+        let Some(completion) = escrow.completion else {
+            let (port_id, channel_id, sequence) = key;
+            return Err(FeeError::PacketNotCompleted {
+                port_id,
+                channel_id,
+                sequence,
+            });
+        };
+
+        self.escrows.remove(&key);
+
+        let EscrowedFee {
+            payer,
+            fee,
+            forward_relayer,
+            ..
+        } = escrow;
+
+        match completion {
+            Completion::Acknowledged(reverse_relayer) => {
+                let forward_relayer = forward_relayer.unwrap_or_else(|| payer.clone());
+                self.credit(&forward_relayer, fee.recv_fee);
+                self.credit(&reverse_relayer, fee.ack_fee);
+                self.credit(&payer, fee.timeout_fee);
+            }
+            Completion::TimedOut(timeout_relayer) => {
+                self.credit(&timeout_relayer, fee.timeout_fee);
+                self.credit(&payer, fee.recv_fee + fee.ack_fee);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn credit(&mut self, account: &Signer, amount: u128) {
+        *self.balances.entry(account.clone()).or_default() += amount;
+    }
+
+    /// Returns the fees earned or refunded to the given account.
+    pub fn balance(&self, account: &Signer) -> u128 {
+        self.balances.get(account).copied().unwrap_or_default()
+    }
+
+    /// Returns the total amount of fees still held in escrow.
+    pub fn total_escrowed(&self) -> u128 {
+        self.escrows.values().map(|escrow| escrow.fee.total()).sum()
+    }
+}
+
+/// A relayer paying the cost of each message it delivers, whose deliveries are
+/// recorded in a [`FeeMarket`] to earn the fees of the packets it relays.
+#[derive(Clone, Debug)]
+pub struct FeeRelayer {
+    signer: Signer,
+    costs: RelayCosts,
+    spent: u128,
+}
+
+impl FeeRelayer {
+    pub fn new(signer: Signer, costs: RelayCosts) -> Self {
+        Self {
+            signer,
+            costs,
+            spent: 0,
+        }
+    }
+
+    pub fn signer(&self) -> &Signer {
+        &self.signer
+    }
+
+    /// Returns the total cost of the messages the relayer delivered.
+    pub fn spent(&self) -> u128 {
+        self.spent
+    }
+
+    /// Returns the fees the relayer earned in the market, net of the cost of
+    /// the messages it delivered.
+    pub fn profit(&self, market: &FeeMarket) -> i128 {
+        market.balance(&self.signer) as i128 - self.spent as i128
+    }
+
+    /// Delivers the messages to the given chain in a single transaction.
+    ///
+    /// The relayer pays for the messages whether or not the transaction
+    /// succeeds, while only the messages of a successful one are recorded in
+    /// the market.
+    pub fn relay(
+        &mut self,
+        ctx: &mut MockContext,
+        router: &mut (impl StagingRouter + ?Sized),
+        msgs: Vec<MsgEnvelope>,
+        market: &mut FeeMarket,
+    ) -> Result<(), RelayerError> {
+        self.spent += msgs.iter().map(|msg| self.costs.cost_of(msg)).sum::<u128>();
+
+        ctx.deliver(router, msgs.iter().cloned())?;
+
+        for msg in &msgs {
+            market.record(msg, &self.signer);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::{
+        MsgAcknowledgement, MsgRecvPacket, MsgTimeout, ACKNOWLEDGEMENT_TYPE_URL,
+        RECV_PACKET_TYPE_URL,
+    };
+    use ibc::core::primitives::StdTimeProvider;
+
+    use super::*;
+    use crate::fixtures::core::channel::{
+        dummy_raw_msg_ack_with_packet, dummy_raw_msg_recv_packet, dummy_raw_msg_timeout,
+        dummy_raw_packet,
+    };
+    use crate::testapp::ibc::core::router::MockRouter;
+
+    fn signer(name: &str) -> Signer {
+        name.to_string().into()
+    }
+
+    fn recv_msg() -> MsgEnvelope {
+        MsgEnvelope::Packet(PacketMsg::Recv(
+            MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(10, &StdTimeProvider)).unwrap(),
+        ))
+    }
+
+    fn ack_msg() -> MsgEnvelope {
+        MsgEnvelope::Packet(PacketMsg::Ack(
+            MsgAcknowledgement::try_from(dummy_raw_msg_ack_with_packet(
+                dummy_raw_packet(10, 1),
+                10,
+            ))
+            .unwrap(),
+        ))
+    }
+
+    fn timeout_msg() -> MsgEnvelope {
+        MsgEnvelope::Packet(PacketMsg::Timeout(
+            MsgTimeout::try_from(dummy_raw_msg_timeout(10, 5, 0)).unwrap(),
+        ))
+    }
+
+    fn key_of(msg: &MsgEnvelope) -> PacketKey {
+        match msg {
+            MsgEnvelope::Packet(PacketMsg::Recv(msg)) => packet_key(&msg.packet),
+            MsgEnvelope::Packet(PacketMsg::Ack(msg)) => packet_key(&msg.packet),
+            MsgEnvelope::Packet(PacketMsg::Timeout(msg)) => packet_key(&msg.packet),
+            _ => unreachable!(),
+        }
+    }
+
+    const FEE: PacketFee = PacketFee {
+        recv_fee: 30,
+        ack_fee: 20,
+        timeout_fee: 10,
+    };
+
+    #[test]
+    fn test_relay_costs() {
+        let costs = RelayCosts::new(1).with_cost(RECV_PACKET_TYPE_URL, 25);
+        assert_eq!(costs.cost_of(&recv_msg()), 25);
+        assert_eq!(costs.cost_of(&ack_msg()), 1);
+    }
+
+    #[test]
+    fn test_fees_distributed_on_ack() {
+        let (payer, forward, reverse) = (signer("payer"), signer("forward"), signer("reverse"));
+        let key = key_of(&recv_msg());
+        assert_eq!(key, key_of(&ack_msg()));
+
+        let mut market = FeeMarket::default();
+        market
+            .pay_packet_fee(key.clone(), payer.clone(), FEE)
+            .unwrap();
+        assert!(matches!(
+            market.pay_packet_fee(key.clone(), payer.clone(), FEE),
+            Err(FeeError::FeeAlreadyEscrowed { .. })
+        ));
+
+        market.record(&recv_msg(), &forward);
+        // Redundant deliveries are not rewarded
+        market.record(&recv_msg(), &reverse);
+        assert!(matches!(
+            market.claim(key.clone()),
+            Err(FeeError::PacketNotCompleted { .. })
+        ));
+
+        market.record(&ack_msg(), &reverse);
+        assert_eq!(market.total_escrowed(), FEE.total());
+        market.claim(key.clone()).unwrap();
+
+        assert_eq!(market.balance(&forward), FEE.recv_fee);
+        assert_eq!(market.balance(&reverse), FEE.ack_fee);
+        assert_eq!(market.balance(&payer), FEE.timeout_fee);
+        assert_eq!(market.total_escrowed(), 0);
+        assert!(matches!(
+            market.claim(key),
+            Err(FeeError::FeeNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_fees_refunded_on_timeout() {
+        let (payer, relayer) = (signer("payer"), signer("relayer"));
+        let key = key_of(&timeout_msg());
+
+        let mut market = FeeMarket::default();
+        market
+            .pay_packet_fee(key.clone(), payer.clone(), FEE)
+            .unwrap();
+        market.record(&timeout_msg(), &relayer);
+        market.claim(key).unwrap();
+
+        assert_eq!(market.balance(&relayer), FEE.timeout_fee);
+        assert_eq!(market.balance(&payer), FEE.recv_fee + FEE.ack_fee);
+    }
+
+    #[test]
+    fn test_failed_relay_is_paid_for() {
+        let costs = RelayCosts::new(1).with_cost(ACKNOWLEDGEMENT_TYPE_URL, 5);
+        let mut relayer = FeeRelayer::new(signer("relayer"), costs);
+        let mut market = FeeMarket::default();
+        market
+            .pay_packet_fee(key_of(&ack_msg()), signer("payer"), FEE)
+            .unwrap();
+
+        // The packet was never sent on this chain, so its acknowledgement fails
+        let mut ctx = MockContext::default();
+        let mut router = MockRouter::new_with_transfer();
+        assert!(relayer
+            .relay(&mut ctx, &mut router, vec![ack_msg()], &mut market)
+            .is_err());
+
+        assert_eq!(relayer.spent(), 5);
+        assert_eq!(relayer.profit(&market), -5);
+        assert!(market.claim(key_of(&ack_msg())).is_err());
+    }
+}
@@ -1,2 +1,4 @@
 pub mod context;
 pub mod error;
+pub mod fees;
+pub mod multihop;
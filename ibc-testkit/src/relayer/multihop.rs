@@ -0,0 +1,110 @@
+//! Assembles the proofs of the channels routed through intermediate chains.
+
+use ibc::core::channel::types::multihop::{MsgMultihopProofs, MultihopProof};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::commitment_types::merkle::apply_prefix;
+use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::path::{ClientConsensusStatePath, ConnectionPath, Path};
+use ibc::core::primitives::prelude::*;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc::primitives::ToVec;
+
+use super::context::RelayerContext;
+use super::error::RelayerError;
+
+/// An intermediate chain of a multi-hop channel.
+pub struct Hop<'a, Ctx> {
+    /// The context of the intermediate chain.
+    pub chain: &'a Ctx,
+    /// The connection on the intermediate chain towards the next chain of the
+    /// route.
+    pub connection_id: ConnectionId,
+    /// The height of the consensus state of the next chain, held by the client
+    /// of `connection_id`, against which the next proofs are verified.
+    pub consensus_height: Height,
+}
+
+/// Builds the proof of `value` being stored under `path` on `counterparty`, as
+/// seen from a chain connected to it through `hops`.
+///
+/// The hops are ordered from the chain adjacent to the receiving chain to the
+/// one adjacent to `counterparty`. The returned proof replaces the single-hop
+/// proof of the channel and packet messages, which are then submitted with the
+/// height of the consensus state of the first intermediate chain as proof
+/// height.
+pub fn build_multihop_proof<Ctx>(
+    counterparty: &Ctx,
+    hops: &[Hop<'_, Ctx>],
+    path: Path,
+    value: Vec<u8>,
+) -> Result<CommitmentProofBytes, RelayerError>
+where
+    Ctx: RelayerContext,
+{
+    let mut connection_proofs = Vec::with_capacity(hops.len());
+    let mut consensus_proofs = Vec::with_capacity(hops.len());
+
+    for hop in hops {
+        let prefix = hop.chain.query_commitment_prefix();
+
+        let conn_end = hop
+            .chain
+            .query_connection_end(&hop.connection_id)
+            .map_err(RelayerError::QueryFailed)?;
+        let conn_path = Path::Connection(ConnectionPath::new(&hop.connection_id));
+        connection_proofs.push(multihop_proof(
+            hop.chain,
+            &prefix,
+            conn_path,
+            conn_end.clone().encode_vec(),
+        ));
+
+        let consensus_state = hop
+            .chain
+            .query_consensus_state(conn_end.client_id(), hop.consensus_height)
+            .map_err(RelayerError::QueryFailed)?;
+        let cons_path = Path::ClientConsensusState(ClientConsensusStatePath::new(
+            conn_end.client_id().clone(),
+            hop.consensus_height.revision_number(),
+            hop.consensus_height.revision_height(),
+        ));
+        consensus_proofs.push(multihop_proof(
+            hop.chain,
+            &prefix,
+            cons_path,
+            Any::from(consensus_state).to_vec(),
+        ));
+    }
+
+    let key_proof = multihop_proof(
+        counterparty,
+        &counterparty.query_commitment_prefix(),
+        path,
+        value,
+    );
+
+    MsgMultihopProofs {
+        key_proof,
+        connection_proofs,
+        consensus_proofs,
+    }
+    .try_into()
+    .map_err(RelayerError::Channel)
+}
+
+fn multihop_proof<Ctx>(
+    chain: &Ctx,
+    prefix: &CommitmentPrefix,
+    path: Path,
+    value: Vec<u8>,
+) -> MultihopProof
+where
+    Ctx: RelayerContext,
+{
+    MultihopProof {
+        proof: chain.query_membership_proof(&path),
+        value,
+        prefixed_key: apply_prefix(prefix, vec![path.to_string()]),
+    }
+}
@@ -0,0 +1,99 @@
+use alloc::sync::Arc;
+
+use ibc::apps::transfer::bank::{BankError, BankKeeper, TokenTransferBankContext};
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::{Amount, PrefixedCoin, PrefixedDenom};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use parking_lot::Mutex;
+use subtle_encoding::bech32;
+
+/// The balances of the accounts of a [`MockBank`], by denomination.
+pub type Balances = BTreeMap<(Signer, PrefixedDenom), Amount>;
+
+/// An in-memory [`BankKeeper`] whose clones share the same balances.
+///
+/// The [`MockContext`](crate::testapp::ibc::core::types::MockContext) owns
+/// one, which it lends to the token transfer module of its router, which rolls
+/// the balances back along with the IBC store when a message fails.
+#[derive(Clone, Debug, Default)]
+pub struct MockBank {
+    balances: Arc<Mutex<Balances>>,
+}
+
+impl MockBank {
+    /// Returns a copy of the balances of all the accounts.
+    pub fn balances(&self) -> Balances {
+        self.balances.lock().clone()
+    }
+
+    /// Replaces the balances of all the accounts with `balances`.
+    pub fn set_balances(&self, balances: Balances) {
+        *self.balances.lock() = balances;
+    }
+
+    fn debit(&self, account: &Signer, coin: &PrefixedCoin) -> Result<(), BankError> {
+        self.verify_sufficient_balance(account, coin)?;
+
+        let mut balances = self.balances.lock();
+        let balance = balances
+            .entry((account.clone(), coin.denom.clone()))
+            .or_insert_with(Amount::zero);
+        *balance = balance
+            .checked_sub(coin.amount)
+            .expect("balance was verified to be sufficient");
+
+        Ok(())
+    }
+
+    fn credit(&self, account: &Signer, coin: &PrefixedCoin) -> Result<(), BankError> {
+        let mut balances = self.balances.lock();
+        let balance = balances
+            .entry((account.clone(), coin.denom.clone()))
+            .or_insert_with(Amount::zero);
+        *balance = balance
+            .checked_add(coin.amount)
+            .ok_or_else(|| BankError::SupplyOverflow {
+                denom: coin.denom.clone(),
+            })?;
+
+        Ok(())
+    }
+}
+
+impl BankKeeper for MockBank {
+    type AccountId = Signer;
+
+    fn send_coins(
+        &mut self,
+        from: &Signer,
+        to: &Signer,
+        coin: &PrefixedCoin,
+    ) -> Result<(), BankError> {
+        self.debit(from, coin)?;
+        self.credit(to, coin)
+    }
+
+    fn mint_coins(&mut self, account: &Signer, coin: &PrefixedCoin) -> Result<(), BankError> {
+        self.credit(account, coin)
+    }
+
+    fn burn_coins(&mut self, account: &Signer, coin: &PrefixedCoin) -> Result<(), BankError> {
+        self.debit(account, coin)
+    }
+
+    fn balance(&self, account: &Signer, denom: &PrefixedDenom) -> Result<Amount, BankError> {
+        Ok(self
+            .balances
+            .lock()
+            .get(&(account.clone(), denom.clone()))
+            .copied()
+            .unwrap_or_else(Amount::zero))
+    }
+}
+
+impl TokenTransferBankContext for MockBank {
+    fn account_from_address(&self, address: &[u8]) -> Result<Signer, TokenTransferError> {
+        Ok(bech32::encode("cosmos", address).into())
+    }
+}
@@ -1,6 +1,9 @@
+use ibc::apps::transfer::bank;
+use ibc::apps::transfer::codec::{JsonCodec, PacketDataCodec};
 use ibc::apps::transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 use ibc::apps::transfer::types::error::TokenTransferError;
 use ibc::apps::transfer::types::{Memo, PrefixedCoin};
+use ibc::core::channel::types::Version;
 use ibc::core::host::types::identifiers::{ChannelId, PortId};
 use ibc::core::primitives::Signer;
 
@@ -8,6 +11,7 @@ use super::types::DummyTransferModule;
 
 impl TokenTransferValidationContext for DummyTransferModule {
     type AccountId = Signer;
+    type PacketDataCodec = JsonCodec;
 
     fn get_port(&self) -> Result<PortId, TokenTransferError> {
         Ok(PortId::transfer())
@@ -22,79 +26,112 @@ impl TokenTransferValidationContext for DummyTransferModule {
     }
     fn escrow_coins_validate(
         &self,
-        _from_account: &Self::AccountId,
+        from_account: &Self::AccountId,
         _port_id: &PortId,
         _channel_id: &ChannelId,
-        _coin: &PrefixedCoin,
+        coin: &PrefixedCoin,
         _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
-        Ok(())
+        match &self.bank {
+            Some(bank) => bank::escrow_coins_validate(bank, from_account, coin),
+            None => Ok(()),
+        }
     }
 
     fn unescrow_coins_validate(
         &self,
         _to_account: &Self::AccountId,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _coin: &PrefixedCoin,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
-        Ok(())
+        match &self.bank {
+            Some(bank) => bank::unescrow_coins_validate(bank, port_id, channel_id, coin),
+            None => Ok(()),
+        }
     }
 
     fn mint_coins_validate(
         &self,
         _account: &Self::AccountId,
         _coin: &PrefixedCoin,
+        _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
         Ok(())
     }
 
     fn burn_coins_validate(
         &self,
-        _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
         _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
-        Ok(())
+        match &self.bank {
+            Some(bank) => bank::burn_coins_validate(bank, account, coin),
+            None => Ok(()),
+        }
+    }
+
+    fn channel_version(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<Version, TokenTransferError> {
+        Ok(Self::PacketDataCodec::version())
     }
 }
 
 impl TokenTransferExecutionContext for DummyTransferModule {
     fn escrow_coins_execute(
         &mut self,
-        _from_account: &Self::AccountId,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _coin: &PrefixedCoin,
+        from_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
         _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
-        Ok(())
+        match &mut self.bank {
+            Some(bank) => bank::escrow_coins_execute(bank, from_account, port_id, channel_id, coin),
+            None => Ok(()),
+        }
     }
 
     fn unescrow_coins_execute(
         &mut self,
-        _to_account: &Self::AccountId,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _coin: &PrefixedCoin,
+        to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
-        Ok(())
+        match &mut self.bank {
+            Some(bank) => bank::unescrow_coins_execute(bank, to_account, port_id, channel_id, coin),
+            None => Ok(()),
+        }
     }
 
     fn mint_coins_execute(
         &mut self,
-        _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
-        Ok(())
+        match &mut self.bank {
+            Some(bank) => bank::mint_coins_execute(bank, account, coin),
+            None => Ok(()),
+        }
     }
 
     fn burn_coins_execute(
         &mut self,
-        _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
         _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
-        Ok(())
+        match &mut self.bank {
+            Some(bank) => bank::burn_coins_execute(bank, account, coin),
+            None => Ok(()),
+        }
     }
 }
@@ -1,3 +1,4 @@
+pub mod bank;
 #[cfg(feature = "serde")]
 pub mod context;
 pub mod module;
@@ -1,3 +1,7 @@
+#[cfg(feature = "serde")]
+use ibc::apps::transfer::module as transfer;
+#[cfg(feature = "serde")]
+use ibc::apps::transfer::types::error::TokenTransferError;
 use ibc::core::channel::types::acknowledgement::Acknowledgement;
 use ibc::core::channel::types::channel::{Counterparty, Order};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
@@ -11,6 +15,9 @@ use ibc::core::router::types::module::ModuleExtras;
 
 use super::types::DummyTransferModule;
 
+/// Packets go through the ICS-20 application when the module has a bank to
+/// move their tokens with, which requires the `serde` feature.
+#[cfg_attr(not(feature = "serde"), allow(unused_variables))]
 impl Module for DummyTransferModule {
     fn on_chan_open_init_validate(
         &self,
@@ -62,46 +69,88 @@ impl Module for DummyTransferModule {
 
     fn on_recv_packet_execute(
         &mut self,
-        _packet: &Packet,
+        packet: &Packet,
         _relayer: &Signer,
-    ) -> (ModuleExtras, Acknowledgement) {
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        #[cfg(feature = "serde")]
+        if self.bank.is_some() {
+            let (extras, ack) = transfer::on_recv_packet_execute(self, packet);
+            return (extras, Some(ack));
+        }
+
         (
             ModuleExtras::empty(),
-            Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+            Some(Acknowledgement::try_from(vec![1u8]).expect("Never fails")),
         )
     }
 
     fn on_timeout_packet_validate(
         &self,
-        _packet: &Packet,
-        _relayer: &Signer,
+        packet: &Packet,
+        relayer: &Signer,
     ) -> Result<(), PacketError> {
+        #[cfg(feature = "serde")]
+        if self.bank.is_some() {
+            return transfer::on_timeout_packet_validate(self, packet, relayer).map_err(app_error);
+        }
+
         Ok(())
     }
 
     fn on_timeout_packet_execute(
         &mut self,
-        _packet: &Packet,
-        _relayer: &Signer,
+        packet: &Packet,
+        relayer: &Signer,
     ) -> (ModuleExtras, Result<(), PacketError>) {
+        #[cfg(feature = "serde")]
+        if self.bank.is_some() {
+            let (extras, result) = transfer::on_timeout_packet_execute(self, packet, relayer);
+            return (extras, result.map_err(app_error));
+        }
+
         (ModuleExtras::empty(), Ok(()))
     }
 
     fn on_acknowledgement_packet_validate(
         &self,
-        _packet: &Packet,
-        _acknowledgement: &Acknowledgement,
-        _relayer: &Signer,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
     ) -> Result<(), PacketError> {
+        #[cfg(feature = "serde")]
+        if self.bank.is_some() {
+            return transfer::on_acknowledgement_packet_validate(
+                self,
+                packet,
+                acknowledgement,
+                relayer,
+            )
+            .map_err(app_error);
+        }
+
         Ok(())
     }
 
     fn on_acknowledgement_packet_execute(
         &mut self,
-        _packet: &Packet,
-        _acknowledgement: &Acknowledgement,
-        _relayer: &Signer,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
     ) -> (ModuleExtras, Result<(), PacketError>) {
+        #[cfg(feature = "serde")]
+        if self.bank.is_some() {
+            let (extras, result) =
+                transfer::on_acknowledgement_packet_execute(self, packet, acknowledgement, relayer);
+            return (extras, result.map_err(app_error));
+        }
+
         (ModuleExtras::empty(), Ok(()))
     }
 }
+
+#[cfg(feature = "serde")]
+fn app_error(e: TokenTransferError) -> PacketError {
+    PacketError::AppModule {
+        description: e.to_string(),
+    }
+}
@@ -1,9 +1,20 @@
+use super::bank::MockBank;
+
 #[derive(Debug)]
-pub struct DummyTransferModule;
+pub struct DummyTransferModule {
+    /// The bank moving the tokens of the transfers handled by the module, if
+    /// any. Without one, the module accepts all the packets as is.
+    pub bank: Option<MockBank>,
+}
 
 impl DummyTransferModule {
     pub fn new() -> Self {
-        Self
+        Self { bank: None }
+    }
+
+    /// Returns a module moving the tokens of its transfers with `bank`.
+    pub fn with_bank(bank: MockBank) -> Self {
+        Self { bank: Some(bank) }
     }
 }
 
@@ -233,6 +233,18 @@ impl ClientStateCommon for MockClientState {
     ) -> Result<(), ClientError> {
         Ok(())
     }
+
+    fn decode_multihop_consensus_state(
+        &self,
+        consensus_state: Any,
+    ) -> Result<(CommitmentRoot, Timestamp), ClientError> {
+        let mock_consensus_state = MockConsensusState::try_from(consensus_state)?;
+
+        Ok((
+            mock_consensus_state.root().clone(),
+            mock_consensus_state.timestamp(),
+        ))
+    }
 }
 
 impl<V> ClientStateValidation<V> for MockClientState
@@ -315,6 +327,66 @@ where
         Ok(Status::Active)
     }
 
+    fn is_within_expiry_grace_period(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        grace_period: Duration,
+    ) -> Result<bool, ClientError> {
+        if self.is_frozen() {
+            return Ok(false);
+        }
+
+        let latest_consensus_state: MockConsensusState =
+            match ctx.consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.latest_height().revision_number(),
+                self.latest_height().revision_height(),
+            )) {
+                Ok(cs) => cs.try_into()?,
+                Err(_) => return Ok(false),
+            };
+
+        let now = ctx.host_timestamp()?;
+
+        Ok(now
+            .duration_since(&latest_consensus_state.timestamp())
+            .is_some_and(|elapsed_since_latest_consensus_state| {
+                elapsed_since_latest_consensus_state
+                    <= self.trusting_period.saturating_add(grace_period)
+            }))
+    }
+
+    fn time_until_expiry(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+    ) -> Result<Option<Duration>, ClientError> {
+        if self.is_frozen() {
+            return Ok(None);
+        }
+
+        let latest_consensus_state: MockConsensusState =
+            match ctx.consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.latest_height().revision_number(),
+                self.latest_height().revision_height(),
+            )) {
+                Ok(cs) => cs.try_into()?,
+                Err(_) => return Ok(Some(Duration::ZERO)),
+            };
+
+        let now = ctx.host_timestamp()?;
+        let elapsed_since_latest_consensus_state = now
+            .duration_since(&latest_consensus_state.timestamp())
+            .unwrap_or(Duration::ZERO);
+
+        Ok(Some(
+            self.trusting_period
+                .saturating_sub(elapsed_since_latest_consensus_state),
+        ))
+    }
+
     fn check_substitute(&self, _ctx: &V, _substitute_client_state: Any) -> Result<(), ClientError> {
         Ok(())
     }
@@ -356,6 +428,17 @@ where
         let header = MockHeader::try_from(header)?;
         let header_height = header.height;
 
+        let path_at_header_height = ClientConsensusStatePath::new(
+            client_id.clone(),
+            header_height.revision_number(),
+            header_height.revision_height(),
+        );
+
+        if ctx.consensus_state(&path_at_header_height).is_ok() {
+            // The header was already installed, so this is a no-op.
+            return Ok(Vec::new());
+        }
+
         let new_client_state = MockClientState::new(header);
         let new_consensus_state = MockConsensusState::new(header);
 
@@ -3,7 +3,7 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 
 use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{HostTimeProvider, Timestamp};
 use ibc::primitives::proto::{Any, Protobuf};
 
 use crate::testapp::ibc::clients::mock::proto::Header as RawMockHeader;
@@ -75,9 +75,11 @@ impl MockHeader {
         }
     }
 
-    pub fn with_current_timestamp(self) -> Self {
+    /// Sets the timestamp of the header to the current time of the host, as
+    /// given by `time_provider`, e.g. that of the context.
+    pub fn with_current_timestamp(self, time_provider: &dyn HostTimeProvider) -> Self {
         Self {
-            timestamp: Timestamp::now(),
+            timestamp: time_provider.now(),
             ..self
         }
     }
@@ -1,24 +1,38 @@
+use core::ops::Bound;
+
+use ibc::clients::tendermint::cache::ValidatorSetCache;
+use ibc::clients::tendermint::context::{
+    Options, TmValidationContext, TrustedBlockState, UntrustedBlockState, Verdict, Verifier,
+};
+use ibc::clients::tendermint::crypto::HostCryptoVerifier;
+use ibc::clients::tendermint::types::VerificationMode;
 use ibc::core::client::context::{
     ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
 };
 use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
 use ibc::core::handler::types::error::ContextError;
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId};
-use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
-use ibc::core::host::ValidationContext;
-use ibc::core::primitives::Timestamp;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::{
+    ChannelEndPath, ClientConsensusStatePath, ClientStatePath, ClientUpdateHeightPath,
+    ClientUpdateTimePath,
+};
+use ibc::core::host::HostValidationContext;
+use ibc::core::primitives::{DefaultCryptoProvider, Timestamp};
 use ibc::primitives::prelude::*;
+use ibc::primitives::proto::{Any, Protobuf};
 
 use crate::testapp::ibc::clients::mock::client_state::MockClientContext;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use crate::testapp::ibc::core::types::MockContext;
 
-pub type PortChannelIdMap<V> = BTreeMap<PortId, BTreeMap<ChannelId, V>>;
+/// A map keyed by the (port, channel) pair of a channel, in the form of its
+/// [`ChannelEndPath`].
+pub type PortChannelIdMap<V> = BTreeMap<ChannelEndPath, V>;
 
 /// A mock of an IBC client record as it is stored in a mock context.
 /// For testing ICS02 handlers mostly, cf. `MockClientContext`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct MockClientRecord {
     /// The client state (representing only the latest height at the moment).
     pub client_state: Option<AnyClientState>,
@@ -29,25 +43,25 @@ pub struct MockClientRecord {
 
 impl MockClientContext for MockContext {
     fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
-        ValidationContext::host_timestamp(self)
+        HostValidationContext::host_timestamp(self)
     }
 
     fn host_height(&self) -> Result<Height, ContextError> {
-        ValidationContext::host_height(self)
+        HostValidationContext::host_height(self)
     }
 }
 
 impl ExtClientValidationContext for MockContext {
     fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
-        ValidationContext::host_timestamp(self)
+        HostValidationContext::host_timestamp(self)
     }
 
     fn host_height(&self) -> Result<Height, ContextError> {
-        ValidationContext::host_height(self)
+        HostValidationContext::host_height(self)
     }
 
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
-        let ibc_store = self.ibc_store.lock();
+        let ibc_store = &self.ibc_store;
         let client_record =
             ibc_store
                 .clients
@@ -66,7 +80,7 @@ impl ExtClientValidationContext for MockContext {
         client_id: &ClientId,
         height: &Height,
     ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
-        let ibc_store = self.ibc_store.lock();
+        let ibc_store = &self.ibc_store;
         let client_record =
             ibc_store
                 .clients
@@ -75,24 +89,13 @@ impl ExtClientValidationContext for MockContext {
                     client_id: client_id.clone(),
                 })?;
 
-        // Get the consensus state heights and sort them in ascending order.
-        let mut heights: Vec<Height> = client_record.consensus_states.keys().copied().collect();
-        heights.sort();
-
-        // Search for next state.
-        for h in heights {
-            if h > *height {
-                // unwrap should never happen, as the consensus state for h must exist
-                return Ok(Some(
-                    client_record
-                        .consensus_states
-                        .get(&h)
-                        .expect("Never fails")
-                        .clone(),
-                ));
-            }
-        }
-        Ok(None)
+        let next_consensus_state = client_record
+            .consensus_states
+            .range((Bound::Excluded(*height), Bound::Unbounded))
+            .next()
+            .map(|(_, consensus_state)| consensus_state.clone());
+
+        Ok(next_consensus_state)
     }
 
     fn prev_consensus_state(
@@ -100,7 +103,7 @@ impl ExtClientValidationContext for MockContext {
         client_id: &ClientId,
         height: &Height,
     ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
-        let ibc_store = self.ibc_store.lock();
+        let ibc_store = &self.ibc_store;
         let client_record =
             ibc_store
                 .clients
@@ -109,24 +112,75 @@ impl ExtClientValidationContext for MockContext {
                     client_id: client_id.clone(),
                 })?;
 
-        // Get the consensus state heights and sort them in descending order.
-        let mut heights: Vec<Height> = client_record.consensus_states.keys().copied().collect();
-        heights.sort_by(|a, b| b.cmp(a));
-
-        // Search for previous state.
-        for h in heights {
-            if h < *height {
-                // unwrap should never happen, as the consensus state for h must exist
-                return Ok(Some(
-                    client_record
-                        .consensus_states
-                        .get(&h)
-                        .expect("Never fails")
-                        .clone(),
-                ));
-            }
+        let prev_consensus_state = client_record
+            .consensus_states
+            .range(..*height)
+            .next_back()
+            .map(|(_, consensus_state)| consensus_state.clone());
+
+        Ok(prev_consensus_state)
+    }
+}
+
+/// The verifier of the headers of the Tendermint clients of a
+/// [`MockContext`].
+#[derive(Clone, Copy, Debug, Default)]
+pub enum MockTmVerifier {
+    /// Verifies the headers as the light client does, with the host crypto.
+    #[default]
+    Host,
+    /// Returns the verdict of the given function for all the headers,
+    /// standing in for the custom verifier of a host.
+    Stub(fn() -> Verdict),
+}
+
+impl Verifier for MockTmVerifier {
+    fn verify_update_header(
+        &self,
+        untrusted: UntrustedBlockState<'_>,
+        trusted: TrustedBlockState<'_>,
+        options: &Options,
+        now: tendermint::Time,
+    ) -> Verdict {
+        match self {
+            Self::Host => HostCryptoVerifier::<DefaultCryptoProvider>::default()
+                .verify_update_header(untrusted, trusted, options, now),
+            Self::Stub(verdict) => verdict(),
+        }
+    }
+
+    fn verify_misbehaviour_header(
+        &self,
+        untrusted: UntrustedBlockState<'_>,
+        trusted: TrustedBlockState<'_>,
+        options: &Options,
+        now: tendermint::Time,
+    ) -> Verdict {
+        match self {
+            Self::Host => HostCryptoVerifier::<DefaultCryptoProvider>::default()
+                .verify_misbehaviour_header(untrusted, trusted, options, now),
+            Self::Stub(verdict) => verdict(),
         }
-        Ok(None)
+    }
+}
+
+impl TmValidationContext for MockContext {
+    type Verifier = MockTmVerifier;
+    type CryptoProvider = DefaultCryptoProvider;
+
+    fn tm_verifier(&self) -> Self::Verifier {
+        self.tm_verifier
+    }
+
+    fn validator_set_cache(&self) -> Option<&ValidatorSetCache> {
+        self.validator_set_cache.as_ref()
+    }
+
+    fn verification_mode(&self, client_id: &ClientId) -> VerificationMode {
+        self.client_verification_modes
+            .get(client_id)
+            .copied()
+            .unwrap_or_default()
     }
 }
 
@@ -135,20 +189,22 @@ impl ClientValidationContext for MockContext {
     type ConsensusStateRef = AnyConsensusState;
 
     fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, ContextError> {
-        match self.ibc_store.lock().clients.get(client_id) {
-            Some(client_record) => {
-                client_record
-                    .client_state
-                    .clone()
-                    .ok_or_else(|| ClientError::ClientStateNotFound {
-                        client_id: client_id.clone(),
-                    })
-            }
-            None => Err(ClientError::ClientStateNotFound {
+        self.client_state_cow(client_id).map(Cow::into_owned)
+    }
+
+    fn client_state_cow(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Cow<'_, Self::ClientStateRef>, ContextError> {
+        self.ibc_store
+            .clients
+            .get(client_id)
+            .and_then(|client_record| client_record.client_state.as_ref())
+            .map(Cow::Borrowed)
+            .ok_or_else(|| ClientError::ClientStateNotFound {
                 client_id: client_id.clone(),
-            }),
-        }
-        .map_err(ContextError::ClientError)
+            })
+            .map_err(ContextError::ClientError)
     }
 
     fn consensus_state(
@@ -160,7 +216,7 @@ impl ClientValidationContext for MockContext {
             client_cons_state_path.revision_number,
             client_cons_state_path.revision_height,
         )?;
-        match self.ibc_store.lock().clients.get(client_id) {
+        match self.ibc_store.clients.get(client_id) {
             Some(client_record) => match client_record.consensus_states.get(&height) {
                 Some(consensus_state) => Ok(consensus_state.clone()),
                 None => Err(ClientError::ConsensusStateNotFound {
@@ -183,7 +239,7 @@ impl ClientValidationContext for MockContext {
     ) -> Result<(Timestamp, Height), ContextError> {
         let key = (client_id.clone(), *height);
         (|| {
-            let ibc_store = self.ibc_store.lock();
+            let ibc_store = &self.ibc_store;
             let time = ibc_store.client_processed_times.get(&key)?;
             let height = ibc_store.client_processed_heights.get(&key)?;
             Some((*time, *height))
@@ -194,6 +250,18 @@ impl ClientValidationContext for MockContext {
         })
         .map_err(ContextError::from)
     }
+
+    fn update_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        let heights = self
+            .ibc_store
+            .client_processed_heights
+            .keys()
+            .filter(|(id, _)| id == client_id)
+            .map(|(_, height)| *height)
+            .collect();
+
+        Ok(heights)
+    }
 }
 
 impl ClientExecutionContext for MockContext {
@@ -204,7 +272,10 @@ impl ClientExecutionContext for MockContext {
         client_state_path: ClientStatePath,
         client_state: Self::ClientStateRef,
     ) -> Result<(), ContextError> {
-        let mut ibc_store = self.ibc_store.lock();
+        self.record_set(client_state_path.clone(), || {
+            Protobuf::<Any>::encode_vec(client_state.clone())
+        });
+        let ibc_store = &mut self.ibc_store;
 
         let client_id = client_state_path.0;
         let client_record = ibc_store
@@ -225,7 +296,10 @@ impl ClientExecutionContext for MockContext {
         consensus_state_path: ClientConsensusStatePath,
         consensus_state: Self::ConsensusStateRef,
     ) -> Result<(), ContextError> {
-        let mut ibc_store = self.ibc_store.lock();
+        self.record_set(consensus_state_path.clone(), || {
+            Protobuf::<Any>::encode_vec(consensus_state.clone())
+        });
+        let ibc_store = &mut self.ibc_store;
 
         let client_record = ibc_store
             .clients
@@ -251,7 +325,8 @@ impl ClientExecutionContext for MockContext {
         &mut self,
         consensus_state_path: ClientConsensusStatePath,
     ) -> Result<(), ContextError> {
-        let mut ibc_store = self.ibc_store.lock();
+        self.record_delete(consensus_state_path.clone());
+        let ibc_store = &mut self.ibc_store;
 
         let client_record = ibc_store
             .clients
@@ -272,15 +347,35 @@ impl ClientExecutionContext for MockContext {
         Ok(())
     }
 
-    fn delete_update_meta(
+    fn delete_update_time(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.record_delete(ClientUpdateTimePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ));
+        self.ibc_store
+            .client_processed_times
+            .remove(&(client_id, height));
+        Ok(())
+    }
+
+    fn delete_update_height(
         &mut self,
         client_id: ClientId,
         height: Height,
     ) -> Result<(), ContextError> {
-        let key = (client_id, height);
-        let mut ibc_store = self.ibc_store.lock();
-        ibc_store.client_processed_times.remove(&key);
-        ibc_store.client_processed_heights.remove(&key);
+        self.record_delete(ClientUpdateHeightPath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ));
+        self.ibc_store
+            .client_processed_heights
+            .remove(&(client_id, height));
         Ok(())
     }
 
@@ -291,7 +386,25 @@ impl ClientExecutionContext for MockContext {
         host_timestamp: Timestamp,
         host_height: Height,
     ) -> Result<(), ContextError> {
-        let mut ibc_store = self.ibc_store.lock();
+        // As in ibc-go, the processed time is committed as big-endian
+        // nanoseconds, and the processed height as its string.
+        self.record_set(
+            ClientUpdateTimePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            || host_timestamp.nanoseconds().to_be_bytes().to_vec(),
+        );
+        self.record_set(
+            ClientUpdateHeightPath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            || host_height.to_string().into_bytes(),
+        );
+        let ibc_store = &mut self.ibc_store;
         ibc_store
             .client_processed_times
             .insert((client_id.clone(), height), host_timestamp);
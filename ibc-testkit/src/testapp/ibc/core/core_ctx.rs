@@ -8,30 +8,54 @@ use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCom
 use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::packet::Receipt;
 use ibc::core::client::types::error::ClientError;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{ClientParams, Height};
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
 use ibc::core::connection::types::error::ConnectionError;
+use ibc::core::connection::types::params::ConnectionParams;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ConnectionId, Sequence};
+use ibc::core::host::staging::StagingContext;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::limits::HostLimits;
+#[cfg(feature = "query")]
+use ibc::core::host::types::path::Path;
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
+    CommitmentPath, ConnectionPath, NextChannelSequencePath, NextClientSequencePath,
+    NextConnectionSequencePath, PruningSeqEndPath, PruningSeqStartPath, ReceiptPath, SeqAckPath,
+    SeqRecvPath, SeqSendPath,
+};
+use ibc::core::host::types::write_set::WriteSet;
+use ibc::core::host::{
+    ChannelExecutionContext, ChannelValidationContext, ClientRegistryExecutionContext,
+    ClientRegistryValidationContext, ConnectionEndValidationContext, ConnectionExecutionContext,
+    ConnectionValidationContext, HostExecutionContext, HostValidationContext, IterableContext,
+    PacketExecutionContext, PacketValidationContext, ProofQueryError,
 };
-use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::primitives::{DefaultCryptoProvider, Signer, Timestamp};
+use ibc::core::router::types::capability::{Capability, CapabilityName};
+use ibc::core::router::types::error::RouterError;
+use ibc::core::router::types::module::ModuleId;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc_proto::ibc::core::connection::v1::ClientPaths as RawClientPaths;
+#[cfg(feature = "query")]
+use ibc_query::core::context::ProvableContext;
+use prost::Message;
 
-use super::types::MockContext;
+use super::types::{MockContext, MockIbcStore};
 use crate::testapp::ibc::clients::mock::client_state::MockClientState;
 use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
-use crate::testapp::ibc::clients::AnyConsensusState;
+use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 
-impl ValidationContext for MockContext {
+impl HostValidationContext for MockContext {
     type V = Self;
-    type HostClientState = MockClientState;
-    type HostConsensusState = MockConsensusState;
+
+    type CryptoProvider = DefaultCryptoProvider;
+
+    type HostError = ProofQueryError;
 
     fn host_height(&self) -> Result<Height, ContextError> {
         Ok(self.latest_height())
@@ -47,10 +71,80 @@ impl ValidationContext for MockContext {
             .expect("Never fails"))
     }
 
+    fn host_limits(&self) -> Result<HostLimits, ContextError> {
+        Ok(self.host_limits.clone())
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.ibc_store
+            .connection_params
+            .as_ref()
+            .map_or(self.block_time, |params| params.max_expected_time_per_block)
+    }
+
+    fn validate_message_signer(&self, _signer: &Signer) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self
+    }
+}
+
+impl ClientRegistryValidationContext for MockContext {
     fn client_counter(&self) -> Result<u64, ContextError> {
-        Ok(self.ibc_store.lock().client_ids_counter)
+        Ok(self.ibc_store.client_ids_counter)
+    }
+
+    fn client_params(&self) -> Result<ClientParams, ContextError> {
+        Ok(self.ibc_store.client_params.clone())
+    }
+
+    fn client_expiry_grace_period(&self) -> Result<Option<Duration>, ContextError> {
+        Ok(self.client_expiry_grace_period)
+    }
+
+    fn authorize_client_update(
+        &self,
+        client_id: &ClientId,
+        _client_message: &Any,
+        signer: &Signer,
+    ) -> Result<(), ContextError> {
+        match self.client_update_authorities.get(client_id) {
+            Some(authority) if authority != signer => Err(ClientError::UnauthorizedClientUpdate {
+                client_id: client_id.clone(),
+                signer: signer.clone(),
+            }
+            .into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl ConnectionEndValidationContext for MockContext {
+    fn connection_end(&self, cid: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        self.connection_end_cow(cid).map(Cow::into_owned)
     }
 
+    fn connection_end_cow(
+        &self,
+        cid: &ConnectionId,
+    ) -> Result<Cow<'_, ConnectionEnd>, ContextError> {
+        match self.ibc_store.connections.get(cid) {
+            Some(connection_end) => Ok(Cow::Borrowed(connection_end)),
+            None => Err(ConnectionError::ConnectionNotFound {
+                connection_id: cid.clone(),
+            }),
+        }
+        .map_err(ContextError::ConnectionError)
+    }
+}
+
+impl ConnectionValidationContext for MockContext {
+    type HostClientState = MockClientState;
+
+    type HostConsensusState = MockConsensusState;
+
     fn host_consensus_state(&self, height: &Height) -> Result<MockConsensusState, ContextError> {
         let cs: AnyConsensusState = match self.host_block(height) {
             Some(block_ref) => Ok(block_ref.clone().into()),
@@ -114,36 +208,59 @@ impl ValidationContext for MockContext {
         Ok(())
     }
 
-    fn connection_end(&self, cid: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
-        match self.ibc_store.lock().connections.get(cid) {
-            Some(connection_end) => Ok(connection_end.clone()),
-            None => Err(ConnectionError::ConnectionNotFound {
-                connection_id: cid.clone(),
-            }),
-        }
-        .map_err(ContextError::ConnectionError)
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.commitment_prefix.clone()
     }
 
-    fn commitment_prefix(&self) -> CommitmentPrefix {
-        CommitmentPrefix::try_from(b"mock".to_vec()).expect("Never fails")
+    fn allows_empty_counterparty_prefix(&self) -> bool {
+        self.allow_empty_counterparty_prefix
     }
 
     fn connection_counter(&self) -> Result<u64, ContextError> {
-        Ok(self.ibc_store.lock().connection_ids_counter)
+        Ok(self.ibc_store.connection_ids_counter)
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        self.connection_versions.clone()
+    }
+
+    fn connections_for_client(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<ConnectionId>, ContextError> {
+        Ok(self
+            .ibc_store
+            .client_connections
+            .get(client_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+impl ChannelValidationContext for MockContext {
+    fn connection_channel_count(&self, conn_id: &ConnectionId) -> Result<u64, ContextError> {
+        let count = self
+            .ibc_store
+            .channels
+            .values()
+            .filter(|chan_end| chan_end.connection_hops().first() == Some(conn_id))
+            .count();
+        Ok(count as u64)
     }
 
     fn channel_end(&self, chan_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        self.channel_end_cow(chan_end_path).map(Cow::into_owned)
+    }
+
+    fn channel_end_cow(
+        &self,
+        chan_end_path: &ChannelEndPath,
+    ) -> Result<Cow<'_, ChannelEnd>, ContextError> {
         let port_id = &chan_end_path.0;
         let channel_id = &chan_end_path.1;
 
-        match self
-            .ibc_store
-            .lock()
-            .channels
-            .get(port_id)
-            .and_then(|map| map.get(channel_id))
-        {
-            Some(channel_end) => Ok(channel_end.clone()),
+        match self.ibc_store.channels.get(chan_end_path) {
+            Some(channel_end) => Ok(Cow::Borrowed(channel_end)),
             None => Err(ChannelError::ChannelNotFound {
                 port_id: port_id.clone(),
                 channel_id: channel_id.clone(),
@@ -152,6 +269,33 @@ impl ValidationContext for MockContext {
         .map_err(ContextError::ChannelError)
     }
 
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        Ok(self.ibc_store.channel_ids_counter)
+    }
+
+    fn enforces_capabilities(&self) -> bool {
+        self.enforce_capabilities
+    }
+
+    fn get_capability(&self, name: &CapabilityName) -> Result<Option<Capability>, ContextError> {
+        Ok(self
+            .ibc_store
+            .capabilities
+            .get(name)
+            .map(|(capability, _)| *capability))
+    }
+
+    fn capability_owners(&self, name: &CapabilityName) -> Result<Vec<ModuleId>, ContextError> {
+        Ok(self
+            .ibc_store
+            .capabilities
+            .get(name)
+            .map(|(_, owners)| owners.clone())
+            .unwrap_or_default())
+    }
+}
+
+impl PacketValidationContext for MockContext {
     fn get_next_sequence_send(
         &self,
         seq_send_path: &SeqSendPath,
@@ -161,10 +305,8 @@ impl ValidationContext for MockContext {
 
         match self
             .ibc_store
-            .lock()
             .next_sequence_send
-            .get(port_id)
-            .and_then(|map| map.get(channel_id))
+            .get(&ChannelEndPath::new(port_id, channel_id))
         {
             Some(sequence) => Ok(*sequence),
             None => Err(PacketError::MissingNextSendSeq {
@@ -184,10 +326,8 @@ impl ValidationContext for MockContext {
 
         match self
             .ibc_store
-            .lock()
             .next_sequence_recv
-            .get(port_id)
-            .and_then(|map| map.get(channel_id))
+            .get(&ChannelEndPath::new(port_id, channel_id))
         {
             Some(sequence) => Ok(*sequence),
             None => Err(PacketError::MissingNextRecvSeq {
@@ -204,10 +344,8 @@ impl ValidationContext for MockContext {
 
         match self
             .ibc_store
-            .lock()
             .next_sequence_ack
-            .get(port_id)
-            .and_then(|map| map.get(channel_id))
+            .get(&ChannelEndPath::new(port_id, channel_id))
         {
             Some(sequence) => Ok(*sequence),
             None => Err(PacketError::MissingNextAckSeq {
@@ -228,10 +366,8 @@ impl ValidationContext for MockContext {
 
         match self
             .ibc_store
-            .lock()
             .packet_commitment
-            .get(port_id)
-            .and_then(|map| map.get(channel_id))
+            .get(&ChannelEndPath::new(port_id, channel_id))
             .and_then(|map| map.get(seq))
         {
             Some(commitment) => Ok(commitment.clone()),
@@ -247,10 +383,8 @@ impl ValidationContext for MockContext {
 
         match self
             .ibc_store
-            .lock()
             .packet_receipt
-            .get(port_id)
-            .and_then(|map| map.get(channel_id))
+            .get(&ChannelEndPath::new(port_id, channel_id))
             .and_then(|map| map.get(seq))
         {
             Some(receipt) => Ok(receipt.clone()),
@@ -269,10 +403,8 @@ impl ValidationContext for MockContext {
 
         match self
             .ibc_store
-            .lock()
             .packet_acknowledgement
-            .get(port_id)
-            .and_then(|map| map.get(channel_id))
+            .get(&ChannelEndPath::new(port_id, channel_id))
             .and_then(|map| map.get(seq))
         {
             Some(ack) => Ok(ack.clone()),
@@ -281,32 +413,301 @@ impl ValidationContext for MockContext {
         .map_err(ContextError::PacketError)
     }
 
-    fn channel_counter(&self) -> Result<u64, ContextError> {
-        Ok(self.ibc_store.lock().channel_ids_counter)
+    fn get_pruning_sequence_start(
+        &self,
+        pruning_seq_start_path: &PruningSeqStartPath,
+    ) -> Result<Option<Sequence>, ContextError> {
+        Ok(self
+            .ibc_store
+            .pruning_sequence_start
+            .get(&ChannelEndPath::new(
+                &pruning_seq_start_path.0,
+                &pruning_seq_start_path.1,
+            ))
+            .copied())
     }
 
-    fn max_expected_time_per_block(&self) -> Duration {
-        self.block_time
+    fn get_pruning_sequence_end(
+        &self,
+        pruning_seq_end_path: &PruningSeqEndPath,
+    ) -> Result<Option<Sequence>, ContextError> {
+        Ok(self
+            .ibc_store
+            .pruning_sequence_end
+            .get(&ChannelEndPath::new(
+                &pruning_seq_end_path.0,
+                &pruning_seq_end_path.1,
+            ))
+            .copied())
     }
+}
 
-    fn validate_message_signer(&self, _signer: &Signer) -> Result<(), ContextError> {
-        Ok(())
+impl IterableContext for MockContext {
+    fn iter_client_states(&self) -> Result<Vec<(ClientStatePath, AnyClientState)>, ContextError> {
+        Ok(self
+            .ibc_store
+            .clients
+            .iter()
+            .filter_map(|(client_id, client_record)| {
+                client_record
+                    .client_state
+                    .clone()
+                    .map(|client_state| (ClientStatePath::new(client_id.clone()), client_state))
+            })
+            .collect())
     }
 
-    fn get_client_validation_context(&self) -> &Self::V {
-        self
+    fn iter_consensus_states(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<(ClientConsensusStatePath, AnyConsensusState)>, ContextError> {
+        Ok(self
+            .ibc_store
+            .clients
+            .get(client_id)
+            .map(|client_record| {
+                client_record
+                    .consensus_states
+                    .iter()
+                    .map(|(height, consensus_state)| {
+                        (
+                            ClientConsensusStatePath::new(
+                                client_id.clone(),
+                                height.revision_number(),
+                                height.revision_height(),
+                            ),
+                            consensus_state.clone(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn iter_connections(&self) -> Result<Vec<(ConnectionPath, ConnectionEnd)>, ContextError> {
+        Ok(self
+            .ibc_store
+            .connections
+            .iter()
+            .map(|(conn_id, conn_end)| (ConnectionPath::new(conn_id), conn_end.clone()))
+            .collect())
+    }
+
+    fn iter_channels(&self) -> Result<Vec<(ChannelEndPath, ChannelEnd)>, ContextError> {
+        Ok(self
+            .ibc_store
+            .channel_ends()
+            .map(|(path, chan_end)| (path.clone(), chan_end.clone()))
+            .collect())
+    }
+
+    fn iter_commitments(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<(CommitmentPath, PacketCommitment)>, ContextError> {
+        let ChannelEndPath(port_id, channel_id) = channel_end_path;
+        Ok(self
+            .ibc_store
+            .packet_commitment
+            .get(channel_end_path)
+            .map(|commitments| {
+                commitments
+                    .iter()
+                    .map(|(seq, commitment)| {
+                        (
+                            CommitmentPath::new(port_id, channel_id, *seq),
+                            commitment.clone(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn iter_receipts(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<(ReceiptPath, Receipt)>, ContextError> {
+        let ChannelEndPath(port_id, channel_id) = channel_end_path;
+        Ok(self
+            .ibc_store
+            .packet_receipt
+            .get(channel_end_path)
+            .map(|receipts| {
+                receipts
+                    .iter()
+                    .map(|(seq, receipt)| {
+                        (ReceiptPath::new(port_id, channel_id, *seq), receipt.clone())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn iter_acknowledgements(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<(AckPath, AcknowledgementCommitment)>, ContextError> {
+        let ChannelEndPath(port_id, channel_id) = channel_end_path;
+        Ok(self
+            .ibc_store
+            .packet_acknowledgement
+            .get(channel_end_path)
+            .map(|acks| {
+                acks.iter()
+                    .map(|(seq, ack)| (AckPath::new(port_id, channel_id, *seq), ack.clone()))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn commitments_page(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        start: Sequence,
+        limit: usize,
+    ) -> Result<Vec<(CommitmentPath, PacketCommitment)>, ContextError> {
+        let ChannelEndPath(port_id, channel_id) = channel_end_path;
+        Ok(self
+            .ibc_store
+            .packet_commitment
+            .get(channel_end_path)
+            .map(|commitments| {
+                commitments
+                    .range(start..)
+                    .take(limit)
+                    .map(|(seq, commitment)| {
+                        (
+                            CommitmentPath::new(port_id, channel_id, *seq),
+                            commitment.clone(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn receipts_page(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        start: Sequence,
+        limit: usize,
+    ) -> Result<Vec<(ReceiptPath, Receipt)>, ContextError> {
+        let ChannelEndPath(port_id, channel_id) = channel_end_path;
+        Ok(self
+            .ibc_store
+            .packet_receipt
+            .get(channel_end_path)
+            .map(|receipts| {
+                receipts
+                    .range(start..)
+                    .take(limit)
+                    .map(|(seq, receipt)| {
+                        (ReceiptPath::new(port_id, channel_id, *seq), receipt.clone())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn acknowledgements_page(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        start: Sequence,
+        limit: usize,
+    ) -> Result<Vec<(AckPath, AcknowledgementCommitment)>, ContextError> {
+        let ChannelEndPath(port_id, channel_id) = channel_end_path;
+        Ok(self
+            .ibc_store
+            .packet_acknowledgement
+            .get(channel_end_path)
+            .map(|acks| {
+                acks.range(start..)
+                    .take(limit)
+                    .map(|(seq, ack)| (AckPath::new(port_id, channel_id, *seq), ack.clone()))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
+
+/// Serves the proofs of [`HostValidationContext::query_with_proof`] to the
+/// gRPC query services. The mock context cannot prove its entries, so the
+/// queries requiring proofs fail.
+#[cfg(feature = "query")]
+impl ProvableContext for MockContext {
+    fn get_proof(&self, height: Height, path: &Path) -> Option<Vec<u8>> {
+        self.query_with_proof(path, height)
+            .ok()
+            .map(|proven| proven.proof.into())
     }
 }
 
-impl ExecutionContext for MockContext {
+impl HostExecutionContext for MockContext {
     type E = Self;
 
     fn get_client_execution_context(&mut self) -> &mut Self::E {
         self
     }
 
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.ibc_store.events.push(event);
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.ibc_store.logs.push(message);
+        Ok(())
+    }
+
+    fn emitted_ibc_events(&self) -> &[IbcEvent] {
+        &self.ibc_store.events
+    }
+
+    fn logged_messages(&self) -> &[String] {
+        &self.ibc_store.logs
+    }
+
+    fn emits_sdk_message_events(&self) -> bool {
+        self.emit_sdk_message_events
+    }
+
+    fn write_set_mut(&mut self) -> Option<&mut WriteSet> {
+        self.write_set.as_mut()
+    }
+}
+
+impl ClientRegistryExecutionContext for MockContext {
     fn increase_client_counter(&mut self) -> Result<(), ContextError> {
-        self.ibc_store.lock().client_ids_counter += 1;
+        self.ibc_store.client_ids_counter += 1;
+        let counter = self.ibc_store.client_ids_counter;
+        self.record_set(NextClientSequencePath, || counter.to_be_bytes().to_vec());
+        Ok(())
+    }
+
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        self.ibc_store.client_params = params;
+        Ok(())
+    }
+
+    fn on_client_updated(
+        &mut self,
+        client_id: &ClientId,
+        consensus_heights: &[Height],
+        _header_type: &str,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .client_update_index
+            .entry(client_id.clone())
+            .or_default()
+            .extend_from_slice(consensus_heights);
+        Ok(())
+    }
+}
+
+impl ConnectionExecutionContext for MockContext {
+    fn store_connection_params(&mut self, params: ConnectionParams) -> Result<(), ContextError> {
+        self.ibc_store.connection_params = Some(params);
         Ok(())
     }
 
@@ -315,9 +716,11 @@ impl ExecutionContext for MockContext {
         connection_path: &ConnectionPath,
         connection_end: ConnectionEnd,
     ) -> Result<(), ContextError> {
+        self.record_set(connection_path.clone(), || {
+            connection_end.clone().encode_vec()
+        });
         let connection_id = connection_path.0.clone();
         self.ibc_store
-            .lock()
             .connections
             .insert(connection_id, connection_end);
         Ok(())
@@ -329,29 +732,96 @@ impl ExecutionContext for MockContext {
         conn_id: ConnectionId,
     ) -> Result<(), ContextError> {
         let client_id = client_connection_path.0.clone();
-        self.ibc_store
-            .lock()
-            .client_connections
-            .insert(client_id, conn_id);
+        let ibc_store = &mut self.ibc_store;
+        let conn_ids = ibc_store.client_connections.entry(client_id).or_default();
+        if !conn_ids.contains(&conn_id) {
+            conn_ids.push(conn_id);
+        }
+        // As in ibc-go, the connections of a client are committed as the
+        // list of their identifiers.
+        let paths = RawClientPaths {
+            paths: conn_ids.iter().map(ToString::to_string).collect(),
+        };
+        self.record_set(client_connection_path.clone(), || paths.encode_to_vec());
         Ok(())
     }
 
     fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
-        self.ibc_store.lock().connection_ids_counter += 1;
+        self.ibc_store.connection_ids_counter += 1;
+        let counter = self.ibc_store.connection_ids_counter;
+        self.record_set(NextConnectionSequencePath, || {
+            counter.to_be_bytes().to_vec()
+        });
         Ok(())
     }
+}
 
+impl ChannelExecutionContext for MockContext {
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.record_set(channel_end_path.clone(), || {
+            channel_end.clone().encode_vec()
+        });
+        self.ibc_store
+            .channels
+            .insert(channel_end_path.clone(), channel_end);
+        Ok(())
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        self.ibc_store.channel_ids_counter += 1;
+        let counter = self.ibc_store.channel_ids_counter;
+        self.record_set(NextChannelSequencePath, || counter.to_be_bytes().to_vec());
+        Ok(())
+    }
+
+    fn new_capability(&mut self, name: &CapabilityName) -> Result<Capability, ContextError> {
+        let ibc_store = &mut self.ibc_store;
+        if ibc_store.capabilities.contains_key(name) {
+            return Err(RouterError::CapabilityAlreadyIssued { name: name.clone() }.into());
+        }
+        let capability = Capability::new(ibc_store.capabilities.len() as u64);
+        ibc_store
+            .capabilities
+            .insert(name.clone(), (capability, Vec::new()));
+        Ok(capability)
+    }
+
+    fn claim_capability(
+        &mut self,
+        module_id: &ModuleId,
+        name: &CapabilityName,
+    ) -> Result<(), ContextError> {
+        let ibc_store = &mut self.ibc_store;
+        let (_, owners) = ibc_store.capabilities.get_mut(name).ok_or_else(|| {
+            RouterError::CapabilityNotOwned {
+                module_id: module_id.clone(),
+                name: name.clone(),
+            }
+        })?;
+        if !owners.contains(module_id) {
+            owners.push(module_id.clone());
+        }
+        Ok(())
+    }
+}
+
+impl PacketExecutionContext for MockContext {
     fn store_packet_commitment(
         &mut self,
         commitment_path: &CommitmentPath,
         commitment: PacketCommitment,
     ) -> Result<(), ContextError> {
+        self.record_set(commitment_path.clone(), || commitment.clone().into_vec());
         self.ibc_store
-            .lock()
             .packet_commitment
-            .entry(commitment_path.port_id.clone())
-            .or_default()
-            .entry(commitment_path.channel_id.clone())
+            .entry(ChannelEndPath::new(
+                &commitment_path.port_id,
+                &commitment_path.channel_id,
+            ))
             .or_default()
             .insert(commitment_path.sequence, commitment);
         Ok(())
@@ -361,11 +831,13 @@ impl ExecutionContext for MockContext {
         &mut self,
         commitment_path: &CommitmentPath,
     ) -> Result<(), ContextError> {
+        self.record_delete(commitment_path.clone());
         self.ibc_store
-            .lock()
             .packet_commitment
-            .get_mut(&commitment_path.port_id)
-            .and_then(|map| map.get_mut(&commitment_path.channel_id))
+            .get_mut(&ChannelEndPath::new(
+                &commitment_path.port_id,
+                &commitment_path.channel_id,
+            ))
             .and_then(|map| map.remove(&commitment_path.sequence));
         Ok(())
     }
@@ -375,12 +847,11 @@ impl ExecutionContext for MockContext {
         path: &ReceiptPath,
         receipt: Receipt,
     ) -> Result<(), ContextError> {
+        // ibc-go commits a single byte as the value of a receipt
+        self.record_set(path.clone(), || vec![1]);
         self.ibc_store
-            .lock()
             .packet_receipt
-            .entry(path.port_id.clone())
-            .or_default()
-            .entry(path.channel_id.clone())
+            .entry(ChannelEndPath::new(&path.port_id, &path.channel_id))
             .or_default()
             .insert(path.sequence, receipt);
         Ok(())
@@ -391,49 +862,75 @@ impl ExecutionContext for MockContext {
         ack_path: &AckPath,
         ack_commitment: AcknowledgementCommitment,
     ) -> Result<(), ContextError> {
+        self.record_set(ack_path.clone(), || ack_commitment.clone().into_vec());
         let port_id = ack_path.port_id.clone();
         let channel_id = ack_path.channel_id.clone();
         let seq = ack_path.sequence;
 
         self.ibc_store
-            .lock()
             .packet_acknowledgement
-            .entry(port_id)
-            .or_default()
-            .entry(channel_id)
+            .entry(ChannelEndPath(port_id, channel_id))
             .or_default()
             .insert(seq, ack_commitment);
         Ok(())
     }
 
     fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.record_delete(ack_path.clone());
         let port_id = ack_path.port_id.clone();
         let channel_id = ack_path.channel_id.clone();
         let sequence = ack_path.sequence;
 
         self.ibc_store
-            .lock()
             .packet_acknowledgement
-            .get_mut(&port_id)
-            .and_then(|map| map.get_mut(&channel_id))
+            .get_mut(&ChannelEndPath(port_id, channel_id))
             .and_then(|map| map.remove(&sequence));
         Ok(())
     }
 
-    fn store_channel(
+    fn delete_packet_receipt(&mut self, receipt_path: &ReceiptPath) -> Result<(), ContextError> {
+        self.record_delete(receipt_path.clone());
+        let port_id = receipt_path.port_id.clone();
+        let channel_id = receipt_path.channel_id.clone();
+        let sequence = receipt_path.sequence;
+
+        self.ibc_store
+            .packet_receipt
+            .get_mut(&ChannelEndPath(port_id, channel_id))
+            .and_then(|map| map.remove(&sequence));
+        Ok(())
+    }
+
+    fn store_pruning_sequence_start(
         &mut self,
-        channel_end_path: &ChannelEndPath,
-        channel_end: ChannelEnd,
+        pruning_seq_start_path: &PruningSeqStartPath,
+        seq: Sequence,
     ) -> Result<(), ContextError> {
-        let port_id = channel_end_path.0.clone();
-        let channel_id = channel_end_path.1.clone();
+        self.record_set(pruning_seq_start_path.clone(), || {
+            seq.value().to_be_bytes().to_vec()
+        });
+        let port_id = pruning_seq_start_path.0.clone();
+        let channel_id = pruning_seq_start_path.1.clone();
 
         self.ibc_store
-            .lock()
-            .channels
-            .entry(port_id)
-            .or_default()
-            .insert(channel_id, channel_end);
+            .pruning_sequence_start
+            .insert(ChannelEndPath(port_id, channel_id), seq);
+        Ok(())
+    }
+
+    fn delete_packet_acknowledgements(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<(), ContextError> {
+        let acks = self
+            .ibc_store
+            .packet_acknowledgement
+            .remove(channel_end_path)
+            .unwrap_or_default();
+        let ChannelEndPath(port_id, channel_id) = channel_end_path;
+        for seq in acks.into_keys() {
+            self.record_delete(AckPath::new(port_id, channel_id, seq));
+        }
         Ok(())
     }
 
@@ -442,15 +939,13 @@ impl ExecutionContext for MockContext {
         seq_send_path: &SeqSendPath,
         seq: Sequence,
     ) -> Result<(), ContextError> {
+        self.record_set(seq_send_path.clone(), || seq.value().to_be_bytes().to_vec());
         let port_id = seq_send_path.0.clone();
         let channel_id = seq_send_path.1.clone();
 
         self.ibc_store
-            .lock()
             .next_sequence_send
-            .entry(port_id)
-            .or_default()
-            .insert(channel_id, seq);
+            .insert(ChannelEndPath(port_id, channel_id), seq);
         Ok(())
     }
 
@@ -459,15 +954,13 @@ impl ExecutionContext for MockContext {
         seq_recv_path: &SeqRecvPath,
         seq: Sequence,
     ) -> Result<(), ContextError> {
+        self.record_set(seq_recv_path.clone(), || seq.value().to_be_bytes().to_vec());
         let port_id = seq_recv_path.0.clone();
         let channel_id = seq_recv_path.1.clone();
 
         self.ibc_store
-            .lock()
             .next_sequence_recv
-            .entry(port_id)
-            .or_default()
-            .insert(channel_id, seq);
+            .insert(ChannelEndPath(port_id, channel_id), seq);
         Ok(())
     }
 
@@ -476,30 +969,56 @@ impl ExecutionContext for MockContext {
         seq_ack_path: &SeqAckPath,
         seq: Sequence,
     ) -> Result<(), ContextError> {
+        self.record_set(seq_ack_path.clone(), || seq.value().to_be_bytes().to_vec());
         let port_id = seq_ack_path.0.clone();
         let channel_id = seq_ack_path.1.clone();
 
         self.ibc_store
-            .lock()
             .next_sequence_ack
-            .entry(port_id)
-            .or_default()
-            .insert(channel_id, seq);
+            .insert(ChannelEndPath(port_id, channel_id), seq);
         Ok(())
     }
 
-    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
-        self.ibc_store.lock().channel_ids_counter += 1;
+    fn delete_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<(), ContextError> {
+        self.record_delete(seq_send_path.clone());
+        let port_id = seq_send_path.0.clone();
+        let channel_id = seq_send_path.1.clone();
+
+        self.ibc_store
+            .next_sequence_send
+            .remove(&ChannelEndPath(port_id, channel_id));
         Ok(())
     }
 
-    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
-        self.ibc_store.lock().events.push(event);
+    fn delete_next_sequence_ack(&mut self, seq_ack_path: &SeqAckPath) -> Result<(), ContextError> {
+        self.record_delete(seq_ack_path.clone());
+        let port_id = seq_ack_path.0.clone();
+        let channel_id = seq_ack_path.1.clone();
+
+        self.ibc_store
+            .next_sequence_ack
+            .remove(&ChannelEndPath(port_id, channel_id));
         Ok(())
     }
+}
 
-    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
-        self.ibc_store.lock().logs.push(message);
-        Ok(())
+/// Checkpoints cover the IBC store and the write set. The balances of the
+/// [`MockContext::bank`] are rolled back by the [`MockRouter`] lending it to
+/// its token transfer module.
+///
+/// [`MockRouter`]: crate::testapp::ibc::core::router::MockRouter
+impl StagingContext for MockContext {
+    type Checkpoint = (MockIbcStore, Option<WriteSet>);
+
+    fn checkpoint(&self) -> Self::Checkpoint {
+        (self.ibc_store.clone(), self.write_set.clone())
+    }
+
+    fn restore(&mut self, (ibc_store, write_set): Self::Checkpoint) {
+        self.ibc_store = ibc_store;
+        self.write_set = write_set;
     }
 }
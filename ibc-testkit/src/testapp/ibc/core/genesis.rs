@@ -0,0 +1,330 @@
+//! Reproducible `MockContext`s, built from a genesis state and the messages
+//! replayed on top of it.
+//!
+//! Bug reports can be reduced to a `(GenesisState, Vec<MsgEnvelope>)` pair,
+//! which [`MockContext::replay`] turns into the context they end up in.
+
+use displaydoc::Display;
+use ibc::core::channel::types::channel::IdentifiedChannelEnd;
+use ibc::core::channel::types::packet::{PacketState, Receipt};
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::IdentifiedConnectionEnd;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::ChannelEndPath;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+use ibc::core::router::router::StagingRouter;
+use typed_builder::TypedBuilder;
+
+use super::router::MockRouter;
+use super::types::{MockClientConfig, MockContext};
+use crate::fixtures::core::context::MockContextConfig;
+
+/// Returns the fixed timestamp of the latest genesis block.
+pub fn genesis_time() -> Timestamp {
+    Timestamp::from_nanoseconds(1_700_000_000_000_000_000).expect("never fails")
+}
+
+/// The IBC state a [`MockContext`] starts from before replaying messages.
+///
+/// Unlike [`MockContextConfig`], the host chain does not default to the
+/// current time, so that replays are deterministic. The `latest_timestamp` of
+/// the `clients` should be set relative to [`genesis_time`] for the same
+/// reason.
+///
+/// Identifiers are expected to be numbered from zero, like the ones allocated
+/// by the handlers: the identifiers of the clients, connections and channels
+/// created during a replay continue after the genesis ones.
+#[derive(Debug, TypedBuilder)]
+pub struct GenesisState {
+    #[builder(default = ChainId::new("mockgaia-0").expect("never fails"))]
+    pub host_id: ChainId,
+    #[builder(default = Height::new(0, 5).expect("never fails"))]
+    pub latest_height: Height,
+    #[builder(default = genesis_time())]
+    pub latest_timestamp: Timestamp,
+    #[builder(default)]
+    pub clients: Vec<MockClientConfig>,
+    #[builder(default)]
+    pub connections: Vec<IdentifiedConnectionEnd>,
+    #[builder(default)]
+    pub channels: Vec<IdentifiedChannelEnd>,
+    /// Next send sequences, carried in the `seq` of the packet states.
+    #[builder(default)]
+    pub send_sequences: Vec<PacketState>,
+    /// Next receive sequences, carried in the `seq` of the packet states.
+    #[builder(default)]
+    pub recv_sequences: Vec<PacketState>,
+    /// Next acknowledgement sequences, carried in the `seq` of the packet
+    /// states.
+    #[builder(default)]
+    pub ack_sequences: Vec<PacketState>,
+    #[builder(default)]
+    pub commitments: Vec<PacketState>,
+    #[builder(default)]
+    pub acknowledgements: Vec<PacketState>,
+    #[builder(default)]
+    pub receipts: Vec<PacketState>,
+}
+
+#[derive(Debug, Display)]
+pub enum ReplayError {
+    /// genesis connection `{connection_id}` references unknown client `{client_id}`
+    UnknownGenesisClient {
+        connection_id: ConnectionId,
+        client_id: ClientId,
+    },
+    /// genesis channel `{port_id}/{channel_id}` references unknown connection `{connection_id}`
+    UnknownGenesisConnection {
+        port_id: PortId,
+        channel_id: ChannelId,
+        connection_id: ConnectionId,
+    },
+    /// genesis channel `{port_id}/{channel_id}` has no connection hops
+    MissingGenesisConnectionHops {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// genesis packet state references unknown channel `{port_id}/{channel_id}`
+    UnknownGenesisChannel {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// replayed message `{index}` failed: `{error}`
+    MessageFailed { index: usize, error: ContextError },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReplayError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::MessageFailed { error: e, .. } => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl MockContext {
+    /// Initializes a context from the `genesis` state and dispatches `msgs`
+    /// on it, with full validation, in order.
+    ///
+    /// Messages are routed to the modules of [`MockRouter::new_with_transfer`].
+    pub fn replay(genesis: GenesisState, msgs: Vec<MsgEnvelope>) -> Result<Self, ReplayError> {
+        Self::replay_with_router(genesis, &mut MockRouter::new_with_transfer(), msgs)
+    }
+
+    /// Same as [`MockContext::replay`], routing the messages with the given
+    /// `router`.
+    pub fn replay_with_router(
+        genesis: GenesisState,
+        router: &mut (impl StagingRouter + ?Sized),
+        msgs: Vec<MsgEnvelope>,
+    ) -> Result<Self, ReplayError> {
+        let mut ctx = Self::from_genesis(genesis)?;
+
+        for (index, msg) in msgs.into_iter().enumerate() {
+            ctx.deliver(router, [msg])
+                .map_err(|e| ReplayError::MessageFailed {
+                    index,
+                    error: e.into(),
+                })?;
+        }
+
+        Ok(ctx)
+    }
+
+    /// Initializes a context from the `genesis` state, checking that the
+    /// state it contains is consistent.
+    pub fn from_genesis(genesis: GenesisState) -> Result<Self, ReplayError> {
+        let mut ctx: Self = MockContextConfig::builder()
+            .host_id(genesis.host_id)
+            .latest_height(genesis.latest_height)
+            .latest_timestamp(genesis.latest_timestamp)
+            .build();
+
+        for client in genesis.clients {
+            ctx = ctx.with_client_config(client);
+        }
+
+        for connection in genesis.connections {
+            if !ctx
+                .ibc_store
+                .clients
+                .contains_key(connection.connection_end.client_id())
+            {
+                return Err(ReplayError::UnknownGenesisClient {
+                    client_id: connection.connection_end.client_id().clone(),
+                    connection_id: connection.connection_id,
+                });
+            }
+            ctx = ctx.with_connection(connection.connection_id, connection.connection_end);
+        }
+
+        for channel in genesis.channels {
+            let connection_id = channel
+                .channel_end
+                .connection_hops()
+                .first()
+                .cloned()
+                .ok_or_else(|| ReplayError::MissingGenesisConnectionHops {
+                    port_id: channel.port_id.clone(),
+                    channel_id: channel.channel_id.clone(),
+                })?;
+
+            let ibc_store = &mut ctx.ibc_store;
+            if !ibc_store.connections.contains_key(&connection_id) {
+                return Err(ReplayError::UnknownGenesisConnection {
+                    port_id: channel.port_id,
+                    channel_id: channel.channel_id,
+                    connection_id,
+                });
+            }
+            ibc_store
+                .connection_channels
+                .entry(connection_id)
+                .or_default()
+                .push((channel.port_id.clone(), channel.channel_id.clone()));
+
+            ctx = ctx.with_channel(channel.port_id, channel.channel_id, channel.channel_end);
+        }
+
+        {
+            let ibc_store = &mut ctx.ibc_store;
+
+            let channels = &ibc_store.channels;
+            let channel_path = |state: &PacketState| {
+                let path = ChannelEndPath::new(&state.port_id, &state.chan_id);
+                if channels.contains_key(&path) {
+                    Ok(path)
+                } else {
+                    Err(ReplayError::UnknownGenesisChannel {
+                        port_id: state.port_id.clone(),
+                        channel_id: state.chan_id.clone(),
+                    })
+                }
+            };
+
+            for state in genesis.send_sequences {
+                let path = channel_path(&state)?;
+                ibc_store.next_sequence_send.insert(path, state.seq);
+            }
+            for state in genesis.recv_sequences {
+                let path = channel_path(&state)?;
+                ibc_store.next_sequence_recv.insert(path, state.seq);
+            }
+            for state in genesis.ack_sequences {
+                let path = channel_path(&state)?;
+                ibc_store.next_sequence_ack.insert(path, state.seq);
+            }
+            for state in genesis.commitments {
+                let path = channel_path(&state)?;
+                ibc_store
+                    .packet_commitment
+                    .entry(path)
+                    .or_default()
+                    .insert(state.seq, state.data.into());
+            }
+            for state in genesis.acknowledgements {
+                let path = channel_path(&state)?;
+                ibc_store
+                    .packet_acknowledgement
+                    .entry(path)
+                    .or_default()
+                    .insert(state.seq, state.data.into());
+            }
+            for state in genesis.receipts {
+                let path = channel_path(&state)?;
+                ibc_store
+                    .packet_receipt
+                    .entry(path)
+                    .or_default()
+                    .insert(state.seq, Receipt::Ok);
+            }
+
+            ibc_store.client_ids_counter = ibc_store.clients.len() as u64;
+            ibc_store.connection_ids_counter = ibc_store.connections.len() as u64;
+            ibc_store.channel_ids_counter = ibc_store.channels.len() as u64;
+        }
+
+        Ok(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::connection::types::msgs::ConnectionMsg;
+    use ibc::core::connection::types::State as ConnectionState;
+    use ibc::core::host::{ConnectionEndValidationContext, ConnectionValidationContext};
+
+    use super::*;
+    use crate::fixtures::core::connection::{
+        dummy_msg_conn_open_init, dummy_msg_conn_open_init_with_client_id, ConnectionEndConfig,
+    };
+
+    fn genesis_connection() -> IdentifiedConnectionEnd {
+        IdentifiedConnectionEnd {
+            connection_id: ConnectionId::zero(),
+            connection_end: ConnectionEndConfig::builder()
+                .build()
+                .try_into()
+                .expect("no error"),
+        }
+    }
+
+    fn genesis() -> GenesisState {
+        GenesisState::builder()
+            .clients(vec![MockClientConfig::builder()
+                .latest_height(Height::new(0, 5).expect("no error"))
+                .latest_timestamp(genesis_time())
+                .build()])
+            .connections(vec![genesis_connection()])
+            .build()
+    }
+
+    #[test]
+    fn test_replay() {
+        let msg = MsgEnvelope::Connection(ConnectionMsg::OpenInit(dummy_msg_conn_open_init()));
+
+        let ctx = MockContext::replay(genesis(), vec![msg.clone(), msg]).expect("no error");
+
+        // The connections opened during the replay follow the genesis one.
+        assert_eq!(ctx.connection_counter().expect("no error"), 3);
+        for conn_id in [ConnectionId::new(1), ConnectionId::new(2)] {
+            assert_eq!(
+                ctx.connection_end(&conn_id).expect("no error").state(),
+                &ConnectionState::Init
+            );
+        }
+    }
+
+    #[test]
+    fn test_replay_failed_message() {
+        let msg = MsgEnvelope::Connection(ConnectionMsg::OpenInit(dummy_msg_conn_open_init()));
+        let msg_unknown_client = MsgEnvelope::Connection(ConnectionMsg::OpenInit(
+            dummy_msg_conn_open_init_with_client_id(
+                dummy_msg_conn_open_init(),
+                ClientId::new("07-tendermint", 1).expect("no error"),
+            ),
+        ));
+
+        let res = MockContext::replay(genesis(), vec![msg, msg_unknown_client]);
+
+        assert!(matches!(
+            res,
+            Err(ReplayError::MessageFailed { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_replay_inconsistent_genesis() {
+        let genesis = GenesisState::builder()
+            .connections(vec![genesis_connection()])
+            .build();
+
+        let res = MockContext::replay(genesis, vec![]);
+
+        assert!(matches!(res, Err(ReplayError::UnknownGenesisClient { .. })));
+    }
+}
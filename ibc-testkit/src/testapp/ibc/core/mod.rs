@@ -1,4 +1,6 @@
 pub mod client_ctx;
 pub mod core_ctx;
+pub mod genesis;
 pub mod router;
+pub mod store;
 pub mod types;
@@ -0,0 +1,57 @@
+use ibc::apps::transfer::handler::{send_transfer_execute, send_transfer_validate};
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::msgs::transfer::{MsgTransfer, TYPE_URL as TRANSFER_TYPE_URL};
+use ibc::apps::transfer::types::MODULE_ID_STR;
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::handler::app_msg::AppMsgRouter;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc::core::primitives::prelude::*;
+use ibc::core::router::types::module::ModuleId;
+use ibc::primitives::proto::Any;
+
+use super::types::MockRouter;
+use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+/// Routes the `MsgTransfer`s to the token transfer module, if registered.
+impl AppMsgRouter for MockRouter {
+    fn routes_app_msg(&self, type_url: &str) -> bool {
+        type_url == TRANSFER_TYPE_URL
+            && self
+                .router
+                .contains_key(&ModuleId::new(MODULE_ID_STR.to_string()))
+    }
+
+    fn validate_app_msg<Ctx>(&self, ctx: &Ctx, msg: Any) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        let msg = MsgTransfer::try_from(msg).map_err(app_error)?;
+        send_transfer_validate(ctx, &self.transfer_module(), msg).map_err(app_error)
+    }
+
+    fn execute_app_msg<Ctx>(&mut self, ctx: &mut Ctx, msg: Any) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        let msg = MsgTransfer::try_from(msg).map_err(app_error)?;
+        send_transfer_execute(ctx, &mut self.transfer_module(), msg).map_err(app_error)
+    }
+}
+
+impl MockRouter {
+    /// Returns the token transfer module, moving tokens with the bank of the
+    /// registered one.
+    fn transfer_module(&self) -> DummyTransferModule {
+        DummyTransferModule {
+            bank: self.transfer_bank.clone(),
+        }
+    }
+}
+
+fn app_error(e: TokenTransferError) -> ContextError {
+    ChannelError::AppModule {
+        description: e.to_string(),
+    }
+    .into()
+}
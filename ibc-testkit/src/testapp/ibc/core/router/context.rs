@@ -2,15 +2,23 @@ use alloc::sync::Arc;
 
 use ibc::core::host::types::identifiers::PortId;
 use ibc::core::router::module::Module;
-use ibc::core::router::router::Router;
+use ibc::core::router::router::{Router, RouterView, StagingRouter};
 use ibc::core::router::types::module::ModuleId;
 
 use super::types::MockRouter;
+use crate::testapp::ibc::applications::transfer::bank::{Balances, MockBank};
 
-impl Router for MockRouter {
+impl RouterView for MockRouter {
     fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
         self.router.get(module_id).map(Arc::as_ref)
     }
+
+    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.port_routes.lookup(port_id).cloned()
+    }
+}
+
+impl Router for MockRouter {
     fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
         // NOTE: The following:
 
@@ -26,8 +34,20 @@ impl Router for MockRouter {
             None => None,
         }
     }
+}
 
-    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
-        self.port_to_module.get(port_id).cloned()
+/// Checkpoints cover the balances of the bank lent to the token transfer
+/// module, if any.
+impl StagingRouter for MockRouter {
+    type Checkpoint = Option<Balances>;
+
+    fn checkpoint(&self) -> Self::Checkpoint {
+        self.transfer_bank.as_ref().map(MockBank::balances)
+    }
+
+    fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        if let (Some(bank), Some(balances)) = (&self.transfer_bank, checkpoint) {
+            bank.set_balances(balances);
+        }
     }
 }
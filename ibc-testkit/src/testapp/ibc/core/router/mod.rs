@@ -1,3 +1,5 @@
+#[cfg(feature = "serde")]
+mod app_msg;
 mod context;
 mod types;
 
@@ -4,32 +4,52 @@ use ibc::apps::transfer::types::MODULE_ID_STR;
 use ibc::core::host::types::identifiers::PortId;
 use ibc::core::primitives::prelude::*;
 use ibc::core::router::module::Module;
+use ibc::core::router::router::PortRoutes;
 use ibc::core::router::types::module::ModuleId;
 
+use crate::testapp::ibc::applications::transfer::bank::MockBank;
 use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
 
 #[derive(Default)]
 pub struct MockRouter {
     pub router: BTreeMap<ModuleId, Arc<dyn Module>>,
 
-    /// Maps ports to the the module that owns it
-    pub port_to_module: BTreeMap<PortId, ModuleId>,
+    /// Maps ports, by identifier or prefix, to the module that owns them
+    pub port_routes: PortRoutes,
+
+    /// The bank lent to the token transfer module, if any
+    pub transfer_bank: Option<MockBank>,
 }
 
 impl MockRouter {
     pub fn new_with_transfer() -> Self {
+        Self::new_with_transfer_module(None)
+    }
+
+    /// Returns a router whose token transfer module moves the tokens of its
+    /// transfers with `bank`, typically the [`MockContext::bank`], so that
+    /// its balances are rolled back along with the context.
+    ///
+    /// [`MockContext::bank`]: crate::testapp::ibc::core::types::MockContext::bank
+    pub fn new_with_transfer_bank(bank: MockBank) -> Self {
+        Self::new_with_transfer_module(Some(bank))
+    }
+
+    fn new_with_transfer_module(bank: Option<MockBank>) -> Self {
         let mut router = Self::default();
 
         let module_id = ModuleId::new(MODULE_ID_STR.to_string());
 
         router.scope_port_to_module(PortId::transfer(), module_id.clone());
 
-        let transfer_mod = DummyTransferModule::new();
+        let transfer_mod = DummyTransferModule { bank: bank.clone() };
 
         router
             .add_route(module_id, transfer_mod)
             .expect("Never fails");
 
+        router.transfer_bank = bank;
+
         router
     }
 
@@ -44,7 +64,28 @@ impl MockRouter {
         }
     }
 
+    /// Registers `module`, typically a middleware [`Stack`] built on top of
+    /// an application, as the owner of `port_id`.
+    ///
+    /// [`Stack`]: ibc::core::router::middleware::Stack
+    pub fn add_stack(
+        &mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+        module: impl Module + 'static,
+    ) -> Result<(), String> {
+        self.add_route(module_id.clone(), module)?;
+        self.scope_port_to_module(port_id, module_id);
+        Ok(())
+    }
+
     pub fn scope_port_to_module(&mut self, port_id: PortId, module_id: ModuleId) {
-        self.port_to_module.insert(port_id, module_id);
+        self.port_routes.add_port(port_id, module_id);
+    }
+
+    /// Routes all the ports starting with `prefix` to `module_id`, e.g. the
+    /// dynamically created `icacontroller-{owner}` ports.
+    pub fn scope_port_prefix_to_module(&mut self, prefix: impl Into<String>, module_id: ModuleId) {
+        self.port_routes.add_prefix(prefix, module_id);
     }
 }
@@ -0,0 +1,77 @@
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::store::{ProvableStore, StoreContext, StoreHost};
+use ibc::core::host::HostValidationContext;
+use ibc::core::primitives::{DefaultCryptoProvider, HostCryptoProvider, Timestamp};
+use ibc::primitives::prelude::*;
+
+use crate::testapp::ibc::clients::mock::client_state::MockClientContext;
+
+/// An in-memory [`ProvableStore`], for testing hosts built on
+/// [`StoreContext`].
+///
+/// Its root is the SHA-256 digest of all its entries, and it does not
+/// produce proofs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InMemoryStore(BTreeMap<Vec<u8>, Vec<u8>>);
+
+impl InMemoryStore {
+    /// Returns the number of entries in the store.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the store has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl ProvableStore for InMemoryStore {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.0.get(key).cloned()
+    }
+
+    fn set(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        self.0.insert(key, value);
+    }
+
+    fn delete(&mut self, key: &[u8]) {
+        self.0.remove(key);
+    }
+
+    fn keys(&self, prefix: &[u8]) -> Vec<Vec<u8>> {
+        self.0
+            .range(prefix.to_vec()..)
+            .map(|(key, _)| key)
+            .take_while(|key| key.starts_with(prefix))
+            .cloned()
+            .collect()
+    }
+
+    fn root(&self) -> Vec<u8> {
+        let mut entries = Vec::new();
+        for (key, value) in &self.0 {
+            entries.extend((key.len() as u64).to_be_bytes());
+            entries.extend(key);
+            entries.extend((value.len() as u64).to_be_bytes());
+            entries.extend(value);
+        }
+
+        DefaultCryptoProvider::sha256(&entries).to_vec()
+    }
+
+    fn prove(&self, _key: &[u8]) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+impl<H: StoreHost> MockClientContext for StoreContext<H> {
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        HostValidationContext::host_timestamp(self)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        HostValidationContext::host_height(self)
+    }
+}
@@ -6,42 +6,62 @@ use core::fmt::Debug;
 use core::ops::{Add, Sub};
 use core::time::Duration;
 
+use ibc::clients::tendermint::cache::ValidatorSetCache;
 use ibc::clients::tendermint::client_state::ClientState as TmClientState;
-use ibc::clients::tendermint::types::TENDERMINT_CLIENT_TYPE;
+use ibc::clients::tendermint::types::{TrustThreshold, VerificationMode, TENDERMINT_CLIENT_TYPE};
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::packet::Receipt;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{ClientParams, Height};
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::commitment_types::specs::ProofSpecs;
+use ibc::core::connection::types::params::ConnectionParams;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::ConnectionEnd;
-use ibc::core::entrypoint::dispatch;
+use ibc::core::entrypoint::dispatch_batch;
 use ibc::core::handler::types::events::IbcEvent;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{
     ChainId, ChannelId, ClientId, ClientType, ConnectionId, PortId, Sequence,
 };
-use ibc::core::host::ValidationContext;
+use ibc::core::host::types::limits::HostLimits;
+use ibc::core::host::types::path::{
+    AckPath, ChannelEndPath, CommitmentPath, Path, ReceiptPath, SeqAckPath, SeqRecvPath,
+    SeqSendPath,
+};
+use ibc::core::host::types::write_set::WriteSet;
+use ibc::core::host::HostValidationContext;
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
-use ibc::core::router::router::Router;
-use parking_lot::Mutex;
+use ibc::core::primitives::{HostTimeProvider, Signer, StdTimeProvider, Timestamp};
+use ibc::core::router::router::StagingRouter;
+use ibc::core::router::types::capability::{Capability, CapabilityName};
+use ibc::core::router::types::module::ModuleId;
 use tendermint_testgen::Validator as TestgenValidator;
 use typed_builder::TypedBuilder;
 
-use super::client_ctx::{MockClientRecord, PortChannelIdMap};
+use super::client_ctx::{MockClientRecord, MockTmVerifier, PortChannelIdMap};
 use crate::fixtures::clients::tendermint::ClientStateConfig as TmClientStateConfig;
 use crate::fixtures::core::context::MockContextConfig;
-use crate::hosts::block::{HostBlock, HostType};
+use crate::hosts::block::{default_consensus_params, ConsensusParams, HostBlock, HostType};
 use crate::relayer::error::RelayerError;
+use crate::testapp::ibc::applications::transfer::bank::MockBank;
 use crate::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState, MOCK_CLIENT_TYPE,
 };
 use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
 use crate::testapp::ibc::clients::mock::header::MockHeader;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+
 pub const DEFAULT_BLOCK_TIME_SECS: u64 = 3;
 
+/// Returns the prefix under which mock hosts commit their IBC state by
+/// default.
+pub fn mock_commitment_prefix() -> CommitmentPrefix {
+    CommitmentPrefix::try_from(b"mock".to_vec()).expect("Never fails")
+}
+
 /// An object that stores all IBC related data.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct MockIbcStore {
     /// The set of all clients, indexed by their id.
     pub clients: BTreeMap<ClientId, MockClientRecord>,
@@ -52,12 +72,23 @@ pub struct MockIbcStore {
     /// Tracks the processed height for the clients
     pub client_processed_heights: BTreeMap<(ClientId, Height), Height>,
 
+    /// Indexes the consensus heights installed by the updates of each client,
+    /// maintained through the `on_client_updated` hook.
+    pub client_update_index: BTreeMap<ClientId, Vec<Height>>,
+
     /// Counter for the client identifiers, necessary for `increase_client_counter` and the
     /// `client_counter` methods.
     pub client_ids_counter: u64,
 
+    /// The ICS-02 parameters of the host.
+    pub client_params: ClientParams,
+
+    /// The ICS-03 parameters of the host, if set. Until then, the host block
+    /// time is used as the maximum expected time per block.
+    pub connection_params: Option<ConnectionParams>,
+
     /// Association between client ids and connection ids.
-    pub client_connections: BTreeMap<ClientId, ConnectionId>,
+    pub client_connections: BTreeMap<ClientId, Vec<ConnectionId>>,
 
     /// All the connections in the store.
     pub connections: BTreeMap<ConnectionId, ConnectionEnd>,
@@ -71,7 +102,7 @@ pub struct MockIbcStore {
     /// Counter for channel identifiers (see `increase_channel_counter`).
     pub channel_ids_counter: u64,
 
-    /// All the channels in the store. TODO Make new key PortId X ChannelId
+    /// All the channels in the store.
     pub channels: PortChannelIdMap<ChannelEnd>,
 
     /// Tracks the sequence number for the next packet to be sent.
@@ -91,6 +122,18 @@ pub struct MockIbcStore {
     /// Used by unordered channel
     pub packet_receipt: PortChannelIdMap<BTreeMap<Sequence, Receipt>>,
 
+    /// Tracks the first sequence whose acknowledgement and receipt are yet to
+    /// be pruned on upgraded channels.
+    pub pruning_sequence_start: PortChannelIdMap<Sequence>,
+
+    /// Tracks the sequence up to which the acknowledgements and receipts of
+    /// upgraded channels can be pruned.
+    pub pruning_sequence_end: PortChannelIdMap<Sequence>,
+
+    /// The capabilities issued by the host, along with the modules owning
+    /// them, indexed by their name.
+    pub capabilities: BTreeMap<CapabilityName, (Capability, Vec<ModuleId>)>,
+
     /// Emitted IBC events in order
     pub events: Vec<IbcEvent>,
 
@@ -98,8 +141,66 @@ pub struct MockIbcStore {
     pub logs: Vec<String>,
 }
 
+impl MockIbcStore {
+    /// Iterates over all the channels in the store.
+    pub fn channel_ends(&self) -> impl Iterator<Item = (&ChannelEndPath, &ChannelEnd)> {
+        self.channels.iter()
+    }
+
+    /// Iterates over the next sequence to be sent on each channel.
+    pub fn next_sequences_send(&self) -> impl Iterator<Item = (SeqSendPath, Sequence)> + '_ {
+        self.next_sequence_send
+            .iter()
+            .map(|(path, seq)| (SeqSendPath::new(&path.0, &path.1), *seq))
+    }
+
+    /// Iterates over the next sequence to be received on each channel.
+    pub fn next_sequences_recv(&self) -> impl Iterator<Item = (SeqRecvPath, Sequence)> + '_ {
+        self.next_sequence_recv
+            .iter()
+            .map(|(path, seq)| (SeqRecvPath::new(&path.0, &path.1), *seq))
+    }
+
+    /// Iterates over the next sequence to be acknowledged on each channel.
+    pub fn next_sequences_ack(&self) -> impl Iterator<Item = (SeqAckPath, Sequence)> + '_ {
+        self.next_sequence_ack
+            .iter()
+            .map(|(path, seq)| (SeqAckPath::new(&path.0, &path.1), *seq))
+    }
+
+    /// Iterates over all the packet commitments in the store.
+    pub fn packet_commitments(&self) -> impl Iterator<Item = (CommitmentPath, &PacketCommitment)> {
+        self.packet_commitment
+            .iter()
+            .flat_map(|(path, commitments)| {
+                commitments.iter().map(|(seq, commitment)| {
+                    (CommitmentPath::new(&path.0, &path.1, *seq), commitment)
+                })
+            })
+    }
+
+    /// Iterates over all the packet receipts in the store.
+    pub fn packet_receipts(&self) -> impl Iterator<Item = (ReceiptPath, &Receipt)> {
+        self.packet_receipt.iter().flat_map(|(path, receipts)| {
+            receipts
+                .iter()
+                .map(|(seq, receipt)| (ReceiptPath::new(&path.0, &path.1, *seq), receipt))
+        })
+    }
+
+    /// Iterates over all the packet acknowledgements in the store.
+    pub fn packet_acknowledgements(
+        &self,
+    ) -> impl Iterator<Item = (AckPath, &AcknowledgementCommitment)> {
+        self.packet_acknowledgement.iter().flat_map(|(path, acks)| {
+            acks.iter()
+                .map(|(seq, ack)| (AckPath::new(&path.0, &path.1, *seq), ack))
+        })
+    }
+}
+
 /// A context implementing the dependencies necessary for testing any IBC module.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct MockContext {
     /// The type of host chain underlying this mock context.
     pub host_chain_type: HostType,
@@ -118,7 +219,67 @@ pub struct MockContext {
     pub block_time: Duration,
 
     /// An object that stores all IBC related data.
-    pub ibc_store: Arc<Mutex<MockIbcStore>>,
+    pub ibc_store: MockIbcStore,
+
+    /// The balances of the host chain, which the token transfer module of a
+    /// [`MockRouter::new_with_transfer_bank`] moves tokens with. Clones of
+    /// the context share them.
+    ///
+    /// [`MockRouter::new_with_transfer_bank`]: crate::testapp::ibc::core::router::MockRouter::new_with_transfer_bank
+    pub bank: MockBank,
+
+    /// Consensus parameters of the host chain.
+    pub consensus_params: ConsensusParams,
+
+    /// Connection versions supported by the host chain.
+    pub connection_versions: Vec<ConnectionVersion>,
+
+    /// Prefix under which the host chain commits its IBC state.
+    pub commitment_prefix: CommitmentPrefix,
+
+    /// Whether connections can be opened with counterparties committing their
+    /// IBC state at the root of their store.
+    pub allow_empty_counterparty_prefix: bool,
+
+    /// Whether the Cosmos SDK `message` event is emitted for each dispatched
+    /// message.
+    pub emit_sdk_message_events: bool,
+
+    /// Whether the ownership of ports and channels is enforced through
+    /// capabilities.
+    pub enforce_capabilities: bool,
+
+    /// Caps on the number of clients, connections and channels created on
+    /// the host chain.
+    pub host_limits: HostLimits,
+
+    /// Window after the trusting period of a client during which the packet
+    /// handlers still accept its proofs, if any.
+    pub client_expiry_grace_period: Option<Duration>,
+
+    /// Operators solely authorized to update the listed clients. Clients not
+    /// listed can be updated by anyone.
+    pub client_update_authorities: BTreeMap<ClientId, Signer>,
+
+    /// Verification modes of the listed Tendermint clients. Clients not
+    /// listed accept skipping updates.
+    pub client_verification_modes: BTreeMap<ClientId, VerificationMode>,
+
+    /// Records the store writes of the handlers, if enabled with
+    /// [`MockContext::with_write_set`].
+    pub write_set: Option<WriteSet>,
+
+    /// Caches the validator sets of the Tendermint headers verified against
+    /// this context, if enabled with [`MockContext::with_validator_set_cache`].
+    pub validator_set_cache: Option<ValidatorSetCache>,
+
+    /// Verifies the headers of the Tendermint clients of this context, as
+    /// set with [`MockContext::with_tm_verifier`].
+    pub tm_verifier: MockTmVerifier,
+
+    /// Provides the time from which the timestamps of the clients added
+    /// without one are derived.
+    pub time_provider: Arc<dyn HostTimeProvider + Send + Sync>,
 }
 
 #[derive(Debug, TypedBuilder)]
@@ -132,8 +293,9 @@ pub struct MockClientConfig {
     latest_height: Height,
     #[builder(default)]
     consensus_state_heights: Vec<Height>,
-    #[builder(default = Timestamp::now())]
-    latest_timestamp: Timestamp,
+    /// Defaults to the current time of the context the client is added to.
+    #[builder(default, setter(strip_option))]
+    latest_timestamp: Option<Timestamp>,
 
     #[builder(default = Duration::from_secs(64000))]
     trusting_period: Duration,
@@ -141,6 +303,16 @@ pub struct MockClientConfig {
     max_clock_drift: Duration,
     #[builder(default = Duration::from_secs(128_000))]
     unbonding_period: Duration,
+
+    // The parameters below only apply to Tendermint clients.
+    #[builder(default = TrustThreshold::ONE_THIRD)]
+    trust_level: TrustThreshold,
+    #[builder(default = ProofSpecs::cosmos())]
+    proof_specs: ProofSpecs,
+    #[builder(default)]
+    upgrade_path: Vec<String>,
+    #[builder(default)]
+    verification_mode: VerificationMode,
 }
 
 /// Returns a MockContext with bare minimum initialization: no clients, no connections and no channels are
@@ -152,26 +324,6 @@ impl Default for MockContext {
     }
 }
 
-/// A manual clone impl is provided because the tests are oblivious to the fact that the `ibc_store`
-/// is a shared ptr.
-impl Clone for MockContext {
-    fn clone(&self) -> Self {
-        let ibc_store = {
-            let ibc_store = self.ibc_store.lock().clone();
-            Arc::new(Mutex::new(ibc_store))
-        };
-
-        Self {
-            host_chain_type: self.host_chain_type,
-            host_chain_id: self.host_chain_id.clone(),
-            max_history_size: self.max_history_size,
-            history: self.history.clone(),
-            block_time: self.block_time,
-            ibc_store,
-        }
-    }
-}
-
 /// Implementation of internal interface for use in testing. The methods in this interface should
 /// _not_ be accessible to any Ics handler.
 impl MockContext {
@@ -210,7 +362,9 @@ impl MockContext {
         );
 
         let block_time = Duration::from_secs(DEFAULT_BLOCK_TIME_SECS);
-        let next_block_timestamp = Timestamp::now().add(block_time).expect("Never fails");
+        let time_provider: Arc<dyn HostTimeProvider + Send + Sync> = Arc::new(StdTimeProvider);
+        let next_block_timestamp = time_provider.now().add(block_time).expect("Never fails");
+        let consensus_params = default_consensus_params();
         MockContext {
             host_chain_type: host_type,
             host_chain_id: host_id.clone(),
@@ -227,11 +381,27 @@ impl MockContext {
                         next_block_timestamp
                             .sub(Duration::from_secs(DEFAULT_BLOCK_TIME_SECS * (i + 1)))
                             .expect("Never fails"),
+                        &consensus_params,
                     )
                 })
                 .collect(),
             block_time,
-            ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
+            ibc_store: MockIbcStore::default(),
+            bank: MockBank::default(),
+            consensus_params,
+            connection_versions: ConnectionVersion::compatibles(),
+            commitment_prefix: mock_commitment_prefix(),
+            allow_empty_counterparty_prefix: false,
+            emit_sdk_message_events: false,
+            enforce_capabilities: false,
+            host_limits: HostLimits::unlimited(),
+            client_expiry_grace_period: None,
+            client_update_authorities: BTreeMap::new(),
+            client_verification_modes: BTreeMap::new(),
+            write_set: None,
+            validator_set_cache: None,
+            tm_verifier: MockTmVerifier::default(),
+            time_provider,
         }
     }
 
@@ -274,7 +444,9 @@ impl MockContext {
         );
 
         let block_time = Duration::from_secs(DEFAULT_BLOCK_TIME_SECS);
-        let next_block_timestamp = Timestamp::now().add(block_time).expect("Never fails");
+        let time_provider: Arc<dyn HostTimeProvider + Send + Sync> = Arc::new(StdTimeProvider);
+        let next_block_timestamp = time_provider.now().add(block_time).expect("Never fails");
+        let consensus_params = default_consensus_params();
 
         let history = (0..max_history_size)
             .rev()
@@ -290,6 +462,7 @@ impl MockContext {
                         .expect("Never fails"),
                     &validator_history[(max_history_size - i) as usize - 1],
                     &validator_history[(max_history_size - i) as usize],
+                    &consensus_params,
                 )
             })
             .collect();
@@ -300,7 +473,22 @@ impl MockContext {
             max_history_size,
             history,
             block_time,
-            ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
+            ibc_store: MockIbcStore::default(),
+            bank: MockBank::default(),
+            consensus_params,
+            connection_versions: ConnectionVersion::compatibles(),
+            commitment_prefix: mock_commitment_prefix(),
+            allow_empty_counterparty_prefix: false,
+            emit_sdk_message_events: false,
+            enforce_capabilities: false,
+            host_limits: HostLimits::unlimited(),
+            client_expiry_grace_period: None,
+            client_update_authorities: BTreeMap::new(),
+            client_verification_modes: BTreeMap::new(),
+            write_set: None,
+            validator_set_cache: None,
+            tm_verifier: MockTmVerifier::default(),
+            time_provider,
         }
     }
 
@@ -442,7 +630,11 @@ impl MockContext {
         )
     }
 
-    pub fn with_client_config(self, client: MockClientConfig) -> Self {
+    pub fn with_client_config(mut self, client: MockClientConfig) -> Self {
+        let latest_timestamp = client
+            .latest_timestamp
+            .unwrap_or_else(|| self.time_provider.now());
+
         let cs_heights = if client.consensus_state_heights.is_empty() {
             vec![client.latest_height]
         } else {
@@ -465,8 +657,7 @@ impl MockContext {
                         (
                             cs_height,
                             MockHeader::new(cs_height).with_timestamp(
-                                client
-                                    .latest_timestamp
+                                latest_timestamp
                                     .sub(self.block_time * (n_blocks as u32))
                                     .expect("never fails"),
                             ),
@@ -475,7 +666,7 @@ impl MockContext {
                     .collect();
 
                 let client_state = MockClientState::new(
-                    MockHeader::new(client.latest_height).with_timestamp(client.latest_timestamp),
+                    MockHeader::new(client.latest_height).with_timestamp(latest_timestamp),
                 );
 
                 let cs_states = blocks
@@ -496,8 +687,7 @@ impl MockContext {
                             HostBlock::generate_tm_block(
                                 client.client_chain_id.clone(),
                                 cs_height.revision_height(),
-                                client
-                                    .latest_timestamp
+                                latest_timestamp
                                     .sub(self.block_time * (n_blocks as u32))
                                     .expect("never fails"),
                             ),
@@ -508,13 +698,19 @@ impl MockContext {
                 let client_state: TmClientState = TmClientStateConfig::builder()
                     .chain_id(client.client_chain_id)
                     .latest_height(client.latest_height)
+                    .trust_level(client.trust_level)
                     .trusting_period(client.trusting_period)
                     .max_clock_drift(client.max_clock_drift)
                     .unbonding_period(client.unbonding_period)
+                    .proof_specs(client.proof_specs)
+                    .upgrade_path(client.upgrade_path)
                     .build()
                     .try_into()
                     .expect("never fails");
 
+                self.client_verification_modes
+                    .insert(client.client_id.clone(), client.verification_mode);
+
                 client_state.inner().validate().expect("never fails");
 
                 let cs_states = blocks
@@ -533,7 +729,6 @@ impl MockContext {
         };
 
         self.ibc_store
-            .lock()
             .clients
             .insert(client.client_id, client_record);
         self
@@ -541,75 +736,88 @@ impl MockContext {
 
     /// Associates a connection to this context.
     pub fn with_connection(
-        self,
+        mut self,
         connection_id: ConnectionId,
         connection_end: ConnectionEnd,
     ) -> Self {
-        self.ibc_store
-            .lock()
-            .connections
-            .insert(connection_id, connection_end);
+        let ibc_store = &mut self.ibc_store;
+        let conn_ids = ibc_store
+            .client_connections
+            .entry(connection_end.client_id().clone())
+            .or_default();
+        if !conn_ids.contains(&connection_id) {
+            conn_ids.push(connection_id.clone());
+        }
+        ibc_store.connections.insert(connection_id, connection_end);
         self
     }
 
     /// Associates a channel (in an arbitrary state) to this context.
     pub fn with_channel(
-        self,
+        mut self,
         port_id: PortId,
         chan_id: ChannelId,
         channel_end: ChannelEnd,
     ) -> Self {
-        let mut channels = self.ibc_store.lock().channels.clone();
-        channels
-            .entry(port_id)
-            .or_default()
-            .insert(chan_id, channel_end);
-        self.ibc_store.lock().channels = channels;
+        self.ibc_store
+            .channels
+            .insert(ChannelEndPath(port_id, chan_id), channel_end);
         self
     }
 
     pub fn with_send_sequence(
-        self,
+        mut self,
         port_id: PortId,
         chan_id: ChannelId,
         seq_number: Sequence,
     ) -> Self {
-        let mut next_sequence_send = self.ibc_store.lock().next_sequence_send.clone();
-        next_sequence_send
-            .entry(port_id)
-            .or_default()
-            .insert(chan_id, seq_number);
-        self.ibc_store.lock().next_sequence_send = next_sequence_send;
+        self.ibc_store
+            .next_sequence_send
+            .insert(ChannelEndPath(port_id, chan_id), seq_number);
         self
     }
 
     pub fn with_recv_sequence(
-        self,
+        mut self,
         port_id: PortId,
         chan_id: ChannelId,
         seq_number: Sequence,
     ) -> Self {
-        let mut next_sequence_recv = self.ibc_store.lock().next_sequence_recv.clone();
-        next_sequence_recv
-            .entry(port_id)
-            .or_default()
-            .insert(chan_id, seq_number);
-        self.ibc_store.lock().next_sequence_recv = next_sequence_recv;
+        self.ibc_store
+            .next_sequence_recv
+            .insert(ChannelEndPath(port_id, chan_id), seq_number);
         self
     }
 
     pub fn with_ack_sequence(
-        self,
+        mut self,
         port_id: PortId,
         chan_id: ChannelId,
         seq_number: Sequence,
     ) -> Self {
-        let mut next_sequence_ack = self.ibc_store.lock().next_sequence_send.clone();
-        next_sequence_ack
-            .entry(port_id)
-            .or_default()
-            .insert(chan_id, seq_number);
-        self.ibc_store.lock().next_sequence_ack = next_sequence_ack;
+        self.ibc_store
+            .next_sequence_ack
+            .insert(ChannelEndPath(port_id, chan_id), seq_number);
+        self
+    }
+
+    /// Sets the pruning sequences of a channel, as upon the completion of its
+    /// upgrade, so that its acknowledgements from `start` up to `end`,
+    /// exclusive, can be pruned.
+    pub fn with_pruning_sequences(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        start: Sequence,
+        end: Sequence,
+    ) -> Self {
+        let chan_end_path = ChannelEndPath(port_id, chan_id);
+        self.ibc_store
+            .pruning_sequence_start
+            .insert(chan_end_path.clone(), start);
+        self.ibc_store
+            .pruning_sequence_end
+            .insert(chan_end_path, end);
         self
     }
 
@@ -622,11 +830,10 @@ impl MockContext {
         } else if target_height.revision_height() < latest_height.revision_height() {
             panic!("Cannot rewind history of the chain to a smaller revision height!")
         } else if target_height.revision_height() > latest_height.revision_height() {
-            // Repeatedly advance the host chain height till we hit the desired height
             let mut ctx = self;
-            while ctx.latest_height().revision_height() < target_height.revision_height() {
-                ctx.advance_host_chain_height()
-            }
+            ctx.advance_host_chain_n_blocks(
+                target_height.revision_height() - latest_height.revision_height(),
+            );
             ctx
         } else {
             // Both the revision number and height match
@@ -635,23 +842,62 @@ impl MockContext {
     }
 
     pub fn with_packet_commitment(
-        self,
+        mut self,
         port_id: PortId,
         chan_id: ChannelId,
         seq: Sequence,
         data: PacketCommitment,
     ) -> Self {
-        let mut packet_commitment = self.ibc_store.lock().packet_commitment.clone();
-        packet_commitment
-            .entry(port_id)
-            .or_default()
-            .entry(chan_id)
+        self.ibc_store
+            .packet_commitment
+            .entry(ChannelEndPath(port_id, chan_id))
             .or_default()
             .insert(seq, data);
-        self.ibc_store.lock().packet_commitment = packet_commitment;
         self
     }
 
+    /// Records the store writes of the handlers executed against this context
+    /// into [`MockContext::write_set`].
+    pub fn with_write_set(self) -> Self {
+        Self {
+            write_set: Some(WriteSet::new()),
+            ..self
+        }
+    }
+
+    /// Records that `value` is written under `path`, if the context keeps a
+    /// write set.
+    pub(crate) fn record_set(&mut self, path: impl Into<Path>, value: impl FnOnce() -> Vec<u8>) {
+        if let Some(write_set) = self.write_set.as_mut() {
+            write_set.set(path, value());
+        }
+    }
+
+    /// Records that `path` is deleted, if the context keeps a write set.
+    pub(crate) fn record_delete(&mut self, path: impl Into<Path>) {
+        if let Some(write_set) = self.write_set.as_mut() {
+            write_set.delete(path);
+        }
+    }
+
+    /// Verifies the headers of the Tendermint clients of this context with
+    /// `verifier`.
+    pub fn with_tm_verifier(self, verifier: MockTmVerifier) -> Self {
+        Self {
+            tm_verifier: verifier,
+            ..self
+        }
+    }
+
+    /// Caches up to `capacity` validator sets of the Tendermint headers
+    /// verified against this context.
+    pub fn with_validator_set_cache(self, capacity: usize) -> Self {
+        Self {
+            validator_set_cache: Some(ValidatorSetCache::new(capacity)),
+            ..self
+        }
+    }
+
     /// Accessor for a block of the local (host) chain from this context.
     /// Returns `None` if the block at the requested height does not exist.
     pub fn host_block(&self, target_height: &Height) -> Option<&HostBlock> {
@@ -666,7 +912,33 @@ impl MockContext {
         }
     }
 
+    /// Returns whether evidence of misbehaviour committed in the given block is
+    /// too old to be handled by the host. As in CometBFT, evidence expires once
+    /// it is older than both the maximum number of blocks and the maximum
+    /// duration set by the evidence parameters.
+    pub fn is_evidence_expired(&self, block: &HostBlock) -> bool {
+        let latest_block = self.history.last().expect("history cannot be empty");
+        let evidence_params = &self.consensus_params.evidence;
+
+        let age_num_blocks = latest_block
+            .height()
+            .revision_height()
+            .saturating_sub(block.height().revision_height());
+        let age_duration = latest_block
+            .timestamp()
+            .duration_since(&block.timestamp())
+            .unwrap_or_default();
+
+        age_num_blocks > evidence_params.max_age_num_blocks
+            && age_duration > evidence_params.max_age_duration.0
+    }
+
     /// Triggers the advancing of the host chain, by extending the history of blocks (or headers).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new block would not be strictly later than the latest
+    /// one, i.e. if the block time of the context is zero.
     pub fn advance_host_chain_height(&mut self) {
         let latest_block = self.history.last().expect("history cannot be empty");
         let new_block = HostBlock::generate_block(
@@ -677,6 +949,11 @@ impl MockContext {
                 .timestamp()
                 .add(self.block_time)
                 .expect("Never fails"),
+            &self.consensus_params,
+        );
+        assert!(
+            new_block.timestamp() > latest_block.timestamp(),
+            "host block timestamps must be strictly increasing"
         );
 
         // Append the new header at the tip of the history.
@@ -690,15 +967,40 @@ impl MockContext {
         }
     }
 
-    /// A datagram passes from the relayer to the IBC module (on host chain).
+    /// Advances the host chain by `n` blocks.
+    pub fn advance_host_chain_n_blocks(&mut self, n: u64) {
+        for _ in 0..n {
+            self.advance_host_chain_height();
+        }
+    }
+
+    /// Advances the host chain block by block until its host timestamp, i.e.
+    /// the timestamp of the block being built, reaches the given one. Does
+    /// nothing if it already has.
+    pub fn advance_until_timestamp(&mut self, timestamp: Timestamp) {
+        while HostValidationContext::host_timestamp(self).expect("Never fails") < timestamp {
+            self.advance_host_chain_height();
+        }
+    }
+
+    /// A batch of datagrams passes from the relayer to the IBC module (on host
+    /// chain), as a single transaction.
     /// Alternative method to `Ics18Context::send` that does not exercise any serialization.
     /// Used in testing the Ics18 algorithms, hence this may return a Ics18Error.
+    ///
+    /// The datagrams are applied atomically: should one fail, all the writes,
+    /// events and logs of the batch are discarded, and its error is returned,
+    /// out of which callers can build the `VersionNegotiationFailed` event of
+    /// a failed version negotiation.
     pub fn deliver(
         &mut self,
-        router: &mut impl Router,
-        msg: MsgEnvelope,
+        router: &mut (impl StagingRouter + ?Sized),
+        msgs: impl IntoIterator<Item = MsgEnvelope>,
     ) -> Result<(), RelayerError> {
-        dispatch(self, router, msg).map_err(RelayerError::TransactionFailed)?;
+        let mut results = dispatch_batch(self, router, msgs.into_iter().collect());
+        if let Some(Err(e)) = results.pop() {
+            return Err(RelayerError::TransactionFailed(e));
+        }
         // Create a new block.
         self.advance_host_chain_height();
         Ok(())
@@ -728,12 +1030,15 @@ impl MockContext {
             if ph.height().increment() != h.height() {
                 return Err("headers in history not sequential".to_string());
             }
+            if ph.timestamp() >= h.timestamp() {
+                return Err("headers in history not monotone in time".to_string());
+            }
         }
         Ok(())
     }
 
     pub fn latest_client_states(&self, client_id: &ClientId) -> AnyClientState {
-        self.ibc_store.lock().clients[client_id]
+        self.ibc_store.clients[client_id]
             .client_state
             .as_ref()
             .expect("Never fails")
@@ -745,7 +1050,7 @@ impl MockContext {
         client_id: &ClientId,
         height: &Height,
     ) -> AnyConsensusState {
-        self.ibc_store.lock().clients[client_id]
+        self.ibc_store.clients[client_id]
             .consensus_states
             .get(height)
             .expect("Never fails")
@@ -759,37 +1064,45 @@ impl MockContext {
             .height()
     }
 
-    pub fn ibc_store_share(&self) -> Arc<Mutex<MockIbcStore>> {
-        self.ibc_store.clone()
-    }
-
     pub fn query_latest_header(&self) -> Option<HostBlock> {
         let block_ref = self.host_block(&self.host_height().expect("Never fails"));
         block_ref.cloned()
     }
 
     pub fn get_events(&self) -> Vec<IbcEvent> {
-        self.ibc_store.lock().events.clone()
+        self.ibc_store.events.clone()
     }
 
     pub fn get_logs(&self) -> Vec<String> {
-        self.ibc_store.lock().logs.clone()
+        self.ibc_store.logs.clone()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use ibc::core::channel::types::acknowledgement::Acknowledgement;
-    use ibc::core::channel::types::channel::{Counterparty, Order};
+    use ibc::core::channel::types::channel::{
+        Counterparty, IdentifiedChannelEnd, Order, State as ChannelState,
+    };
     use ibc::core::channel::types::error::{ChannelError, PacketError};
     use ibc::core::channel::types::packet::Packet;
     use ibc::core::channel::types::Version;
-    use ibc::core::primitives::Signer;
+    use ibc::core::client::context::ClientValidationContext;
+    use ibc::core::connection::types::IdentifiedConnectionEnd;
+    use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
+    use ibc::core::host::{
+        ChannelValidationContext, ConnectionEndValidationContext, ConnectionValidationContext,
+        IterableContext, ProofQueryError,
+    };
+    use ibc::core::primitives::{FixedTimeProvider, Signer};
     use ibc::core::router::module::Module;
+    use ibc::core::router::router::Router;
     use ibc::core::router::types::module::{ModuleExtras, ModuleId};
+    use tendermint::evidence::{Duration as EvidenceDuration, Params as EvidenceParams};
 
     use super::*;
     use crate::fixtures::core::channel::PacketConfig;
+    use crate::fixtures::core::connection::ConnectionEndConfig;
     use crate::fixtures::core::signer::dummy_bech32_account;
     use crate::testapp::ibc::core::router::MockRouter;
 
@@ -932,6 +1245,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_advance_host_chain() {
+        let mut ctx = MockContextConfig::builder()
+            .latest_height(Height::new(0, 5).expect("Never fails"))
+            .build();
+
+        ctx.advance_host_chain_n_blocks(3);
+        assert_eq!(ctx.latest_height(), Height::new(0, 8).expect("Never fails"));
+        assert!(ctx.validate().is_ok());
+
+        let block_time = ctx.block_time;
+        let target =
+            (ctx.host_timestamp().expect("Never fails") + block_time * 4).expect("Never fails");
+        ctx.advance_until_timestamp(target);
+        assert_eq!(
+            ctx.latest_height(),
+            Height::new(0, 12).expect("Never fails")
+        );
+        assert_eq!(ctx.host_timestamp().expect("Never fails"), target);
+        assert!(ctx.validate().is_ok());
+
+        // Reached timestamps do not advance the chain any further.
+        ctx.advance_until_timestamp(target);
+        assert_eq!(
+            ctx.latest_height(),
+            Height::new(0, 12).expect("Never fails")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "host block timestamps must be strictly increasing")]
+    fn test_advance_host_chain_zero_block_time() {
+        let mut ctx = MockContextConfig::builder()
+            .block_time(Duration::ZERO)
+            .latest_height(Height::new(0, 5).expect("Never fails"))
+            .build();
+
+        assert!(ctx.validate().is_err());
+        ctx.advance_host_chain_height();
+    }
+
+    #[test]
+    fn test_evidence_window() {
+        let default_params = default_consensus_params();
+        let max_age_num_blocks = 3;
+
+        let ctx = MockContextConfig::builder()
+            .max_history_size(5)
+            .latest_height(Height::new(0, 10).expect("Never fails"))
+            .consensus_params(ConsensusParams {
+                evidence: EvidenceParams {
+                    max_age_num_blocks,
+                    max_age_duration: EvidenceDuration(Duration::from_secs(
+                        DEFAULT_BLOCK_TIME_SECS * max_age_num_blocks,
+                    )),
+                    ..default_params.evidence
+                },
+                ..default_params
+            })
+            .build();
+
+        let oldest_valid_height = Height::new(0, 10 - max_age_num_blocks).expect("Never fails");
+        let oldest_valid_block = ctx.host_block(&oldest_valid_height).expect("Never fails");
+        assert!(!ctx.is_evidence_expired(oldest_valid_block));
+
+        let expired_height = oldest_valid_height.sub(1).expect("Never fails");
+        let expired_block = ctx.host_block(&expired_height).expect("Never fails");
+        assert!(ctx.is_evidence_expired(expired_block));
+
+        // The host consensus states are retained for as long as the history
+        // holds their block, regardless of the evidence window.
+        assert!(ctx.host_consensus_state(&expired_height).is_ok());
+
+        let pruned_height = Height::new(0, 10 - 5).expect("Never fails");
+        assert!(ctx.host_consensus_state(&pruned_height).is_err());
+    }
+
     #[test]
     fn test_router() {
         #[derive(Debug, Default)]
@@ -992,12 +1382,12 @@ mod tests {
                 &mut self,
                 _packet: &Packet,
                 _relayer: &Signer,
-            ) -> (ModuleExtras, Acknowledgement) {
+            ) -> (ModuleExtras, Option<Acknowledgement>) {
                 self.counter += 1;
 
                 (
                     ModuleExtras::empty(),
-                    Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+                    Some(Acknowledgement::try_from(vec![1u8]).expect("Never fails")),
                 )
             }
 
@@ -1092,10 +1482,10 @@ mod tests {
                 &mut self,
                 _packet: &Packet,
                 _relayer: &Signer,
-            ) -> (ModuleExtras, Acknowledgement) {
+            ) -> (ModuleExtras, Option<Acknowledgement>) {
                 (
                     ModuleExtras::empty(),
-                    Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+                    Some(Acknowledgement::try_from(vec![1u8]).expect("Never fails")),
                 )
             }
 
@@ -1157,4 +1547,316 @@ mod tests {
             on_recv_packet_result("barmodule"),
         ];
     }
+
+    #[test]
+    fn test_ibc_store_iteration() {
+        let port_id = PortId::transfer();
+        let chan_id = ChannelId::new(1);
+        let channel_end = ChannelEnd::new(
+            ChannelState::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+            vec![ConnectionId::zero()],
+            Version::new("ics20-1".to_string()),
+        )
+        .expect("no error");
+        let commitment = PacketCommitment::from(vec![1]);
+
+        let ctx = MockContext::default()
+            .with_channel(port_id.clone(), chan_id.clone(), channel_end.clone())
+            .with_send_sequence(port_id.clone(), chan_id.clone(), 3.into())
+            .with_recv_sequence(port_id.clone(), chan_id.clone(), 2.into())
+            .with_ack_sequence(port_id.clone(), chan_id.clone(), 1.into())
+            .with_packet_commitment(
+                port_id.clone(),
+                chan_id.clone(),
+                1.into(),
+                commitment.clone(),
+            );
+
+        let store = &ctx.ibc_store;
+
+        assert_eq!(
+            store.channel_ends().collect::<Vec<_>>(),
+            vec![(&ChannelEndPath::new(&port_id, &chan_id), &channel_end)]
+        );
+        assert_eq!(
+            store.next_sequences_send().collect::<Vec<_>>(),
+            vec![(SeqSendPath::new(&port_id, &chan_id), 3.into())]
+        );
+        assert_eq!(
+            store.next_sequences_recv().collect::<Vec<_>>(),
+            vec![(SeqRecvPath::new(&port_id, &chan_id), 2.into())]
+        );
+        assert_eq!(
+            store.next_sequences_ack().collect::<Vec<_>>(),
+            vec![(SeqAckPath::new(&port_id, &chan_id), 1.into())]
+        );
+        assert_eq!(
+            store.packet_commitments().collect::<Vec<_>>(),
+            vec![(
+                CommitmentPath::new(&port_id, &chan_id, 1.into()),
+                &commitment
+            )]
+        );
+        assert_eq!(store.packet_receipts().count(), 0);
+        assert_eq!(store.packet_acknowledgements().count(), 0);
+    }
+
+    #[test]
+    fn test_iterable_context() {
+        let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+        let client_height = Height::new(0, 5).expect("no error");
+        let port_id = PortId::transfer();
+        let chan_id = ChannelId::zero();
+        let chan_end_path = ChannelEndPath::new(&port_id, &chan_id);
+
+        let ctx = MockContext::default()
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_id(client_id.clone())
+                    .latest_height(client_height)
+                    .build(),
+            )
+            .with_packet_commitment(
+                port_id.clone(),
+                chan_id.clone(),
+                2.into(),
+                PacketCommitment::from(vec![2]),
+            )
+            .with_packet_commitment(
+                port_id.clone(),
+                chan_id.clone(),
+                1.into(),
+                PacketCommitment::from(vec![1]),
+            );
+
+        let client_states = ctx.iter_client_states().expect("no error");
+        assert_eq!(client_states.len(), 1);
+        assert_eq!(client_states[0].0, ClientStatePath::new(client_id.clone()));
+
+        let consensus_states = ctx.iter_consensus_states(&client_id).expect("no error");
+        assert_eq!(
+            consensus_states
+                .into_iter()
+                .map(|(path, _)| path)
+                .collect::<Vec<_>>(),
+            vec![ClientConsensusStatePath::new(client_id, 0, 5)]
+        );
+
+        // Commitments are ordered by sequence.
+        assert_eq!(
+            ctx.iter_commitments(&chan_end_path).expect("no error"),
+            vec![
+                (
+                    CommitmentPath::new(&port_id, &chan_id, 1.into()),
+                    PacketCommitment::from(vec![1])
+                ),
+                (
+                    CommitmentPath::new(&port_id, &chan_id, 2.into()),
+                    PacketCommitment::from(vec![2])
+                ),
+            ]
+        );
+
+        assert!(ctx.iter_connections().expect("no error").is_empty());
+        assert!(ctx.iter_channels().expect("no error").is_empty());
+        assert!(ctx
+            .iter_receipts(&chan_end_path)
+            .expect("no error")
+            .is_empty());
+        assert!(ctx
+            .iter_acknowledgements(&chan_end_path)
+            .expect("no error")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_borrowed_store_getters() {
+        let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+        let conn_id = ConnectionId::zero();
+        let conn_end: ConnectionEnd = ConnectionEndConfig::builder()
+            .build()
+            .try_into()
+            .expect("no error");
+        let chan_end_path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero());
+        let chan_end = ChannelEnd::new(
+            ChannelState::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+            vec![conn_id.clone()],
+            Version::new("ics20-1".to_string()),
+        )
+        .expect("no error");
+
+        let ctx = MockContext::default()
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_id(client_id.clone())
+                    .latest_height(Height::new(0, 5).expect("no error"))
+                    .build(),
+            )
+            .with_connection(conn_id.clone(), conn_end.clone())
+            .with_channel(
+                chan_end_path.0.clone(),
+                chan_end_path.1.clone(),
+                chan_end.clone(),
+            );
+
+        // The mock host lends its ends and client states instead of cloning them.
+        assert!(matches!(
+            ctx.connection_end_cow(&conn_id),
+            Ok(Cow::Borrowed(end)) if *end == conn_end
+        ));
+        assert!(matches!(
+            ctx.channel_end_cow(&chan_end_path),
+            Ok(Cow::Borrowed(end)) if *end == chan_end
+        ));
+        assert!(matches!(
+            ctx.client_state_cow(&client_id),
+            Ok(Cow::Borrowed(_))
+        ));
+
+        assert!(ctx.connection_end_cow(&ConnectionId::new(1)).is_err());
+        assert!(ctx
+            .channel_end_cow(&ChannelEndPath::new(
+                &PortId::transfer(),
+                &ChannelId::new(1)
+            ))
+            .is_err());
+        assert!(ctx
+            .client_state_cow(&ClientId::new("07-tendermint", 1).expect("no error"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_query_with_proof_unsupported() {
+        let ctx = MockContext::default();
+        let path = Path::ClientState(ClientStatePath::new(
+            ClientId::new("07-tendermint", 0).expect("no error"),
+        ));
+        let height = ctx.latest_height();
+
+        // The mock host keeps no provable store.
+        let error = ctx.query_with_proof(&path, height).unwrap_err();
+        assert!(matches!(
+            error.host_error::<<MockContext as HostValidationContext>::HostError>(),
+            Some(ProofQueryError::Unsupported { path: p, height: h }) if *p == path && *h == height
+        ));
+    }
+
+    #[test]
+    fn test_identified_iteration() {
+        let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+        let conn_id = ConnectionId::new(1);
+        let conn_end: ConnectionEnd = ConnectionEndConfig::builder()
+            .build()
+            .try_into()
+            .expect("no error");
+        let port_id = PortId::transfer();
+        let chan_id = ChannelId::new(2);
+        let chan_end = ChannelEnd::new(
+            ChannelState::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+            vec![conn_id.clone()],
+            Version::new("ics20-1".to_string()),
+        )
+        .expect("no error");
+
+        let ctx = MockContext::default()
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_id(client_id.clone())
+                    .latest_height(Height::new(0, 5).expect("no error"))
+                    .build(),
+            )
+            .with_connection(conn_id.clone(), conn_end.clone())
+            .with_channel(port_id.clone(), chan_id.clone(), chan_end.clone());
+
+        let client_states = ctx.all_client_states().expect("no error");
+        assert_eq!(client_states.len(), 1);
+        assert_eq!(client_states[0].0, client_id);
+
+        assert_eq!(
+            ctx.all_connection_ends().expect("no error"),
+            vec![IdentifiedConnectionEnd::new(conn_id, conn_end)]
+        );
+        assert_eq!(
+            ctx.all_channel_ends().expect("no error"),
+            vec![IdentifiedChannelEnd::new(port_id, chan_id, chan_end)]
+        );
+    }
+
+    #[test]
+    fn test_packet_state_pages() {
+        let port_id = PortId::transfer();
+        let chan_id = ChannelId::zero();
+        let chan_end_path = ChannelEndPath::new(&port_id, &chan_id);
+
+        let ctx = (1..=5u64).fold(MockContext::default(), |ctx, seq| {
+            ctx.with_packet_commitment(
+                port_id.clone(),
+                chan_id.clone(),
+                seq.into(),
+                PacketCommitment::from(vec![seq as u8]),
+            )
+        });
+
+        let page_sequences = |start: u64, limit| {
+            ctx.commitments_page(&chan_end_path, start.into(), limit)
+                .expect("no error")
+                .into_iter()
+                .map(|(path, _)| u64::from(path.sequence))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(page_sequences(1, 2), vec![1, 2]);
+        assert_eq!(page_sequences(3, 2), vec![3, 4]);
+        assert_eq!(page_sequences(5, 2), vec![5]);
+        assert!(page_sequences(6, 2).is_empty());
+
+        assert!(ctx
+            .receipts_page(&chan_end_path, 1.into(), 2)
+            .expect("no error")
+            .is_empty());
+        assert!(ctx
+            .acknowledgements_page(&chan_end_path, 1.into(), 2)
+            .expect("no error")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_time_from_provider() {
+        let now = Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap();
+        let block_time = Duration::from_secs(DEFAULT_BLOCK_TIME_SECS);
+
+        let ctx = MockContextConfig::builder()
+            .time_provider(Arc::new(FixedTimeProvider(now)))
+            .build()
+            .with_client_config(
+                MockClientConfig::builder()
+                    .latest_height(Height::new(0, 3).unwrap())
+                    .build(),
+            );
+
+        assert_eq!(ctx.host_timestamp().unwrap(), now.add(block_time).unwrap());
+
+        let client_id = ClientId::new("07-tendermint", 0).unwrap();
+        let client_state: MockClientState = ctx
+            .ibc_store
+            .clients
+            .get(&client_id)
+            .and_then(|record| record.client_state.clone())
+            .and_then(|client_state| match client_state {
+                AnyClientState::Mock(client_state) => Some(client_state),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(client_state.header.timestamp, now);
+
+        let header =
+            MockHeader::new(Height::new(0, 4).unwrap()).with_current_timestamp(&*ctx.time_provider);
+        assert_eq!(header.timestamp, now);
+    }
 }
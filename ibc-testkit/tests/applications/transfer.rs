@@ -1,14 +1,46 @@
+use std::collections::BTreeMap;
+
+use ibc::apps::transfer::bank::BankKeeper;
+use ibc::apps::transfer::codec::{JsonCodec, PacketDataCodec, ProtoCodec, ProtoV2Codec};
+use ibc::apps::transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use ibc::apps::transfer::handler::{
+    migrate_total_escrow, refund_packet_token_validate, send_transfer, send_transfer_validate,
+    update_params,
+};
 use ibc::apps::transfer::module::{
     on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
-    on_chan_open_try_validate,
+    on_chan_open_try_validate, on_recv_packet_execute, on_timeout_packet_execute,
+};
+use ibc::apps::transfer::query::{query_denom_trace, resolve_denom};
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::msgs::transfer::MsgTransferV2;
+use ibc::apps::transfer::types::packet::PacketDataV2;
+use ibc::apps::transfer::types::proto::transfer::v1::QueryDenomTraceRequest;
+use ibc::apps::transfer::types::{
+    Amount, Memo, PrefixedCoin, PrefixedDenom, TransferParams, VERSION,
 };
-use ibc::apps::transfer::types::VERSION;
-use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementResult};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::PacketMsg;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::client::context::ClientExecutionContext;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::entrypoint::{dispatch, dispatch_batch, simulate};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::HostExecutionContext;
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
 use ibc::cosmos_host::utils::cosmos_adr028_escrow_address;
+use ibc_testkit::fixtures::core::channel::{dummy_msg_recv_packet, dummy_proof};
+use ibc_testkit::fixtures::core::connection::{ConnectionEndConfig, CounterpartyConfig};
 use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use subtle_encoding::bech32;
 
 fn get_defaults() -> (
@@ -26,7 +58,7 @@ fn get_defaults() -> (
     let counterparty = Counterparty::new(port_id.clone(), Some(channel_id.clone()));
 
     (
-        DummyTransferModule,
+        DummyTransferModule::new(),
         order,
         connection_hops,
         port_id,
@@ -166,3 +198,756 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+/// If the counterparty expects another packet data encoding, then fail
+#[test]
+fn test_on_chan_open_try_counterparty_other_encoding() {
+    let (ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+    let res = on_chan_open_try_validate(
+        &ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &ProtoCodec::version(),
+    );
+
+    assert!(res.is_err());
+}
+
+/// A transfer context keeping the balances of its accounts in memory, whose
+/// packet data is encoded as by [`ProtoV2Codec`] for the version of its
+/// channels.
+#[derive(Clone)]
+struct BankContext {
+    channel_version: Version,
+    balances: BTreeMap<(String, PrefixedDenom), Amount>,
+    /// The memos passed to the unescrow and mint hooks, in order.
+    memos: Vec<Memo>,
+    total_escrow: BTreeMap<PrefixedDenom, Amount>,
+    denom_traces: BTreeMap<String, PrefixedDenom>,
+    /// The parameters of each channel, and the global ones under `None`.
+    params: BTreeMap<Option<(PortId, ChannelId)>, TransferParams>,
+}
+
+impl BankContext {
+    fn new(channel_version: Version) -> Self {
+        Self {
+            channel_version,
+            balances: BTreeMap::new(),
+            memos: Vec::new(),
+            total_escrow: BTreeMap::new(),
+            denom_traces: BTreeMap::new(),
+            params: BTreeMap::new(),
+        }
+    }
+
+    fn escrow_account(port_id: &PortId, channel_id: &ChannelId) -> Signer {
+        format!("escrow/{port_id}/{channel_id}").into()
+    }
+
+    fn balance(&self, account: &Signer, denom: &PrefixedDenom) -> Amount {
+        self.balances
+            .get(&(account.to_string(), denom.clone()))
+            .copied()
+            .unwrap_or_else(Amount::zero)
+    }
+
+    fn verify_balance(
+        &self,
+        account: &Signer,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        let balance = self.balance(account, &coin.denom);
+        if balance < coin.amount {
+            return Err(TokenTransferError::InsufficientFunds {
+                send_attempt: coin.amount.to_string(),
+                available_funds: balance.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn add(&mut self, account: &Signer, coin: &PrefixedCoin) -> Result<(), TokenTransferError> {
+        let balance = self.balance(account, &coin.denom).try_add(coin.amount)?;
+        self.balances
+            .insert((account.to_string(), coin.denom.clone()), balance);
+        Ok(())
+    }
+
+    fn sub(&mut self, account: &Signer, coin: &PrefixedCoin) -> Result<(), TokenTransferError> {
+        let balance = self.balance(account, &coin.denom).try_sub(coin.amount)?;
+        self.balances
+            .insert((account.to_string(), coin.denom.clone()), balance);
+        Ok(())
+    }
+}
+
+impl TokenTransferValidationContext for BankContext {
+    type AccountId = Signer;
+    type PacketDataCodec = ProtoV2Codec;
+
+    fn get_port(&self) -> Result<PortId, TokenTransferError> {
+        Ok(PortId::transfer())
+    }
+
+    fn can_send_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn escrow_coins_validate(
+        &self,
+        from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.verify_balance(from_account, coin)
+    }
+
+    fn unescrow_coins_validate(
+        &self,
+        _to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.verify_balance(&Self::escrow_account(port_id, channel_id), coin)
+    }
+
+    fn mint_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.verify_balance(account, coin)
+    }
+
+    fn channel_version(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<Version, TokenTransferError> {
+        Ok(self.channel_version.clone())
+    }
+
+    fn get_denom_trace(
+        &self,
+        trace_hash: &str,
+    ) -> Result<Option<PrefixedDenom>, TokenTransferError> {
+        Ok(self.denom_traces.get(trace_hash).cloned())
+    }
+
+    fn get_total_escrow(
+        &self,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<Amount>, TokenTransferError> {
+        Ok(Some(
+            self.total_escrow
+                .get(denom)
+                .copied()
+                .unwrap_or_else(Amount::zero),
+        ))
+    }
+
+    fn get_params(
+        &self,
+        channel: Option<(&PortId, &ChannelId)>,
+    ) -> Result<TransferParams, TokenTransferError> {
+        let channel = channel.map(|(port_id, channel_id)| (port_id.clone(), channel_id.clone()));
+
+        Ok(self
+            .params
+            .get(&channel)
+            .or_else(|| self.params.get(&None))
+            .copied()
+            .unwrap_or_default())
+    }
+}
+
+impl TokenTransferExecutionContext for BankContext {
+    fn escrow_coins_execute(
+        &mut self,
+        from_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.sub(from_account, coin)?;
+        self.add(&Self::escrow_account(port_id, channel_id), coin)
+    }
+
+    fn unescrow_coins_execute(
+        &mut self,
+        to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.sub(&Self::escrow_account(port_id, channel_id), coin)?;
+        self.add(to_account, coin)?;
+        self.memos.push(memo.clone());
+        Ok(())
+    }
+
+    fn mint_coins_execute(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+        memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.add(account, coin)?;
+        self.memos.push(memo.clone());
+        Ok(())
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.sub(account, coin)
+    }
+
+    fn store_denom_trace(
+        &mut self,
+        trace_hash: &str,
+        denom: &PrefixedDenom,
+    ) -> Result<(), TokenTransferError> {
+        self.denom_traces
+            .insert(trace_hash.to_string(), denom.clone());
+        Ok(())
+    }
+
+    fn store_total_escrow(
+        &mut self,
+        denom: &PrefixedDenom,
+        amount: Amount,
+    ) -> Result<(), TokenTransferError> {
+        self.total_escrow.insert(denom.clone(), amount);
+        Ok(())
+    }
+
+    fn store_params(
+        &mut self,
+        channel: Option<(&PortId, &ChannelId)>,
+        params: TransferParams,
+    ) -> Result<(), TokenTransferError> {
+        let channel = channel.map(|(port_id, channel_id)| (port_id.clone(), channel_id.clone()));
+        self.params.insert(channel, params);
+        Ok(())
+    }
+}
+
+fn sender() -> Signer {
+    "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"
+        .to_string()
+        .into()
+}
+
+fn receiver() -> Signer {
+    "cosmos1x54ltnyg88k0ejmk8ytwrhd3ltm84xehrnlslf"
+        .to_string()
+        .into()
+}
+
+fn packet_data(tokens: &[(&str, u64)]) -> PacketDataV2 {
+    PacketDataV2 {
+        tokens: tokens
+            .iter()
+            .map(|&(denom, amount)| PrefixedCoin {
+                denom: denom.parse().expect("valid denom"),
+                amount: amount.into(),
+            })
+            .collect(),
+        sender: sender(),
+        receiver: receiver(),
+        memo: Memo::default(),
+        forwarding: Default::default(),
+    }
+}
+
+/// Returns a packet received over `transfer/channel-1` from
+/// `transfer/channel-0` of the counterparty.
+fn recv_packet(data: Vec<u8>) -> Packet {
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data,
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: TimeoutTimestamp::Never,
+    }
+}
+
+/// Returns a packet sent over `transfer/channel-1` to `transfer/channel-0` of
+/// the counterparty.
+fn sent_packet(data: Vec<u8>) -> Packet {
+    Packet {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(0),
+        ..recv_packet(data)
+    }
+}
+
+/// Returns a context with the `ics20-2` channel `transfer/channel-1` open to
+/// `transfer/channel-0` of the counterparty.
+fn send_packet_ctx() -> MockContext {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::new(0))),
+        vec![ConnectionId::zero()],
+        ProtoV2Codec::version(),
+    )
+    .unwrap();
+
+    let conn_end_on_a: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .client_id(client_id)
+                .prefix(CommitmentPrefix::empty())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
+
+    MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(Height::new(0, 5).unwrap())
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::new(1), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::new(1), 1.into())
+}
+
+fn msg_transfer(packet_data: PacketDataV2) -> MsgTransferV2 {
+    MsgTransferV2 {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        packet_data,
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 100).unwrap()),
+        timeout_timestamp_on_b: TimeoutTimestamp::Never,
+    }
+}
+
+fn ack_error_code(ack: &Acknowledgement) -> Option<u32> {
+    AcknowledgementResult::try_from(ack)
+        .expect("valid acknowledgement")
+        .code()
+}
+
+#[test]
+fn test_recv_packet_decodes_for_channel_version() {
+    let v1 = JsonCodec::version();
+    let v2 = ProtoV2Codec::version();
+    let data = packet_data(&[("uatom", 10), ("uosmo", 5)]);
+    let v2_bytes = ProtoV2Codec::encode_v2(&data, &v2).unwrap();
+
+    // Multi-token packets are received over `ics20-2` channels.
+    let mut ctx = BankContext::new(v2.clone());
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(v2_bytes.clone()));
+    assert_eq!(ack_error_code(&ack), None);
+    let voucher: PrefixedDenom = "transfer/channel-1/uosmo".parse().unwrap();
+    assert_eq!(ctx.balance(&receiver(), &voucher), Amount::from(5u64));
+
+    // Over `ics20-1` channels, they are rejected with an error
+    // acknowledgement, as by ibc-go.
+    let mut ctx = BankContext::new(v1.clone());
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(v2_bytes));
+    assert_eq!(
+        ack_error_code(&ack),
+        Some(TokenTransferError::PacketDataDeserialization.ack_code())
+    );
+    assert!(ctx.balances.is_empty());
+
+    // Single-token packets of `ics20-1` channels are only accepted over them.
+    let data = packet_data(&[("uatom", 10)]);
+    let v1_bytes = ProtoV2Codec::encode_v2(&data, &v1).unwrap();
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(v1_bytes.clone()));
+    assert_eq!(ack_error_code(&ack), None);
+
+    let mut ctx = BankContext::new(v2);
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(v1_bytes));
+    assert!(ack_error_code(&ack).is_some());
+}
+
+#[test]
+fn test_recv_packet_passes_memo() {
+    let mut ctx = BankContext::new(ProtoV2Codec::version());
+    let escrow = BankContext::escrow_account(&PortId::transfer(), &ChannelId::new(1));
+    let native = PrefixedCoin {
+        denom: "uatom".parse().unwrap(),
+        amount: 10u64.into(),
+    };
+    ctx.add(&escrow, &native).unwrap();
+    migrate_total_escrow(&mut ctx, [native.clone()]).unwrap();
+
+    // The returning `uatom` tokens are unescrowed, while the `uosmo` ones
+    // are minted as vouchers, both along with the memo of the packet.
+    let mut data = packet_data(&[("transfer/channel-0/uatom", 10), ("uosmo", 5)]);
+    data.memo = "swap:uatom".into();
+    let bytes = ProtoV2Codec::encode_v2(&data, &ProtoV2Codec::version()).unwrap();
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(bytes));
+    assert_eq!(ack_error_code(&ack), None);
+
+    assert_eq!(ctx.balance(&receiver(), &native.denom), native.amount);
+    assert_eq!(ctx.memos, vec![data.memo.clone(), data.memo]);
+}
+
+#[test]
+fn test_duplicate_denoms_rejected() {
+    let mut ctx = BankContext::new(ProtoV2Codec::version());
+    let escrow = BankContext::escrow_account(&PortId::transfer(), &ChannelId::new(1));
+    let native = PrefixedCoin {
+        denom: "uatom".parse().unwrap(),
+        amount: 10u64.into(),
+    };
+    ctx.add(&escrow, &native).unwrap();
+    ctx.add(&sender(), &native).unwrap();
+
+    // Each of the returning tokens is covered by the escrow on its own, but
+    // not both of them, so none is unescrowed.
+    let data = packet_data(&[
+        ("transfer/channel-0/uatom", 10),
+        ("transfer/channel-0/uatom", 10),
+    ]);
+    let bytes = ProtoV2Codec::encode_v2(&data, &ProtoV2Codec::version()).unwrap();
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(bytes));
+    assert_eq!(
+        ack_error_code(&ack),
+        Some(
+            TokenTransferError::DuplicateDenom {
+                denom: String::new()
+            }
+            .ack_code()
+        )
+    );
+    assert_eq!(ctx.balance(&receiver(), &native.denom), Amount::zero());
+    assert_eq!(ctx.balance(&escrow, &native.denom), native.amount);
+
+    // Likewise, the sender cannot escrow the same funds twice.
+    let send_ctx = send_packet_ctx();
+    let msg = msg_transfer(packet_data(&[("uatom", 10), ("uatom", 10)]));
+    assert!(matches!(
+        send_transfer_validate(&send_ctx, &ctx, msg),
+        Err(TokenTransferError::DuplicateDenom { denom }) if denom == "uatom"
+    ));
+}
+
+#[test]
+fn test_total_escrow() {
+    let mut send_ctx = send_packet_ctx();
+    let mut ctx = BankContext::new(ProtoV2Codec::version());
+    let uatom: PrefixedDenom = "uatom".parse().unwrap();
+    ctx.add(
+        &sender(),
+        &PrefixedCoin {
+            denom: uatom.clone(),
+            amount: 100u64.into(),
+        },
+    )
+    .unwrap();
+
+    // Escrowing the sent tokens increases the total escrow.
+    send_transfer(
+        &mut send_ctx,
+        &mut ctx,
+        msg_transfer(packet_data(&[("uatom", 30)])),
+    )
+    .unwrap();
+    send_transfer(
+        &mut send_ctx,
+        &mut ctx,
+        msg_transfer(packet_data(&[("uatom", 20)])),
+    )
+    .unwrap();
+    assert_eq!(ctx.total_escrow[&uatom], Amount::from(50u64));
+
+    // Refunding the tokens of a timed out packet decreases it.
+    let data =
+        ProtoV2Codec::encode_v2(&packet_data(&[("uatom", 20)]), &ProtoV2Codec::version()).unwrap();
+    let (_, res) = on_timeout_packet_execute(&mut ctx, &sent_packet(data), &sender());
+    res.unwrap();
+    assert_eq!(ctx.total_escrow[&uatom], Amount::from(30u64));
+
+    // So does unescrowing the tokens coming back.
+    let data = packet_data(&[("transfer/channel-0/uatom", 10)]);
+    let bytes = ProtoV2Codec::encode_v2(&data, &ProtoV2Codec::version()).unwrap();
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(bytes.clone()));
+    assert_eq!(ack_error_code(&ack), None);
+    assert_eq!(ctx.total_escrow[&uatom], Amount::from(20u64));
+
+    // Unescrowing tokens escrowed before the host started tracking the total
+    // escrow fails until the host seeds it.
+    ctx.total_escrow.clear();
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(bytes.clone()));
+    assert_eq!(ack_error_code(&ack), Some(1));
+    assert_eq!(ctx.balance(&receiver(), &uatom), Amount::from(10u64));
+    assert!(matches!(
+        refund_packet_token_validate(
+            &ctx,
+            &sent_packet(Vec::new()),
+            &packet_data(&[("uatom", 10)])
+        ),
+        Err(TokenTransferError::TotalEscrowUnderflow { denom }) if denom == "uatom"
+    ));
+
+    migrate_total_escrow(
+        &mut ctx,
+        [10u64, 10].map(|amount| PrefixedCoin {
+            denom: uatom.clone(),
+            amount: amount.into(),
+        }),
+    )
+    .unwrap();
+    assert_eq!(ctx.total_escrow[&uatom], Amount::from(20u64));
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(bytes));
+    assert_eq!(ack_error_code(&ack), None);
+    assert_eq!(ctx.total_escrow[&uatom], Amount::from(10u64));
+    assert_eq!(ctx.balance(&receiver(), &uatom), Amount::from(20u64));
+
+    // Sending is rejected when the total escrow would overflow.
+    ctx.total_escrow.insert(uatom.clone(), Amount::max_value());
+    let res = send_transfer_validate(&send_ctx, &ctx, msg_transfer(packet_data(&[("uatom", 1)])));
+    assert!(matches!(
+        res,
+        Err(TokenTransferError::TotalEscrowOverflow { denom }) if denom == "uatom"
+    ));
+}
+
+#[test]
+fn test_recv_packet_registers_denom_trace() {
+    let mut ctx = BankContext::new(ProtoV2Codec::version());
+    let voucher: PrefixedDenom = "transfer/channel-1/uosmo".parse().unwrap();
+    let ibc_denom = format!("ibc/{}", voucher.trace_hash());
+    assert!(matches!(
+        resolve_denom(&ctx, &ibc_denom),
+        Err(TokenTransferError::DenomTraceNotFound { .. })
+    ));
+
+    let data = packet_data(&[("uosmo", 5)]);
+    let bytes = ProtoV2Codec::encode_v2(&data, &ProtoV2Codec::version()).unwrap();
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(bytes));
+    assert_eq!(ack_error_code(&ack), None);
+
+    // The trace of the minted vouchers is registered under their hash, from
+    // which their `ibc/{hash}` denomination resolves.
+    assert_eq!(ctx.denom_traces[&voucher.trace_hash()], voucher);
+    assert_eq!(resolve_denom(&ctx, &ibc_denom).unwrap(), voucher);
+
+    let response = query_denom_trace(&ctx, QueryDenomTraceRequest { hash: ibc_denom }).unwrap();
+    assert_eq!(response.denom_trace, Some(voucher.into()));
+}
+
+#[test]
+fn test_transfer_params() {
+    let send_ctx = send_packet_ctx();
+    let mut ctx = BankContext::new(ProtoV2Codec::version());
+    ctx.add(
+        &sender(),
+        &PrefixedCoin {
+            denom: "uatom".parse().unwrap(),
+            amount: 10u64.into(),
+        },
+    )
+    .unwrap();
+    let msg = msg_transfer(packet_data(&[("uatom", 10)]));
+    let bytes =
+        ProtoV2Codec::encode_v2(&packet_data(&[("uosmo", 5)]), &ProtoV2Codec::version()).unwrap();
+    let receive_disabled = Some(
+        TokenTransferError::ReceiveDisabled {
+            reason: String::new(),
+        }
+        .ack_code(),
+    );
+
+    let channel = Some((PortId::transfer(), ChannelId::new(1)));
+    for disabled in [None, channel] {
+        let mut ctx = ctx.clone();
+
+        update_params(
+            &mut ctx,
+            disabled.clone(),
+            TransferParams {
+                send_enabled: false,
+                receive_enabled: true,
+            },
+        )
+        .unwrap();
+        assert!(matches!(
+            send_transfer_validate(&send_ctx, &ctx, msg.clone()),
+            Err(TokenTransferError::SendDisabled { .. })
+        ));
+        let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(bytes.clone()));
+        assert_eq!(ack_error_code(&ack), None);
+
+        update_params(
+            &mut ctx,
+            disabled,
+            TransferParams {
+                send_enabled: true,
+                receive_enabled: false,
+            },
+        )
+        .unwrap();
+        assert!(send_transfer_validate(&send_ctx, &ctx, msg.clone()).is_ok());
+        let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(bytes.clone()));
+        assert_eq!(ack_error_code(&ack), receive_disabled);
+    }
+
+    // Pausing transfers globally pauses them on the channels enabling them.
+    update_params(
+        &mut ctx,
+        None,
+        TransferParams {
+            send_enabled: false,
+            receive_enabled: false,
+        },
+    )
+    .unwrap();
+    update_params(
+        &mut ctx,
+        Some((PortId::transfer(), ChannelId::new(1))),
+        TransferParams::default(),
+    )
+    .unwrap();
+    assert!(matches!(
+        send_transfer_validate(&send_ctx, &ctx, msg.clone()),
+        Err(TokenTransferError::SendDisabled { .. })
+    ));
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(bytes.clone()));
+    assert_eq!(ack_error_code(&ack), receive_disabled);
+
+    // Transfers resume once enabled globally again.
+    update_params(&mut ctx, None, TransferParams::default()).unwrap();
+    assert!(send_transfer_validate(&send_ctx, &ctx, msg).is_ok());
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &recv_packet(bytes));
+    assert_eq!(ack_error_code(&ack), None);
+}
+
+/// Returns a context ready to receive packets over `transfer/channel-1`,
+/// along with a router whose transfer module moves tokens with its bank.
+fn recv_packet_host() -> (MockContext, MockRouter) {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let proof_height = Height::new(0, 5).unwrap();
+
+    let mut ctx = send_packet_ctx();
+    ctx.get_client_execution_context()
+        .store_update_meta(
+            client_id,
+            proof_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+            proof_height,
+        )
+        .unwrap();
+    let router = MockRouter::new_with_transfer_bank(ctx.bank.clone());
+
+    (ctx, router)
+}
+
+fn msg_recv_packet(packet: Packet) -> MsgEnvelope {
+    let msg = dummy_msg_recv_packet(
+        packet,
+        dummy_proof().try_into().unwrap(),
+        Height::new(0, 5).unwrap(),
+        sender(),
+    );
+    PacketMsg::from(msg).into()
+}
+
+#[test]
+fn test_failed_batch_rolls_back_balances() {
+    let (mut ctx, mut router) = recv_packet_host();
+
+    let bytes =
+        ProtoV2Codec::encode_v2(&packet_data(&[("uosmo", 5)]), &JsonCodec::version()).unwrap();
+    let packet = recv_packet(bytes);
+    let packet_on_unknown_channel = Packet {
+        chan_id_on_b: ChannelId::new(2),
+        ..packet.clone()
+    };
+    let voucher: PrefixedDenom = "transfer/channel-1/uosmo".parse().unwrap();
+
+    // The vouchers minted upon receiving the packet are burnt again when a
+    // later message of the batch fails.
+    let results = dispatch_batch(
+        &mut ctx,
+        &mut router,
+        vec![
+            msg_recv_packet(packet.clone()),
+            msg_recv_packet(packet_on_unknown_channel),
+        ],
+    );
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert_eq!(
+        ctx.bank.balance(&receiver(), &voucher).unwrap(),
+        Amount::zero()
+    );
+
+    // Received in a batch of its own, the packet mints them once and for all.
+    let results = dispatch_batch(&mut ctx, &mut router, vec![msg_recv_packet(packet)]);
+    assert!(results[0].is_ok());
+    assert_eq!(
+        ctx.bank.balance(&receiver(), &voucher).unwrap(),
+        Amount::from(5u64)
+    );
+}
+
+#[test]
+fn test_simulate_recv_packet_keeps_balances() {
+    let (mut ctx, mut router) = recv_packet_host();
+
+    let bytes =
+        ProtoV2Codec::encode_v2(&packet_data(&[("uosmo", 5)]), &JsonCodec::version()).unwrap();
+    let msg = msg_recv_packet(recv_packet(bytes));
+    let voucher: PrefixedDenom = "transfer/channel-1/uosmo".parse().unwrap();
+
+    // The simulation reports the events of the receipt, but neither the
+    // receipt nor the minted vouchers outlive it.
+    let simulation = simulate(&mut ctx, &mut router, msg.clone()).unwrap();
+    assert!(!simulation.output.events.is_empty());
+    assert_eq!(
+        ctx.bank.balance(&receiver(), &voucher).unwrap(),
+        Amount::zero()
+    );
+
+    let output = dispatch(&mut ctx, &mut router, msg).unwrap();
+    assert_eq!(simulation.output, output);
+    assert_eq!(
+        ctx.bank.balance(&receiver(), &voucher).unwrap(),
+        Amount::from(5u64)
+    );
+}
@@ -0,0 +1,343 @@
+//! Checks that the ABCI events emitted for `IbcEvent`s, as well as the ones
+//! emitted by ibc-go chains, decode into the expected events.
+
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::Order;
+use ibc::core::channel::types::events::{
+    ChannelClosed, OpenInit as ChannelOpenInit, PruneAcknowledgements, SendPacket, TimeoutPacket,
+    WriteAcknowledgement,
+};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::client::types::events::{ClientMisbehaviour, CreateClient, UpdateClient};
+use ibc::core::client::types::{Height, MisbehaviourEvidence, MisbehaviourKind};
+use ibc::core::connection::types::events::{OpenInit as ConnectionOpenInit, OpenTry};
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::handler::types::events::{
+    CapReached, CapScope, IbcEvent, MessageEvent, SdkMessageEvent, VersionNegotiationFailed,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ClientType, ConnectionId, PortId};
+use ibc::core::router::types::event::ModuleEvent;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use tendermint::abci;
+
+fn height(revision_height: u64) -> Height {
+    Height::new(0, revision_height).expect("no error")
+}
+
+fn packet() -> Packet {
+    dummy_raw_packet(10, 1_700_000_000_000_000_000)
+        .try_into()
+        .expect("no error")
+}
+
+fn round_trip(event: IbcEvent) {
+    let abci_event = abci::Event::try_from(event.clone()).expect("no error");
+
+    assert_eq!(IbcEvent::try_from(abci_event).expect("no error"), event);
+}
+
+#[test]
+fn client_events_round_trip() {
+    let client_type = ClientType::new("07-tendermint").expect("no error");
+    let client_id = client_type.build_client_id(0);
+
+    round_trip(IbcEvent::CreateClient(CreateClient::new(
+        client_id.clone(),
+        client_type.clone(),
+        height(5),
+    )));
+    round_trip(IbcEvent::UpdateClient(UpdateClient::new(
+        client_id.clone(),
+        client_type.clone(),
+        height(6),
+        vec![height(6), height(7)],
+        vec![0xde, 0xad, 0xbe, 0xef],
+    )));
+    round_trip(IbcEvent::ClientMisbehaviour(ClientMisbehaviour::new(
+        client_id.clone(),
+        client_type.clone(),
+        None,
+    )));
+    round_trip(IbcEvent::ClientMisbehaviour(ClientMisbehaviour::new(
+        client_id,
+        client_type,
+        Some(MisbehaviourEvidence {
+            kind: MisbehaviourKind::DoubleSign,
+            conflicting_heights: vec![height(8), height(8)],
+            conflicting_hashes: vec![vec![1; 32], vec![2; 32]],
+        }),
+    )));
+}
+
+#[test]
+fn connection_events_round_trip() {
+    let client_id_on_a = ClientId::new("07-tendermint", 0).expect("no error");
+    let client_id_on_b = ClientId::new("07-tendermint", 1).expect("no error");
+
+    round_trip(IbcEvent::OpenInitConnection(ConnectionOpenInit::new(
+        ConnectionId::zero(),
+        client_id_on_a.clone(),
+        client_id_on_b.clone(),
+    )));
+    round_trip(IbcEvent::OpenTryConnection(OpenTry::new(
+        ConnectionId::new(1),
+        client_id_on_b,
+        ConnectionId::zero(),
+        client_id_on_a,
+    )));
+}
+
+#[test]
+fn channel_events_round_trip() {
+    round_trip(IbcEvent::OpenInitChannel(ChannelOpenInit::new(
+        PortId::transfer(),
+        ChannelId::zero(),
+        PortId::transfer(),
+        ConnectionId::zero(),
+        ChannelVersion::new("ics20-1".to_string()),
+    )));
+    round_trip(IbcEvent::ChannelClosed(ChannelClosed::new(
+        PortId::transfer(),
+        ChannelId::zero(),
+        PortId::transfer(),
+        None,
+        ConnectionId::zero(),
+        Order::Ordered,
+    )));
+    round_trip(IbcEvent::PruneAcknowledgements(PruneAcknowledgements::new(
+        PortId::transfer(),
+        ChannelId::zero(),
+        3,
+        7,
+    )));
+}
+
+#[test]
+fn packet_events_round_trip() {
+    round_trip(IbcEvent::SendPacket(SendPacket::new(
+        packet(),
+        Order::Unordered,
+        ConnectionId::zero(),
+    )));
+    round_trip(IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+        packet(),
+        Acknowledgement::try_from(b"{\"result\":\"AQ==\"}".to_vec()).expect("no error"),
+        ConnectionId::zero(),
+    )));
+    round_trip(IbcEvent::TimeoutPacket(TimeoutPacket::new(
+        packet(),
+        Order::Ordered,
+    )));
+}
+
+#[test]
+fn host_events_round_trip() {
+    round_trip(
+        CapReached::new(
+            CapScope::ConnectionsPerClient(ClientId::new("07-tendermint", 0).expect("no error")),
+            3,
+        )
+        .into(),
+    );
+    round_trip(
+        VersionNegotiationFailed::new(
+            ConnectionVersion::compatibles()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            vec!["ics20-1".to_string()],
+        )
+        .into(),
+    );
+    round_trip(MessageEvent::Channel.into());
+    round_trip(MessageEvent::Module("transfer".to_string()).into());
+    round_trip(
+        SdkMessageEvent::new(
+            "/ibc.core.client.v1.MsgCreateClient".to_string(),
+            "cosmos1".to_string(),
+            "ibc".to_string(),
+        )
+        .into(),
+    );
+}
+
+#[test]
+fn module_events_round_trip() {
+    round_trip(
+        ModuleEvent {
+            kind: "fungible_token_packet".to_string(),
+            attributes: vec![("receiver", "cosmos1").into(), ("success", "true").into()],
+        }
+        .into(),
+    );
+}
+
+#[test]
+fn malformed_core_event_is_rejected() {
+    let event = abci::Event {
+        kind: "create_client".to_string(),
+        attributes: vec![("client_id", "07-tendermint-0").into()],
+    };
+
+    assert!(IbcEvent::try_from(event).is_err());
+}
+
+fn ibc_go_event(kind: &str, attributes: &[(&str, &str)]) -> abci::Event {
+    abci::Event {
+        kind: kind.to_string(),
+        attributes: attributes.iter().map(|&attr| attr.into()).collect(),
+    }
+}
+
+#[test]
+fn ibc_go_update_client() {
+    // ibc-go v8 no longer emits the header of the update.
+    let event = ibc_go_event(
+        "update_client",
+        &[
+            ("client_id", "07-tendermint-0"),
+            ("client_type", "07-tendermint"),
+            ("consensus_height", "0-100"),
+            ("consensus_heights", "0-100"),
+        ],
+    );
+
+    let client_type = ClientType::new("07-tendermint").expect("no error");
+    let expected = UpdateClient::new(
+        client_type.build_client_id(0),
+        client_type,
+        height(100),
+        vec![height(100)],
+        vec![],
+    );
+
+    assert_eq!(
+        IbcEvent::try_from(event).expect("no error"),
+        IbcEvent::UpdateClient(expected)
+    );
+}
+
+#[test]
+fn ibc_go_packet_events() {
+    let packet = packet();
+
+    let send_packet = ibc_go_event(
+        "send_packet",
+        &[
+            ("packet_data", "\0"),
+            ("packet_data_hex", "00"),
+            ("packet_timeout_height", "0-10"),
+            ("packet_timeout_timestamp", "1700000000000000000"),
+            ("packet_sequence", "1"),
+            ("packet_src_port", "transfer"),
+            ("packet_src_channel", "channel-0"),
+            ("packet_dst_port", "transfer"),
+            ("packet_dst_channel", "channel-0"),
+            ("packet_channel_ordering", "ORDER_UNORDERED"),
+            ("packet_connection", "connection-0"),
+            ("connection_id", "connection-0"),
+        ],
+    );
+    assert_eq!(
+        IbcEvent::try_from(send_packet).expect("no error"),
+        IbcEvent::SendPacket(SendPacket::new(
+            packet.clone(),
+            Order::Unordered,
+            ConnectionId::zero(),
+        ))
+    );
+
+    // Events lacking the hexadecimal packet data and acknowledgement, and the
+    // deprecated `packet_connection`, are still decoded.
+    let write_ack = ibc_go_event(
+        "write_acknowledgement",
+        &[
+            ("packet_data", "\0"),
+            ("packet_timeout_height", "0-10"),
+            ("packet_timeout_timestamp", "1700000000000000000"),
+            ("packet_sequence", "1"),
+            ("packet_src_port", "transfer"),
+            ("packet_src_channel", "channel-0"),
+            ("packet_dst_port", "transfer"),
+            ("packet_dst_channel", "channel-0"),
+            ("packet_ack", "{\"result\":\"AQ==\"}"),
+            ("connection_id", "connection-0"),
+        ],
+    );
+    assert_eq!(
+        IbcEvent::try_from(write_ack).expect("no error"),
+        IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+            packet,
+            Acknowledgement::try_from(b"{\"result\":\"AQ==\"}".to_vec()).expect("no error"),
+            ConnectionId::zero(),
+        ))
+    );
+}
+
+#[test]
+fn ibc_go_sdk_message_event() {
+    let event = ibc_go_event(
+        "message",
+        &[
+            ("action", "/ibc.core.client.v1.MsgUpdateClient"),
+            ("sender", "cosmos1"),
+            ("module", "ibc"),
+            ("msg_index", "0"),
+        ],
+    );
+
+    assert_eq!(
+        IbcEvent::try_from(event).expect("no error"),
+        IbcEvent::SdkMessage(SdkMessageEvent::new(
+            "/ibc.core.client.v1.MsgUpdateClient".to_string(),
+            "cosmos1".to_string(),
+            "ibc".to_string(),
+        ))
+    );
+}
+
+#[test]
+fn sdk_message_event_is_module_event() {
+    let event = ibc_go_event(
+        "message",
+        &[
+            ("action", "/cosmos.bank.v1beta1.MsgSend"),
+            ("sender", "cosmos1"),
+        ],
+    );
+
+    assert!(matches!(
+        IbcEvent::try_from(event).expect("no error"),
+        IbcEvent::Module(_)
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn ibc_go_json_event() {
+    let json = r#"{
+        "type": "channel_open_init",
+        "attributes": [
+            { "key": "port_id", "value": "transfer", "index": true },
+            { "key": "channel_id", "value": "channel-0", "index": true },
+            { "key": "counterparty_port_id", "value": "transfer", "index": true },
+            { "key": "connection_id", "value": "connection-0", "index": true },
+            { "key": "version", "value": "ics20-1", "index": true },
+            { "key": "msg_index", "value": "0", "index": true }
+        ]
+    }"#;
+
+    let event: abci::Event = serde_json::from_str(json).expect("no error");
+
+    assert_eq!(
+        IbcEvent::try_from(event).expect("no error"),
+        IbcEvent::OpenInitChannel(ChannelOpenInit::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            ConnectionId::zero(),
+            ChannelVersion::new("ics20-1".to_string()),
+        ))
+    );
+}
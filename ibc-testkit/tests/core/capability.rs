@@ -0,0 +1,39 @@
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::capability::{authenticate_capability, bind_port};
+use ibc::core::host::types::identifiers::PortId;
+use ibc::core::router::types::capability::{Capability, CapabilityName};
+use ibc::core::router::types::error::RouterError;
+use ibc::core::router::types::module::ModuleId;
+use ibc_testkit::fixtures::core::context::MockContextConfig;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+#[test]
+fn test_bind_port() {
+    let mut ctx: MockContext = MockContextConfig::builder()
+        .enforce_capabilities(true)
+        .build();
+    let owner = ModuleId::new("transfer".to_string());
+    let other = ModuleId::new("other".to_string());
+    let port_id = PortId::transfer();
+    let port_capability_name = CapabilityName::port(&port_id);
+
+    let capability = bind_port(&mut ctx, &owner, &port_id).unwrap();
+
+    assert!(authenticate_capability(&ctx, &owner, &capability, &port_capability_name).is_ok());
+    assert!(authenticate_capability(&ctx, &other, &capability, &port_capability_name).is_err());
+    assert!(authenticate_capability(
+        &ctx,
+        &owner,
+        &Capability::new(capability.index() + 1),
+        &port_capability_name
+    )
+    .is_err());
+
+    let res = bind_port(&mut ctx, &other, &port_id);
+    assert!(matches!(
+        res,
+        Err(ContextError::RouterError(RouterError::PortAlreadyBound { port_id: bound_port_id }))
+            if bound_port_id == port_id
+    ));
+}
@@ -0,0 +1,521 @@
+//! Golden tests pinning the ABCI events converted from each `IbcEvent`
+//! variant, as hosts hashing their events rely on their format only changing
+//! along with `EVENT_SCHEMA_VERSION`.
+
+use core::time::Duration;
+
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::Order;
+use ibc::core::channel::types::events::{
+    AcknowledgePacket, ChannelClosed, CloseConfirm, CloseInit, OpenAck as ChannelOpenAck,
+    OpenConfirm as ChannelOpenConfirm, OpenInit as ChannelOpenInit, OpenTry as ChannelOpenTry,
+    ReceivePacket, SendPacket, TimeoutPacket, WriteAcknowledgement,
+};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::client::types::events::{
+    ClientExpired, ClientExpiring, ClientMisbehaviour, CreateClient, UpdateClient,
+    UpdateClientNoOp, UpdateClientParams, UpgradeClient,
+};
+use ibc::core::client::types::{Height, MisbehaviourEvidence, MisbehaviourKind};
+use ibc::core::connection::types::events::{
+    OpenAck as ConnectionOpenAck, OpenConfirm as ConnectionOpenConfirm,
+    OpenInit as ConnectionOpenInit, OpenTry as ConnectionOpenTry,
+};
+use ibc::core::handler::types::events::{
+    CapReached, CapScope, IbcEvent, MessageEvent, SdkMessageEvent, VersionNegotiationFailed,
+    EVENT_SCHEMA_VERSION,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ClientType, ConnectionId, PortId};
+use ibc::core::router::types::event::ModuleEvent;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use tendermint::abci;
+
+/// The attributes shared by the packet events carrying the packet data.
+const PACKET_DATA_ATTRIBUTES: [(&str, &str); 2] =
+    [("packet_data", "\0"), ("packet_data_hex", "00")];
+
+/// The attributes shared by all packet events, following the packet data.
+const PACKET_ATTRIBUTES: [(&str, &str); 7] = [
+    ("packet_timeout_height", "0-10"),
+    ("packet_timeout_timestamp", "1700000000000000000"),
+    ("packet_sequence", "1"),
+    ("packet_src_port", "transfer"),
+    ("packet_src_channel", "channel-0"),
+    ("packet_dst_port", "transfer"),
+    ("packet_dst_channel", "channel-0"),
+];
+
+fn height(revision_height: u64) -> Height {
+    Height::new(0, revision_height).expect("no error")
+}
+
+fn client_type() -> ClientType {
+    ClientType::new("07-tendermint").expect("no error")
+}
+
+fn client_id(counter: u64) -> ClientId {
+    client_type().build_client_id(counter)
+}
+
+fn packet() -> Packet {
+    dummy_raw_packet(10, 1_700_000_000_000_000_000)
+        .try_into()
+        .expect("no error")
+}
+
+/// Asserts that `event` converts into the ABCI event of type `kind` holding
+/// exactly `attributes`, in that order, indexed when `index` is set.
+fn assert_golden(event: IbcEvent, kind: &str, attributes: &[(&str, &str)], index: bool) {
+    let abci_event = abci::Event::try_from(event).expect("no error");
+
+    let actual: Vec<(&str, &str, bool)> = abci_event
+        .attributes
+        .iter()
+        .map(|attr| (attr.key.as_str(), attr.value.as_str(), attr.index))
+        .collect();
+    let expected: Vec<(&str, &str, bool)> = attributes
+        .iter()
+        .map(|&(key, value)| (key, value, index))
+        .collect();
+
+    assert_eq!(abci_event.kind, kind);
+    assert_eq!(
+        actual, expected,
+        "attributes of `{kind}` event in version {GOLDEN_SCHEMA_VERSION} of the event schema"
+    );
+}
+
+/// The version of the event format pinned by the golden tests below.
+const GOLDEN_SCHEMA_VERSION: u32 = 1;
+
+#[test]
+fn event_schema_version() {
+    // Changing the golden attributes of an existing event requires bumping
+    // both `EVENT_SCHEMA_VERSION` and the version pinned here.
+    assert_eq!(EVENT_SCHEMA_VERSION, GOLDEN_SCHEMA_VERSION);
+}
+
+#[test]
+fn client_events_golden() {
+    assert_golden(
+        IbcEvent::CreateClient(CreateClient::new(client_id(0), client_type(), height(5))),
+        "create_client",
+        &[
+            ("client_id", "07-tendermint-0"),
+            ("client_type", "07-tendermint"),
+            ("consensus_height", "0-5"),
+        ],
+        false,
+    );
+    assert_golden(
+        IbcEvent::UpdateClient(UpdateClient::new(
+            client_id(0),
+            client_type(),
+            height(6),
+            vec![height(6), height(7)],
+            vec![0xde, 0xad, 0xbe, 0xef],
+        )),
+        "update_client",
+        &[
+            ("client_id", "07-tendermint-0"),
+            ("client_type", "07-tendermint"),
+            ("consensus_height", "0-6"),
+            ("consensus_heights", "0-6,0-7"),
+            ("header", "deadbeef"),
+        ],
+        false,
+    );
+    assert_golden(
+        IbcEvent::UpdateClientNoOp(UpdateClientNoOp::new(
+            client_id(0),
+            client_type(),
+            vec![0xde, 0xad, 0xbe, 0xef],
+        )),
+        "update_client_no_op",
+        &[
+            ("client_id", "07-tendermint-0"),
+            ("client_type", "07-tendermint"),
+            ("header", "deadbeef"),
+        ],
+        false,
+    );
+    assert_golden(
+        IbcEvent::UpgradeClient(UpgradeClient::new(client_id(0), client_type(), height(8))),
+        "upgrade_client",
+        &[
+            ("client_id", "07-tendermint-0"),
+            ("client_type", "07-tendermint"),
+            ("consensus_height", "0-8"),
+        ],
+        false,
+    );
+    assert_golden(
+        IbcEvent::ClientMisbehaviour(ClientMisbehaviour::new(client_id(0), client_type(), None)),
+        "client_misbehaviour",
+        &[
+            ("client_id", "07-tendermint-0"),
+            ("client_type", "07-tendermint"),
+        ],
+        false,
+    );
+    assert_golden(
+        IbcEvent::ClientMisbehaviour(ClientMisbehaviour::new(
+            client_id(0),
+            client_type(),
+            Some(MisbehaviourEvidence {
+                kind: MisbehaviourKind::DoubleSign,
+                conflicting_heights: vec![height(9), height(9)],
+                conflicting_hashes: vec![vec![0x01, 0x02], vec![0x0a, 0x0b]],
+            }),
+        )),
+        "client_misbehaviour",
+        &[
+            ("client_id", "07-tendermint-0"),
+            ("client_type", "07-tendermint"),
+            ("misbehaviour_type", "double_sign"),
+            ("conflicting_heights", "0-9,0-9"),
+            ("conflicting_hashes", "0102,0a0b"),
+        ],
+        false,
+    );
+    assert_golden(
+        IbcEvent::UpdateClientParams(UpdateClientParams::new(vec![
+            "07-tendermint".to_string(),
+            "06-solomachine".to_string(),
+        ])),
+        "update_client_params",
+        &[("allowed_clients", "07-tendermint,06-solomachine")],
+        false,
+    );
+    assert_golden(
+        IbcEvent::ClientExpiring(ClientExpiring::new(
+            client_id(0),
+            client_type(),
+            Duration::from_secs(3600),
+        )),
+        "client_expiring",
+        &[
+            ("client_id", "07-tendermint-0"),
+            ("client_type", "07-tendermint"),
+            ("seconds_until_expiry", "3600"),
+        ],
+        false,
+    );
+    assert_golden(
+        IbcEvent::ClientExpired(ClientExpired::new(client_id(0), client_type())),
+        "client_expired",
+        &[
+            ("client_id", "07-tendermint-0"),
+            ("client_type", "07-tendermint"),
+        ],
+        false,
+    );
+}
+
+#[test]
+fn connection_events_golden() {
+    assert_golden(
+        IbcEvent::OpenInitConnection(ConnectionOpenInit::new(
+            ConnectionId::zero(),
+            client_id(0),
+            client_id(1),
+        )),
+        "connection_open_init",
+        &[
+            ("connection_id", "connection-0"),
+            ("client_id", "07-tendermint-0"),
+            ("counterparty_client_id", "07-tendermint-1"),
+            ("counterparty_connection_id", ""),
+        ],
+        false,
+    );
+
+    let attributes = [
+        ("connection_id", "connection-1"),
+        ("client_id", "07-tendermint-1"),
+        ("counterparty_client_id", "07-tendermint-0"),
+        ("counterparty_connection_id", "connection-0"),
+    ];
+    assert_golden(
+        IbcEvent::OpenTryConnection(ConnectionOpenTry::new(
+            ConnectionId::new(1),
+            client_id(1),
+            ConnectionId::zero(),
+            client_id(0),
+        )),
+        "connection_open_try",
+        &attributes,
+        false,
+    );
+    assert_golden(
+        IbcEvent::OpenAckConnection(ConnectionOpenAck::new(
+            ConnectionId::new(1),
+            client_id(1),
+            ConnectionId::zero(),
+            client_id(0),
+        )),
+        "connection_open_ack",
+        &attributes,
+        false,
+    );
+    assert_golden(
+        IbcEvent::OpenConfirmConnection(ConnectionOpenConfirm::new(
+            ConnectionId::new(1),
+            client_id(1),
+            ConnectionId::zero(),
+            client_id(0),
+        )),
+        "connection_open_confirm",
+        &attributes,
+        false,
+    );
+}
+
+#[test]
+fn channel_events_golden() {
+    let version = ChannelVersion::new("ics20-1".to_string());
+
+    assert_golden(
+        IbcEvent::OpenInitChannel(ChannelOpenInit::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            ConnectionId::zero(),
+            version.clone(),
+        )),
+        "channel_open_init",
+        &[
+            ("port_id", "transfer"),
+            ("channel_id", "channel-0"),
+            ("counterparty_port_id", "transfer"),
+            ("counterparty_channel_id", ""),
+            ("connection_id", "connection-0"),
+            ("version", "ics20-1"),
+        ],
+        false,
+    );
+    assert_golden(
+        IbcEvent::OpenTryChannel(ChannelOpenTry::new(
+            PortId::transfer(),
+            ChannelId::new(1),
+            PortId::transfer(),
+            ChannelId::zero(),
+            ConnectionId::new(1),
+            version,
+        )),
+        "channel_open_try",
+        &[
+            ("port_id", "transfer"),
+            ("channel_id", "channel-1"),
+            ("counterparty_port_id", "transfer"),
+            ("counterparty_channel_id", "channel-0"),
+            ("connection_id", "connection-1"),
+            ("version", "ics20-1"),
+        ],
+        false,
+    );
+
+    let attributes = [
+        ("port_id", "transfer"),
+        ("channel_id", "channel-0"),
+        ("counterparty_port_id", "transfer"),
+        ("counterparty_channel_id", "channel-1"),
+        ("connection_id", "connection-0"),
+    ];
+    assert_golden(
+        IbcEvent::OpenAckChannel(ChannelOpenAck::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            ChannelId::new(1),
+            ConnectionId::zero(),
+        )),
+        "channel_open_ack",
+        &attributes,
+        false,
+    );
+    assert_golden(
+        IbcEvent::OpenConfirmChannel(ChannelOpenConfirm::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            ChannelId::new(1),
+            ConnectionId::zero(),
+        )),
+        "channel_open_confirm",
+        &attributes,
+        false,
+    );
+    assert_golden(
+        IbcEvent::CloseInitChannel(CloseInit::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            ChannelId::new(1),
+            ConnectionId::zero(),
+        )),
+        "channel_close_init",
+        &attributes,
+        false,
+    );
+    assert_golden(
+        IbcEvent::CloseConfirmChannel(CloseConfirm::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            ChannelId::new(1),
+            ConnectionId::zero(),
+        )),
+        "channel_close_confirm",
+        &attributes,
+        false,
+    );
+    assert_golden(
+        IbcEvent::ChannelClosed(ChannelClosed::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            None,
+            ConnectionId::zero(),
+            Order::Ordered,
+        )),
+        "channel_close",
+        &[
+            ("port_id", "transfer"),
+            ("channel_id", "channel-0"),
+            ("counterparty_port_id", "transfer"),
+            ("counterparty_channel_id", ""),
+            ("connection_id", "connection-0"),
+            ("packet_channel_ordering", "ORDER_ORDERED"),
+        ],
+        false,
+    );
+}
+
+#[test]
+fn packet_events_golden() {
+    let ordering = [("packet_channel_ordering", "ORDER_UNORDERED")];
+    let connection = [("packet_connection", "connection-0")];
+
+    let with_data: Vec<_> = [
+        &PACKET_DATA_ATTRIBUTES[..],
+        &PACKET_ATTRIBUTES,
+        &ordering,
+        &connection,
+    ]
+    .concat();
+    assert_golden(
+        IbcEvent::SendPacket(SendPacket::new(
+            packet(),
+            Order::Unordered,
+            ConnectionId::zero(),
+        )),
+        "send_packet",
+        &with_data,
+        false,
+    );
+    assert_golden(
+        IbcEvent::ReceivePacket(ReceivePacket::new(
+            packet(),
+            Order::Unordered,
+            ConnectionId::zero(),
+        )),
+        "recv_packet",
+        &with_data,
+        false,
+    );
+
+    let ack = [
+        ("packet_ack", "{\"result\":\"AQ==\"}"),
+        ("packet_ack_hex", "7b22726573756c74223a2241513d3d227d"),
+    ];
+    assert_golden(
+        IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+            packet(),
+            Acknowledgement::try_from(b"{\"result\":\"AQ==\"}".to_vec()).expect("no error"),
+            ConnectionId::zero(),
+        )),
+        "write_acknowledgement",
+        &[
+            &PACKET_DATA_ATTRIBUTES[..],
+            &PACKET_ATTRIBUTES,
+            &ack,
+            &connection,
+        ]
+        .concat(),
+        false,
+    );
+
+    assert_golden(
+        IbcEvent::AcknowledgePacket(AcknowledgePacket::new(
+            packet(),
+            Order::Unordered,
+            ConnectionId::zero(),
+        )),
+        "acknowledge_packet",
+        &[&PACKET_ATTRIBUTES[..], &ordering, &connection].concat(),
+        false,
+    );
+    assert_golden(
+        IbcEvent::TimeoutPacket(TimeoutPacket::new(packet(), Order::Unordered)),
+        "timeout_packet",
+        &[&PACKET_ATTRIBUTES[..], &ordering].concat(),
+        false,
+    );
+}
+
+#[test]
+fn host_events_golden() {
+    assert_golden(
+        CapReached::new(CapScope::ConnectionsPerClient(client_id(0)), 3).into(),
+        "cap_reached",
+        &[
+            ("cap", "max_connections_per_client"),
+            ("max", "3"),
+            ("client_id", "07-tendermint-0"),
+        ],
+        false,
+    );
+    assert_golden(
+        VersionNegotiationFailed::new(vec!["ics20-2".to_string()], vec!["ics20-1".to_string()])
+            .into(),
+        "version_negotiation_failed",
+        &[
+            ("proposed_versions", "[ ics20-2 ]"),
+            ("supported_versions", "[ ics20-1 ]"),
+        ],
+        false,
+    );
+    assert_golden(
+        ModuleEvent {
+            kind: "fungible_token_packet".to_string(),
+            attributes: vec![("receiver", "cosmos1").into(), ("success", "true").into()],
+        }
+        .into(),
+        "fungible_token_packet",
+        &[("receiver", "cosmos1"), ("success", "true")],
+        false,
+    );
+    assert_golden(
+        MessageEvent::Channel.into(),
+        "message",
+        &[("module", "ibc_channel")],
+        true,
+    );
+    assert_golden(
+        SdkMessageEvent::new(
+            "/ibc.core.channel.v1.MsgRecvPacket".to_string(),
+            "cosmos1".to_string(),
+            "ibc".to_string(),
+        )
+        .into(),
+        "message",
+        &[
+            ("action", "/ibc.core.channel.v1.MsgRecvPacket"),
+            ("sender", "cosmos1"),
+            ("module", "ibc"),
+        ],
+        true,
+    );
+}
@@ -0,0 +1,388 @@
+//! Test vectors checking that the ABCI events emitted by ibc-rs can be parsed
+//! back by relayers.
+//!
+//! The `hermes` module below vendors the attribute expectations Hermes relies
+//! on when it turns ABCI events into packets and client updates: which event
+//! kinds it looks at, which attribute keys it reads, and how each value is
+//! decoded. Any change to the emitted attributes that breaks one of these
+//! expectations will make the tests in this file fail.
+
+use core::ops::Add;
+use core::time::Duration;
+
+use ibc::core::channel::handler::send_packet;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::events::{ReceivePacket, SendPacket, WriteAcknowledgement};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::events::{CreateClient, UpdateClient};
+use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::entrypoint::execute;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ClientType, ConnectionId, PortId};
+use ibc::core::primitives::*;
+use ibc::primitives::proto::Any;
+use ibc::primitives::ToVec;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::fixtures::core::connection::{ConnectionEndConfig, CounterpartyConfig};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use tendermint::abci;
+
+/// A minimal re-implementation of the event parsing performed by Hermes.
+mod hermes {
+    use core::str::FromStr;
+
+    use ibc::core::channel::types::packet::Packet;
+    use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+    use ibc::core::client::types::Height;
+    use ibc::core::host::types::identifiers::{ChannelId, ClientId, ClientType, PortId};
+    use subtle_encoding::hex;
+    use tendermint::abci;
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ClientAttributes {
+        pub client_id: ClientId,
+        pub client_type: ClientType,
+        pub consensus_height: Height,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum RelayerEvent {
+        CreateClient(ClientAttributes),
+        UpdateClient {
+            common: ClientAttributes,
+            header: Vec<u8>,
+        },
+        SendPacket(Packet),
+        ReceivePacket(Packet),
+        WriteAcknowledgement {
+            packet: Packet,
+            ack: Vec<u8>,
+        },
+    }
+
+    /// Parses an ABCI event the way Hermes does. Returns `Ok(None)` for event
+    /// kinds Hermes does not relay on.
+    pub fn parse(event: &abci::Event) -> Result<Option<RelayerEvent>, String> {
+        let parsed = match event.kind.as_str() {
+            "create_client" => RelayerEvent::CreateClient(client_attributes(event)?),
+            "update_client" => RelayerEvent::UpdateClient {
+                common: client_attributes(event)?,
+                header: decode_hex(event, "header")?,
+            },
+            "send_packet" => RelayerEvent::SendPacket(packet(event)?),
+            "recv_packet" => RelayerEvent::ReceivePacket(packet(event)?),
+            "write_acknowledgement" => RelayerEvent::WriteAcknowledgement {
+                packet: packet(event)?,
+                ack: decode_hex(event, "packet_ack_hex")?,
+            },
+            _ => return Ok(None),
+        };
+
+        Ok(Some(parsed))
+    }
+
+    fn attribute<'a>(event: &'a abci::Event, key: &str) -> Result<&'a str, String> {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+            .ok_or_else(|| format!("`{}` event is missing attribute `{key}`", event.kind))
+    }
+
+    fn parse_attribute<T>(event: &abci::Event, key: &str) -> Result<T, String>
+    where
+        T: FromStr,
+        T::Err: core::fmt::Debug,
+    {
+        let value = attribute(event, key)?;
+        value
+            .parse()
+            .map_err(|e| format!("invalid `{key}` value `{value}`: {e:?}"))
+    }
+
+    fn decode_hex(event: &abci::Event, key: &str) -> Result<Vec<u8>, String> {
+        let value = attribute(event, key)?;
+        hex::decode(value).map_err(|e| format!("invalid `{key}` hex value `{value}`: {e}"))
+    }
+
+    fn client_attributes(event: &abci::Event) -> Result<ClientAttributes, String> {
+        Ok(ClientAttributes {
+            client_id: parse_attribute(event, "client_id")?,
+            client_type: parse_attribute(event, "client_type")?,
+            consensus_height: parse_attribute(event, "consensus_height")?,
+        })
+    }
+
+    /// Hermes encodes the absence of a timeout height as `0-0`, which is not a
+    /// valid `Height`.
+    fn timeout_height(event: &abci::Event) -> Result<TimeoutHeight, String> {
+        let value = attribute(event, "packet_timeout_height")?;
+        if value == "0-0" {
+            return Ok(TimeoutHeight::Never);
+        }
+        value
+            .parse::<Height>()
+            .map(TimeoutHeight::At)
+            .map_err(|e| format!("invalid `packet_timeout_height` value `{value}`: {e}"))
+    }
+
+    fn packet(event: &abci::Event) -> Result<Packet, String> {
+        let timeout_timestamp = parse_attribute::<u64>(event, "packet_timeout_timestamp")?;
+
+        Ok(Packet {
+            seq_on_a: parse_attribute::<u64>(event, "packet_sequence")?.into(),
+            port_id_on_a: parse_attribute::<PortId>(event, "packet_src_port")?,
+            chan_id_on_a: parse_attribute::<ChannelId>(event, "packet_src_channel")?,
+            port_id_on_b: parse_attribute::<PortId>(event, "packet_dst_port")?,
+            chan_id_on_b: parse_attribute::<ChannelId>(event, "packet_dst_channel")?,
+            // Hermes only reads the hex-encoded data, since the raw attribute
+            // requires the packet data to be valid UTF-8.
+            data: decode_hex(event, "packet_data_hex")?,
+            timeout_height_on_b: timeout_height(event)?,
+            timeout_timestamp_on_b: TimeoutTimestamp::try_from(timeout_timestamp)
+                .map_err(|e| format!("invalid `packet_timeout_timestamp`: {e}"))?,
+        })
+    }
+}
+
+use hermes::{ClientAttributes, RelayerEvent};
+
+fn to_abci(event: IbcEvent) -> abci::Event {
+    abci::Event::try_from(event).expect("event converts to ABCI")
+}
+
+fn parse(event: IbcEvent) -> RelayerEvent {
+    let abci_event = to_abci(event);
+    hermes::parse(&abci_event)
+        .unwrap_or_else(|e| panic!("Hermes failed to parse `{}`: {e}", abci_event.kind))
+        .unwrap_or_else(|| panic!("Hermes ignored `{}` event", abci_event.kind))
+}
+
+fn sample_packet(timeout_height_on_b: TimeoutHeight, data: Vec<u8>) -> Packet {
+    Packet {
+        seq_on_a: 7.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(3),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(11),
+        data,
+        timeout_height_on_b,
+        timeout_timestamp_on_b: TimeoutTimestamp::At(
+            Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap(),
+        ),
+    }
+}
+
+fn sample_client_attributes() -> ClientAttributes {
+    ClientAttributes {
+        client_id: ClientId::new("07-tendermint", 4).unwrap(),
+        client_type: "07-tendermint".parse::<ClientType>().unwrap(),
+        consensus_height: Height::new(2, 15).unwrap(),
+    }
+}
+
+#[test]
+fn create_client_vector() {
+    let expected = sample_client_attributes();
+
+    let event = CreateClient::new(
+        expected.client_id.clone(),
+        expected.client_type.clone(),
+        expected.consensus_height,
+    );
+
+    assert_eq!(
+        parse(IbcEvent::CreateClient(event)),
+        RelayerEvent::CreateClient(expected)
+    );
+}
+
+#[test]
+fn update_client_vector() {
+    let expected = sample_client_attributes();
+    let header = vec![0x0a, 0x00, 0xff, 0x42];
+
+    let event = UpdateClient::new(
+        expected.client_id.clone(),
+        expected.client_type.clone(),
+        expected.consensus_height,
+        vec![expected.consensus_height],
+        header.clone(),
+    );
+
+    assert_eq!(
+        parse(IbcEvent::UpdateClient(event)),
+        RelayerEvent::UpdateClient {
+            common: expected,
+            header,
+        }
+    );
+}
+
+#[test]
+fn packet_event_vectors() {
+    let connection_id = ConnectionId::new(5);
+
+    // Covers both encodings of the timeout height.
+    let packets = [
+        sample_packet(
+            TimeoutHeight::At(Height::new(1, 100).unwrap()),
+            br#"{"amount":"10"}"#.to_vec(),
+        ),
+        sample_packet(TimeoutHeight::Never, b"payload".to_vec()),
+    ];
+
+    for packet in packets {
+        let send = SendPacket::new(packet.clone(), Order::Unordered, connection_id.clone());
+        assert_eq!(
+            parse(IbcEvent::SendPacket(send)),
+            RelayerEvent::SendPacket(packet.clone())
+        );
+
+        let recv = ReceivePacket::new(packet.clone(), Order::Ordered, connection_id.clone());
+        assert_eq!(
+            parse(IbcEvent::ReceivePacket(recv)),
+            RelayerEvent::ReceivePacket(packet.clone())
+        );
+
+        let ack = Acknowledgement::try_from(br#"{"result":"AQ=="}"#.to_vec()).unwrap();
+        let write_ack =
+            WriteAcknowledgement::new(packet.clone(), ack.clone(), connection_id.clone());
+        assert_eq!(
+            parse(IbcEvent::WriteAcknowledgement(write_ack)),
+            RelayerEvent::WriteAcknowledgement {
+                packet,
+                ack: ack.as_bytes().to_vec(),
+            }
+        );
+    }
+}
+
+#[test]
+fn missing_attribute_is_rejected() {
+    let packet = sample_packet(TimeoutHeight::Never, b"payload".to_vec());
+    let mut event = to_abci(IbcEvent::SendPacket(SendPacket::new(
+        packet,
+        Order::Unordered,
+        ConnectionId::zero(),
+    )));
+    event
+        .attributes
+        .retain(|attr| attr.key != "packet_data_hex");
+
+    assert!(hermes::parse(&event).is_err());
+}
+
+#[test]
+fn send_packet_handler_events() {
+    let client_id = ClientId::new("07-tendermint", 0).unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .client_id(client_id)
+                .prefix(CommitmentPrefix::empty())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(Height::new(0, 5).unwrap())
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let timestamp_future = Timestamp::now().add(Duration::from_secs(10)).unwrap();
+    let mut packet: Packet = dummy_raw_packet(10, timestamp_future.nanoseconds())
+        .try_into()
+        .unwrap();
+    packet.seq_on_a = 1.into();
+    packet.data = br#"{"amount":"1"}"#.to_vec();
+
+    send_packet(&mut ctx, packet.clone()).expect("send_packet succeeds");
+
+    let relayed: Vec<RelayerEvent> = ctx
+        .get_events()
+        .into_iter()
+        .filter_map(|event| hermes::parse(&to_abci(event)).expect("Hermes parses the event"))
+        .collect();
+
+    assert_eq!(relayed, vec![RelayerEvent::SendPacket(packet)]);
+}
+
+#[test]
+fn update_client_handler_events() {
+    let client_id = ClientId::new("9999-mock", 0).unwrap();
+
+    let mut ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_id(client_id.clone())
+            .client_type(mock_client_type())
+            .latest_height(Height::new(0, 42).unwrap())
+            .build(),
+    );
+    let mut router = MockRouter::new_with_transfer();
+
+    let height = Height::new(0, 46).unwrap();
+    let header: Any = MockHeader::new(height)
+        .with_timestamp(Timestamp::now())
+        .into();
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: header.clone(),
+        signer: dummy_account_id(),
+    };
+
+    execute(
+        &mut ctx,
+        &mut router,
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    )
+    .expect("update client succeeds");
+
+    let relayed: Vec<RelayerEvent> = ctx
+        .get_events()
+        .into_iter()
+        .filter_map(|event| hermes::parse(&to_abci(event)).expect("Hermes parses the event"))
+        .collect();
+
+    assert_eq!(
+        relayed,
+        vec![RelayerEvent::UpdateClient {
+            common: ClientAttributes {
+                client_id,
+                client_type: mock_client_type(),
+                consensus_height: height,
+            },
+            header: header.to_vec(),
+        }]
+    );
+}
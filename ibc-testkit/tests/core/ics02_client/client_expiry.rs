@@ -0,0 +1,65 @@
+use core::time::Duration;
+
+use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::client::expiry::check_client_expirations;
+use ibc::core::client::types::events::{ClientExpired, ClientExpiring};
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::ChainId;
+use ibc::core::primitives::Timestamp;
+use ibc_testkit::fixtures::core::context::MockContextConfig;
+use ibc_testkit::testapp::ibc::core::types::MockClientConfig;
+use test_log::test;
+
+#[test]
+fn test_check_client_expirations() {
+    let client_id = tm_client_type().build_client_id(0);
+    let timestamp = Timestamp::now();
+    let trusting_period = Duration::from_secs(64);
+    let warning_period = Duration::from_secs(10);
+
+    let mut ctx = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .latest_timestamp(timestamp)
+        .block_time(Duration::from_secs(1))
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .client_id(client_id.clone())
+                .latest_height(Height::new(1, 18).unwrap())
+                .client_type(tm_client_type())
+                .latest_timestamp(timestamp)
+                .trusting_period(trusting_period)
+                .build(),
+        );
+    let client_ids = [client_id.clone()];
+
+    // The client has its whole trusting period left.
+    check_client_expirations(&mut ctx, &client_ids, warning_period).unwrap();
+    assert!(ctx.get_events().is_empty());
+
+    ctx.advance_until_timestamp((timestamp + Duration::from_secs(60)).unwrap());
+    check_client_expirations(&mut ctx, &client_ids, warning_period).unwrap();
+    assert_eq!(
+        ctx.get_events(),
+        vec![IbcEvent::ClientExpiring(ClientExpiring::new(
+            client_id.clone(),
+            tm_client_type(),
+            Duration::from_secs(4),
+        ))]
+    );
+
+    ctx.advance_until_timestamp(
+        ((timestamp + trusting_period).unwrap() + Duration::from_secs(1)).unwrap(),
+    );
+    check_client_expirations(&mut ctx, &client_ids, warning_period).unwrap();
+    assert_eq!(
+        ctx.get_events().last(),
+        Some(&IbcEvent::ClientExpired(ClientExpired::new(
+            client_id,
+            tm_client_type(),
+        )))
+    );
+}
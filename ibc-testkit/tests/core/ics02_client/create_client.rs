@@ -3,16 +3,25 @@ use ibc::clients::tendermint::types::{
 };
 use ibc::core::client::context::client_state::ClientStateCommon;
 use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::handler::update_client_params;
 use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient};
-use ibc::core::client::types::Height;
-use ibc::core::entrypoint::{execute, validate};
+use ibc::core::client::types::{ClientParams, Height};
+use ibc::core::entrypoint::{
+    dispatch, dispatch_batch, execute, execute_deferred, simulate, validate,
+};
 use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{CapReached, CapScope, IbcEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::{ClientStateRef, ValidationContext};
+use ibc::core::host::staging::StagingError;
+use ibc::core::host::types::limits::HostLimits;
+use ibc::core::host::types::path::{NextClientSequencePath, Path};
+use ibc::core::host::types::write_set::{StoreWrite, WriteSet};
+use ibc::core::host::{ClientRegistryValidationContext, ClientStateRef};
 use ibc_testkit::fixtures::clients::tendermint::{
     dummy_tendermint_header, dummy_tm_client_state_from_header,
 };
+use ibc_testkit::fixtures::core::context::MockContextConfig;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
@@ -49,11 +58,133 @@ fn test_create_client_ok() {
 
     assert!(res.is_ok(), "execution happy path");
 
+    let output = res.unwrap();
+    assert_eq!(output.events, ctx.get_events());
+    assert_eq!(output.logs, ctx.get_logs());
+    assert!(!output.logs.is_empty());
+
     let expected_client_state = ClientStateRef::<MockContext>::try_from(msg.client_state).unwrap();
     assert_eq!(expected_client_state.client_type(), client_type);
     assert_eq!(ctx.client_state(&client_id).unwrap(), expected_client_state);
 }
 
+#[test]
+fn test_create_client_batch_is_atomic() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+    let invalid_msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        TmConsensusState::from(dummy_tendermint_header()).into(),
+        dummy_account_id(),
+    );
+
+    let client_counter = ctx.client_counter().unwrap();
+
+    let results = dispatch_batch(
+        &mut ctx,
+        &mut router,
+        vec![
+            MsgEnvelope::from(ClientMsg::from(msg.clone())),
+            MsgEnvelope::from(ClientMsg::from(invalid_msg)),
+        ],
+    );
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err(), "the consensus state is not a mock one");
+    assert_eq!(ctx.client_counter().unwrap(), client_counter);
+    assert!(ctx.ibc_store.events.is_empty());
+
+    let results = dispatch_batch(
+        &mut ctx,
+        &mut router,
+        vec![MsgEnvelope::from(ClientMsg::from(msg))],
+    );
+
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(ctx.client_counter().unwrap(), client_counter + 1);
+}
+
+#[test]
+fn test_simulate_create_client() {
+    let mut ctx = MockContext::default().with_write_set();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let client_counter = ctx.client_counter().unwrap();
+
+    let simulation = simulate(&mut ctx, &mut router, msg_envelope.clone()).unwrap();
+
+    assert_eq!(ctx.client_counter().unwrap(), client_counter);
+    assert!(ctx.get_events().is_empty());
+    assert_eq!(ctx.write_set, Some(WriteSet::new()));
+
+    let output = dispatch(&mut ctx, &mut router, msg_envelope).unwrap();
+
+    assert_eq!(simulation.output, output);
+    assert_eq!(
+        simulation.writes,
+        ctx.write_set.expect("write set is recorded").writes()
+    );
+}
+
+#[test]
+fn test_execute_deferred_create_client() {
+    let mut ctx = MockContext::default().with_write_set();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let client_counter = ctx.client_counter().unwrap();
+    let client_id = mock_client_type().build_client_id(client_counter);
+
+    // Without a write set, the writes would be lost, so the message is not
+    // executed.
+    let mut ctx_without_write_set = MockContext::default();
+    let err = execute_deferred(
+        &mut ctx_without_write_set,
+        &mut router,
+        msg_envelope.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err.host_error::<StagingError>(),
+        Some(StagingError::WriteSetNotRecorded)
+    ));
+    assert!(ctx_without_write_set.get_events().is_empty());
+
+    let deferred = execute_deferred(&mut ctx, &mut router, msg_envelope).unwrap();
+
+    assert!(ctx.client_state(&client_id).is_err());
+    assert_eq!(ctx.client_counter().unwrap(), client_counter);
+    assert!(ctx.get_events().is_empty());
+    assert!(!deferred.output.events.is_empty());
+    assert!(deferred.writes.contains(&StoreWrite {
+        path: Path::NextClientSequence(NextClientSequencePath),
+        value: Some((client_counter + 1).to_be_bytes().to_vec()),
+    }));
+}
+
 #[test]
 fn test_tm_create_client_ok() {
     let signer = dummy_account_id();
@@ -120,3 +251,90 @@ fn test_invalid_frozen_tm_client_creation() {
         Err(ContextError::ClientError(ClientError::ClientFrozen { .. }))
     ))
 }
+
+#[test]
+fn test_create_client_not_allowed() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let params = ClientParams::new(vec![tm_client_type().as_str().to_string()]).unwrap();
+
+    update_client_params::validate(&ctx, &params).unwrap();
+    update_client_params::execute(&mut ctx, params.clone()).unwrap();
+
+    assert_eq!(ctx.client_params().unwrap(), params);
+    let ibc_events = ctx.get_events();
+    let IbcEvent::UpdateClientParams(event) = ibc_events.last().unwrap() else {
+        panic!("unexpected event variant");
+    };
+    assert_eq!(event.allowed_clients(), params.allowed_clients.as_slice());
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(ClientError::ClientTypeNotAllowed { client_type }))
+            if client_type == mock_client_type()
+    ));
+
+    // Allowing the client type again lets the client be created.
+    update_client_params::execute(&mut ctx, ClientParams::default()).unwrap();
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+}
+
+#[test]
+fn test_create_client_max_clients() {
+    let mut ctx: MockContext = MockContextConfig::builder()
+        .host_limits(HostLimits::unlimited().with_max_clients(2))
+        .build();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    for _ in 0..2 {
+        let res = validate(&ctx, &router, msg_envelope.clone());
+        assert!(res.is_ok(), "result: {res:?}");
+
+        let res = execute(&mut ctx, &mut router, msg_envelope.clone());
+        assert!(res.is_ok(), "result: {res:?}");
+    }
+
+    // Only the creation exhausting the cap emits the event.
+    let cap_events: Vec<_> = ctx
+        .get_events()
+        .into_iter()
+        .filter_map(|event| match event {
+            IbcEvent::CapReached(event) => Some(event),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(cap_events, vec![CapReached::new(CapScope::Clients, 2)]);
+
+    let res = validate(&ctx, &router, msg_envelope);
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(ClientError::MaxClientsReached {
+            max_clients: 2
+        }))
+    ));
+}
@@ -1,6 +1,8 @@
+pub mod client_expiry;
 #[cfg(feature = "serde")]
 pub mod create_client;
 pub mod recover_client;
+pub mod submit_evidence;
 pub mod update_client;
 #[cfg(feature = "serde")]
 pub mod upgrade_client;
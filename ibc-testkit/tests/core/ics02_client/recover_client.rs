@@ -7,7 +7,7 @@ use ibc::core::client::types::Height;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ClientId;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::ClientRegistryValidationContext;
 use ibc::core::primitives::{Signer, Timestamp};
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
@@ -55,7 +55,10 @@ fn setup_client_recovery_fixture(
     // Create the subject client
     let msg = MsgCreateClient::new(
         subject_client_state.into(),
-        MockConsensusState::new(MockHeader::new(subject_height).with_current_timestamp()).into(),
+        MockConsensusState::new(
+            MockHeader::new(subject_height).with_current_timestamp(&*ctx.time_provider),
+        )
+        .into(),
         signer.clone(),
     );
 
@@ -68,13 +71,17 @@ fn setup_client_recovery_fixture(
     execute(&mut ctx, &mut router, msg_envelope).expect("create subject client execution");
 
     // Create the substitute client
-    let substitute_client_state =
-        MockClientState::new(MockHeader::new(substitute_height).with_current_timestamp())
-            .with_trusting_period(substitute_trusting_period);
+    let substitute_client_state = MockClientState::new(
+        MockHeader::new(substitute_height).with_current_timestamp(&*ctx.time_provider),
+    )
+    .with_trusting_period(substitute_trusting_period);
 
     let msg = MsgCreateClient::new(
         substitute_client_state.into(),
-        MockConsensusState::new(MockHeader::new(substitute_height).with_current_timestamp()).into(),
+        MockConsensusState::new(
+            MockHeader::new(substitute_height).with_current_timestamp(&*ctx.time_provider),
+        )
+        .into(),
         signer.clone(),
     );
 
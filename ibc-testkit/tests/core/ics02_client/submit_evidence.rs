@@ -0,0 +1,97 @@
+use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::Height;
+use ibc::core::entrypoint::submit_evidence;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::primitives::Timestamp;
+use ibc::primitives::proto::Any;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+
+struct Fixture {
+    ctx: MockContext,
+    client_id: ClientId,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_id(client_id.clone())
+            .latest_height(Height::new(0, 42).unwrap())
+            .build(),
+    );
+
+    Fixture { ctx, client_id }
+}
+
+fn mock_misbehaviour(client_id: &ClientId) -> Any {
+    let timestamp = Timestamp::now();
+    let height = Height::new(0, 46).unwrap();
+
+    MockMisbehaviour {
+        client_id: client_id.clone(),
+        header1: MockHeader::new(height).with_timestamp(timestamp),
+        header2: MockHeader::new(height).with_timestamp(timestamp),
+    }
+    .into()
+}
+
+#[rstest]
+fn test_submit_evidence_freezes_client(fixture: Fixture) {
+    let Fixture { mut ctx, client_id } = fixture;
+
+    let res = submit_evidence(&mut ctx, &client_id, mock_misbehaviour(&client_id));
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    assert!(client_state.status(&ctx, &client_id).unwrap().is_frozen());
+
+    let ibc_events = ctx.get_events();
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Client)
+    ));
+    let IbcEvent::ClientMisbehaviour(event) = &ibc_events[1] else {
+        panic!("unexpected event variant");
+    };
+    assert_eq!(event.client_id(), &client_id);
+    assert_eq!(event.client_type(), &mock_client_type());
+
+    // A frozen client does not take any more evidence.
+    let res = submit_evidence(&mut ctx, &client_id, mock_misbehaviour(&client_id));
+    assert!(res.is_err());
+}
+
+#[rstest]
+fn test_submit_evidence_without_misbehaviour(fixture: Fixture) {
+    let Fixture { mut ctx, client_id } = fixture;
+
+    let header: Any = MockHeader::new(Height::new(0, 46).unwrap())
+        .with_timestamp(Timestamp::now())
+        .into();
+
+    let res = submit_evidence(&mut ctx, &client_id, header);
+    assert!(res.is_err());
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    assert!(client_state.status(&ctx, &client_id).unwrap().is_active());
+    assert!(ctx.get_events().is_empty());
+}
+
+#[rstest]
+fn test_submit_evidence_nonexisting_client(fixture: Fixture) {
+    let Fixture { mut ctx, .. } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 1).expect("no error");
+
+    let res = submit_evidence(&mut ctx, &client_id, mock_misbehaviour(&client_id));
+    assert!(res.is_err());
+}
@@ -2,38 +2,47 @@ use core::str::FromStr;
 use core::time::Duration;
 
 use ibc::clients::tendermint::client_state::ClientState;
+use ibc::clients::tendermint::context::Verdict;
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
 use ibc::clients::tendermint::types::{
     client_type as tm_client_type, ClientState as TmClientState, Header as TmHeader,
-    Misbehaviour as TmMisbehaviour,
+    Misbehaviour as TmMisbehaviour, TrustThreshold, VerificationMode,
 };
 use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
 use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::events::ClientMisbehaviour;
 use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
 use ibc::core::client::types::proto::v1::Height as RawHeight;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{Height, MisbehaviourKind};
 use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChainId, ClientId, ClientType};
 use ibc::core::host::types::path::ClientConsensusStatePath;
-use ibc::core::host::ValidationContext;
-use ibc::core::primitives::Timestamp;
+use ibc::core::host::HostValidationContext;
+use ibc::core::primitives::{Signer, Timestamp};
 use ibc::primitives::proto::Any;
 use ibc::primitives::ToVec;
+use ibc_testkit::fixtures::clients::tendermint::{
+    tm_light_client_attack_evidence, tm_misbehaviour_from_host_blocks,
+};
 use ibc_testkit::fixtures::core::context::MockContextConfig;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
-use ibc_testkit::hosts::block::{HostBlock, HostType};
+use ibc_testkit::hosts::block::{HostBlock, HostType, SyntheticTmBlock};
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
 };
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+use ibc_testkit::testapp::ibc::core::client_ctx::MockTmVerifier;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use rstest::*;
+use tendermint::AppHash;
 use tendermint_testgen::Validator as TestgenValidator;
 
 struct Fixture {
@@ -109,6 +118,160 @@ fn test_update_client_ok(fixture: Fixture) {
     );
 }
 
+#[rstest]
+fn test_update_client_authorized_signer(fixture: Fixture) {
+    let Fixture { mut ctx, router } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let operator = Signer::from("operator".to_string());
+
+    ctx.client_update_authorities
+        .insert(client_id.clone(), operator.clone());
+
+    let msg_update = |signer: Signer| {
+        MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: MockHeader::new(Height::new(0, 46).unwrap())
+                .with_timestamp(Timestamp::now())
+                .into(),
+            signer,
+        }))
+    };
+
+    let res = validate(&ctx, &router, msg_update(dummy_account_id()));
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(
+                ClientError::UnauthorizedClientUpdate { .. }
+            ))
+        ),
+        "only the operator may update the client: {res:?}"
+    );
+
+    let res = validate(&ctx, &router, msg_update(operator));
+
+    assert!(res.is_ok(), "the operator updates the client: {res:?}");
+}
+
+#[rstest]
+fn test_update_client_tracks_update_heights(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+    } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let initial_heights = ctx.update_heights(&client_id).unwrap();
+
+    let update_heights: Vec<Height> = [46, 48]
+        .into_iter()
+        .map(|h| Height::new(0, h).unwrap())
+        .collect();
+
+    for height in &update_heights {
+        let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: MockHeader::new(*height)
+                .with_timestamp(Timestamp::now())
+                .into(),
+            signer: dummy_account_id(),
+        }));
+
+        let res = execute(&mut ctx, &mut router, msg_envelope);
+        assert!(res.is_ok(), "result: {res:?}");
+    }
+
+    let heights = ctx.update_heights(&client_id).unwrap();
+    assert_eq!(heights.len(), initial_heights.len() + update_heights.len());
+    assert!(heights.ends_with(&update_heights));
+    assert!(heights
+        .iter()
+        .all(|height| ctx.client_update_meta(&client_id, height).is_ok()));
+
+    let unknown_client_id = ClientId::new("07-tendermint", 1).expect("no error");
+    assert!(ctx.update_heights(&unknown_client_id).unwrap().is_empty());
+}
+
+#[rstest]
+fn test_update_client_calls_update_hook(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+    } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let update_heights: Vec<Height> = [46, 48]
+        .into_iter()
+        .map(|h| Height::new(0, h).unwrap())
+        .collect();
+
+    for height in &update_heights {
+        let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: MockHeader::new(*height)
+                .with_timestamp(Timestamp::now())
+                .into(),
+            signer: dummy_account_id(),
+        }));
+
+        let res = execute(&mut ctx, &mut router, msg_envelope);
+        assert!(res.is_ok(), "result: {res:?}");
+    }
+
+    assert_eq!(
+        ctx.ibc_store.client_update_index.get(&client_id),
+        Some(&update_heights)
+    );
+}
+
+#[rstest]
+fn test_delete_update_meta(fixture: Fixture) {
+    use ibc::core::client::context::ClientExecutionContext;
+
+    let Fixture {
+        mut ctx,
+        mut router,
+    } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    for height in [46, 48] {
+        let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: MockHeader::new(Height::new(0, height).unwrap())
+                .with_timestamp(Timestamp::now())
+                .into(),
+            signer: dummy_account_id(),
+        }));
+
+        let res = execute(&mut ctx, &mut router, msg_envelope);
+        assert!(res.is_ok(), "result: {res:?}");
+    }
+
+    let heights = ctx.update_heights(&client_id).unwrap();
+    let (first_height, remaining_heights) = heights.split_first().unwrap();
+
+    // Removing only the update time leaves the height tracked.
+    ctx.delete_update_time(client_id.clone(), *first_height)
+        .unwrap();
+    assert!(ctx.client_update_meta(&client_id, first_height).is_err());
+    assert_eq!(ctx.update_heights(&client_id).unwrap(), heights);
+
+    ctx.delete_update_height(client_id.clone(), *first_height)
+        .unwrap();
+    assert_eq!(ctx.update_heights(&client_id).unwrap(), remaining_heights);
+
+    ctx.delete_all_update_meta(&client_id).unwrap();
+    assert!(ctx.update_heights(&client_id).unwrap().is_empty());
+    assert!(remaining_heights
+        .iter()
+        .all(|height| ctx.client_update_meta(&client_id, height).is_err()));
+    assert!(ctx.ibc_store.client_processed_times.is_empty());
+}
+
 #[rstest]
 // Tests successful submission of a header with a height below the latest
 // client's height and ensures that `ConsensusState` is stored at the correct
@@ -356,6 +519,116 @@ fn test_update_synthetic_tendermint_client_adjacent_ok() {
     assert_eq!(client_state.latest_height(), latest_header_height);
 }
 
+#[rstest]
+fn test_update_synthetic_tendermint_client_stub_verifier() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .build(),
+        );
+
+    let router = MockRouter::new_with_transfer();
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b)
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(update_height)
+        .build();
+
+    let mut tm_block = ctx_b
+        .host_block(&update_height)
+        .unwrap()
+        .clone()
+        .try_into_tm_block()
+        .unwrap();
+    tm_block.trusted_height = client_height;
+    // The header no longer matches the commit signed by the validators.
+    tm_block.light_block.signed_header.header.app_hash = AppHash::try_from(vec![1; 32]).unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id,
+        client_message: TmHeader::from(tm_block).into(),
+        signer: dummy_account_id(),
+    }));
+
+    // The light client verifier rejects the tampered header...
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(res.is_err(), "result: {res:?}");
+
+    // ...which the stub verifier plugged in by the host accepts.
+    let ctx = ctx.with_tm_verifier(MockTmVerifier::Stub(|| Verdict::Success));
+    let res = validate(&ctx, &router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_with_validator_set_cache() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b.clone())
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(Height::new(1, 22).unwrap())
+        .build();
+
+    let mut ctx = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b)
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .latest_timestamp(ctx_b.host_block(&client_height).unwrap().timestamp())
+                .build(),
+        )
+        .with_validator_set_cache(4);
+
+    let mut router = MockRouter::new_with_transfer();
+
+    // Both updates come from the same validator set, which is cached upon
+    // the first one.
+    let mut trusted_height = client_height;
+    for update_height in [Height::new(1, 21).unwrap(), Height::new(1, 22).unwrap()] {
+        let mut block = ctx_b.host_block(&update_height).unwrap().clone();
+        block.set_trusted_height(trusted_height);
+
+        let msg = MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: block.into(),
+            signer: dummy_account_id(),
+        };
+        let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+        let res = validate(&ctx, &router, msg_envelope.clone());
+        assert!(res.is_ok(), "result: {res:?}");
+        let res = execute(&mut ctx, &mut router, msg_envelope);
+        assert!(res.is_ok(), "result: {res:?}");
+
+        assert_eq!(ctx.validator_set_cache.as_ref().unwrap().len(), 1);
+        trusted_height = update_height;
+    }
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    assert_eq!(client_state.latest_height(), Height::new(1, 22).unwrap());
+}
+
 #[rstest]
 fn test_update_synthetic_tendermint_client_validator_change_ok() {
     let client_id = tm_client_type().build_client_id(0);
@@ -595,6 +868,56 @@ fn test_update_synthetic_tendermint_client_non_adjacent_ok() {
     assert_eq!(client_state.latest_height(), latest_header_height);
 }
 
+#[rstest]
+#[case::adjacent(20, true)]
+#[case::non_adjacent(19, false)]
+fn test_update_synthetic_tendermint_client_sequential(
+    #[case] trusted_revision_height: u64,
+    #[case] want_pass: bool,
+) {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let trusted_height = Height::new(1, trusted_revision_height).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .consensus_state_heights(vec![trusted_height, client_height])
+                .verification_mode(VerificationMode::Sequential)
+                .build(),
+        );
+
+    let router = MockRouter::new_with_transfer();
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b)
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(update_height)
+        .build();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().clone();
+    block.set_trusted_height(trusted_height);
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ClientMsg::from(msg)));
+
+    assert_eq!(res.is_ok(), want_pass, "result: {res:?}");
+}
+
 #[rstest]
 fn test_update_synthetic_tendermint_client_duplicate_ok() {
     let client_id = tm_client_type().build_client_id(0);
@@ -696,7 +1019,7 @@ fn test_update_synthetic_tendermint_client_duplicate_ok() {
             ClientState::from(client_state).into()
         };
 
-        let mut ibc_store = ctx_a.ibc_store.lock();
+        let ibc_store = &mut ctx_a.ibc_store;
         let client_record = ibc_store.clients.get_mut(&client_id).unwrap();
 
         client_record
@@ -815,7 +1138,129 @@ fn test_update_client_events(fixture: Fixture) {
     assert_eq!(update_client_event.header(), &header.to_vec());
 }
 
-fn ensure_misbehaviour(ctx: &MockContext, client_id: &ClientId, client_type: &ClientType) {
+#[rstest]
+fn test_update_client_duplicate_header_no_op(fixture: Fixture) {
+    use ibc::core::client::context::ExtClientValidationContext;
+
+    let Fixture {
+        mut ctx,
+        mut router,
+    } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let timestamp = Timestamp::now();
+
+    let height = Height::new(0, 46).unwrap();
+    let header: Any = MockHeader::new(height).with_timestamp(timestamp).into();
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: header.clone(),
+        signer: dummy_account_id(),
+    }));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope.clone());
+    assert!(res.is_ok());
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    let consensus_heights =
+        ExtClientValidationContext::consensus_state_heights(&ctx, &client_id).unwrap();
+
+    // Resubmitting the same header is a successful no-op.
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    assert_eq!(ctx.client_state(&client_id).unwrap(), client_state);
+    assert_eq!(
+        ExtClientValidationContext::consensus_state_heights(&ctx, &client_id).unwrap(),
+        consensus_heights
+    );
+
+    let ibc_events = ctx.get_events();
+    assert_eq!(ibc_events.len(), 4);
+    assert!(matches!(
+        ibc_events[2],
+        IbcEvent::Message(MessageEvent::Client)
+    ));
+    let IbcEvent::UpdateClientNoOp(no_op_event) = &ibc_events[3] else {
+        panic!("unexpected event variant");
+    };
+
+    assert_eq!(no_op_event.client_id(), &client_id);
+    assert_eq!(no_op_event.client_type(), &mock_client_type());
+    assert_eq!(no_op_event.header(), &header.to_vec());
+}
+
+#[rstest]
+fn test_consensus_state_height_lookups() {
+    use ibc::core::client::context::ExtClientValidationContext;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let heights: Vec<Height> = [5, 10, 15]
+        .into_iter()
+        .map(|h| Height::new(0, h).unwrap())
+        .collect();
+
+    let ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_id(client_id.clone())
+            .latest_height(heights[2])
+            .consensus_state_heights(heights.clone())
+            .build(),
+    );
+
+    let consensus_state_at = |height: Height| {
+        ctx.consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ))
+        .unwrap()
+    };
+
+    assert_eq!(
+        ExtClientValidationContext::consensus_state_heights(&ctx, &client_id).unwrap(),
+        heights
+    );
+
+    // Lookups skip the consensus state stored at the given height itself.
+    assert_eq!(
+        ctx.next_consensus_state(&client_id, &heights[1]).unwrap(),
+        Some(consensus_state_at(heights[2]))
+    );
+    assert_eq!(
+        ctx.prev_consensus_state(&client_id, &heights[1]).unwrap(),
+        Some(consensus_state_at(heights[0]))
+    );
+    assert_eq!(
+        ctx.next_consensus_state(&client_id, &Height::new(0, 11).unwrap())
+            .unwrap(),
+        Some(consensus_state_at(heights[2]))
+    );
+    assert_eq!(
+        ctx.prev_consensus_state(&client_id, &Height::new(0, 11).unwrap())
+            .unwrap(),
+        Some(consensus_state_at(heights[1]))
+    );
+    assert_eq!(
+        ctx.next_consensus_state(&client_id, &heights[2]).unwrap(),
+        None
+    );
+    assert_eq!(
+        ctx.prev_consensus_state(&client_id, &heights[0]).unwrap(),
+        None
+    );
+
+    let unknown_client_id = ClientId::new("07-tendermint", 1).expect("no error");
+    assert!(ExtClientValidationContext::consensus_state_heights(&ctx, &unknown_client_id).is_err());
+}
+
+fn ensure_misbehaviour(
+    ctx: &MockContext,
+    client_id: &ClientId,
+    client_type: &ClientType,
+) -> ClientMisbehaviour {
     let client_state = ctx.client_state(client_id).unwrap();
 
     let status = client_state.status(ctx, client_id).unwrap();
@@ -833,6 +1278,8 @@ fn ensure_misbehaviour(ctx: &MockContext, client_id: &ClientId, client_type: &Cl
     };
     assert_eq!(misbehaviour_client_event.client_id(), client_id);
     assert_eq!(misbehaviour_client_event.client_type(), client_type);
+
+    misbehaviour_client_event.clone()
 }
 
 /// Tests misbehaviour handling for the mock client.
@@ -855,6 +1302,35 @@ fn test_misbehaviour_client_ok(fixture: Fixture) {
     let res = execute(&mut ctx, &mut router, msg_envelope);
     assert!(res.is_ok());
 
+    let event = ensure_misbehaviour(&ctx, &client_id, &mock_client_type());
+    assert!(event.evidence().is_none());
+}
+
+/// Tests that freezing a client on misbehaviour is permissionless, even when
+/// only an operator may update it.
+#[rstest]
+fn test_misbehaviour_client_unauthorized_signer(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+    } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    ctx.client_update_authorities
+        .insert(client_id.clone(), Signer::from("operator".to_string()));
+
+    let msg_envelope = msg_update_client(&client_id);
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(
+        res.is_ok(),
+        "misbehaviour is not subject to authorization: {res:?}"
+    );
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
     ensure_misbehaviour(&ctx, &client_id, &mock_client_type());
 }
 
@@ -926,27 +1402,121 @@ fn test_misbehaviour_synthetic_tendermint_equivocation() {
         .latest_height(misbehaviour_height)
         .build();
 
-    // Get chain-B's header at `misbehaviour_height`
-    let header1: TmHeader = {
-        let mut block = ctx_b.host_block(&misbehaviour_height).unwrap().clone();
-        block.set_trusted_height(client_height);
-        block.try_into_tm_block().unwrap().into()
+    // Get chain-B's block at `misbehaviour_height`
+    let block1 = ctx_b.host_block(&misbehaviour_height).unwrap().clone();
+
+    // Generate an equivocal block for chain-B at `misbehaviour_height`
+    let block2 = HostBlock::generate_block(
+        chain_id_b,
+        HostType::SyntheticTendermint,
+        misbehaviour_height.revision_height(),
+        Timestamp::now(),
+        &ctx_b.consensus_params,
+    );
+
+    let misbehaviour =
+        tm_misbehaviour_from_host_blocks(client_id.clone(), client_height, block1, block2).unwrap();
+    let conflicting_hashes: Vec<Vec<u8>> = [misbehaviour.header1(), misbehaviour.header2()]
+        .iter()
+        .map(|header| {
+            header
+                .signed_header
+                .commit
+                .block_id
+                .hash
+                .as_bytes()
+                .to_vec()
+        })
+        .collect();
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: misbehaviour.into(),
+        signer: dummy_account_id(),
     };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
 
-    // Generate an equivocal header for chain-B at `misbehaviour_height`
-    let header2 = {
-        let mut tm_block = HostBlock::generate_tm_block(
-            chain_id_b,
-            misbehaviour_height.revision_height(),
-            Timestamp::now(),
+    let res = validate(&ctx_a, &router_a, msg_envelope.clone());
+    assert!(res.is_ok());
+    let res = execute(&mut ctx_a, &mut router_a, msg_envelope);
+    assert!(res.is_ok());
+    let evidence = ensure_misbehaviour(&ctx_a, &client_id, &tm_client_type())
+        .evidence()
+        .cloned()
+        .expect("the Tendermint client provides misbehaviour evidence");
+    assert_eq!(evidence.kind, MisbehaviourKind::DoubleSign);
+    assert_eq!(
+        evidence.conflicting_heights,
+        vec![misbehaviour_height, misbehaviour_height]
+    );
+    assert_eq!(evidence.conflicting_hashes, conflicting_hashes);
+}
+
+/// Tests misbehaviour handling for the synthetic Tendermint client, with the
+/// misbehaviour built out of Tendermint light client attack evidence.
+#[rstest]
+fn test_misbehaviour_synthetic_tendermint_light_client_attack_evidence() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let misbehaviour_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx_a = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .build(),
         );
-        tm_block.trusted_height = client_height;
-        tm_block.into()
-    };
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b.clone())
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(misbehaviour_height)
+        .build();
+
+    // The honest chain-B header at `misbehaviour_height`
+    let honest_block = ctx_b
+        .host_block(&misbehaviour_height)
+        .unwrap()
+        .clone()
+        .try_into_tm_block()
+        .unwrap();
+    let trusted_next_validators = honest_block.trusted_next_validators.clone();
+    let honest_header: TmHeader = SyntheticTmBlock {
+        trusted_height: client_height,
+        ..honest_block
+    }
+    .into();
+
+    // The conflicting block reported by a full node of chain-B
+    let conflicting_block = HostBlock::generate_tm_block(
+        chain_id_b,
+        misbehaviour_height.revision_height(),
+        Timestamp::now(),
+    );
+    let evidence = tm_light_client_attack_evidence(&conflicting_block, client_height);
+
+    let misbehaviour = TmMisbehaviour::from_light_client_attack_evidence(
+        client_id.clone(),
+        evidence,
+        trusted_next_validators,
+        honest_header,
+    )
+    .unwrap();
+    assert_eq!(misbehaviour.header1().trusted_height, client_height);
+    assert_eq!(misbehaviour.header2().trusted_height, client_height);
 
     let msg = MsgUpdateClient {
         client_id: client_id.clone(),
-        client_message: TmMisbehaviour::new(client_id.clone(), header1, header2).into(),
+        client_message: misbehaviour.into(),
         signer: dummy_account_id(),
     };
     let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
@@ -1022,6 +1592,64 @@ fn test_misbehaviour_synthetic_tendermint_bft_time() {
     ensure_misbehaviour(&ctx_a, &client_id, &tm_client_type());
 }
 
+/// Tests misbehaviour handling for the synthetic Tendermint client, with the
+/// header at the greater height not being the later one.
+#[rstest]
+fn test_misbehaviour_synthetic_tendermint_time_violation() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx_a = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .build(),
+        );
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let now = Timestamp::now();
+    let header_at = |height: Height, timestamp: Timestamp| -> TmHeader {
+        let mut tm_block =
+            HostBlock::generate_tm_block(chain_id_b.clone(), height.revision_height(), timestamp);
+        tm_block.trusted_height = client_height;
+        tm_block.into()
+    };
+    let height_1 = client_height.add(2);
+    let height_2 = client_height.add(1);
+    let header1 = header_at(height_1, now);
+    let header2 = header_at(
+        height_2,
+        Timestamp::from_nanoseconds(now.nanoseconds() + 1_000_000_000).unwrap(),
+    );
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: TmMisbehaviour::new(client_id.clone(), header1, header2).into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx_a, &router_a, msg_envelope.clone());
+    assert!(res.is_ok(), "{res:?}");
+    let res = execute(&mut ctx_a, &mut router_a, msg_envelope);
+    assert!(res.is_ok(), "{res:?}");
+
+    let evidence = ensure_misbehaviour(&ctx_a, &client_id, &tm_client_type())
+        .evidence()
+        .cloned()
+        .expect("the Tendermint client provides misbehaviour evidence");
+    assert_eq!(evidence.kind, MisbehaviourKind::TimeViolation);
+    assert_eq!(evidence.conflicting_heights, vec![height_1, height_2]);
+}
+
 #[rstest]
 fn test_expired_client() {
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
@@ -1051,16 +1679,47 @@ fn test_expired_client() {
                 .build(),
         );
 
-    while ctx.host_timestamp().expect("no error") < (timestamp + trusting_period).expect("no error")
-    {
-        ctx.advance_host_chain_height();
-    }
+    ctx.advance_until_timestamp((timestamp + trusting_period).expect("no error"));
 
     let client_state = ctx.client_state(&client_id).unwrap();
 
     assert!(client_state.status(&ctx, &client_id).unwrap().is_expired());
 }
 
+#[rstest]
+fn test_client_config_tendermint_params() {
+    let client_id = tm_client_type().build_client_id(0);
+
+    let mut raw_specs: Vec<_> = ProofSpecs::cosmos().into();
+    raw_specs.truncate(1);
+    let proof_specs = ProofSpecs::new(raw_specs).unwrap();
+
+    let ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_chain_id(ChainId::new("mockgaiaB-1").unwrap())
+            .client_id(client_id.clone())
+            .latest_height(Height::new(1, 20).unwrap())
+            .client_type(tm_client_type())
+            .trust_level(TrustThreshold::TWO_THIRDS)
+            .trusting_period(Duration::from_secs(32))
+            .unbonding_period(Duration::from_secs(64))
+            .max_clock_drift(Duration::from_secs(8))
+            .proof_specs(proof_specs.clone())
+            .build(),
+    );
+
+    let AnyClientState::Tendermint(client_state) = ctx.client_state(&client_id).unwrap() else {
+        panic!("unexpected client state type");
+    };
+    let client_state = client_state.inner();
+
+    assert_eq!(client_state.trust_level, TrustThreshold::TWO_THIRDS);
+    assert_eq!(client_state.trusting_period, Duration::from_secs(32));
+    assert_eq!(client_state.unbonding_period, Duration::from_secs(64));
+    assert_eq!(client_state.max_clock_drift, Duration::from_secs(8));
+    assert_eq!(client_state.proof_specs, proof_specs);
+}
+
 #[rstest]
 fn test_client_update_max_clock_drift() {
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
@@ -1099,11 +1758,9 @@ fn test_client_update_max_clock_drift() {
         .max_history_size(u64::MAX)
         .build();
 
-    while ctx_b.host_timestamp().expect("no error")
-        < (ctx_a.host_timestamp().expect("no error") + max_clock_drift).expect("no error")
-    {
-        ctx_b.advance_host_chain_height();
-    }
+    ctx_b.advance_until_timestamp(
+        (ctx_a.host_timestamp().expect("no error") + max_clock_drift).expect("no error"),
+    );
 
     // include current block
     ctx_b.advance_host_chain_height();
@@ -1,18 +1,32 @@
-use ibc::clients::tendermint::types::client_type;
+use ibc::clients::tendermint::client_state::ClientState as TmClientState;
+use ibc::clients::tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc::clients::tendermint::types::{client_type, ConsensusState as TmConsensusStateType};
+use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
 use ibc::core::client::context::ClientValidationContext;
 use ibc::core::client::types::error::{ClientError, UpgradeClientError};
-use ibc::core::client::types::msgs::{ClientMsg, MsgUpgradeClient};
+use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient, MsgUpgradeClient};
 use ibc::core::client::types::Height;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::core::host::types::identifiers::ChainId;
+use ibc::core::host::types::path::{
+    ClientConsensusStatePath, UpgradeClientPath, UPGRADED_IBC_STATE,
+};
+use ibc::core::host::HostValidationContext;
+use ibc::primitives::proto::Any;
+use ibc::primitives::ToVec;
 use ibc_testkit::fixtures::clients::tendermint::{
     dummy_tendermint_header, dummy_tm_client_state_from_header,
+    ClientStateConfig as TmClientStateConfig,
 };
 use ibc_testkit::fixtures::core::client::dummy_msg_upgrade_client;
+use ibc_testkit::fixtures::core::commitment::dummy_cosmos_store_proofs;
+use ibc_testkit::fixtures::core::context::MockContextConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::fixtures::{Expect, Fixture};
+use ibc_testkit::hosts::block::HostType;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
@@ -169,3 +183,142 @@ fn upgrade_client_fail_unknown_upgraded_client_state() {
     });
     upgrade_client_validate(&fxt, Expect::Failure(Some(expected_err)));
 }
+
+/// Chain B halts at `(0, 20)` and restarts at `(1, 1)` with an incremented
+/// revision number. The Tendermint client of chain B on chain A is upgraded
+/// against proofs of the upgraded states committed by chain B before halting,
+/// and keeps tracking chain B on its new revision thereafter.
+#[test]
+fn upgrade_tendermint_client_to_new_revision() {
+    let client_id = client_type().build_client_id(0);
+    let chain_id_b = ChainId::new("mockgaiaB-0").unwrap();
+    let halt_height = Height::new(0, 20).unwrap();
+    let upgrade_path = vec!["upgrade".to_string(), UPGRADED_IBC_STATE.to_string()];
+
+    let mut ctx = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b)
+                .client_id(client_id.clone())
+                .client_type(client_type())
+                .latest_height(halt_height)
+                .upgrade_path(upgrade_path.clone())
+                .build(),
+        );
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let upgraded_chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+    let upgrade_height = Height::new(1, 1).unwrap();
+    let ctx_b = MockContextConfig::builder()
+        .host_id(upgraded_chain_id_b.clone())
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(Height::new(1, 2).unwrap())
+        .build();
+
+    let upgraded_client_state: TmClientState = TmClientStateConfig::builder()
+        .chain_id(upgraded_chain_id_b.clone())
+        .latest_height(upgrade_height)
+        .upgrade_path(upgrade_path)
+        .build()
+        .try_into()
+        .unwrap();
+    let upgraded_client_state = Any::from(upgraded_client_state);
+
+    let upgrade_block = ctx_b
+        .host_block(&upgrade_height)
+        .unwrap()
+        .clone()
+        .try_into_tm_block()
+        .unwrap();
+    let upgraded_consensus_state =
+        Any::from(TmConsensusState::from(upgrade_block.header().clone()));
+
+    // Chain B commits the upgraded states in its upgrade store at the halt
+    // height, under the root of its last consensus state known to chain A.
+    let (root, [proof_upgrade_client, proof_upgrade_consensus_state]) = dummy_cosmos_store_proofs(
+        "upgrade",
+        [
+            (
+                UpgradeClientPath::UpgradedClientState(halt_height.revision_height())
+                    .to_string()
+                    .into_bytes(),
+                upgraded_client_state.to_vec(),
+            ),
+            (
+                UpgradeClientPath::UpgradedClientConsensusState(halt_height.revision_height())
+                    .to_string()
+                    .into_bytes(),
+                upgraded_consensus_state.to_vec(),
+            ),
+        ],
+    );
+    {
+        let ibc_store = &mut ctx.ibc_store;
+        let consensus_states = &mut ibc_store
+            .clients
+            .get_mut(&client_id)
+            .unwrap()
+            .consensus_states;
+        let AnyConsensusState::Tendermint(halt_consensus_state) = &consensus_states[&halt_height]
+        else {
+            panic!("unexpected consensus state variant");
+        };
+        let halt_consensus_state = halt_consensus_state.inner();
+        let halt_consensus_state = TmConsensusStateType::new(
+            root,
+            halt_consensus_state.timestamp,
+            halt_consensus_state.next_validators_hash,
+        );
+        consensus_states.insert(halt_height, halt_consensus_state.into());
+    }
+
+    let msg = MsgUpgradeClient {
+        client_id: client_id.clone(),
+        upgraded_client_state,
+        upgraded_consensus_state,
+        proof_upgrade_client,
+        proof_upgrade_consensus_state,
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "validation failed: {res:?}");
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "execution failed: {res:?}");
+
+    let AnyClientState::Tendermint(client_state) = ctx.client_state(&client_id).unwrap() else {
+        panic!("unexpected client state variant");
+    };
+    assert_eq!(client_state.inner().chain_id, upgraded_chain_id_b);
+    assert_eq!(client_state.latest_height(), upgrade_height);
+
+    // The client accepts headers of the new revision, trusting the upgraded
+    // consensus state.
+    let update_height = Height::new(1, 2).unwrap();
+    let mut block = ctx_b.host_block(&update_height).unwrap().clone();
+    block.set_trusted_height(upgrade_height);
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "validation failed: {res:?}");
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "execution failed: {res:?}");
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    assert_eq!(client_state.latest_height(), update_height);
+    assert!(client_state
+        .status(ctx.get_client_validation_context(), &client_id)
+        .unwrap()
+        .is_active());
+}
@@ -8,7 +8,7 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ClientId;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::{ConnectionEndValidationContext, ConnectionValidationContext};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::ZERO_DURATION;
 use ibc_testkit::fixtures::core::connection::dummy_conn_open_confirm;
@@ -38,7 +38,7 @@ fn conn_open_confirm_fixture(ctx: Ctx) -> Fixture<MsgConnectionOpenConfirm> {
         State::Init,
         client_id.clone(),
         counterparty,
-        ValidationContext::get_compatible_versions(&ctx_default),
+        ConnectionValidationContext::get_compatible_versions(&ctx_default),
         ZERO_DURATION,
     )
     .unwrap();
@@ -108,9 +108,11 @@ fn conn_open_confirm_execute(fxt: &mut Fixture<MsgConnectionOpenConfirm>, expect
             let IbcEvent::OpenConfirmConnection(conn_open_try_event) = event else {
                 unreachable!()
             };
-            let conn_end =
-                ValidationContext::connection_end(&fxt.ctx, conn_open_try_event.conn_id_on_b())
-                    .unwrap();
+            let conn_end = ConnectionEndValidationContext::connection_end(
+                &fxt.ctx,
+                conn_open_try_event.conn_id_on_b(),
+            )
+            .unwrap();
             assert_eq!(conn_end.state().clone(), State::Open);
         }
     }
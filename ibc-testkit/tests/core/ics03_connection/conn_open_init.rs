@@ -1,24 +1,43 @@
 use ibc::core::client::types::Height;
-use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenInit};
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::error::ConnectionError;
+use ibc::core::connection::types::msgs::{
+    ConnectionMsg, MsgConnectionOpenInit, CONN_OPEN_INIT_TYPE_URL,
+};
 use ibc::core::connection::types::version::Version;
-use ibc::core::connection::types::State;
+use ibc::core::connection::types::{Counterparty, State};
 use ibc::core::entrypoint::{execute, validate};
-use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{
+    CapReached, CapScope, IbcEvent, MessageEvent, SdkMessageEvent,
+};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::limits::HostLimits;
+use ibc::core::host::types::path::{
+    ClientConnectionPath, ConnectionPath, NextConnectionSequencePath,
+};
+use ibc::core::host::types::write_set::StoreWrite;
+use ibc::core::host::{ConnectionEndValidationContext, ConnectionValidationContext};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::Protobuf;
+use ibc_proto::ibc::core::connection::v1::ClientPaths as RawClientPaths;
 use ibc_testkit::fixtures::core::connection::{
     dummy_msg_conn_open_init, msg_conn_open_init_with_counterparty_conn_id,
-    msg_conn_open_with_version,
+    msg_conn_open_with_version, VersionConfig,
 };
+use ibc_testkit::fixtures::core::context::MockContextConfig;
 use ibc_testkit::fixtures::{Expect, Fixture};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use prost::Message;
 use test_log::test;
 
 enum Ctx {
     Default,
     WithClient,
+    WithClientCustomVersions,
+    WithClientAllowingEmptyPrefix,
 }
 
 enum Msg {
@@ -26,6 +45,7 @@ enum Msg {
     NoVersion,
     BadVersion,
     WithCounterpartyConnId,
+    EmptyCounterpartyPrefix,
 }
 
 fn conn_open_init_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<MsgConnectionOpenInit> {
@@ -37,22 +57,46 @@ fn conn_open_init_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<MsgConn
             msg_conn_open_with_version(msg_default, Some("random identifier 424242"))
         }
         Msg::WithCounterpartyConnId => msg_conn_open_init_with_counterparty_conn_id(msg_default, 2),
+        Msg::EmptyCounterpartyPrefix => MsgConnectionOpenInit {
+            counterparty: Counterparty::new(
+                msg_default.counterparty.client_id().clone(),
+                None,
+                CommitmentPrefix::empty(),
+            ),
+            ..msg_default
+        },
     };
 
-    let ctx_default = MockContext::default();
+    let client_config = MockClientConfig::builder()
+        .client_id(msg.client_id_on_a.clone())
+        .latest_height(Height::new(0, 10).unwrap())
+        .build();
     let ctx = match ctx_variant {
-        Ctx::WithClient => ctx_default.with_client_config(
-            MockClientConfig::builder()
-                .client_id(msg.client_id_on_a.clone())
-                .latest_height(Height::new(0, 10).unwrap())
-                .build(),
-        ),
-        _ => ctx_default,
+        Ctx::Default => MockContext::default(),
+        Ctx::WithClient => MockContext::default().with_client_config(client_config),
+        Ctx::WithClientCustomVersions => MockContextConfig::builder()
+            .connection_versions(vec![custom_version()])
+            .build()
+            .with_client_config(client_config),
+        Ctx::WithClientAllowingEmptyPrefix => MockContextConfig::builder()
+            .allow_empty_counterparty_prefix(true)
+            .build()
+            .with_client_config(client_config),
     };
 
     Fixture { ctx, msg }
 }
 
+/// A version the host supports instead of the compatible ones.
+fn custom_version() -> Version {
+    VersionConfig::builder()
+        .identifier("2")
+        .features(vec!["ORDER_UNORDERED".to_string()])
+        .build()
+        .try_into()
+        .unwrap()
+}
+
 fn conn_open_init_validate(fxt: &Fixture<MsgConnectionOpenInit>, expect: Expect) {
     let router = MockRouter::new_with_transfer();
     let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(fxt.msg.clone()));
@@ -100,9 +144,11 @@ fn conn_open_init_execute(
             let IbcEvent::OpenInitConnection(conn_open_init_event) = event else {
                 unreachable!()
             };
-            let conn_end =
-                ValidationContext::connection_end(&fxt.ctx, conn_open_init_event.conn_id_on_a())
-                    .unwrap();
+            let conn_end = ConnectionEndValidationContext::connection_end(
+                &fxt.ctx,
+                conn_open_init_event.conn_id_on_a(),
+            )
+            .unwrap();
             assert_eq!(conn_end.state().clone(), State::Init);
             assert_eq!(conn_end.versions(), expected_version);
         }
@@ -127,7 +173,7 @@ fn conn_open_init_no_context() {
 fn conn_open_init_no_version() {
     let mut fxt = conn_open_init_fixture(Ctx::WithClient, Msg::NoVersion);
     conn_open_init_validate(&fxt, Expect::Success);
-    let expected_version = ValidationContext::get_compatible_versions(&fxt.ctx.clone());
+    let expected_version = ConnectionValidationContext::get_compatible_versions(&fxt.ctx.clone());
     conn_open_init_execute(&mut fxt, Expect::Success, expected_version);
 }
 #[test]
@@ -143,3 +189,183 @@ fn conn_open_init_with_counterparty_conn_id() {
     let expected_version = vec![fxt.msg.version.clone().unwrap()];
     conn_open_init_execute(&mut fxt, Expect::Success, expected_version);
 }
+
+#[test]
+fn conn_open_init_custom_versions() {
+    let mut fxt = conn_open_init_fixture(Ctx::WithClientCustomVersions, Msg::NoVersion);
+    conn_open_init_validate(&fxt, Expect::Success);
+    conn_open_init_execute(&mut fxt, Expect::Success, vec![custom_version()]);
+}
+
+#[test]
+fn conn_open_init_custom_versions_unsupported_version() {
+    let fxt = conn_open_init_fixture(Ctx::WithClientCustomVersions, Msg::Default);
+    conn_open_init_validate(&fxt, Expect::Failure(None));
+}
+
+#[test]
+fn conn_open_init_empty_counterparty_prefix() {
+    let fxt = conn_open_init_fixture(Ctx::WithClient, Msg::EmptyCounterpartyPrefix);
+    conn_open_init_validate(&fxt, Expect::Failure(None));
+}
+
+#[test]
+fn conn_open_init_empty_counterparty_prefix_allowed() {
+    let mut fxt = conn_open_init_fixture(
+        Ctx::WithClientAllowingEmptyPrefix,
+        Msg::EmptyCounterpartyPrefix,
+    );
+    conn_open_init_validate(&fxt, Expect::Success);
+    let expected_version = vec![fxt.msg.version.clone().unwrap()];
+    conn_open_init_execute(&mut fxt, Expect::Success, expected_version);
+
+    let conn_end =
+        ConnectionEndValidationContext::connection_end(&fxt.ctx, &ConnectionId::new(0)).unwrap();
+    assert!(conn_end.counterparty().prefix().is_empty());
+}
+
+#[test]
+fn conn_open_init_max_connections_per_client() {
+    let msg = dummy_msg_conn_open_init();
+    let client_id = msg.client_id_on_a.clone();
+    let mut ctx = MockContextConfig::builder()
+        .host_limits(HostLimits::unlimited().with_max_connections_per_client(1))
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(client_id.clone())
+                .latest_height(Height::new(0, 10).unwrap())
+                .build(),
+        );
+    let mut router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx, &mut router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let ibc_events = ctx.get_events();
+    assert_eq!(
+        ibc_events.last(),
+        Some(&IbcEvent::CapReached(CapReached::new(
+            CapScope::ConnectionsPerClient(client_id.clone()),
+            1
+        )))
+    );
+
+    let res = validate(&ctx, &router, msg_envelope);
+    assert!(matches!(
+        res,
+        Err(ContextError::ConnectionError(
+            ConnectionError::MaxConnectionsPerClientReached {
+                client_id: capped_client_id,
+                max_connections: 1,
+            }
+        )) if capped_client_id == client_id
+    ));
+}
+
+#[test]
+fn conn_open_init_connections_for_client() {
+    let msg = dummy_msg_conn_open_init();
+    let client_id = msg.client_id_on_a.clone();
+    let mut ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_id(client_id.clone())
+            .latest_height(Height::new(0, 10).unwrap())
+            .build(),
+    );
+    let mut router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(msg));
+
+    assert!(ctx.connections_for_client(&client_id).unwrap().is_empty());
+
+    for _ in 0..2 {
+        let res = execute(&mut ctx, &mut router, msg_envelope.clone());
+        assert!(res.is_ok(), "result: {res:?}");
+    }
+
+    assert_eq!(
+        ctx.connections_for_client(&client_id).unwrap(),
+        vec![ConnectionId::new(0), ConnectionId::new(1)]
+    );
+    assert_eq!(ctx.client_connection_count(&client_id).unwrap(), 2);
+}
+
+#[test]
+fn conn_open_init_sdk_message_event() {
+    let msg = dummy_msg_conn_open_init();
+    let signer = msg.signer.clone();
+    let mut ctx = MockContextConfig::builder()
+        .emit_sdk_message_events(true)
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(msg.client_id_on_a.clone())
+                .latest_height(Height::new(0, 10).unwrap())
+                .build(),
+        );
+    let mut router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let ibc_events = ctx.get_events();
+    assert_eq!(ibc_events.len(), 3);
+    assert_eq!(
+        ibc_events[0],
+        IbcEvent::SdkMessage(SdkMessageEvent::new(
+            CONN_OPEN_INIT_TYPE_URL.to_string(),
+            signer.to_string(),
+            "ibc".to_string(),
+        ))
+    );
+    assert!(matches!(
+        ibc_events[1],
+        IbcEvent::Message(MessageEvent::Connection)
+    ));
+}
+
+#[test]
+fn conn_open_init_write_set() {
+    let msg = dummy_msg_conn_open_init();
+    let client_id = msg.client_id_on_a.clone();
+    let mut ctx = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(client_id.clone())
+                .latest_height(Height::new(0, 10).unwrap())
+                .build(),
+        )
+        .with_write_set();
+    let mut router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let conn_id = ConnectionId::new(0);
+    let conn_end = ctx.connection_end(&conn_id).unwrap();
+    let client_paths = RawClientPaths {
+        paths: vec![conn_id.to_string()],
+    };
+    let expected_writes = vec![
+        StoreWrite {
+            path: NextConnectionSequencePath.into(),
+            value: Some(1u64.to_be_bytes().to_vec()),
+        },
+        StoreWrite {
+            path: ClientConnectionPath::new(client_id).into(),
+            value: Some(client_paths.encode_to_vec()),
+        },
+        StoreWrite {
+            path: ConnectionPath::new(&conn_id).into(),
+            value: Some(conn_end.encode_vec()),
+        },
+    ];
+
+    assert_eq!(ctx.write_set.unwrap().writes(), expected_writes.as_slice());
+}
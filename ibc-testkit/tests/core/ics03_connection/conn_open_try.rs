@@ -1,14 +1,19 @@
 use ibc::core::client::types::Height;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenTry};
+use ibc::core::connection::types::version::Version;
 use ibc::core::connection::types::State;
 use ibc::core::entrypoint::{execute, validate};
-use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent, VersionNegotiationFailed};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::types::identifiers::{ChainId, ConnectionId};
+use ibc::core::host::{ConnectionEndValidationContext, ConnectionValidationContext};
 use ibc::core::primitives::prelude::*;
-use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_try;
+use ibc_testkit::fixtures::core::connection::{dummy_msg_conn_open_try, VersionConfig};
 use ibc_testkit::fixtures::core::context::MockContextConfig;
 use ibc_testkit::fixtures::{Expect, Fixture};
+use ibc_testkit::relayer::error::RelayerError;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use test_log::test;
@@ -16,6 +21,13 @@ use test_log::test;
 enum Ctx {
     Default,
     WithClient,
+    /// The host only supports unordered channels.
+    WithClientUnorderedOnly,
+    /// The host supports none of the versions of the counterparty.
+    WithClientOtherVersion,
+    /// The identifier of the host ends with a number which is not a revision
+    /// number.
+    WithClientNonEpochHost,
 }
 
 enum Msg {
@@ -50,13 +62,24 @@ fn conn_open_try_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<MsgConne
         ),
     };
 
+    let connection_versions = match ctx_variant {
+        Ctx::WithClientUnorderedOnly => vec![unordered_only_version("1")],
+        Ctx::WithClientOtherVersion => vec![unordered_only_version("2")],
+        _ => Version::compatibles(),
+    };
+    let host_id = match ctx_variant {
+        Ctx::WithClientNonEpochHost => ChainId::new_without_revision("mockgaia-1").unwrap(),
+        _ => ChainId::new("mockgaia-0").unwrap(),
+    };
     let ctx_new = MockContextConfig::builder()
+        .host_id(host_id)
         .max_history_size(max_history_size)
         .latest_height(host_chain_height)
+        .connection_versions(connection_versions)
         .build();
     let ctx = match ctx_variant {
         Ctx::Default => MockContext::default(),
-        Ctx::WithClient => ctx_new.with_client_config(
+        _ => ctx_new.with_client_config(
             MockClientConfig::builder()
                 .client_id(msg.client_id_on_b.clone())
                 .latest_height(Height::new(0, client_cons_state_height).unwrap())
@@ -66,6 +89,15 @@ fn conn_open_try_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<MsgConne
     Fixture { ctx, msg }
 }
 
+fn unordered_only_version(identifier: &str) -> Version {
+    VersionConfig::builder()
+        .identifier(identifier)
+        .features(vec!["ORDER_UNORDERED".to_string()])
+        .build()
+        .try_into()
+        .unwrap()
+}
+
 fn conn_open_try_validate(fxt: &Fixture<MsgConnectionOpenTry>, expect: Expect) {
     let router = MockRouter::new_with_transfer();
     let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(fxt.msg.clone()));
@@ -109,9 +141,11 @@ fn conn_open_try_execute(fxt: &mut Fixture<MsgConnectionOpenTry>, expect: Expect
             let IbcEvent::OpenTryConnection(conn_open_try_event) = event else {
                 unreachable!()
             };
-            let conn_end =
-                ValidationContext::connection_end(&fxt.ctx, conn_open_try_event.conn_id_on_b())
-                    .unwrap();
+            let conn_end = ConnectionEndValidationContext::connection_end(
+                &fxt.ctx,
+                conn_open_try_event.conn_id_on_b(),
+            )
+            .unwrap();
             assert_eq!(conn_end.state().clone(), State::TryOpen);
         }
     }
@@ -124,6 +158,13 @@ fn conn_open_try_healthy() {
     conn_open_try_execute(&mut fxt, Expect::Success);
 }
 
+#[test]
+fn conn_open_try_non_epoch_host() {
+    let mut fxt = conn_open_try_fixture(Ctx::WithClientNonEpochHost, Msg::Default);
+    conn_open_try_validate(&fxt, Expect::Success);
+    conn_open_try_execute(&mut fxt, Expect::Success);
+}
+
 #[test]
 fn conn_open_try_height_advanced() {
     let fxt = conn_open_try_fixture(Ctx::WithClient, Msg::HeightAdvanced);
@@ -147,3 +188,54 @@ fn conn_open_try_no_client() {
     let fxt = conn_open_try_fixture(Ctx::Default, Msg::Default);
     conn_open_try_validate(&fxt, Expect::Failure(None));
 }
+
+#[test]
+fn conn_open_try_host_versions() {
+    let mut fxt = conn_open_try_fixture(Ctx::WithClientUnorderedOnly, Msg::Default);
+    conn_open_try_validate(&fxt, Expect::Success);
+    conn_open_try_execute(&mut fxt, Expect::Success);
+
+    // The negotiated version only features what the host supports.
+    let conn_end =
+        ConnectionEndValidationContext::connection_end(&fxt.ctx, &ConnectionId::zero()).unwrap();
+    assert_eq!(conn_end.versions(), [unordered_only_version("1")]);
+}
+
+#[test]
+fn conn_open_try_no_common_version() {
+    let mut fxt = conn_open_try_fixture(Ctx::WithClientOtherVersion, Msg::Default);
+    conn_open_try_validate(&fxt, Expect::Failure(None));
+
+    let mut router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(fxt.msg.clone()));
+    let res = fxt.ctx.deliver(&mut router, [msg_envelope]);
+
+    // The error names the versions of both chains.
+    assert!(
+        matches!(
+            &res,
+            Err(RelayerError::TransactionFailed(ContextError::ConnectionError(
+                ConnectionError::NoCommonVersion { supported, proposed }
+            ))) if supported == &[unordered_only_version("2")] && proposed == &fxt.msg.versions_on_a
+        ),
+        "result: {res:?}"
+    );
+
+    let proposed = fxt
+        .msg
+        .versions_on_a
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    let supported = vec![unordered_only_version("2").to_string()];
+    let Err(RelayerError::TransactionFailed(e)) = res else {
+        unreachable!()
+    };
+    assert_eq!(
+        VersionNegotiationFailed::from_error(&e),
+        Some(VersionNegotiationFailed::new(proposed, supported))
+    );
+
+    // The failed transaction leaves no events behind.
+    assert!(fxt.ctx.get_events().is_empty());
+}
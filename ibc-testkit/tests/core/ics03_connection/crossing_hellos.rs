@@ -0,0 +1,171 @@
+use core::time::Duration;
+
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::msgs::{
+    ConnectionMsg, MsgConnectionOpenAck, MsgConnectionOpenInit, MsgConnectionOpenTry,
+};
+use ibc::core::connection::types::State;
+use ibc::core::entrypoint::dispatch;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::{ConnectionEndValidationContext, ConnectionValidationContext};
+use ibc::core::primitives::prelude::*;
+use ibc_testkit::fixtures::core::connection::{
+    dummy_msg_conn_open_ack, dummy_msg_conn_open_init, dummy_msg_conn_open_try,
+};
+use ibc_testkit::fixtures::core::context::MockContextConfig;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+const CLIENT_CONS_STATE_HEIGHT: u64 = 10;
+const HOST_HEIGHT: u64 = 35;
+
+/// Returns a chain hosting a client of its counterparty, which it identifies
+/// the same way as the counterparty identifies the client of the chain.
+fn chain() -> MockContext {
+    MockContextConfig::builder()
+        .max_history_size(5)
+        .latest_height(Height::new(0, HOST_HEIGHT).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(dummy_msg_conn_open_init().client_id_on_a)
+                .latest_height(Height::new(0, CLIENT_CONS_STATE_HEIGHT).unwrap())
+                .build(),
+        )
+}
+
+fn deliver(ctx: &mut MockContext, msg: impl Into<ConnectionMsg>) -> Result<(), ContextError> {
+    let mut router = MockRouter::new_with_transfer();
+    dispatch(ctx, &mut router, MsgEnvelope::from(msg.into())).map(|_| ())
+}
+
+fn msg_conn_open_try(previous_conn_id: &ConnectionId) -> MsgConnectionOpenTry {
+    let msg = dummy_msg_conn_open_try(CLIENT_CONS_STATE_HEIGHT, HOST_HEIGHT);
+
+    #[allow(deprecated)]
+    MsgConnectionOpenTry {
+        previous_connection_id: previous_conn_id.to_string(),
+        ..msg
+    }
+}
+
+fn msg_conn_open_ack(
+    conn_id_on_a: &ConnectionId,
+    conn_id_on_b: &ConnectionId,
+) -> MsgConnectionOpenAck {
+    MsgConnectionOpenAck {
+        conn_id_on_a: conn_id_on_a.clone(),
+        conn_id_on_b: conn_id_on_b.clone(),
+        ..dummy_msg_conn_open_ack(CLIENT_CONS_STATE_HEIGHT, HOST_HEIGHT)
+    }
+}
+
+/// Drives a connection handshake in which both chains send their
+/// `ConnOpenInit` before receiving the one of their counterparty, and
+/// returns the identifiers of both connection ends.
+///
+/// The relayer then delivers on each chain a `ConnOpenTry` reusing the
+/// connection initialised by the chain, followed by a `ConnOpenAck`.
+fn crossing_hellos(
+    ctx_a: &mut MockContext,
+    ctx_b: &mut MockContext,
+) -> Result<(ConnectionId, ConnectionId), ContextError> {
+    let conn_id_on_a = ConnectionId::new(ctx_a.connection_counter()?);
+    let conn_id_on_b = ConnectionId::new(ctx_b.connection_counter()?);
+
+    // Both chains initialise their end of the connection.
+    deliver(ctx_a, dummy_msg_conn_open_init())?;
+    deliver(ctx_b, dummy_msg_conn_open_init())?;
+
+    // The init of each chain is relayed to the other one.
+    deliver(ctx_b, msg_conn_open_try(&conn_id_on_b))?;
+    deliver(ctx_a, msg_conn_open_try(&conn_id_on_a))?;
+
+    // The try of each chain is relayed to the other one.
+    deliver(ctx_a, msg_conn_open_ack(&conn_id_on_a, &conn_id_on_b))?;
+    deliver(ctx_b, msg_conn_open_ack(&conn_id_on_b, &conn_id_on_a))?;
+
+    Ok((conn_id_on_a, conn_id_on_b))
+}
+
+#[test]
+fn test_crossing_hellos_ok() {
+    let mut ctx_a = chain();
+    let mut ctx_b = chain();
+
+    let (conn_id_on_a, conn_id_on_b) = crossing_hellos(&mut ctx_a, &mut ctx_b).unwrap();
+
+    for (ctx, conn_id, counterparty_conn_id) in [
+        (&ctx_a, &conn_id_on_a, &conn_id_on_b),
+        (&ctx_b, &conn_id_on_b, &conn_id_on_a),
+    ] {
+        let conn_end = ctx.connection_end(conn_id).unwrap();
+        assert_eq!(conn_end.state(), &State::Open);
+        assert_eq!(
+            conn_end.counterparty().connection_id(),
+            Some(counterparty_conn_id)
+        );
+
+        // The connection initialised by the chain is the one which opened.
+        assert_eq!(ctx.connection_counter().unwrap(), 1);
+    }
+}
+
+#[test]
+fn test_crossing_hellos_previous_conn_mismatch() {
+    let mut ctx_b = chain();
+
+    let msg_init = MsgConnectionOpenInit {
+        delay_period: Duration::from_secs(1),
+        ..dummy_msg_conn_open_init()
+    };
+    deliver(&mut ctx_b, msg_init).unwrap();
+
+    let conn_id_on_b = ConnectionId::zero();
+    assert!(deliver(&mut ctx_b, msg_conn_open_try(&conn_id_on_b)).is_err());
+    assert_eq!(
+        ctx_b.connection_end(&conn_id_on_b).unwrap().state(),
+        &State::Init
+    );
+}
+
+#[test]
+fn test_crossing_hellos_previous_conn_not_found() {
+    let mut ctx_b = chain();
+
+    assert!(deliver(&mut ctx_b, msg_conn_open_try(&ConnectionId::zero())).is_err());
+    assert_eq!(ctx_b.connection_counter().unwrap(), 0);
+}
+
+#[test]
+fn test_crossing_hellos_previous_conn_reused_once() {
+    let mut ctx_b = chain();
+
+    deliver(&mut ctx_b, dummy_msg_conn_open_init()).unwrap();
+
+    let conn_id_on_b = ConnectionId::zero();
+    deliver(&mut ctx_b, msg_conn_open_try(&conn_id_on_b)).unwrap();
+
+    // The connection is no longer in its `Init` state.
+    assert!(deliver(&mut ctx_b, msg_conn_open_try(&conn_id_on_b)).is_err());
+}
+
+#[test]
+fn test_crossing_hellos_ack_other_counterparty_conn() {
+    let mut ctx_a = chain();
+
+    deliver(&mut ctx_a, dummy_msg_conn_open_init()).unwrap();
+
+    let conn_id_on_a = ConnectionId::zero();
+    deliver(&mut ctx_a, msg_conn_open_try(&conn_id_on_a)).unwrap();
+
+    // The try relayed to the chain was for `connection-0` on the counterparty.
+    let res = deliver(
+        &mut ctx_a,
+        msg_conn_open_ack(&conn_id_on_a, &ConnectionId::new(1)),
+    );
+    assert!(res.is_err());
+}
@@ -1,21 +1,22 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
+use ibc::core::channel::types::error::PacketError;
 use ibc::core::channel::types::msgs::{MsgAcknowledgement, PacketMsg};
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
-use ibc::core::connection::types::version::Version as ConnectionVersion;
-use ibc::core::connection::types::{
-    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
-};
+use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::ExecutionContext;
+use ibc::core::host::types::limits::HostLimits;
+use ibc::core::host::HostExecutionContext;
 use ibc::core::primitives::*;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_acknowledgement;
+use ibc_testkit::fixtures::core::connection::{ConnectionEndConfig, CounterpartyConfig};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use rstest::*;
@@ -70,18 +71,17 @@ fn fixture() -> Fixture {
     let mut chan_end_on_a_ordered = chan_end_on_a_unordered.clone();
     chan_end_on_a_ordered.ordering = Order::Ordered;
 
-    let conn_end_on_a = ConnectionEnd::new(
-        ConnectionState::Open,
-        default_client_id.clone(),
-        ConnectionCounterparty::new(
-            default_client_id.clone(),
-            Some(ConnectionId::zero()),
-            CommitmentPrefix::empty(),
-        ),
-        ConnectionVersion::compatibles(),
-        ZERO_DURATION,
-    )
-    .unwrap();
+    let conn_end_on_a: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(default_client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .client_id(default_client_id.clone())
+                .prefix(CommitmentPrefix::empty())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
 
     Fixture {
         ctx,
@@ -273,3 +273,37 @@ fn ack_ordered_chan_execute(fixture: Fixture) {
     ));
     assert!(matches!(ibc_events[1], IbcEvent::AcknowledgePacket(_)));
 }
+
+#[rstest]
+fn ack_fail_acknowledgement_too_large(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        conn_end_on_a,
+        chan_end_on_a_unordered,
+        ..
+    } = fixture;
+
+    let max_size = msg.acknowledgement.as_bytes().len() as u64 - 1;
+    let mut ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end_on_a_unordered,
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a);
+    ctx.host_limits = HostLimits::unlimited().with_max_ack_size(max_size);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(PacketError::AcknowledgementTooLarge { max_size: m, .. })) if m == max_size
+        ),
+        "Validation fails because the acknowledgement exceeds the host limit. res: {res:?}"
+    );
+}
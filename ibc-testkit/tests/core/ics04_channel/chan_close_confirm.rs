@@ -1,18 +1,16 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelCloseConfirm};
+use ibc::core::channel::types::packet::Receipt;
 use ibc::core::channel::types::Version;
-use ibc::core::connection::types::version::Version as ConnectionVersion;
-use ibc::core::connection::types::{
-    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
-};
+use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ConnectionId;
-use ibc::core::host::ValidationContext;
-use ibc::core::primitives::*;
+use ibc::core::host::types::path::{AckPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath};
+use ibc::core::host::{HostValidationContext, PacketExecutionContext, PacketValidationContext};
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_close_confirm;
-use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::connection::ConnectionEndConfig;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
@@ -24,14 +22,11 @@ fn test_chan_close_confirm_validate() {
     let default_context = MockContext::default();
     let client_consensus_state_height = default_context.host_height().unwrap();
 
-    let conn_end = ConnectionEnd::new(
-        ConnectionState::Open,
-        client_id.clone(),
-        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
-        ConnectionVersion::compatibles(),
-        ZERO_DURATION,
-    )
-    .unwrap();
+    let conn_end: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .build()
+        .try_into()
+        .unwrap();
 
     let msg_chan_close_confirm = MsgChannelCloseConfirm::try_from(
         dummy_raw_msg_chan_close_confirm(client_consensus_state_height.revision_height()),
@@ -83,14 +78,11 @@ fn test_chan_close_confirm_execute() {
     let default_context = MockContext::default();
     let client_consensus_state_height = default_context.host_height().unwrap();
 
-    let conn_end = ConnectionEnd::new(
-        ConnectionState::Open,
-        client_id.clone(),
-        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
-        ConnectionVersion::compatibles(),
-        ZERO_DURATION,
-    )
-    .unwrap();
+    let conn_end: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .build()
+        .try_into()
+        .unwrap();
 
     let msg_chan_close_confirm = MsgChannelCloseConfirm::try_from(
         dummy_raw_msg_chan_close_confirm(client_consensus_state_height.revision_height()),
@@ -142,3 +134,83 @@ fn test_chan_close_confirm_execute() {
 
     assert!(matches!(ibc_events[1], IbcEvent::CloseConfirmChannel(_)));
 }
+
+#[test]
+fn test_chan_close_confirm_deletes_stale_state() {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+    let default_context = MockContext::default();
+    let client_consensus_state_height = default_context.host_height().unwrap();
+
+    let conn_end: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .build()
+        .try_into()
+        .unwrap();
+
+    let msg_chan_close_confirm = MsgChannelCloseConfirm::try_from(
+        dummy_raw_msg_chan_close_confirm(client_consensus_state_height.revision_height()),
+    )
+    .unwrap();
+    let port_id = msg_chan_close_confirm.port_id_on_b.clone();
+    let chan_id = msg_chan_close_confirm.chan_id_on_b.clone();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_confirm));
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(port_id.clone(), Some(chan_id.clone())),
+        vec![conn_id.clone()],
+        Version::empty(),
+    )
+    .unwrap();
+
+    let mut context = default_context
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(client_id.clone())
+                .latest_height(client_consensus_state_height)
+                .build(),
+        )
+        .with_connection(conn_id, conn_end)
+        .with_channel(port_id.clone(), chan_id.clone(), chan_end)
+        .with_send_sequence(port_id.clone(), chan_id.clone(), 3.into())
+        .with_recv_sequence(port_id.clone(), chan_id.clone(), 5.into())
+        .with_ack_sequence(port_id.clone(), chan_id.clone(), 3.into());
+
+    let ack_path = AckPath::new(&port_id, &chan_id, 4.into());
+    let receipt_path = ReceiptPath::new(&port_id, &chan_id, 4.into());
+    context
+        .store_packet_acknowledgement(&ack_path, vec![1u8].into())
+        .unwrap();
+    context
+        .store_packet_receipt(&receipt_path, Receipt::Ok)
+        .unwrap();
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution success: happy path");
+
+    // The counterparty channel is closed, so no packet can be sent,
+    // acknowledged, nor have its acknowledgement relayed anymore.
+    assert!(context
+        .get_next_sequence_send(&SeqSendPath::new(&port_id, &chan_id))
+        .is_err());
+    assert!(context
+        .get_next_sequence_ack(&SeqAckPath::new(&port_id, &chan_id))
+        .is_err());
+    assert!(context.get_packet_acknowledgement(&ack_path).is_err());
+
+    // The counterparty still proves against them that its packets were not
+    // received, to time them out on close.
+    assert_eq!(
+        context
+            .get_next_sequence_recv(&SeqRecvPath::new(&port_id, &chan_id))
+            .unwrap(),
+        5.into()
+    );
+    assert!(context.get_packet_receipt(&receipt_path).is_ok());
+}
@@ -1,35 +1,151 @@
+use ibc::apps::transfer::types::MODULE_ID_STR;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelCloseInit};
+use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::Version;
-use ibc::core::connection::types::version::Version as ConnectionVersion;
-use ibc::core::connection::types::{
-    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
-};
+use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
-use ibc::core::host::ValidationContext;
-use ibc::core::primitives::*;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::{AckPath, SeqAckPath, SeqSendPath};
+use ibc::core::host::{HostValidationContext, PacketExecutionContext, PacketValidationContext};
+use ibc::core::primitives::Signer;
+use ibc::core::router::module::Module;
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_close_init;
-use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::connection::ConnectionEndConfig;
+use ibc_testkit::fixtures::core::signer::dummy_bech32_account;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 
+/// A module that only lets `authority` close its channels.
+#[derive(Debug)]
+struct GuardedModule {
+    authority: Signer,
+}
+
+impl Module for GuardedModule {
+    fn on_chan_open_init_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), version.clone()))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), counterparty_version.clone()))
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        signer: &Signer,
+    ) -> Result<(), ChannelError> {
+        if signer != &self.authority {
+            return Err(ChannelError::AppModule {
+                description: format!("{signer} cannot close channel {port_id}/{channel_id}"),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        (ModuleExtras::empty(), None)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
+
 #[test]
 fn test_chan_close_init_validate() {
     let client_id = mock_client_type().build_client_id(24);
     let conn_id = ConnectionId::new(2);
 
-    let conn_end = ConnectionEnd::new(
-        ConnectionState::Open,
-        client_id.clone(),
-        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
-        ConnectionVersion::compatibles(),
-        ZERO_DURATION,
-    )
-    .unwrap();
+    let conn_end: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .build()
+        .try_into()
+        .unwrap();
 
     let msg_chan_close_init =
         MsgChannelCloseInit::try_from(dummy_raw_msg_chan_close_init()).unwrap();
@@ -82,14 +198,11 @@ fn test_chan_close_init_execute() {
     let client_id = mock_client_type().build_client_id(24);
     let conn_id = ConnectionId::new(2);
 
-    let conn_end = ConnectionEnd::new(
-        ConnectionState::Open,
-        client_id.clone(),
-        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
-        ConnectionVersion::compatibles(),
-        ZERO_DURATION,
-    )
-    .unwrap();
+    let conn_end: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .build()
+        .try_into()
+        .unwrap();
 
     let msg_chan_close_init =
         MsgChannelCloseInit::try_from(dummy_raw_msg_chan_close_init()).unwrap();
@@ -144,3 +257,146 @@ fn test_chan_close_init_execute() {
 
     assert!(matches!(ibc_events[1], IbcEvent::CloseInitChannel(_)));
 }
+
+#[test]
+fn test_chan_close_init_deletes_stale_state() {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+
+    let conn_end: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .build()
+        .try_into()
+        .unwrap();
+
+    let msg_chan_close_init =
+        MsgChannelCloseInit::try_from(dummy_raw_msg_chan_close_init()).unwrap();
+    let port_id = msg_chan_close_init.port_id_on_a.clone();
+    let chan_id = msg_chan_close_init.chan_id_on_a.clone();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_init));
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(port_id.clone(), Some(chan_id.clone())),
+        vec![conn_id.clone()],
+        Version::empty(),
+    )
+    .unwrap();
+
+    let mut context = {
+        let default_context = MockContext::default();
+        let client_consensus_state_height = default_context.host_height().unwrap();
+
+        default_context
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_id(client_id.clone())
+                    .latest_height(client_consensus_state_height)
+                    .build(),
+            )
+            .with_connection(conn_id, conn_end)
+            .with_channel(port_id.clone(), chan_id.clone(), chan_end)
+            .with_send_sequence(port_id.clone(), chan_id.clone(), 3.into())
+            .with_ack_sequence(port_id.clone(), chan_id.clone(), 3.into())
+    };
+
+    let ack_path = AckPath::new(&port_id, &chan_id, 4.into());
+    context
+        .store_packet_acknowledgement(&ack_path, vec![1u8].into())
+        .unwrap();
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    assert!(context
+        .get_next_sequence_send(&SeqSendPath::new(&port_id, &chan_id))
+        .is_err());
+    assert!(context
+        .get_next_sequence_ack(&SeqAckPath::new(&port_id, &chan_id))
+        .is_err());
+
+    // The counterparty channel may still be open and acknowledge the packet.
+    assert!(context.get_packet_acknowledgement(&ack_path).is_ok());
+}
+
+#[test]
+fn test_chan_close_init_vetoed_by_module() {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+
+    let conn_end: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .build()
+        .try_into()
+        .unwrap();
+
+    let msg_chan_close_init =
+        MsgChannelCloseInit::try_from(dummy_raw_msg_chan_close_init()).unwrap();
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(
+            msg_chan_close_init.port_id_on_a.clone(),
+            Some(msg_chan_close_init.chan_id_on_a.clone()),
+        ),
+        vec![conn_id.clone()],
+        Version::empty(),
+    )
+    .unwrap();
+
+    let context = {
+        let default_context = MockContext::default();
+        let client_consensus_state_height = default_context.host_height().unwrap();
+
+        default_context
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_id(client_id.clone())
+                    .latest_height(client_consensus_state_height)
+                    .build(),
+            )
+            .with_connection(conn_id, conn_end)
+            .with_channel(
+                msg_chan_close_init.port_id_on_a.clone(),
+                msg_chan_close_init.chan_id_on_a.clone(),
+                chan_end,
+            )
+    };
+
+    let router_with_authority = |authority: Signer| {
+        let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+        let mut router = MockRouter::default();
+        router.scope_port_to_module(msg_chan_close_init.port_id_on_a.clone(), module_id.clone());
+        router
+            .add_route(module_id, GuardedModule { authority })
+            .unwrap();
+        router
+    };
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_init.clone()));
+
+    let router = router_with_authority(dummy_bech32_account().into());
+    let res = validate(&context, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Validation expected to succeed for the authority. Error: {res:?}"
+    );
+
+    let router = router_with_authority("cosmos1authority".to_string().into());
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(ChannelError::AppModule { .. }))
+        ),
+        "Validation expected to fail when the module vetoes the closure. Result: {res:?}"
+    );
+}
@@ -1,15 +1,29 @@
+use ibc::apps::transfer::types::MODULE_ID_STR;
 use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenInit};
+use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
-use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{ConnectionEnd, State as ConnectionState};
 use ibc::core::entrypoint::{execute, validate};
-use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{CapReached, CapScope, IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::capability::{bind_port, verify_capability_owner};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::limits::HostLimits;
+use ibc::core::host::types::path::ChannelEndPath;
+use ibc::core::host::{ChannelExecutionContext, ChannelValidationContext};
+use ibc::core::router::middleware::{Middleware, Stack};
+use ibc::core::router::module::Module;
+use ibc::core::router::router::{Router, RouterView};
+use ibc::core::router::types::capability::CapabilityName;
+use ibc::core::router::types::error::RouterError;
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_init;
-use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_init;
+use ibc_testkit::fixtures::core::connection::{dummy_msg_conn_open_init, ConnectionEndConfig};
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use rstest::*;
@@ -36,14 +50,14 @@ fn fixture() -> Fixture {
     let client_id_on_a = tm_client_type().build_client_id(0);
     let client_height = Height::new(0, 10).unwrap();
 
-    let conn_end_on_a = ConnectionEnd::new(
-        ConnectionState::Init,
-        msg_conn_init.client_id_on_a.clone(),
-        msg_conn_init.counterparty.clone(),
-        ConnectionVersion::compatibles(),
-        msg_conn_init.delay_period,
-    )
-    .unwrap();
+    let conn_end_on_a: ConnectionEnd = ConnectionEndConfig::builder()
+        .state(ConnectionState::Init)
+        .client_id(msg_conn_init.client_id_on_a.clone())
+        .counterparty(msg_conn_init.counterparty.clone())
+        .delay_period(msg_conn_init.delay_period)
+        .build()
+        .try_into()
+        .unwrap();
 
     let ctx = default_ctx
         .with_client_config(
@@ -121,3 +135,201 @@ fn chan_open_init_fail_no_connection(fixture: Fixture) {
         "Validation fails because no connection exists in the context"
     )
 }
+
+#[rstest]
+fn chan_open_init_max_channels_per_connection(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+        msg,
+    } = fixture;
+
+    ctx.host_limits = HostLimits::unlimited().with_max_channels_per_connection(1);
+
+    let res = validate(&ctx, &router, msg.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx, &mut router, msg.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let ibc_events = ctx.get_events();
+    assert_eq!(
+        ibc_events.last(),
+        Some(&IbcEvent::CapReached(CapReached::new(
+            CapScope::ChannelsPerConnection(ConnectionId::zero()),
+            1
+        )))
+    );
+
+    let res = validate(&ctx, &router, msg);
+    assert!(matches!(
+        res,
+        Err(ContextError::ChannelError(
+            ChannelError::MaxChannelsPerConnectionReached {
+                max_channels: 1,
+                ..
+            }
+        ))
+    ));
+}
+
+#[rstest]
+fn chan_open_init_enforced_capabilities(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+        msg,
+    } = fixture;
+
+    ctx.enforce_capabilities = true;
+    let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+
+    let res = validate(&ctx, &router, msg.clone());
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::RouterError(
+                RouterError::CapabilityNotOwned { .. }
+            ))
+        ),
+        "the port is not bound: {res:?}"
+    );
+
+    bind_port(&mut ctx, &module_id, &PortId::transfer()).unwrap();
+
+    let res = validate(&ctx, &router, msg.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx, &mut router, msg);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let chan_capability_name = CapabilityName::channel(&PortId::transfer(), &ChannelId::zero());
+    assert!(verify_capability_owner(&ctx, &module_id, &chan_capability_name).is_ok());
+    assert!(verify_capability_owner(
+        &ctx,
+        &ModuleId::new("other".to_string()),
+        &chan_capability_name
+    )
+    .is_err());
+}
+
+#[rstest]
+fn chan_open_init_failed_delivery_is_discarded(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+        msg,
+    } = fixture;
+
+    ctx.enforce_capabilities = true;
+    let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    bind_port(&mut ctx, &module_id, &PortId::transfer()).unwrap();
+
+    // Claiming the capability over the opened channel fails once the channel
+    // end is written.
+    ctx.new_capability(&CapabilityName::channel(
+        &PortId::transfer(),
+        &ChannelId::zero(),
+    ))
+    .unwrap();
+
+    let channel_counter = ctx.channel_counter().unwrap();
+    let event_count = ctx.ibc_store.events.len();
+    let write_set = ctx.write_set.clone();
+
+    let res = ctx.deliver(&mut router, [msg]);
+    assert!(res.is_err(), "result: {res:?}");
+
+    assert_eq!(ctx.channel_counter().unwrap(), channel_counter);
+    assert!(ctx
+        .channel_end(&ChannelEndPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero()
+        ))
+        .is_err());
+    assert_eq!(ctx.ibc_store.events.len(), event_count);
+    assert_eq!(ctx.write_set, write_set);
+}
+
+/// Records the channels opened by the module below it in state borrowed from
+/// the host.
+struct RecordingMiddleware<'a> {
+    opened_channels: &'a mut Vec<ChannelId>,
+}
+
+impl Middleware for RecordingMiddleware<'_> {
+    fn on_chan_open_init_execute(
+        &mut self,
+        next: &mut dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let res = next.on_chan_open_init_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )?;
+        self.opened_channels.push(channel_id.clone());
+        Ok(res)
+    }
+}
+
+/// A router built for a single message over a module borrowing host state.
+struct BorrowingRouter<'a> {
+    module: Stack<RecordingMiddleware<'a>, DummyTransferModule>,
+}
+
+impl RouterView for BorrowingRouter<'_> {
+    fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+        if *module_id == ModuleId::new(MODULE_ID_STR.to_string()) {
+            Some(&self.module)
+        } else {
+            None
+        }
+    }
+
+    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+        (port_id == &PortId::transfer()).then(|| ModuleId::new(MODULE_ID_STR.to_string()))
+    }
+}
+
+impl Router for BorrowingRouter<'_> {
+    fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+        if *module_id == ModuleId::new(MODULE_ID_STR.to_string()) {
+            Some(&mut self.module)
+        } else {
+            None
+        }
+    }
+}
+
+#[rstest]
+fn chan_open_init_borrowing_router(fixture: Fixture) {
+    let Fixture { mut ctx, msg, .. } = fixture;
+    let mut opened_channels = Vec::new();
+
+    {
+        let mut router = BorrowingRouter {
+            module: RecordingMiddleware {
+                opened_channels: &mut opened_channels,
+            }
+            .wrap(DummyTransferModule::new()),
+        };
+        let router: &mut dyn Router = &mut router;
+
+        let res = validate(&ctx, router, msg.clone());
+        assert!(res.is_ok(), "result: {res:?}");
+
+        let res = execute(&mut ctx, router, msg);
+        assert!(res.is_ok(), "result: {res:?}");
+    }
+
+    assert_eq!(opened_channels, vec![ChannelId::zero()]);
+}
@@ -1,17 +1,13 @@
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenTry};
 use ibc::core::client::types::Height;
-use ibc::core::connection::types::version::Version as ConnectionVersion;
-use ibc::core::connection::types::{
-    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
-};
+use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
-use ibc::core::host::ValidationContext;
-use ibc::core::primitives::*;
+use ibc::core::host::ChannelValidationContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_try;
-use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::connection::ConnectionEndConfig;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
@@ -35,14 +31,11 @@ fn fixture() -> Fixture {
     let client_id_on_b = mock_client_type().build_client_id(45);
 
     // This is the connection underlying the channel we're trying to open.
-    let conn_end_on_b = ConnectionEnd::new(
-        ConnectionState::Open,
-        client_id_on_b.clone(),
-        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
-        ConnectionVersion::compatibles(),
-        ZERO_DURATION,
-    )
-    .unwrap();
+    let conn_end_on_b: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id_on_b.clone())
+        .build()
+        .try_into()
+        .unwrap();
 
     // We're going to test message processing against this message.
     // Note: we make the counterparty's channel_id `None`.
@@ -5,7 +5,10 @@ pub mod chan_open_ack;
 pub mod chan_open_confirm;
 pub mod chan_open_init;
 pub mod chan_open_try;
+pub mod prune_acknowledgements;
 pub mod recv_packet;
 pub mod send_packet;
 pub mod timeout;
+pub mod timeout_batch;
 pub mod timeout_on_close;
+pub mod write_acknowledgement;
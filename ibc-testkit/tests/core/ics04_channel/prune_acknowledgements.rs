@@ -0,0 +1,206 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::AcknowledgementCommitment;
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgPruneAcknowledgements};
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::Version;
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{AckPath, PruningSeqStartPath, ReceiptPath};
+use ibc::core::host::{PacketExecutionContext, PacketValidationContext};
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_prune_acknowledgements;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+/// Returns a context whose channel was upgraded before the packets
+/// `1..pruning_seq_end` were received, with the given sequences acknowledged.
+fn upgraded_context(order: Order, acked: &[u64], pruning_seq_end: u64) -> MockContext {
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        order,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end)
+        .with_pruning_sequences(
+            PortId::transfer(),
+            ChannelId::zero(),
+            Sequence::from(1),
+            Sequence::from(pruning_seq_end),
+        );
+
+    for &seq in acked {
+        let seq = Sequence::from(seq);
+        ctx.store_packet_acknowledgement(
+            &AckPath::new(&PortId::transfer(), &ChannelId::zero(), seq),
+            AcknowledgementCommitment::from(vec![1]),
+        )
+        .unwrap();
+        if order == Order::Unordered {
+            ctx.store_packet_receipt(
+                &ReceiptPath::new(&PortId::transfer(), &ChannelId::zero(), seq),
+                Receipt::Ok,
+            )
+            .unwrap();
+        }
+    }
+
+    ctx
+}
+
+fn prune_msg(limit: u64) -> MsgEnvelope {
+    let msg =
+        MsgPruneAcknowledgements::try_from(dummy_raw_msg_prune_acknowledgements(limit)).unwrap();
+    MsgEnvelope::from(ChannelMsg::from(msg))
+}
+
+fn is_acked(ctx: &MockContext, seq: u64) -> bool {
+    ctx.get_packet_acknowledgement(&AckPath::new(
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        seq.into(),
+    ))
+    .is_ok()
+}
+
+fn is_received(ctx: &MockContext, seq: u64) -> bool {
+    ctx.get_packet_receipt(&ReceiptPath::new(
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        seq.into(),
+    ))
+    .is_ok()
+}
+
+fn pruning_seq_start(ctx: &MockContext) -> Option<Sequence> {
+    ctx.get_pruning_sequence_start(&PruningSeqStartPath::new(
+        &PortId::transfer(),
+        &ChannelId::zero(),
+    ))
+    .unwrap()
+}
+
+fn last_prune_event(ctx: &MockContext) -> (u64, u64) {
+    let events = ctx.get_events();
+    assert!(matches!(
+        events[events.len() - 2],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    let IbcEvent::PruneAcknowledgements(event) = &events[events.len() - 1] else {
+        panic!("expected a prune acknowledgements event, got {events:?}");
+    };
+    assert_eq!(event.port_id(), &PortId::transfer());
+    assert_eq!(event.chan_id(), &ChannelId::zero());
+    (
+        event.total_pruned_sequences(),
+        event.total_remaining_sequences(),
+    )
+}
+
+#[test]
+fn prune_acknowledgements_up_to_limit() {
+    let mut ctx = upgraded_context(Order::Unordered, &[1, 2, 3, 4, 5], 6);
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg = prune_msg(3);
+    validate(&ctx, &router, msg.clone()).expect("validation should succeed");
+    execute(&mut ctx, &mut router, msg).expect("execution should succeed");
+
+    for seq in 1..=3 {
+        assert!(!is_acked(&ctx, seq) && !is_received(&ctx, seq));
+    }
+    for seq in 4..=5 {
+        assert!(is_acked(&ctx, seq) && is_received(&ctx, seq));
+    }
+    assert_eq!(pruning_seq_start(&ctx), Some(Sequence::from(4)));
+    assert_eq!(last_prune_event(&ctx), (3, 2));
+
+    // Pruning resumes where it stopped.
+    let msg = prune_msg(10);
+    validate(&ctx, &router, msg.clone()).expect("validation should succeed");
+    execute(&mut ctx, &mut router, msg).expect("execution should succeed");
+
+    assert!(!is_acked(&ctx, 4) && !is_acked(&ctx, 5));
+    assert_eq!(pruning_seq_start(&ctx), Some(Sequence::from(6)));
+    assert_eq!(last_prune_event(&ctx), (2, 0));
+}
+
+#[test]
+fn prune_acknowledgements_stops_at_unacknowledged_packet() {
+    let mut ctx = upgraded_context(Order::Unordered, &[1, 2, 4], 6);
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg = prune_msg(10);
+    validate(&ctx, &router, msg.clone()).expect("validation should succeed");
+    execute(&mut ctx, &mut router, msg).expect("execution should succeed");
+
+    assert!(!is_acked(&ctx, 1) && !is_acked(&ctx, 2));
+    assert!(is_acked(&ctx, 4) && is_received(&ctx, 4));
+    assert_eq!(pruning_seq_start(&ctx), Some(Sequence::from(3)));
+    assert_eq!(last_prune_event(&ctx), (2, 3));
+}
+
+#[test]
+fn prune_acknowledgements_ordered_chan() {
+    let mut ctx = upgraded_context(Order::Ordered, &[1, 2], 3);
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg = prune_msg(10);
+    validate(&ctx, &router, msg.clone()).expect("validation should succeed");
+    execute(&mut ctx, &mut router, msg).expect("execution should succeed");
+
+    assert!(!is_acked(&ctx, 1) && !is_acked(&ctx, 2));
+    assert_eq!(last_prune_event(&ctx), (2, 0));
+}
+
+#[test]
+fn prune_acknowledgements_fails_if_channel_not_upgraded() {
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+    let ctx = MockContext::default().with_channel(PortId::transfer(), ChannelId::zero(), chan_end);
+    let router = MockRouter::new_with_transfer();
+
+    let res = validate(&ctx, &router, prune_msg(10));
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::PruningSequenceEndNotFound { .. }
+            ))
+        ),
+        "validation should fail on a channel that was never upgraded: {res:?}"
+    );
+}
+
+#[test]
+fn prune_acknowledgements_fails_if_channel_not_found() {
+    let ctx = MockContext::default();
+    let router = MockRouter::new_with_transfer();
+
+    let res = validate(&ctx, &router, prune_msg(10));
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::ChannelNotFound { .. }
+            ))
+        ),
+        "validation should fail on a missing channel: {res:?}"
+    );
+}
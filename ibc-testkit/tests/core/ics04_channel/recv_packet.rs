@@ -1,23 +1,36 @@
+use core::time::Duration;
+
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc::core::channel::types::error::PacketError;
 use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
-use ibc::core::connection::types::version::Version as ConnectionVersion;
-use ibc::core::connection::types::{
-    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
-};
+use ibc::core::connection::handler::update_connection_params;
+use ibc::core::connection::types::error::ConnectionError;
+use ibc::core::connection::types::params::ConnectionParams;
+use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::ExecutionContext;
+use ibc::core::host::types::limits::HostLimits;
+use ibc::core::host::types::path::{AckPath, CommitmentPath, Path};
+use ibc::core::host::{
+    ConnectionValidationContext, HostExecutionContext, HostValidationContext,
+    PacketValidationContext,
+};
 use ibc::core::primitives::*;
 use ibc_testkit::fixtures::core::channel::{dummy_msg_recv_packet, dummy_raw_msg_recv_packet};
+use ibc_testkit::fixtures::core::connection::{ConnectionEndConfig, CounterpartyConfig};
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::relayer::context::RelayerContext;
+use ibc_testkit::relayer::multihop::{build_multihop_proof, Hop};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use rstest::*;
@@ -46,8 +59,11 @@ fn fixture() -> Fixture {
 
     let client_height = host_height.increment();
 
-    let msg = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(client_height.revision_height()))
-        .unwrap();
+    let msg = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(
+        client_height.revision_height(),
+        &*context.time_provider,
+    ))
+    .unwrap();
 
     let packet = msg.packet.clone();
 
@@ -60,18 +76,17 @@ fn fixture() -> Fixture {
     )
     .unwrap();
 
-    let conn_end_on_b = ConnectionEnd::new(
-        ConnectionState::Open,
-        client_id.clone(),
-        ConnectionCounterparty::new(
-            client_id.clone(),
-            Some(ConnectionId::zero()),
-            CommitmentPrefix::empty(),
-        ),
-        ConnectionVersion::compatibles(),
-        ZERO_DURATION,
-    )
-    .unwrap();
+    let conn_end_on_b: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .client_id(client_id.clone())
+                .prefix(CommitmentPrefix::empty())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
 
     Fixture {
         context,
@@ -164,6 +179,217 @@ fn recv_packet_validate_happy_path(fixture: Fixture) {
     )
 }
 
+#[rstest]
+fn recv_packet_validate_expired_client_grace_period(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        client_id,
+        ..
+    } = fixture;
+
+    // The mock client trusts its consensus states for 10 seconds.
+    let expired_for = Duration::from_secs(20);
+    let latest_timestamp = (context.host_timestamp().unwrap() - expired_for).unwrap();
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .latest_timestamp(latest_timestamp)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_send_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            1.into(),
+        )
+        .with_height(host_height)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+    context
+        .get_client_execution_context()
+        .store_update_meta(
+            client_id,
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the client is expired and no grace period is set"
+    );
+
+    context.client_expiry_grace_period = Some(Duration::from_secs(30));
+
+    let res = validate(&context, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds within the grace period. err: {res:?}"
+    );
+
+    context.client_expiry_grace_period = Some(Duration::from_secs(5));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails once the grace period has elapsed"
+    );
+}
+
+#[rstest]
+fn recv_packet_validate_multihop(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        client_height,
+        host_height,
+        client_id,
+        ..
+    } = fixture;
+
+    let packet = msg.packet.clone();
+
+    // The packet is routed from A to B through M.
+    let ctx_a = MockContext::default();
+    let conn_id_on_a = ConnectionId::new(7);
+
+    let conn_id_on_m = ConnectionId::new(1);
+    let height_of_a_on_m = Height::new(0, 3).unwrap();
+    let conn_end_on_m: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .connection_id(Some(conn_id_on_a.clone()))
+                .prefix(ctx_a.commitment_prefix())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
+    let ctx_m = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(height_of_a_on_m)
+                .build(),
+        )
+        .with_connection(conn_id_on_m.clone(), conn_end_on_m);
+
+    let conn_end_on_b: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .prefix(ctx_m.commitment_prefix())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::zero(), conn_id_on_m.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let mut context = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_height(host_height);
+
+    context
+        .get_client_execution_context()
+        .store_update_meta(
+            client_id,
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    let hops = [Hop {
+        chain: &ctx_m,
+        connection_id: conn_id_on_m,
+        consensus_height: height_of_a_on_m,
+    }];
+    let commitment = compute_packet_commitment(
+        &packet.data,
+        &packet.timeout_height_on_b,
+        &packet.timeout_timestamp_on_b,
+    );
+    let prove_commitment = |sequence| {
+        build_multihop_proof(
+            &ctx_a,
+            &hops,
+            Path::Commitment(CommitmentPath::new(
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                sequence,
+            )),
+            commitment.clone().into_vec(),
+        )
+        .unwrap()
+    };
+
+    msg.proof_commitment_on_a = prove_commitment(packet.seq_on_a);
+    let res = validate(
+        &context,
+        &router,
+        MsgEnvelope::from(PacketMsg::from(msg.clone())),
+    );
+    assert!(
+        res.is_ok(),
+        "Happy path: multi-hop validation should succeed. err: {res:?}"
+    );
+
+    // The key proof must be for the commitment of the received packet.
+    msg.proof_commitment_on_a = prove_commitment(packet.seq_on_a.increment());
+    let res = validate(&context, &router, MsgEnvelope::from(PacketMsg::from(msg)));
+    assert!(
+        res.is_err(),
+        "Validation fails because the proof is for another packet"
+    );
+}
+
 #[rstest]
 fn recv_packet_timeout_expired(fixture: Fixture) {
     let Fixture {
@@ -185,7 +411,7 @@ fn recv_packet_timeout_expired(fixture: Fixture) {
         chan_id_on_b: ChannelId::zero(),
         data: Vec::new(),
         timeout_height_on_b: client_height.into(),
-        timeout_timestamp_on_b: Timestamp::from_nanoseconds(1).unwrap(),
+        timeout_timestamp_on_b: Timestamp::from_nanoseconds(1).unwrap().into(),
     };
 
     let msg_packet_old = dummy_msg_recv_packet(
@@ -236,12 +462,24 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
         .with_connection(ConnectionId::zero(), conn_end_on_b)
         .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
 
+    let ack_path_on_b = AckPath::new(
+        &msg.packet.port_id_on_b,
+        &msg.packet.chan_id_on_b,
+        msg.packet.seq_on_a,
+    );
     let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
 
     let res = execute(&mut ctx, &mut router, msg_env);
 
     assert!(res.is_ok());
 
+    // The acknowledgement is stored in its committed form only.
+    let ack = Acknowledgement::try_from(vec![1u8]).expect("Never fails");
+    assert_eq!(
+        ctx.get_packet_acknowledgement(&ack_path_on_b).unwrap(),
+        compute_ack_commitment(&ack)
+    );
+
     let ibc_events = ctx.get_events();
 
     assert_eq!(ibc_events.len(), 4);
@@ -256,3 +494,130 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
     ));
     assert!(matches!(&ibc_events[3], &IbcEvent::WriteAcknowledgement(_)));
 }
+
+#[rstest]
+fn recv_packet_block_delay(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        client_id,
+        ..
+    } = fixture;
+
+    // With the default 3s per block of the mock host, the delay period amounts
+    // to 4 blocks.
+    let conn_end_on_b: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .client_id(client_id.clone())
+                .prefix(CommitmentPrefix::empty())
+                .build(),
+        )
+        .delay_period(Duration::from_secs(12))
+        .build()
+        .try_into()
+        .unwrap();
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_height(host_height);
+
+    // The client was updated at height 1, 5 blocks before the host height.
+    context
+        .get_client_execution_context()
+        .store_update_meta(
+            client_id,
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+            Height::new(0, 1).unwrap(),
+        )
+        .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Happy path: validation should succeed. err: {res:?}"
+    );
+
+    // Lowering the expected time per block raises the delay to 12 blocks.
+    let params = ConnectionParams::new(Duration::from_secs(1)).unwrap();
+    update_connection_params::validate(&context, &params).unwrap();
+    update_connection_params::execute(&mut context, params).unwrap();
+
+    assert_eq!(
+        context.max_expected_time_per_block(),
+        Duration::from_secs(1)
+    );
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughBlocksElapsed { .. }
+            ))
+        ),
+        "validation should fail as not enough blocks elapsed. res: {res:?}"
+    );
+}
+
+#[rstest]
+fn recv_packet_fail_data_too_large(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+
+    let packet = &msg.packet;
+    let max_size = packet.data.len() as u64 - 1;
+    let mut context = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        );
+    context.host_limits = HostLimits::unlimited().with_max_packet_data_size(max_size);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(PacketError::PacketDataTooLarge { max_size: m, .. })) if m == max_size
+        ),
+        "Validation fails because the packet data exceeds the host limit. res: {res:?}"
+    );
+}
@@ -3,32 +3,40 @@ use core::time::Duration;
 
 use ibc::core::channel::handler::send_packet;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
-use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::commitment::{
+    compute_packet_commitment, AcknowledgementCommitment, PacketCommitment,
+};
+use ibc::core::channel::types::error::{ChannelError, PacketError};
+use ibc::core::channel::types::packet::{Packet, Receipt};
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
-use ibc::core::connection::types::version::Version as ConnectionVersion;
-use ibc::core::connection::types::{
-    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{
+    AckPath, ChannelEndPath, CommitmentPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc::core::host::types::write_set::StoreWrite;
+use ibc::core::host::{
+    ChannelValidationContext, ConnectionEndValidationContext, HostExecutionContext,
+    HostValidationContext, PacketExecutionContext, PacketValidationContext,
 };
-use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc::core::primitives::*;
 use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::fixtures::core::connection::{ConnectionEndConfig, CounterpartyConfig};
+use ibc_testkit::fixtures::{run_handler_tests, Fixture, HandlerTest, Outcome};
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use test_log::test;
 
+const MESSAGE_EVENT: &str = "message";
+const SEND_PACKET_EVENT: &str = "send_packet";
+
 #[test]
 fn send_packet_processing() {
     let default_client_id = ClientId::new("07-tendermint", 0).expect("no error");
 
-    struct Test {
-        name: String,
-        ctx: MockContext,
-        packet: Packet,
-        want_pass: bool,
-    }
-
     let context = MockContext::default();
 
     let chan_end_on_a = ChannelEnd::new(
@@ -40,18 +48,17 @@ fn send_packet_processing() {
     )
     .unwrap();
 
-    let conn_end_on_a = ConnectionEnd::new(
-        ConnectionState::Open,
-        default_client_id.clone(),
-        ConnectionCounterparty::new(
-            default_client_id.clone(),
-            Some(ConnectionId::zero()),
-            CommitmentPrefix::empty(),
-        ),
-        ConnectionVersion::compatibles(),
-        ZERO_DURATION,
-    )
-    .unwrap();
+    let conn_end_on_a: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(default_client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .client_id(default_client_id.clone())
+                .prefix(CommitmentPrefix::empty())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
 
     let timestamp_future = Timestamp::now().add(Duration::from_secs(10)).unwrap();
     let timestamp_ns_past = 1;
@@ -83,112 +90,417 @@ fn send_packet_processing() {
             .unwrap();
 
     let client_height = Height::new(0, client_raw_height).unwrap();
+    let low_timeout_height = packet_timeout_one_before_client_height.timeout_height_on_b;
 
-    let tests: Vec<Test> = vec![
-        Test {
+    let tests: Vec<HandlerTest<Packet>> = vec![
+        HandlerTest {
             name: "Processing fails because no channel exists in the context".to_string(),
-            ctx: context.clone(),
-            packet: packet.clone(),
-            want_pass: false,
+            fixture: Fixture {
+                ctx: context.clone(),
+                msg: packet.clone(),
+            },
+            outcome: Outcome::Failure(
+                ChannelError::ChannelNotFound {
+                    port_id: PortId::transfer(),
+                    channel_id: ChannelId::zero(),
+                }
+                .into(),
+            ),
         },
-        Test {
+        HandlerTest {
             name: "Good parameters".to_string(),
-            ctx: context
-                .clone()
-                .with_client_config(
-                    MockClientConfig::builder()
-                        .latest_height(client_height)
-                        .build(),
-                )
-                .with_connection(ConnectionId::zero(), conn_end_on_a.clone())
-                .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a.clone())
-                .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into()),
-            packet,
-            want_pass: true,
+            fixture: Fixture {
+                ctx: context
+                    .clone()
+                    .with_client_config(
+                        MockClientConfig::builder()
+                            .latest_height(client_height)
+                            .build(),
+                    )
+                    .with_connection(ConnectionId::zero(), conn_end_on_a.clone())
+                    .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a.clone())
+                    .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into()),
+                msg: packet,
+            },
+            outcome: Outcome::Success(vec![MESSAGE_EVENT, SEND_PACKET_EVENT]),
         },
-        Test {
+        HandlerTest {
             name: "Packet timeout height same as destination chain height".to_string(),
-            ctx: context
-                .clone()
-                .with_client_config(
-                    MockClientConfig::builder()
-                        .latest_height(client_height)
-                        .build(),
-                )
-                .with_connection(ConnectionId::zero(), conn_end_on_a.clone())
-                .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a.clone())
-                .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into()),
-            packet: packet_timeout_equal_client_height,
-            want_pass: true,
+            fixture: Fixture {
+                ctx: context
+                    .clone()
+                    .with_client_config(
+                        MockClientConfig::builder()
+                            .latest_height(client_height)
+                            .build(),
+                    )
+                    .with_connection(ConnectionId::zero(), conn_end_on_a.clone())
+                    .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a.clone())
+                    .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into()),
+                msg: packet_timeout_equal_client_height,
+            },
+            outcome: Outcome::Success(vec![MESSAGE_EVENT, SEND_PACKET_EVENT]),
         },
-        Test {
+        HandlerTest {
             name: "Packet timeout height one more than destination chain height".to_string(),
-            ctx: context
-                .clone()
-                .with_client_config(
-                    MockClientConfig::builder()
-                        .latest_height(client_height)
-                        .build(),
-                )
-                .with_connection(ConnectionId::zero(), conn_end_on_a.clone())
-                .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a.clone())
-                .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into()),
-            packet: packet_timeout_one_before_client_height,
-            want_pass: false,
+            fixture: Fixture {
+                ctx: context
+                    .clone()
+                    .with_client_config(
+                        MockClientConfig::builder()
+                            .latest_height(client_height)
+                            .build(),
+                    )
+                    .with_connection(ConnectionId::zero(), conn_end_on_a.clone())
+                    .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a.clone())
+                    .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into()),
+                msg: packet_timeout_one_before_client_height,
+            },
+            outcome: Outcome::Failure(
+                PacketError::LowPacketHeight {
+                    chain_height: client_height,
+                    timeout_height: low_timeout_height,
+                }
+                .into(),
+            ),
         },
-        Test {
+        HandlerTest {
             name: "Packet timeout due to timestamp".to_string(),
-            ctx: context
-                .with_client_config(
-                    MockClientConfig::builder()
-                        .latest_height(client_height)
-                        .build(),
+            fixture: Fixture {
+                ctx: context
+                    .with_client_config(
+                        MockClientConfig::builder()
+                            .latest_height(client_height)
+                            .build(),
+                    )
+                    .with_connection(ConnectionId::zero(), conn_end_on_a)
+                    .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+                    .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into()),
+                msg: packet_with_timestamp_old,
+            },
+            outcome: Outcome::Failure(PacketError::LowPacketTimestamp.into()),
+        },
+    ];
+
+    run_handler_tests(tests, send_packet);
+}
+
+#[test]
+fn send_packet_records_writes() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .client_id(client_id)
+                .prefix(CommitmentPrefix::empty())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
+
+    let timestamp_future = Timestamp::now().add(Duration::from_secs(10)).unwrap();
+
+    let mut packet: Packet = dummy_raw_packet(10, timestamp_future.nanoseconds())
+        .try_into()
+        .unwrap();
+    packet.seq_on_a = 1.into();
+    packet.data = vec![0];
+
+    let mut ctx = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(Height::new(0, 5).unwrap())
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into())
+        .with_write_set();
+
+    send_packet(&mut ctx, packet.clone()).unwrap();
+
+    let expected_writes = vec![
+        StoreWrite {
+            path: SeqSendPath::new(&PortId::transfer(), &ChannelId::zero()).into(),
+            value: Some(2u64.to_be_bytes().to_vec()),
+        },
+        StoreWrite {
+            path: CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), 1.into()).into(),
+            value: Some(
+                compute_packet_commitment(
+                    &packet.data,
+                    &packet.timeout_height_on_b,
+                    &packet.timeout_timestamp_on_b,
                 )
-                .with_connection(ConnectionId::zero(), conn_end_on_a)
-                .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
-                .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into()),
-            packet: packet_with_timestamp_old,
-            want_pass: false,
+                .into_vec(),
+            ),
         },
-    ]
-    .into_iter()
-    .collect();
-
-    for mut test in tests {
-        let res = send_packet(&mut test.ctx, test.packet.clone());
-        // Additionally check the events and the output objects in the result.
-        match res {
-            Ok(()) => {
-                assert!(
-                        test.want_pass,
-                        "send_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
-                        test.name,
-                        test.packet.clone(),
-                        test.ctx.clone()
-                    );
-
-                let ibc_events = test.ctx.get_events();
-
-                assert!(!ibc_events.is_empty()); // Some events must exist.
-
-                assert_eq!(ibc_events.len(), 2);
-                assert!(matches!(
-                    &ibc_events[0],
-                    &IbcEvent::Message(MessageEvent::Channel)
-                ));
-                // TODO: The object in the output is a PacketResult what can we check on it?
-                assert!(matches!(&ibc_events[1], &IbcEvent::SendPacket(_)));
-            }
-            Err(e) => {
-                assert!(
-                    !test.want_pass,
-                    "send_packet: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
-                    test.name,
-                    test.packet.clone(),
-                    test.ctx.clone(),
-                    e,
-                );
-            }
-        }
+    ];
+
+    assert_eq!(ctx.write_set.unwrap().writes(), expected_writes.as_slice());
+}
+
+/// A host only running applications that send packets, which implements the
+/// host, connection end, channel and packet contexts, but neither the client
+/// registry nor the connection handshake contexts, nor the channel execution
+/// context.
+struct SendOnlyHost(MockContext);
+
+impl HostValidationContext for SendOnlyHost {
+    type V = MockContext;
+    type CryptoProvider = <MockContext as HostValidationContext>::CryptoProvider;
+    type HostError = <MockContext as HostValidationContext>::HostError;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        &self.0
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.0.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.0.host_timestamp()
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.0.max_expected_time_per_block()
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.0.validate_message_signer(signer)
+    }
+}
+
+impl ConnectionEndValidationContext for SendOnlyHost {
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        self.0.connection_end(conn_id)
+    }
+}
+
+impl ChannelValidationContext for SendOnlyHost {
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        self.0.channel_end(channel_end_path)
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        self.0.channel_counter()
+    }
+}
+
+impl PacketValidationContext for SendOnlyHost {
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        self.0.get_next_sequence_send(seq_send_path)
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.0.get_next_sequence_recv(seq_recv_path)
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        self.0.get_next_sequence_ack(seq_ack_path)
     }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        self.0.get_packet_commitment(commitment_path)
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.0.get_packet_receipt(receipt_path)
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.0.get_packet_acknowledgement(ack_path)
+    }
+}
+
+impl HostExecutionContext for SendOnlyHost {
+    type E = MockContext;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        &mut self.0
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.0.emit_ibc_event(event)
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.0.log_message(message)
+    }
+
+    fn emitted_ibc_events(&self) -> &[IbcEvent] {
+        self.0.emitted_ibc_events()
+    }
+
+    fn logged_messages(&self) -> &[String] {
+        self.0.logged_messages()
+    }
+}
+
+impl PacketExecutionContext for SendOnlyHost {
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.0.store_packet_commitment(commitment_path, commitment)
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.0.delete_packet_commitment(commitment_path)
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.0.store_packet_receipt(receipt_path, receipt)
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.0
+            .store_packet_acknowledgement(ack_path, ack_commitment)
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.0.delete_packet_acknowledgement(ack_path)
+    }
+
+    fn delete_packet_acknowledgements(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<(), ContextError> {
+        self.0.delete_packet_acknowledgements(channel_end_path)
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.0.store_next_sequence_send(seq_send_path, seq)
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.0.store_next_sequence_recv(seq_recv_path, seq)
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.0.store_next_sequence_ack(seq_ack_path, seq)
+    }
+
+    fn delete_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<(), ContextError> {
+        self.0.delete_next_sequence_send(seq_send_path)
+    }
+
+    fn delete_next_sequence_ack(&mut self, seq_ack_path: &SeqAckPath) -> Result<(), ContextError> {
+        self.0.delete_next_sequence_ack(seq_ack_path)
+    }
+}
+
+#[test]
+fn send_packet_on_send_only_host() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .client_id(client_id)
+                .prefix(CommitmentPrefix::empty())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
+
+    let timestamp_future = Timestamp::now().add(Duration::from_secs(10)).unwrap();
+
+    let mut packet: Packet = dummy_raw_packet(10, timestamp_future.nanoseconds())
+        .try_into()
+        .unwrap();
+    packet.seq_on_a = 1.into();
+    packet.data = vec![0];
+
+    let mut host = SendOnlyHost(
+        MockContext::default()
+            .with_client_config(
+                MockClientConfig::builder()
+                    .latest_height(Height::new(0, 5).unwrap())
+                    .build(),
+            )
+            .with_connection(ConnectionId::zero(), conn_end_on_a)
+            .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+            .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into()),
+    );
+
+    send_packet(&mut host, packet.clone()).unwrap();
+
+    let commitment_path = CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), 1.into());
+    assert_eq!(
+        host.get_packet_commitment(&commitment_path).unwrap(),
+        compute_packet_commitment(
+            &packet.data,
+            &packet.timeout_height_on_b,
+            &packet.timeout_timestamp_on_b,
+        )
+    );
+    assert_eq!(
+        host.get_next_sequence_send(&SeqSendPath::new(&PortId::transfer(), &ChannelId::zero()))
+            .unwrap(),
+        2.into()
+    );
 }
@@ -1,21 +1,20 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
 use ibc::core::channel::types::msgs::{MsgTimeout, PacketMsg};
+use ibc::core::channel::types::timeout::TimeoutTimestamp;
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
-use ibc::core::connection::types::version::Version as ConnectionVersion;
-use ibc::core::connection::types::{
-    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
-};
+use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::ExecutionContext;
+use ibc::core::host::HostExecutionContext;
 use ibc::core::primitives::*;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout;
+use ibc_testkit::fixtures::core::connection::{ConnectionEndConfig, CounterpartyConfig};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use rstest::*;
@@ -78,18 +77,17 @@ fn fixture() -> Fixture {
     let mut chan_end_on_a_ordered = chan_end_on_a_unordered.clone();
     chan_end_on_a_ordered.ordering = Order::Ordered;
 
-    let conn_end_on_a = ConnectionEnd::new(
-        ConnectionState::Open,
-        client_id.clone(),
-        ConnectionCounterparty::new(
-            client_id.clone(),
-            Some(ConnectionId::zero()),
-            CommitmentPrefix::empty(),
-        ),
-        ConnectionVersion::compatibles(),
-        ZERO_DURATION,
-    )
-    .unwrap();
+    let conn_end_on_a: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .client_id(client_id.clone())
+                .prefix(CommitmentPrefix::empty())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
 
     Fixture {
         ctx,
@@ -179,9 +177,12 @@ fn timeout_fail_proof_timeout_not_reached(fixture: Fixture) {
     } = fixture;
 
     // timeout timestamp has not reached yet
-    let timeout_timestamp_on_b =
-        (msg.packet.timeout_timestamp_on_b + core::time::Duration::new(10, 0)).unwrap();
-    msg.packet.timeout_timestamp_on_b = timeout_timestamp_on_b;
+    let TimeoutTimestamp::At(timeout_timestamp_on_b) = msg.packet.timeout_timestamp_on_b else {
+        panic!("the fixture packet has a timeout timestamp");
+    };
+    msg.packet.timeout_timestamp_on_b = (timeout_timestamp_on_b + core::time::Duration::new(10, 0))
+        .unwrap()
+        .into();
     let packet_commitment = compute_packet_commitment(
         &msg.packet.data,
         &msg.packet.timeout_height_on_b,
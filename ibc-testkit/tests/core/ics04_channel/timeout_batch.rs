@@ -0,0 +1,205 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_packet_commitment;
+use ibc::core::channel::types::error::PacketError;
+use ibc::core::channel::types::msgs::{MsgTimeoutBatch, PacketMsg, TIMEOUT_BATCH_TYPE_URL};
+use ibc::core::channel::types::Version;
+use ibc::core::client::context::ClientExecutionContext;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, CommitmentPath};
+use ibc::core::host::{ChannelValidationContext, HostExecutionContext, PacketValidationContext};
+use ibc::core::primitives::{Timestamp, ToProto};
+use ibc_testkit::fixtures::core::channel::{dummy_proof, dummy_raw_msg_timeout_batch};
+use ibc_testkit::fixtures::core::connection::{ConnectionEndConfig, CounterpartyConfig};
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+const PACKET_COUNT: u64 = 3;
+
+fn context_with_packets(msg: &MsgTimeoutBatch, order: Order) -> MockContext {
+    let client_id = ClientId::new("07-tendermint", 0).unwrap();
+    let client_height = Height::new(0, 2).unwrap();
+
+    let conn_end_on_a: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .client_id(client_id.clone())
+                .prefix(CommitmentPrefix::empty())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
+
+    let packet = &msg.packets[0];
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        order,
+        Counterparty::new(
+            packet.port_id_on_b.clone(),
+            Some(packet.chan_id_on_b.clone()),
+        ),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a);
+
+    for packet in &msg.packets {
+        ctx = ctx.with_packet_commitment(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+            compute_packet_commitment(
+                &packet.data,
+                &packet.timeout_height_on_b,
+                &packet.timeout_timestamp_on_b,
+            ),
+        );
+    }
+
+    ctx.get_client_execution_context()
+        .store_update_meta(
+            client_id,
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    ctx
+}
+
+fn msg(timeout_height: u64, proof_count: usize) -> MsgTimeoutBatch {
+    let mut raw = dummy_raw_msg_timeout_batch(2, timeout_height, 0, PACKET_COUNT);
+    raw.proofs_unreceived = vec![dummy_proof(); proof_count];
+    MsgTimeoutBatch::try_from(raw).unwrap()
+}
+
+#[test]
+fn timeout_batch_unordered_chan() {
+    let msg = msg(1, PACKET_COUNT as usize);
+    let mut ctx = context_with_packets(&msg, Order::Unordered);
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg.clone()));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(
+        res.is_ok(),
+        "Good parameters for unordered channels: {res:?}"
+    );
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok());
+
+    for packet in &msg.packets {
+        let commitment_path =
+            CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+        assert!(ctx.get_packet_commitment(&commitment_path).is_err());
+    }
+
+    let timeout_events = ctx
+        .get_events()
+        .iter()
+        .filter(|event| matches!(event, IbcEvent::TimeoutPacket(_)))
+        .count();
+    assert_eq!(timeout_events, PACKET_COUNT as usize);
+}
+
+#[test]
+fn timeout_batch_unordered_chan_needs_proof_per_packet() {
+    let msg = msg(1, 1);
+    let ctx = context_with_packets(&msg, Order::Unordered);
+    let router = MockRouter::new_with_transfer();
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(PacketMsg::from(msg)));
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(
+                PacketError::InvalidTimeoutBatch { .. }
+            ))
+        ),
+        "The receipt of every packet must be proven absent. res: {res:?}"
+    );
+}
+
+#[test]
+fn timeout_batch_ordered_chan() {
+    let msg = msg(1, 1);
+    let mut ctx = context_with_packets(&msg, Order::Ordered);
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg.clone()));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(
+        res.is_ok(),
+        "A single proof covers all packets of ordered channels: {res:?}"
+    );
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok());
+
+    let chan_end_on_a = ctx
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+    assert_eq!(chan_end_on_a.state, State::Closed);
+
+    // The channel is closed once for the whole batch.
+    let channel_closed_events = ctx
+        .get_events()
+        .iter()
+        .filter(|event| matches!(event, IbcEvent::ChannelClosed(_)))
+        .count();
+    assert_eq!(channel_closed_events, 1);
+}
+
+#[test]
+fn timeout_batch_fail_timeout_not_reached() {
+    let msg = msg(10, PACKET_COUNT as usize);
+    let ctx = context_with_packets(&msg, Order::Unordered);
+    let router = MockRouter::new_with_transfer();
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(PacketMsg::from(msg)));
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(
+                PacketError::PacketTimeoutNotReached { .. }
+            ))
+        ),
+        "Validation fails because the packets have not timed out. res: {res:?}"
+    );
+}
+
+#[test]
+fn timeout_batch_decodes_from_any() {
+    let msg = msg(1, PACKET_COUNT as usize);
+    let any = msg.clone().to_any();
+
+    assert_eq!(any.type_url, TIMEOUT_BATCH_TYPE_URL);
+    assert_eq!(
+        MsgEnvelope::try_from(any).unwrap(),
+        MsgEnvelope::from(PacketMsg::from(msg))
+    );
+}
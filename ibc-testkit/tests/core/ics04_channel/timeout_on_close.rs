@@ -5,16 +5,14 @@ use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
-use ibc::core::connection::types::version::Version as ConnectionVersion;
-use ibc::core::connection::types::{
-    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
-};
+use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::validate;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::ExecutionContext;
+use ibc::core::host::HostExecutionContext;
 use ibc::core::primitives::*;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout_on_close;
+use ibc_testkit::fixtures::core::connection::{ConnectionEndConfig, CounterpartyConfig};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use rstest::*;
@@ -64,18 +62,17 @@ fn fixture() -> Fixture {
     )
     .unwrap();
 
-    let conn_end_on_a = ConnectionEnd::new(
-        ConnectionState::Open,
-        default_client_id.clone(),
-        ConnectionCounterparty::new(
-            default_client_id.clone(),
-            Some(ConnectionId::zero()),
-            CommitmentPrefix::empty(),
-        ),
-        ConnectionVersion::compatibles(),
-        ZERO_DURATION,
-    )
-    .unwrap();
+    let conn_end_on_a: ConnectionEnd = ConnectionEndConfig::builder()
+        .client_id(default_client_id.clone())
+        .counterparty(
+            CounterpartyConfig::builder()
+                .client_id(default_client_id.clone())
+                .prefix(CommitmentPrefix::empty())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
 
     Fixture {
         context,
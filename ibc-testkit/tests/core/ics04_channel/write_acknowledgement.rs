@@ -0,0 +1,131 @@
+use ibc::core::channel::handler::write_acknowledgement;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::PacketError;
+use ibc::core::channel::types::packet::{Packet, Receipt};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::path::{AckPath, ReceiptPath};
+use ibc::core::host::{PacketExecutionContext, PacketValidationContext};
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::fixtures::core::connection::{ConnectionEndConfig, CounterpartyConfig};
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+fn context_with_channel(packet: &Packet, order: Order) -> MockContext {
+    let conn_end_on_b: ConnectionEnd = ConnectionEndConfig::builder()
+        .counterparty(
+            CounterpartyConfig::builder()
+                .prefix(CommitmentPrefix::empty())
+                .build(),
+        )
+        .build()
+        .try_into()
+        .unwrap();
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        order,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(Height::new(0, 5).unwrap())
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+}
+
+fn packet() -> Packet {
+    let mut packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+    packet.seq_on_a = 1.into();
+    packet
+}
+
+fn ack() -> Acknowledgement {
+    Acknowledgement::try_from(vec![1u8]).unwrap()
+}
+
+#[test]
+fn write_acknowledgement_unordered() {
+    let packet = packet();
+    let mut ctx = context_with_channel(&packet, Order::Unordered);
+
+    let res = write_acknowledgement(&mut ctx, &packet, ack());
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(
+                PacketError::PacketNotReceived { .. }
+            ))
+        ),
+        "A packet cannot be acknowledged before it is received. res: {res:?}"
+    );
+
+    ctx.store_packet_receipt(
+        &ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a),
+        Receipt::Ok,
+    )
+    .unwrap();
+
+    write_acknowledgement(&mut ctx, &packet, ack()).unwrap();
+
+    let ack_path = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    assert_eq!(
+        ctx.get_packet_acknowledgement(&ack_path).unwrap(),
+        ctx.ack_commitment(&ack())
+    );
+    assert!(matches!(
+        ctx.get_events().last(),
+        Some(IbcEvent::WriteAcknowledgement(_))
+    ));
+
+    let res = write_acknowledgement(&mut ctx, &packet, ack());
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(
+                PacketError::AcknowledgementExists { .. }
+            ))
+        ),
+        "A packet cannot be acknowledged twice. res: {res:?}"
+    );
+}
+
+#[test]
+fn write_acknowledgement_ordered() {
+    let packet = packet();
+
+    let ctx = context_with_channel(&packet, Order::Ordered);
+    let mut ctx_not_received = ctx.clone().with_recv_sequence(
+        packet.port_id_on_b.clone(),
+        packet.chan_id_on_b.clone(),
+        packet.seq_on_a,
+    );
+    let mut ctx_received = ctx.with_recv_sequence(
+        packet.port_id_on_b.clone(),
+        packet.chan_id_on_b.clone(),
+        packet.seq_on_a.increment(),
+    );
+
+    assert!(write_acknowledgement(&mut ctx_not_received, &packet, ack()).is_err());
+    assert!(write_acknowledgement(&mut ctx_received, &packet, ack()).is_ok());
+}
@@ -0,0 +1,111 @@
+use ibc::apps::transfer::types::MODULE_ID_STR;
+use ibc::core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementResult};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::host::types::identifiers::PortId;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::middleware::Middleware;
+use ibc::core::router::module::Module;
+use ibc::core::router::router::{Router, RouterView};
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
+use ibc_testkit::fixtures::core::channel::PacketConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use test_log::test;
+
+/// A middleware that expects its tag in front of the packet data, and puts it
+/// back in front of the acknowledgement written by the layers below.
+#[derive(Debug)]
+struct TaggingMiddleware {
+    tag: u8,
+}
+
+impl Middleware for TaggingMiddleware {
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let Some((&tag, data)) = packet.data.split_first() else {
+            return (
+                ModuleExtras::empty(),
+                Some(AcknowledgementResult::error(1, "missing tag").into()),
+            );
+        };
+        if tag != self.tag {
+            return (
+                ModuleExtras::empty(),
+                Some(AcknowledgementResult::error(1, "unexpected tag").into()),
+            );
+        }
+
+        let unwrapped_packet = Packet {
+            data: data.to_vec(),
+            ..packet.clone()
+        };
+        let (extras, ack) = next.on_recv_packet_execute(&unwrapped_packet, relayer);
+
+        let ack = ack.map(|ack| {
+            let mut wrapped = vec![self.tag];
+            wrapped.extend_from_slice(ack.as_bytes());
+            Acknowledgement::try_from(wrapped).expect("ack is not empty")
+        });
+
+        (extras, ack)
+    }
+}
+
+fn packet_with_data(data: &[u8]) -> Packet {
+    PacketConfig::builder().data(data.to_vec()).build()
+}
+
+#[test]
+fn test_middleware_stack_wraps_data_and_acks() {
+    let mut stack = TaggingMiddleware { tag: b'f' }
+        .wrap(TaggingMiddleware { tag: b'c' }.wrap(DummyTransferModule::new()));
+    let relayer = dummy_account_id();
+
+    let (_, ack) = stack.on_recv_packet_execute(&packet_with_data(b"fc-payload"), &relayer);
+    assert_eq!(ack.expect("synchronous ack").as_bytes(), &[b'f', b'c', 1]);
+
+    // The outermost layer rejects the packet before it reaches the inner ones.
+    let (_, ack) = stack.on_recv_packet_execute(&packet_with_data(b"cf-payload"), &relayer);
+    let ack = ack.expect("synchronous ack");
+    let result = AcknowledgementResult::try_from(&ack).expect("valid ack result");
+    assert_eq!(result, AcknowledgementResult::error(1, "unexpected tag"));
+}
+
+#[test]
+fn test_middleware_stack_forwards_by_default() {
+    let mut stack = TaggingMiddleware { tag: b'f' }.wrap(DummyTransferModule::new());
+    let packet = packet_with_data(b"f-payload");
+    let relayer = dummy_account_id();
+
+    assert!(stack.on_timeout_packet_validate(&packet, &relayer).is_ok());
+    let (_, res) = stack.on_timeout_packet_execute(&packet, &relayer);
+    assert!(res.is_ok());
+}
+
+#[test]
+fn test_router_registers_stack_per_port() {
+    let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    let stack = TaggingMiddleware { tag: b'f' }.wrap(DummyTransferModule::new());
+
+    let mut router = MockRouter::default();
+    router
+        .add_stack(PortId::transfer(), module_id.clone(), stack)
+        .expect("no module registered yet");
+
+    assert_eq!(
+        router.lookup_module(&PortId::transfer()),
+        Some(module_id.clone())
+    );
+
+    let module = router
+        .get_route_mut(&module_id)
+        .expect("stack is registered");
+    let (_, ack) = module.on_recv_packet_execute(&packet_with_data(b"f"), &dummy_account_id());
+    assert_eq!(ack.expect("synchronous ack").as_bytes(), &[b'f', 1]);
+}
@@ -1,5 +1,11 @@
+pub mod abci_events;
+pub mod capability;
+pub mod event_schema;
+pub mod hermes_compat;
 pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
+pub mod middleware;
 #[cfg(feature = "serde")]
 pub mod router;
+pub mod store_context;
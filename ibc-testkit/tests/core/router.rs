@@ -11,15 +11,17 @@ use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::msgs::ConnectionMsg;
-use ibc::core::entrypoint::dispatch;
+use ibc::core::entrypoint::{dispatch, dispatch_any};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ConnectionId;
 use ibc::core::host::types::path::CommitmentPath;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::PacketValidationContext;
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Timestamp, ToProto};
+use ibc::core::router::types::error::RouterError;
+use ibc::primitives::proto::Any;
 use ibc_testkit::fixtures::applications::transfer::{
     extract_transfer_packet, MsgTransferConfig, PacketDataConfig,
 };
@@ -51,6 +53,8 @@ fn routing_module_and_keepers() {
     enum TestMsg {
         Ics26(MsgEnvelope),
         Ics20(MsgTransfer),
+        /// A transfer dispatched through the router, as encoded by its host.
+        Ics20Any(MsgTransfer),
     }
 
     impl From<MsgEnvelope> for TestMsg {
@@ -92,9 +96,14 @@ fn routing_module_and_keepers() {
     let mut router = MockRouter::new_with_transfer();
 
     let create_client_msg = MsgCreateClient::new(
-        MockClientState::new(MockHeader::new(start_client_height).with_current_timestamp()).into(),
-        MockConsensusState::new(MockHeader::new(start_client_height).with_current_timestamp())
-            .into(),
+        MockClientState::new(
+            MockHeader::new(start_client_height).with_current_timestamp(&*ctx.time_provider),
+        )
+        .into(),
+        MockConsensusState::new(
+            MockHeader::new(start_client_height).with_current_timestamp(&*ctx.time_provider),
+        )
+        .into(),
         default_signer.clone(),
     );
 
@@ -171,7 +180,8 @@ fn routing_module_and_keepers() {
 
     msg_to_on_close.packet.data = packet_data;
 
-    let msg_recv_packet = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(35)).unwrap();
+    let msg_recv_packet =
+        MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(35, &*ctx.time_provider)).unwrap();
     let msg_ack_packet = MsgAcknowledgement::try_from(dummy_raw_msg_ack_with_packet(
         extract_transfer_packet(&msg_transfer, 1u64.into()).into(),
         35,
@@ -214,7 +224,7 @@ fn routing_module_and_keepers() {
             msg: MsgEnvelope::Client(ClientMsg::UpdateClient(MsgUpdateClient {
                 client_id: client_id.clone(),
                 client_message: MockHeader::new(update_client_height)
-                    .with_current_timestamp()
+                    .with_current_timestamp(&*ctx.time_provider)
                     .into(),
                 signer: default_signer.clone(),
             }))
@@ -280,7 +290,7 @@ fn routing_module_and_keepers() {
         },
         Test {
             name: "Packet send".to_string(),
-            msg: msg_transfer.into(),
+            msg: TestMsg::Ics20Any(msg_transfer),
             want_pass: true,
             state_check: None,
         },
@@ -291,7 +301,7 @@ fn routing_module_and_keepers() {
             msg: MsgEnvelope::Client(ClientMsg::UpdateClient(MsgUpdateClient {
                 client_id: client_id.clone(),
                 client_message: MockHeader::new(update_client_height_after_send)
-                    .with_current_timestamp()
+                    .with_current_timestamp(&*ctx.time_provider)
                     .into(),
                 signer: default_signer.clone(),
             }))
@@ -327,7 +337,7 @@ fn routing_module_and_keepers() {
         },
         Test {
             name: "Packet send".to_string(),
-            msg: msg_transfer_two.into(),
+            msg: TestMsg::Ics20Any(msg_transfer_two),
             want_pass: true,
             state_check: None,
         },
@@ -336,7 +346,7 @@ fn routing_module_and_keepers() {
             msg: MsgEnvelope::Client(ClientMsg::UpdateClient(MsgUpdateClient {
                 client_id: client_id.clone(),
                 client_message: MockHeader::new(update_client_height_after_second_send)
-                    .with_current_timestamp()
+                    .with_current_timestamp(&*ctx.time_provider)
                     .into(),
                 signer: default_signer,
             }))
@@ -347,16 +357,30 @@ fn routing_module_and_keepers() {
         // Timeout packets
         Test {
             name: "Transfer message no timeout".to_string(),
-            msg: msg_transfer_no_timeout.into(),
+            msg: msg_transfer_no_timeout.clone().into(),
             want_pass: true,
             state_check: None,
         },
         Test {
             name: "Transfer message no timeout nor timestamp".to_string(),
-            msg: msg_transfer_no_timeout_or_timestamp.into(),
+            msg: msg_transfer_no_timeout_or_timestamp.clone().into(),
             want_pass: true,
             state_check: None,
         },
+        // Transfers without any timeout are rejected upon decoding, as the
+        // packets they send could never time out.
+        Test {
+            name: "Dispatched transfer message no timeout".to_string(),
+            msg: TestMsg::Ics20Any(msg_transfer_no_timeout),
+            want_pass: false,
+            state_check: None,
+        },
+        Test {
+            name: "Dispatched transfer message no timeout nor timestamp".to_string(),
+            msg: TestMsg::Ics20Any(msg_transfer_no_timeout_or_timestamp),
+            want_pass: false,
+            state_check: None,
+        },
         //ICS04-close channel
         Test {
             name: "Channel close init succeeds".to_string(),
@@ -404,11 +428,13 @@ fn routing_module_and_keepers() {
     for test in tests {
         let res = match test.msg.clone() {
             TestMsg::Ics26(msg) => dispatch(&mut ctx, &mut router, msg).map(|_| ()),
-            TestMsg::Ics20(msg) => send_transfer(&mut ctx, &mut DummyTransferModule, msg)
+            TestMsg::Ics20(msg) => send_transfer(&mut ctx, &mut DummyTransferModule::new(), msg)
                 .map_err(|e: TokenTransferError| ChannelError::AppModule {
                     description: e.to_string(),
                 })
                 .map_err(ContextError::from),
+            // Application messages reach their module through the router.
+            TestMsg::Ics20Any(msg) => dispatch_any(&mut ctx, &mut router, msg.to_any()).map(|_| ()),
         };
 
         assert_eq!(
@@ -432,3 +458,32 @@ fn routing_module_and_keepers() {
         }
     }
 }
+
+#[test]
+fn dispatch_any_routes_by_type_url() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 5).unwrap();
+
+    let create_client_msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+
+    let res = dispatch_any(&mut ctx, &mut router, create_client_msg.to_any());
+    assert!(res.is_ok(), "core messages are dispatched: {res:?}");
+
+    let unknown_msg = Any {
+        type_url: "/unknown.v1.Msg".to_string(),
+        value: Vec::new(),
+    };
+
+    let res = dispatch_any(&mut ctx, &mut router, unknown_msg);
+    assert!(matches!(
+        res,
+        Err(ContextError::RouterError(
+            RouterError::UnknownMessageTypeUrl { .. }
+        ))
+    ));
+}
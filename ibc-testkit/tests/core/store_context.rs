@@ -0,0 +1,186 @@
+use ibc::core::channel::types::commitment::AcknowledgementCommitment;
+use ibc::core::client::context::{
+    ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
+};
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::entrypoint::dispatch;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::store::{ProvableStore, StoreContext, StoreHost};
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::host::types::path::{AckPath, ChannelEndPath, ClientStatePath, Path};
+use ibc::core::host::{
+    ClientRegistryValidationContext, HostValidationContext, PacketExecutionContext,
+    PacketValidationContext, ProofQueryError,
+};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{DefaultCryptoProvider, Signer, Timestamp};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::store::InMemoryStore;
+use test_log::test;
+
+/// A host whose only state outside of its IBC store is its height.
+struct TestHost {
+    height: Height,
+}
+
+impl StoreHost for TestHost {
+    type Store = InMemoryStore;
+    type ClientState = MockClientState;
+    type ConsensusState = AnyConsensusState;
+    type HostClientState = MockClientState;
+    type HostConsensusState = MockConsensusState;
+    type CryptoProvider = DefaultCryptoProvider;
+    type HostError = ProofQueryError;
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        Ok(self.height)
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        Ok(MockHeader::new(self.height).timestamp)
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        Ok(MockConsensusState::new(MockHeader::new(*height)))
+    }
+
+    fn validate_self_client(
+        &self,
+        _client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        CommitmentPrefix::try_from(b"ibc".to_vec()).expect("never fails")
+    }
+
+    fn validate_message_signer(&self, _signer: &Signer) -> Result<(), ContextError> {
+        Ok(())
+    }
+}
+
+fn store_context() -> StoreContext<TestHost> {
+    StoreContext::new(
+        InMemoryStore::default(),
+        TestHost {
+            height: Height::new(0, 10).unwrap(),
+        },
+    )
+}
+
+#[test]
+fn test_create_client_in_store() {
+    let mut ctx = store_context();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 5).unwrap();
+
+    let client_state = MockClientState::new(MockHeader::new(height));
+    let msg = MsgCreateClient::new(
+        client_state.into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+
+    let root = ctx.root();
+
+    dispatch(
+        &mut ctx,
+        &mut router,
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    )
+    .expect("create client succeeds");
+
+    let client_id = mock_client_type().build_client_id(0);
+    let key = ClientStatePath::new(client_id.clone()).to_string();
+    assert_eq!(key, "clients/9999-mock-0/clientState");
+    assert!(ctx.store().get(key.as_bytes()).is_some());
+
+    assert_eq!(ctx.client_state(&client_id).unwrap(), client_state);
+    assert_eq!(ctx.client_counter().unwrap(), 1);
+    assert_eq!(ctx.consensus_state_heights(&client_id).unwrap(), [height]);
+    assert_ne!(ctx.root(), root);
+
+    let host_height = ctx.host().height;
+    let host_timestamp = MockHeader::new(host_height).timestamp;
+    ctx.store_update_meta(client_id.clone(), height, host_timestamp, host_height)
+        .unwrap();
+    assert_eq!(
+        ctx.client_update_meta(&client_id, &height).unwrap(),
+        (host_timestamp, host_height)
+    );
+    assert_eq!(ctx.update_heights(&client_id).unwrap(), [height]);
+
+    let events = ctx.take_events();
+    assert!(matches!(
+        events.as_slice(),
+        [IbcEvent::Message(_), IbcEvent::CreateClient(_)]
+    ));
+    assert!(ctx.take_events().is_empty());
+}
+
+#[test]
+fn test_delete_packet_acknowledgements_in_store() {
+    let mut ctx = store_context();
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::new(0);
+    let other_channel_id = ChannelId::new(1);
+
+    for channel_id in [&channel_id, &other_channel_id] {
+        for seq in 1..=3u64 {
+            ctx.store_packet_acknowledgement(
+                &AckPath::new(&port_id, channel_id, Sequence::from(seq)),
+                AcknowledgementCommitment::from(vec![1]),
+            )
+            .unwrap();
+        }
+    }
+
+    ctx.delete_packet_acknowledgements(&ChannelEndPath::new(&port_id, &channel_id))
+        .unwrap();
+
+    for seq in 1..=3u64 {
+        let ack_path = AckPath::new(&port_id, &channel_id, Sequence::from(seq));
+        assert!(ctx.get_packet_acknowledgement(&ack_path).is_err());
+
+        let ack_path = AckPath::new(&port_id, &other_channel_id, Sequence::from(seq));
+        assert!(ctx.get_packet_acknowledgement(&ack_path).is_ok());
+    }
+}
+
+#[test]
+fn test_query_with_proof_in_store() {
+    let ctx = store_context();
+    let path = Path::ClientState(ClientStatePath::new(mock_client_type().build_client_id(0)));
+
+    // The in-memory store does not produce proofs.
+    let host_height = ctx.host().height;
+    let error = ctx.query_with_proof(&path, host_height).unwrap_err();
+    assert!(matches!(
+        error.host_error(),
+        Some(ProofQueryError::MissingProof { path: p }) if *p == path
+    ));
+
+    // Past heights are not retained.
+    let past_height = host_height.decrement().unwrap();
+    let error = ctx.query_with_proof(&path, past_height).unwrap_err();
+    assert!(matches!(
+        error.host_error(),
+        Some(ProofQueryError::HeightNotRetained { height, latest_height, .. })
+            if *height == past_height && *latest_height == host_height
+    ));
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "serde")]
+
+use ibc::clients::tendermint::types::{
+    client_type as tm_client_type, ConsensusState as TmConsensusState,
+};
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::path::{ClientStatePath, NextClientSequencePath};
+use ibc::core::host::ClientRegistryValidationContext;
+use ibc::core::primitives::HostTimeProvider;
+use ibc_testkit::fixtures::clients::tendermint::{
+    dummy_tendermint_header, dummy_tm_client_state_from_header,
+};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::node::MockNode;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use test_log::test;
+
+fn create_mock_client_msg(height: Height, time_provider: &dyn HostTimeProvider) -> MsgEnvelope {
+    MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height).with_current_timestamp(time_provider)).into(),
+        MockConsensusState::new(MockHeader::new(height).with_current_timestamp(time_provider))
+            .into(),
+        dummy_account_id(),
+    )))
+}
+
+fn create_tm_client_msg() -> MsgEnvelope {
+    let tm_header = dummy_tendermint_header();
+
+    MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+        dummy_tm_client_state_from_header(tm_header.clone()).into(),
+        TmConsensusState::from(tm_header).into(),
+        dummy_account_id(),
+    )))
+}
+
+#[test]
+fn test_node_commits_delivered_txs() {
+    let mut node = MockNode::default();
+    let genesis_height = node.latest_height();
+    let genesis_app_hash = node.app_hash().clone();
+
+    let mock_client_msg =
+        create_mock_client_msg(Height::new(0, 42).unwrap(), &*node.ctx.time_provider);
+    let results = node.produce_block([vec![create_tm_client_msg()], vec![mock_client_msg]]);
+
+    assert_eq!(results.height, genesis_height.increment());
+    assert_eq!(node.latest_height(), results.height);
+    assert_eq!(&results.app_hash, node.app_hash());
+    assert_ne!(results.app_hash, genesis_app_hash);
+
+    assert_eq!(results.tx_results.len(), 2);
+    for tx_result in &results.tx_results {
+        let events = tx_result.as_ref().unwrap();
+        assert!(matches!(events[1], IbcEvent::CreateClient(_)));
+    }
+
+    // Events are flushed with the block they were emitted in.
+    assert!(node.ctx.get_events().is_empty());
+
+    let committed_state = node.committed_state();
+    let client_state_path = ClientStatePath::new(tm_client_type().build_client_id(0));
+    assert!(committed_state.contains_key(&client_state_path.to_string()));
+    assert_eq!(
+        committed_state[&NextClientSequencePath.to_string()],
+        2u64.to_be_bytes()
+    );
+}
+
+#[test]
+fn test_node_reverts_failed_txs() {
+    let mut node = MockNode::default();
+    let genesis_app_hash = node.app_hash().clone();
+
+    node.begin_block();
+
+    // The client creation is reverted along with the failing update.
+    let client_message = MockHeader::new(Height::new(0, 42).unwrap())
+        .with_current_timestamp(&*node.ctx.time_provider)
+        .into();
+    let tx_result = node.deliver_tx([
+        create_tm_client_msg(),
+        MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+            client_id: mock_client_type().build_client_id(1),
+            client_message,
+            signer: dummy_account_id(),
+        })),
+    ]);
+    assert!(tx_result.is_err());
+
+    let results = node.commit();
+
+    assert_eq!(results.app_hash, genesis_app_hash);
+    assert!(node.committed_state().is_empty());
+    assert_eq!(node.ctx.client_counter().unwrap(), 0);
+    assert!(node.ctx.get_events().is_empty());
+}